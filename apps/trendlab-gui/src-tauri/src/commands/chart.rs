@@ -78,6 +78,33 @@ pub struct DrawdownPoint {
     pub drawdown: f64,
 }
 
+/// A single point on an indicator overlay line.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndicatorPoint {
+    /// Unix timestamp in seconds
+    pub time: i64,
+    /// Indicator value
+    pub value: f64,
+}
+
+/// One indicator column in an overlay, aligned with [`IndicatorOverlayData::time`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverlaySeries {
+    /// Column name (e.g. "dc_upper", "kc_lower")
+    pub name: String,
+    /// One value per bar; null during warmup
+    pub values: Vec<Option<f64>>,
+}
+
+/// Indicator overlay for the cached bars of a symbol.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndicatorOverlayData {
+    pub symbol: String,
+    /// Unix timestamps in seconds, one per bar
+    pub time: Vec<i64>,
+    pub series: Vec<OverlaySeries>,
+}
+
 /// A trade marker for chart display.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeMarker {
@@ -119,6 +146,10 @@ pub struct ChartData {
     pub drawdown: Option<Vec<DrawdownPoint>>,
     /// Trade markers
     pub trades: Option<Vec<TradeMarker>>,
+    /// Accumulation/Distribution line overlay (Candlestick mode)
+    pub ad_line: Option<Vec<IndicatorPoint>>,
+    /// Williams A/D overlay (Candlestick mode)
+    pub williams_ad: Option<Vec<IndicatorPoint>>,
 }
 
 /// Chart overlay options.
@@ -132,6 +163,10 @@ pub struct ChartOverlays {
     pub trades: bool,
     /// Show crosshair
     pub crosshair: bool,
+    /// Show Accumulation/Distribution line
+    pub ad_line: bool,
+    /// Show Williams A/D
+    pub williams_ad: bool,
 }
 
 /// Chart state stored in AppState.
@@ -161,6 +196,8 @@ impl Default for ChartState {
                 volume: true,
                 trades: true,
                 crosshair: true,
+                ad_line: false,
+                williams_ad: false,
             },
         }
     }
@@ -182,6 +219,8 @@ pub fn get_chart_state(state: State<'_, AppState>) -> ChartState {
     let show_drawdown = engine.chart.show_drawdown;
     let show_volume = engine.chart.show_volume;
     let show_crosshair = engine.chart.show_crosshair;
+    let show_ad_line = engine.chart.show_ad_line;
+    let show_williams_ad = engine.chart.show_williams_ad;
     let selected_index = engine.chart.selected_result_index;
 
     // Get config_id from selected result if available
@@ -208,6 +247,8 @@ pub fn get_chart_state(state: State<'_, AppState>) -> ChartState {
             volume: show_volume,
             trades: true,
             crosshair: show_crosshair,
+            ad_line: show_ad_line,
+            williams_ad: show_williams_ad,
         },
     }
 }
@@ -355,6 +396,61 @@ pub fn get_candle_data(
     Ok(candles)
 }
 
+/// Compute an indicator overlay (e.g. Donchian, Keltner, Supertrend, Bollinger)
+/// over the cached bars for a symbol, for previewing before a backtest.
+#[tauri::command]
+pub fn get_indicator_overlay(
+    state: State<'_, AppState>,
+    symbol: String,
+    indicator_spec: trendlab_core::IndicatorSpec,
+) -> Result<IndicatorOverlayData, GuiError> {
+    debug!(symbol = %symbol, spec = ?indicator_spec, "Computing indicator overlay");
+
+    let engine = state.engine_read();
+    let overlay = engine
+        .data
+        .indicator_overlay(&symbol, &indicator_spec)
+        .map_err(|e| GuiError::Internal(format!("Indicator computation failed: {}", e)))?
+        .ok_or_else(|| GuiError::NotFound {
+            resource: format!("Cached bars for {}", symbol),
+        })?;
+    drop(engine);
+
+    Ok(IndicatorOverlayData {
+        symbol,
+        time: overlay.timestamps.iter().map(|ts| ts.timestamp()).collect(),
+        series: overlay
+            .series
+            .into_iter()
+            .map(|(name, values)| OverlaySeries { name, values })
+            .collect(),
+    })
+}
+
+/// Compute an indicator over candles and pair each value with its candle time.
+fn candle_indicator(
+    candles: &[CandleData],
+    symbol: &str,
+    indicator: fn(&[trendlab_core::Bar]) -> Vec<f64>,
+) -> Vec<IndicatorPoint> {
+    let bars: Vec<trendlab_core::Bar> = candles
+        .iter()
+        .map(|c| {
+            let ts = chrono::DateTime::from_timestamp(c.time, 0).unwrap_or_default();
+            trendlab_core::Bar::new(ts, c.open, c.high, c.low, c.close, c.volume, symbol, "1d")
+        })
+        .collect();
+
+    candles
+        .iter()
+        .zip(indicator(&bars))
+        .map(|(c, value)| IndicatorPoint {
+            time: c.time,
+            value,
+        })
+        .collect()
+}
+
 /// Get equity curve for a specific result.
 #[tauri::command]
 pub fn get_equity_curve(
@@ -750,7 +846,16 @@ pub fn get_chart_data(state: State<'_, AppState>) -> Result<ChartData, GuiError>
             })?;
 
             debug!(symbol = %symbol, "Fetching candlestick data");
-            let candles = get_candle_data(state.clone(), symbol)?;
+            let candles = get_candle_data(state.clone(), symbol.clone())?;
+
+            let ad_line = gui_chart_state
+                .overlays
+                .ad_line
+                .then(|| candle_indicator(&candles, &symbol, trendlab_core::ad_line));
+            let williams_ad = gui_chart_state
+                .overlays
+                .williams_ad
+                .then(|| candle_indicator(&candles, &symbol, trendlab_core::williams_ad));
 
             Ok(ChartData {
                 candles: Some(candles),
@@ -758,6 +863,8 @@ pub fn get_chart_data(state: State<'_, AppState>) -> Result<ChartData, GuiError>
                 curves: None,
                 drawdown: None,
                 trades: None,
+                ad_line,
+                williams_ad,
             })
         }
         ChartMode::Equity => {
@@ -816,6 +923,8 @@ pub fn get_chart_data(state: State<'_, AppState>) -> Result<ChartData, GuiError>
                 curves: None,
                 drawdown,
                 trades,
+                ad_line: None,
+                williams_ad: None,
             })
         }
         ChartMode::MultiTicker => {
@@ -826,6 +935,8 @@ pub fn get_chart_data(state: State<'_, AppState>) -> Result<ChartData, GuiError>
                 curves: Some(curves),
                 drawdown: None,
                 trades: None,
+                ad_line: None,
+                williams_ad: None,
             })
         }
         ChartMode::Portfolio => {
@@ -836,6 +947,8 @@ pub fn get_chart_data(state: State<'_, AppState>) -> Result<ChartData, GuiError>
                 curves: None,
                 drawdown: None,
                 trades: None,
+                ad_line: None,
+                williams_ad: None,
             })
         }
         ChartMode::StrategyComparison => {
@@ -846,6 +959,8 @@ pub fn get_chart_data(state: State<'_, AppState>) -> Result<ChartData, GuiError>
                 curves: Some(curves),
                 drawdown: None,
                 trades: None,
+                ad_line: None,
+                williams_ad: None,
             })
         }
     }
@@ -6,6 +6,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use tauri::State;
+use trendlab_engine::worker::WorkerCommand;
 
 use crate::error::GuiError;
 use crate::state::AppState;
@@ -46,6 +47,102 @@ pub struct ResultRow {
     pub equity_curve: Vec<f64>,
 }
 
+impl From<&trendlab_core::SweepConfigResult> for ResultRow {
+    fn from(r: &trendlab_core::SweepConfigResult) -> Self {
+        ResultRow {
+            id: format!("{:?}", r.config_id),
+            symbol: "".to_string(), // SweepConfigResult doesn't have symbol
+            strategy: format!("{:?}", r.config_id),
+            config_id: format!("{:?}", r.config_id),
+            metrics: ResultMetrics {
+                total_return: r.metrics.total_return,
+                cagr: r.metrics.cagr,
+                sharpe: r.metrics.sharpe,
+                sortino: r.metrics.sortino,
+                max_drawdown: r.metrics.max_drawdown,
+                calmar: r.metrics.calmar,
+                win_rate: r.metrics.win_rate,
+                profit_factor: r.metrics.profit_factor,
+                num_trades: r.metrics.num_trades,
+                turnover: r.metrics.turnover,
+            },
+            equity_curve: r.backtest_result.equity.iter().map(|p| p.equity).collect(),
+        }
+    }
+}
+
+/// Trades per page returned by [`get_result_trades`].
+pub const TRADE_PAGE_SIZE: usize = 500;
+
+/// A closed trade for the blotter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeRow {
+    /// Entry/exit fills, PnL, direction and exit reason
+    #[serde(flatten)]
+    pub trade: trendlab_core::Trade,
+    /// Maximum adverse excursion as a fraction of entry price (on closes)
+    pub mae_pct: f64,
+    /// Maximum favorable excursion as a fraction of entry price (on closes)
+    pub mfe_pct: f64,
+}
+
+/// One page of a result's trade list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradePage {
+    pub trades: Vec<TradeRow>,
+    /// Total trades in the result
+    pub total: usize,
+    /// Zero-based page index
+    pub page: usize,
+    pub page_size: usize,
+}
+
+impl TradePage {
+    /// Build page `page` of `result`'s trades.
+    ///
+    /// Excursions are measured on the closes recorded in the equity curve
+    /// while the trade was open, since the sweep result keeps no bars.
+    pub fn from_backtest(result: &trendlab_core::BacktestResult, page: usize) -> Self {
+        let trades = result
+            .trades
+            .iter()
+            .skip(page * TRADE_PAGE_SIZE)
+            .take(TRADE_PAGE_SIZE)
+            .map(|trade| {
+                let entry = trade.entry.price;
+                let closes = result
+                    .equity
+                    .iter()
+                    .filter(|p| p.ts >= trade.entry.ts && p.ts <= trade.exit.ts)
+                    .map(|p| p.close);
+                let (mut mae_pct, mut mfe_pct) = (0.0_f64, 0.0_f64);
+                if entry > 0.0 {
+                    for close in closes {
+                        let change = match trade.direction {
+                            trendlab_core::TradeDirection::Long => (close - entry) / entry,
+                            trendlab_core::TradeDirection::Short => (entry - close) / entry,
+                        };
+                        mae_pct = mae_pct.max(-change);
+                        mfe_pct = mfe_pct.max(change);
+                    }
+                }
+                TradeRow {
+                    trade: trade.clone(),
+                    mae_pct,
+                    mfe_pct,
+                }
+            })
+            .collect();
+
+        Self {
+            trades,
+            total: result.trades.len(),
+            page,
+            page_size: TRADE_PAGE_SIZE,
+        }
+    }
+}
+
 /// View mode for results display.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -136,6 +233,9 @@ pub struct ResultsQuery {
     pub limit: Option<usize>,
     pub symbol_filter: Option<String>,
     pub strategy_filter: Option<String>,
+    /// Metric filter expression, e.g. "sharpe > 1 and num_trades >= 30"
+    #[serde(default)]
+    pub metric_filter: Option<String>,
 }
 
 impl Default for ResultsQuery {
@@ -147,6 +247,7 @@ impl Default for ResultsQuery {
             limit: None,
             symbol_filter: None,
             strategy_filter: None,
+            metric_filter: None,
         }
     }
 }
@@ -198,34 +299,34 @@ pub fn get_results_count(state: State<'_, AppState>) -> usize {
 
 /// Get all results with optional filtering and sorting.
 #[tauri::command]
-pub fn get_results(state: State<'_, AppState>, query: Option<ResultsQuery>) -> Vec<ResultRow> {
-    let engine = state.engine_read();
+pub fn get_results(
+    state: State<'_, AppState>,
+    query: Option<ResultsQuery>,
+) -> Result<Vec<ResultRow>, GuiError> {
     let query = query.unwrap_or_default();
 
+    let metric_filter = query
+        .metric_filter
+        .as_deref()
+        .filter(|f| !f.trim().is_empty())
+        .map(trendlab_core::parse_filter)
+        .transpose()
+        .map_err(|e| GuiError::InvalidInput {
+            message: format!("Invalid metric filter: {}", e),
+        })?;
+
+    let engine = state.engine_read();
+
     // Convert engine's SweepConfigResult to GUI's ResultRow
     let mut results: Vec<ResultRow> = engine
         .results
         .results
         .iter()
-        .map(|r| ResultRow {
-            id: format!("{:?}", r.config_id),
-            symbol: "".to_string(), // SweepConfigResult doesn't have symbol
-            strategy: format!("{:?}", r.config_id),
-            config_id: format!("{:?}", r.config_id),
-            metrics: ResultMetrics {
-                total_return: r.metrics.total_return,
-                cagr: r.metrics.cagr,
-                sharpe: r.metrics.sharpe,
-                sortino: r.metrics.sortino,
-                max_drawdown: r.metrics.max_drawdown,
-                calmar: r.metrics.calmar,
-                win_rate: r.metrics.win_rate,
-                profit_factor: r.metrics.profit_factor,
-                num_trades: r.metrics.num_trades,
-                turnover: r.metrics.turnover,
-            },
-            equity_curve: r.backtest_result.equity.iter().map(|p| p.equity).collect(),
+        .filter(|r| match &metric_filter {
+            Some(expr) => expr.matches(&r.metrics),
+            None => true,
         })
+        .map(ResultRow::from)
         .collect();
     drop(engine); // Release lock before filtering
 
@@ -297,7 +398,7 @@ pub fn get_results(state: State<'_, AppState>, query: Option<ResultsQuery>) -> V
         results.truncate(limit);
     }
 
-    results
+    Ok(results)
 }
 
 /// Get ticker summaries (best per ticker).
@@ -364,30 +465,72 @@ pub fn get_result_detail(
         .results
         .iter()
         .find(|r| format!("{:?}", r.config_id) == result_id)
-        .map(|r| ResultRow {
-            id: format!("{:?}", r.config_id),
-            symbol: "".to_string(),
-            strategy: format!("{:?}", r.config_id),
-            config_id: format!("{:?}", r.config_id),
-            metrics: ResultMetrics {
-                total_return: r.metrics.total_return,
-                cagr: r.metrics.cagr,
-                sharpe: r.metrics.sharpe,
-                sortino: r.metrics.sortino,
-                max_drawdown: r.metrics.max_drawdown,
-                calmar: r.metrics.calmar,
-                win_rate: r.metrics.win_rate,
-                profit_factor: r.metrics.profit_factor,
-                num_trades: r.metrics.num_trades,
-                turnover: r.metrics.turnover,
-            },
-            equity_curve: r.backtest_result.equity.iter().map(|p| p.equity).collect(),
-        })
+        .map(ResultRow::from)
         .ok_or_else(|| GuiError::NotFound {
             resource: format!("Result with id '{}'", result_id),
         })
 }
 
+/// Get a page of a result's trade list (all trades if there are at most
+/// [`TRADE_PAGE_SIZE`]).
+#[tauri::command]
+pub fn get_result_trades(
+    state: State<'_, AppState>,
+    config_id: String,
+    page: Option<usize>,
+) -> Result<TradePage, GuiError> {
+    let engine = state.engine_read();
+
+    engine
+        .results
+        .results
+        .iter()
+        .find(|r| format!("{:?}", r.config_id) == config_id)
+        .map(|r| TradePage::from_backtest(&r.backtest_result, page.unwrap_or(0)))
+        .ok_or_else(|| GuiError::NotFound {
+            resource: format!("Result with id '{}'", config_id),
+        })
+}
+
+/// Compute bootstrap Sharpe CI, permutation p-value and confidence grade for
+/// a result.
+///
+/// Runs on the worker; the outcome arrives as a `worker:statistics-complete`
+/// (or `worker:statistics-error`) event. Defaults to [`BootstrapConfig::quick`].
+///
+/// [`BootstrapConfig::quick`]: trendlab_core::BootstrapConfig::quick
+#[tauri::command]
+pub fn run_statistics(
+    state: State<'_, AppState>,
+    config_id: String,
+    bootstrap_config: Option<trendlab_core::BootstrapConfig>,
+) -> Result<(), GuiError> {
+    let config = bootstrap_config.unwrap_or_else(trendlab_core::BootstrapConfig::quick);
+    config.validate().map_err(|e| GuiError::InvalidInput {
+        message: format!("Invalid bootstrap config: {}", e),
+    })?;
+
+    let engine = state.engine_read();
+    let backtest_result = engine
+        .results
+        .results
+        .iter()
+        .find(|r| format!("{:?}", r.config_id) == config_id)
+        .map(|r| r.backtest_result.clone())
+        .ok_or_else(|| GuiError::NotFound {
+            resource: format!("Result with id '{}'", config_id),
+        })?;
+    drop(engine);
+
+    state
+        .send_command(WorkerCommand::ComputeStatistics {
+            config_id,
+            backtest_result,
+            config,
+        })
+        .map_err(|e| GuiError::Internal(format!("Failed to start statistics: {}", e)))
+}
+
 /// Set the selected result ID.
 #[tauri::command]
 pub fn select_result(state: State<'_, AppState>, result_id: Option<String>) {
@@ -475,3 +618,29 @@ pub fn export_artifact(state: State<'_, AppState>, result_id: String) -> Result<
 pub fn clear_results(state: State<'_, AppState>) {
     state.clear_results();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trendlab_core::{run_backtest, BacktestConfig, DonchianBreakoutStrategy};
+    use trendlab_engine::test_support::sine_wave_bars;
+
+    #[test]
+    fn test_trade_page_matches_backtest_trades() {
+        let bars = sine_wave_bars(120, 0.25, 10.0, 0.0, "TEST");
+        let mut strategy = DonchianBreakoutStrategy::new(10, 5);
+        let result = run_backtest(&bars, &mut strategy, BacktestConfig::default()).unwrap();
+        assert!(!result.trades.is_empty());
+
+        let page = TradePage::from_backtest(&result, 0);
+        assert_eq!(page.total, result.trades.len());
+        assert_eq!(page.trades.len(), result.trades.len());
+        for (row, trade) in page.trades.iter().zip(&result.trades) {
+            assert_eq!(&row.trade, trade);
+            assert!(row.mae_pct >= 0.0 && row.mfe_pct >= 0.0);
+        }
+
+        // Past the last page is empty
+        assert!(TradePage::from_backtest(&result, 1).trades.is_empty());
+    }
+}
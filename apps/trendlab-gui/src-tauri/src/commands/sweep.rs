@@ -98,6 +98,7 @@ impl CostModel {
         trendlab_core::CostModel {
             fees_bps_per_side: self.fees_bps,
             slippage_bps: self.slippage_bps,
+            ..Default::default()
         }
     }
 
@@ -324,6 +325,11 @@ pub fn start_sweep(state: State<'_, AppState>) -> Result<StartSweepResponse, Gui
         cost_model: core_cost_model,
         qty: 100.0,
         pyramid_config: trendlab_core::PyramidConfig::default(),
+        stop_loss_pct: None,
+        take_profit_pct: None,
+        cash_yield_annual: 0.0,
+        intrabar: trendlab_core::IntrabarModel::default(),
+        warmup_bars: None,
     };
 
     // Register job and set running status (GUI-side tracking)
@@ -473,5 +479,6 @@ fn build_strategy_grid(
 
     trendlab_core::MultiStrategyGrid {
         strategies: configs,
+        seed: 0,
     }
 }
@@ -288,6 +288,10 @@ pub fn start_yolo_mode(
         cost_model: core_cost_model,
         qty: 100.0,
         pyramid_config: trendlab_core::PyramidConfig::default(),
+        stop_loss_pct: None,
+        take_profit_pct: None,
+        cash_yield_annual: 0.0,
+        intrabar: trendlab_core::IntrabarModel::default(),
     };
 
     // Load existing leaderboards if any
@@ -436,5 +440,6 @@ fn build_strategy_grid(strategies: &[String]) -> trendlab_core::MultiStrategyGri
 
     trendlab_core::MultiStrategyGrid {
         strategies: configs,
+        seed: 0,
     }
 }
@@ -61,6 +61,8 @@ fn apply_worker_update(engine: &mut trendlab_engine::app::App, update: &WorkerUp
         // Ready/Idle updates
         WorkerUpdate::Ready => {}
         WorkerUpdate::Idle => {}
+        WorkerUpdate::Timing { .. } => {}
+        WorkerUpdate::TimingSummary { .. } => {}
 
         // Search results
         WorkerUpdate::SearchResults { query, results } => {
@@ -145,12 +147,16 @@ fn apply_worker_update(engine: &mut trendlab_engine::app::App, update: &WorkerUp
                 total: *total_configs,
             };
         }
-        WorkerUpdate::SweepProgress { completed, total } => {
+        WorkerUpdate::SweepProgress {
+            completed, total, ..
+        } => {
             engine.operation = OperationState::RunningSweep {
                 completed: *completed,
                 total: *total,
             };
         }
+        // Streamed to the frontend only; the engine stores the final result
+        WorkerUpdate::SweepPartialResults { .. } => {}
         WorkerUpdate::SweepComplete { result } => {
             engine.operation = OperationState::Idle;
             engine.results.results = result.config_results.clone();
@@ -233,6 +239,10 @@ fn apply_worker_update(engine: &mut trendlab_engine::app::App, update: &WorkerUp
         WorkerUpdate::AnalysisError { analysis_id, error } => {
             tracing::error!(analysis_id, error, "Analysis failed");
         }
+        WorkerUpdate::StatisticsComplete { .. } => {}
+        WorkerUpdate::StatisticsError { config_id, error } => {
+            tracing::error!(config_id, error, "Statistics failed");
+        }
 
         // YOLO mode
         WorkerUpdate::YoloIterationComplete {
@@ -262,6 +272,9 @@ fn apply_worker_update(engine: &mut trendlab_engine::app::App, update: &WorkerUp
         WorkerUpdate::YoloProgress { iteration, .. } => {
             engine.yolo.iteration = *iteration;
         }
+        WorkerUpdate::YoloConfigRejected { reason, .. } => {
+            engine.yolo.record_rejection(*reason);
+        }
 
         // Cancelled states - return to idle
         WorkerUpdate::MultiSweepCancelled { .. } => {
@@ -283,6 +296,7 @@ fn apply_worker_update(engine: &mut trendlab_engine::app::App, update: &WorkerUp
 
 /// Emit a worker update as a Tauri event for the React frontend.
 fn emit_worker_update(app_handle: &tauri::AppHandle, update: &WorkerUpdate) {
+    use commands::results::ResultRow;
     use tauri::Emitter;
 
     match update {
@@ -327,12 +341,52 @@ fn emit_worker_update(app_handle: &tauri::AppHandle, update: &WorkerUpdate) {
                 }),
             );
         }
-        WorkerUpdate::SweepProgress { completed, total } => {
+        WorkerUpdate::SweepProgress {
+            completed,
+            total,
+            eta_seconds,
+        } => {
             let _ = app_handle.emit(
                 "worker:sweep-progress",
                 serde_json::json!({
                     "completed": completed,
-                    "total": total
+                    "total": total,
+                    "etaSeconds": eta_seconds
+                }),
+            );
+        }
+        WorkerUpdate::StatisticsComplete {
+            config_id,
+            statistics,
+            permutation,
+        } => {
+            let _ = app_handle.emit(
+                "worker:statistics-complete",
+                serde_json::json!({
+                    "config_id": config_id,
+                    "sharpe_ci": statistics.sharpe_ci,
+                    "permutation_p_value": permutation.p_value,
+                    "confidence_grade": statistics.confidence_grade.as_str(),
+                    "badge": statistics.confidence_grade.badge(),
+                    "statistics": statistics
+                }),
+            );
+        }
+        WorkerUpdate::StatisticsError { config_id, error } => {
+            let _ = app_handle.emit(
+                "worker:statistics-error",
+                serde_json::json!({
+                    "config_id": config_id,
+                    "error": error
+                }),
+            );
+        }
+        WorkerUpdate::SweepPartialResults { batch } => {
+            let rows: Vec<ResultRow> = batch.iter().map(ResultRow::from).collect();
+            let _ = app_handle.emit(
+                "worker:sweep-partial",
+                serde_json::json!({
+                    "batch": rows
                 }),
             );
         }
@@ -386,6 +440,27 @@ fn emit_worker_update(app_handle: &tauri::AppHandle, update: &WorkerUpdate) {
         WorkerUpdate::YoloStopped { .. } => {
             let _ = app_handle.emit("worker:yolo-stopped", ());
         }
+        WorkerUpdate::Timing { command, millis } => {
+            let _ = app_handle.emit(
+                "worker:timing",
+                serde_json::json!({
+                    "command": command,
+                    "millis": millis
+                }),
+            );
+        }
+        WorkerUpdate::TimingSummary { summary } => {
+            let _ = app_handle.emit(
+                "worker:timing-summary",
+                serde_json::json!({
+                    "commands": summary.commands,
+                    "totalMillis": summary.total_millis,
+                    "sweepConfigs": summary.sweep_configs,
+                    "sweepMillis": summary.sweep_millis,
+                    "configsPerSec": summary.configs_per_sec()
+                }),
+            );
+        }
         // Other updates don't need frontend notification
         _ => {}
     }
@@ -497,6 +572,8 @@ pub fn run() {
             commands::results::get_ticker_summaries,
             commands::results::get_strategy_summaries,
             commands::results::get_result_detail,
+            commands::results::get_result_trades,
+            commands::results::run_statistics,
             commands::results::select_result,
             commands::results::get_selected_result,
             commands::results::set_view_mode,
@@ -511,6 +588,7 @@ pub fn run() {
             commands::chart::toggle_overlay,
             commands::chart::get_overlays,
             commands::chart::get_candle_data,
+            commands::chart::get_indicator_overlay,
             commands::chart::get_equity_curve,
             commands::chart::get_drawdown_curve,
             commands::chart::get_multi_ticker_curves,
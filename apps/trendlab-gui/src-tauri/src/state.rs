@@ -161,6 +161,7 @@ impl AppState {
             cost_model: RwLock::new(trendlab_core::CostModel {
                 fees_bps_per_side: 5.0,
                 slippage_bps: 5.0,
+                ..Default::default()
             }),
             sweep_depth: RwLock::new(SweepDepth::Standard),
         }
@@ -481,6 +482,8 @@ impl AppState {
             "drawdown" => engine.chart.show_drawdown = enabled,
             "volume" => engine.chart.show_volume = enabled,
             "crosshair" => engine.chart.show_crosshair = enabled,
+            "ad_line" => engine.chart.show_ad_line = enabled,
+            "williams_ad" => engine.chart.show_williams_ad = enabled,
             _ => {}
         }
     }
@@ -94,6 +94,7 @@ export const createResultsSlice: SliceCreator<ResultsSlice> = (set, get) => ({
         limit: query?.limit,
         symbol_filter: query?.symbol_filter,
         strategy_filter: query?.strategy_filter,
+        metric_filter: query?.metric_filter,
       };
 
       const results = await invoke<ResultRow[]>('get_results', { query: effectiveQuery });
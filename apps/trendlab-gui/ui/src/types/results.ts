@@ -92,6 +92,8 @@ export interface ResultsQuery {
   limit?: number;
   symbol_filter?: string;
   strategy_filter?: string;
+  /** Metric filter expression, e.g. "sharpe > 1 and num_trades >= 30" */
+  metric_filter?: string;
 }
 
 /** Results state */
@@ -81,6 +81,9 @@ pub struct TrendLabWorld {
     parabolic_sar_values: Option<Vec<Option<trendlab_core::ParabolicSAR>>>,
     opening_range_values: Option<Vec<Option<trendlab_core::OpeningRange>>>,
 
+    // Phase 6 indicator state
+    obv_values: Option<Vec<f64>>,
+
     // Sweep state
     sweep_grid: Option<trendlab_core::SweepGrid>,
     sweep_result: Option<trendlab_core::SweepResult>,
@@ -265,9 +268,15 @@ async fn when_run_backtest_fixed(world: &mut TrendLabWorld, entry: String, exit:
         cost_model: trendlab_core::backtest::CostModel {
             fees_bps_per_side: world.fees_bps_per_side,
             slippage_bps: world.slippage_bps,
+            ..Default::default()
         },
         qty: 1.0,
         pyramid_config: trendlab_core::backtest::PyramidConfig::default(),
+        stop_loss_pct: None,
+        take_profit_pct: None,
+        cash_yield_annual: 0.0,
+        intrabar: trendlab_core::backtest::IntrabarModel::default(),
+        warmup_bars: None,
     };
 
     let res = trendlab_core::backtest::run_backtest(&world.bars, &mut strat, cfg)
@@ -1393,9 +1402,15 @@ async fn when_run_strategy(world: &mut TrendLabWorld) {
         cost_model: trendlab_core::backtest::CostModel {
             fees_bps_per_side: world.fees_bps_per_side,
             slippage_bps: world.slippage_bps,
+            ..Default::default()
         },
         qty: 1.0,
         pyramid_config: trendlab_core::backtest::PyramidConfig::default(),
+        stop_loss_pct: None,
+        take_profit_pct: None,
+        cash_yield_annual: 0.0,
+        intrabar: trendlab_core::backtest::IntrabarModel::default(),
+        warmup_bars: None,
     };
 
     // Try Donchian strategy first
@@ -2337,9 +2352,15 @@ async fn when_run_strategy_with_backtest(world: &mut TrendLabWorld) {
         cost_model: trendlab_core::backtest::CostModel {
             fees_bps_per_side: world.fees_bps_per_side,
             slippage_bps: world.slippage_bps,
+            ..Default::default()
         },
         qty: 1.0,
         pyramid_config: trendlab_core::backtest::PyramidConfig::default(),
+        stop_loss_pct: None,
+        take_profit_pct: None,
+        cash_yield_annual: 0.0,
+        intrabar: trendlab_core::backtest::IntrabarModel::default(),
+        warmup_bars: None,
     };
 
     // For strategies that use FixedEntryExitStrategy approach
@@ -3098,6 +3119,7 @@ fn extract_metric(
         trendlab_core::RankMetric::WinRate => metrics.win_rate,
         trendlab_core::RankMetric::ProfitFactor => metrics.profit_factor,
         trendlab_core::RankMetric::TotalReturn => metrics.total_return,
+        trendlab_core::RankMetric::Custom(weights) => weights.score(metrics),
     }
 }
 
@@ -3142,9 +3164,15 @@ async fn given_completed_backtest_with_costs(
         cost_model: trendlab_core::backtest::CostModel {
             fees_bps_per_side: fees,
             slippage_bps: slippage,
+            ..Default::default()
         },
         qty: 1.0,
         pyramid_config: trendlab_core::backtest::PyramidConfig::default(),
+        stop_loss_pct: None,
+        take_profit_pct: None,
+        cash_yield_annual: 0.0,
+        intrabar: trendlab_core::backtest::IntrabarModel::default(),
+        warmup_bars: None,
     };
 
     world.fees_bps_per_side = fees;
@@ -3189,6 +3217,7 @@ async fn when_export_strategy_artifact(world: &mut TrendLabWorld) {
     let cost_model = trendlab_core::CostModel {
         fees_bps_per_side: world.fees_bps_per_side,
         slippage_bps: world.slippage_bps,
+        ..Default::default()
     };
 
     let artifact = trendlab_core::create_donchian_artifact(
@@ -3530,7 +3559,7 @@ async fn then_output_is_valid_artifact(world: &mut TrendLabWorld) {
 // ============================================================================
 
 #[given(
-    regex = r"^an MA crossover strategy with fast period (\d+) and slow period (\d+) using (SMA|EMA)$"
+    regex = r"^an MA crossover strategy with fast period (\d+) and slow period (\d+) using (SMA|EMA|Hull|KAMA)$"
 )]
 async fn given_ma_crossover_strategy(
     world: &mut TrendLabWorld,
@@ -3543,6 +3572,8 @@ async fn given_ma_crossover_strategy(
     let ma_type = match ma_type.as_str() {
         "SMA" => trendlab_core::MAType::SMA,
         "EMA" => trendlab_core::MAType::EMA,
+        "Hull" => trendlab_core::MAType::Hull,
+        "KAMA" => trendlab_core::MAType::Kama,
         _ => panic!("Unknown MA type: {}", ma_type),
     };
     world.ma_crossover_strategy = Some(trendlab_core::MACrossoverStrategy::new(
@@ -3741,11 +3772,13 @@ async fn then_slow_period_must_be(world: &mut TrendLabWorld, expected: String) {
     );
 }
 
-#[then(regex = r"^the MA type must be (SMA|EMA)$")]
+#[then(regex = r"^the MA type must be (SMA|EMA|Hull|KAMA)$")]
 async fn then_ma_type_must_be(world: &mut TrendLabWorld, expected: String) {
     let expected_type = match expected.as_str() {
         "SMA" => trendlab_core::MAType::SMA,
         "EMA" => trendlab_core::MAType::EMA,
+        "Hull" => trendlab_core::MAType::Hull,
+        "KAMA" => trendlab_core::MAType::Kama,
         _ => panic!("Unknown MA type: {}", expected),
     };
     let strategy = world
@@ -4390,7 +4423,7 @@ async fn when_run_backtest_vol_sizing(world: &mut TrendLabWorld) {
         .donchian_strategy
         .clone()
         .expect("Donchian strategy not set");
-    let sizer = world.vol_sizer.as_ref().expect("Sizer not set");
+    let sizer = world.vol_sizer.as_mut().expect("Sizer not set");
 
     let config = trendlab_core::backtest::BacktestSizingConfig {
         initial_cash: world.account_size,
@@ -4642,6 +4675,11 @@ async fn when_run_pyramid_backtest(world: &mut TrendLabWorld) {
         cost_model: trendlab_core::backtest::CostModel::default(),
         qty: 1.0,
         pyramid_config: pyramid_cfg,
+        stop_loss_pct: None,
+        take_profit_pct: None,
+        cash_yield_annual: 0.0,
+        intrabar: trendlab_core::backtest::IntrabarModel::default(),
+        warmup_bars: None,
     };
 
     let result =
@@ -6371,13 +6409,14 @@ async fn then_af_max_default(world: &mut TrendLabWorld) {
 // Phase 4: Opening Range Breakout Strategy Steps
 // ============================================================================
 
-#[given(regex = r"^an ORB strategy with (\d+) bars, (Weekly|Monthly|Rolling) period$")]
+#[given(regex = r"^an ORB strategy with (\d+) bars, (Weekly|Monthly|Rolling|Daily) period$")]
 async fn given_orb_strategy(world: &mut TrendLabWorld, range_bars: String, period: String) {
     let range_bars = range_bars.parse::<usize>().unwrap();
     let period = match period.as_str() {
         "Weekly" => trendlab_core::OpeningPeriod::Weekly,
         "Monthly" => trendlab_core::OpeningPeriod::Monthly,
         "Rolling" => trendlab_core::OpeningPeriod::Rolling,
+        "Daily" => trendlab_core::OpeningPeriod::Daily,
         _ => panic!("Unknown period: {}", period),
     };
     world.orb_strategy = Some(trendlab_core::OpeningRangeBreakoutStrategy::new(
@@ -6745,6 +6784,7 @@ fn create_mock_viz_data() -> (
             symbol: "TEST".to_string(),
             start_date: "2023-01-01".to_string(),
             end_date: "2023-12-31".to_string(),
+            seed: 0,
         },
         data_version: "test-v1".to_string(),
         started_at: Utc::now(),
@@ -6771,6 +6811,7 @@ fn create_mock_viz_data() -> (
         trades: vec![],
         pyramid_trades: vec![],
         equity,
+        vs_benchmark: None,
     };
 
     let metrics = trendlab_core::Metrics {
@@ -6787,6 +6828,8 @@ fn create_mock_viz_data() -> (
         max_consecutive_losses: 0,
         max_consecutive_wins: 0,
         avg_losing_streak: 0.0,
+        ulcer_index: 0.0,
+        upi: 0.0,
     };
 
     let config_result = trendlab_core::SweepConfigResult {
@@ -7570,6 +7613,50 @@ async fn then_position_states_valid(world: &mut TrendLabWorld) {
     }
 }
 
+// =============================================================================
+// Phase 6: Volume Indicator Step Definitions
+// =============================================================================
+
+#[when("I compute OBV")]
+async fn when_compute_obv(world: &mut TrendLabWorld) {
+    world.obv_values = Some(trendlab_core::obv(&world.bars));
+}
+
+#[then(regex = r"^OBV must (rise|fall) by the bar volume on every (up|down)-close bar$")]
+async fn then_obv_moves_with_close(
+    world: &mut TrendLabWorld,
+    direction: String,
+    close_move: String,
+) {
+    let obv = world.obv_values.as_ref().expect("OBV not computed");
+    assert_eq!(obv.len(), world.bars.len());
+
+    let sign = if direction == "rise" { 1.0 } else { -1.0 };
+    let mut checked = 0;
+    for i in 1..world.bars.len() {
+        let prev_close = world.bars[i - 1].close;
+        let close = world.bars[i].close;
+        let is_match = if close_move == "up" {
+            close > prev_close
+        } else {
+            close < prev_close
+        };
+        if !is_match {
+            continue;
+        }
+
+        assert_f64_eq(
+            obv[i] - obv[i - 1],
+            sign * world.bars[i].volume,
+            1e-9,
+            &format!("OBV change at index {}", i),
+        );
+        checked += 1;
+    }
+
+    assert!(checked > 0, "Fixture has no {}-close bars", close_move);
+}
+
 fn main() {
     // Note: this test is `harness = false` (cucumber CLI), so libtest flags like `--nocapture`
     // are not supported. Pass cucumber flags instead: `cargo test -p trendlab-bdd --test bdd -- --help`
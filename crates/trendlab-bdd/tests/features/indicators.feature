@@ -29,3 +29,10 @@ Feature: Indicator calculations are correctly aligned
     And I modify bars after index 6
     And I compute Donchian channel with lookback 5 again
     Then Donchian values through index 6 must be identical
+
+  @obv @volume
+  Scenario: OBV rises on up-closes and falls on down-closes
+    Given a synthetic bar series from fixture synth/oscillator_30.csv
+    When I compute OBV
+    Then OBV must rise by the bar volume on every up-close bar
+    And OBV must fall by the bar volume on every down-close bar
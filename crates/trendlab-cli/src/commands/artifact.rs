@@ -118,6 +118,7 @@ pub fn execute_export(run_id: &str, config_id: &str) -> Result<PathBuf> {
             .backtest_config
             .cost_model
             .slippage_bps,
+        ..manifest.sweep_config.backtest_config.cost_model
     };
 
     // Create the artifact
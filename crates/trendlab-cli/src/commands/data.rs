@@ -7,20 +7,25 @@ use chrono::{Datelike, NaiveDate, TimeZone, Utc};
 use sha2::{Digest, Sha256};
 use std::path::{Path, PathBuf};
 use trendlab_core::data::{
-    parse_yahoo_csv, write_partitioned_parquet, CacheMetadata, DataQualityChecker,
-    DataQualityReport, FetchRequest,
+    check_yahoo_response, parse_yahoo_csv, write_partitioned_parquet, CacheMetadata,
+    DataQualityChecker, DataQualityReport, FetchRequest, ProviderError, RateLimiter, RetryPolicy,
+    DEFAULT_REQUESTS_PER_SEC,
 };
 
 /// Configuration for the data layer.
 pub struct DataConfig {
     /// Base directory for all data (typically "data")
     pub data_dir: PathBuf,
+
+    /// Maximum provider requests per second (0 disables pacing)
+    pub requests_per_sec: f64,
 }
 
 impl Default for DataConfig {
     fn default() -> Self {
         Self {
             data_dir: PathBuf::from("data"),
+            requests_per_sec: DEFAULT_REQUESTS_PER_SEC,
         }
     }
 }
@@ -50,6 +55,8 @@ pub struct RefreshResult {
     pub source: RefreshSource,
     pub quality_report: DataQualityReport,
     pub parquet_paths: Vec<PathBuf>,
+    /// Retries needed before Yahoo returned data (0 for cache hits)
+    pub retries: u32,
 }
 
 /// Whether data was fetched fresh or loaded from cache.
@@ -57,6 +64,10 @@ pub struct RefreshResult {
 pub enum RefreshSource {
     Cache,
     Fresh,
+    /// An overlapping cache entry was extended by fetching only the missing ranges.
+    Incremental {
+        ranges_fetched: usize,
+    },
 }
 
 /// Refresh Yahoo Finance data for multiple symbols.
@@ -65,12 +76,14 @@ pub async fn refresh_yahoo(
     start: NaiveDate,
     end: NaiveDate,
     force: bool,
+    retry: &RetryPolicy,
     config: &DataConfig,
 ) -> Result<Vec<RefreshResult>> {
     let mut results = Vec::new();
+    let mut limiter = RateLimiter::new(config.requests_per_sec);
 
     for symbol in tickers {
-        let result = refresh_symbol(symbol, start, end, force, config).await?;
+        let result = refresh_symbol(symbol, start, end, force, retry, &mut limiter, config).await?;
         results.push(result);
     }
 
@@ -83,6 +96,8 @@ async fn refresh_symbol(
     start: NaiveDate,
     end: NaiveDate,
     force: bool,
+    retry: &RetryPolicy,
+    limiter: &mut RateLimiter,
     config: &DataConfig,
 ) -> Result<RefreshResult> {
     let _request = FetchRequest::daily(symbol, start, end).with_force(force);
@@ -93,19 +108,45 @@ async fn refresh_symbol(
     let cache_path = raw_dir.join(format!("yahoo/{}/{}_{}.csv", symbol, start, end));
     let meta_path = raw_dir.join(format!("yahoo/{}/{}_{}.meta.json", symbol, start, end));
 
-    let (csv_text, source) = if !force && cache_path.exists() && meta_path.exists() {
-        // Load from cache
-        let csv = std::fs::read_to_string(&cache_path)
-            .with_context(|| format!("Failed to read cache file: {}", cache_path.display()))?;
-        (csv, RefreshSource::Cache)
+    let cached = if !force && cache_path.exists() && meta_path.exists() {
+        load_verified_cache(&cache_path, &meta_path)
+    } else {
+        None
+    };
+
+    let (csv_text, source, retries) = if let Some((_, csv)) = cached {
+        (csv, RefreshSource::Cache, 0)
+    } else if let Some((meta, mut csv)) =
+        find_overlapping_cache(&raw_dir, symbol, start, end).filter(|_| !force)
+    {
+        // Extend an overlapping cache entry with just the missing ranges
+        let missing = meta.missing_ranges(start, end);
+        let mut retries = 0;
+        for &(gap_start, gap_end) in &missing {
+            let (gap_csv, gap_retries) =
+                fetch_yahoo_csv(symbol, gap_start, gap_end, retry, limiter).await?;
+            csv = merge_yahoo_csv(&csv, &gap_csv);
+            retries += gap_retries;
+        }
+        let csv = trim_yahoo_csv(&csv, start, end);
+
+        write_cache(&csv, symbol, start, end, &raw_dir)?;
+
+        (
+            csv,
+            RefreshSource::Incremental {
+                ranges_fetched: missing.len(),
+            },
+            retries,
+        )
     } else {
         // Fetch fresh data
-        let csv = fetch_yahoo_csv(symbol, start, end).await?;
+        let (csv, retries) = fetch_yahoo_csv(symbol, start, end, retry, limiter).await?;
 
         // Write to cache
         write_cache(&csv, symbol, start, end, &raw_dir)?;
 
-        (csv, RefreshSource::Fresh)
+        (csv, RefreshSource::Fresh, retries)
     };
 
     // Parse CSV to bars
@@ -133,6 +174,7 @@ async fn refresh_symbol(
         source,
         quality_report,
         parquet_paths,
+        retries,
     })
 }
 
@@ -140,7 +182,16 @@ async fn refresh_symbol(
 ///
 /// Yahoo Finance's download endpoint requires authentication, but the chart API
 /// is more accessible. We fetch JSON data and convert it to CSV format.
-async fn fetch_yahoo_csv(symbol: &str, start: NaiveDate, end: NaiveDate) -> Result<String> {
+/// Every request is paced by `limiter`. Rate-limit and network failures are
+/// retried with exponential backoff (jittered for rate limits); the number of
+/// retries used is returned alongside the CSV.
+async fn fetch_yahoo_csv(
+    symbol: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+    retry: &RetryPolicy,
+    limiter: &mut RateLimiter,
+) -> Result<(String, u32)> {
     // Build chart API URL
     let start_ts = Utc
         .with_ymd_and_hms(start.year(), start.month(), start.day(), 0, 0, 0)
@@ -163,23 +214,26 @@ async fn fetch_yahoo_csv(symbol: &str, start: NaiveDate, end: NaiveDate) -> Resu
         .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
         .build()?;
 
-    let response = client
-        .get(&url)
-        .send()
-        .await
-        .with_context(|| format!("Failed to fetch data for {}", symbol))?;
-
-    let status = response.status();
-    if !status.is_success() {
-        if status.as_u16() == 404 {
-            anyhow::bail!("Symbol not found: {}", symbol);
+    let mut retries = 0;
+    let body = loop {
+        tokio::time::sleep(limiter.reserve_now()).await;
+        match fetch_chart_body(&client, &url, symbol).await {
+            Ok(body) => break body,
+            Err(e) if e.is_retryable() && retries + 1 < retry.max_attempts => {
+                retries += 1;
+                let delay = match e {
+                    ProviderError::RateLimited { .. } => retry.jittered_backoff(retries),
+                    _ => retry.backoff(retries),
+                };
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to fetch data for {}", symbol));
+            }
         }
-        anyhow::bail!("HTTP error {}: {}", status.as_u16(), status.as_str());
-    }
+    };
 
-    let json: serde_json::Value = response
-        .json()
-        .await
+    let json: serde_json::Value = serde_json::from_str(&body)
         .with_context(|| format!("Failed to parse JSON response for {}", symbol))?;
 
     // Extract data from the chart API response
@@ -277,7 +331,146 @@ async fn fetch_yahoo_csv(symbol: &str, start: NaiveDate, end: NaiveDate) -> Resu
         ));
     }
 
-    Ok(csv)
+    Ok((csv, retries))
+}
+
+/// Make a single chart API request and return the body if it looks like data.
+async fn fetch_chart_body(
+    client: &reqwest::Client,
+    url: &str,
+    symbol: &str,
+) -> Result<String, ProviderError> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| ProviderError::NetworkError {
+            message: e.to_string(),
+        })?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(ProviderError::from_http_status(status.as_u16(), symbol));
+    }
+
+    let body = response
+        .text()
+        .await
+        .map_err(|e| ProviderError::NetworkError {
+            message: e.to_string(),
+        })?;
+    check_yahoo_response(&body, true)?;
+
+    Ok(body)
+}
+
+/// Find the cached range for `symbol` that overlaps `start..=end` the most.
+///
+/// Only entries that overlap or touch the request are considered, so the
+/// missing ranges can be fetched and merged without leaving a hole. Entries
+/// that fail checksum verification are skipped. Returns the metadata and the
+/// cached CSV.
+fn find_overlapping_cache(
+    raw_dir: &Path,
+    symbol: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Option<(CacheMetadata, String)> {
+    let cache_dir = raw_dir.join(format!("yahoo/{}", symbol));
+    let entries = std::fs::read_dir(&cache_dir).ok()?;
+
+    entries
+        .filter_map(|entry| {
+            let meta_path = entry.ok()?.path();
+            let name = meta_path.file_name()?.to_str()?;
+            let csv_path = cache_dir.join(format!("{}.csv", name.strip_suffix(".meta.json")?));
+            let meta: CacheMetadata =
+                serde_json::from_str(&std::fs::read_to_string(&meta_path).ok()?).ok()?;
+            if meta.missing_ranges(start, end) == vec![(start, end)] {
+                return None;
+            }
+            let overlap_days = (meta.end.min(end) - meta.start.max(start)).num_days();
+            Some((overlap_days, csv_path, meta_path))
+        })
+        .max_by_key(|(overlap_days, _, _)| *overlap_days)
+        .and_then(|(_, csv_path, meta_path)| load_verified_cache(&csv_path, &meta_path))
+}
+
+/// Load a cached CSV, verifying it against the checksum in its metadata.
+///
+/// Returns `None` (with a warning on stderr) if either file is unreadable or
+/// the content no longer matches the recorded checksum, so callers refetch
+/// instead of parsing a truncated or corrupted file.
+fn load_verified_cache(cache_path: &Path, meta_path: &Path) -> Option<(CacheMetadata, String)> {
+    let load = || -> Result<(CacheMetadata, String)> {
+        let meta_json = std::fs::read_to_string(meta_path)
+            .with_context(|| format!("Failed to read {}", meta_path.display()))?;
+        let meta: CacheMetadata = serde_json::from_str(&meta_json)
+            .with_context(|| format!("Invalid cache metadata {}", meta_path.display()))?;
+        let csv = std::fs::read_to_string(cache_path)
+            .with_context(|| format!("Failed to read {}", cache_path.display()))?;
+
+        let actual = sha256_hex(&csv);
+        if actual != meta.checksum {
+            anyhow::bail!(
+                "checksum mismatch for {} (expected {}, found {})",
+                cache_path.display(),
+                meta.checksum,
+                actual
+            );
+        }
+        Ok((meta, csv))
+    };
+
+    match load() {
+        Ok(cached) => Some(cached),
+        Err(e) => {
+            eprintln!("Warning: ignoring cached data, refetching: {:#}", e);
+            None
+        }
+    }
+}
+
+/// Hex-encoded SHA-256 of `text`, as stored in [`CacheMetadata::checksum`].
+fn sha256_hex(text: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(text.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Merge two Yahoo CSVs by date; rows in `newer` replace rows for the same date.
+fn merge_yahoo_csv(base: &str, newer: &str) -> String {
+    let mut rows = std::collections::BTreeMap::new();
+    for line in base.lines().skip(1).chain(newer.lines().skip(1)) {
+        if let Some((date, _)) = line.split_once(',') {
+            rows.insert(date.to_string(), line);
+        }
+    }
+
+    let mut csv = String::from("Date,Open,High,Low,Close,Adj Close,Volume\n");
+    for line in rows.values() {
+        csv.push_str(line);
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Keep only the rows of a Yahoo CSV dated within `start..=end`.
+fn trim_yahoo_csv(csv_text: &str, start: NaiveDate, end: NaiveDate) -> String {
+    let mut lines = csv_text.lines();
+    let mut csv = lines.next().unwrap_or_default().to_string();
+    csv.push('\n');
+    for line in lines {
+        let in_range = line
+            .split_once(',')
+            .and_then(|(date, _)| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+            .is_some_and(|date| date >= start && date <= end);
+        if in_range {
+            csv.push_str(line);
+            csv.push('\n');
+        }
+    }
+    csv
 }
 
 /// Write raw CSV and metadata to cache.
@@ -298,9 +491,7 @@ fn write_cache(
     std::fs::write(&cache_path, csv_text)?;
 
     // Calculate checksum
-    let mut hasher = Sha256::new();
-    hasher.update(csv_text.as_bytes());
-    let checksum = format!("{:x}", hasher.finalize());
+    let checksum = sha256_hex(csv_text);
 
     // Count rows (excluding header)
     let row_count = csv_text.lines().skip(1).filter(|l| !l.is_empty()).count();
@@ -383,6 +574,58 @@ mod tests {
         assert_eq!(date.day(), 15);
     }
 
+    #[test]
+    fn test_merge_yahoo_csv_prefers_newer_rows() {
+        let header = "Date,Open,High,Low,Close,Adj Close,Volume\n";
+        let cached = format!(
+            "{}2023-12-28,1,1,1,1,1,10\n2023-12-29,2,2,2,2,2,20\n",
+            header
+        );
+        let tail = format!(
+            "{}2023-12-29,3,3,3,3,3,30\n2024-01-02,4,4,4,4,4,40\n",
+            header
+        );
+
+        let merged = merge_yahoo_csv(&cached, &tail);
+        assert_eq!(
+            merged,
+            format!(
+                "{}2023-12-28,1,1,1,1,1,10\n2023-12-29,3,3,3,3,3,30\n2024-01-02,4,4,4,4,4,40\n",
+                header
+            )
+        );
+
+        let trimmed = trim_yahoo_csv(
+            &merged,
+            NaiveDate::from_ymd_opt(2023, 12, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+        );
+        assert_eq!(trimmed, format!("{}2023-12-29,3,3,3,3,3,30\n", header));
+    }
+
+    #[test]
+    fn test_corrupt_cache_fails_checksum() {
+        let dir = tempfile::tempdir().unwrap();
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let csv = "Date,Open,High,Low,Close,Adj Close,Volume\n\
+                   2024-01-02,100,101,99,100.5,100.5,1000\n\
+                   2024-01-03,100.5,102,100,101.5,101.5,1200\n";
+        write_cache(csv, "TEST", start, end, dir.path()).unwrap();
+
+        let cache_path = dir.path().join("yahoo/TEST/2024-01-01_2024-01-31.csv");
+        let meta_path = dir
+            .path()
+            .join("yahoo/TEST/2024-01-01_2024-01-31.meta.json");
+        let (_, loaded) = load_verified_cache(&cache_path, &meta_path).unwrap();
+        assert_eq!(loaded, csv);
+
+        // Truncate the file mid-row, as an interrupted write would
+        std::fs::write(&cache_path, &csv[..csv.len() - 20]).unwrap();
+        assert!(load_verified_cache(&cache_path, &meta_path).is_none());
+        assert!(find_overlapping_cache(dir.path(), "TEST", start, end).is_none());
+    }
+
     #[test]
     fn test_parse_date_invalid() {
         let result = parse_date("not-a-date");
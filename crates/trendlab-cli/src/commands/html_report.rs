@@ -5,7 +5,11 @@ use maud::{html, Markup, PreEscaped, DOCTYPE};
 use std::fs;
 use std::path::PathBuf;
 
-use trendlab_core::{RunManifest, SweepConfigResult};
+use trendlab_core::{monthly_returns, EquityPoint, RunManifest, SweepConfigResult, Trade};
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
 
 /// CSS styles for the report (inline for self-contained HTML).
 const REPORT_STYLES: &str = r##"
@@ -171,6 +175,36 @@ footer {
     text-align: center;
     min-width: 50px;
 }
+
+.table-note {
+    color: var(--text-secondary);
+    font-size: 0.85rem;
+    margin-bottom: 0.75rem;
+}
+
+.table-scroll {
+    max-height: 500px;
+    overflow: auto;
+}
+
+table.sortable th {
+    cursor: pointer;
+    user-select: none;
+}
+
+table.sortable th[data-order="asc"]::after { content: " \25B2"; }
+table.sortable th[data-order="desc"]::after { content: " \25BC"; }
+
+.monthly-container {
+    overflow-x: auto;
+}
+
+.monthly-container table th,
+.monthly-container table td {
+    padding: 0.5rem;
+    text-align: center;
+    min-width: 60px;
+}
 "##;
 
 /// JavaScript for interactive charts (inline for self-contained HTML).
@@ -185,8 +219,40 @@ document.addEventListener('DOMContentLoaded', function() {
     if (heatmapData.length > 0) {
         renderHeatmap(heatmapData);
     }
+
+    document.querySelectorAll('table.sortable').forEach(makeSortable);
 });
 
+// Click a header to sort by that column; cells sort by data-sort when present.
+function makeSortable(table) {
+    const headers = Array.from(table.querySelectorAll('thead th'));
+    headers.forEach((th, col) => {
+        th.addEventListener('click', function() {
+            const asc = th.dataset.order !== 'asc';
+            headers.forEach(h => delete h.dataset.order);
+            th.dataset.order = asc ? 'asc' : 'desc';
+
+            const key = row => {
+                const cell = row.cells[col];
+                const raw = cell.dataset.sort !== undefined ? cell.dataset.sort : cell.textContent;
+                const num = Number(raw);
+                return raw !== '' && !isNaN(num) ? num : raw;
+            };
+            const tbody = table.tBodies[0];
+            const rows = Array.from(tbody.rows);
+            rows.sort((a, b) => {
+                const x = key(a);
+                const y = key(b);
+                const cmp = typeof x === 'number' && typeof y === 'number'
+                    ? x - y
+                    : String(x).localeCompare(String(y));
+                return asc ? cmp : -cmp;
+            });
+            rows.forEach(r => tbody.appendChild(r));
+        });
+    });
+}
+
 function renderEquityChart(data) {
     const container = document.getElementById('equity-chart');
     if (!container || data.length === 0) return;
@@ -276,6 +342,10 @@ pub fn generate_html_report(manifest: &RunManifest, results: &[SweepConfigResult
         .max_by(|a, b| a.metrics.cagr.partial_cmp(&b.metrics.cagr).unwrap())
         .map(|r| &r.metrics);
 
+    let best = results
+        .iter()
+        .max_by(|a, b| a.metrics.sharpe.partial_cmp(&b.metrics.sharpe).unwrap());
+
     let equity_js = generate_equity_js(results);
     let heatmap_js = generate_heatmap_js(results);
 
@@ -338,6 +408,18 @@ pub fn generate_html_report(manifest: &RunManifest, results: &[SweepConfigResult
                         }
                     }
 
+                    @if let Some(best) = best {
+                        section {
+                            h2 { "Monthly Returns (Best Configuration)" }
+                            (monthly_returns_table(&best.backtest_result.equity))
+                        }
+
+                        section {
+                            h2 { "Trade Blotter (Best Configuration)" }
+                            (trade_blotter(&best.backtest_result.trades))
+                        }
+                    }
+
                     section {
                         h2 { "Top 10 Configurations by Sharpe" }
                         (configurations_table(results, 10))
@@ -427,6 +509,125 @@ fn configurations_table(results: &[SweepConfigResult], limit: usize) -> Markup {
     }
 }
 
+/// Closed trades, sortable by any column.
+fn trade_blotter(trades: &[Trade]) -> Markup {
+    html! {
+        p class="table-note" {
+            (trades.len()) " closed trades | click a column header to sort"
+        }
+        @if !trades.is_empty() {
+            div class="table-scroll" {
+                table class="sortable" {
+                    thead {
+                        tr {
+                            th { "#" }
+                            th { "Entry" }
+                            th { "Exit" }
+                            th { "Side" }
+                            th { "Qty" }
+                            th { "Entry Px" }
+                            th { "Exit Px" }
+                            th { "Net PnL" }
+                            th { "Return" }
+                            th { "Exit Reason" }
+                        }
+                    }
+                    tbody {
+                        @for (i, t) in trades.iter().enumerate() {
+                            @let notional = (t.entry.price * t.entry.qty).abs();
+                            @let ret = if notional > 0.0 { t.net_pnl / notional } else { 0.0 };
+                            @let pnl_class = if t.net_pnl > 0.0 { "number positive" } else { "number negative" };
+                            tr {
+                                td class="number" { (i + 1) }
+                                td data-sort=(t.entry.ts.timestamp()) {
+                                    (t.entry.ts.format("%Y-%m-%d"))
+                                }
+                                td data-sort=(t.exit.ts.timestamp()) {
+                                    (t.exit.ts.format("%Y-%m-%d"))
+                                }
+                                td { (format!("{:?}", t.direction)) }
+                                td class="number" { (t.entry.qty) }
+                                td class="number" data-sort=(t.entry.price) {
+                                    (format!("{:.2}", t.entry.price))
+                                }
+                                td class="number" data-sort=(t.exit.price) {
+                                    (format!("{:.2}", t.exit.price))
+                                }
+                                td class=(pnl_class) data-sort=(t.net_pnl) {
+                                    (format!("{:.2}", t.net_pnl))
+                                }
+                                td class=(pnl_class) data-sort=(ret) {
+                                    (format!("{:.2}%", ret * 100.0))
+                                }
+                                td { (format!("{:?}", t.exit_reason)) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Year x month matrix of equity returns, shaded green (up) or red (down).
+fn monthly_returns_table(equity: &[EquityPoint]) -> Markup {
+    let months = monthly_returns(equity);
+    let mut years: Vec<i32> = months.iter().map(|m| m.year).collect();
+    years.dedup();
+
+    html! {
+        @if months.is_empty() {
+            p class="table-note" { "No equity data." }
+        } @else {
+            div class="monthly-container" {
+                table {
+                    thead {
+                        tr {
+                            th { "Year" }
+                            @for name in MONTH_NAMES {
+                                th { (name) }
+                            }
+                            th { "Total" }
+                        }
+                    }
+                    tbody {
+                        @for year in &years {
+                            @let row: Vec<_> = months.iter().filter(|m| m.year == *year).collect();
+                            @let year_ret = row.iter().fold(1.0, |acc, m| acc * (1.0 + m.ret)) - 1.0;
+                            tr {
+                                th { (year) }
+                                @for month in 1..=12u32 {
+                                    @if let Some(m) = row.iter().find(|m| m.month == month) {
+                                        (month_cell(m.ret))
+                                    } @else {
+                                        td { "-" }
+                                    }
+                                }
+                                (month_cell(year_ret))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Return cell tinted by sign, with intensity saturating at +/-10%.
+fn month_cell(ret: f64) -> Markup {
+    let alpha = 0.15 + 0.6 * (ret.abs() / 0.10).min(1.0);
+    let (class, rgb) = if ret >= 0.0 {
+        ("number positive", "158, 206, 106")
+    } else {
+        ("number negative", "247, 118, 142")
+    };
+    html! {
+        td class=(class) style=(format!("background: rgba({}, {:.2})", rgb, alpha)) {
+            (format!("{:+.1}%", ret * 100.0))
+        }
+    }
+}
+
 fn generate_equity_js(results: &[SweepConfigResult]) -> String {
     let best = results
         .iter()
@@ -531,6 +732,7 @@ mod tests {
                     exit_lookbacks: vec![5, 10],
                 },
                 backtest_config: BacktestConfig::default(),
+                seed: 0,
             },
             data_version: "abc123".to_string(),
             started_at: chrono::Utc::now(),
@@ -570,6 +772,7 @@ mod tests {
                     trades: vec![],
                     pyramid_trades: vec![],
                     equity: mock_equity(),
+                    vs_benchmark: None,
                 },
                 metrics: Metrics {
                     sharpe: 1.5,
@@ -585,6 +788,8 @@ mod tests {
                     max_consecutive_losses: 0,
                     max_consecutive_wins: 0,
                     avg_losing_streak: 0.0,
+                    ulcer_index: 0.0,
+                    upi: 0.0,
                 },
             },
             SweepConfigResult {
@@ -597,6 +802,7 @@ mod tests {
                     trades: vec![],
                     pyramid_trades: vec![],
                     equity: mock_equity(),
+                    vs_benchmark: None,
                 },
                 metrics: Metrics {
                     sharpe: 0.8,
@@ -612,6 +818,8 @@ mod tests {
                     max_consecutive_losses: 0,
                     max_consecutive_wins: 0,
                     avg_losing_streak: 0.0,
+                    ulcer_index: 0.0,
+                    upi: 0.0,
                 },
             },
         ]
@@ -630,6 +838,63 @@ mod tests {
         assert!(html_str.contains("Summary"));
     }
 
+    #[test]
+    fn test_html_report_trade_blotter_and_monthly_returns() {
+        use chrono::TimeZone;
+        use trendlab_core::{Fill, Side};
+
+        let ts = |m, d| chrono::Utc.with_ymd_and_hms(2024, m, d, 0, 0, 0).unwrap();
+        let fill = |ts, side, price| Fill {
+            ts,
+            side,
+            qty: 10.0,
+            price,
+            fees: 0.0,
+            raw_price: price,
+            atr_at_fill: None,
+        };
+        let trade = |entry, exit, entry_px: f64, exit_px: f64| Trade {
+            entry: fill(entry, Side::Buy, entry_px),
+            exit: fill(exit, Side::Sell, exit_px),
+            gross_pnl: (exit_px - entry_px) * 10.0,
+            net_pnl: (exit_px - entry_px) * 10.0,
+            direction: Default::default(),
+            exit_reason: Default::default(),
+        };
+        let point = |ts, equity| EquityPoint {
+            ts,
+            cash: equity,
+            position_qty: 0.0,
+            close: 100.0,
+            equity,
+        };
+
+        let mut results = mock_results();
+        let best = &mut results[0].backtest_result;
+        best.trades = vec![
+            trade(ts(1, 3), ts(1, 20), 100.0, 110.0),
+            trade(ts(2, 5), ts(2, 16), 110.0, 99.0),
+        ];
+        // January +2.0%, February -3.5%
+        best.equity = vec![
+            point(ts(1, 2), 10000.0),
+            point(ts(1, 31), 10200.0),
+            point(ts(2, 29), 9843.0),
+        ];
+
+        let html = generate_html_report(&mock_manifest(), &results).into_string();
+
+        assert!(html.contains("Trade Blotter"));
+        assert!(html.contains("2 closed trades"));
+        assert!(html.contains("class=\"sortable\""));
+        assert!(html.contains("2024-01-20"));
+        assert!(html.contains("Monthly Returns"));
+        assert!(html.contains(">+2.0%</td>"));
+        assert!(html.contains(">-3.5%</td>"));
+        // Jan and Feb compound into the year total
+        assert!(html.contains(">-1.6%</td>"));
+    }
+
     #[test]
     fn test_generate_heatmap_js() {
         let results = mock_results();
@@ -8,3 +8,4 @@ pub mod run;
 pub mod scan;
 pub mod sweep;
 pub mod terminal;
+pub mod walk_forward;
@@ -2,12 +2,15 @@
 
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
-use trendlab_core::{RunManifest, SweepConfigResult};
+use trendlab_core::{parse_filter, ConfigId, RunManifest, SweepConfigResult};
 
-use super::terminal::{color_value, format_sweep_table_colored, print_section, sparkline};
+use super::terminal::{
+    color_value, format_sweep_table_colored, print_section, render_equity_sparkline,
+};
 
 /// Get the reports base directory.
 fn reports_dir() -> PathBuf {
@@ -47,9 +50,36 @@ fn load_sweep_results(run_id: &str) -> Result<(RunManifest, Vec<SweepConfigResul
     Ok((manifest, results))
 }
 
+/// Keep only results whose metrics satisfy a filter expression.
+///
+/// See `trendlab_core::result_filter` for the expression syntax.
+pub fn apply_filter(
+    results: Vec<SweepConfigResult>,
+    filter: Option<&str>,
+) -> Result<Vec<SweepConfigResult>> {
+    let Some(filter) = filter else {
+        return Ok(results);
+    };
+
+    let expr =
+        parse_filter(filter).with_context(|| format!("Invalid --filter expression: {}", filter))?;
+    Ok(results
+        .into_iter()
+        .filter(|r| expr.matches(&r.metrics))
+        .collect())
+}
+
 /// Display a summary of a sweep run with colored terminal output.
-pub fn execute_summary(run_id: &str, top_n: usize) -> Result<()> {
+pub fn execute_summary(run_id: &str, top_n: usize, filter: Option<&str>) -> Result<()> {
     let (manifest, results) = load_sweep_results(run_id)?;
+    let total_configs = results.len();
+    let results = apply_filter(results, filter)?;
+    if results.is_empty() {
+        bail!(
+            "No configurations match the filter ({} tested)",
+            total_configs
+        );
+    }
 
     // Header
     println!("\n{}", "═".repeat(90).cyan());
@@ -90,8 +120,17 @@ pub fn execute_summary(run_id: &str, top_n: usize) -> Result<()> {
             .len()
             .to_string()
             .white(),
-        results.len().to_string().white().bold()
+        total_configs.to_string().white().bold()
     );
+    if let Some(filter) = filter {
+        println!(
+            "  {} {} ({} of {} configs match)",
+            "Filter:".dimmed(),
+            filter.white(),
+            results.len().to_string().white().bold(),
+            total_configs
+        );
+    }
     println!(
         "  {} {}",
         "Executed:".dimmed(),
@@ -154,7 +193,11 @@ pub fn execute_summary(run_id: &str, top_n: usize) -> Result<()> {
             .iter()
             .map(|e| e.equity)
             .collect();
-        println!("    {}  {}", "Equity:".dimmed(), sparkline(&equity).green());
+        println!(
+            "    {}  {}",
+            "Equity:".dimmed(),
+            render_equity_sparkline(&equity, 60).green()
+        );
     }
 
     // Grid statistics
@@ -213,8 +256,9 @@ pub fn execute_summary(run_id: &str, top_n: usize) -> Result<()> {
 }
 
 /// Export sweep results to CSV.
-pub fn execute_export(run_id: &str, output: &str) -> Result<()> {
+pub fn execute_export(run_id: &str, output: &str, filter: Option<&str>) -> Result<()> {
     let (_manifest, results) = load_sweep_results(run_id)?;
+    let results = apply_filter(results, filter)?;
 
     let mut csv = String::new();
     csv.push_str("entry_lookback,exit_lookback,sharpe,cagr,sortino,calmar,max_drawdown,total_return,win_rate,profit_factor,num_trades,turnover\n");
@@ -244,6 +288,149 @@ pub fn execute_export(run_id: &str, output: &str) -> Result<()> {
     Ok(())
 }
 
+/// Metric deltas for a config present in both compared runs (B minus A).
+#[derive(Debug, Clone)]
+pub struct ConfigDelta {
+    pub config_id: ConfigId,
+    pub cagr_delta: f64,
+    pub sharpe_delta: f64,
+    pub max_drawdown_delta: f64,
+    /// Profitable (total return > 0) in run A but not in run B
+    pub flipped_to_loss: bool,
+}
+
+/// Side-by-side comparison of two sweep runs, matched by `ConfigId`.
+#[derive(Debug, Clone, Default)]
+pub struct RunComparison {
+    /// Matched configs, sorted by absolute Sharpe delta (largest first)
+    pub deltas: Vec<ConfigDelta>,
+    pub only_in_a: Vec<ConfigId>,
+    pub only_in_b: Vec<ConfigId>,
+}
+
+/// Match two sets of sweep results by `ConfigId` and compute metric deltas.
+pub fn compare_results(a: &[SweepConfigResult], b: &[SweepConfigResult]) -> RunComparison {
+    let key = |r: &SweepConfigResult| (r.config_id.entry_lookback, r.config_id.exit_lookback);
+    let map_a: BTreeMap<_, _> = a.iter().map(|r| (key(r), r)).collect();
+    let map_b: BTreeMap<_, _> = b.iter().map(|r| (key(r), r)).collect();
+
+    let mut comparison = RunComparison::default();
+
+    for (k, ra) in &map_a {
+        match map_b.get(k) {
+            Some(rb) => comparison.deltas.push(ConfigDelta {
+                config_id: ra.config_id.clone(),
+                cagr_delta: rb.metrics.cagr - ra.metrics.cagr,
+                sharpe_delta: rb.metrics.sharpe - ra.metrics.sharpe,
+                max_drawdown_delta: rb.metrics.max_drawdown - ra.metrics.max_drawdown,
+                flipped_to_loss: ra.metrics.total_return > 0.0 && rb.metrics.total_return <= 0.0,
+            }),
+            None => comparison.only_in_a.push(ra.config_id.clone()),
+        }
+    }
+    comparison.only_in_b = map_b
+        .iter()
+        .filter(|(k, _)| !map_a.contains_key(k))
+        .map(|(_, r)| r.config_id.clone())
+        .collect();
+
+    comparison.deltas.sort_by(|x, y| {
+        y.sharpe_delta
+            .abs()
+            .partial_cmp(&x.sharpe_delta.abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    comparison
+}
+
+/// Compare two sweep runs and print per-config metric deltas.
+pub fn execute_compare(run_a: &str, run_b: &str, top_n: usize) -> Result<()> {
+    let (_, results_a) = load_sweep_results(run_a)?;
+    let (_, results_b) = load_sweep_results(run_b)?;
+    let comparison = compare_results(&results_a, &results_b);
+
+    println!("\n{}", "═".repeat(90).cyan());
+    println!(
+        "  {} {} → {}",
+        "RUN COMPARISON".cyan().bold(),
+        run_a.dimmed(),
+        run_b.white()
+    );
+    println!("{}", "═".repeat(90).cyan());
+
+    print_section("Largest Changes (B − A)");
+    println!(
+        "  {:<8} {:<8} {:>12} {:>12} {:>12}",
+        "Entry", "Exit", "ΔCAGR", "ΔSharpe", "ΔMaxDD"
+    );
+    for d in comparison.deltas.iter().take(top_n) {
+        println!(
+            "  {:<8} {:<8} {:>12} {:>12} {:>12}",
+            d.config_id.entry_lookback,
+            d.config_id.exit_lookback,
+            color_value(
+                d.cagr_delta,
+                format!("{:+.2}%", d.cagr_delta * 100.0),
+                false
+            ),
+            color_value(d.sharpe_delta, format!("{:+.3}", d.sharpe_delta), false),
+            color_value(
+                d.max_drawdown_delta,
+                format!("{:+.2}%", d.max_drawdown_delta * 100.0),
+                true
+            )
+        );
+    }
+
+    let flipped: Vec<&ConfigDelta> = comparison
+        .deltas
+        .iter()
+        .filter(|d| d.flipped_to_loss)
+        .collect();
+    if !flipped.is_empty() {
+        print_section("Flipped from Profitable to Unprofitable");
+        for d in flipped {
+            println!(
+                "  {} Entry: {}, Exit: {}",
+                "✗".red(),
+                d.config_id.entry_lookback,
+                d.config_id.exit_lookback
+            );
+        }
+    }
+
+    for (label, ids) in [
+        (format!("Only in {}", run_a), &comparison.only_in_a),
+        (format!("Only in {}", run_b), &comparison.only_in_b),
+    ] {
+        if ids.is_empty() {
+            continue;
+        }
+        print_section(&label);
+        for id in ids {
+            println!("  Entry: {}, Exit: {}", id.entry_lookback, id.exit_lookback);
+        }
+    }
+
+    println!(
+        "\n  {} matched, {} flipped to loss, {} only in A, {} only in B",
+        comparison.deltas.len().to_string().white().bold(),
+        comparison
+            .deltas
+            .iter()
+            .filter(|d| d.flipped_to_loss)
+            .count()
+            .to_string()
+            .red(),
+        comparison.only_in_a.len(),
+        comparison.only_in_b.len()
+    );
+    println!("\n{}", "═".repeat(90).cyan());
+
+    Ok(())
+}
+
 /// List available sweep runs.
 pub fn list_runs() -> Result<Vec<String>> {
     let dir = reports_dir();
@@ -277,4 +464,69 @@ mod tests {
         let dir = reports_dir();
         assert!(dir.to_string_lossy().contains("reports"));
     }
+
+    fn make_result(entry: usize, sharpe: f64, num_trades: u32) -> SweepConfigResult {
+        SweepConfigResult {
+            config_id: trendlab_core::ConfigId::new(entry, 5),
+            backtest_result: Default::default(),
+            metrics: trendlab_core::Metrics {
+                sharpe,
+                num_trades,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_apply_filter() {
+        let results = vec![
+            make_result(10, 1.5, 40),
+            make_result(20, 0.5, 40),
+            make_result(30, 1.2, 10),
+        ];
+
+        let all = apply_filter(results.clone(), None).unwrap();
+        assert_eq!(all.len(), 3);
+
+        let kept = apply_filter(results.clone(), Some("sharpe > 1 and num_trades >= 30")).unwrap();
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].config_id.entry_lookback, 10);
+
+        assert!(apply_filter(results, Some("sharpe >>> 1")).is_err());
+    }
+
+    #[test]
+    fn test_compare_run_with_itself() {
+        let results = vec![
+            make_result(10, 1.5, 40),
+            make_result(20, -0.5, 40),
+            make_result(30, 1.2, 10),
+        ];
+
+        let comparison = compare_results(&results, &results);
+        assert_eq!(comparison.deltas.len(), 3);
+        assert!(comparison.only_in_a.is_empty());
+        assert!(comparison.only_in_b.is_empty());
+        for d in &comparison.deltas {
+            assert_eq!(d.cagr_delta, 0.0);
+            assert_eq!(d.sharpe_delta, 0.0);
+            assert_eq!(d.max_drawdown_delta, 0.0);
+            assert!(!d.flipped_to_loss);
+        }
+    }
+
+    #[test]
+    fn test_compare_flips_and_unmatched() {
+        let mut a = vec![make_result(10, 1.5, 40), make_result(20, 0.8, 40)];
+        a[0].metrics.total_return = 0.3;
+        let mut b = vec![make_result(10, -0.2, 40), make_result(30, 1.0, 40)];
+        b[0].metrics.total_return = -0.1;
+
+        let comparison = compare_results(&a, &b);
+        assert_eq!(comparison.deltas.len(), 1);
+        assert!(comparison.deltas[0].flipped_to_loss);
+        assert!((comparison.deltas[0].sharpe_delta + 1.7).abs() < 1e-12);
+        assert_eq!(comparison.only_in_a, vec![ConfigId::new(20, 5)]);
+        assert_eq!(comparison.only_in_b, vec![ConfigId::new(30, 5)]);
+    }
 }
@@ -3,10 +3,11 @@
 use anyhow::{bail, Result};
 use chrono::{Datelike, NaiveDate};
 use colored::Colorize;
+use serde::{Deserialize, Serialize};
 
 use trendlab_core::{
-    backtest::{run_backtest, BacktestConfig, CostModel, FillModel},
-    compute_metrics, read_parquet, DonchianBreakoutStrategy, Metrics,
+    backtest::{run_backtest, BacktestConfig},
+    compute_metrics, read_parquet, AppConfig, DonchianBreakoutStrategy, Metrics,
 };
 
 use super::data::DataConfig;
@@ -42,6 +43,40 @@ pub struct RunResult {
     pub end_date: NaiveDate,
 }
 
+/// Machine-readable output of `trendlab run --json`.
+///
+/// This is the scripting contract for the CLI: fields may be added, but
+/// existing names and meanings must not change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct RunJson {
+    /// Ticker symbol that was backtested
+    pub symbol: String,
+    /// Strategy with its parameters, e.g. "donchian:20,10"
+    pub strategy_id: String,
+    /// First bar actually used (YYYY-MM-DD)
+    pub start_date: NaiveDate,
+    /// Last bar actually used (YYYY-MM-DD)
+    pub end_date: NaiveDate,
+    /// Number of bars in the backtest
+    pub num_bars: usize,
+    /// Full performance metrics
+    pub metrics: Metrics,
+}
+
+impl From<&RunResult> for RunJson {
+    fn from(result: &RunResult) -> Self {
+        Self {
+            symbol: result.symbol.clone(),
+            strategy_id: result.strategy_id.clone(),
+            start_date: result.start_date,
+            end_date: result.end_date,
+            num_bars: result.num_bars,
+            metrics: result.metrics.clone(),
+        }
+    }
+}
+
 /// Parse strategy string into configuration.
 ///
 /// Format: "donchian" or "donchian:20,10" (entry,exit lookbacks)
@@ -138,16 +173,7 @@ pub fn execute_run(
         DonchianBreakoutStrategy::new(strat_config.entry_lookback, strat_config.exit_lookback);
 
     // Configure backtest
-    let bt_config = BacktestConfig {
-        initial_cash: 100_000.0,
-        fill_model: FillModel::NextOpen,
-        cost_model: CostModel {
-            fees_bps_per_side: 10.0,
-            slippage_bps: 5.0,
-        },
-        qty: 100.0,
-        pyramid_config: trendlab_core::PyramidConfig::default(),
-    };
+    let bt_config = AppConfig::load().backtest_config();
 
     // Run backtest
     let result = run_backtest(&all_bars, &mut strategy, bt_config)?;
@@ -309,6 +335,11 @@ pub fn format_metrics(result: &RunResult) -> String {
     output
 }
 
+/// Format run results as pretty-printed JSON.
+pub fn format_metrics_json(result: &RunResult) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&RunJson::from(result))?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,15 +1,17 @@
 //! Scan command for daily signal alerts.
 //!
-//! Scans a user-curated watchlist for today's signals across configured strategies.
+//! Scans a user-curated watchlist for today's signals across configured strategies,
+//! or ranks a whole universe's fresh long entries for a single strategy.
 
 use crate::commands::data::{refresh_yahoo, DataConfig};
 use anyhow::{anyhow, Context, Result};
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use polars::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use trendlab_core::{
-    create_strategy_v2, dataframe_to_bars, scan_symbol_parquet_lazy, Position, Signal, StrategySpec,
+    create_strategy_v2, dataframe_to_bars, scan_symbol_parquet_lazy, Bar, Position, RetryPolicy,
+    Signal, StrategySpec, Universe,
 };
 
 // =============================================================================
@@ -209,6 +211,8 @@ pub fn parse_strategy(s: &str) -> Result<(StrategySpec, String, String)> {
                 .get(2)
                 .map(|s| match s.to_lowercase().as_str() {
                     "ema" => MAType::EMA,
+                    "hull" | "hma" => MAType::Hull,
+                    "kama" => MAType::Kama,
                     _ => MAType::SMA,
                 })
                 .unwrap_or(MAType::SMA);
@@ -282,22 +286,7 @@ fn compute_signal(
     // Parse strategy
     let (spec, strategy_type, params_str) = parse_strategy(strategy_str)?;
 
-    // Load bars from Parquet
-    let parquet_dir = data_config.parquet_dir();
-    let lf = scan_symbol_parquet_lazy(&parquet_dir, symbol, "1d", None, None)
-        .map_err(|e| anyhow!("Failed to load data for {}: {}", symbol, e))?;
-
-    let df = lf
-        .sort(["ts"], SortMultipleOptions::default())
-        .collect()
-        .map_err(|e| anyhow!("Failed to collect DataFrame for {}: {}", symbol, e))?;
-
-    let bars = dataframe_to_bars(&df)
-        .map_err(|e| anyhow!("Failed to convert to bars for {}: {}", symbol, e))?;
-
-    if bars.is_empty() {
-        return Err(anyhow!("No bars found for {}", symbol));
-    }
+    let bars = load_daily_bars(symbol, data_config)?;
 
     // Create strategy and check warmup
     let strategy = create_strategy_v2(&spec);
@@ -361,6 +350,26 @@ fn compute_signal(
     })
 }
 
+/// Load a symbol's cached daily bars from Parquet, sorted by timestamp.
+fn load_daily_bars(symbol: &str, data_config: &DataConfig) -> Result<Vec<Bar>> {
+    let parquet_dir = data_config.parquet_dir();
+    let lf = scan_symbol_parquet_lazy(&parquet_dir, symbol, "1d", None, None)
+        .map_err(|e| anyhow!("Failed to load data for {}: {}", symbol, e))?;
+
+    let df = lf
+        .sort(["ts"], SortMultipleOptions::default())
+        .collect()
+        .map_err(|e| anyhow!("Failed to collect DataFrame for {}: {}", symbol, e))?;
+
+    let bars = dataframe_to_bars(&df)
+        .map_err(|e| anyhow!("Failed to convert to bars for {}: {}", symbol, e))?;
+
+    if bars.is_empty() {
+        return Err(anyhow!("No bars found for {}", symbol));
+    }
+    Ok(bars)
+}
+
 /// Simulate position state through bar history, tracking position and transition points.
 /// Returns (current_position, last_entry_bar_index, last_exit_bar_index).
 fn simulate_position_history(
@@ -426,7 +435,15 @@ pub async fn execute_scan(
     // Refresh data for all tickers
     let tickers = watchlist.all_tickers();
     println!("Refreshing data for {} tickers...", tickers.len());
-    refresh_yahoo(&tickers, start, today, false, data_config).await?;
+    refresh_yahoo(
+        &tickers,
+        start,
+        today,
+        false,
+        &RetryPolicy::default(),
+        data_config,
+    )
+    .await?;
     println!();
 
     // Run signal computation - collect ALL signals first
@@ -553,6 +570,229 @@ pub async fn execute_scan(
     })
 }
 
+// =============================================================================
+// Universe Scan
+// =============================================================================
+
+/// Bars used for the momentum score (~3 months of trading days).
+pub const MOMENTUM_LOOKBACK_BARS: usize = 63;
+
+/// A symbol with a fresh long entry, ranked by momentum.
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedSetup {
+    /// 1-based rank (highest momentum first)
+    pub rank: usize,
+    pub symbol: String,
+    pub sector: Option<String>,
+    /// Return over the last `MOMENTUM_LOOKBACK_BARS` bars
+    pub momentum: f64,
+    pub close_price: f64,
+    /// Date of the bar the entry fired on
+    pub entry_date: String,
+}
+
+/// Complete universe scan output.
+#[derive(Debug, Clone, Serialize)]
+pub struct UniverseScanOutput {
+    pub as_of: String,
+    pub universe_name: String,
+    pub strategy: String,
+    pub params: String,
+    pub total_symbols: usize,
+    pub setups: Vec<RankedSetup>,
+    pub errors: Vec<ScanError>,
+}
+
+/// Load a universe by file extension (`.csv`, `.json`, otherwise TOML).
+///
+/// A missing file falls back to the built-in universe, as the TUI does.
+pub fn load_universe(path: &Path) -> Result<Universe> {
+    if !path.exists() {
+        return Ok(Universe::default_universe());
+    }
+    let universe = match path.extension().and_then(|e| e.to_str()) {
+        Some("csv") => Universe::from_csv(path),
+        Some("json") => Universe::from_json(path),
+        _ => Universe::load(path),
+    };
+    universe.with_context(|| format!("Failed to load universe: {}", path.display()))
+}
+
+/// Momentum score: return over the last `MOMENTUM_LOOKBACK_BARS` bars
+/// (or the whole history if shorter).
+fn momentum_score(bars: &[Bar]) -> f64 {
+    let last = bars.len() - 1;
+    let base = bars[last.saturating_sub(MOMENTUM_LOOKBACK_BARS)].close;
+    if base > 0.0 {
+        bars[last].close / base - 1.0
+    } else {
+        0.0
+    }
+}
+
+/// Evaluate one symbol: `Some(setup)` if the strategy entered long within
+/// the last `freshness_bars` bars up to `as_of`.
+fn evaluate_universe_symbol(
+    symbol: &str,
+    spec: &StrategySpec,
+    as_of: Option<NaiveDate>,
+    freshness_bars: usize,
+    data_config: &DataConfig,
+) -> Result<Option<RankedSetup>> {
+    let mut bars = load_daily_bars(symbol, data_config)?;
+    if let Some(date) = as_of {
+        bars.retain(|b| b.ts.date_naive() <= date);
+    }
+
+    let strategy = create_strategy_v2(spec);
+    let warmup = strategy.warmup_period();
+    if bars.len() <= warmup + freshness_bars {
+        return Err(anyhow!(
+            "Insufficient data for {}: {} bars, need > {}",
+            symbol,
+            bars.len(),
+            warmup + freshness_bars
+        ));
+    }
+
+    let (position, last_entry_bar, _) = simulate_position_history(&bars, strategy.as_ref());
+    let fresh_entry = match (position, last_entry_bar) {
+        (Position::Long, Some(idx)) if bars.len() - 1 - idx < freshness_bars => idx,
+        _ => return Ok(None),
+    };
+
+    Ok(Some(RankedSetup {
+        rank: 0,
+        symbol: symbol.to_string(),
+        sector: None,
+        momentum: momentum_score(&bars),
+        close_price: bars[bars.len() - 1].close,
+        entry_date: bars[fresh_entry].ts.date_naive().to_string(),
+    }))
+}
+
+/// Rank a universe's fresh long entries for one strategy.
+///
+/// Uses cached Parquet data only (run `data refresh-yahoo` first), so the
+/// scan is reproducible for a past `as_of` date.
+///
+/// # Arguments
+/// * `strategy_str` - Strategy in [`parse_strategy`] format (e.g. "donchian:55,20")
+/// * `as_of` - Ignore bars after this date (None = latest cached bar)
+/// * `top_n` - Keep the N highest-momentum setups (0 = all)
+/// * `freshness_bars` - Entry must have fired within this many bars (0 = any open position)
+pub fn execute_universe_scan(
+    universe: &Universe,
+    strategy_str: &str,
+    as_of: Option<NaiveDate>,
+    top_n: usize,
+    freshness_bars: usize,
+    data_config: &DataConfig,
+) -> Result<UniverseScanOutput> {
+    let (spec, strategy_type, params_str) = parse_strategy(strategy_str)?;
+    let sectors = universe.build_sector_lookup();
+    let symbols = universe.all_tickers_sorted();
+
+    // Same convention as the watchlist scan: 0 disables the freshness check
+    let effective_freshness = if freshness_bars == 0 {
+        9999
+    } else {
+        freshness_bars
+    };
+
+    let mut setups = Vec::new();
+    let mut errors = Vec::new();
+    for symbol in &symbols {
+        match evaluate_universe_symbol(symbol, &spec, as_of, effective_freshness, data_config) {
+            Ok(Some(mut setup)) => {
+                setup.sector = sectors.get(symbol).cloned();
+                setups.push(setup);
+            }
+            Ok(None) => {}
+            Err(e) => errors.push(ScanError {
+                symbol: symbol.clone(),
+                strategy: strategy_str.to_string(),
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    setups.sort_by(|a, b| {
+        b.momentum
+            .partial_cmp(&a.momentum)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.symbol.cmp(&b.symbol))
+    });
+    if top_n > 0 {
+        setups.truncate(top_n);
+    }
+    for (i, setup) in setups.iter_mut().enumerate() {
+        setup.rank = i + 1;
+    }
+
+    Ok(UniverseScanOutput {
+        as_of: as_of
+            .map(|d| d.to_string())
+            .unwrap_or_else(|| "latest".to_string()),
+        universe_name: universe.name.clone(),
+        strategy: strategy_type,
+        params: params_str,
+        total_symbols: symbols.len(),
+        setups,
+        errors,
+    })
+}
+
+/// Format universe scan output for terminal display.
+pub fn format_universe_scan_output(output: &UniverseScanOutput) -> String {
+    use colored::Colorize;
+
+    let mut result = String::new();
+
+    result.push_str(&format!(
+        "\n{} - {} ({}) as of {}\n",
+        "Universe Scan".bold(),
+        output.strategy,
+        output.params,
+        output.as_of.bright_blue()
+    ));
+    result.push_str(&format!(
+        "Universe: {} ({} symbols)\n",
+        output.universe_name, output.total_symbols
+    ));
+    result.push_str(&format!("{:-<60}\n", ""));
+
+    if output.setups.is_empty() {
+        result.push_str(&format!("\n{}\n", "No fresh long entries.".yellow()));
+    } else {
+        result.push_str(&format!(
+            "{:>4}  {:<8} {:>10} {:>10}  {:<12} {}\n",
+            "#", "Symbol", "Momentum", "Close", "Entry", "Sector"
+        ));
+        for setup in &output.setups {
+            result.push_str(&format!(
+                "{:>4}  {:<8} {:>9.1}% {:>10.2}  {:<12} {}\n",
+                setup.rank,
+                setup.symbol.bright_white(),
+                setup.momentum * 100.0,
+                setup.close_price,
+                setup.entry_date,
+                setup.sector.as_deref().unwrap_or("-")
+            ));
+        }
+    }
+
+    if !output.errors.is_empty() {
+        result.push_str(&format!(
+            "\n{} {} symbols skipped (no cached data or too few bars)\n",
+            "Note:".yellow(),
+            output.errors.len()
+        ));
+    }
+
+    result
+}
+
 /// Format scan output for terminal display.
 pub fn format_scan_output(output: &ScanOutput) -> String {
     use colored::Colorize;
@@ -568,11 +808,11 @@ pub fn format_scan_output(output: &ScanOutput) -> String {
     result.push_str(&format!("{:-<60}\n", ""));
 
     // Summary
-    let strategies_per_ticker = if output.summary.total_tickers > 0 {
-        output.summary.total_checks / output.summary.total_tickers
-    } else {
-        0
-    };
+    let strategies_per_ticker = output
+        .summary
+        .total_checks
+        .checked_div(output.summary.total_tickers)
+        .unwrap_or(0);
     result.push_str(&format!(
         "Scanned {} tickers x {} strategies = {} checks\n",
         output.summary.total_tickers, strategies_per_ticker, output.summary.total_checks
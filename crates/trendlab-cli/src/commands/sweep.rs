@@ -2,17 +2,21 @@
 
 use anyhow::{bail, Context, Result};
 use chrono::{NaiveDate, Utc};
+use polars::prelude::DataFrame;
+use serde::{Deserialize, Serialize};
 use std::fs;
 
 use trendlab_core::{
-    backtest::{BacktestConfig, CostModel, FillModel},
-    generate_summary_markdown, run_strategy_sweep_polars_parallel, run_sweep,
-    scan_symbol_parquet_lazy, PolarsBacktestConfig, RankMetric, ResultPaths, RunManifest,
-    StrategyGridConfig, StrategyParams, StrategyTypeId, SweepConfig, SweepGrid, SweepResult,
+    backtest::BacktestConfig, generate_summary_markdown, run_strategy_sweep_polars_parallel,
+    run_sweep, scan_symbol_parquet_lazy, AppConfig, Metrics, PolarsBacktestConfig, RankMetric,
+    ResultPaths, RunManifest, StrategyGridConfig, StrategyParams, StrategyTypeId, SweepConfig,
+    SweepGrid, SweepResult,
 };
 
 use super::data::DataConfig;
-use super::terminal::{color_value, format_sweep_table_colored, print_section, sparkline};
+use super::terminal::{
+    color_value, format_sweep_table_colored, print_section, render_equity_sparkline,
+};
 
 /// Sweep grid specification from CLI args.
 #[derive(Debug, Clone)]
@@ -103,6 +107,55 @@ fn parse_values(s: &str) -> Result<Vec<usize>> {
     }
 }
 
+/// Load daily bars for a ticker and date range via the direct Parquet pipeline.
+///
+/// Uses `scan_symbol_parquet_lazy` for Parquet -> LazyFrame, avoiding the
+/// Vec<Bar> intermediate conversion.
+pub(crate) fn load_daily_bars(
+    ticker: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+    data_config: &DataConfig,
+) -> Result<DataFrame> {
+    let parquet_dir = data_config.parquet_dir();
+
+    let lf = match scan_symbol_parquet_lazy(&parquet_dir, ticker, "1d", Some(start), Some(end)) {
+        Ok(lf) => lf,
+        Err(e) => {
+            bail!(
+                "No data found for {}. Run 'trendlab data refresh-yahoo --tickers {}' first.\nError: {}",
+                ticker,
+                ticker,
+                e
+            );
+        }
+    };
+
+    let df = lf.collect().context("Failed to collect Parquet data")?;
+    if df.height() == 0 {
+        bail!("No bars in date range {} to {} for {}", start, end, ticker);
+    }
+
+    Ok(df)
+}
+
+/// Polars backtest settings shared by CLI sweeps.
+pub(crate) fn default_polars_config() -> PolarsBacktestConfig {
+    AppConfig::load().polars_config()
+}
+
+/// Donchian strategy grid config for a CLI grid spec.
+pub(crate) fn donchian_grid_config(grid_spec: &GridSpec) -> StrategyGridConfig {
+    StrategyGridConfig {
+        strategy_type: StrategyTypeId::Donchian,
+        enabled: true,
+        params: StrategyParams::Donchian {
+            entry_lookbacks: grid_spec.entry_lookbacks.clone(),
+            exit_lookbacks: grid_spec.exit_lookbacks.clone(),
+        },
+    }
+}
+
 /// Result of a sweep execution.
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -115,6 +168,10 @@ pub struct SweepExecutionResult {
 }
 
 /// Execute a parameter sweep.
+///
+/// When `quiet` is set, progress messages are suppressed so stdout can carry
+/// machine-readable output.
+#[allow(clippy::too_many_arguments)]
 pub fn execute_sweep(
     strategy: &str,
     ticker: &str,
@@ -123,6 +180,7 @@ pub fn execute_sweep(
     grid_spec: Option<&str>,
     data_config: &DataConfig,
     sequential: bool,
+    quiet: bool,
 ) -> Result<SweepExecutionResult> {
     let start_time = std::time::Instant::now();
 
@@ -144,60 +202,35 @@ pub fn execute_sweep(
     );
     let num_configs = grid.len();
 
-    println!(
-        "Grid: {} entry × {} exit = {} configurations",
-        grid_spec.entry_lookbacks.len(),
-        grid_spec.exit_lookbacks.len(),
-        num_configs
-    );
-
-    // Load data using direct Parquet pipeline (Phase 4)
-    let parquet_dir = data_config.parquet_dir();
-
-    // Use scan_symbol_parquet_lazy for direct Parquet -> LazyFrame pipeline
-    // This avoids the Vec<Bar> intermediate conversion
-    let lf = match scan_symbol_parquet_lazy(&parquet_dir, ticker, "1d", Some(start), Some(end)) {
-        Ok(lf) => lf,
-        Err(e) => {
-            bail!(
-                "No data found for {}. Run 'trendlab data refresh-yahoo --tickers {}' first.\nError: {}",
-                ticker,
-                ticker,
-                e
-            );
-        }
-    };
+    if !quiet {
+        println!(
+            "Grid: {} entry × {} exit = {} configurations",
+            grid_spec.entry_lookbacks.len(),
+            grid_spec.exit_lookbacks.len(),
+            num_configs
+        );
+    }
 
-    // Collect to DataFrame for the Polars sweep
-    let df = lf.collect().context("Failed to collect Parquet data")?;
+    let df = load_daily_bars(ticker, start, end, data_config)?;
     let bar_count = df.height();
 
-    if bar_count == 0 {
-        bail!("No bars in date range {} to {} for {}", start, end, ticker);
+    if !quiet {
+        println!(
+            "Loaded {} bars for {} (direct Parquet pipeline)",
+            bar_count, ticker
+        );
     }
 
-    println!(
-        "Loaded {} bars for {} (direct Parquet pipeline)",
-        bar_count, ticker
-    );
-
     // Configure backtest
-    let backtest_config = BacktestConfig {
-        initial_cash: 100_000.0,
-        fill_model: FillModel::NextOpen,
-        cost_model: CostModel {
-            fees_bps_per_side: 10.0,
-            slippage_bps: 5.0,
-        },
-        qty: 100.0,
-        pyramid_config: trendlab_core::PyramidConfig::default(),
-    };
+    let backtest_config = AppConfig::load().backtest_config();
 
     // Run sweep
-    println!(
-        "Running sweep with {} threads...",
-        rayon::current_num_threads()
-    );
+    if !quiet {
+        println!(
+            "Running sweep with {} threads...",
+            rayon::current_num_threads()
+        );
+    }
 
     let sweep_result = if sequential {
         // Use sequential (original) backtest - needs Vec<Bar>
@@ -207,38 +240,36 @@ pub fn execute_sweep(
         run_sweep(&all_bars, &grid, backtest_config)
     } else {
         // Use Polars vectorized backtest (direct DataFrame - Phase 4)
-        let polars_config =
-            PolarsBacktestConfig::new(100_000.0, 100.0).with_cost_model(CostModel {
-                fees_bps_per_side: 10.0,
-                slippage_bps: 5.0,
-            });
-
-        // Create a Donchian strategy grid config
-        let strategy_config = StrategyGridConfig {
-            strategy_type: StrategyTypeId::Donchian,
-            enabled: true,
-            params: StrategyParams::Donchian {
-                entry_lookbacks: grid_spec.entry_lookbacks.clone(),
-                exit_lookbacks: grid_spec.exit_lookbacks.clone(),
-            },
-        };
-
-        run_strategy_sweep_polars_parallel(&df, &strategy_config, &polars_config)?
+        run_strategy_sweep_polars_parallel(
+            &df,
+            &donchian_grid_config(&grid_spec),
+            &default_polars_config(),
+        )?
     };
 
     let elapsed = start_time.elapsed();
     let elapsed_secs = elapsed.as_secs_f64();
 
-    println!(
-        "Completed {} configurations in {:.2}s ({:.1} configs/sec)",
-        num_configs,
-        elapsed_secs,
-        num_configs as f64 / elapsed_secs
-    );
+    if !quiet {
+        println!(
+            "Completed {} configurations in {:.2}s ({:.1} configs/sec)",
+            num_configs,
+            elapsed_secs,
+            num_configs as f64 / elapsed_secs
+        );
+    }
 
     // Save results
     let output_paths = ResultPaths::for_sweep(&sweep_result.sweep_id);
-    save_sweep_results(&sweep_result, &grid, &backtest_config, ticker, start, end)?;
+    save_sweep_results(
+        &sweep_result,
+        &grid,
+        &backtest_config,
+        ticker,
+        start,
+        end,
+        quiet,
+    )?;
 
     Ok(SweepExecutionResult {
         sweep_id: sweep_result.sweep_id.clone(),
@@ -257,6 +288,7 @@ fn save_sweep_results(
     symbol: &str,
     start: NaiveDate,
     end: NaiveDate,
+    quiet: bool,
 ) -> Result<()> {
     let paths = ResultPaths::for_sweep(&result.sweep_id);
 
@@ -271,6 +303,7 @@ fn save_sweep_results(
         symbol: symbol.to_string(),
         start_date: start.to_string(),
         end_date: end.to_string(),
+        seed: 0,
     };
 
     let manifest = RunManifest {
@@ -294,11 +327,13 @@ fn save_sweep_results(
     let json_path = paths.results_parquet.with_extension("json");
     fs::write(&json_path, results_json)?;
 
-    println!();
-    println!("Results saved to:");
-    println!("  Manifest: {}", paths.manifest.display());
-    println!("  Summary:  {}", paths.summary_md.display());
-    println!("  Results:  {}", json_path.display());
+    if !quiet {
+        println!();
+        println!("Results saved to:");
+        println!("  Manifest: {}", paths.manifest.display());
+        println!("  Summary:  {}", paths.summary_md.display());
+        println!("  Results:  {}", json_path.display());
+    }
 
     Ok(())
 }
@@ -385,7 +420,10 @@ pub fn format_sweep_summary(result: &SweepExecutionResult, top_n: usize) -> Stri
             .iter()
             .map(|e| e.equity)
             .collect();
-        output.push_str(&format!("    Equity:  {}\n", sparkline(&equity).green()));
+        output.push_str(&format!(
+            "    Equity:  {}\n",
+            render_equity_sparkline(&equity, 60).green()
+        ));
     }
 
     output.push_str(&format!("\n{}\n", "═".repeat(90).cyan()));
@@ -393,6 +431,44 @@ pub fn format_sweep_summary(result: &SweepExecutionResult, top_n: usize) -> Stri
     output
 }
 
+/// One ranked configuration in `trendlab sweep --json` output.
+///
+/// This is the scripting contract for the CLI: fields may be added, but
+/// existing names and meanings must not change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SweepConfigJson {
+    /// 1-based position when ranked by Sharpe ratio (descending)
+    pub rank: usize,
+    /// Donchian entry lookback in bars
+    pub entry_lookback: usize,
+    /// Donchian exit lookback in bars
+    pub exit_lookback: usize,
+    /// Full performance metrics for this configuration
+    pub metrics: Metrics,
+}
+
+/// Build the ranked JSON rows for a sweep, best Sharpe first.
+pub fn sweep_json_rows(result: &SweepExecutionResult) -> Vec<SweepConfigJson> {
+    result
+        .sweep_result
+        .rank_by(RankMetric::Sharpe, false)
+        .into_iter()
+        .enumerate()
+        .map(|(i, r)| SweepConfigJson {
+            rank: i + 1,
+            entry_lookback: r.config_id.entry_lookback,
+            exit_lookback: r.config_id.exit_lookback,
+            metrics: r.metrics.clone(),
+        })
+        .collect()
+}
+
+/// Format sweep results as a pretty-printed JSON array of ranked configs.
+pub fn format_sweep_json(result: &SweepExecutionResult) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&sweep_json_rows(result))?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
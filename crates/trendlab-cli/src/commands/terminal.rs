@@ -125,6 +125,49 @@ pub fn sparkline(values: &[f64]) -> String {
         .collect()
 }
 
+/// Render an equity curve as a block sparkline exactly `width` columns wide.
+///
+/// The curve is split into `width` buckets. Each bucket is drawn at whichever
+/// of its min or max moves furthest from the previous column, so short
+/// drawdowns survive downsampling instead of being stepped over.
+pub fn render_equity_sparkline(equity: &[f64], width: usize) -> String {
+    if equity.is_empty() || width == 0 {
+        return String::new();
+    }
+
+    let blocks = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let len = equity.len();
+
+    let mut columns = Vec::with_capacity(width);
+    let mut prev = equity[0];
+    for i in 0..width {
+        let start = (i * len / width).min(len - 1);
+        let end = ((i + 1) * len / width).max(start + 1).min(len);
+        let bucket = &equity[start..end];
+        let lo = bucket.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi = bucket.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let value = if (prev - lo) > (hi - prev) { lo } else { hi };
+        columns.push(value);
+        prev = value;
+    }
+
+    let min = columns.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = columns.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    if range == 0.0 || !range.is_finite() {
+        return blocks[3].to_string().repeat(width);
+    }
+
+    columns
+        .iter()
+        .map(|v| {
+            let normalized = ((v - min) / range * 7.0).round() as usize;
+            blocks[normalized.min(7)]
+        })
+        .collect()
+}
+
 /// Render a larger ASCII chart for equity curve.
 #[allow(dead_code)]
 pub fn render_equity_chart(equity: &[f64], width: usize, height: usize) -> String {
@@ -320,6 +363,42 @@ mod tests {
         assert!(result.ends_with('█'));
     }
 
+    #[test]
+    fn test_render_equity_sparkline_width() {
+        let equity: Vec<f64> = (0..500)
+            .map(|i| 10000.0 + (i as f64).sin() * 100.0)
+            .collect();
+        assert_eq!(render_equity_sparkline(&equity, 60).chars().count(), 60);
+
+        // Shorter series are stretched to fill the requested width
+        let short = vec![1.0, 2.0, 3.0];
+        assert_eq!(render_equity_sparkline(&short, 10).chars().count(), 10);
+        assert_eq!(render_equity_sparkline(&[], 10), "");
+    }
+
+    #[test]
+    fn test_render_equity_sparkline_rising() {
+        let blocks = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        let equity: Vec<f64> = (0..200).map(|i| 10000.0 + i as f64 * 10.0).collect();
+        let spark = render_equity_sparkline(&equity, 16);
+        let heights: Vec<usize> = spark
+            .chars()
+            .map(|c| blocks.iter().position(|&b| b == c).unwrap())
+            .collect();
+
+        assert!(heights.windows(2).all(|w| w[0] <= w[1]));
+        assert!(heights[0] < heights[heights.len() - 1]);
+    }
+
+    #[test]
+    fn test_render_equity_sparkline_keeps_drawdown() {
+        // A one-bar dip inside a single bucket must still show up
+        let mut equity = vec![100.0; 100];
+        equity[55] = 50.0;
+        let spark = render_equity_sparkline(&equity, 10);
+        assert!(spark.contains('▁'));
+    }
+
     #[test]
     fn test_color_value_positive() {
         let result = color_value(1.5, "1.5".to_string(), false);
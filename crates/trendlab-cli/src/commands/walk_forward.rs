@@ -0,0 +1,310 @@
+//! Walk-forward command implementation - rolling train/test validation of a sweep.
+//!
+//! For each fold the full grid is swept on the in-sample slice, the best
+//! config by Sharpe is chosen, and that single config is evaluated on the
+//! following out-of-sample slice.
+
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use colored::Colorize;
+use polars::prelude::DataFrame;
+use serde::{Deserialize, Serialize};
+
+use trendlab_core::{
+    compute_metrics, generate_walk_forward_folds, run_strategy_sweep_polars_parallel,
+    slice_by_index, BacktestResult, FoldResult, PolarsBacktestConfig, RankMetric,
+    StrategyGridConfig, WalkForwardConfig, WalkForwardFold, WalkForwardResult,
+};
+
+use super::data::DataConfig;
+use super::sweep::{
+    default_polars_config, donchian_grid_config, load_daily_bars, parse_grid, GridSpec,
+};
+use super::terminal::{color_value, print_section};
+
+/// Outcome of a walk-forward run over one ticker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalkForwardExecutionResult {
+    pub symbol: String,
+    pub num_bars: usize,
+    /// Aggregated out-of-sample result with per-fold chosen configs
+    pub result: WalkForwardResult,
+}
+
+/// Parse a walk-forward spec of the form "train:test:step" (bar counts).
+///
+/// No gap is inserted between train and test; Donchian signals only look
+/// backwards, so adjacent windows cannot leak.
+pub fn parse_walk_forward(spec: &str) -> Result<WalkForwardConfig> {
+    let parts: Vec<&str> = spec.split(':').map(str::trim).collect();
+    if parts.len() != 3 {
+        bail!(
+            "Invalid walk-forward spec: {}. Use train:test:step (e.g. 252:63:63)",
+            spec
+        );
+    }
+
+    let parse = |name: &str, v: &str| -> Result<usize> {
+        v.parse()
+            .with_context(|| format!("Invalid {} bars in walk-forward spec: {}", name, v))
+    };
+
+    Ok(WalkForwardConfig {
+        in_sample_bars: parse("train", parts[0])?,
+        out_of_sample_bars: parse("test", parts[1])?,
+        gap_bars: 0,
+        step_bars: parse("step", parts[2])?,
+        min_folds: 1,
+    })
+}
+
+/// Keep only the part of a backtest that falls on or after `from`.
+///
+/// Used to drop the indicator warm-up bars that precede an out-of-sample window.
+fn trim_to_window(result: &BacktestResult, from: chrono::DateTime<chrono::Utc>) -> BacktestResult {
+    BacktestResult {
+        fills: result
+            .fills
+            .iter()
+            .filter(|f| f.ts >= from)
+            .cloned()
+            .collect(),
+        trades: result
+            .trades
+            .iter()
+            .filter(|t| t.entry.ts >= from)
+            .cloned()
+            .collect(),
+        pyramid_trades: result
+            .pyramid_trades
+            .iter()
+            .filter(|t| t.entries.first().is_some_and(|e| e.ts >= from))
+            .cloned()
+            .collect(),
+        equity: result
+            .equity
+            .iter()
+            .filter(|e| e.ts >= from)
+            .cloned()
+            .collect(),
+        vs_benchmark: None,
+    }
+}
+
+/// Run a walk-forward sweep: optimise on each train slice, score on the test slice.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_walk_forward(
+    strategy: &str,
+    ticker: &str,
+    start: NaiveDate,
+    end: NaiveDate,
+    grid_spec: Option<&str>,
+    wf_config: &WalkForwardConfig,
+    data_config: &DataConfig,
+    quiet: bool,
+) -> Result<WalkForwardExecutionResult> {
+    if !strategy.to_lowercase().starts_with("donchian") {
+        bail!("Unknown strategy: {}. Available: donchian", strategy);
+    }
+
+    let grid_spec = match grid_spec {
+        Some(gs) => parse_grid(gs)?,
+        None => GridSpec::default(),
+    };
+    let strategy_config = donchian_grid_config(&grid_spec);
+    let polars_config = default_polars_config();
+
+    let df = load_daily_bars(ticker, start, end, data_config)?;
+    let num_bars = df.height();
+    let folds = generate_walk_forward_folds(num_bars, wf_config)?;
+
+    if !quiet {
+        println!(
+            "Walk-forward: {} folds over {} bars ({} train / {} test / {} step)",
+            folds.len(),
+            num_bars,
+            wf_config.in_sample_bars,
+            wf_config.out_of_sample_bars,
+            wf_config.step_bars
+        );
+    }
+
+    let mut fold_results = Vec::with_capacity(folds.len());
+    for fold in &folds {
+        fold_results.push(run_fold(&df, fold, &strategy_config, &polars_config)?);
+    }
+
+    Ok(WalkForwardExecutionResult {
+        symbol: ticker.to_string(),
+        num_bars,
+        result: WalkForwardResult::from_folds(fold_results, wf_config.clone()),
+    })
+}
+
+/// Sweep the in-sample slice, then evaluate the winner out of sample.
+fn run_fold(
+    df: &DataFrame,
+    fold: &WalkForwardFold,
+    strategy_config: &StrategyGridConfig,
+    polars_config: &PolarsBacktestConfig,
+) -> Result<FoldResult> {
+    let is_df = slice_by_index(df, fold.is_start, fold.is_end)?;
+    let is_sweep = run_strategy_sweep_polars_parallel(&is_df, strategy_config, polars_config)?;
+    let best = is_sweep
+        .rank_by(RankMetric::Sharpe, false)
+        .into_iter()
+        .next()
+        .with_context(|| format!("Fold {} produced no configurations", fold.fold_idx))?
+        .clone();
+
+    // Evaluate with enough preceding history for the indicators to warm up,
+    // then score only the out-of-sample bars.
+    let warmup = best
+        .config_id
+        .entry_lookback
+        .max(best.config_id.exit_lookback);
+    let eval_start = fold.oos_start.saturating_sub(warmup);
+    let eval_df = slice_by_index(df, eval_start, fold.oos_end)?;
+
+    let single = GridSpec {
+        entry_lookbacks: vec![best.config_id.entry_lookback],
+        exit_lookbacks: vec![best.config_id.exit_lookback],
+    };
+    let oos_sweep = run_strategy_sweep_polars_parallel(
+        &eval_df,
+        &donchian_grid_config(&single),
+        polars_config,
+    )?;
+    let oos_full = oos_sweep
+        .config_results
+        .into_iter()
+        .next()
+        .with_context(|| format!("Fold {} out-of-sample run failed", fold.fold_idx))?
+        .backtest_result;
+
+    let oos_index = fold.oos_start - eval_start;
+    let oos_metrics = match oos_full.equity.get(oos_index) {
+        Some(first) => {
+            let starting_equity = if oos_index > 0 {
+                oos_full.equity[oos_index - 1].equity
+            } else {
+                polars_config.initial_cash
+            };
+            compute_metrics(&trim_to_window(&oos_full, first.ts), starting_equity)
+        }
+        None => Default::default(),
+    };
+
+    Ok(FoldResult {
+        fold_idx: fold.fold_idx,
+        best_is_config: best.config_id.id(),
+        is_sharpe: best.metrics.sharpe,
+        oos_sharpe: oos_metrics.sharpe,
+        is_cagr: best.metrics.cagr,
+        oos_cagr: oos_metrics.cagr,
+        is_max_drawdown: best.metrics.max_drawdown,
+        oos_max_drawdown: oos_metrics.max_drawdown,
+        oos_trades: oos_metrics.num_trades,
+    })
+}
+
+/// Format walk-forward results for display with colored terminal output.
+pub fn format_walk_forward_summary(result: &WalkForwardExecutionResult) -> String {
+    let wf = &result.result;
+    let mut output = String::new();
+
+    output.push_str(&format!("\n{}\n", "═".repeat(90).cyan()));
+    output.push_str(&format!(
+        "  {} {} ({} bars, {} folds)\n",
+        "WALK-FORWARD RESULTS".cyan().bold(),
+        result.symbol.white(),
+        result.num_bars,
+        wf.folds.len()
+    ));
+    output.push_str(&format!("{}\n", "═".repeat(90).cyan()));
+
+    print_section("Per-Fold Chosen Configs");
+    output.push_str(&format!(
+        "  {:<6} {:<20} {:>10} {:>10} {:>10} {:>10} {:>8}\n",
+        "Fold", "Config", "IS Sharpe", "OOS Sharpe", "OOS CAGR", "OOS MaxDD", "Trades"
+    ));
+    for f in &wf.folds {
+        output.push_str(&format!(
+            "  {:<6} {:<20} {:>10} {:>10} {:>10} {:>10} {:>8}\n",
+            f.fold_idx,
+            f.best_is_config,
+            format!("{:.3}", f.is_sharpe),
+            color_value(f.oos_sharpe, format!("{:.3}", f.oos_sharpe), false),
+            color_value(f.oos_cagr, format!("{:.1}%", f.oos_cagr * 100.0), false),
+            color_value(
+                f.oos_max_drawdown,
+                format!("{:.1}%", f.oos_max_drawdown * 100.0),
+                true
+            ),
+            f.oos_trades
+        ));
+    }
+
+    output.push_str(&format!("\n{}\n", "─".repeat(90).dimmed()));
+    output.push_str(&format!(
+        "  {} {} (± {:.3})\n",
+        "Mean OOS Sharpe:".cyan(),
+        color_value(
+            wf.mean_oos_sharpe,
+            format!("{:.3}", wf.mean_oos_sharpe),
+            false
+        ),
+        wf.std_oos_sharpe
+    ));
+    output.push_str(&format!(
+        "  {} {}\n",
+        "Mean OOS CAGR:  ".cyan(),
+        color_value(
+            wf.mean_oos_cagr,
+            format!("{:.2}%", wf.mean_oos_cagr * 100.0),
+            false
+        )
+    ));
+    output.push_str(&format!(
+        "  {} {:.2}%\n",
+        "Mean OOS MaxDD: ".cyan(),
+        wf.mean_oos_drawdown * 100.0
+    ));
+    output.push_str(&format!(
+        "  {} {:.0}% of folds, {} OOS trades\n",
+        "Profitable:     ".cyan(),
+        wf.pct_profitable_folds * 100.0,
+        wf.total_oos_trades
+    ));
+    output.push_str(&format!(
+        "  {} {:.2}   {} {}\n",
+        "Degradation:    ".cyan(),
+        wf.mean_degradation,
+        "Grade:".cyan(),
+        wf.grade().to_string().white().bold()
+    ));
+
+    output.push_str(&format!("\n{}\n", "═".repeat(90).cyan()));
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_walk_forward() {
+        let config = parse_walk_forward("252:63:21").unwrap();
+        assert_eq!(config.in_sample_bars, 252);
+        assert_eq!(config.out_of_sample_bars, 63);
+        assert_eq!(config.step_bars, 21);
+        assert_eq!(config.gap_bars, 0);
+    }
+
+    #[test]
+    fn test_parse_walk_forward_invalid() {
+        assert!(parse_walk_forward("252:63").is_err());
+        assert!(parse_walk_forward("252:x:21").is_err());
+    }
+}
@@ -9,12 +9,18 @@ use commands::report;
 use commands::run;
 use commands::scan;
 use commands::sweep;
+use commands::walk_forward;
 use trendlab_cli::commands;
+use trendlab_core::RetryPolicy;
 
 #[derive(Parser)]
 #[command(name = "trendlab")]
 #[command(author, version, about = "Research-grade trend-following backtester", long_about = None)]
 struct Cli {
+    /// Print machine-readable JSON instead of formatted tables (run, sweep)
+    #[arg(long, global = true)]
+    json: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -76,6 +82,11 @@ enum Commands {
         /// Use sequential backtest instead of vectorized Polars (slower but useful for debugging)
         #[arg(long, default_value = "false")]
         sequential: bool,
+
+        /// Walk-forward validation as "train:test:step" in bars (e.g. "252:63:63").
+        /// Sweeps each train window, then scores the best config on the next test window.
+        #[arg(long)]
+        walk_forward: Option<String>,
     },
 
     /// Generate reports
@@ -90,7 +101,7 @@ enum Commands {
         command: ArtifactCommands,
     },
 
-    /// Scan watchlist for today's signals
+    /// Scan watchlist for today's signals, or rank the universe by one strategy
     Scan {
         /// Path to watchlist TOML file
         #[arg(short, long, default_value = "configs/watchlist.toml")]
@@ -119,6 +130,23 @@ enum Commands {
         /// Set to 1 to show all signals without confirmation filter.
         #[arg(long, default_value = "2")]
         min_confirm: usize,
+
+        /// Rank the whole universe by this strategy's fresh long entries instead of
+        /// scanning the watchlist (e.g. "donchian:55,20"). Uses cached data only.
+        #[arg(long)]
+        strategy: Option<String>,
+
+        /// Universe file for --strategy (TOML, CSV or JSON; built-in universe if missing)
+        #[arg(long, default_value = "configs/universe.toml")]
+        universe: String,
+
+        /// Evaluate --strategy signals as of this date (YYYY-MM-DD, default: latest bar)
+        #[arg(long)]
+        as_of: Option<String>,
+
+        /// Number of setups to list with --strategy (0 = all)
+        #[arg(long, default_value = "20")]
+        top_n: usize,
     },
 }
 
@@ -141,6 +169,14 @@ enum DataCommands {
         /// Force refresh even if cached
         #[arg(long, default_value = "false")]
         force: bool,
+
+        /// Maximum attempts per symbol when Yahoo rate-limits or the network fails
+        #[arg(long, default_value = "4")]
+        max_attempts: u32,
+
+        /// Maximum Yahoo requests per second (0 disables pacing)
+        #[arg(long, default_value = "2.0")]
+        requests_per_sec: f64,
     },
 
     /// Show data status and quality report
@@ -162,6 +198,11 @@ enum ReportCommands {
         /// Number of top configurations to display
         #[arg(long, default_value = "5")]
         top_n: usize,
+
+        /// Only include configs matching a metric filter,
+        /// e.g. "sharpe > 1 and max_drawdown < 0.2 and num_trades >= 30"
+        #[arg(long)]
+        filter: Option<String>,
     },
 
     /// Generate self-contained HTML report
@@ -184,6 +225,25 @@ enum ReportCommands {
         /// Output path
         #[arg(short, long)]
         output: String,
+
+        /// Only export configs matching a metric filter (see `report summary --filter`)
+        #[arg(long)]
+        filter: Option<String>,
+    },
+
+    /// Compare two sweep runs config-by-config
+    Compare {
+        /// Baseline run ID
+        #[arg(long)]
+        run_a: String,
+
+        /// Run ID to compare against the baseline
+        #[arg(long)]
+        run_b: String,
+
+        /// Number of largest changes to display
+        #[arg(long, default_value = "10")]
+        top_n: usize,
     },
 
     /// List available sweep runs
@@ -222,8 +282,18 @@ async fn main() -> Result<()> {
                 start,
                 end,
                 force,
+                max_attempts,
+                requests_per_sec,
             } => {
-                run_refresh_yahoo(&tickers, &start, &end, force).await?;
+                run_refresh_yahoo(
+                    &tickers,
+                    &start,
+                    &end,
+                    force,
+                    max_attempts,
+                    requests_per_sec,
+                )
+                .await?;
             }
             DataCommands::Status { ticker } => {
                 run_data_status(ticker.as_deref())?;
@@ -240,14 +310,20 @@ async fn main() -> Result<()> {
             let end_date = data::parse_date(&end)?;
             let config = DataConfig::default();
 
-            println!("Running backtest...");
-            println!("  Strategy: {}", strategy);
-            println!("  Ticker: {}", ticker);
-            println!("  Range: {} to {}", start, end);
-            println!();
+            if !cli.json {
+                println!("Running backtest...");
+                println!("  Strategy: {}", strategy);
+                println!("  Ticker: {}", ticker);
+                println!("  Range: {} to {}", start, end);
+                println!();
+            }
 
             let result = run::execute_run(&strategy, &ticker, start_date, end_date, &config)?;
-            println!("{}", run::format_metrics(&result));
+            if cli.json {
+                println!("{}", run::format_metrics_json(&result)?);
+            } else {
+                println!("{}", run::format_metrics(&result));
+            }
         }
 
         Commands::Sweep {
@@ -258,27 +334,51 @@ async fn main() -> Result<()> {
             grid,
             top_n,
             sequential,
+            walk_forward,
         } => {
             let start_date = data::parse_date(&start)?;
             let end_date = data::parse_date(&end)?;
             let config = DataConfig::default();
 
-            println!("Running parameter sweep...");
-            println!("  Strategy: {}", strategy);
-            println!("  Ticker:   {}", ticker);
-            println!("  Range:    {} to {}", start, end);
-            if let Some(ref g) = grid {
-                println!("  Grid:     {}", g);
-            }
-            println!(
-                "  Engine:   {}",
-                if sequential {
-                    "Sequential"
+            if let Some(spec) = walk_forward {
+                let wf_config = walk_forward::parse_walk_forward(&spec)?;
+                let result = walk_forward::execute_walk_forward(
+                    &strategy,
+                    &ticker,
+                    start_date,
+                    end_date,
+                    grid.as_deref(),
+                    &wf_config,
+                    &config,
+                    cli.json,
+                )?;
+
+                if cli.json {
+                    println!("{}", serde_json::to_string_pretty(&result)?);
                 } else {
-                    "Polars (vectorized)"
+                    println!("{}", walk_forward::format_walk_forward_summary(&result));
                 }
-            );
-            println!();
+                return Ok(());
+            }
+
+            if !cli.json {
+                println!("Running parameter sweep...");
+                println!("  Strategy: {}", strategy);
+                println!("  Ticker:   {}", ticker);
+                println!("  Range:    {} to {}", start, end);
+                if let Some(ref g) = grid {
+                    println!("  Grid:     {}", g);
+                }
+                println!(
+                    "  Engine:   {}",
+                    if sequential {
+                        "Sequential"
+                    } else {
+                        "Polars (vectorized)"
+                    }
+                );
+                println!();
+            }
 
             let result = sweep::execute_sweep(
                 &strategy,
@@ -288,20 +388,40 @@ async fn main() -> Result<()> {
                 grid.as_deref(),
                 &config,
                 sequential,
+                cli.json,
             )?;
 
-            println!("{}", sweep::format_sweep_summary(&result, top_n));
+            if cli.json {
+                println!("{}", sweep::format_sweep_json(&result)?);
+            } else {
+                println!("{}", sweep::format_sweep_summary(&result, top_n));
+            }
         }
 
         Commands::Report { command } => match command {
-            ReportCommands::Summary { run_id, top_n } => {
-                report::execute_summary(&run_id, top_n)?;
+            ReportCommands::Summary {
+                run_id,
+                top_n,
+                filter,
+            } => {
+                report::execute_summary(&run_id, top_n, filter.as_deref())?;
             }
             ReportCommands::Html { run_id, open } => {
                 html_report::execute_html_report(&run_id, open)?;
             }
-            ReportCommands::Export { run_id, output } => {
-                report::execute_export(&run_id, &output)?;
+            ReportCommands::Export {
+                run_id,
+                output,
+                filter,
+            } => {
+                report::execute_export(&run_id, &output, filter.as_deref())?;
+            }
+            ReportCommands::Compare {
+                run_a,
+                run_b,
+                top_n,
+            } => {
+                report::execute_compare(&run_a, &run_b, top_n)?;
             }
             ReportCommands::List => {
                 let runs = report::list_runs()?;
@@ -332,7 +452,36 @@ async fn main() -> Result<()> {
             actionable_only,
             freshness,
             min_confirm,
+            strategy,
+            universe,
+            as_of,
+            top_n,
         } => {
+            if let Some(strategy) = strategy {
+                let as_of = as_of.as_deref().map(data::parse_date).transpose()?;
+                let universe = scan::load_universe(std::path::Path::new(&universe))?;
+                let result = scan::execute_universe_scan(
+                    &universe,
+                    &strategy,
+                    as_of,
+                    top_n,
+                    freshness,
+                    &DataConfig::default(),
+                )?;
+
+                if let Some(output_path) = output {
+                    let json = serde_json::to_string_pretty(&result)?;
+                    std::fs::write(&output_path, &json)?;
+                    println!("Scan results written to: {}", output_path);
+                }
+                if cli.json {
+                    println!("{}", serde_json::to_string_pretty(&result)?);
+                } else {
+                    println!("{}", scan::format_universe_scan_output(&result));
+                }
+                return Ok(());
+            }
+
             let watchlist_path = std::path::Path::new(&watchlist);
             let config = DataConfig::default();
 
@@ -377,11 +526,22 @@ async fn main() -> Result<()> {
 }
 
 /// Execute the refresh-yahoo command.
-async fn run_refresh_yahoo(tickers: &str, start: &str, end: &str, force: bool) -> Result<()> {
+async fn run_refresh_yahoo(
+    tickers: &str,
+    start: &str,
+    end: &str,
+    force: bool,
+    max_attempts: u32,
+    requests_per_sec: f64,
+) -> Result<()> {
     let tickers = data::parse_tickers(tickers)?;
     let start_date = data::parse_date(start)?;
     let end_date = data::parse_date(end)?;
-    let config = DataConfig::default();
+    let config = DataConfig {
+        requests_per_sec,
+        ..Default::default()
+    };
+    let retry = RetryPolicy::default().with_max_attempts(max_attempts);
 
     println!("Refreshing Yahoo Finance data...");
     println!("  Tickers: {}", tickers.join(", "));
@@ -389,7 +549,8 @@ async fn run_refresh_yahoo(tickers: &str, start: &str, end: &str, force: bool) -
     println!("  Force: {}", force);
     println!();
 
-    let results = data::refresh_yahoo(&tickers, start_date, end_date, force, &config).await?;
+    let results =
+        data::refresh_yahoo(&tickers, start_date, end_date, force, &retry, &config).await?;
 
     // Print summary
     println!("Results:");
@@ -397,8 +558,14 @@ async fn run_refresh_yahoo(tickers: &str, start: &str, end: &str, force: bool) -
 
     for result in &results {
         let source_str = match result.source {
-            RefreshSource::Cache => "cached",
-            RefreshSource::Fresh => "fetched",
+            RefreshSource::Cache => "cached".to_string(),
+            RefreshSource::Fresh if result.retries > 0 => {
+                format!("fetched after {} retries", result.retries)
+            }
+            RefreshSource::Fresh => "fetched".to_string(),
+            RefreshSource::Incremental { ranges_fetched } => {
+                format!("extended cache ({} range(s) fetched)", ranges_fetched)
+            }
         };
 
         let quality_str = if result.quality_report.is_clean() {
@@ -0,0 +1,72 @@
+//! Integration tests for the `--json` output mode.
+
+use std::process::Command;
+
+use chrono::{Duration, TimeZone, Utc};
+use trendlab_cli::commands::sweep::SweepConfigJson;
+use trendlab_core::{write_partitioned_parquet, Bar};
+
+/// Write a year of synthetic daily bars under `<root>/data/parquet`.
+fn write_test_data(root: &std::path::Path, symbol: &str) {
+    let start = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let bars: Vec<Bar> = (0..365)
+        .map(|i| {
+            let close = 100.0 + i as f64 * 0.2 + 5.0 * (i as f64 / 10.0).sin();
+            Bar::new(
+                start + Duration::days(i),
+                close - 0.5,
+                close + 1.0,
+                close - 1.0,
+                close,
+                1_000_000.0,
+                symbol,
+                "1d",
+            )
+        })
+        .collect();
+
+    write_partitioned_parquet(&bars, &root.join("data/parquet")).unwrap();
+}
+
+#[test]
+fn test_sweep_json_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    write_test_data(dir.path(), "TEST");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_trendlab-cli"))
+        .current_dir(dir.path())
+        .args([
+            "--json",
+            "sweep",
+            "--strategy",
+            "donchian",
+            "--ticker",
+            "TEST",
+            "--start",
+            "2023-01-01",
+            "--end",
+            "2023-12-31",
+            "--grid",
+            "entry:20,30;exit:5,10",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "sweep failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let rows: Vec<SweepConfigJson> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(rows.len(), 4);
+
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(row.rank, i + 1);
+        assert!([20, 30].contains(&row.entry_lookback));
+        assert!([5, 10].contains(&row.exit_lookback));
+    }
+    for pair in rows.windows(2) {
+        assert!(pair[0].metrics.sharpe >= pair[1].metrics.sharpe);
+    }
+}
@@ -0,0 +1,96 @@
+//! Integration tests for the universe scan.
+
+use chrono::{Duration, TimeZone, Utc};
+use trendlab_cli::commands::data::DataConfig;
+use trendlab_cli::commands::scan::execute_universe_scan;
+use trendlab_core::{write_partitioned_parquet, Bar, Universe};
+
+const FIXTURE_UNIVERSE: &str = r#"
+[universe]
+name = "Fixture"
+
+[[sectors]]
+id = "tech"
+name = "Technology"
+tickers = ["BRKA", "BRKB", "CHOP"]
+
+[[sectors]]
+id = "energy"
+name = "Energy"
+tickers = ["LATE", "NODATA"]
+"#;
+
+/// Write 200 sideways daily bars under `<root>/parquet`, with every close
+/// from `jump_at` onwards lifted by `jump` (a Donchian breakout on that bar).
+fn write_fixture(root: &std::path::Path, symbol: &str, jump_at: usize, jump: f64) {
+    let start = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let bars: Vec<Bar> = (0..200)
+        .map(|i| {
+            let lift = if i >= jump_at { jump } else { 0.0 };
+            let close = 100.0 + 5.0 * (i as f64 / 10.0).sin() + lift;
+            Bar::new(
+                start + Duration::days(i as i64),
+                close - 0.5,
+                close + 1.0,
+                close - 1.0,
+                close,
+                1_000_000.0,
+                symbol,
+                "1d",
+            )
+        })
+        .collect();
+
+    write_partitioned_parquet(&bars, &root.join("parquet")).unwrap();
+}
+
+#[test]
+fn test_universe_scan_ranks_fresh_breakouts() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), "BRKA", 199, 15.0);
+    write_fixture(dir.path(), "BRKB", 199, 30.0);
+    write_fixture(dir.path(), "CHOP", 200, 0.0);
+    write_fixture(dir.path(), "LATE", 150, 15.0);
+    let data_config = DataConfig {
+        data_dir: dir.path().to_path_buf(),
+        ..Default::default()
+    };
+    let universe = Universe::from_toml(FIXTURE_UNIVERSE).unwrap();
+
+    let result =
+        execute_universe_scan(&universe, "donchian:20,10", None, 10, 1, &data_config).unwrap();
+
+    let symbols: Vec<&str> = result.setups.iter().map(|s| s.symbol.as_str()).collect();
+    assert_eq!(symbols, vec!["BRKB", "BRKA"]);
+    assert_eq!(result.setups[0].rank, 1);
+    assert!(result.setups[0].momentum > result.setups[1].momentum);
+    assert_eq!(result.setups[0].sector.as_deref(), Some("Technology"));
+    assert_eq!(result.setups[0].entry_date, "2023-07-19");
+    assert_eq!(result.total_symbols, 5);
+
+    // The symbol without cached data is reported, not fatal
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.errors[0].symbol, "NODATA");
+
+    // top_n trims the ranked list
+    let top1 =
+        execute_universe_scan(&universe, "donchian:20,10", None, 1, 1, &data_config).unwrap();
+    assert_eq!(top1.setups.len(), 1);
+    assert_eq!(top1.setups[0].symbol, "BRKB");
+
+    // As of LATE's breakout day, only LATE had a fresh entry
+    let as_of =
+        (Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap() + Duration::days(150)).date_naive();
+    let past = execute_universe_scan(
+        &universe,
+        "donchian:20,10",
+        Some(as_of),
+        10,
+        1,
+        &data_config,
+    )
+    .unwrap();
+    let symbols: Vec<&str> = past.setups.iter().map(|s| s.symbol.as_str()).collect();
+    assert_eq!(symbols, vec!["LATE"]);
+    assert_eq!(past.as_of, "2023-05-31");
+}
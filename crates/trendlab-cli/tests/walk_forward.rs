@@ -0,0 +1,58 @@
+//! Integration tests for walk-forward sweeps.
+
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use trendlab_cli::commands::data::DataConfig;
+use trendlab_cli::commands::walk_forward::{execute_walk_forward, parse_walk_forward};
+use trendlab_core::{write_partitioned_parquet, Bar};
+
+/// Write a year of synthetic daily bars under `<root>/parquet`.
+fn write_fixture(root: &std::path::Path, symbol: &str, n: i64) {
+    let start = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let bars: Vec<Bar> = (0..n)
+        .map(|i| {
+            let close = 100.0 + i as f64 * 0.2 + 5.0 * (i as f64 / 10.0).sin();
+            Bar::new(
+                start + Duration::days(i),
+                close - 0.5,
+                close + 1.0,
+                close - 1.0,
+                close,
+                1_000_000.0,
+                symbol,
+                "1d",
+            )
+        })
+        .collect();
+
+    write_partitioned_parquet(&bars, &root.join("parquet")).unwrap();
+}
+
+#[test]
+fn test_walk_forward_fold_count_matches_config() {
+    let dir = tempfile::tempdir().unwrap();
+    write_fixture(dir.path(), "TEST", 365);
+    let data_config = DataConfig {
+        data_dir: dir.path().to_path_buf(),
+        ..Default::default()
+    };
+
+    // 365 bars, 120 train + 40 test, stepping 40: train starts at 0, 40, ..., 200
+    let wf_config = parse_walk_forward("120:40:40").unwrap();
+    let result = execute_walk_forward(
+        "donchian",
+        "TEST",
+        NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+        Some("entry:10,20;exit:5,10"),
+        &wf_config,
+        &data_config,
+        true,
+    )
+    .unwrap();
+
+    assert_eq!(result.result.folds.len(), 6);
+    for (i, fold) in result.result.folds.iter().enumerate() {
+        assert_eq!(fold.fold_idx, i);
+        assert!(fold.best_is_config.starts_with("donchian_"));
+    }
+}
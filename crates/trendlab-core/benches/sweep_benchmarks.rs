@@ -5,13 +5,16 @@
 //! - `run_strategy_sweep_polars_parallel` (rayon parallel)
 //! - `run_strategy_sweep_polars_cached` (indicator caching)
 //! - `run_strategy_sweep_polars_lazy` (optimal lazy + batched indicators)
+//!
+//! Also measures the per-bar `Strategy::signal` loop with and without
+//! `Strategy::prepare` precomputation.
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use polars::prelude::*;
 use trendlab_core::{
     run_strategy_sweep_polars, run_strategy_sweep_polars_cached, run_strategy_sweep_polars_lazy,
-    run_strategy_sweep_polars_parallel, PolarsBacktestConfig, StrategyGridConfig, StrategyParams,
-    StrategyTypeId,
+    run_strategy_sweep_polars_parallel, Bar, DonchianBreakoutStrategy, PolarsBacktestConfig,
+    Position, Strategy, StrategyGridConfig, StrategyParams, StrategyTypeId,
 };
 
 /// Generate synthetic OHLCV data for benchmarking.
@@ -61,6 +64,38 @@ fn generate_benchmark_data(num_bars: usize) -> DataFrame {
     .expect("Failed to create benchmark DataFrame")
 }
 
+/// Same synthetic series as [`generate_benchmark_data`], as bars.
+fn generate_benchmark_bars(num_bars: usize) -> Vec<Bar> {
+    let df = generate_benchmark_data(num_bars);
+    let column = |name: &str| -> Vec<f64> {
+        df.column(name)
+            .and_then(|c| c.f64().map(|ca| ca.into_no_null_iter().collect()))
+            .expect("Missing benchmark column")
+    };
+    let (opens, highs, lows, closes) = (
+        column("open"),
+        column("high"),
+        column("low"),
+        column("close"),
+    );
+    let start = chrono::DateTime::from_timestamp(19000 * 86_400, 0).expect("Valid base date");
+
+    (0..num_bars)
+        .map(|i| {
+            Bar::new(
+                start + chrono::Duration::days(i as i64),
+                opens[i],
+                highs[i],
+                lows[i],
+                closes[i],
+                1_000_000.0,
+                "BENCH",
+                "1d",
+            )
+        })
+        .collect()
+}
+
 /// Create a Donchian grid with specified size.
 fn donchian_grid(size: &str) -> StrategyGridConfig {
     let params = match size {
@@ -260,6 +295,39 @@ fn bench_ma_crossover(c: &mut Criterion) {
     group.finish();
 }
 
+/// Per-bar signal loop: rescanning the slice every bar vs prepared channels.
+fn bench_donchian_prepare(c: &mut Criterion) {
+    let mut group = c.benchmark_group("donchian_signal_loop");
+    let bars = generate_benchmark_bars(2000);
+
+    group.throughput(Throughput::Elements(bars.len() as u64));
+
+    let signal_loop = |strategy: &DonchianBreakoutStrategy| {
+        (0..bars.len())
+            .filter(|&i| {
+                strategy
+                    .signal(black_box(&bars[..=i]), Position::Flat)
+                    .is_entry()
+            })
+            .count()
+    };
+
+    group.bench_function("rescan", |b| {
+        let strategy = DonchianBreakoutStrategy::turtle_system_1();
+        b.iter(|| signal_loop(&strategy))
+    });
+
+    group.bench_function("prepared", |b| {
+        let mut strategy = DonchianBreakoutStrategy::turtle_system_1();
+        b.iter(|| {
+            strategy.prepare(black_box(&bars));
+            signal_loop(&strategy)
+        })
+    });
+
+    group.finish();
+}
+
 /// Quick sanity check benchmark (small, fast).
 fn bench_sanity(c: &mut Criterion) {
     let df = generate_benchmark_data(100);
@@ -280,5 +348,6 @@ criterion_group!(
     bench_donchian_data_sizes,
     bench_donchian_grid_sizes,
     bench_ma_crossover,
+    bench_donchian_prepare,
 );
 criterion_main!(benches);
@@ -4,11 +4,23 @@
 //! - Return distribution metrics (VaR, CVaR, skewness, kurtosis)
 //! - Regime-based performance analysis (volatility regimes)
 //! - Trade-level analysis (MAE, MFE, holding period, edge ratio)
+//! - Monte Carlo trade-order shuffling (drawdown and final equity confidence)
+//! - Win/loss streak statistics
+//! - Trade seasonality by entry month and weekday
+//! - Underwater (drawdown-from-peak) equity curve
+//! - Calendar-month returns of the equity curve
+//! - Rolling correlation against a benchmark
 //!
 //! Designed for swing trading (2-10 week holding periods) and options overlay decisions.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Utc};
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::backtest::{EquityPoint, Trade};
 
 /// Configuration for statistical analysis.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +35,10 @@ pub struct AnalysisConfig {
     pub var_levels: Vec<f64>,
     /// Holding period histogram bucket edges (in trading days).
     pub holding_buckets: Vec<usize>,
+    /// Initial risk per trade as a fraction of entry price (e.g., 0.05 = 5% stop),
+    /// the denominator for R-multiples. `None` uses each trade's MAE instead.
+    #[serde(default)]
+    pub risk_per_trade: Option<f64>,
 }
 
 impl Default for AnalysisConfig {
@@ -34,6 +50,7 @@ impl Default for AnalysisConfig {
             var_levels: vec![0.95, 0.99],
             // Default buckets: 1-5, 6-10, 11-20, 21-50, 50+ days
             holding_buckets: vec![5, 10, 20, 50],
+            risk_per_trade: None,
         }
     }
 }
@@ -438,6 +455,9 @@ pub struct TradeAnalysis {
     pub edge_ratio: EdgeRatioStats,
     /// Volatility at entry analysis.
     pub vol_at_entry: VolAtEntryStats,
+    /// R-multiple (return / initial risk) distribution.
+    #[serde(default)]
+    pub r_multiples: RMultipleStats,
     /// Number of trades analyzed.
     pub n_trades: usize,
 }
@@ -586,6 +606,30 @@ impl Default for VolAtEntryStats {
     }
 }
 
+/// R-multiple distribution: each trade's return divided by its initial risk.
+///
+/// Risk is `AnalysisConfig::risk_per_trade` when set, otherwise the trade's
+/// MAE. Trades with no risk (MAE of zero and no configured stop) are skipped.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RMultipleStats {
+    /// Per-trade R-multiples, in trade order.
+    pub values: Vec<f64>,
+    /// Mean R.
+    pub mean: f64,
+    /// Median R.
+    pub median: f64,
+    /// Mean R of winning trades.
+    pub avg_win: f64,
+    /// Mean R of losing trades (negative).
+    pub avg_loss: f64,
+    /// Expectancy in R: win rate × avg_win + loss rate × avg_loss.
+    pub expectancy: f64,
+    /// Fraction of trades returning more than +1R.
+    pub pct_above_1r: f64,
+    /// Number of trades whose risk fell back to MAE.
+    pub n_risk_from_mae: usize,
+}
+
 // =============================================================================
 // TRADE EXCURSION DATA (for computation)
 // =============================================================================
@@ -607,6 +651,334 @@ pub struct TradeExcursion {
     pub is_winner: bool,
 }
 
+// =============================================================================
+// MONTE CARLO TRADE SHUFFLING
+// =============================================================================
+
+/// 5th/50th/95th percentiles of a simulated distribution.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct McPercentiles {
+    /// 5th percentile.
+    pub p5: f64,
+    /// Median.
+    pub p50: f64,
+    /// 95th percentile.
+    pub p95: f64,
+}
+
+/// Result of Monte Carlo trade-order shuffling.
+///
+/// Equity is reconstructed as cumulative net PnL starting from zero, so final
+/// equity and max drawdown are in currency units rather than percentages.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct McResult {
+    /// Number of shuffled simulations.
+    pub n_sims: usize,
+    /// Number of trades in each simulation.
+    pub n_trades: usize,
+    /// Final equity of the actual trade ordering.
+    pub actual_final_equity: f64,
+    /// Max drawdown (peak-to-trough) of the actual trade ordering.
+    pub actual_max_drawdown: f64,
+    /// Distribution of final equity across shuffles.
+    pub final_equity: McPercentiles,
+    /// Distribution of max drawdown across shuffles.
+    pub max_drawdown: McPercentiles,
+    /// Fraction of shuffles with a deeper max drawdown than the actual ordering.
+    pub pct_worse_drawdown: f64,
+}
+
+/// Reshuffle closed-trade PnL to see how lucky the actual trade ordering was.
+///
+/// Each simulation permutes the net PnL sequence (preserving the trade count
+/// and PnL multiset), rebuilds the cumulative equity curve, and records its
+/// final equity and max drawdown.
+pub fn monte_carlo_trades(trades: &[Trade], n_sims: usize, seed: u64) -> McResult {
+    let mut pnls: Vec<f64> = trades.iter().map(|t| t.net_pnl).collect();
+    let (actual_final_equity, actual_max_drawdown) = pnl_path_stats(&pnls);
+
+    if pnls.is_empty() || n_sims == 0 {
+        return McResult {
+            n_sims,
+            n_trades: pnls.len(),
+            actual_final_equity,
+            actual_max_drawdown,
+            ..Default::default()
+        };
+    }
+
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut finals = Vec::with_capacity(n_sims);
+    let mut drawdowns = Vec::with_capacity(n_sims);
+
+    for _ in 0..n_sims {
+        pnls.shuffle(&mut rng);
+        let (final_equity, max_drawdown) = pnl_path_stats(&pnls);
+        finals.push(final_equity);
+        drawdowns.push(max_drawdown);
+    }
+
+    let n_worse = drawdowns
+        .iter()
+        .filter(|&&dd| dd > actual_max_drawdown)
+        .count();
+
+    finals.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    drawdowns.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    McResult {
+        n_sims,
+        n_trades: pnls.len(),
+        actual_final_equity,
+        actual_max_drawdown,
+        final_equity: mc_percentiles(&finals),
+        max_drawdown: mc_percentiles(&drawdowns),
+        pct_worse_drawdown: n_worse as f64 / n_sims as f64,
+    }
+}
+
+/// Final equity and max drawdown of a cumulative PnL path starting at zero.
+fn pnl_path_stats(pnls: &[f64]) -> (f64, f64) {
+    let mut equity = 0.0;
+    let mut peak = 0.0_f64;
+    let mut max_drawdown = 0.0_f64;
+    for &pnl in pnls {
+        equity += pnl;
+        peak = peak.max(equity);
+        max_drawdown = max_drawdown.max(peak - equity);
+    }
+    (equity, max_drawdown)
+}
+
+fn mc_percentiles(sorted: &[f64]) -> McPercentiles {
+    let pick = |p: f64| {
+        let idx = (p * (sorted.len() - 1) as f64).round() as usize;
+        sorted[idx.min(sorted.len() - 1)]
+    };
+    McPercentiles {
+        p5: pick(0.05),
+        p50: pick(0.50),
+        p95: pick(0.95),
+    }
+}
+
+// =============================================================================
+// WIN/LOSS STREAKS
+// =============================================================================
+
+/// Consecutive win/loss runs over a sequence of closed trades.
+///
+/// A win is `net_pnl > 0`; anything else (including breakeven) is a loss.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct StreakStats {
+    /// Longest run of consecutive winners.
+    pub longest_win_streak: usize,
+    /// Longest run of consecutive losers.
+    pub longest_loss_streak: usize,
+    /// Streak as of the last trade: positive for wins, negative for losses, 0 with no trades.
+    pub current_streak: i64,
+    /// Number of winning streaks of each length (including the current one).
+    pub win_streak_counts: BTreeMap<usize, usize>,
+    /// Number of losing streaks of each length (including the current one).
+    pub loss_streak_counts: BTreeMap<usize, usize>,
+}
+
+/// Compute win/loss streak statistics for trades in the order given.
+pub fn streak_stats(trades: &[Trade]) -> StreakStats {
+    let mut stats = StreakStats::default();
+
+    for (is_win, len) in streak_runs(trades) {
+        if is_win {
+            stats.longest_win_streak = stats.longest_win_streak.max(len);
+            *stats.win_streak_counts.entry(len).or_default() += 1;
+            stats.current_streak = len as i64;
+        } else {
+            stats.longest_loss_streak = stats.longest_loss_streak.max(len);
+            *stats.loss_streak_counts.entry(len).or_default() += 1;
+            stats.current_streak = -(len as i64);
+        }
+    }
+
+    stats
+}
+
+/// Run-length encode trades into (is_win, length) streaks.
+fn streak_runs(trades: &[Trade]) -> Vec<(bool, usize)> {
+    let mut runs: Vec<(bool, usize)> = Vec::new();
+    for trade in trades {
+        let is_win = trade.net_pnl > 0.0;
+        match runs.last_mut() {
+            Some((last, len)) if *last == is_win => *len += 1,
+            _ => runs.push((is_win, 1)),
+        }
+    }
+    runs
+}
+
+// =============================================================================
+// SEASONALITY
+// =============================================================================
+
+/// Closed-trade returns falling in one calendar bucket.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct SeasonalBucket {
+    /// Number of trades entered in this bucket.
+    pub count: usize,
+    /// Mean trade return (net PnL / entry notional); 0 when empty.
+    pub mean_return: f64,
+}
+
+/// Trade returns bucketed by the entry fill's month and weekday.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Seasonality {
+    /// Index 0 = January .. 11 = December.
+    pub by_month: [SeasonalBucket; 12],
+    /// Index 0 = Monday .. 6 = Sunday.
+    pub by_weekday: [SeasonalBucket; 7],
+}
+
+/// Bucket closed-trade returns by entry month and entry weekday (UTC).
+pub fn seasonality(trades: &[Trade]) -> Seasonality {
+    let mut month_sums = [0.0; 12];
+    let mut weekday_sums = [0.0; 7];
+    let mut result = Seasonality::default();
+
+    for trade in trades {
+        let notional = trade.entry.price * trade.entry.qty;
+        let ret = if notional.abs() > 0.0 {
+            trade.net_pnl / notional.abs()
+        } else {
+            0.0
+        };
+        let month = trade.entry.ts.month0() as usize;
+        let weekday = trade.entry.ts.weekday().num_days_from_monday() as usize;
+
+        month_sums[month] += ret;
+        result.by_month[month].count += 1;
+        weekday_sums[weekday] += ret;
+        result.by_weekday[weekday].count += 1;
+    }
+
+    for (bucket, sum) in result.by_month.iter_mut().zip(month_sums) {
+        if bucket.count > 0 {
+            bucket.mean_return = sum / bucket.count as f64;
+        }
+    }
+    for (bucket, sum) in result.by_weekday.iter_mut().zip(weekday_sums) {
+        if bucket.count > 0 {
+            bucket.mean_return = sum / bucket.count as f64;
+        }
+    }
+
+    result
+}
+
+/// Fraction below the running equity peak at each bar.
+///
+/// Values are 0 at new peaks and negative in drawdowns (-0.10 = 10% below peak).
+pub fn underwater_curve(equity: &[EquityPoint]) -> Vec<f64> {
+    let mut peak = f64::NEG_INFINITY;
+    equity
+        .iter()
+        .map(|point| {
+            peak = peak.max(point.equity);
+            if peak > 0.0 {
+                point.equity / peak - 1.0
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Compounded equity return over one calendar month.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct MonthlyReturn {
+    pub year: i32,
+    /// 1 = January .. 12 = December.
+    pub month: u32,
+    /// Fractional return (0.05 = +5%).
+    pub ret: f64,
+}
+
+/// Calendar-month returns of an equity curve (UTC), oldest first.
+///
+/// Each month runs from the previous month's last equity value (the first
+/// bar, for the first month) to its own last value. Months without bars
+/// are omitted.
+pub fn monthly_returns(equity: &[EquityPoint]) -> Vec<MonthlyReturn> {
+    let Some(first) = equity.first() else {
+        return Vec::new();
+    };
+
+    let mut month_end: BTreeMap<(i32, u32), f64> = BTreeMap::new();
+    for point in equity {
+        month_end.insert((point.ts.year(), point.ts.month()), point.equity);
+    }
+
+    let mut prev = first.equity;
+    month_end
+        .into_iter()
+        .map(|((year, month), end)| {
+            let ret = if prev > 0.0 { end / prev - 1.0 } else { 0.0 };
+            prev = end;
+            MonthlyReturn { year, month, ret }
+        })
+        .collect()
+}
+
+/// Rolling Pearson correlation between strategy and benchmark returns.
+///
+/// Both series are `(timestamp, return)` pairs and are aligned by timestamp.
+/// The output has one entry per strategy return: `None` where the benchmark
+/// has no return at that timestamp, before `window` aligned observations have
+/// accumulated, or when either side is flat over the window. Otherwise it is
+/// the correlation over the last `window` aligned observations.
+///
+/// A correlation drifting toward 1.0 means the strategy is behaving like
+/// buy-and-hold of the benchmark.
+pub fn rolling_correlation(
+    strategy_returns: &[(DateTime<Utc>, f64)],
+    benchmark_returns: &[(DateTime<Utc>, f64)],
+    window: usize,
+) -> Vec<Option<f64>> {
+    let benchmark_by_ts: HashMap<DateTime<Utc>, f64> = benchmark_returns.iter().copied().collect();
+
+    let mut aligned: Vec<(f64, f64)> = Vec::new();
+    strategy_returns
+        .iter()
+        .map(|(ts, s)| {
+            let b = *benchmark_by_ts.get(ts)?;
+            aligned.push((*s, b));
+            if window < 2 || aligned.len() < window {
+                return None;
+            }
+            pearson(&aligned[aligned.len() - window..])
+        })
+        .collect()
+}
+
+/// Pearson correlation of paired samples; `None` if either side has no variance.
+pub(crate) fn pearson(pairs: &[(f64, f64)]) -> Option<f64> {
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let mut cov = 0.0;
+    let mut var_x = 0.0;
+    let mut var_y = 0.0;
+    for (x, y) in pairs {
+        cov += (x - mean_x) * (y - mean_y);
+        var_x += (x - mean_x).powi(2);
+        var_y += (y - mean_y).powi(2);
+    }
+
+    if var_x <= 1e-20 || var_y <= 1e-20 {
+        return None;
+    }
+    Some((cov / (var_x * var_y).sqrt()).clamp(-1.0, 1.0))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -619,6 +991,7 @@ mod tests {
         assert_eq!(config.low_vol_threshold, 0.75);
         assert_eq!(config.var_levels, vec![0.95, 0.99]);
         assert_eq!(config.holding_buckets, vec![5, 10, 20, 50]);
+        assert_eq!(config.risk_per_trade, None);
     }
 
     #[test]
@@ -637,4 +1010,198 @@ mod tests {
         assert_ne!(high, neutral);
         assert_ne!(neutral, low);
     }
+
+    fn make_trade(day: i64, net_pnl: f64) -> Trade {
+        use crate::backtest::{ExitReason, Fill, Side, TradeDirection};
+        use chrono::TimeZone;
+
+        let ts = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::days(day);
+        let fill = |side| Fill {
+            ts,
+            side,
+            qty: 1.0,
+            price: 100.0,
+            fees: 0.0,
+            raw_price: 100.0,
+            atr_at_fill: None,
+        };
+        Trade {
+            entry: fill(Side::Buy),
+            exit: fill(Side::Sell),
+            gross_pnl: net_pnl,
+            net_pnl,
+            direction: TradeDirection::Long,
+            exit_reason: ExitReason::Signal,
+        }
+    }
+
+    #[test]
+    fn test_underwater_curve() {
+        use chrono::TimeZone;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let equity: Vec<EquityPoint> = [100.0, 110.0, 99.0, 105.0, 110.0, 115.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &equity)| EquityPoint {
+                ts: start + chrono::Duration::days(i as i64),
+                cash: equity,
+                position_qty: 0.0,
+                close: equity,
+                equity,
+            })
+            .collect();
+
+        let underwater = underwater_curve(&equity);
+        assert_eq!(underwater.len(), equity.len());
+        assert_eq!(underwater[0], 0.0);
+        assert_eq!(underwater[1], 0.0);
+        assert!((underwater[2] - -0.10).abs() < 1e-12);
+        assert!(underwater[3] < 0.0 && underwater[3] > -0.10);
+        assert_eq!(underwater[4], 0.0);
+        assert_eq!(underwater[5], 0.0);
+        assert!(underwater_curve(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_monte_carlo_trades_preserves_final_equity() {
+        // Losses clustered up front make the actual ordering look unlucky
+        let pnls = [
+            -50.0, -40.0, -30.0, 20.0, 80.0, 60.0, -10.0, 45.0, 35.0, -25.0,
+        ];
+        let trades: Vec<Trade> = pnls
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| make_trade(i as i64, p))
+            .collect();
+
+        let result = monte_carlo_trades(&trades, 1000, 42);
+
+        assert_eq!(result.n_sims, 1000);
+        assert_eq!(result.n_trades, 10);
+        assert!((result.actual_final_equity - 85.0).abs() < 1e-9);
+        assert!((result.actual_max_drawdown - 120.0).abs() < 1e-9);
+        // Shuffling preserves the PnL multiset, so every path ends at the same equity
+        assert!((result.final_equity.p50 - result.actual_final_equity).abs() < 1e-9);
+        assert!((result.final_equity.p5 - result.final_equity.p95).abs() < 1e-9);
+        // Drawdowns vary with ordering but never exceed the sum of losses
+        assert!(result.max_drawdown.p5 <= result.max_drawdown.p50);
+        assert!(result.max_drawdown.p50 <= result.max_drawdown.p95);
+        assert!(result.max_drawdown.p95 <= 155.0 + 1e-9);
+        assert!((0.0..=1.0).contains(&result.pct_worse_drawdown));
+    }
+
+    #[test]
+    fn test_monte_carlo_trades_empty() {
+        let result = monte_carlo_trades(&[], 100, 42);
+        assert_eq!(result.n_trades, 0);
+        assert_eq!(result.final_equity, McPercentiles::default());
+    }
+
+    #[test]
+    fn test_streak_stats_known_sequence() {
+        // W W L L L W
+        let pnls = [10.0, 5.0, -3.0, 0.0, -7.0, 12.0];
+        let trades: Vec<Trade> = pnls
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| make_trade(i as i64, p))
+            .collect();
+
+        let stats = streak_stats(&trades);
+        assert_eq!(stats.longest_win_streak, 2);
+        assert_eq!(stats.longest_loss_streak, 3);
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.win_streak_counts, BTreeMap::from([(1, 1), (2, 1)]));
+        assert_eq!(stats.loss_streak_counts, BTreeMap::from([(3, 1)]));
+
+        assert_eq!(streak_stats(&trades[..5]).current_streak, -3);
+        assert_eq!(streak_stats(&[]), StreakStats::default());
+    }
+
+    #[test]
+    fn test_seasonality_splits_january_winners_from_july_losers() {
+        // make_trade enters at 100 x 1 share, day 0 = Monday 2024-01-01
+        let mut trades: Vec<Trade> = (0..3).map(|d| make_trade(d, 10.0)).collect();
+        trades.extend((182..184).map(|d| make_trade(d, -5.0))); // July 1-2
+
+        let result = seasonality(&trades);
+
+        let january = result.by_month[0];
+        assert_eq!(january.count, 3);
+        assert!((january.mean_return - 0.10).abs() < 1e-12);
+        let july = result.by_month[6];
+        assert_eq!(july.count, 2);
+        assert!((july.mean_return + 0.05).abs() < 1e-12);
+        let others: usize = result.by_month.iter().map(|b| b.count).sum();
+        assert_eq!(others, 5);
+
+        // Jan 1 and Jul 1 2024 are both Mondays
+        assert_eq!(result.by_weekday[0].count, 2);
+        assert!((result.by_weekday[0].mean_return - 0.025).abs() < 1e-12);
+        assert_eq!(result.by_weekday[5].count, 0);
+    }
+
+    #[test]
+    fn test_monthly_returns_compound_month_ends() {
+        use chrono::TimeZone;
+
+        let point = |y, m, d, equity| EquityPoint {
+            ts: Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap(),
+            cash: equity,
+            position_qty: 0.0,
+            close: equity,
+            equity,
+        };
+        let equity = vec![
+            point(2023, 12, 29, 100.0),
+            point(2024, 1, 2, 90.0),
+            point(2024, 1, 31, 110.0),
+            point(2024, 2, 15, 120.0),
+            point(2024, 2, 29, 99.0),
+        ];
+
+        let months = monthly_returns(&equity);
+        assert_eq!(months.len(), 3);
+        // A single-bar first month has nothing to compound
+        assert_eq!(
+            (months[0].year, months[0].month, months[0].ret),
+            (2023, 12, 0.0)
+        );
+        assert_eq!((months[1].year, months[1].month), (2024, 1));
+        assert!((months[1].ret - 0.10).abs() < 1e-12);
+        assert!((months[2].ret + 0.10).abs() < 1e-12);
+        assert!(monthly_returns(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_rolling_correlation_with_itself_is_one() {
+        use chrono::TimeZone;
+
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let returns: Vec<(DateTime<Utc>, f64)> = (0..30)
+            .map(|i| {
+                let ts = start + chrono::Duration::days(i);
+                (ts, 0.01 * ((i * 7 % 11) as f64 - 5.0) / 5.0)
+            })
+            .collect();
+
+        let corr = rolling_correlation(&returns, &returns, 10);
+        assert_eq!(corr.len(), returns.len());
+        assert!(corr[..9].iter().all(|c| c.is_none()));
+        assert!(corr[9..]
+            .iter()
+            .all(|c| c.is_some_and(|c| (c - 1.0).abs() < 1e-9)));
+
+        // Inverted benchmark with a missing day: aligned by timestamp, not index
+        let inverted: Vec<(DateTime<Utc>, f64)> = returns
+            .iter()
+            .filter(|(ts, _)| *ts != start + chrono::Duration::days(3))
+            .map(|&(ts, r)| (ts, -r))
+            .collect();
+        let corr = rolling_correlation(&returns, &inverted, 10);
+        assert_eq!(corr[3], None);
+        assert_eq!(corr[9], None);
+        assert!((corr[10].unwrap() + 1.0).abs() < 1e-9);
+    }
 }
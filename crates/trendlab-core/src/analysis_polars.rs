@@ -3,13 +3,13 @@
 //! Implements vectorized computation for:
 //! - Return distribution metrics (VaR, CVaR, skewness, kurtosis)
 //! - Regime-based performance analysis
-//! - Trade-level statistics (MAE, MFE, holding period)
+//! - Trade-level statistics (MAE, MFE, holding period, R-multiples)
 
 use crate::analysis::{
     AnalysisConfig, DrawdownRegime, DrawdownRegimeAnalysis, DrawdownThresholds, EdgeRatioStats,
-    ExcursionStats, HoldingBucket, HoldingPeriodStats, RegimeAnalysis, RegimeConcentrationScore,
-    RegimeMetrics, ReturnDistribution, StatisticalAnalysis, TradeAnalysis, TradeExcursion,
-    TrendRegime, TrendRegimeAnalysis, VolAtEntryStats, VolRegime,
+    ExcursionStats, HoldingBucket, HoldingPeriodStats, RMultipleStats, RegimeAnalysis,
+    RegimeConcentrationScore, RegimeMetrics, ReturnDistribution, StatisticalAnalysis,
+    TradeAnalysis, TradeExcursion, TrendRegime, TrendRegimeAnalysis, VolAtEntryStats, VolRegime,
 };
 use crate::backtest::{BacktestResult, EquityPoint, Trade};
 use crate::bar::Bar;
@@ -887,6 +887,7 @@ pub fn compute_trade_analysis(
     let mfe = compute_excursion_stats(&excursions, |e| e.mfe_pct);
     let edge_ratio = compute_edge_ratio_stats(&excursions);
     let vol_at_entry = compute_vol_at_entry_stats(&excursions);
+    let r_multiples = compute_r_multiple_stats(&excursions, config.risk_per_trade);
 
     Ok(TradeAnalysis {
         holding_period,
@@ -894,10 +895,71 @@ pub fn compute_trade_analysis(
         mfe,
         edge_ratio,
         vol_at_entry,
+        r_multiples,
         n_trades: excursions.len(),
     })
 }
 
+/// Compute R-multiple statistics.
+///
+/// Risk is `risk_per_trade` (fraction of entry price) when set, otherwise each
+/// trade's MAE; trades with zero MAE have no defined risk and are skipped.
+fn compute_r_multiple_stats(
+    excursions: &[TradeExcursion],
+    risk_per_trade: Option<f64>,
+) -> RMultipleStats {
+    let mut n_risk_from_mae = 0;
+    let values: Vec<f64> = excursions
+        .iter()
+        .filter_map(|e| {
+            let risk = match risk_per_trade {
+                Some(risk) => risk,
+                None => {
+                    n_risk_from_mae += 1;
+                    e.mae_pct
+                }
+            };
+            (risk > 0.0).then_some(e.return_pct / risk)
+        })
+        .collect();
+
+    if values.is_empty() {
+        return RMultipleStats::default();
+    }
+
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+
+    let mut sorted = values.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let median = percentile(&sorted, 0.5);
+
+    let wins: Vec<f64> = values.iter().copied().filter(|r| *r > 0.0).collect();
+    let losses: Vec<f64> = values.iter().copied().filter(|r| *r <= 0.0).collect();
+    let avg = |rs: &[f64]| {
+        if rs.is_empty() {
+            0.0
+        } else {
+            rs.iter().sum::<f64>() / rs.len() as f64
+        }
+    };
+    let avg_win = avg(&wins);
+    let avg_loss = avg(&losses);
+    let win_rate = wins.len() as f64 / n;
+    let expectancy = win_rate * avg_win + (1.0 - win_rate) * avg_loss;
+
+    RMultipleStats {
+        pct_above_1r: values.iter().filter(|r| **r > 1.0).count() as f64 / n,
+        values,
+        mean,
+        median,
+        avg_win,
+        avg_loss,
+        expectancy,
+        n_risk_from_mae,
+    }
+}
+
 /// Compute ATR series for all bars.
 fn compute_atr_series(bars: &[Bar], period: usize) -> Vec<f64> {
     if bars.len() < 2 {
@@ -1286,6 +1348,91 @@ mod tests {
         assert!((corr - (-1.0)).abs() < 0.001);
     }
 
+    // =========================================================================
+    // R-MULTIPLE TESTS
+    // =========================================================================
+
+    fn r_multiple_fixture() -> (Vec<Trade>, Vec<Bar>) {
+        use crate::backtest::{ExitReason, Fill, Side, TradeDirection};
+        use chrono::TimeZone;
+
+        // Flat 100 closes; lows set each trade's MAE
+        let lows = [100.0, 95.0, 100.0, 100.0, 96.0, 95.0, 100.0, 100.0, 100.0];
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let bars: Vec<Bar> = lows
+            .iter()
+            .enumerate()
+            .map(|(i, &low)| {
+                let ts = start + chrono::Duration::days(i as i64);
+                Bar::new(ts, 100.0, 110.0, low, 100.0, 1000.0, "TEST", "1d")
+            })
+            .collect();
+
+        let fill = |day: usize, side| Fill {
+            ts: bars[day].ts,
+            side,
+            qty: 1.0,
+            price: 100.0,
+            fees: 0.0,
+            raw_price: 100.0,
+            atr_at_fill: None,
+        };
+        // (entry day, exit day, net PnL): +10%, -5%, +2.5%
+        let trades = [(0, 2, 10.0), (3, 5, -5.0), (6, 8, 2.5)]
+            .iter()
+            .map(|&(entry, exit, net_pnl)| Trade {
+                entry: fill(entry, Side::Buy),
+                exit: fill(exit, Side::Sell),
+                gross_pnl: net_pnl,
+                net_pnl,
+                direction: TradeDirection::Long,
+                exit_reason: ExitReason::Signal,
+            })
+            .collect();
+
+        (trades, bars)
+    }
+
+    #[test]
+    fn test_r_multiples_with_known_stop() {
+        let (trades, bars) = r_multiple_fixture();
+        let config = AnalysisConfig {
+            risk_per_trade: Some(0.05),
+            ..Default::default()
+        };
+
+        let r = compute_trade_analysis(&trades, &bars, &config)
+            .unwrap()
+            .r_multiples;
+
+        let expected = [2.0, -1.0, 0.5];
+        assert_eq!(r.values.len(), 3);
+        for (value, expected) in r.values.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-9, "{} vs {}", value, expected);
+        }
+        assert!((r.mean - 0.5).abs() < 1e-9);
+        assert!((r.median - 0.5).abs() < 1e-9);
+        assert!((r.expectancy - r.mean).abs() < 1e-9);
+        assert!((r.avg_win - 1.25).abs() < 1e-9);
+        assert!((r.avg_loss + 1.0).abs() < 1e-9);
+        assert!((r.pct_above_1r - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(r.n_risk_from_mae, 0);
+    }
+
+    #[test]
+    fn test_r_multiples_fall_back_to_mae() {
+        let (trades, bars) = r_multiple_fixture();
+        let r = compute_trade_analysis(&trades, &bars, &AnalysisConfig::default())
+            .unwrap()
+            .r_multiples;
+
+        // MAE is 5% for the first two trades; the third never dips below entry
+        assert_eq!(r.n_risk_from_mae, 3);
+        assert_eq!(r.values.len(), 2);
+        assert!((r.values[0] - 2.0).abs() < 1e-9);
+        assert!((r.values[1] + 1.0).abs() < 1e-9);
+    }
+
     // =========================================================================
     // DRAWDOWN REGIME TESTS
     // =========================================================================
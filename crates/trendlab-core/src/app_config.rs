@@ -0,0 +1,216 @@
+//! Application defaults loaded from `trendlab.toml`.
+//!
+//! Provides:
+//! - Default starting capital, cost model and fill model for TUI/CLI backtests
+//! - TOML file loading with environment variable overrides
+//!
+//! Precedence is environment variables, then `trendlab.toml`, then the
+//! built-in defaults ($100k, 10 bps fees, 5 bps slippage, next-open fills).
+//!
+//! ```toml
+//! [backtest]
+//! initial_cash = 250000.0
+//! fees_bps_per_side = 2.0
+//! slippage_bps = 1.0
+//! fill_model = "Vwap"
+//! ```
+
+use crate::backtest::{BacktestConfig, CostModel, FillModel};
+use crate::backtest_polars::PolarsBacktestConfig;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Default config file name, looked up in the working directory.
+pub const APP_CONFIG_FILE: &str = "trendlab.toml";
+
+/// Errors that can occur when loading the application config.
+#[derive(Debug, Error)]
+pub enum AppConfigError {
+    #[error("Failed to read config file: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Failed to parse config TOML: {0}")]
+    ParseError(#[from] toml::de::Error),
+}
+
+/// Backtest defaults shared by the TUI, GUI and CLI.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BacktestDefaults {
+    pub initial_cash: f64,
+    /// Fixed position size used by sweeps.
+    pub qty: f64,
+    pub fees_bps_per_side: f64,
+    pub slippage_bps: f64,
+    pub fill_model: FillModel,
+}
+
+impl Default for BacktestDefaults {
+    fn default() -> Self {
+        Self {
+            initial_cash: 100_000.0,
+            qty: 100.0,
+            fees_bps_per_side: 10.0,
+            slippage_bps: 5.0,
+            fill_model: FillModel::NextOpen,
+        }
+    }
+}
+
+/// Application configuration (`trendlab.toml`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    pub backtest: BacktestDefaults,
+}
+
+impl AppConfig {
+    /// Load the config the way the applications do.
+    ///
+    /// Reads `TRENDLAB_CONFIG` (or `trendlab.toml` in the working directory) if
+    /// it exists, then applies environment overrides:
+    /// - `TRENDLAB_INITIAL_CASH`
+    /// - `TRENDLAB_FEES_BPS`
+    /// - `TRENDLAB_SLIPPAGE_BPS`
+    /// - `TRENDLAB_FILL_MODEL` ("next_open" or "vwap")
+    ///
+    /// A missing file yields the built-in defaults; an unreadable one is
+    /// logged as a warning and ignored.
+    pub fn load() -> Self {
+        let path = std::env::var("TRENDLAB_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from(APP_CONFIG_FILE));
+
+        let mut config = if path.exists() {
+            Self::from_file(&path).unwrap_or_else(|e| {
+                tracing::warn!("Ignoring config file {}: {}", path.display(), e);
+                Self::default()
+            })
+        } else {
+            Self::default()
+        };
+        config.apply_overrides(|var| std::env::var(var).ok());
+        config
+    }
+
+    /// Load config from a TOML file, without environment overrides.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, AppConfigError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_toml(&content)
+    }
+
+    /// Parse config from a TOML string. Missing keys keep their defaults.
+    pub fn from_toml(content: &str) -> Result<Self, AppConfigError> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Apply overrides from `lookup` (the environment, in [`AppConfig::load`]).
+    ///
+    /// Unparseable values are logged as warnings and skipped.
+    fn apply_overrides(&mut self, lookup: impl Fn(&str) -> Option<String>) {
+        let number = |var: &str| -> Option<f64> {
+            let value = lookup(var)?;
+            match value.trim().parse() {
+                Ok(v) => Some(v),
+                Err(_) => {
+                    tracing::warn!("Ignoring {}={:?}: not a number", var, value);
+                    None
+                }
+            }
+        };
+
+        let backtest = &mut self.backtest;
+        if let Some(v) = number("TRENDLAB_INITIAL_CASH") {
+            backtest.initial_cash = v;
+        }
+        if let Some(v) = number("TRENDLAB_FEES_BPS") {
+            backtest.fees_bps_per_side = v;
+        }
+        if let Some(v) = number("TRENDLAB_SLIPPAGE_BPS") {
+            backtest.slippage_bps = v;
+        }
+        if let Some(value) = lookup("TRENDLAB_FILL_MODEL") {
+            match value.trim().to_lowercase().as_str() {
+                "next_open" | "nextopen" => backtest.fill_model = FillModel::NextOpen,
+                "vwap" => backtest.fill_model = FillModel::Vwap,
+                _ => tracing::warn!(
+                    "Ignoring TRENDLAB_FILL_MODEL={:?}: expected next_open or vwap",
+                    value
+                ),
+            }
+        }
+    }
+
+    /// Cost model built from the configured fees and slippage.
+    pub fn cost_model(&self) -> CostModel {
+        CostModel {
+            fees_bps_per_side: self.backtest.fees_bps_per_side,
+            slippage_bps: self.backtest.slippage_bps,
+            ..Default::default()
+        }
+    }
+
+    /// Sequential backtest config with the configured defaults.
+    pub fn backtest_config(&self) -> BacktestConfig {
+        BacktestConfig {
+            initial_cash: self.backtest.initial_cash,
+            fill_model: self.backtest.fill_model,
+            cost_model: self.cost_model(),
+            qty: self.backtest.qty,
+            ..Default::default()
+        }
+    }
+
+    /// Polars backtest config with the configured defaults.
+    pub fn polars_config(&self) -> PolarsBacktestConfig {
+        PolarsBacktestConfig::new(self.backtest.initial_cash, self.backtest.qty)
+            .with_cost_model(self.cost_model())
+            .with_fill_model(self.backtest.fill_model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_app_config_from_toml() {
+        let toml = r#"
+[backtest]
+initial_cash = 250000.0
+fees_bps_per_side = 2.5
+fill_model = "Vwap"
+"#;
+        let config = AppConfig::from_toml(toml).unwrap();
+        let bt = config.backtest_config();
+
+        assert_eq!(bt.initial_cash, 250_000.0);
+        assert_eq!(bt.cost_model.fees_bps_per_side, 2.5);
+        assert_eq!(bt.fill_model, FillModel::Vwap);
+        // Unset keys keep the built-in defaults
+        assert_eq!(bt.cost_model.slippage_bps, 5.0);
+        assert_eq!(bt.qty, 100.0);
+
+        assert_eq!(AppConfig::from_toml("").unwrap(), AppConfig::default());
+        assert!(AppConfig::from_toml("[backtest]\ninitial_cash = \"lots\"").is_err());
+    }
+
+    #[test]
+    fn test_app_config_env_overrides_file() {
+        let mut config = AppConfig::from_toml("[backtest]\nfees_bps_per_side = 2.5").unwrap();
+        let env: HashMap<&str, &str> = [
+            ("TRENDLAB_FEES_BPS", "1.0"),
+            ("TRENDLAB_SLIPPAGE_BPS", "oops"),
+            ("TRENDLAB_FILL_MODEL", "vwap"),
+        ]
+        .into_iter()
+        .collect();
+        config.apply_overrides(|var| env.get(var).map(|v| v.to_string()));
+
+        assert_eq!(config.backtest.fees_bps_per_side, 1.0);
+        assert_eq!(config.backtest.slippage_bps, 5.0);
+        assert_eq!(config.backtest.fill_model, FillModel::Vwap);
+    }
+}
@@ -11,11 +11,11 @@ use crate::strategy::{
     DonchianBreakoutStrategy, FiftyTwoWeekHighStrategy, ParabolicSARStrategy, Position, Signal,
     Strategy, SupertrendStrategy,
 };
-use crate::sweep::StrategyConfigId;
+use crate::sweep::{MultiStrategySweepResult, StrategyConfigId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
 
 /// Current schema version for StrategyArtifact.
 pub const SCHEMA_VERSION: &str = "1.0.0";
@@ -280,6 +280,7 @@ impl ArtifactBuilder {
     pub fn fill_model(mut self, fm: FillModel) -> Self {
         self.fill_model = Some(match fm {
             FillModel::NextOpen => "NextOpen".to_string(),
+            FillModel::Vwap => "Vwap".to_string(),
         });
         self
     }
@@ -381,6 +382,8 @@ pub enum ArtifactError {
     MissingField(&'static str),
     InvalidData(String),
     UnsupportedStrategy(String),
+    UnsupportedIndicator(String),
+    Io(String),
 }
 
 impl std::fmt::Display for ArtifactError {
@@ -391,12 +394,249 @@ impl std::fmt::Display for ArtifactError {
             ArtifactError::UnsupportedStrategy(s) => {
                 write!(f, "Strategy does not support artifact export yet: {}", s)
             }
+            ArtifactError::UnsupportedIndicator(s) => {
+                write!(f, "Indicator type has no Pine mapping yet: {}", s)
+            }
+            ArtifactError::Io(msg) => write!(f, "IO error: {}", msg),
         }
     }
 }
 
 impl std::error::Error for ArtifactError {}
 
+/// The strategy-specific parts of an artifact, independent of any bar data.
+struct ArtifactSpec {
+    strategy_id: &'static str,
+    indicators: Vec<IndicatorDef>,
+    rules: Rules,
+    parameters: HashMap<String, ParamValue>,
+}
+
+/// Indicators, rules and parameters for Donchian breakout.
+fn donchian_spec(entry_lookback: usize, exit_lookback: usize) -> ArtifactSpec {
+    // Build indicator definitions
+    let indicators = vec![
+        IndicatorDef {
+            id: "donchian_entry".to_string(),
+            indicator_type: "donchian".to_string(),
+            params: {
+                let mut p = HashMap::new();
+                p.insert("lookback".to_string(), ParamValue::from(entry_lookback));
+                p
+            },
+            pine_expr: Some(format!(
+                "ta.highest(high[1], {}) / ta.lowest(low[1], {})",
+                entry_lookback, entry_lookback
+            )),
+        },
+        IndicatorDef {
+            id: "donchian_exit".to_string(),
+            indicator_type: "donchian".to_string(),
+            params: {
+                let mut p = HashMap::new();
+                p.insert("lookback".to_string(), ParamValue::from(exit_lookback));
+                p
+            },
+            pine_expr: Some(format!(
+                "ta.highest(high[1], {}) / ta.lowest(low[1], {})",
+                exit_lookback, exit_lookback
+            )),
+        },
+    ];
+
+    // Build rules
+    let rules = Rules {
+        entry: Rule {
+            condition: "close > donchian_entry.upper".to_string(),
+            pine_condition: format!("close > ta.highest(high[1], {})", entry_lookback),
+            position_required: Some("flat".to_string()),
+        },
+        exit: Rule {
+            condition: "close < donchian_exit.lower".to_string(),
+            pine_condition: format!("close < ta.lowest(low[1], {})", exit_lookback),
+            position_required: Some("long".to_string()),
+        },
+    };
+
+    // Build parameters
+    let mut parameters = HashMap::new();
+    parameters.insert(
+        "entry_lookback".to_string(),
+        ParamValue::from(entry_lookback),
+    );
+    parameters.insert("exit_lookback".to_string(), ParamValue::from(exit_lookback));
+
+    ArtifactSpec {
+        strategy_id: "donchian_breakout",
+        indicators,
+        rules,
+        parameters,
+    }
+}
+
+/// Indicators, rules and parameters for 52-week high breakout.
+fn fifty_two_week_high_spec(period: usize, entry_pct: f64, exit_pct: f64) -> ArtifactSpec {
+    // Build indicator definitions
+    let indicators = vec![IndicatorDef {
+        id: "period_channel".to_string(),
+        indicator_type: "highest_lowest".to_string(),
+        params: {
+            let mut p = HashMap::new();
+            p.insert("period".to_string(), ParamValue::from(period));
+            p
+        },
+        pine_expr: Some(format!(
+            "ta.highest(high[1], {}) / ta.lowest(low[1], {})",
+            period, period
+        )),
+    }];
+
+    // Build rules
+    let rules = Rules {
+        entry: Rule {
+            condition: format!(
+                "close > highest_high * {:.2} (within {}% of {}-day high)",
+                entry_pct / 100.0,
+                100.0 - entry_pct,
+                period
+            ),
+            pine_condition: format!(
+                "close >= ta.highest(high[1], {}) * {}",
+                period,
+                entry_pct / 100.0
+            ),
+            position_required: Some("flat".to_string()),
+        },
+        exit: Rule {
+            condition: format!(
+                "close < highest_high * {:.2} (dropped {}% from {}-day high)",
+                exit_pct / 100.0,
+                100.0 - exit_pct,
+                period
+            ),
+            pine_condition: format!(
+                "close < ta.highest(high[1], {}) * {}",
+                period,
+                exit_pct / 100.0
+            ),
+            position_required: Some("long".to_string()),
+        },
+    };
+
+    // Build parameters
+    let mut parameters = HashMap::new();
+    parameters.insert("period".to_string(), ParamValue::from(period));
+    parameters.insert("entry_pct".to_string(), ParamValue::from(entry_pct));
+    parameters.insert("exit_pct".to_string(), ParamValue::from(exit_pct));
+
+    ArtifactSpec {
+        strategy_id: "52wk_high",
+        indicators,
+        rules,
+        parameters,
+    }
+}
+
+/// Indicators, rules and parameters for Supertrend.
+fn supertrend_spec(atr_period: usize, multiplier: f64) -> ArtifactSpec {
+    // Build indicator definitions
+    let indicators = vec![IndicatorDef {
+        id: "supertrend".to_string(),
+        indicator_type: "supertrend".to_string(),
+        params: {
+            let mut p = HashMap::new();
+            p.insert("atr_period".to_string(), ParamValue::from(atr_period));
+            p.insert("multiplier".to_string(), ParamValue::from(multiplier));
+            p
+        },
+        pine_expr: Some(format!("ta.supertrend({}, {})", multiplier, atr_period)),
+    }];
+
+    // Build rules
+    let rules = Rules {
+        entry: Rule {
+            condition: "Supertrend flips to uptrend (close crosses above supertrend line)"
+                .to_string(),
+            pine_condition: format!(
+                "[supertrend, direction] = ta.supertrend({}, {})\nta.crossover(close, supertrend)",
+                multiplier, atr_period
+            ),
+            position_required: Some("flat".to_string()),
+        },
+        exit: Rule {
+            condition: "Supertrend flips to downtrend (close crosses below supertrend line)"
+                .to_string(),
+            pine_condition: format!(
+                "[supertrend, direction] = ta.supertrend({}, {})\nta.crossunder(close, supertrend)",
+                multiplier, atr_period
+            ),
+            position_required: Some("long".to_string()),
+        },
+    };
+
+    // Build parameters
+    let mut parameters = HashMap::new();
+    parameters.insert("atr_period".to_string(), ParamValue::from(atr_period));
+    parameters.insert("multiplier".to_string(), ParamValue::from(multiplier));
+
+    ArtifactSpec {
+        strategy_id: "supertrend",
+        indicators,
+        rules,
+        parameters,
+    }
+}
+
+/// Indicators, rules and parameters for Parabolic SAR.
+fn parabolic_sar_spec(af_start: f64, af_step: f64, af_max: f64) -> ArtifactSpec {
+    // Build indicator definitions
+    let indicators = vec![IndicatorDef {
+        id: "parabolic_sar".to_string(),
+        indicator_type: "parabolic_sar".to_string(),
+        params: {
+            let mut p = HashMap::new();
+            p.insert("af_start".to_string(), ParamValue::from(af_start));
+            p.insert("af_step".to_string(), ParamValue::from(af_step));
+            p.insert("af_max".to_string(), ParamValue::from(af_max));
+            p
+        },
+        pine_expr: Some(format!("ta.sar({}, {}, {})", af_start, af_step, af_max)),
+    }];
+
+    // Build rules
+    let rules = Rules {
+        entry: Rule {
+            condition: "SAR flips below price (uptrend begins)".to_string(),
+            pine_condition: format!(
+                "sar = ta.sar({}, {}, {})\nta.crossover(close, sar)",
+                af_start, af_step, af_max
+            ),
+            position_required: Some("flat".to_string()),
+        },
+        exit: Rule {
+            condition: "SAR flips above price (downtrend begins)".to_string(),
+            pine_condition: format!(
+                "sar = ta.sar({}, {}, {})\nta.crossunder(close, sar)",
+                af_start, af_step, af_max
+            ),
+            position_required: Some("long".to_string()),
+        },
+    };
+
+    // Build parameters
+    let mut parameters = HashMap::new();
+    parameters.insert("af_start".to_string(), ParamValue::from(af_start));
+    parameters.insert("af_step".to_string(), ParamValue::from(af_step));
+    parameters.insert("af_max".to_string(), ParamValue::from(af_max));
+
+    ArtifactSpec {
+        strategy_id: "parabolic_sar",
+        indicators,
+        rules,
+        parameters,
+    }
+}
+
 /// Create artifact for Donchian breakout strategy.
 pub fn create_donchian_artifact(
     bars: &[Bar],
@@ -485,57 +725,12 @@ pub fn create_donchian_artifact(
         });
     }
 
-    // Build indicator definitions
-    let indicators = vec![
-        IndicatorDef {
-            id: "donchian_entry".to_string(),
-            indicator_type: "donchian".to_string(),
-            params: {
-                let mut p = HashMap::new();
-                p.insert("lookback".to_string(), ParamValue::from(entry_lookback));
-                p
-            },
-            pine_expr: Some(format!(
-                "ta.highest(high[1], {}) / ta.lowest(low[1], {})",
-                entry_lookback, entry_lookback
-            )),
-        },
-        IndicatorDef {
-            id: "donchian_exit".to_string(),
-            indicator_type: "donchian".to_string(),
-            params: {
-                let mut p = HashMap::new();
-                p.insert("lookback".to_string(), ParamValue::from(exit_lookback));
-                p
-            },
-            pine_expr: Some(format!(
-                "ta.highest(high[1], {}) / ta.lowest(low[1], {})",
-                exit_lookback, exit_lookback
-            )),
-        },
-    ];
-
-    // Build rules
-    let rules = Rules {
-        entry: Rule {
-            condition: "close > donchian_entry.upper".to_string(),
-            pine_condition: format!("close > ta.highest(high[1], {})", entry_lookback),
-            position_required: Some("flat".to_string()),
-        },
-        exit: Rule {
-            condition: "close < donchian_exit.lower".to_string(),
-            pine_condition: format!("close < ta.lowest(low[1], {})", exit_lookback),
-            position_required: Some("long".to_string()),
-        },
-    };
-
-    // Build parameters
-    let mut parameters = HashMap::new();
-    parameters.insert(
-        "entry_lookback".to_string(),
-        ParamValue::from(entry_lookback),
-    );
-    parameters.insert("exit_lookback".to_string(), ParamValue::from(exit_lookback));
+    let ArtifactSpec {
+        indicators,
+        rules,
+        parameters,
+        ..
+    } = donchian_spec(entry_lookback, exit_lookback);
 
     Ok(StrategyArtifact {
         schema_version: SCHEMA_VERSION.to_string(),
@@ -642,61 +837,15 @@ pub fn create_52wk_high_artifact(
             },
             signal: signal_str,
             position_after: Some(position_str.to_string()),
-        });
-    }
-
-    // Build indicator definitions
-    let indicators = vec![IndicatorDef {
-        id: "period_channel".to_string(),
-        indicator_type: "highest_lowest".to_string(),
-        params: {
-            let mut p = HashMap::new();
-            p.insert("period".to_string(), ParamValue::from(period));
-            p
-        },
-        pine_expr: Some(format!(
-            "ta.highest(high[1], {}) / ta.lowest(low[1], {})",
-            period, period
-        )),
-    }];
-
-    // Build rules
-    let rules = Rules {
-        entry: Rule {
-            condition: format!(
-                "close > highest_high * {:.2} (within {}% of {}-day high)",
-                entry_pct / 100.0,
-                100.0 - entry_pct,
-                period
-            ),
-            pine_condition: format!(
-                "close >= ta.highest(high[1], {}) * {}",
-                period,
-                entry_pct / 100.0
-            ),
-            position_required: Some("flat".to_string()),
-        },
-        exit: Rule {
-            condition: format!(
-                "close < highest_high * {:.2} (dropped {}% from {}-day high)",
-                exit_pct / 100.0,
-                100.0 - exit_pct,
-                period
-            ),
-            pine_condition: format!(
-                "close < ta.highest(high[1], {}) * {}",
-                period,
-                exit_pct / 100.0
-            ),
-            position_required: Some("long".to_string()),
-        },
-    };
+        });
+    }
 
-    // Build parameters
-    let mut parameters = HashMap::new();
-    parameters.insert("period".to_string(), ParamValue::from(period));
-    parameters.insert("entry_pct".to_string(), ParamValue::from(entry_pct));
-    parameters.insert("exit_pct".to_string(), ParamValue::from(exit_pct));
+    let ArtifactSpec {
+        indicators,
+        rules,
+        parameters,
+        ..
+    } = fifty_two_week_high_spec(period, entry_pct, exit_pct);
 
     Ok(StrategyArtifact {
         schema_version: SCHEMA_VERSION.to_string(),
@@ -806,45 +955,12 @@ pub fn create_supertrend_artifact(
         });
     }
 
-    // Build indicator definitions
-    let indicators = vec![IndicatorDef {
-        id: "supertrend".to_string(),
-        indicator_type: "supertrend".to_string(),
-        params: {
-            let mut p = HashMap::new();
-            p.insert("atr_period".to_string(), ParamValue::from(atr_period));
-            p.insert("multiplier".to_string(), ParamValue::from(multiplier));
-            p
-        },
-        pine_expr: Some(format!("ta.supertrend({}, {})", multiplier, atr_period)),
-    }];
-
-    // Build rules
-    let rules = Rules {
-        entry: Rule {
-            condition: "Supertrend flips to uptrend (close crosses above supertrend line)"
-                .to_string(),
-            pine_condition: format!(
-                "[supertrend, direction] = ta.supertrend({}, {})\nta.crossover(close, supertrend)",
-                multiplier, atr_period
-            ),
-            position_required: Some("flat".to_string()),
-        },
-        exit: Rule {
-            condition: "Supertrend flips to downtrend (close crosses below supertrend line)"
-                .to_string(),
-            pine_condition: format!(
-                "[supertrend, direction] = ta.supertrend({}, {})\nta.crossunder(close, supertrend)",
-                multiplier, atr_period
-            ),
-            position_required: Some("long".to_string()),
-        },
-    };
-
-    // Build parameters
-    let mut parameters = HashMap::new();
-    parameters.insert("atr_period".to_string(), ParamValue::from(atr_period));
-    parameters.insert("multiplier".to_string(), ParamValue::from(multiplier));
+    let ArtifactSpec {
+        indicators,
+        rules,
+        parameters,
+        ..
+    } = supertrend_spec(atr_period, multiplier);
 
     Ok(StrategyArtifact {
         schema_version: SCHEMA_VERSION.to_string(),
@@ -954,45 +1070,12 @@ pub fn create_parabolic_sar_artifact(
         });
     }
 
-    // Build indicator definitions
-    let indicators = vec![IndicatorDef {
-        id: "parabolic_sar".to_string(),
-        indicator_type: "parabolic_sar".to_string(),
-        params: {
-            let mut p = HashMap::new();
-            p.insert("af_start".to_string(), ParamValue::from(af_start));
-            p.insert("af_step".to_string(), ParamValue::from(af_step));
-            p.insert("af_max".to_string(), ParamValue::from(af_max));
-            p
-        },
-        pine_expr: Some(format!("ta.sar({}, {}, {})", af_start, af_step, af_max)),
-    }];
-
-    // Build rules
-    let rules = Rules {
-        entry: Rule {
-            condition: "SAR flips below price (uptrend begins)".to_string(),
-            pine_condition: format!(
-                "sar = ta.sar({}, {}, {})\nta.crossover(close, sar)",
-                af_start, af_step, af_max
-            ),
-            position_required: Some("flat".to_string()),
-        },
-        exit: Rule {
-            condition: "SAR flips above price (downtrend begins)".to_string(),
-            pine_condition: format!(
-                "sar = ta.sar({}, {}, {})\nta.crossunder(close, sar)",
-                af_start, af_step, af_max
-            ),
-            position_required: Some("long".to_string()),
-        },
-    };
-
-    // Build parameters
-    let mut parameters = HashMap::new();
-    parameters.insert("af_start".to_string(), ParamValue::from(af_start));
-    parameters.insert("af_step".to_string(), ParamValue::from(af_step));
-    parameters.insert("af_max".to_string(), ParamValue::from(af_max));
+    let ArtifactSpec {
+        indicators,
+        rules,
+        parameters,
+        ..
+    } = parabolic_sar_spec(af_start, af_step, af_max);
 
     Ok(StrategyArtifact {
         schema_version: SCHEMA_VERSION.to_string(),
@@ -1087,6 +1170,123 @@ pub fn create_artifact_from_config(
     }
 }
 
+/// Artifact spec for a config, mirroring [`create_artifact_from_config`].
+fn spec_from_config(config: &StrategyConfigId) -> Result<ArtifactSpec, ArtifactError> {
+    match config {
+        StrategyConfigId::Donchian {
+            entry_lookback,
+            exit_lookback,
+        } => Ok(donchian_spec(*entry_lookback, *exit_lookback)),
+        StrategyConfigId::TurtleS1 => Ok(donchian_spec(20, 10)),
+        StrategyConfigId::TurtleS2 => Ok(donchian_spec(55, 20)),
+        StrategyConfigId::FiftyTwoWeekHigh {
+            period,
+            entry_pct,
+            exit_pct,
+        } => Ok(fifty_two_week_high_spec(*period, *entry_pct, *exit_pct)),
+        StrategyConfigId::Supertrend {
+            atr_period,
+            multiplier,
+        } => Ok(supertrend_spec(*atr_period, *multiplier)),
+        StrategyConfigId::ParabolicSar {
+            af_start,
+            af_step,
+            af_max,
+        } => Ok(parabolic_sar_spec(*af_start, *af_step, *af_max)),
+        _ => Err(ArtifactError::UnsupportedStrategy(format!(
+            "{:?}",
+            config.strategy_type()
+        ))),
+    }
+}
+
+/// Outcome of [`export_bundle`].
+#[derive(Debug, Clone, Default)]
+pub struct BundleExport {
+    /// Artifact files written, one per exported strategy
+    pub paths: Vec<PathBuf>,
+    /// Strategies skipped because artifact export isn't implemented for them
+    pub skipped: Vec<String>,
+}
+
+/// Export the best config of every strategy in a multi-strategy sweep.
+///
+/// Writes one `<strategy_id>_<config>.json` artifact per strategy into `dir`,
+/// recording `cost_model` (the one the sweep ran with). Sweep results don't
+/// carry bar data, so the artifacts have empty parity vectors; use
+/// [`create_artifact_from_config`] with bars when parity vectors are needed.
+/// Strategies without artifact support are listed in [`BundleExport::skipped`]
+/// rather than failing the export.
+pub fn export_bundle(
+    result: &MultiStrategySweepResult,
+    dir: &Path,
+    cost_model: CostModel,
+) -> Result<BundleExport, ArtifactError> {
+    let mut best: Vec<_> = result.best_per_strategy.iter().collect();
+    best.sort_by_key(|(strategy, _)| strategy.id());
+
+    let mut export = BundleExport::default();
+    for (strategy, entry) in best {
+        let spec = match spec_from_config(&entry.config_id) {
+            Ok(spec) => spec,
+            Err(ArtifactError::UnsupportedStrategy(_)) => {
+                export.skipped.push(strategy.name().to_string());
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        let display = entry.config_id.display();
+        let config_slug: String = display
+            .split_whitespace()
+            .last()
+            .unwrap_or("default")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let filename = format!("{}_{}", strategy.id(), config_slug.trim_matches('_'));
+
+        let artifact = StrategyArtifact {
+            schema_version: SCHEMA_VERSION.to_string(),
+            strategy_id: spec.strategy_id.to_string(),
+            strategy_version: Some("1.0.0".to_string()),
+            symbol: entry.symbol.clone().unwrap_or_else(|| "MULTI".to_string()),
+            timeframe: "1d".to_string(),
+            generated_at: Some(Utc::now()),
+            data_range: match (entry.dates.first(), entry.dates.last()) {
+                (Some(start), Some(end)) => Some(DataRange {
+                    start: *start,
+                    end: *end,
+                }),
+                _ => None,
+            },
+            indicators: spec.indicators,
+            rules: spec.rules,
+            fill_model: "NextOpen".to_string(),
+            cost_model: cost_model.into(),
+            parameters: spec.parameters,
+            parity_vectors: ParityVectors {
+                description: Some(
+                    "No bar data in sweep results; regenerate with bars for parity vectors"
+                        .to_string(),
+                ),
+                vectors: Vec::new(),
+            },
+            metadata: Some(ArtifactMetadata {
+                run_id: Some(result.sweep_id.clone()),
+                config_id: Some(display),
+                notes: Some(format!("Best {} config by Sharpe", strategy.name())),
+            }),
+        };
+
+        let path = export_artifact_to_file(&artifact, dir, &filename)
+            .map_err(|e| ArtifactError::Io(e.to_string()))?;
+        export.paths.push(path);
+    }
+
+    Ok(export)
+}
+
 /// Export an artifact to a file.
 pub fn export_artifact_to_file(
     artifact: &StrategyArtifact,
@@ -1101,6 +1301,343 @@ pub fn export_artifact_to_file(
     Ok(output_path)
 }
 
+// =============================================================================
+// Parity Comparison
+// =============================================================================
+
+/// Differences found on a single bar when comparing parity vectors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BarParityDiff {
+    pub index: usize,
+    pub ts: DateTime<Utc>,
+    /// Max absolute difference per indicator (over all fields of multi-value indicators)
+    pub indicator_diffs: BTreeMap<String, f64>,
+    /// Indicators present on only one side, or with mismatched shapes
+    pub missing: Vec<String>,
+    /// Expected and actual signals differ
+    pub signal_mismatch: bool,
+    /// Any difference exceeds tolerance, or something is missing/mismatched
+    pub exceeds_tolerance: bool,
+}
+
+impl BarParityDiff {
+    /// Largest indicator difference on this bar.
+    pub fn max_abs_diff(&self) -> f64 {
+        self.indicator_diffs.values().copied().fold(0.0, f64::max)
+    }
+}
+
+/// Result of comparing two sets of parity vectors bar by bar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParityReport {
+    pub tolerance: f64,
+    pub bars_compared: usize,
+    /// Max absolute difference per indicator over all bars
+    pub max_abs_diff: BTreeMap<String, f64>,
+    /// Per-bar differences, in bar order
+    pub bars: Vec<BarParityDiff>,
+}
+
+impl ParityReport {
+    /// Bars that exceeded tolerance.
+    pub fn flagged_bars(&self) -> Vec<&BarParityDiff> {
+        self.bars.iter().filter(|b| b.exceeds_tolerance).collect()
+    }
+
+    /// True if no bar exceeded tolerance.
+    pub fn is_match(&self) -> bool {
+        self.bars.iter().all(|b| !b.exceeds_tolerance)
+    }
+}
+
+/// Absolute difference between two indicator values, or `None` if their shapes differ.
+fn indicator_diff(expected: &IndicatorValue, actual: &IndicatorValue) -> Option<f64> {
+    match (expected, actual) {
+        (IndicatorValue::Single(a), IndicatorValue::Single(b)) => Some((a - b).abs()),
+        (IndicatorValue::Null, IndicatorValue::Null) => Some(0.0),
+        (IndicatorValue::Multi(a), IndicatorValue::Multi(b)) => {
+            if a.len() != b.len() {
+                return None;
+            }
+            a.iter().try_fold(0.0_f64, |max, (key, va)| {
+                b.get(key).map(|vb| max.max((va - vb).abs()))
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Compare parity vectors (e.g., Rust output vs values exported from TradingView).
+///
+/// Bars are matched by position and must share timestamps. Each bar reports
+/// the max absolute difference per indicator and is flagged if any
+/// difference exceeds `tol`, an indicator is missing on one side, or the
+/// signals disagree. Vectors of different lengths are rejected with
+/// [`ArtifactError::InvalidData`].
+pub fn compare_parity(
+    expected: &ParityVectors,
+    actual: &ParityVectors,
+    tol: f64,
+) -> Result<ParityReport, ArtifactError> {
+    if expected.vectors.len() != actual.vectors.len() {
+        return Err(ArtifactError::InvalidData(format!(
+            "parity vector length mismatch: expected {} bars, got {}",
+            expected.vectors.len(),
+            actual.vectors.len()
+        )));
+    }
+
+    let empty = HashMap::new();
+    let mut max_abs_diff: BTreeMap<String, f64> = BTreeMap::new();
+    let mut bars = Vec::with_capacity(expected.vectors.len());
+
+    for (index, (exp, act)) in expected.vectors.iter().zip(&actual.vectors).enumerate() {
+        if exp.ts != act.ts {
+            return Err(ArtifactError::InvalidData(format!(
+                "parity vector timestamp mismatch at bar {}: expected {}, got {}",
+                index, exp.ts, act.ts
+            )));
+        }
+
+        let exp_ind = exp.indicators.as_ref().unwrap_or(&empty);
+        let act_ind = act.indicators.as_ref().unwrap_or(&empty);
+        let keys: BTreeSet<&String> = exp_ind.keys().chain(act_ind.keys()).collect();
+
+        let mut indicator_diffs = BTreeMap::new();
+        let mut missing = Vec::new();
+        for key in keys {
+            match (exp_ind.get(key), act_ind.get(key)) {
+                (Some(a), Some(b)) => match indicator_diff(a, b) {
+                    Some(diff) => {
+                        let overall = max_abs_diff.entry(key.clone()).or_insert(0.0);
+                        *overall = overall.max(diff);
+                        indicator_diffs.insert(key.clone(), diff);
+                    }
+                    None => missing.push(key.clone()),
+                },
+                _ => missing.push(key.clone()),
+            }
+        }
+
+        let signal_mismatch = exp.signal != act.signal;
+        let exceeds_tolerance = signal_mismatch
+            || !missing.is_empty()
+            || indicator_diffs.values().any(|d| *d > tol || d.is_nan());
+
+        bars.push(BarParityDiff {
+            index,
+            ts: exp.ts,
+            indicator_diffs,
+            missing,
+            signal_mismatch,
+            exceeds_tolerance,
+        });
+    }
+
+    Ok(ParityReport {
+        tolerance: tol,
+        bars_compared: bars.len(),
+        max_abs_diff,
+        bars,
+    })
+}
+
+// =============================================================================
+// Pine Script Generation
+// =============================================================================
+
+/// Render an artifact as a Pine Script v6 `strategy(...)` script.
+///
+/// Indicators become named series (used for plotting), and the entry/exit
+/// rules' `pine_condition`s become the trade conditions, gated on the rule's
+/// `position_required`. Fees map to `commission_value` (percent per side).
+/// Pine's `slippage` is in ticks, so `slippage_bps` is passed through
+/// one-to-one, which matches a $100 instrument with a $0.01 tick.
+///
+/// Returns [`ArtifactError::UnsupportedIndicator`] naming every indicator
+/// type that has no Pine mapping.
+pub fn to_pine_v6(artifact: &StrategyArtifact) -> Result<String, ArtifactError> {
+    let unsupported: Vec<&str> = artifact
+        .indicators
+        .iter()
+        .filter(|def| pine_indicator_lines(def).is_none())
+        .map(|def| def.indicator_type.as_str())
+        .collect();
+    if !unsupported.is_empty() {
+        return Err(ArtifactError::UnsupportedIndicator(unsupported.join(", ")));
+    }
+
+    let mut out = String::new();
+    out.push_str("//@version=6\n");
+    out.push_str(&format!(
+        "strategy(\"{} {} {}\", overlay=true, margin_long=100, margin_short=100,\n",
+        artifact.strategy_id, artifact.symbol, artifact.timeframe
+    ));
+    out.push_str("         default_qty_type=strategy.percent_of_equity, default_qty_value=100,\n");
+    out.push_str(&format!(
+        "         commission_type=strategy.commission.percent, commission_value={},\n",
+        artifact.cost_model.fees_bps_per_side / 100.0
+    ));
+    out.push_str(&format!(
+        "         slippage={})\n\n",
+        artifact.cost_model.slippage_bps.round().max(0.0) as i64
+    ));
+
+    out.push_str(&format!("// {}\n", "=".repeat(76)));
+    out.push_str(&format!("// STRATEGY: {}\n", artifact.strategy_id));
+    let mut params: Vec<_> = artifact.parameters.iter().collect();
+    params.sort_by(|a, b| a.0.cmp(b.0));
+    let params: Vec<String> = params
+        .into_iter()
+        .map(|(k, v)| format!("{}={}", k, pine_param(v)))
+        .collect();
+    out.push_str(&format!("// CONFIG: {}\n", params.join(", ")));
+    out.push_str(&format!(
+        "// FILL MODEL: {} (orders fill at the next bar's open)\n",
+        artifact.fill_model
+    ));
+    out.push_str(&format!(
+        "// COSTS: {} bps fees per side, {} bps slippage\n",
+        artifact.cost_model.fees_bps_per_side, artifact.cost_model.slippage_bps
+    ));
+    out.push_str("// SOURCE: TrendLab strategy artifact\n");
+    out.push_str(&format!("// {}\n\n", "=".repeat(76)));
+
+    out.push_str("// === INDICATORS ===\n");
+    let mut plots = Vec::new();
+    for def in &artifact.indicators {
+        let (lines, series) = pine_indicator_lines(def).unwrap_or_default();
+        for line in lines {
+            out.push_str(&line);
+            out.push('\n');
+        }
+        plots.extend(series);
+    }
+
+    // Rule conditions may carry their own declarations on leading lines;
+    // emit each once, then use the final line as the boolean expression.
+    out.push_str("\n// === SIGNALS ===\n");
+    let mut declared: Vec<&str> = Vec::new();
+    let mut conditions = Vec::new();
+    for (name, rule) in [
+        ("entryCondition", &artifact.rules.entry),
+        ("exitCondition", &artifact.rules.exit),
+    ] {
+        let lines: Vec<&str> = rule
+            .pine_condition
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect();
+        let (expr, prelude) = lines.split_last().ok_or_else(|| {
+            ArtifactError::InvalidData(format!("empty pine_condition for {}", name))
+        })?;
+        for line in prelude {
+            if !declared.contains(line) {
+                out.push_str(line);
+                out.push('\n');
+                declared.push(line);
+            }
+        }
+        conditions.push((name, *expr, rule.position_required.as_deref()));
+    }
+    for (name, expr, _) in &conditions {
+        out.push_str(&format!("bool {} = {}\n", name, expr));
+    }
+
+    out.push_str("\n// === STRATEGY EXECUTION ===\n");
+    for (name, _, required) in &conditions {
+        let guard = match *required {
+            Some("flat") => " and strategy.position_size == 0",
+            Some("long") => " and strategy.position_size > 0",
+            Some("short") => " and strategy.position_size < 0",
+            _ => "",
+        };
+        out.push_str(&format!("if {}{}\n", name, guard));
+        if *name == "entryCondition" {
+            out.push_str("    strategy.entry(\"Long\", strategy.long)\n");
+        } else {
+            out.push_str("    strategy.close(\"Long\")\n");
+        }
+    }
+
+    out.push_str("\n// === PLOTTING ===\n");
+    for series in plots {
+        out.push_str(&format!("plot({0}, \"{0}\")\n", series));
+    }
+    out.push_str("bgcolor(strategy.position_size > 0 ? color.new(color.green, 90) : na)\n");
+
+    Ok(out)
+}
+
+/// Pine declarations for an indicator plus the series names worth plotting.
+///
+/// Returns `None` for indicator types without a Pine mapping.
+fn pine_indicator_lines(def: &IndicatorDef) -> Option<(Vec<String>, Vec<String>)> {
+    let id = pine_ident(&def.id);
+    let param = |key: &str| def.params.get(key).map(pine_param);
+
+    match def.indicator_type.as_str() {
+        "donchian" | "highest_lowest" => {
+            let n = param("lookback").or_else(|| param("period"))?;
+            Some((
+                vec![
+                    format!("float {}_upper = ta.highest(high[1], {})", id, n),
+                    format!("float {}_lower = ta.lowest(low[1], {})", id, n),
+                ],
+                vec![format!("{}_upper", id), format!("{}_lower", id)],
+            ))
+        }
+        "supertrend" => {
+            let atr_period = param("atr_period")?;
+            let multiplier = param("multiplier")?;
+            Some((
+                vec![format!(
+                    "[{0}_line, {0}_dir] = ta.supertrend({1}, {2})",
+                    id, multiplier, atr_period
+                )],
+                vec![format!("{}_line", id)],
+            ))
+        }
+        "parabolic_sar" => {
+            let start = param("af_start")?;
+            let step = param("af_step")?;
+            let max = param("af_max")?;
+            Some((
+                vec![format!(
+                    "float {} = ta.sar({}, {}, {})",
+                    id, start, step, max
+                )],
+                vec![id],
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Render a parameter value as a Pine literal.
+fn pine_param(value: &ParamValue) -> String {
+    match value {
+        ParamValue::Integer(v) => v.to_string(),
+        ParamValue::Float(v) => format!("{:?}", v),
+        ParamValue::String(v) => format!("\"{}\"", v),
+        ParamValue::Bool(v) => v.to_string(),
+    }
+}
+
+/// Turn an artifact id into a valid Pine identifier.
+fn pine_ident(id: &str) -> String {
+    let ident: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("i_{}", ident)
+    } else {
+        ident
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1133,6 +1670,7 @@ mod tests {
         let cost_model = CostModel {
             fees_bps_per_side: 10.0,
             slippage_bps: 5.0,
+            ..Default::default()
         };
 
         let mut strategy = DonchianBreakoutStrategy::new(5, 3);
@@ -1220,4 +1758,188 @@ mod tests {
 
         assert_eq!(artifact.strategy_id, "test_strategy");
     }
+
+    #[test]
+    fn test_to_pine_v6_donchian() {
+        let bars = sample_bars();
+        let cost_model = CostModel {
+            fees_bps_per_side: 10.0,
+            slippage_bps: 5.0,
+            ..Default::default()
+        };
+        let artifact =
+            create_donchian_artifact(&bars, 5, 3, cost_model, &BacktestResult::default()).unwrap();
+
+        let pine = to_pine_v6(&artifact).unwrap();
+
+        assert!(pine.starts_with("//@version=6\n"));
+        assert!(pine.contains("strategy(\"donchian_breakout TEST 1d\""));
+        assert!(pine.contains("commission_value=0.1,"));
+        assert!(pine.contains("slippage=5)"));
+        assert!(pine.contains("float donchian_entry_upper = ta.highest(high[1], 5)"));
+        assert!(pine.contains("float donchian_exit_lower = ta.lowest(low[1], 3)"));
+        assert!(pine.contains("bool entryCondition = close > ta.highest(high[1], 5)"));
+        assert!(pine.contains("bool exitCondition = close < ta.lowest(low[1], 3)"));
+        assert!(pine.contains("if entryCondition and strategy.position_size == 0"));
+        assert!(pine.contains("if exitCondition and strategy.position_size > 0"));
+    }
+
+    #[test]
+    fn test_to_pine_v6_declares_shared_rule_lines_once() {
+        let artifact =
+            create_supertrend_artifact(&sample_bars(), 3, 2.0, CostModel::default()).unwrap();
+
+        let pine = to_pine_v6(&artifact).unwrap();
+
+        assert_eq!(pine.matches("[supertrend, direction] =").count(), 1);
+        assert!(pine.contains("bool entryCondition = ta.crossover(close, supertrend)"));
+    }
+
+    #[test]
+    fn test_to_pine_v6_unsupported_indicator() {
+        let bars = sample_bars();
+        let mut artifact = create_donchian_artifact(
+            &bars,
+            5,
+            3,
+            CostModel::default(),
+            &BacktestResult::default(),
+        )
+        .unwrap();
+        artifact.indicators.push(IndicatorDef {
+            id: "kama".to_string(),
+            indicator_type: "kaufman_ama".to_string(),
+            params: HashMap::new(),
+            pine_expr: None,
+        });
+
+        let err = to_pine_v6(&artifact).unwrap_err();
+        assert!(matches!(err, ArtifactError::UnsupportedIndicator(_)));
+        assert!(err.to_string().contains("kaufman_ama"));
+    }
+
+    #[test]
+    fn test_compare_parity_flags_off_bar() {
+        let bars = sample_bars();
+        let artifact = create_donchian_artifact(
+            &bars,
+            5,
+            3,
+            CostModel::default(),
+            &BacktestResult::default(),
+        )
+        .unwrap();
+        let expected = artifact.parity_vectors.clone();
+        let mut actual = artifact.parity_vectors;
+
+        // Nudge the entry channel's upper band on bar 7
+        let indicators = actual.vectors[7].indicators.as_mut().unwrap();
+        if let Some(IndicatorValue::Multi(ch)) = indicators.get_mut("donchian_entry") {
+            *ch.get_mut("upper").unwrap() += 0.5;
+        }
+
+        let report = compare_parity(&expected, &actual, 1e-6).unwrap();
+        assert_eq!(report.bars_compared, 10);
+        assert!(!report.is_match());
+
+        let flagged = report.flagged_bars();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].index, 7);
+        assert!((flagged[0].indicator_diffs["donchian_entry"] - 0.5).abs() < 1e-9);
+        assert_eq!(flagged[0].indicator_diffs["donchian_exit"], 0.0);
+        assert!((report.max_abs_diff["donchian_entry"] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compare_parity_length_mismatch() {
+        let bars = sample_bars();
+        let artifact = create_donchian_artifact(
+            &bars,
+            5,
+            3,
+            CostModel::default(),
+            &BacktestResult::default(),
+        )
+        .unwrap();
+        let mut actual = artifact.parity_vectors.clone();
+        actual.vectors.pop();
+
+        let err = compare_parity(&artifact.parity_vectors, &actual, 1e-6).unwrap_err();
+        assert!(err.to_string().contains("expected 10 bars, got 9"));
+    }
+
+    #[test]
+    fn test_export_bundle() {
+        use crate::metrics::Metrics;
+        use crate::sweep::{StrategyBestResult, StrategyTypeId};
+
+        let best = |strategy_type, config_id| StrategyBestResult {
+            strategy_type,
+            config_id,
+            symbol: Some("SPY".to_string()),
+            metrics: Metrics::default(),
+            equity_curve: vec![100_000.0, 101_000.0],
+            dates: vec![
+                Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap(),
+            ],
+        };
+
+        let mut result = MultiStrategySweepResult::new("bundle_test".to_string());
+        result.best_per_strategy.insert(
+            StrategyTypeId::Donchian,
+            best(
+                StrategyTypeId::Donchian,
+                StrategyConfigId::Donchian {
+                    entry_lookback: 20,
+                    exit_lookback: 10,
+                },
+            ),
+        );
+        result.best_per_strategy.insert(
+            StrategyTypeId::Supertrend,
+            best(
+                StrategyTypeId::Supertrend,
+                StrategyConfigId::Supertrend {
+                    atr_period: 10,
+                    multiplier: 3.0,
+                },
+            ),
+        );
+        result.best_per_strategy.insert(
+            StrategyTypeId::Tsmom,
+            best(
+                StrategyTypeId::Tsmom,
+                StrategyConfigId::Tsmom { lookback: 63 },
+            ),
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let cost_model = CostModel {
+            fees_bps_per_side: 7.5,
+            slippage_bps: 2.0,
+            ..Default::default()
+        };
+        let export = export_bundle(&result, dir.path(), cost_model).unwrap();
+
+        assert_eq!(export.paths.len(), 2);
+        assert_eq!(
+            export.skipped,
+            vec![StrategyTypeId::Tsmom.name().to_string()]
+        );
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+        assert!(dir.path().join("donchian_20_10.json").exists());
+
+        for path in &export.paths {
+            let json = std::fs::read_to_string(path).unwrap();
+            let artifact: StrategyArtifact = serde_json::from_str(&json).unwrap();
+            assert_eq!(artifact.symbol, "SPY");
+            assert_eq!(artifact.cost_model.fees_bps_per_side, 7.5);
+            assert_eq!(artifact.cost_model.slippage_bps, 2.0);
+            assert_eq!(
+                artifact.metadata.unwrap().run_id.as_deref(),
+                Some("bundle_test")
+            );
+        }
+    }
 }
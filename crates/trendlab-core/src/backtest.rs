@@ -3,19 +3,89 @@
 //! Assumptions (Phase 1):
 //! - Signals are computed on bar close.
 //! - Fills occur on the next bar open.
+//! - A signal on the final bar has no next open and is dropped.
 //! - Long-only (flat or long).
 //! - Supports both fixed and dynamic (volatility-based) position sizing.
+//! - Optional stop-loss / take-profit brackets exit longs intrabar.
+//! - Multi-symbol portfolios share one cash balance via `run_portfolio_backtest`.
 
+use crate::backtest_polars::{run_backtest_polars, PolarsBacktestConfig};
 use crate::bar::Bar;
+use crate::data::bars_to_dataframe;
 use crate::error::{Result, TrendLabError};
-use crate::sizing::{PositionSizer, SizeResult};
-use crate::strategy::{Position, Signal, Strategy};
+use crate::indicators::atr_wilder;
+use crate::metrics::{compute_relative_metrics, RelativeMetrics};
+use crate::sizing::{atr_volatility, PositionSizer, SizeResult};
+use crate::strategy::{Position, Signal, Strategy, TradingMode};
+use crate::strategy_v2::StrategyV2;
+use polars::prelude::IntoLazy;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FillModel {
     /// Signal on close of bar `t` fills at open of bar `t+1`.
     NextOpen,
+    /// Signal on close of bar `t` fills at the typical price (H+L+C)/3 of bar `t+1`.
+    ///
+    /// An approximation of the bar's VWAP for when tick or intraday volume data
+    /// is unavailable; it is not a true volume-weighted price.
+    Vwap,
+}
+
+impl FillModel {
+    /// Price (before slippage) at which a pending signal fills on `bar`.
+    pub fn fill_price(&self, bar: &Bar) -> f64 {
+        match self {
+            FillModel::NextOpen => bar.open,
+            FillModel::Vwap => bar.typical_price(),
+        }
+    }
+}
+
+/// Where a stop-loss or take-profit fills when the bar trades through its level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum IntrabarModel {
+    /// Stops and targets fill exactly at their trigger price.
+    Optimistic,
+    /// Stops fill at the bar's worst price (the low, for a long); targets at the trigger.
+    Pessimistic,
+    /// Fills at the trigger unless the open gaps past it, then at the open
+    /// (the original behavior).
+    #[default]
+    Gap,
+}
+
+impl IntrabarModel {
+    /// Raw fill price for a long stop at `stop` that `bar` has traded through.
+    pub fn stop_fill_price(&self, bar: &Bar, stop: f64) -> f64 {
+        match self {
+            IntrabarModel::Optimistic => stop,
+            IntrabarModel::Pessimistic => bar.low,
+            IntrabarModel::Gap => bar.open.min(stop),
+        }
+    }
+
+    /// Raw fill price for a long target at `target` that `bar` has traded through.
+    pub fn target_fill_price(&self, bar: &Bar, target: f64) -> f64 {
+        match self {
+            IntrabarModel::Optimistic | IntrabarModel::Pessimistic => target,
+            IntrabarModel::Gap => bar.open.max(target),
+        }
+    }
+}
+
+/// How per-fill slippage is charged.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum SlippageModel {
+    /// `CostModel::slippage_bps` of the fill price (the original behavior).
+    #[default]
+    FixedBps,
+    /// `fraction × ATR(period)` per share, using Wilder ATR at the fill bar.
+    ///
+    /// Charges more in volatile names. Falls back to `slippage_bps` until the
+    /// ATR has warmed up.
+    AtrFraction { period: usize, fraction: f64 },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -24,6 +94,18 @@ pub struct CostModel {
     pub fees_bps_per_side: f64,
     /// Slippage in basis points (bps) applied in the "worse" direction.
     pub slippage_bps: f64,
+    /// Slippage model; `FixedBps` uses `slippage_bps` above.
+    #[serde(default)]
+    pub slippage_model: SlippageModel,
+    /// Commission per share per side (e.g., 0.005 = half a cent per share).
+    #[serde(default)]
+    pub commission_per_share: f64,
+    /// Minimum commission per fill when a per-share commission applies.
+    #[serde(default)]
+    pub min_commission: f64,
+    /// Charge the per-share commission instead of (rather than on top of) the bps fee.
+    #[serde(default)]
+    pub commission_replaces_bps: bool,
 }
 
 impl Default for CostModel {
@@ -31,6 +113,69 @@ impl Default for CostModel {
         Self {
             fees_bps_per_side: 0.0,
             slippage_bps: 0.0,
+            slippage_model: SlippageModel::FixedBps,
+            commission_per_share: 0.0,
+            min_commission: 0.0,
+            commission_replaces_bps: false,
+        }
+    }
+}
+
+impl CostModel {
+    /// Per-share commission with a per-fill minimum.
+    pub fn per_share(commission_per_share: f64, min_commission: f64) -> Self {
+        Self {
+            commission_per_share,
+            min_commission,
+            commission_replaces_bps: true,
+            ..Self::default()
+        }
+    }
+
+    /// Fill price after slippage in the "worse" direction for `side`.
+    ///
+    /// `atr` is the slippage ATR at the fill bar (see [`CostModel::slippage_atr`]).
+    /// Shared by the sequential and Polars backtest paths.
+    pub fn slipped_price(&self, side: Side, raw_price: f64, atr: Option<f64>) -> f64 {
+        if let (SlippageModel::AtrFraction { fraction, .. }, Some(atr)) = (self.slippage_model, atr)
+        {
+            return match side {
+                Side::Buy => raw_price + fraction * atr,
+                Side::Sell => raw_price - fraction * atr,
+            };
+        }
+
+        let slip_rate = self.slippage_bps / 10_000.0;
+        match side {
+            Side::Buy => raw_price * (1.0 + slip_rate),
+            Side::Sell => raw_price * (1.0 - slip_rate),
+        }
+    }
+
+    /// Per-bar ATR read by the slippage model, or `None` for fixed-bps slippage.
+    pub fn slippage_atr(&self, bars: &[Bar]) -> Option<Vec<Option<f64>>> {
+        match self.slippage_model {
+            SlippageModel::FixedBps => None,
+            SlippageModel::AtrFraction { period, .. } => Some(atr_wilder(bars, period)),
+        }
+    }
+
+    /// Total fees for one fill of `qty` shares at `price`.
+    ///
+    /// Shared by the sequential and Polars backtest paths so both charge identically.
+    pub fn fees_for_fill(&self, qty: f64, price: f64) -> f64 {
+        let bps_fee = (qty * price).abs() * self.fees_bps_per_side / 10_000.0;
+
+        let has_commission = self.commission_per_share > 0.0 || self.min_commission > 0.0;
+        if !has_commission {
+            return bps_fee;
+        }
+
+        let commission = (qty.abs() * self.commission_per_share).max(self.min_commission);
+        if self.commission_replaces_bps {
+            commission
+        } else {
+            bps_fee + commission
         }
     }
 }
@@ -70,6 +215,17 @@ impl PyramidConfig {
         }
     }
 
+    /// Add a unit every `threshold_atr_multiple` ATRs of favorable movement,
+    /// up to `max_units`, for any strategy.
+    pub fn every_atr(max_units: usize, threshold_atr_multiple: f64, atr_period: usize) -> Self {
+        Self {
+            enabled: true,
+            max_units,
+            threshold_atr_multiple,
+            atr_period,
+        }
+    }
+
     /// Disabled pyramiding (single unit).
     pub fn disabled() -> Self {
         Self::default()
@@ -85,6 +241,26 @@ pub struct BacktestConfig {
     pub qty: f64,
     /// Pyramiding configuration.
     pub pyramid_config: PyramidConfig,
+    /// Hard stop-loss as a fraction below the entry price (e.g., 0.05 = 5%).
+    /// Applied intrabar to long positions by `run_backtest`.
+    #[serde(default)]
+    pub stop_loss_pct: Option<f64>,
+    /// Take-profit target as a fraction above the entry price (e.g., 0.10 = 10%).
+    /// Applied intrabar to long positions by `run_backtest`.
+    #[serde(default)]
+    pub take_profit_pct: Option<f64>,
+    /// Annual yield earned on idle cash (e.g., 0.05 = 5% T-bill rate).
+    /// Accrues daily at `yield / 252`; open positions earn nothing on their notional.
+    #[serde(default)]
+    pub cash_yield_annual: f64,
+    /// Fill assumption for stop-loss / take-profit exits.
+    #[serde(default)]
+    pub intrabar: IntrabarModel,
+    /// Minimum bars of history before any signal is acted on, on top of the
+    /// strategy's own `warmup_period`. Guards against strategies that read
+    /// partial indicators as neutral and trade on them.
+    #[serde(default)]
+    pub warmup_bars: Option<usize>,
 }
 
 impl Default for BacktestConfig {
@@ -95,6 +271,11 @@ impl Default for BacktestConfig {
             cost_model: CostModel::default(),
             qty: 1.0,
             pyramid_config: PyramidConfig::default(),
+            stop_loss_pct: None,
+            take_profit_pct: None,
+            cash_yield_annual: 0.0,
+            intrabar: IntrabarModel::default(),
+            warmup_bars: None,
         }
     }
 }
@@ -105,6 +286,59 @@ impl BacktestConfig {
         self.pyramid_config = pyramid_config;
         self
     }
+
+    /// Create config with a hard stop-loss below the entry price.
+    pub fn with_stop_loss(mut self, pct: f64) -> Self {
+        self.stop_loss_pct = Some(pct);
+        self
+    }
+
+    /// Create config with a take-profit target above the entry price.
+    pub fn with_take_profit(mut self, pct: f64) -> Self {
+        self.take_profit_pct = Some(pct);
+        self
+    }
+
+    /// Create config with interest accruing on idle cash.
+    pub fn with_cash_yield(mut self, annual_yield: f64) -> Self {
+        self.cash_yield_annual = annual_yield;
+        self
+    }
+
+    /// Create config with the given stop/target fill assumption.
+    pub fn with_intrabar(mut self, intrabar: IntrabarModel) -> Self {
+        self.intrabar = intrabar;
+        self
+    }
+
+    /// Create config that suppresses signals until `bars` bars exist.
+    pub fn with_warmup_bars(mut self, bars: usize) -> Self {
+        self.warmup_bars = Some(bars);
+        self
+    }
+
+    /// Bars required before `strategy`'s signals are acted on: the larger of
+    /// its declared `min_bars` and `warmup_bars`.
+    pub fn effective_warmup<S: Strategy + ?Sized>(&self, strategy: &S) -> usize {
+        strategy.min_bars().max(self.warmup_bars.unwrap_or(0))
+    }
+}
+
+/// One bar of interest on idle cash.
+///
+/// Short-sale proceeds are excluded (marked at `prev_close`), so only cash that
+/// is genuinely uninvested earns the yield.
+pub(crate) fn cash_interest(
+    cash: f64,
+    position_qty: f64,
+    prev_close: f64,
+    cash_yield_annual: f64,
+) -> f64 {
+    if cash_yield_annual == 0.0 {
+        return 0.0;
+    }
+    let idle_cash = (cash + position_qty.min(0.0) * prev_close).max(0.0);
+    idle_cash * cash_yield_annual / 252.0
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -126,6 +360,20 @@ pub enum Side {
     Sell,
 }
 
+/// Why a trade was closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ExitReason {
+    /// Strategy exit signal, filled at the next open.
+    #[default]
+    Signal,
+    /// Hard stop-loss hit intrabar.
+    StopLoss,
+    /// Take-profit target hit intrabar.
+    TakeProfit,
+    /// Partial close when a portfolio rebalance trims the position.
+    Rebalance,
+}
+
 /// Direction of a trade (long or short).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum TradeDirection {
@@ -145,6 +393,9 @@ pub struct Trade {
     /// Direction of the trade.
     #[serde(default)]
     pub direction: TradeDirection,
+    /// Why the trade was closed.
+    #[serde(default)]
+    pub exit_reason: ExitReason,
 }
 
 /// A pyramided trade consisting of multiple entry fills and a single exit.
@@ -247,6 +498,9 @@ pub struct BacktestResult {
     /// Pyramid trades (when pyramiding is enabled).
     pub pyramid_trades: Vec<PyramidTrade>,
     pub equity: Vec<EquityPoint>,
+    /// Metrics relative to a benchmark, set by [`BacktestResult::with_benchmark`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vs_benchmark: Option<RelativeMetrics>,
 }
 
 impl BacktestResult {
@@ -258,9 +512,64 @@ impl BacktestResult {
     pub fn current_units(&self) -> f64 {
         self.equity.last().map(|p| p.position_qty).unwrap_or(0.0)
     }
+    /// Attach metrics relative to `benchmark`, e.g. [`buy_and_hold`] of the same symbol.
+    pub fn with_benchmark(mut self, benchmark: &BacktestResult) -> Self {
+        self.vs_benchmark = Some(compute_relative_metrics(&self, &benchmark.equity));
+        self
+    }
+}
+
+/// Buy-and-hold baseline: invest all cash at the first bar and hold to the end.
+///
+/// The entry fills at the first bar's price under `config.fill_model`, sized to
+/// the largest quantity the cash covers after slippage and fees. The position is
+/// never closed, so `trades` is empty and the final equity is marked at the last
+/// close. Idle cash accrues `cash_yield_annual` as in [`run_backtest`].
+pub fn buy_and_hold(bars: &[Bar], config: &BacktestConfig) -> BacktestResult {
+    let Some(first) = bars.first() else {
+        return BacktestResult::default();
+    };
+
+    let raw_price = config.fill_model.fill_price(first);
+    let price = config.cost_model.slipped_price(Side::Buy, raw_price, None);
+    let qty = affordable_qty(config.initial_cash, price, &config.cost_model);
+    let fill = execute_fill(
+        first.ts,
+        Side::Buy,
+        qty,
+        raw_price,
+        &config.cost_model,
+        None,
+        None,
+    );
+
+    let mut cash = config.initial_cash - fill.qty * fill.price - fill.fees;
+    let mut equity = Vec::with_capacity(bars.len());
+    for (i, bar) in bars.iter().enumerate() {
+        if i > 0 {
+            cash += cash_interest(cash, qty, bars[i - 1].close, config.cash_yield_annual);
+        }
+        equity.push(EquityPoint {
+            ts: bar.ts,
+            cash,
+            position_qty: qty,
+            close: bar.close,
+            equity: cash + qty * bar.close,
+        });
+    }
+
+    BacktestResult {
+        fills: vec![fill],
+        equity,
+        ..BacktestResult::default()
+    }
 }
 
 /// Run a backtest over `bars` with a stateful strategy.
+///
+/// A signal emitted on the last bar is never filled: there is no next open to
+/// execute it at, so no position is opened (or closed) for it. This matches
+/// `run_backtest_polars`.
 pub fn run_backtest<S: Strategy + ?Sized>(
     bars: &[Bar],
     strategy: &mut S,
@@ -272,6 +581,7 @@ pub fn run_backtest<S: Strategy + ?Sized>(
             trades: vec![],
             pyramid_trades: vec![],
             equity: vec![],
+            vs_benchmark: None,
         });
     }
 
@@ -281,8 +591,25 @@ pub fn run_backtest<S: Strategy + ?Sized>(
     if config.qty <= 0.0 {
         return Err(TrendLabError::Config("qty must be > 0".into()));
     }
+    if bars.len() < strategy.min_bars() {
+        tracing::warn!(
+            strategy = strategy.id(),
+            bars = bars.len(),
+            min_bars = strategy.min_bars(),
+            "Fewer bars than the strategy's warm-up; it cannot trade"
+        );
+    }
+    if config.stop_loss_pct.is_some_and(|p| p <= 0.0 || p >= 1.0) {
+        return Err(TrendLabError::Config(
+            "stop_loss_pct must be in (0, 1)".into(),
+        ));
+    }
+    if config.take_profit_pct.is_some_and(|p| p <= 0.0) {
+        return Err(TrendLabError::Config("take_profit_pct must be > 0".into()));
+    }
 
     strategy.reset();
+    strategy.prepare(bars);
 
     let mut cash = config.initial_cash;
     let mut position_qty = 0.0;
@@ -294,7 +621,20 @@ pub fn run_backtest<S: Strategy + ?Sized>(
     let mut current_entry: Option<Fill> = None;
     let mut equity: Vec<EquityPoint> = Vec::with_capacity(bars.len());
 
+    let slippage_atr = config.cost_model.slippage_atr(bars);
+    let slip_atr = |i: usize| slippage_atr.as_ref().and_then(|atr| atr[i]);
+
     for i in 0..bars.len() {
+        // 0) Accrue interest on idle cash held over the prior bar.
+        if i > 0 {
+            cash += cash_interest(
+                cash,
+                position_qty,
+                bars[i - 1].close,
+                config.cash_yield_annual,
+            );
+        }
+
         // 1) Execute fills on open (from prior close).
         if let Some(sig) = pending_signal.take() {
             if i == 0 {
@@ -302,7 +642,7 @@ pub fn run_backtest<S: Strategy + ?Sized>(
             } else {
                 match (sig, position) {
                     (Signal::EnterLong, Position::Flat) => {
-                        let raw_price = bars[i].open;
+                        let raw_price = config.fill_model.fill_price(&bars[i]);
                         let fill = execute_fill(
                             bars[i].ts,
                             Side::Buy,
@@ -310,6 +650,7 @@ pub fn run_backtest<S: Strategy + ?Sized>(
                             raw_price,
                             &config.cost_model,
                             None,
+                            slip_atr(i),
                         );
                         cash -= fill.qty * fill.price;
                         cash -= fill.fees;
@@ -319,7 +660,7 @@ pub fn run_backtest<S: Strategy + ?Sized>(
                         fills.push(fill);
                     }
                     (Signal::ExitLong, Position::Long) => {
-                        let raw_price = bars[i].open;
+                        let raw_price = config.fill_model.fill_price(&bars[i]);
                         let fill = execute_fill(
                             bars[i].ts,
                             Side::Sell,
@@ -327,6 +668,7 @@ pub fn run_backtest<S: Strategy + ?Sized>(
                             raw_price,
                             &config.cost_model,
                             None,
+                            slip_atr(i),
                         );
                         cash += fill.qty * fill.price;
                         cash -= fill.fees;
@@ -346,12 +688,13 @@ pub fn run_backtest<S: Strategy + ?Sized>(
                             gross_pnl,
                             net_pnl,
                             direction: TradeDirection::Long,
+                            exit_reason: ExitReason::Signal,
                         });
                         fills.push(fill);
                     }
                     // Short entry: Flat -> Short
                     (Signal::EnterShort, Position::Flat) => {
-                        let raw_price = bars[i].open;
+                        let raw_price = config.fill_model.fill_price(&bars[i]);
                         let fill = execute_fill(
                             bars[i].ts,
                             Side::Sell, // Sell to open short
@@ -359,6 +702,7 @@ pub fn run_backtest<S: Strategy + ?Sized>(
                             raw_price,
                             &config.cost_model,
                             None,
+                            slip_atr(i),
                         );
                         // Short sale: receive cash (we're selling borrowed shares)
                         cash += fill.qty * fill.price;
@@ -371,7 +715,7 @@ pub fn run_backtest<S: Strategy + ?Sized>(
                     }
                     // Short exit (cover): Short -> Flat
                     (Signal::ExitShort, Position::Short) => {
-                        let raw_price = bars[i].open;
+                        let raw_price = config.fill_model.fill_price(&bars[i]);
                         let fill = execute_fill(
                             bars[i].ts,
                             Side::Buy, // Buy to close short
@@ -379,6 +723,7 @@ pub fn run_backtest<S: Strategy + ?Sized>(
                             raw_price,
                             &config.cost_model,
                             None,
+                            slip_atr(i),
                         );
                         // Cover: pay cash to buy back shares
                         cash -= fill.qty * fill.price;
@@ -401,6 +746,7 @@ pub fn run_backtest<S: Strategy + ?Sized>(
                             gross_pnl,
                             net_pnl,
                             direction: TradeDirection::Short,
+                            exit_reason: ExitReason::Signal,
                         });
                         fills.push(fill);
                     }
@@ -411,7 +757,45 @@ pub fn run_backtest<S: Strategy + ?Sized>(
             }
         }
 
-        // 2) Mark-to-market equity at close (after any open fills).
+        // 2) Bracket exits: stop-loss / take-profit hit intrabar on an open long.
+        if position == Position::Long {
+            if let Some((raw_price, exit_reason)) =
+                bracket_exit(&bars[i], current_entry.as_ref(), &config)
+            {
+                let fill = execute_fill(
+                    bars[i].ts,
+                    Side::Sell,
+                    config.qty,
+                    raw_price,
+                    &config.cost_model,
+                    None,
+                    slip_atr(i),
+                );
+                cash += fill.qty * fill.price;
+                cash -= fill.fees;
+                position_qty -= fill.qty;
+                position = Position::Flat;
+
+                let entry = current_entry.take().ok_or_else(|| {
+                    TrendLabError::Strategy("exit fill without an entry fill".into())
+                })?;
+
+                let gross_pnl = (fill.price - entry.price) * entry.qty;
+                let net_pnl = gross_pnl - entry.fees - fill.fees;
+
+                trades.push(Trade {
+                    entry,
+                    exit: fill.clone(),
+                    gross_pnl,
+                    net_pnl,
+                    direction: TradeDirection::Long,
+                    exit_reason,
+                });
+                fills.push(fill);
+            }
+        }
+
+        // 3) Mark-to-market equity at close (after any fills).
         let close = bars[i].close;
         let eq = cash + position_qty * close;
         equity.push(EquityPoint {
@@ -422,17 +806,15 @@ pub fn run_backtest<S: Strategy + ?Sized>(
             equity: eq,
         });
 
-        // 3) Compute signal on close to be filled next bar open.
+        // 4) Compute signal on close to be filled next bar open.
         let hist = &bars[..=i];
-        let sig = if i + 1 >= strategy.warmup_period() {
+        let sig = if i + 1 >= config.effective_warmup(strategy) {
             strategy.signal(hist, position)
         } else {
             Signal::Hold
         };
 
-        pending_signal = match config.fill_model {
-            FillModel::NextOpen => Some(sig),
-        };
+        pending_signal = Some(sig);
     }
 
     Ok(BacktestResult {
@@ -440,9 +822,47 @@ pub fn run_backtest<S: Strategy + ?Sized>(
         trades,
         pyramid_trades: vec![],
         equity,
+        vs_benchmark: None,
     })
 }
 
+/// Check whether a long position's stop-loss or take-profit is hit on `bar`.
+///
+/// Levels are relative to the entry fill price; the fill price within the bar
+/// follows `config.intrabar`. If both levels are inside the bar's range, the
+/// intrabar order is unknown, so the stop is assumed to hit first (conservative).
+///
+/// Returns the raw (pre-slippage) exit price and the exit reason.
+fn bracket_exit(
+    bar: &Bar,
+    entry: Option<&Fill>,
+    config: &BacktestConfig,
+) -> Option<(f64, ExitReason)> {
+    let entry_price = entry?.price;
+
+    if let Some(pct) = config.stop_loss_pct {
+        let stop = entry_price * (1.0 - pct);
+        if bar.low <= stop {
+            return Some((
+                config.intrabar.stop_fill_price(bar, stop),
+                ExitReason::StopLoss,
+            ));
+        }
+    }
+
+    if let Some(pct) = config.take_profit_pct {
+        let target = entry_price * (1.0 + pct);
+        if bar.high >= target {
+            return Some((
+                config.intrabar.target_fill_price(bar, target),
+                ExitReason::TakeProfit,
+            ));
+        }
+    }
+
+    None
+}
+
 /// Configuration for backtest with dynamic position sizing.
 #[derive(Debug, Clone)]
 pub struct BacktestSizingConfig {
@@ -472,11 +892,12 @@ struct PendingEntry {
 ///
 /// Unlike `run_backtest`, this version uses a `PositionSizer` to determine
 /// position size at each entry signal. The sizer computes position size based
-/// on the bars available at signal time.
+/// on the bars available at signal time, and is fed the mark-to-market equity
+/// at every close so equity-aware sizers (e.g. `DrawdownSizer`) stay current.
 pub fn run_backtest_with_sizer<S: Strategy, P: PositionSizer>(
     bars: &[Bar],
     strategy: &mut S,
-    sizer: &P,
+    sizer: &mut P,
     config: BacktestSizingConfig,
 ) -> Result<BacktestResult> {
     if bars.is_empty() {
@@ -485,6 +906,7 @@ pub fn run_backtest_with_sizer<S: Strategy, P: PositionSizer>(
             trades: vec![],
             pyramid_trades: vec![],
             equity: vec![],
+            vs_benchmark: None,
         });
     }
 
@@ -493,6 +915,7 @@ pub fn run_backtest_with_sizer<S: Strategy, P: PositionSizer>(
     }
 
     strategy.reset();
+    strategy.prepare(bars);
 
     let mut cash = config.initial_cash;
     let mut position_qty = 0.0;
@@ -508,11 +931,14 @@ pub fn run_backtest_with_sizer<S: Strategy, P: PositionSizer>(
     let mut current_entry: Option<Fill> = None;
     let mut equity: Vec<EquityPoint> = Vec::with_capacity(bars.len());
 
+    let slippage_atr = config.cost_model.slippage_atr(bars);
+    let slip_atr = |i: usize| slippage_atr.as_ref().and_then(|atr| atr[i]);
+
     for i in 0..bars.len() {
         // 1) Execute fills on open (from prior close signal).
         if let Some(entry) = pending_entry.take() {
             if i > 0 && matches!(entry.signal, Signal::EnterLong) && position == Position::Flat {
-                let raw_price = bars[i].open;
+                let raw_price = config.fill_model.fill_price(&bars[i]);
                 let qty = entry.size_result.units;
 
                 let fill = execute_fill(
@@ -522,6 +948,7 @@ pub fn run_backtest_with_sizer<S: Strategy, P: PositionSizer>(
                     raw_price,
                     &config.cost_model,
                     entry.size_result.atr,
+                    slip_atr(i),
                 );
                 cash -= fill.qty * fill.price;
                 cash -= fill.fees;
@@ -536,7 +963,7 @@ pub fn run_backtest_with_sizer<S: Strategy, P: PositionSizer>(
 
         if let Some(sig) = pending_exit.take() {
             if i > 0 && matches!(sig, Signal::ExitLong) && position == Position::Long {
-                let raw_price = bars[i].open;
+                let raw_price = config.fill_model.fill_price(&bars[i]);
                 let qty = entry_qty; // Use same qty as entry
 
                 let fill = execute_fill(
@@ -546,6 +973,7 @@ pub fn run_backtest_with_sizer<S: Strategy, P: PositionSizer>(
                     raw_price,
                     &config.cost_model,
                     entry_atr,
+                    slip_atr(i),
                 );
                 cash += fill.qty * fill.price;
                 cash -= fill.fees;
@@ -567,6 +995,7 @@ pub fn run_backtest_with_sizer<S: Strategy, P: PositionSizer>(
                     gross_pnl,
                     net_pnl,
                     direction: TradeDirection::Long,
+                    exit_reason: ExitReason::Signal,
                 });
                 fills.push(fill);
             }
@@ -582,6 +1011,7 @@ pub fn run_backtest_with_sizer<S: Strategy, P: PositionSizer>(
             close,
             equity: eq,
         });
+        sizer.update_equity(eq);
 
         // 3) Compute signal on close.
         let hist = &bars[..=i];
@@ -617,6 +1047,7 @@ pub fn run_backtest_with_sizer<S: Strategy, P: PositionSizer>(
         trades,
         pyramid_trades: vec![],
         equity,
+        vs_benchmark: None,
     })
 }
 
@@ -666,36 +1097,85 @@ impl PyramidState {
 /// - Maximum of `max_units` total units
 /// - All units exit together on exit signal
 ///
+/// Adds are triggered by price movement alone, so this works with any
+/// strategy; `AddLong` signals are not required. Every unit is `config.qty`.
+///
 /// Returns `BacktestResult` with `pyramid_trades` populated.
 pub fn run_backtest_with_pyramid<S: Strategy>(
     bars: &[Bar],
     strategy: &mut S,
     config: BacktestConfig,
 ) -> Result<BacktestResult> {
-    use crate::indicators::atr_wilder;
+    if !bars.is_empty() {
+        if config.initial_cash <= 0.0 {
+            return Err(TrendLabError::Config("initial_cash must be > 0".into()));
+        }
+        if config.qty <= 0.0 {
+            return Err(TrendLabError::Config("qty must be > 0".into()));
+        }
+
+        // If pyramiding disabled, delegate to standard backtest
+        if !config.pyramid_config.enabled {
+            return run_backtest(bars, strategy, config);
+        }
+    }
+
+    let qty = config.qty;
+    pyramid_backtest(bars, strategy, config, 0, |_, _| {
+        Some(SizeResult {
+            units: qty,
+            atr: None,
+            dollar_vol_per_unit: None,
+        })
+    })
+}
+
+/// Run a pyramiding backtest with each unit sized by a `PositionSizer`.
+///
+/// Same add rules as [`run_backtest_with_pyramid`], but the initial entry and
+/// every add are sized by `sizer` at signal time instead of `config.qty`.
+/// A unit is skipped when the sizer returns `None`. Pyramiding is always on;
+/// `pyramid_config.enabled` is ignored.
+pub fn run_backtest_with_pyramid_sizer<S: Strategy, P: PositionSizer>(
+    bars: &[Bar],
+    strategy: &mut S,
+    sizer: &P,
+    config: BacktestConfig,
+) -> Result<BacktestResult> {
+    pyramid_backtest(
+        bars,
+        strategy,
+        config,
+        sizer.warmup_period(),
+        |hist, price| sizer.size(hist, price),
+    )
+}
 
+/// Shared pyramiding loop; `unit_size` sizes each unit from the bars up to
+/// the signal bar and its close.
+fn pyramid_backtest<S: Strategy>(
+    bars: &[Bar],
+    strategy: &mut S,
+    config: BacktestConfig,
+    sizer_warmup: usize,
+    unit_size: impl Fn(&[Bar], f64) -> Option<SizeResult>,
+) -> Result<BacktestResult> {
     if bars.is_empty() {
         return Ok(BacktestResult {
             fills: vec![],
             trades: vec![],
             pyramid_trades: vec![],
             equity: vec![],
+            vs_benchmark: None,
         });
     }
 
     if config.initial_cash <= 0.0 {
         return Err(TrendLabError::Config("initial_cash must be > 0".into()));
     }
-    if config.qty <= 0.0 {
-        return Err(TrendLabError::Config("qty must be > 0".into()));
-    }
-
-    // If pyramiding disabled, delegate to standard backtest
-    if !config.pyramid_config.enabled {
-        return run_backtest(bars, strategy, config);
-    }
 
     strategy.reset();
+    strategy.prepare(bars);
 
     let pyramid_cfg = &config.pyramid_config;
     let atr_values = atr_wilder(bars, pyramid_cfg.atr_period);
@@ -705,20 +1185,24 @@ pub fn run_backtest_with_pyramid<S: Strategy>(
     let mut position = Position::Flat;
 
     let mut pending_signal: Option<Signal> = None;
-    let mut pending_pyramid_price: Option<f64> = None; // Price at which pyramid was triggered
+    let mut pending_entry_qty: Option<f64> = None; // Size of a pending initial entry
+    let mut pending_add_qty: Option<f64> = None; // Size of a pending pyramid add
     let mut fills: Vec<Fill> = vec![];
     let mut pyramid_trades: Vec<PyramidTrade> = vec![];
     let mut equity: Vec<EquityPoint> = Vec::with_capacity(bars.len());
     let mut pyr_state = PyramidState::default();
 
+    let slippage_atr = config.cost_model.slippage_atr(bars);
+    let slip_atr = |i: usize| slippage_atr.as_ref().and_then(|atr| atr[i]);
+
     for i in 0..bars.len() {
         let current_bar = &bars[i];
 
         // 1) Execute pending entry/exit/pyramid fills on open
         if let Some(sig) = pending_signal.take() {
-            match (sig, position) {
-                (Signal::EnterLong, Position::Flat) => {
-                    let raw_price = current_bar.open;
+            match (sig, position, pending_entry_qty.take()) {
+                (Signal::EnterLong, Position::Flat, Some(qty)) => {
+                    let raw_price = config.fill_model.fill_price(current_bar);
                     let entry_atr = if i > 0 {
                         atr_values[i - 1].unwrap_or(4.0) // Use prior bar's ATR, default if unavailable
                     } else {
@@ -728,10 +1212,11 @@ pub fn run_backtest_with_pyramid<S: Strategy>(
                     let fill = execute_fill(
                         current_bar.ts,
                         Side::Buy,
-                        config.qty,
+                        qty,
                         raw_price,
                         &config.cost_model,
                         Some(entry_atr),
+                        slip_atr(i),
                     );
                     cash -= fill.qty * fill.price;
                     cash -= fill.fees;
@@ -744,9 +1229,9 @@ pub fn run_backtest_with_pyramid<S: Strategy>(
                     pyr_state.entries.push(fill.clone());
                     fills.push(fill);
                 }
-                (Signal::ExitLong, Position::Long) => {
+                (Signal::ExitLong, Position::Long, _) => {
                     // Exit all units together
-                    let raw_price = current_bar.open;
+                    let raw_price = config.fill_model.fill_price(current_bar);
                     let total_qty = position_qty;
                     let fill = execute_fill(
                         current_bar.ts,
@@ -755,6 +1240,7 @@ pub fn run_backtest_with_pyramid<S: Strategy>(
                         raw_price,
                         &config.cost_model,
                         Some(pyr_state.entry_atr),
+                        slip_atr(i),
                     );
                     cash += fill.qty * fill.price;
                     cash -= fill.fees;
@@ -776,27 +1262,27 @@ pub fn run_backtest_with_pyramid<S: Strategy>(
         }
 
         // Execute pending pyramid add
-        if pending_pyramid_price.take().is_some()
-            && position == Position::Long
-            && pyr_state.can_add(pyramid_cfg.max_units)
-        {
-            let raw_price = current_bar.open;
-            let fill = execute_fill(
-                current_bar.ts,
-                Side::Buy,
-                config.qty,
-                raw_price,
-                &config.cost_model,
-                Some(pyr_state.entry_atr),
-            );
-            cash -= fill.qty * fill.price;
-            cash -= fill.fees;
-            position_qty += fill.qty;
+        if let Some(qty) = pending_add_qty.take() {
+            if position == Position::Long && pyr_state.can_add(pyramid_cfg.max_units) {
+                let raw_price = config.fill_model.fill_price(current_bar);
+                let fill = execute_fill(
+                    current_bar.ts,
+                    Side::Buy,
+                    qty,
+                    raw_price,
+                    &config.cost_model,
+                    Some(pyr_state.entry_atr),
+                    slip_atr(i),
+                );
+                cash -= fill.qty * fill.price;
+                cash -= fill.fees;
+                position_qty += fill.qty;
 
-            pyr_state.units += 1;
-            pyr_state.last_add_price = fill.price;
-            pyr_state.entries.push(fill.clone());
-            fills.push(fill);
+                pyr_state.units += 1;
+                pyr_state.last_add_price = fill.price;
+                pyr_state.entries.push(fill.clone());
+                fills.push(fill);
+            }
         }
 
         // 2) Mark-to-market equity at close
@@ -812,7 +1298,10 @@ pub fn run_backtest_with_pyramid<S: Strategy>(
 
         // 3) Compute signals on close for next bar
         let hist = &bars[..=i];
-        let warmup = strategy.warmup_period().max(pyramid_cfg.atr_period);
+        let warmup = config
+            .effective_warmup(strategy)
+            .max(pyramid_cfg.atr_period)
+            .max(sizer_warmup);
 
         let sig = if i + 1 >= warmup {
             strategy.signal(hist, position)
@@ -820,6 +1309,11 @@ pub fn run_backtest_with_pyramid<S: Strategy>(
             Signal::Hold
         };
 
+        // Size the initial entry now (at signal time)
+        if sig == Signal::EnterLong && position == Position::Flat {
+            pending_entry_qty = unit_size(hist, close).map(|s| s.units);
+        }
+
         // Set pending signal for entry/exit
         pending_signal = Some(sig);
 
@@ -828,7 +1322,7 @@ pub fn run_backtest_with_pyramid<S: Strategy>(
             && pyr_state.can_add(pyramid_cfg.max_units)
             && pyr_state.should_pyramid(close, pyramid_cfg.threshold_atr_multiple)
         {
-            pending_pyramid_price = Some(close);
+            pending_add_qty = unit_size(hist, close).map(|s| s.units);
         }
     }
 
@@ -837,6 +1331,7 @@ pub fn run_backtest_with_pyramid<S: Strategy>(
         trades: vec![], // Standard trades not populated for pyramid backtest
         pyramid_trades,
         equity,
+        vs_benchmark: None,
     })
 }
 
@@ -847,17 +1342,11 @@ fn execute_fill(
     raw_price: f64,
     costs: &CostModel,
     atr_at_fill: Option<f64>,
+    slippage_atr: Option<f64>,
 ) -> Fill {
-    let slip_rate = costs.slippage_bps / 10_000.0;
-    let fee_rate = costs.fees_bps_per_side / 10_000.0;
-
-    let slipped_price = match side {
-        Side::Buy => raw_price * (1.0 + slip_rate),
-        Side::Sell => raw_price * (1.0 - slip_rate),
-    };
+    let slipped_price = costs.slipped_price(side, raw_price, slippage_atr);
 
-    let notional = qty * slipped_price;
-    let fees = notional.abs() * fee_rate;
+    let fees = costs.fees_for_fill(qty, slipped_price);
 
     Fill {
         ts,
@@ -870,65 +1359,729 @@ fn execute_fill(
     }
 }
 
-/// A deterministic, test-only strategy: enter at a fixed bar index, exit at a fixed bar index.
-///
-/// The strategy generates signals on bar **close** at index `entry_idx` / `exit_idx`.
-#[derive(Debug, Clone)]
-pub struct FixedEntryExitStrategy {
-    entry_idx: usize,
-    exit_idx: usize,
+/// How a portfolio backtest splits its capital pool across symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Allocation {
+    /// Each entry targets `1 / n_symbols` of portfolio equity.
+    EqualWeight,
+    /// Each entry targets this fraction of portfolio equity (e.g., 0.25 = 25%).
+    FixedFraction(f64),
+    /// Equal risk contribution: weights proportional to inverse trailing
+    /// volatility (ATR over `lookback` bars relative to price, see
+    /// [`crate::sizing::atr_volatility`]). Open positions are resized toward
+    /// their weights every `rebalance_every` bars.
+    RiskParity {
+        lookback: usize,
+        rebalance_every: usize,
+    },
 }
 
-impl FixedEntryExitStrategy {
-    pub fn new(entry_idx: usize, exit_idx: usize) -> Self {
-        Self {
-            entry_idx,
-            exit_idx,
+impl Allocation {
+    /// Largest weight risk parity gives any one symbol, so a near-zero
+    /// volatility estimate cannot pull the whole pool into one position.
+    pub const MAX_RISK_PARITY_WEIGHT: f64 = 0.8;
+
+    /// Volatility floor that keeps inverse-volatility weights finite.
+    const MIN_VOLATILITY: f64 = 1e-6;
+
+    /// Target weight per symbol, given each symbol's bars up to the prior close.
+    ///
+    /// Under risk parity, symbols still warming up get an equal-weight share
+    /// and the rest split the remainder by inverse volatility.
+    pub fn target_weights(&self, histories: &[&[Bar]]) -> Vec<f64> {
+        let n = histories.len();
+        match self {
+            Allocation::EqualWeight => vec![1.0 / n as f64; n],
+            Allocation::FixedFraction(fraction) => vec![*fraction; n],
+            Allocation::RiskParity { lookback, .. } => {
+                let inverse_vols: Vec<Option<f64>> = histories
+                    .iter()
+                    .map(|bars| {
+                        atr_volatility(bars, *lookback).map(|v| 1.0 / v.max(Self::MIN_VOLATILITY))
+                    })
+                    .collect();
+                let unknown = inverse_vols.iter().filter(|v| v.is_none()).count();
+                let known_share = 1.0 - unknown as f64 / n as f64;
+                let inverse_sum: f64 = inverse_vols.iter().flatten().sum();
+
+                inverse_vols
+                    .iter()
+                    .map(|inv| match inv {
+                        Some(inv) => {
+                            (known_share * inv / inverse_sum).min(Self::MAX_RISK_PARITY_WEIGHT)
+                        }
+                        None => 1.0 / n as f64,
+                    })
+                    .collect()
+            }
         }
     }
-}
 
-impl Strategy for FixedEntryExitStrategy {
-    fn id(&self) -> &str {
-        "fixed_entry_exit"
+    /// Bars between rebalances of open positions, if this allocation rebalances.
+    pub fn rebalance_every(&self) -> Option<usize> {
+        match self {
+            Allocation::RiskParity {
+                rebalance_every, ..
+            } => Some(*rebalance_every),
+            _ => None,
+        }
     }
+}
 
-    fn warmup_period(&self) -> usize {
-        0
-    }
+/// Portfolio mark-to-market at one timestamp.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioEquityPoint {
+    pub ts: chrono::DateTime<chrono::Utc>,
+    pub cash: f64,
+    /// Market value of each symbol's position at its latest close.
+    pub positions: BTreeMap<String, f64>,
+    pub equity: f64,
+}
 
-    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
-        let idx = bars.len().saturating_sub(1);
-        match current_position {
-            Position::Flat if idx == self.entry_idx => Signal::EnterLong,
-            Position::Long if idx == self.exit_idx => Signal::ExitLong,
-            _ => Signal::Hold,
-        }
+/// Result of [`run_portfolio_backtest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct PortfolioResult {
+    /// Fills per symbol, in time order.
+    pub fills: BTreeMap<String, Vec<Fill>>,
+    /// Closed trades per symbol, in time order.
+    pub trades: BTreeMap<String, Vec<Trade>>,
+    /// One point per distinct timestamp across all symbols.
+    pub equity: Vec<PortfolioEquityPoint>,
+}
+
+impl PortfolioResult {
+    pub fn last_equity(&self) -> Option<f64> {
+        self.equity.last().map(|p| p.equity)
     }
+}
 
-    fn reset(&mut self) {}
+/// Per-symbol state while walking the aligned timeline.
+struct PortfolioLeg {
+    next_idx: usize,
+    position_qty: f64,
+    position: Position,
+    last_close: Option<f64>,
+    pending_signal: Option<Signal>,
+    current_entry: Option<Fill>,
 }
 
-/// A deterministic, test-only strategy for short positions.
-///
-/// The strategy generates short entry/exit signals at fixed bar indices.
-#[derive(Debug, Clone)]
-pub struct FixedShortStrategy {
-    entry_idx: usize,
-    exit_idx: usize,
+/// Fold a buy into an open entry, averaging its price and accumulating fees.
+fn add_to_entry(entry: &mut Fill, fill: &Fill) {
+    let qty = entry.qty + fill.qty;
+    entry.price = (entry.price * entry.qty + fill.price * fill.qty) / qty;
+    entry.raw_price = (entry.raw_price * entry.qty + fill.raw_price * fill.qty) / qty;
+    entry.fees += fill.fees;
+    entry.qty = qty;
 }
 
-impl FixedShortStrategy {
-    pub fn new(entry_idx: usize, exit_idx: usize) -> Self {
-        Self {
-            entry_idx,
-            exit_idx,
-        }
+/// Realize the part of an open entry closed by a trimming sell.
+///
+/// Entry fees are prorated, so the trimmed trade plus the remaining entry
+/// still account for every fee paid.
+fn close_partial(entry: &mut Fill, exit: Fill) -> Trade {
+    let share = exit.qty / entry.qty;
+    let closed = Fill {
+        qty: exit.qty,
+        fees: entry.fees * share,
+        ..entry.clone()
+    };
+    entry.qty -= closed.qty;
+    entry.fees -= closed.fees;
+
+    let gross_pnl = (exit.price - closed.price) * closed.qty;
+    let net_pnl = gross_pnl - closed.fees - exit.fees;
+    Trade {
+        entry: closed,
+        exit,
+        gross_pnl,
+        net_pnl,
+        direction: TradeDirection::Long,
+        exit_reason: ExitReason::Rebalance,
     }
 }
 
-impl Strategy for FixedShortStrategy {
-    fn id(&self) -> &str {
+/// Largest quantity whose cost plus fees fits in `cash` at `price`.
+///
+/// Bounds the fee by its linear part plus the minimum commission, so the
+/// result never overdraws cash whichever fee rule applies. A few ulps of
+/// headroom keep `qty * price` from rounding past `cash`.
+fn affordable_qty(cash: f64, price: f64, costs: &CostModel) -> f64 {
+    let per_share = price * (1.0 + costs.fees_bps_per_side / 10_000.0) + costs.commission_per_share;
+    ((cash - costs.min_commission) / (per_share * (1.0 + 4.0 * f64::EPSILON))).max(0.0)
+}
+
+/// Run a long-only backtest of several symbols against one shared cash balance.
+///
+/// Bars are aligned by timestamp: the timeline is the union of every symbol's
+/// timestamps, and a symbol missing a bar simply trades nothing and is marked
+/// at its last close. Each symbol has its own strategy, keyed by symbol.
+///
+/// Signals fill on the next bar under the config's fill and slippage models,
+/// as in [`run_backtest`]. An entry targets its `allocation` weight of the
+/// equity marked at the prior close, capped by the cash on hand, so cash never
+/// goes negative. Allocations with a rebalance
+/// cadence also resize open positions toward their weights every N bars;
+/// otherwise positions are only sized when a signal changes. On each bar
+/// exits and rebalancing trims fill before any buys so freed cash is reusable.
+/// Short signals, stops, and cash yield are not modelled.
+pub fn run_portfolio_backtest(
+    symbol_bars: &HashMap<String, Vec<Bar>>,
+    strategies: &mut HashMap<String, Box<dyn Strategy>>,
+    config: BacktestConfig,
+    allocation: Allocation,
+) -> Result<PortfolioResult> {
+    if config.initial_cash <= 0.0 {
+        return Err(TrendLabError::Config("initial_cash must be > 0".into()));
+    }
+    match allocation {
+        Allocation::FixedFraction(fraction) if fraction <= 0.0 || fraction > 1.0 => {
+            return Err(TrendLabError::Config(
+                "allocation fraction must be in (0, 1]".into(),
+            ));
+        }
+        Allocation::RiskParity {
+            lookback,
+            rebalance_every,
+        } if lookback == 0 || rebalance_every == 0 => {
+            return Err(TrendLabError::Config(
+                "risk parity lookback and rebalance_every must be > 0".into(),
+            ));
+        }
+        _ => {}
+    }
+    if symbol_bars.is_empty() {
+        return Ok(PortfolioResult::default());
+    }
+
+    // Sorted symbols keep fill order (and so cash usage) deterministic.
+    let symbols: Vec<&String> = symbol_bars
+        .keys()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    for symbol in &symbols {
+        let strategy = strategies
+            .get_mut(*symbol)
+            .ok_or_else(|| TrendLabError::Config(format!("no strategy for symbol {}", symbol)))?;
+        strategy.reset();
+        strategy.prepare(&symbol_bars[*symbol]);
+    }
+
+    let slippage_atr: Vec<Option<Vec<Option<f64>>>> = symbols
+        .iter()
+        .map(|symbol| config.cost_model.slippage_atr(&symbol_bars[*symbol]))
+        .collect();
+    let slip_atr = |k: usize, i: usize| slippage_atr[k].as_ref().and_then(|atr| atr[i]);
+
+    let timeline: BTreeSet<chrono::DateTime<chrono::Utc>> = symbol_bars
+        .values()
+        .flat_map(|bars| bars.iter().map(|b| b.ts))
+        .collect();
+
+    let mut cash = config.initial_cash;
+    let mut legs: Vec<PortfolioLeg> = symbols
+        .iter()
+        .map(|_| PortfolioLeg {
+            next_idx: 0,
+            position_qty: 0.0,
+            position: Position::Flat,
+            last_close: None,
+            pending_signal: None,
+            current_entry: None,
+        })
+        .collect();
+    let mut result = PortfolioResult {
+        fills: symbols.iter().map(|s| ((*s).clone(), vec![])).collect(),
+        trades: symbols.iter().map(|s| ((*s).clone(), vec![])).collect(),
+        equity: Vec::with_capacity(timeline.len()),
+    };
+
+    for (step, ts) in timeline.into_iter().enumerate() {
+        let prior_equity = result
+            .equity
+            .last()
+            .map(|p| p.equity)
+            .unwrap_or(config.initial_cash);
+
+        // Bars of each symbol that trade at this timestamp.
+        let today: Vec<Option<usize>> = symbols
+            .iter()
+            .zip(&legs)
+            .map(|(symbol, leg)| {
+                let bars = &symbol_bars[*symbol];
+                (leg.next_idx < bars.len() && bars[leg.next_idx].ts == ts).then_some(leg.next_idx)
+            })
+            .collect();
+
+        // Weights use only bars up to each symbol's prior close.
+        let histories: Vec<&[Bar]> = symbols
+            .iter()
+            .zip(&legs)
+            .map(|(symbol, leg)| &symbol_bars[*symbol][..leg.next_idx])
+            .collect();
+        let weights = allocation.target_weights(&histories);
+        let rebalance = allocation
+            .rebalance_every()
+            .is_some_and(|every| step > 0 && step % every == 0);
+
+        // 1) Exits at the open free cash before any entries are sized.
+        for (k, symbol) in symbols.iter().enumerate() {
+            let (Some(i), leg) = (today[k], &mut legs[k]) else {
+                continue;
+            };
+            if leg.position != Position::Long || leg.pending_signal != Some(Signal::ExitLong) {
+                continue;
+            }
+            leg.pending_signal = None;
+
+            let raw_price = config.fill_model.fill_price(&symbol_bars[*symbol][i]);
+            let fill = execute_fill(
+                ts,
+                Side::Sell,
+                leg.position_qty,
+                raw_price,
+                &config.cost_model,
+                None,
+                slip_atr(k, i),
+            );
+            cash += fill.qty * fill.price;
+            cash -= fill.fees;
+            leg.position_qty = 0.0;
+            leg.position = Position::Flat;
+
+            let entry = leg
+                .current_entry
+                .take()
+                .ok_or_else(|| TrendLabError::Strategy("exit fill without an entry fill".into()))?;
+            let gross_pnl = (fill.price - entry.price) * entry.qty;
+            let net_pnl = gross_pnl - entry.fees - fill.fees;
+
+            let trades = result.trades.get_mut(*symbol).expect("symbol registered");
+            trades.push(Trade {
+                entry,
+                exit: fill.clone(),
+                gross_pnl,
+                net_pnl,
+                direction: TradeDirection::Long,
+                exit_reason: ExitReason::Signal,
+            });
+            result
+                .fills
+                .get_mut(*symbol)
+                .expect("symbol registered")
+                .push(fill);
+        }
+
+        // 2) Rebalance open positions toward their weights: trims, then adds.
+        if rebalance {
+            let mut adds = Vec::new();
+            for (k, symbol) in symbols.iter().enumerate() {
+                let (Some(i), leg) = (today[k], &mut legs[k]) else {
+                    continue;
+                };
+                let raw_price = config.fill_model.fill_price(&symbol_bars[*symbol][i]);
+                if leg.position != Position::Long || raw_price <= 0.0 {
+                    continue;
+                }
+                let diff = weights[k] * prior_equity / raw_price - leg.position_qty;
+                if diff.abs() <= leg.position_qty * 1e-9 {
+                    continue;
+                }
+                if diff > 0.0 {
+                    adds.push((k, diff));
+                    continue;
+                }
+
+                let fill = execute_fill(
+                    ts,
+                    Side::Sell,
+                    -diff,
+                    raw_price,
+                    &config.cost_model,
+                    None,
+                    slip_atr(k, i),
+                );
+                cash += fill.qty * fill.price;
+                cash -= fill.fees;
+                leg.position_qty -= fill.qty;
+
+                let entry = leg.current_entry.as_mut().ok_or_else(|| {
+                    TrendLabError::Strategy("rebalance of a position without an entry".into())
+                })?;
+                let trade = close_partial(entry, fill.clone());
+                let trades = result.trades.get_mut(*symbol).expect("symbol registered");
+                trades.push(trade);
+                result
+                    .fills
+                    .get_mut(*symbol)
+                    .expect("symbol registered")
+                    .push(fill);
+            }
+
+            for (k, diff) in adds {
+                let symbol = symbols[k];
+                let leg = &mut legs[k];
+                let i = today[k].expect("traded today");
+                let raw_price = config.fill_model.fill_price(&symbol_bars[symbol][i]);
+                let price = config
+                    .cost_model
+                    .slipped_price(Side::Buy, raw_price, slip_atr(k, i));
+                let qty = diff.min(affordable_qty(cash, price, &config.cost_model));
+                if qty <= 0.0 {
+                    continue;
+                }
+
+                let fill = execute_fill(
+                    ts,
+                    Side::Buy,
+                    qty,
+                    raw_price,
+                    &config.cost_model,
+                    None,
+                    slip_atr(k, i),
+                );
+                cash -= fill.qty * fill.price;
+                cash -= fill.fees;
+                leg.position_qty += fill.qty;
+                let entry = leg.current_entry.as_mut().ok_or_else(|| {
+                    TrendLabError::Strategy("rebalance of a position without an entry".into())
+                })?;
+                add_to_entry(entry, &fill);
+                result
+                    .fills
+                    .get_mut(symbol)
+                    .expect("symbol registered")
+                    .push(fill);
+            }
+        }
+
+        // 3) Entries at the open, sized from prior equity and capped by cash.
+        for (k, symbol) in symbols.iter().enumerate() {
+            let (Some(i), leg) = (today[k], &mut legs[k]) else {
+                continue;
+            };
+            if leg.position != Position::Flat || leg.pending_signal != Some(Signal::EnterLong) {
+                continue;
+            }
+            leg.pending_signal = None;
+
+            let raw_price = config.fill_model.fill_price(&symbol_bars[*symbol][i]);
+            let price = config
+                .cost_model
+                .slipped_price(Side::Buy, raw_price, slip_atr(k, i));
+            if price <= 0.0 {
+                continue;
+            }
+            let qty = (prior_equity * weights[k] / price).min(affordable_qty(
+                cash,
+                price,
+                &config.cost_model,
+            ));
+            if qty <= 0.0 {
+                continue;
+            }
+
+            let fill = execute_fill(
+                ts,
+                Side::Buy,
+                qty,
+                raw_price,
+                &config.cost_model,
+                None,
+                slip_atr(k, i),
+            );
+            cash -= fill.qty * fill.price;
+            cash -= fill.fees;
+            leg.position_qty = fill.qty;
+            leg.position = Position::Long;
+            leg.current_entry = Some(fill.clone());
+            result
+                .fills
+                .get_mut(*symbol)
+                .expect("symbol registered")
+                .push(fill);
+        }
+
+        // 4) Mark to market, then compute signals on close for the next open.
+        let mut positions = BTreeMap::new();
+        for (k, symbol) in symbols.iter().enumerate() {
+            let leg = &mut legs[k];
+            if let Some(i) = today[k] {
+                let bars = &symbol_bars[*symbol];
+                leg.last_close = Some(bars[i].close);
+                leg.next_idx = i + 1;
+
+                let strategy = strategies.get_mut(*symbol).expect("checked above");
+                leg.pending_signal = if i + 1 >= config.effective_warmup(&**strategy) {
+                    Some(strategy.signal(&bars[..=i], leg.position))
+                } else {
+                    None
+                };
+            }
+            let value = leg.position_qty * leg.last_close.unwrap_or(0.0);
+            positions.insert((*symbol).clone(), value);
+        }
+
+        let equity = cash + positions.values().sum::<f64>();
+        result.equity.push(PortfolioEquityPoint {
+            ts,
+            cash,
+            positions,
+            equity,
+        });
+    }
+
+    Ok(result)
+}
+
+/// Tolerance used by [`assert_engine_parity`] when comparing prices, fees,
+/// quantities and equity. Applied relative to the larger magnitude, with an
+/// absolute floor of the tolerance itself for values near zero.
+pub const ENGINE_PARITY_TOLERANCE: f64 = 1e-6;
+
+/// One position in a result list where the two engines disagree.
+///
+/// `None` means that engine produced fewer entries than the other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParityMismatch<T> {
+    pub index: usize,
+    pub sequential: Option<T>,
+    pub polars: Option<T>,
+}
+
+/// Structured difference between `run_backtest` and `run_backtest_polars`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParityDiff {
+    /// Set when either engine failed to run; nothing was compared.
+    pub engine_error: Option<String>,
+    pub fills: Vec<ParityMismatch<Fill>>,
+    pub trades: Vec<ParityMismatch<Trade>>,
+    /// `(sequential, polars)` final equity when they differ.
+    pub final_equity: Option<(f64, f64)>,
+}
+
+impl ParityDiff {
+    /// True when no disagreement was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.engine_error.is_none()
+            && self.fills.is_empty()
+            && self.trades.is_empty()
+            && self.final_equity.is_none()
+    }
+
+    fn engine_error(msg: impl Into<String>) -> Self {
+        Self {
+            engine_error: Some(msg.into()),
+            ..Self::default()
+        }
+    }
+}
+
+impl std::fmt::Display for ParityDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(err) = &self.engine_error {
+            return write!(f, "engine error: {}", err);
+        }
+        write!(
+            f,
+            "{} fill mismatch(es), {} trade mismatch(es)",
+            self.fills.len(),
+            self.trades.len()
+        )?;
+        if let Some(first) = self.fills.first() {
+            write!(
+                f,
+                "; first fill #{}: sequential={:?} polars={:?}",
+                first.index, first.sequential, first.polars
+            )?;
+        }
+        if let Some((seq, pol)) = self.final_equity {
+            write!(f, "; final equity sequential={:.6} polars={:.6}", seq, pol)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs a `StrategyV2` through the sequential kernel via its bar-by-bar `signal`.
+struct SequentialV2<'a, S: ?Sized>(&'a S);
+
+impl<S: StrategyV2 + ?Sized> Strategy for SequentialV2<'_, S> {
+    fn id(&self) -> &str {
+        self.0.id()
+    }
+
+    fn warmup_period(&self) -> usize {
+        self.0.warmup_period()
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        self.0.signal(bars, current_position)
+    }
+
+    fn reset(&mut self) {}
+}
+
+fn parity_close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= ENGINE_PARITY_TOLERANCE * a.abs().max(b.abs()).max(1.0)
+}
+
+fn fills_match(a: &Fill, b: &Fill) -> bool {
+    a.ts == b.ts
+        && a.side == b.side
+        && parity_close(a.qty, b.qty)
+        && parity_close(a.price, b.price)
+        && parity_close(a.fees, b.fees)
+        && parity_close(a.raw_price, b.raw_price)
+}
+
+fn trades_match(a: &Trade, b: &Trade) -> bool {
+    fills_match(&a.entry, &b.entry)
+        && fills_match(&a.exit, &b.exit)
+        && a.direction == b.direction
+        && a.exit_reason == b.exit_reason
+        && parity_close(a.gross_pnl, b.gross_pnl)
+        && parity_close(a.net_pnl, b.net_pnl)
+}
+
+fn diff_lists<T: Clone>(
+    sequential: &[T],
+    polars: &[T],
+    matches: impl Fn(&T, &T) -> bool,
+) -> Vec<ParityMismatch<T>> {
+    (0..sequential.len().max(polars.len()))
+        .filter_map(|index| {
+            let seq = sequential.get(index);
+            let pol = polars.get(index);
+            match (seq, pol) {
+                (Some(a), Some(b)) if matches(a, b) => None,
+                _ => Some(ParityMismatch {
+                    index,
+                    sequential: seq.cloned(),
+                    polars: pol.cloned(),
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Run `strategy` through both `run_backtest` and `run_backtest_polars` and
+/// compare fills, trades and final equity.
+///
+/// The sequential engine drives the strategy through `StrategyV2::signal`,
+/// the Polars engine through its vectorized columns, so this checks both the
+/// two kernels and the strategy's two signal paths. Only the long-only,
+/// fixed-quantity configuration both engines support can be compared: pyramiding
+/// and stop/take-profit brackets are reported as an engine error.
+pub fn assert_engine_parity<S: StrategyV2 + ?Sized>(
+    bars: &[Bar],
+    strategy: &S,
+    config: BacktestConfig,
+) -> std::result::Result<(), ParityDiff> {
+    if config.pyramid_config.enabled
+        || config.stop_loss_pct.is_some()
+        || config.take_profit_pct.is_some()
+    {
+        return Err(ParityDiff::engine_error(
+            "pyramiding and stop brackets are not supported by the Polars engine",
+        ));
+    }
+
+    let sequential = run_backtest(bars, &mut SequentialV2(strategy), config)
+        .map_err(|e| ParityDiff::engine_error(format!("sequential: {}", e)))?;
+
+    let polars_config = PolarsBacktestConfig {
+        initial_cash: config.initial_cash,
+        qty: config.qty,
+        cost_model: config.cost_model,
+        trading_mode: TradingMode::LongOnly,
+        cash_yield_annual: config.cash_yield_annual,
+        fill_model: config.fill_model,
+        warmup_bars: config.warmup_bars,
+        ..PolarsBacktestConfig::default()
+    };
+    let df =
+        bars_to_dataframe(bars).map_err(|e| ParityDiff::engine_error(format!("polars: {}", e)))?;
+    let polars = run_backtest_polars(df.lazy(), strategy, &polars_config)
+        .and_then(|r| r.to_backtest_result())
+        .map_err(|e| ParityDiff::engine_error(format!("polars: {}", e)))?;
+
+    let seq_equity = sequential.last_equity().unwrap_or(config.initial_cash);
+    let polars_equity = polars.last_equity().unwrap_or(config.initial_cash);
+
+    let diff = ParityDiff {
+        engine_error: None,
+        fills: diff_lists(&sequential.fills, &polars.fills, fills_match),
+        trades: diff_lists(&sequential.trades, &polars.trades, trades_match),
+        final_equity: (!parity_close(seq_equity, polars_equity))
+            .then_some((seq_equity, polars_equity)),
+    };
+
+    if diff.is_empty() {
+        Ok(())
+    } else {
+        Err(diff)
+    }
+}
+
+/// A deterministic, test-only strategy: enter at a fixed bar index, exit at a fixed bar index.
+///
+/// The strategy generates signals on bar **close** at index `entry_idx` / `exit_idx`.
+#[derive(Debug, Clone)]
+pub struct FixedEntryExitStrategy {
+    entry_idx: usize,
+    exit_idx: usize,
+}
+
+impl FixedEntryExitStrategy {
+    pub fn new(entry_idx: usize, exit_idx: usize) -> Self {
+        Self {
+            entry_idx,
+            exit_idx,
+        }
+    }
+}
+
+impl Strategy for FixedEntryExitStrategy {
+    fn id(&self) -> &str {
+        "fixed_entry_exit"
+    }
+
+    fn warmup_period(&self) -> usize {
+        0
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        let idx = bars.len().saturating_sub(1);
+        match current_position {
+            Position::Flat if idx == self.entry_idx => Signal::EnterLong,
+            Position::Long if idx == self.exit_idx => Signal::ExitLong,
+            _ => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// A deterministic, test-only strategy for short positions.
+///
+/// The strategy generates short entry/exit signals at fixed bar indices.
+#[derive(Debug, Clone)]
+pub struct FixedShortStrategy {
+    entry_idx: usize,
+    exit_idx: usize,
+}
+
+impl FixedShortStrategy {
+    pub fn new(entry_idx: usize, exit_idx: usize) -> Self {
+        Self {
+            entry_idx,
+            exit_idx,
+        }
+    }
+}
+
+impl Strategy for FixedShortStrategy {
+    fn id(&self) -> &str {
         "fixed_short"
     }
 
@@ -1095,4 +2248,802 @@ mod tests {
             );
         }
     }
+
+    fn mk_ohlc_bar(day: u32, open: f64, high: f64, low: f64, close: f64) -> Bar {
+        let ts = chrono::Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap();
+        Bar::new(ts, open, high, low, close, 0.0, "TEST", "1d")
+    }
+
+    #[test]
+    fn stop_loss_exits_at_stop_price() {
+        let bars = vec![
+            mk_ohlc_bar(1, 100.0, 100.0, 100.0, 100.0), // Signal long at close
+            mk_ohlc_bar(2, 100.0, 102.0, 99.0, 101.0),  // Entry at 100, stop 95 untouched
+            mk_ohlc_bar(3, 101.0, 101.0, 94.0, 96.0),   // Low breaches 95 stop
+            mk_ohlc_bar(4, 96.0, 97.0, 95.0, 96.0),
+        ];
+
+        let mut strat = FixedEntryExitStrategy::new(0, 100);
+        let config = BacktestConfig::default().with_stop_loss(0.05);
+        let res = run_backtest(&bars, &mut strat, config).unwrap();
+
+        assert_eq!(res.trades.len(), 1);
+        let trade = &res.trades[0];
+        assert_eq!(trade.exit_reason, ExitReason::StopLoss);
+        assert!((trade.exit.price - 95.0).abs() < 1e-9);
+        assert_eq!(trade.exit.ts, bars[2].ts);
+        assert!((trade.gross_pnl + 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn take_profit_exits_at_target_price() {
+        let bars = vec![
+            mk_ohlc_bar(1, 100.0, 100.0, 100.0, 100.0),
+            mk_ohlc_bar(2, 100.0, 105.0, 99.0, 104.0), // Entry at 100, target 110 untouched
+            mk_ohlc_bar(3, 104.0, 112.0, 103.0, 111.0), // High breaches 110 target
+            mk_ohlc_bar(4, 111.0, 112.0, 110.0, 111.0),
+        ];
+
+        let mut strat = FixedEntryExitStrategy::new(0, 100);
+        let config = BacktestConfig::default().with_take_profit(0.10);
+        let res = run_backtest(&bars, &mut strat, config).unwrap();
+
+        assert_eq!(res.trades.len(), 1);
+        let trade = &res.trades[0];
+        assert_eq!(trade.exit_reason, ExitReason::TakeProfit);
+        assert!((trade.exit.price - 110.0).abs() < 1e-9);
+        assert!((trade.gross_pnl - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stop_and_target_same_bar_assumes_stop() {
+        let bars = vec![
+            mk_ohlc_bar(1, 100.0, 100.0, 100.0, 100.0),
+            mk_ohlc_bar(2, 100.0, 101.0, 99.0, 100.0),
+            mk_ohlc_bar(3, 100.0, 115.0, 90.0, 105.0), // Both 95 stop and 110 target in range
+        ];
+
+        let mut strat = FixedEntryExitStrategy::new(0, 100);
+        let config = BacktestConfig::default()
+            .with_stop_loss(0.05)
+            .with_take_profit(0.10);
+        let res = run_backtest(&bars, &mut strat, config).unwrap();
+
+        assert_eq!(res.trades.len(), 1);
+        assert_eq!(res.trades[0].exit_reason, ExitReason::StopLoss);
+        assert!((res.trades[0].exit.price - 95.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn gap_down_stop_fill_depends_on_intrabar_model() {
+        let bars = vec![
+            mk_ohlc_bar(1, 100.0, 100.0, 100.0, 100.0),
+            mk_ohlc_bar(2, 100.0, 101.0, 99.0, 100.0), // Entry at 100, stop 95
+            mk_ohlc_bar(3, 90.0, 92.0, 88.0, 91.0),    // Gaps down through the stop
+            mk_ohlc_bar(4, 91.0, 92.0, 90.0, 91.0),
+        ];
+
+        let exit_price = |intrabar| {
+            let mut strat = FixedEntryExitStrategy::new(0, 100);
+            let config = BacktestConfig::default()
+                .with_stop_loss(0.05)
+                .with_intrabar(intrabar);
+            let res = run_backtest(&bars, &mut strat, config).unwrap();
+            assert_eq!(res.trades.len(), 1);
+            assert_eq!(res.trades[0].exit_reason, ExitReason::StopLoss);
+            assert_eq!(res.trades[0].exit.ts, bars[2].ts);
+            res.trades[0].exit.price
+        };
+
+        assert!((exit_price(IntrabarModel::Gap) - 90.0).abs() < 1e-9);
+        assert!((exit_price(IntrabarModel::Optimistic) - 95.0).abs() < 1e-9);
+        assert!((exit_price(IntrabarModel::Pessimistic) - 88.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn intrabar_model_without_gap() {
+        // Opens above the stop, trades down through it, and above the target
+        let bar = mk_ohlc_bar(1, 100.0, 112.0, 92.0, 100.0);
+
+        assert_eq!(IntrabarModel::Gap.stop_fill_price(&bar, 95.0), 95.0);
+        assert_eq!(IntrabarModel::Optimistic.stop_fill_price(&bar, 95.0), 95.0);
+        assert_eq!(IntrabarModel::Pessimistic.stop_fill_price(&bar, 95.0), 92.0);
+        for model in [
+            IntrabarModel::Gap,
+            IntrabarModel::Optimistic,
+            IntrabarModel::Pessimistic,
+        ] {
+            assert_eq!(model.target_fill_price(&bar, 110.0), 110.0);
+        }
+    }
+
+    #[test]
+    fn sized_pyramid_adds_at_atr_spacing() {
+        // Close rises 1/bar, opens at the prior close, true range 2 => ATR 2
+        let bars: Vec<Bar> = (0..16)
+            .map(|i| {
+                let close = 100.0 + i as f64;
+                mk_ohlc_bar(i as u32 + 1, close - 1.0, close + 0.5, close - 1.5, close)
+            })
+            .collect();
+        let sizer = crate::sizing::FixedSizer::new(25.0);
+
+        // 1 ATR spacing = 2 points: entry at 103, adds at 105, 107, 109, 111
+        let config = BacktestConfig::default().with_pyramid(PyramidConfig::every_atr(10, 1.0, 3));
+        let mut strat = FixedEntryExitStrategy::new(3, 12);
+        let res = run_backtest_with_pyramid_sizer(&bars, &mut strat, &sizer, config).unwrap();
+
+        assert_eq!(res.pyramid_trades.len(), 1);
+        let trade = &res.pyramid_trades[0];
+        let prices: Vec<f64> = trade.entries.iter().map(|f| f.raw_price).collect();
+        assert_eq!(prices, vec![103.0, 105.0, 107.0, 109.0, 111.0]);
+        assert!(trade.entries.iter().all(|f| f.qty == 25.0));
+        assert_eq!(trade.total_units, 125.0);
+
+        // Capped at max_units
+        let config = BacktestConfig::default().with_pyramid(PyramidConfig::every_atr(3, 1.0, 3));
+        let res = run_backtest_with_pyramid_sizer(&bars, &mut strat, &sizer, config).unwrap();
+        assert_eq!(res.pyramid_trades[0].entries.len(), 3);
+    }
+
+    #[test]
+    fn sizer_backtest_feeds_equity_to_drawdown_sizer() {
+        use crate::sizing::{DrawdownSizer, FixedSizer};
+
+        let bars: Vec<Bar> = (1..=10).map(|day| mk_bar(day, 100.0, 100.0)).collect();
+        let config = BacktestSizingConfig::default();
+        let run = |sizer: &mut DrawdownSizer<FixedSizer>| {
+            let mut strat = FixedEntryExitStrategy::new(3, 6);
+            run_backtest_with_sizer(&bars, &mut strat, sizer, config.clone()).unwrap()
+        };
+
+        // A stale 150k reading would size fully; the backtest's 100k equity
+        // is a third below that peak, past the cap, so the entry is skipped
+        let mut sizer =
+            DrawdownSizer::new(FixedSizer::new(100.0), 0.20).with_equity(150_000.0, 150_000.0);
+        let res = run(&mut sizer);
+        assert!(res.fills.is_empty());
+
+        // 10% below the peak: half size
+        let peak = 100_000.0 / 0.9;
+        let mut sizer = DrawdownSizer::new(FixedSizer::new(100.0), 0.20).with_equity(peak, peak);
+        let res = run(&mut sizer);
+        assert_eq!(res.trades.len(), 1);
+        assert!((res.trades[0].entry.qty - 50.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn per_share_commission_with_minimum() {
+        let costs = CostModel::per_share(0.005, 1.0);
+
+        // 1,000 shares at $0.005/share = $5.00, above the minimum
+        assert!((costs.fees_for_fill(1_000.0, 3.0) - 5.0).abs() < 1e-9);
+        // 100 shares = $0.50, so the $1.00 minimum kicks in
+        assert!((costs.fees_for_fill(100.0, 3.0) - 1.0).abs() < 1e-9);
+
+        // Without a minimum, 100 shares cost exactly $0.50
+        let no_min = CostModel::per_share(0.005, 0.0);
+        assert!((no_min.fees_for_fill(100.0, 3.0) - 0.5).abs() < 1e-9);
+
+        // On top of bps: 10 bps on $300 notional = $0.30, plus $0.50 commission
+        let combined = CostModel {
+            fees_bps_per_side: 10.0,
+            commission_per_share: 0.005,
+            ..Default::default()
+        };
+        assert!((combined.fees_for_fill(100.0, 3.0) - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn per_share_commission_applied_to_fills() {
+        let bars = vec![
+            mk_bar(1, 2.0, 2.0),
+            mk_bar(2, 2.0, 2.0),
+            mk_bar(3, 2.5, 2.5),
+            mk_bar(4, 2.5, 2.5),
+        ];
+
+        let mut strat = FixedEntryExitStrategy::new(0, 1);
+        let config = BacktestConfig {
+            qty: 100.0,
+            cost_model: CostModel::per_share(0.005, 1.0),
+            ..Default::default()
+        };
+        let res = run_backtest(&bars, &mut strat, config).unwrap();
+
+        assert_eq!(res.fills.len(), 2);
+        assert!(res.fills.iter().all(|f| (f.fees - 1.0).abs() < 1e-9));
+        // $0.50 gross per share * 100 shares, less $1.00 minimum on each side
+        assert!((res.trades[0].net_pnl - 48.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn idle_cash_earns_yield() {
+        // One year of daily bars with no trades: equity should grow by ~5%
+        let start = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let bars: Vec<Bar> = (0..253)
+            .map(|i| {
+                let ts = start + chrono::Duration::days(i);
+                Bar::new(ts, 100.0, 100.0, 100.0, 100.0, 0.0, "TEST", "1d")
+            })
+            .collect();
+
+        let mut strat = FixedEntryExitStrategy::new(usize::MAX, usize::MAX);
+        let config = BacktestConfig::default().with_cash_yield(0.05);
+        let res = run_backtest(&bars, &mut strat, config).unwrap();
+
+        assert!(res.trades.is_empty());
+        let growth = res.equity.last().unwrap().equity / config.initial_cash - 1.0;
+        // 252 daily accruals at 5%/252 compound to ~5.13%
+        assert!(
+            (growth - 0.05).abs() < 0.002,
+            "expected ~5% growth, got {:.4}",
+            growth
+        );
+    }
+
+    fn symbol_bars(symbol: &str, prices: &[(u32, f64)]) -> Vec<Bar> {
+        prices
+            .iter()
+            .map(|&(day, price)| {
+                let ts = chrono::Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap();
+                Bar::new(ts, price, price, price, price, 0.0, symbol, "1d")
+            })
+            .collect()
+    }
+
+    fn fixed_strategies(specs: &[(&str, usize, usize)]) -> HashMap<String, Box<dyn Strategy>> {
+        specs
+            .iter()
+            .map(|&(symbol, entry, exit)| {
+                let strategy: Box<dyn Strategy> =
+                    Box::new(FixedEntryExitStrategy::new(entry, exit));
+                (symbol.to_string(), strategy)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn portfolio_equity_is_blended_sum_of_legs() {
+        // A trends up; B chops around and has no bar on day 4
+        let mut bars = HashMap::new();
+        bars.insert(
+            "A".to_string(),
+            symbol_bars(
+                "A",
+                &[
+                    (1, 100.0),
+                    (2, 102.0),
+                    (3, 104.0),
+                    (4, 106.0),
+                    (5, 108.0),
+                    (6, 110.0),
+                    (7, 112.0),
+                    (8, 114.0),
+                ],
+            ),
+        );
+        bars.insert(
+            "B".to_string(),
+            symbol_bars(
+                "B",
+                &[
+                    (1, 50.0),
+                    (2, 49.0),
+                    (3, 51.0),
+                    (5, 48.0),
+                    (6, 52.0),
+                    (7, 50.0),
+                    (8, 47.0),
+                ],
+            ),
+        );
+        let mut strategies = fixed_strategies(&[("A", 1, 5), ("B", 0, 4)]);
+
+        let res = run_portfolio_backtest(
+            &bars,
+            &mut strategies,
+            BacktestConfig::default(),
+            Allocation::EqualWeight,
+        )
+        .unwrap();
+
+        // One point per distinct date across both symbols
+        assert_eq!(res.equity.len(), 8);
+        for point in &res.equity {
+            assert!(point.cash >= 0.0, "cash went negative: {:?}", point);
+            let blended = point.cash + point.positions.values().sum::<f64>();
+            assert!((point.equity - blended).abs() < 1e-9);
+        }
+
+        // B enters day 2 with half the pool; on day 4 it is marked at day 3's close
+        let b_entry = &res.fills["B"][0];
+        assert_eq!(b_entry.price, 49.0);
+        assert!((b_entry.qty * b_entry.price - 50_000.0).abs() < 1e-6);
+        assert!((res.equity[3].positions["B"] - b_entry.qty * 51.0).abs() < 1e-9);
+
+        // Both legs closed: total P&L is the sum of the per-symbol trades
+        assert_eq!(res.trades["A"].len(), 1);
+        assert_eq!(res.trades["B"].len(), 1);
+        let total_pnl: f64 = res.trades.values().flatten().map(|t| t.net_pnl).sum();
+        let final_equity = res.last_equity().unwrap();
+        assert!((final_equity - 100_000.0 - total_pnl).abs() < 1e-6);
+    }
+
+    #[test]
+    fn portfolio_entries_capped_by_cash() {
+        let prices: Vec<(u32, f64)> = (1..=5).map(|d| (d, 100.0)).collect();
+        let mut bars = HashMap::new();
+        bars.insert("AAA".to_string(), symbol_bars("AAA", &prices));
+        bars.insert("BBB".to_string(), symbol_bars("BBB", &prices));
+        let mut strategies = fixed_strategies(&[("AAA", 0, 10), ("BBB", 0, 10)]);
+        let config = BacktestConfig {
+            cost_model: CostModel {
+                fees_bps_per_side: 10.0,
+                ..CostModel::default()
+            },
+            ..BacktestConfig::default()
+        };
+
+        let res = run_portfolio_backtest(
+            &bars,
+            &mut strategies,
+            config,
+            Allocation::FixedFraction(0.8),
+        )
+        .unwrap();
+
+        // AAA takes 80%; BBB only gets what is left after AAA's cost and fees
+        let aaa = &res.fills["AAA"][0];
+        let bbb = &res.fills["BBB"][0];
+        assert!((aaa.qty * aaa.price - 80_000.0).abs() < 1e-6);
+        assert!(bbb.qty * bbb.price + bbb.fees <= 100_000.0 - 80_000.0 - aaa.fees + 1e-9);
+        assert!(res.equity.iter().all(|p| p.cash >= 0.0));
+    }
+
+    #[test]
+    fn portfolio_rejects_bad_inputs() {
+        let mut bars = HashMap::new();
+        bars.insert("A".to_string(), symbol_bars("A", &[(1, 10.0), (2, 11.0)]));
+
+        let mut missing = fixed_strategies(&[("B", 0, 1)]);
+        let res = run_portfolio_backtest(
+            &bars,
+            &mut missing,
+            BacktestConfig::default(),
+            Allocation::EqualWeight,
+        );
+        assert!(res.is_err());
+
+        let mut strategies = fixed_strategies(&[("A", 0, 1)]);
+        let res = run_portfolio_backtest(
+            &bars,
+            &mut strategies,
+            BacktestConfig::default(),
+            Allocation::FixedFraction(1.5),
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn portfolio_fills_follow_fill_and_slippage_models() {
+        let bars: Vec<Bar> = (0..8)
+            .map(|i| {
+                let ts = chrono::Utc
+                    .with_ymd_and_hms(2024, 1, 1 + i as u32, 0, 0, 0)
+                    .unwrap();
+                let open = 100.0 + i as f64;
+                let range = 1.0 + (i % 3) as f64;
+                Bar::new(
+                    ts,
+                    open,
+                    open + range,
+                    open - range,
+                    open + 0.5,
+                    0.0,
+                    "A",
+                    "1d",
+                )
+            })
+            .collect();
+        let config = BacktestConfig {
+            fill_model: FillModel::Vwap,
+            cost_model: CostModel {
+                slippage_bps: 10.0,
+                slippage_model: SlippageModel::AtrFraction {
+                    period: 2,
+                    fraction: 0.5,
+                },
+                ..CostModel::default()
+            },
+            ..BacktestConfig::default()
+        };
+
+        let mut symbol_bars = HashMap::new();
+        symbol_bars.insert("A".to_string(), bars.clone());
+        let mut strategies = fixed_strategies(&[("A", 3, 6)]);
+        let res = run_portfolio_backtest(
+            &symbol_bars,
+            &mut strategies,
+            config,
+            Allocation::EqualWeight,
+        )
+        .unwrap();
+
+        // Same raw and slipped prices as the single-symbol engine
+        let single = run_backtest(&bars, &mut FixedEntryExitStrategy::new(3, 6), config).unwrap();
+        let prices = |fills: &[Fill]| -> Vec<(f64, f64)> {
+            fills.iter().map(|f| (f.raw_price, f.price)).collect()
+        };
+        assert_eq!(res.fills["A"].len(), 2);
+        assert_eq!(prices(&res.fills["A"]), prices(&single.fills));
+        assert_eq!(res.fills["A"][0].raw_price, bars[4].typical_price());
+        assert!(res.fills["A"][0].price > res.fills["A"][0].raw_price);
+    }
+
+    /// Flat-priced bars at 100 whose range sets the ATR (`range` on each side).
+    fn ranged_bars(symbol: &str, ranges: &[f64]) -> Vec<Bar> {
+        ranges
+            .iter()
+            .enumerate()
+            .map(|(i, &range)| {
+                let ts = chrono::Utc
+                    .with_ymd_and_hms(2024, 1, 1 + i as u32, 0, 0, 0)
+                    .unwrap();
+                Bar::new(
+                    ts,
+                    100.0,
+                    100.0 + range,
+                    100.0 - range,
+                    100.0,
+                    0.0,
+                    symbol,
+                    "1d",
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn risk_parity_halves_weight_of_twice_as_volatile_symbol() {
+        let mut bars = HashMap::new();
+        bars.insert("CALM".to_string(), ranged_bars("CALM", &[1.0; 10]));
+        bars.insert("WILD".to_string(), ranged_bars("WILD", &[2.0; 10]));
+        let mut strategies = fixed_strategies(&[("CALM", 5, 100), ("WILD", 5, 100)]);
+        let allocation = Allocation::RiskParity {
+            lookback: 5,
+            rebalance_every: 100,
+        };
+
+        let res = run_portfolio_backtest(
+            &bars,
+            &mut strategies,
+            BacktestConfig::default(),
+            allocation,
+        )
+        .unwrap();
+
+        let calm = &res.fills["CALM"][0];
+        let wild = &res.fills["WILD"][0];
+        let calm_notional = calm.qty * calm.price;
+        let wild_notional = wild.qty * wild.price;
+        assert!((calm_notional - 200_000.0 / 3.0).abs() < 1.0);
+        assert!((wild_notional / calm_notional - 0.5).abs() < 0.01);
+        assert!(res.equity.iter().all(|p| p.cash >= 0.0));
+    }
+
+    #[test]
+    fn risk_parity_caps_near_zero_volatility() {
+        let flat = ranged_bars("FLAT", &[0.0; 6]);
+        let normal = ranged_bars("NORMAL", &[1.0; 6]);
+        let allocation = Allocation::RiskParity {
+            lookback: 5,
+            rebalance_every: 1,
+        };
+
+        let weights = allocation.target_weights(&[&flat, &normal]);
+        assert_eq!(weights[0], Allocation::MAX_RISK_PARITY_WEIGHT);
+        assert!(weights[1] < 0.01);
+
+        // Still warming up: equal-weight share
+        let weights = allocation.target_weights(&[&flat[..2], &normal]);
+        assert_eq!(weights[0], 0.5);
+        assert!((weights[1] - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn risk_parity_rebalances_when_volatility_shifts() {
+        // STEADY keeps its range; SHIFTY triples its range from bar 15
+        let mut shifty_ranges = vec![1.0; 15];
+        shifty_ranges.extend([3.0; 15]);
+        let mut bars = HashMap::new();
+        bars.insert("STEADY".to_string(), ranged_bars("STEADY", &[1.0; 30]));
+        bars.insert("SHIFTY".to_string(), ranged_bars("SHIFTY", &shifty_ranges));
+        let mut strategies = fixed_strategies(&[("STEADY", 5, 28), ("SHIFTY", 5, 28)]);
+        let allocation = Allocation::RiskParity {
+            lookback: 5,
+            rebalance_every: 5,
+        };
+
+        let res = run_portfolio_backtest(
+            &bars,
+            &mut strategies,
+            BacktestConfig::default(),
+            allocation,
+        )
+        .unwrap();
+
+        // SHIFTY was trimmed once its risk rose; STEADY was topped up
+        let shifty_trades = &res.trades["SHIFTY"];
+        assert!(shifty_trades
+            .iter()
+            .any(|t| t.exit_reason == ExitReason::Rebalance));
+        assert!(res.fills["STEADY"].len() > 2);
+        let at_20 = &res.equity[20].positions;
+        assert!((at_20["STEADY"] / at_20["SHIFTY"] - 3.0).abs() < 0.01);
+
+        for point in &res.equity {
+            assert!(point.cash >= 0.0);
+        }
+        let total_pnl: f64 = res.trades.values().flatten().map(|t| t.net_pnl).sum();
+        assert!((res.last_equity().unwrap() - 100_000.0 - total_pnl).abs() < 1e-6);
+    }
+
+    fn parity_fixture_bars(n: i64) -> Vec<Bar> {
+        let start = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        (0..n)
+            .map(|i| {
+                let t = i as f64;
+                let close = 100.0 + 0.3 * t + 8.0 * (t / 7.0).sin();
+                let open = close - 0.5 * (t / 3.0).cos();
+                let high = open.max(close) + 0.25;
+                let low = open.min(close) - 0.25;
+                Bar::new(
+                    start + chrono::Duration::days(i),
+                    open,
+                    high,
+                    low,
+                    close,
+                    1000.0,
+                    "TEST",
+                    "1d",
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn engines_agree_across_strategies() {
+        use crate::strategy_v2::{DonchianBreakoutV2, TsmomV2};
+
+        let bars = parity_fixture_bars(200);
+        let costly = BacktestConfig {
+            initial_cash: 10_000.0,
+            qty: 10.0,
+            cost_model: CostModel {
+                fees_bps_per_side: 5.0,
+                slippage_bps: 2.0,
+                ..CostModel::default()
+            },
+            cash_yield_annual: 0.03,
+            ..BacktestConfig::default()
+        };
+        let strategies: Vec<Box<dyn StrategyV2>> = vec![
+            Box::new(DonchianBreakoutV2::new(10, 5)),
+            Box::new(DonchianBreakoutV2::new(20, 10)),
+            Box::new(TsmomV2::new(21)),
+        ];
+
+        for strategy in &strategies {
+            let traded = run_backtest(&bars, &mut SequentialV2(strategy.as_ref()), costly)
+                .unwrap()
+                .trades
+                .len();
+            assert!(traded > 0, "{} never traded on the fixture", strategy.id());
+
+            for config in [
+                BacktestConfig::default(),
+                costly,
+                costly.with_warmup_bars(60),
+            ] {
+                if let Err(diff) = assert_engine_parity(&bars, strategy.as_ref(), config) {
+                    panic!("{} engines disagree: {}", strategy.id(), diff);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn engine_parity_rejects_unsupported_config() {
+        use crate::strategy_v2::DonchianBreakoutV2;
+
+        let config = BacktestConfig {
+            stop_loss_pct: Some(0.05),
+            ..BacktestConfig::default()
+        };
+        let diff = assert_engine_parity(
+            &parity_fixture_bars(50),
+            &DonchianBreakoutV2::new(10, 5),
+            config,
+        )
+        .unwrap_err();
+        assert!(diff.engine_error.is_some());
+    }
+
+    #[test]
+    fn vwap_fill_uses_typical_price_and_reconciles() {
+        let ts = |day| chrono::Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap();
+        let bars = vec![
+            Bar::new(ts(1), 10.0, 11.0, 9.0, 10.0, 100.0, "TEST", "1d"),
+            Bar::new(ts(2), 10.0, 12.0, 9.5, 11.0, 100.0, "TEST", "1d"),
+            Bar::new(ts(3), 11.0, 14.0, 10.0, 13.0, 100.0, "TEST", "1d"),
+            Bar::new(ts(4), 13.0, 15.0, 12.0, 14.5, 100.0, "TEST", "1d"),
+            Bar::new(ts(5), 14.0, 16.0, 13.0, 15.0, 100.0, "TEST", "1d"),
+        ];
+        let config = BacktestConfig {
+            initial_cash: 10_000.0,
+            qty: 10.0,
+            fill_model: FillModel::Vwap,
+            cost_model: CostModel {
+                fees_bps_per_side: 10.0,
+                ..CostModel::default()
+            },
+            ..BacktestConfig::default()
+        };
+
+        let mut strat = FixedEntryExitStrategy::new(1, 3);
+        let res = run_backtest(&bars, &mut strat, config).unwrap();
+
+        // Entry signal on bar 1 fills at bar 2's (14 + 10 + 13) / 3
+        let entry = &res.fills[0];
+        assert!((entry.raw_price - 37.0 / 3.0).abs() < 1e-12);
+        assert!((entry.price - bars[2].typical_price()).abs() < 1e-12);
+        assert!((res.trades[0].exit.price - bars[4].typical_price()).abs() < 1e-12);
+
+        // Flat at the end: equity = initial cash + realized net PnL
+        let net: f64 = res.trades.iter().map(|t| t.net_pnl).sum();
+        assert!((res.last_equity().unwrap() - (10_000.0 + net)).abs() < 1e-9);
+
+        // The Polars engine fills at the same price
+        let strategy = crate::strategy_v2::DonchianBreakoutV2::new(10, 5);
+        if let Err(diff) = assert_engine_parity(&parity_fixture_bars(200), &strategy, config) {
+            panic!("VWAP engines disagree: {}", diff);
+        }
+    }
+
+    #[test]
+    fn atr_slippage_penalizes_volatile_bars_more() {
+        // Same opens and closes; only the intrabar range differs
+        let fixture = |half_range: f64| -> Vec<Bar> {
+            (0..30)
+                .map(|i| {
+                    let ts = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+                        + chrono::Duration::days(i);
+                    let px = 100.0 + i as f64 * 0.5;
+                    Bar::new(
+                        ts,
+                        px,
+                        px + half_range,
+                        px - half_range,
+                        px,
+                        1000.0,
+                        "TEST",
+                        "1d",
+                    )
+                })
+                .collect()
+        };
+        let config = BacktestConfig {
+            cost_model: CostModel {
+                slippage_model: SlippageModel::AtrFraction {
+                    period: 14,
+                    fraction: 0.1,
+                },
+                ..CostModel::default()
+            },
+            ..BacktestConfig::default()
+        };
+
+        let run = |bars: &[Bar]| {
+            let mut strat = FixedEntryExitStrategy::new(19, 24);
+            run_backtest(bars, &mut strat, config).unwrap()
+        };
+        let calm_bars = fixture(0.5);
+        let wild_bars = fixture(5.0);
+        let calm = run(&calm_bars);
+        let wild = run(&wild_bars);
+
+        // Entry fills on bar 20 at open + 0.1 × ATR(14) of that bar
+        let wild_atr = atr_wilder(&wild_bars, 14)[20].unwrap();
+        let wild_entry = &wild.fills[0];
+        assert!((wild_entry.price - (wild_entry.raw_price + 0.1 * wild_atr)).abs() < 1e-9);
+
+        let entry_slip = |r: &BacktestResult| r.fills[0].price - r.fills[0].raw_price;
+        let exit_slip = |r: &BacktestResult| r.fills[1].raw_price - r.fills[1].price;
+        assert!(entry_slip(&wild) > entry_slip(&calm));
+        assert!(exit_slip(&wild) > exit_slip(&calm));
+        assert!(wild.trades[0].net_pnl < calm.trades[0].net_pnl);
+
+        // The Polars engine charges the same ATR slippage
+        let strategy = crate::strategy_v2::DonchianBreakoutV2::new(10, 5);
+        if let Err(diff) = assert_engine_parity(&parity_fixture_bars(200), &strategy, config) {
+            panic!("ATR slippage engines disagree: {}", diff);
+        }
+    }
+
+    #[test]
+    fn buy_and_hold_matches_price_ratio_net_of_entry_costs() {
+        let bars = vec![
+            mk_bar(1, 50.0, 52.0),
+            mk_bar(2, 52.0, 55.0),
+            mk_bar(3, 55.0, 54.0),
+            mk_bar(4, 54.0, 60.0),
+        ];
+        let config = BacktestConfig {
+            initial_cash: 10_000.0,
+            cost_model: CostModel {
+                fees_bps_per_side: 10.0,
+                ..CostModel::default()
+            },
+            ..BacktestConfig::default()
+        };
+
+        let bh = buy_and_hold(&bars, &config);
+        assert_eq!(bh.fills.len(), 1);
+        assert!(bh.trades.is_empty());
+
+        // Everything but the entry fee is invested at the first open
+        let entry_fees = bh.fills[0].fees;
+        assert!(entry_fees > 0.0);
+        let expected = (10_000.0 - entry_fees) * (60.0 / 50.0);
+        assert!((bh.last_equity().unwrap() - expected).abs() < 1e-6);
+
+        // Relative metrics are only populated once a benchmark is supplied
+        assert!(bh.vs_benchmark.is_none());
+        let mut strat = FixedEntryExitStrategy::new(0, 99);
+        let res = run_backtest(&bars, &mut strat, config)
+            .unwrap()
+            .with_benchmark(&bh);
+        let rel = res.vs_benchmark.unwrap();
+        assert_eq!(rel.n_observations, 3);
+        assert!(bh.clone().with_benchmark(&bh).vs_benchmark.unwrap().beta > 0.999);
+    }
+
+    #[test]
+    fn warmup_bars_suppresses_early_entries() {
+        // Steady rise: every close clears the prior 50-day high once one exists
+        let start = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let bars: Vec<Bar> = (0..120)
+            .map(|i| {
+                let ts = start + chrono::Duration::days(i);
+                let p = 100.0 + i as f64;
+                Bar::new(ts, p, p + 0.5, p - 0.5, p, 0.0, "TEST", "1d")
+            })
+            .collect();
+
+        let first_entry = |config: BacktestConfig| {
+            let mut strat = crate::strategy::DonchianBreakoutStrategy::new(50, 25);
+            let res = run_backtest(&bars, &mut strat, config).unwrap();
+            bars.iter().position(|b| b.ts == res.fills[0].ts).unwrap()
+        };
+
+        // Breakout signal at bar 50 (the first full channel), filled at 51
+        assert_eq!(first_entry(BacktestConfig::default()), 51);
+        // A shorter override never undercuts the strategy's own warmup
+        assert_eq!(
+            first_entry(BacktestConfig::default().with_warmup_bars(10)),
+            51
+        );
+        // A longer one delays the first signal to bar 79, filled at 80
+        assert_eq!(
+            first_entry(BacktestConfig::default().with_warmup_bars(80)),
+            80
+        );
+
+        // A strategy with no declared warmup is held back too
+        let mut eager = FixedEntryExitStrategy::new(0, 100);
+        let config = BacktestConfig::default().with_warmup_bars(50);
+        let res = run_backtest(&bars, &mut eager, config).unwrap();
+        assert!(res.fills.is_empty());
+    }
 }
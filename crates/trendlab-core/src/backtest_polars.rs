@@ -7,6 +7,7 @@
 //! Assumptions (same as sequential backtest):
 //! - Signals are computed on bar close
 //! - Fills occur on the next bar open
+//! - A signal on the final bar has no next open and is dropped
 //! - Supports Long-only, Short-only, or Long/Short trading modes
 //!
 //! Position States:
@@ -14,9 +15,14 @@
 //! -  0 = Flat (no position)
 //! -  1 = Long (positive position)
 
-use crate::backtest::{BacktestResult, CostModel, EquityPoint, Fill, Side, Trade, TradeDirection};
+use crate::backtest::{
+    cash_interest, BacktestResult, CostModel, EquityPoint, ExitReason, Fill, FillModel, Side,
+    SlippageModel, Trade, TradeDirection,
+};
 use crate::error::{Result, TrendLabError};
+use crate::indicators::{atr_wilder_from_true_range, true_range_hlc};
 use crate::indicators_polars::donchian_channel_exprs;
+use crate::metrics::MetricsConfig;
 use crate::strategy_v2::StrategyV2;
 use chrono::{TimeZone, Utc};
 use polars::prelude::*;
@@ -33,6 +39,15 @@ pub struct PolarsBacktestConfig {
     pub cost_model: CostModel,
     /// Trading mode (LongOnly, ShortOnly, or LongShort)
     pub trading_mode: crate::strategy::TradingMode,
+    /// Annual yield earned on idle cash (accrues daily at yield / 252)
+    pub cash_yield_annual: f64,
+    /// Annualization used for sweep metrics (set `periods_per_year` for non-daily bars)
+    pub metrics: MetricsConfig,
+    /// Price at which pending signals fill on the next bar
+    pub fill_model: FillModel,
+    /// Minimum bars of history before any signal is acted on (same as
+    /// `BacktestConfig::warmup_bars` on the sequential engine)
+    pub warmup_bars: Option<usize>,
 }
 
 impl Default for PolarsBacktestConfig {
@@ -42,6 +57,10 @@ impl Default for PolarsBacktestConfig {
             qty: 1.0,
             cost_model: CostModel::default(),
             trading_mode: crate::strategy::TradingMode::LongOnly,
+            cash_yield_annual: 0.0,
+            metrics: MetricsConfig::default(),
+            fill_model: FillModel::NextOpen,
+            warmup_bars: None,
         }
     }
 }
@@ -53,9 +72,28 @@ impl PolarsBacktestConfig {
             qty,
             cost_model: CostModel::default(),
             trading_mode: crate::strategy::TradingMode::LongOnly,
+            cash_yield_annual: 0.0,
+            metrics: MetricsConfig::default(),
+            fill_model: FillModel::NextOpen,
+            warmup_bars: None,
         }
     }
 
+    pub fn with_metrics_config(mut self, metrics: MetricsConfig) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub fn with_cash_yield(mut self, annual_yield: f64) -> Self {
+        self.cash_yield_annual = annual_yield;
+        self
+    }
+
+    pub fn with_fill_model(mut self, fill_model: FillModel) -> Self {
+        self.fill_model = fill_model;
+        self
+    }
+
     pub fn with_cost_model(mut self, cost_model: CostModel) -> Self {
         self.cost_model = cost_model;
         self
@@ -65,6 +103,11 @@ impl PolarsBacktestConfig {
         self.trading_mode = trading_mode;
         self
     }
+
+    pub fn with_warmup_bars(mut self, bars: usize) -> Self {
+        self.warmup_bars = Some(bars);
+        self
+    }
 }
 
 /// Configuration for Polars-native Donchian backtest.
@@ -125,6 +168,10 @@ impl DonchianBacktestConfig {
             qty: self.qty,
             cost_model: self.cost_model,
             trading_mode: crate::strategy::TradingMode::LongOnly,
+            cash_yield_annual: 0.0,
+            metrics: MetricsConfig::default(),
+            fill_model: FillModel::NextOpen,
+            warmup_bars: None,
         }
     }
 }
@@ -156,6 +203,7 @@ impl PolarsBacktestResult {
                 trades: vec![],
                 pyramid_trades: vec![],
                 equity: vec![],
+                vs_benchmark: None,
             });
         }
 
@@ -217,6 +265,7 @@ impl PolarsBacktestResult {
             trades,
             pyramid_trades: vec![],
             equity,
+            vs_benchmark: None,
         })
     }
 
@@ -259,6 +308,12 @@ impl PolarsBacktestResult {
             .map_err(TrendLabError::Polars)?
             .f64()
             .map_err(TrendLabError::Polars)?;
+        // Absent on frames produced before VWAP fills existed; those filled at the open
+        let fill_raw_price_col = self
+            .df
+            .column("fill_raw_price")
+            .ok()
+            .and_then(|c| c.f64().ok());
         let fill_price_col = self
             .df
             .column("fill_price")
@@ -302,7 +357,11 @@ impl PolarsBacktestResult {
                     .timestamp_millis_opt(ts_ms)
                     .single()
                     .unwrap_or_else(Utc::now);
-                let raw_price = open_col.get(i).unwrap_or(0.0);
+                let raw_price = fill_raw_price_col
+                    .as_ref()
+                    .and_then(|c| c.get(i))
+                    .or_else(|| open_col.get(i))
+                    .unwrap_or(0.0);
                 let price = fill_price_col.get(i).unwrap_or(raw_price);
                 let fees = fill_fees_col.get(i).unwrap_or(0.0);
                 let qty = fill_qty_col.get(i).unwrap_or(0.0);
@@ -339,6 +398,7 @@ impl PolarsBacktestResult {
                             gross_pnl,
                             net_pnl,
                             direction: TradeDirection::Long,
+                            exit_reason: ExitReason::Signal,
                         });
                     }
                 }
@@ -357,6 +417,7 @@ impl PolarsBacktestResult {
                             gross_pnl,
                             net_pnl,
                             direction: TradeDirection::Short,
+                            exit_reason: ExitReason::Signal,
                         });
                     }
                 }
@@ -584,6 +645,21 @@ fn apply_position_state_machine(
         .f64()
         .map_err(TrendLabError::Polars)?;
 
+    // VWAP fills need the bar's range; NextOpen only reads the open
+    let range_cols = match config.fill_model {
+        FillModel::NextOpen => None,
+        FillModel::Vwap => Some((
+            df.column("high")
+                .map_err(TrendLabError::Polars)?
+                .f64()
+                .map_err(TrendLabError::Polars)?,
+            df.column("low")
+                .map_err(TrendLabError::Polars)?
+                .f64()
+                .map_err(TrendLabError::Polars)?,
+        )),
+    };
+
     // Output arrays - position_state: -1=Short, 0=Flat, 1=Long
     let mut position_state: Vec<i32> = Vec::with_capacity(n);
     let mut entry_fill: Vec<bool> = Vec::with_capacity(n); // Long entry
@@ -591,6 +667,7 @@ fn apply_position_state_machine(
     let mut entry_short_fill: Vec<bool> = Vec::with_capacity(n); // Short entry
     let mut exit_short_fill: Vec<bool> = Vec::with_capacity(n); // Short exit (cover)
     let mut fill_price: Vec<f64> = Vec::with_capacity(n);
+    let mut fill_raw_price: Vec<f64> = Vec::with_capacity(n);
     let mut fill_fees: Vec<f64> = Vec::with_capacity(n);
     let mut fill_qty: Vec<f64> = Vec::with_capacity(n);
     let mut cash: Vec<f64> = Vec::with_capacity(n);
@@ -602,18 +679,47 @@ fn apply_position_state_machine(
     let mut current_position_qty = 0.0; // Positive for long, negative for short
     let mut current_state = 0_i32; // -1=Short, 0=Flat, 1=Long
 
+    // Signals before this many bars of history are ignored
+    let warmup = config.warmup_bars.unwrap_or(0);
+
     // Pending signals from previous bar
     let mut pending_entry_long = false;
     let mut pending_exit_long = false;
     let mut pending_entry_short = false;
     let mut pending_exit_short = false;
 
-    let fee_rate = config.cost_model.fees_bps_per_side / 10_000.0;
-    let slippage_rate = config.cost_model.slippage_bps / 10_000.0;
+    // Per-bar ATR when slippage scales with volatility (same series as the sequential engine)
+    let slippage_atr = match config.cost_model.slippage_model {
+        SlippageModel::FixedBps => None,
+        SlippageModel::AtrFraction { period, .. } => {
+            let series = |name: &str| -> Result<Vec<f64>> {
+                Ok(df
+                    .column(name)
+                    .map_err(TrendLabError::Polars)?
+                    .f64()
+                    .map_err(TrendLabError::Polars)?
+                    .into_iter()
+                    .map(|v| v.unwrap_or(0.0))
+                    .collect())
+            };
+            let tr = true_range_hlc(&series("high")?, &series("low")?, &series("close")?);
+            Some(atr_wilder_from_true_range(&tr, period))
+        }
+    };
 
     for i in 0..n {
         let open = open_col.get(i).unwrap_or(0.0);
         let close = close_col.get(i).unwrap_or(0.0);
+        let bar_atr = slippage_atr.as_ref().and_then(|atr| atr[i]);
+        // Same as FillModel::fill_price on the sequential side
+        let raw_fill_price = match &range_cols {
+            Some((high_col, low_col)) => {
+                let high = high_col.get(i).unwrap_or(open);
+                let low = low_col.get(i).unwrap_or(open);
+                (high + low + close) / 3.0
+            }
+            None => open,
+        };
 
         let mut is_entry_fill = false;
         let mut is_exit_fill = false;
@@ -623,12 +729,24 @@ fn apply_position_state_machine(
         let mut bar_fill_fees = 0.0;
         let mut bar_fill_qty = 0.0;
 
-        // Execute pending signals on this bar's open
+        // Accrue interest on idle cash held over the prior bar
+        if i > 0 {
+            current_cash += cash_interest(
+                current_cash,
+                current_position_qty,
+                close_col.get(i - 1).unwrap_or(0.0),
+                config.cash_yield_annual,
+            );
+        }
+
+        // Execute pending signals on this bar (at the open, or the typical price for VWAP)
         if i > 0 {
             if pending_entry_long && current_state == 0 {
                 // Execute long entry (buy to open)
-                let price = open * (1.0 + slippage_rate); // Slippage makes price worse for buyer
-                let fees = price * config.qty * fee_rate;
+                let price = config
+                    .cost_model
+                    .slipped_price(Side::Buy, raw_fill_price, bar_atr);
+                let fees = config.cost_model.fees_for_fill(config.qty, price);
 
                 current_cash -= price * config.qty;
                 current_cash -= fees;
@@ -641,8 +759,10 @@ fn apply_position_state_machine(
                 bar_fill_qty = config.qty;
             } else if pending_exit_long && current_state == 1 {
                 // Execute long exit (sell to close)
-                let price = open * (1.0 - slippage_rate); // Slippage makes price worse for seller
-                let fees = price * current_position_qty * fee_rate;
+                let price = config
+                    .cost_model
+                    .slipped_price(Side::Sell, raw_fill_price, bar_atr);
+                let fees = config.cost_model.fees_for_fill(current_position_qty, price);
 
                 current_cash += price * current_position_qty;
                 current_cash -= fees;
@@ -656,8 +776,10 @@ fn apply_position_state_machine(
             } else if pending_entry_short && current_state == 0 {
                 // Execute short entry (sell to open)
                 // When shorting: receive cash from sale, but need to eventually buy back
-                let price = open * (1.0 - slippage_rate); // Slippage makes price worse for seller
-                let fees = price * config.qty * fee_rate;
+                let price = config
+                    .cost_model
+                    .slipped_price(Side::Sell, raw_fill_price, bar_atr);
+                let fees = config.cost_model.fees_for_fill(config.qty, price);
 
                 current_cash += price * config.qty; // Receive cash from short sale
                 current_cash -= fees;
@@ -670,9 +792,11 @@ fn apply_position_state_machine(
                 bar_fill_qty = config.qty;
             } else if pending_exit_short && current_state == -1 {
                 // Execute short exit (buy to cover)
-                let price = open * (1.0 + slippage_rate); // Slippage makes price worse for buyer
+                let price = config
+                    .cost_model
+                    .slipped_price(Side::Buy, raw_fill_price, bar_atr);
                 let qty_to_cover = current_position_qty.abs();
-                let fees = price * qty_to_cover * fee_rate;
+                let fees = config.cost_model.fees_for_fill(qty_to_cover, price);
 
                 current_cash -= price * qty_to_cover; // Pay to buy back shares
                 current_cash -= fees;
@@ -699,6 +823,11 @@ fn apply_position_state_machine(
         entry_short_fill.push(is_entry_short_fill);
         exit_short_fill.push(is_exit_short_fill);
         fill_price.push(bar_fill_price);
+        fill_raw_price.push(if bar_fill_qty > 0.0 {
+            raw_fill_price
+        } else {
+            0.0
+        });
         fill_fees.push(bar_fill_fees);
         fill_qty.push(bar_fill_qty);
         cash.push(current_cash);
@@ -741,7 +870,11 @@ fn apply_position_state_machine(
             TradingMode::LongOnly => false,
         };
 
-        // Generate pending signals based on current state
+        // Generate pending signals based on current state. Signals raised on
+        // the final bar stay pending when the loop ends and are never filled.
+        if i + 1 < warmup {
+            continue;
+        }
         match current_state {
             0 => {
                 // Flat: can enter long or short (based on trading mode)
@@ -751,18 +884,10 @@ fn apply_position_state_machine(
                     pending_entry_short = true;
                 }
             }
-            1 => {
-                // Long: can only exit long
-                if has_exit_long {
-                    pending_exit_long = true;
-                }
-            }
-            -1 => {
-                // Short: can only exit short
-                if has_exit_short {
-                    pending_exit_short = true;
-                }
-            }
+            // Long: can only exit long
+            1 if has_exit_long => pending_exit_long = true,
+            // Short: can only exit short
+            -1 if has_exit_short => pending_exit_short = true,
             _ => {}
         }
     }
@@ -780,6 +905,8 @@ fn apply_position_state_machine(
         .map_err(TrendLabError::Polars)?;
     df.with_column(Series::new("fill_price".into(), fill_price))
         .map_err(TrendLabError::Polars)?;
+    df.with_column(Series::new("fill_raw_price".into(), fill_raw_price))
+        .map_err(TrendLabError::Polars)?;
     df.with_column(Series::new("fill_fees".into(), fill_fees))
         .map_err(TrendLabError::Polars)?;
     df.with_column(Series::new("fill_qty".into(), fill_qty))
@@ -904,7 +1031,7 @@ pub fn run_strategy_sweep_polars(
     strategy_config: &crate::sweep::StrategyGridConfig,
     config: &PolarsBacktestConfig,
 ) -> Result<crate::sweep::SweepResult> {
-    use crate::metrics::compute_metrics;
+    use crate::metrics::compute_metrics_with_config;
     use crate::strategy_v2::create_strategy_v2_from_config;
     use crate::sweep::{ConfigId, SweepConfigResult, SweepResult};
     use chrono::Utc;
@@ -937,7 +1064,8 @@ pub fn run_strategy_sweep_polars(
         let backtest_result = polars_result.to_backtest_result()?;
 
         // Compute metrics
-        let metrics = compute_metrics(&backtest_result, config.initial_cash);
+        let metrics =
+            compute_metrics_with_config(&backtest_result, config.initial_cash, &config.metrics);
 
         // Create legacy ConfigId for compatibility
         let legacy_config_id = strategy_config_id.to_legacy_config_id();
@@ -959,6 +1087,7 @@ pub fn run_strategy_sweep_polars(
         config_results,
         started_at,
         completed_at,
+        cancelled: false,
     })
 }
 
@@ -969,7 +1098,7 @@ fn process_single_config(
     df_arc: &std::sync::Arc<polars::frame::DataFrame>,
     config: &PolarsBacktestConfig,
 ) -> Option<crate::sweep::SweepConfigResult> {
-    use crate::metrics::compute_metrics;
+    use crate::metrics::compute_metrics_with_config;
     use crate::strategy_v2::create_strategy_v2_from_config;
     use crate::sweep::{ConfigId, SweepConfigResult};
 
@@ -984,7 +1113,8 @@ fn process_single_config(
     let backtest_result = polars_result.to_backtest_result().ok()?;
 
     // Compute metrics
-    let metrics = compute_metrics(&backtest_result, config.initial_cash);
+    let metrics =
+        compute_metrics_with_config(&backtest_result, config.initial_cash, &config.metrics);
 
     // Create legacy ConfigId for compatibility
     let legacy_config_id = strategy_config_id.to_legacy_config_id();
@@ -1009,12 +1139,36 @@ pub fn run_strategy_sweep_polars_parallel(
     df: &DataFrame,
     strategy_config: &crate::sweep::StrategyGridConfig,
     config: &PolarsBacktestConfig,
+) -> Result<crate::sweep::SweepResult> {
+    let never_cancelled = std::sync::atomic::AtomicBool::new(false);
+    run_strategy_sweep_polars_parallel_with_cancel(df, strategy_config, config, &never_cancelled)
+}
+
+/// Run a parallel Polars sweep that stops early once `cancel` is set.
+///
+/// Each rayon task checks the flag before starting its config, so a cancelled
+/// sweep finishes only the configs already in flight. The partial result is
+/// returned with `cancelled` set rather than as an error.
+pub fn run_strategy_sweep_polars_parallel_with_cancel(
+    df: &DataFrame,
+    strategy_config: &crate::sweep::StrategyGridConfig,
+    config: &PolarsBacktestConfig,
+    cancel: &std::sync::atomic::AtomicBool,
 ) -> Result<crate::sweep::SweepResult> {
     use crate::sweep::{SweepConfigResult, SweepResult};
     use chrono::Utc;
     use rayon::prelude::*;
+    use std::sync::atomic::Ordering;
     use std::sync::Arc;
 
+    let run_config = |strategy_config_id: &crate::sweep::StrategyConfigId,
+                      df_arc: &Arc<DataFrame>| {
+        if cancel.load(Ordering::Relaxed) {
+            return None;
+        }
+        process_single_config(strategy_config_id, df_arc, config)
+    };
+
     let sweep_id = format!(
         "polars_{}_{}_{}",
         strategy_config.strategy_type.id(),
@@ -1036,20 +1190,17 @@ pub fn run_strategy_sweep_polars_parallel(
         // Small sweep: process all at once
         configs
             .par_iter()
-            .filter_map(|strategy_config_id| {
-                process_single_config(strategy_config_id, &df_arc, config)
-            })
+            .filter_map(|strategy_config_id| run_config(strategy_config_id, &df_arc))
             .collect()
     } else {
         // Large sweep: process in chunks to limit rayon queue depth
         configs
             .chunks(CHUNK_SIZE)
+            .take_while(|_| !cancel.load(Ordering::Relaxed))
             .flat_map(|chunk| {
                 chunk
                     .par_iter()
-                    .filter_map(|strategy_config_id| {
-                        process_single_config(strategy_config_id, &df_arc, config)
-                    })
+                    .filter_map(|strategy_config_id| run_config(strategy_config_id, &df_arc))
                     .collect::<Vec<_>>()
             })
             .collect()
@@ -1062,9 +1213,98 @@ pub fn run_strategy_sweep_polars_parallel(
         config_results,
         started_at,
         completed_at,
+        cancelled: cancel.load(Ordering::Relaxed),
     })
 }
 
+/// Run every enabled strategy in `grid` across several symbols, in parallel.
+///
+/// Each (symbol, strategy) pair becomes one rayon task, so small grids no longer
+/// leave cores idle while a large one finishes. Thread use is bounded the same
+/// way as YOLO mode:
+/// - `outer_threads` caps the rayon pool that runs the tasks (and their inner
+///   per-config parallelism); `None` uses the global pool
+/// - `polars_max_threads` sets `POLARS_MAX_THREADS` for Polars' own pool
+///
+/// Tasks are collected in symbol-then-grid order before aggregation, so the
+/// result does not depend on completion order. A seeded grid also gets a
+/// seed-derived `sweep_id`, leaving only the wall-clock timestamps to differ
+/// between runs. The first failing sweep aborts the run.
+pub fn run_multi_strategy_sweep_polars_parallel(
+    symbol_dfs: &std::collections::HashMap<String, DataFrame>,
+    grid: &crate::sweep::MultiStrategyGrid,
+    config: &PolarsBacktestConfig,
+    polars_max_threads: Option<usize>,
+    outer_threads: Option<usize>,
+) -> Result<crate::sweep::MultiStrategySweepResult> {
+    use crate::sweep::{MultiStrategySweepResult, SweepResult};
+    use rayon::prelude::*;
+
+    let available_threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+
+    if let Some(threads) = polars_max_threads {
+        let capped = threads.max(1).min(available_threads);
+        std::env::set_var("POLARS_MAX_THREADS", capped.to_string());
+    }
+
+    let mut symbols: Vec<&String> = symbol_dfs.keys().collect();
+    symbols.sort();
+
+    let tasks: Vec<(&String, &crate::sweep::StrategyGridConfig)> = symbols
+        .iter()
+        .flat_map(|symbol| {
+            grid.strategies
+                .iter()
+                .filter(|s| s.enabled)
+                .map(move |s| (*symbol, s))
+        })
+        .collect();
+
+    let started_at = Utc::now();
+    let run_tasks = || -> Vec<Result<SweepResult>> {
+        tasks
+            .par_iter()
+            .map(|(symbol, strategy_config)| {
+                run_strategy_sweep_polars_parallel(&symbol_dfs[*symbol], strategy_config, config)
+            })
+            .collect()
+    };
+
+    let sweep_results = match outer_threads {
+        Some(threads) => {
+            let capped = threads.max(1).min(available_threads);
+            match rayon::ThreadPoolBuilder::new().num_threads(capped).build() {
+                Ok(pool) => pool.install(run_tasks),
+                Err(_) => run_tasks(),
+            }
+        }
+        None => run_tasks(),
+    };
+
+    let sweep_id = if grid.seed != 0 {
+        format!("multi_strategy_polars_seed_{}", grid.seed)
+    } else {
+        format!(
+            "multi_strategy_polars_{}",
+            started_at.format("%Y%m%d_%H%M%S")
+        )
+    };
+    let mut result = MultiStrategySweepResult::new(sweep_id);
+    for ((symbol, strategy_config), sweep_result) in tasks.iter().zip(sweep_results) {
+        result.add_result(
+            (*symbol).clone(),
+            strategy_config.strategy_type,
+            sweep_result?,
+        );
+    }
+    result.started_at = started_at;
+    result.compute_aggregations();
+
+    Ok(result)
+}
+
 /// Run a Polars-native sweep with indicator caching.
 ///
 /// This is the most optimized version of the sweep runner:
@@ -1081,7 +1321,7 @@ pub fn run_strategy_sweep_polars_cached(
     config: &PolarsBacktestConfig,
 ) -> Result<crate::sweep::SweepResult> {
     use crate::indicator_cache::{collect_indicator_requirements, IndicatorCache};
-    use crate::metrics::compute_metrics;
+    use crate::metrics::compute_metrics_with_config;
     use crate::strategy_v2::create_strategy_v2_from_config;
     use crate::sweep::{ConfigId, SweepConfigResult, SweepResult};
     use chrono::Utc;
@@ -1132,7 +1372,8 @@ pub fn run_strategy_sweep_polars_cached(
                 run_backtest_polars(cached_df.clone().lazy(), strategy.as_ref(), config).ok()?;
 
             let backtest_result = polars_result.to_backtest_result().ok()?;
-            let metrics = compute_metrics(&backtest_result, config.initial_cash);
+            let metrics =
+                compute_metrics_with_config(&backtest_result, config.initial_cash, &config.metrics);
             let legacy_config_id = strategy_config_id.to_legacy_config_id();
 
             Some(SweepConfigResult {
@@ -1153,6 +1394,7 @@ pub fn run_strategy_sweep_polars_cached(
         config_results,
         started_at,
         completed_at,
+        cancelled: false,
     })
 }
 
@@ -1165,7 +1407,7 @@ pub fn run_strategy_sweep_polars_optimized(
     strategy_config: &crate::sweep::StrategyGridConfig,
     config: &PolarsBacktestConfig,
 ) -> Result<crate::sweep::SweepResult> {
-    use crate::metrics::compute_metrics;
+    use crate::metrics::compute_metrics_with_config;
     use crate::strategy_v2::create_strategy_v2_from_config;
     use crate::sweep::{ConfigId, SweepConfigResult, SweepResult};
     use chrono::Utc;
@@ -1202,7 +1444,8 @@ pub fn run_strategy_sweep_polars_optimized(
             let polars_result =
                 run_backtest_polars(df.clone().lazy(), strategy.as_ref(), config).ok()?;
             let backtest_result = polars_result.to_backtest_result().ok()?;
-            let metrics = compute_metrics(&backtest_result, config.initial_cash);
+            let metrics =
+                compute_metrics_with_config(&backtest_result, config.initial_cash, &config.metrics);
             let legacy_config_id = strategy_config_id.to_legacy_config_id();
 
             Some(SweepConfigResult {
@@ -1223,6 +1466,7 @@ pub fn run_strategy_sweep_polars_optimized(
         config_results,
         started_at,
         completed_at,
+        cancelled: false,
     })
 }
 
@@ -1241,7 +1485,7 @@ pub fn run_strategy_sweep_polars_lazy(
     config: &PolarsBacktestConfig,
 ) -> Result<crate::sweep::SweepResult> {
     use crate::indicator_cache::{collect_indicator_requirements, LazyIndicatorCache};
-    use crate::metrics::compute_metrics;
+    use crate::metrics::compute_metrics_with_config;
     use crate::strategy_v2::create_strategy_v2_from_config;
     use crate::sweep::{ConfigId, SweepConfigResult, SweepResult};
     use chrono::Utc;
@@ -1286,7 +1530,8 @@ pub fn run_strategy_sweep_polars_lazy(
                 run_backtest_polars(cached_df.clone().lazy(), strategy.as_ref(), config).ok()?;
 
             let backtest_result = polars_result.to_backtest_result().ok()?;
-            let metrics = compute_metrics(&backtest_result, config.initial_cash);
+            let metrics =
+                compute_metrics_with_config(&backtest_result, config.initial_cash, &config.metrics);
             let legacy_config_id = strategy_config_id.to_legacy_config_id();
 
             Some(SweepConfigResult {
@@ -1307,6 +1552,7 @@ pub fn run_strategy_sweep_polars_lazy(
         config_results,
         started_at,
         completed_at,
+        cancelled: false,
     })
 }
 
@@ -1470,7 +1716,7 @@ where
     F: Fn(StreamingSweepProgress) + Send + Sync,
 {
     use crate::indicator_cache::{collect_indicator_requirements, LazyIndicatorCache};
-    use crate::metrics::compute_metrics;
+    use crate::metrics::compute_metrics_with_config;
     use crate::strategy_v2::create_strategy_v2_from_config;
     use polars::io::parquet::write::ParquetWriter;
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -1628,7 +1874,11 @@ where
                     run_backtest_polars(cached_df.clone().lazy(), strategy.as_ref(), config)
                         .ok()?;
                 let backtest_result = polars_result.to_backtest_result().ok()?;
-                let metrics = compute_metrics(&backtest_result, config.initial_cash);
+                let metrics = compute_metrics_with_config(
+                    &backtest_result,
+                    config.initial_cash,
+                    &config.metrics,
+                );
                 let legacy_config_id = strategy_config_id.to_legacy_config_id();
 
                 Some((
@@ -1762,6 +2012,7 @@ mod tests {
     use super::*;
     use crate::bar::Bar;
     use crate::data::bars_to_dataframe;
+    use crate::strategy::{Position, Signal};
     use chrono::TimeZone;
 
     fn make_trending_bars(n: usize, trend: f64) -> Vec<Bar> {
@@ -1780,6 +2031,163 @@ mod tests {
             .collect()
     }
 
+    fn make_multi_strategy_fixture() -> (
+        std::collections::HashMap<String, DataFrame>,
+        crate::sweep::MultiStrategyGrid,
+    ) {
+        use crate::sweep::{MultiStrategyGrid, StrategyGridConfig, StrategyParams, StrategyTypeId};
+
+        let mut symbol_dfs = std::collections::HashMap::new();
+        symbol_dfs.insert(
+            "UP".to_string(),
+            bars_to_dataframe(&make_trending_bars(150, 0.5)).unwrap(),
+        );
+        symbol_dfs.insert(
+            "DOWN".to_string(),
+            bars_to_dataframe(&make_trending_bars(150, -0.3)).unwrap(),
+        );
+
+        let grid = MultiStrategyGrid {
+            strategies: vec![
+                StrategyGridConfig {
+                    strategy_type: StrategyTypeId::Donchian,
+                    enabled: true,
+                    params: StrategyParams::Donchian {
+                        entry_lookbacks: vec![10, 20],
+                        exit_lookbacks: vec![5, 10],
+                    },
+                },
+                StrategyGridConfig {
+                    strategy_type: StrategyTypeId::Tsmom,
+                    enabled: true,
+                    params: StrategyParams::Tsmom {
+                        lookbacks: vec![10, 30],
+                    },
+                },
+            ],
+            seed: 0,
+        };
+
+        (symbol_dfs, grid)
+    }
+
+    #[test]
+    fn test_multi_strategy_parallel_matches_serial() {
+        let (symbol_dfs, grid) = make_multi_strategy_fixture();
+        let config = PolarsBacktestConfig::new(100_000.0, 100.0);
+
+        let serial =
+            run_multi_strategy_sweep_polars_parallel(&symbol_dfs, &grid, &config, None, Some(1))
+                .unwrap();
+        let parallel =
+            run_multi_strategy_sweep_polars_parallel(&symbol_dfs, &grid, &config, None, Some(4))
+                .unwrap();
+
+        assert_eq!(serial.results.len(), 4);
+        assert_eq!(serial.results.len(), parallel.results.len());
+        for (key, a) in &serial.results {
+            let b = &parallel.results[key];
+            assert_eq!(a.config_results.len(), b.config_results.len());
+            for (ra, rb) in a.config_results.iter().zip(&b.config_results) {
+                assert_eq!(ra.config_id, rb.config_id);
+                assert_eq!(ra.backtest_result, rb.backtest_result);
+                assert_eq!(ra.metrics.sharpe.to_bits(), rb.metrics.sharpe.to_bits());
+            }
+        }
+        for (symbol, best) in &serial.best_per_symbol {
+            let other = &parallel.best_per_symbol[symbol];
+            assert_eq!(
+                best.metrics.sharpe.to_bits(),
+                other.metrics.sharpe.to_bits()
+            );
+        }
+    }
+
+    /// Serialize everything except wall-clock timestamps, in a fixed key order.
+    fn canonical_bytes(result: &crate::sweep::MultiStrategySweepResult) -> Vec<u8> {
+        let mut results: Vec<_> = result
+            .results
+            .iter()
+            .map(|((symbol, strategy), sweep)| {
+                ((symbol.clone(), strategy.id()), &sweep.config_results)
+            })
+            .collect();
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut best_per_symbol: Vec<_> = result.best_per_symbol.iter().collect();
+        best_per_symbol.sort_by(|a, b| a.0.cmp(b.0));
+        let mut best_per_strategy: Vec<_> = result
+            .best_per_strategy
+            .iter()
+            .map(|(k, v)| (k.id(), v))
+            .collect();
+        best_per_strategy.sort_by(|a, b| a.0.cmp(b.0));
+
+        serde_json::to_vec(&(
+            &result.sweep_id,
+            results,
+            best_per_symbol,
+            best_per_strategy,
+            &result.strategy_comparison,
+        ))
+        .unwrap()
+    }
+
+    /// Daily bars following a seeded random walk, so no strategy has a clear edge.
+    fn make_random_walk_bars(n: usize, seed: u64) -> Vec<Bar> {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let mut close: f64 = 100.0;
+        (0..n)
+            .map(|i| {
+                let ts = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+                    + chrono::Duration::days(i as i64);
+                let open = close;
+                close *= 1.0 + rng.gen_range(-0.02..0.021);
+                let high = open.max(close) * 1.005;
+                let low = open.min(close) * 0.995;
+                Bar::new(ts, open, high, low, close, 1000.0, "TEST", "1d")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_seeded_multi_strategy_sweep_is_reproducible() {
+        use crate::sweep::{apply_fdr, fdr_p_values};
+        use crate::MultipleComparisonMethod;
+
+        let (_, grid) = make_multi_strategy_fixture();
+        let grid = grid.with_seed(20240101);
+        let config = PolarsBacktestConfig::new(100_000.0, 100.0);
+        let mut symbol_dfs = std::collections::HashMap::new();
+        symbol_dfs.insert(
+            "WALK".to_string(),
+            bars_to_dataframe(&make_random_walk_bars(250, 3)).unwrap(),
+        );
+
+        let single =
+            run_multi_strategy_sweep_polars_parallel(&symbol_dfs, &grid, &config, None, Some(1))
+                .unwrap();
+        let multi =
+            run_multi_strategy_sweep_polars_parallel(&symbol_dfs, &grid, &config, None, Some(4))
+                .unwrap();
+
+        assert_eq!(single.sweep_id, "multi_strategy_polars_seed_20240101");
+        assert_eq!(canonical_bytes(&single), canonical_bytes(&multi));
+
+        // The grid draws no randomness; the seed drives the FDR permutation tests
+        let p_values = fdr_p_values(&single, grid.seed);
+        assert_eq!(p_values, fdr_p_values(&multi, grid.seed));
+        assert_ne!(p_values, fdr_p_values(&single, grid.seed + 1));
+
+        let method = MultipleComparisonMethod::BenjaminiHochberg;
+        assert_eq!(
+            apply_fdr(&single, 0.05, method, grid.seed),
+            apply_fdr(&multi, 0.05, method, grid.seed)
+        );
+    }
+
     #[test]
     fn test_polars_backtest_empty() {
         let bars: Vec<Bar> = vec![];
@@ -1871,6 +2279,7 @@ mod tests {
             .with_cost_model(CostModel {
                 fees_bps_per_side: 10.0, // 10 bps = 0.1%
                 slippage_bps: 5.0,       // 5 bps = 0.05%
+                ..Default::default()
             });
         let result_with_cost = run_donchian_backtest_polars(df.lazy(), &config_with_cost).unwrap();
 
@@ -1881,6 +2290,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_polars_vs_sequential_parity_per_share_commission() {
+        use crate::backtest::{run_backtest, BacktestConfig};
+        use crate::strategy::DonchianBreakoutStrategy;
+
+        let bars = make_trending_bars(100, 0.5);
+        let cost_model = CostModel::per_share(0.005, 1.0);
+
+        let mut strategy = DonchianBreakoutStrategy::new(10, 5);
+        let seq_config = BacktestConfig {
+            initial_cash: 10000.0,
+            qty: 10.0,
+            cost_model,
+            ..BacktestConfig::default()
+        };
+        let seq_result = run_backtest(&bars, &mut strategy, seq_config).unwrap();
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let polars_config = DonchianBacktestConfig::new(10, 5)
+            .with_initial_cash(10000.0)
+            .with_qty(10.0)
+            .with_cost_model(cost_model);
+        let polars_result = run_donchian_backtest_polars(df.lazy(), &polars_config).unwrap();
+
+        // 10 shares at $0.005 is below the minimum, so every fill pays $1.00
+        assert!(seq_result.fills.iter().all(|f| (f.fees - 1.0).abs() < 1e-9));
+
+        let seq_final = seq_result.equity.last().unwrap().equity;
+        assert!(
+            (seq_final - polars_result.final_equity).abs() < 10.0,
+            "Equity mismatch: sequential={:.2} vs polars={:.2}",
+            seq_final,
+            polars_result.final_equity
+        );
+    }
+
+    #[test]
+    fn test_polars_idle_cash_earns_yield() {
+        use crate::strategy_v2::DonchianBreakoutV2;
+
+        // Flat prices never break out, so the account sits in cash all year
+        let bars: Vec<Bar> = (0..253)
+            .map(|i| {
+                let ts =
+                    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::days(i);
+                Bar::new(ts, 100.0, 100.0, 100.0, 100.0, 1000.0, "TEST", "1d")
+            })
+            .collect();
+        let df = bars_to_dataframe(&bars).unwrap();
+
+        let strategy = DonchianBreakoutV2::new(20, 10);
+        let config = PolarsBacktestConfig::new(100_000.0, 1.0).with_cash_yield(0.05);
+        let result = run_backtest_polars(df.lazy(), &strategy, &config).unwrap();
+
+        assert_eq!(result.num_trades, 0);
+        let growth = result.final_equity / 100_000.0 - 1.0;
+        assert!(
+            (growth - 0.05).abs() < 0.002,
+            "expected ~5% growth, got {:.4}",
+            growth
+        );
+    }
+
     #[test]
     fn test_polars_vs_sequential_parity() {
         use crate::backtest::{run_backtest, BacktestConfig};
@@ -2288,6 +2760,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parallel_sweep_cancel_returns_partial_result() {
+        use crate::sweep::{StrategyGridConfig, StrategyParams, StrategyTypeId};
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let bars = make_trending_bars(2000, 0.1);
+        let df = bars_to_dataframe(&bars).unwrap();
+        let grid = StrategyGridConfig {
+            strategy_type: StrategyTypeId::Donchian,
+            enabled: true,
+            params: StrategyParams::Donchian {
+                entry_lookbacks: (5..=60).collect(),
+                exit_lookbacks: (2..=30).collect(),
+            },
+        };
+        let total = grid.config_count();
+        let config = PolarsBacktestConfig::default();
+
+        // Already-cancelled sweeps do no work
+        let cancel = AtomicBool::new(true);
+        let result =
+            run_strategy_sweep_polars_parallel_with_cancel(&df, &grid, &config, &cancel).unwrap();
+        assert!(result.cancelled);
+        assert!(result.is_empty());
+
+        // Cancelling mid-run stops at the next config boundary
+        let cancel = AtomicBool::new(false);
+        let started = std::time::Instant::now();
+        let result = std::thread::scope(|scope| {
+            scope.spawn(|| {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+                cancel.store(true, Ordering::SeqCst);
+            });
+            run_strategy_sweep_polars_parallel_with_cancel(&df, &grid, &config, &cancel).unwrap()
+        });
+        assert!(result.cancelled);
+        assert!(result.len() < total);
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+    }
+
     #[test]
     fn test_streaming_sweep_basic() {
         use crate::sweep::{StrategyGridConfig, StrategyParams, StrategyTypeId};
@@ -2461,4 +2973,97 @@ mod tests {
         assert_eq!(result.total_configs, 0);
         assert_eq!(result.batches_written, 0);
     }
+    /// Enters once the close exceeds 150; never exits.
+    struct LastBarBreakout {
+        spec: crate::strategy_v2::StrategySpec,
+    }
+
+    impl LastBarBreakout {
+        fn new() -> Self {
+            Self {
+                spec: crate::strategy_v2::StrategySpec::donchian(1, 1),
+            }
+        }
+    }
+
+    impl crate::strategy::Strategy for LastBarBreakout {
+        fn id(&self) -> &str {
+            "last_bar_breakout"
+        }
+
+        fn warmup_period(&self) -> usize {
+            0
+        }
+
+        fn signal(&self, bars: &[Bar], position: Position) -> Signal {
+            let close = bars.last().map(|b| b.close).unwrap_or(0.0);
+            if position == Position::Flat && close > 150.0 {
+                Signal::EnterLong
+            } else {
+                Signal::Hold
+            }
+        }
+
+        fn reset(&mut self) {}
+    }
+
+    impl StrategyV2 for LastBarBreakout {
+        fn spec(&self) -> &crate::strategy_v2::StrategySpec {
+            &self.spec
+        }
+
+        fn warmup_period(&self) -> usize {
+            0
+        }
+
+        fn signal(&self, bars: &[Bar], position: Position) -> Signal {
+            crate::strategy::Strategy::signal(self, bars, position)
+        }
+
+        fn add_indicators_to_lf(&self, lf: LazyFrame) -> LazyFrame {
+            lf
+        }
+
+        fn add_signals_to_lf(&self, lf: LazyFrame) -> LazyFrame {
+            lf.with_columns([
+                col("close").gt(lit(150.0)).alias("raw_entry"),
+                lit(false).alias("raw_exit"),
+            ])
+        }
+    }
+
+    #[test]
+    fn test_last_bar_signal_ignored_by_both_engines() {
+        let mut bars: Vec<Bar> = (0..20)
+            .map(|i| {
+                let ts =
+                    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::days(i);
+                Bar::new(ts, 100.0, 101.0, 99.0, 100.0, 1000.0, "TEST", "1d")
+            })
+            .collect();
+        let last = bars.last_mut().unwrap();
+        last.high = 201.0;
+        last.close = 200.0;
+
+        let initial_cash = 10_000.0;
+        let seq_config = crate::backtest::BacktestConfig {
+            initial_cash,
+            qty: 1.0,
+            ..Default::default()
+        };
+        let mut strategy = LastBarBreakout::new();
+        let seq = crate::backtest::run_backtest(&bars, &mut strategy, seq_config).unwrap();
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let polars_config = PolarsBacktestConfig::new(initial_cash, 1.0);
+        let polars = run_backtest_polars(df.lazy(), &strategy, &polars_config).unwrap();
+        let polars_trades = polars.to_backtest_result().unwrap().trades;
+
+        assert!(seq.fills.is_empty());
+        assert!(seq.trades.is_empty());
+        assert_eq!(seq.trades.len(), polars_trades.len());
+        assert_eq!(polars.num_trades, 0);
+        assert_eq!(seq.last_equity(), Some(initial_cash));
+        assert!((polars.final_equity - initial_cash).abs() < 1e-9);
+    }
 }
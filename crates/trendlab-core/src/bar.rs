@@ -66,6 +66,11 @@ impl Bar {
         (self.close - self.open).abs()
     }
 
+    /// Returns the bar's typical price ((high + low + close) / 3).
+    pub fn typical_price(&self) -> f64 {
+        (self.high + self.low + self.close) / 3.0
+    }
+
     /// Returns true if this is a bullish (green) bar.
     pub fn is_bullish(&self) -> bool {
         self.close > self.open
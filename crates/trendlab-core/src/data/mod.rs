@@ -1,7 +1,7 @@
 //! Data layer: providers, caching, normalization, and quality checks.
 //!
 //! This module handles:
-//! - Fetching raw OHLCV data from providers (Yahoo Finance, etc.)
+//! - Fetching raw OHLCV data from providers (Yahoo Finance, Tiingo)
 //! - Caching raw responses with metadata
 //! - Normalizing to canonical Parquet format
 //! - Data quality validation and reporting
@@ -9,6 +9,8 @@
 mod parquet;
 mod provider;
 mod quality;
+mod resample;
+mod tiingo;
 mod yahoo;
 
 pub use parquet::{
@@ -16,6 +18,16 @@ pub use parquet::{
     read_parquet, scan_multiple_parquet_lazy, scan_parquet_lazy, scan_symbol_parquet_lazy,
     write_parquet, write_partitioned_parquet,
 };
-pub use provider::{CacheMetadata, DataSource, FetchRequest, FetchResult, ProviderError};
-pub use quality::{DataQualityChecker, DataQualityReport, QualityIssue};
-pub use yahoo::{build_yahoo_chart_url, build_yahoo_url, parse_yahoo_chart_json, parse_yahoo_csv};
+pub use provider::{
+    CacheMetadata, DataSource, FetchRequest, FetchResult, ProviderError, RateLimiter, RetryPolicy,
+    DEFAULT_REQUESTS_PER_SEC,
+};
+pub use quality::{
+    DataQualityChecker, DataQualityReport, FillMethod, QualityIssue, TradingCalendar,
+};
+pub use resample::{resample, ResamplePeriod};
+pub use tiingo::{build_tiingo_url, parse_tiingo_json, tiingo_api_key, TIINGO_API_KEY_VAR};
+pub use yahoo::{
+    build_yahoo_chart_url, build_yahoo_url, check_yahoo_response, parse_yahoo_chart_json,
+    parse_yahoo_csv,
+};
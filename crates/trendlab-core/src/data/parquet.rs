@@ -389,7 +389,9 @@ pub fn scan_symbol_parquet_lazy(
             .single()
             .unwrap()
             .timestamp_millis();
-        lf = lf.filter(col("ts").gt_eq(lit(start_ts)));
+        lf = lf.filter(
+            col("ts").gt_eq(lit(start_ts).cast(DataType::Datetime(TimeUnit::Milliseconds, None))),
+        );
     }
 
     if let Some(end) = end_date {
@@ -398,7 +400,9 @@ pub fn scan_symbol_parquet_lazy(
             .single()
             .unwrap()
             .timestamp_millis();
-        lf = lf.filter(col("ts").lt_eq(lit(end_ts)));
+        lf = lf.filter(
+            col("ts").lt_eq(lit(end_ts).cast(DataType::Datetime(TimeUnit::Milliseconds, None))),
+        );
     }
 
     Ok(lf)
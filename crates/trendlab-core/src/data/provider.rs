@@ -5,9 +5,13 @@
 
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Errors that can occur when fetching or parsing provider data.
+///
+/// Each failure mode has its own variant so callers can decide whether to
+/// retry (see [`ProviderError::is_retryable`]).
 #[derive(Debug, Error)]
 pub enum ProviderError {
     #[error("Symbol not found: {symbol}")]
@@ -16,17 +20,64 @@ pub enum ProviderError {
     #[error("Parse error: {message}")]
     ParseError { message: String },
 
+    #[error("Parse error at row {row}: {message}")]
+    RowParseError { row: usize, message: String },
+
+    #[error("Empty response for {symbol}")]
+    EmptyResponse { symbol: String },
+
     #[error("Invalid date range: {start} to {end}")]
     InvalidDateRange { start: NaiveDate, end: NaiveDate },
 
     #[error("Network error: {message}")]
     NetworkError { message: String },
 
+    #[error("Rate limited by provider: {message}")]
+    RateLimited { message: String },
+
+    #[error("Request rejected by provider: HTTP {status}")]
+    RequestRejected { status: u16 },
+
     #[error("Cache error: {message}")]
     CacheError { message: String },
 
     #[error("IO error: {message}")]
     IoError { message: String },
+
+    #[error("Missing API key for {provider}: set the {var} environment variable")]
+    MissingApiKey { provider: String, var: String },
+}
+
+impl ProviderError {
+    /// Map a non-success HTTP status to a provider error.
+    ///
+    /// Only 408 (timeout), 429 (rate limit) and 5xx map to retryable errors;
+    /// any other status means the request itself was rejected.
+    pub fn from_http_status(status: u16, symbol: &str) -> Self {
+        match status {
+            404 => ProviderError::SymbolNotFound {
+                symbol: symbol.to_string(),
+            },
+            429 => ProviderError::RateLimited {
+                message: "HTTP 429 Too Many Requests".to_string(),
+            },
+            408 | 500..=599 => ProviderError::NetworkError {
+                message: format!("HTTP {}", status),
+            },
+            _ => ProviderError::RequestRejected { status },
+        }
+    }
+
+    /// Returns true if the request may succeed when retried after a delay.
+    ///
+    /// Only rate limiting and network failures are transient; a missing
+    /// symbol, an empty result or an unparseable body will not change.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ProviderError::RateLimited { .. } | ProviderError::NetworkError { .. }
+        )
+    }
 }
 
 impl From<std::io::Error> for ProviderError {
@@ -75,6 +126,139 @@ impl FetchRequest {
     }
 }
 
+/// Exponential backoff settings for provider requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Total attempts including the first request (1 = no retries)
+    pub max_attempts: u32,
+
+    /// Delay before the first retry, in milliseconds
+    pub initial_backoff_ms: u64,
+
+    /// Upper bound on any single delay, in milliseconds
+    pub max_backoff_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            initial_backoff_ms: 1_000,
+            max_backoff_ms: 16_000,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Set the total number of attempts (at least 1).
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the given retry (1-based), doubling each time up to the cap.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u64 << retry.saturating_sub(1).min(20);
+        Duration::from_millis(
+            self.initial_backoff_ms
+                .saturating_mul(factor)
+                .min(self.max_backoff_ms),
+        )
+    }
+
+    /// [`RetryPolicy::backoff`] scaled into `[backoff / 2, backoff]` by `unit` (in `[0, 1]`).
+    ///
+    /// Spreading the delays keeps concurrent fetches from retrying in lockstep
+    /// after a shared 429.
+    pub fn backoff_with_jitter(&self, retry: u32, unit: f64) -> Duration {
+        self.backoff(retry)
+            .mul_f64(0.5 + 0.5 * unit.clamp(0.0, 1.0))
+    }
+
+    /// [`RetryPolicy::backoff_with_jitter`] with a random jitter.
+    pub fn jittered_backoff(&self, retry: u32) -> Duration {
+        self.backoff_with_jitter(retry, rand::random::<f64>())
+    }
+}
+
+/// Default request rate for provider fetches (requests per second).
+pub const DEFAULT_REQUESTS_PER_SEC: f64 = 2.0;
+
+/// Token-bucket rate limiter for provider requests.
+///
+/// The limiter never sleeps itself: callers reserve a slot and sleep for the
+/// returned delay, which keeps it usable from both async and blocking code.
+/// [`RateLimiter::reserve`] takes the clock as an argument so tests can drive
+/// it with a mock clock; [`RateLimiter::reserve_now`] uses the wall clock.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    requests_per_sec: f64,
+    burst: f64,
+    tokens: f64,
+    last: Duration,
+    origin: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `requests_per_sec` with no burst beyond one request.
+    ///
+    /// Non-positive or non-finite rates disable limiting.
+    pub fn new(requests_per_sec: f64) -> Self {
+        Self {
+            requests_per_sec,
+            burst: 1.0,
+            tokens: 1.0,
+            last: Duration::ZERO,
+            origin: Instant::now(),
+        }
+    }
+
+    /// Allow up to `burst` back-to-back requests before pacing kicks in.
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1) as f64;
+        self.tokens = self.burst;
+        self
+    }
+
+    /// Configured rate in requests per second.
+    pub fn requests_per_sec(&self) -> f64 {
+        self.requests_per_sec
+    }
+
+    /// Reserve one request now, returning how long to wait before sending it.
+    pub fn reserve_now(&mut self) -> Duration {
+        self.reserve(self.origin.elapsed())
+    }
+
+    /// Reserve one request at time `now` (elapsed since the limiter was created), returning how long to wait before sending it.
+    ///
+    /// Reservations are queued: a caller that gets a non-zero delay has already
+    /// consumed its token, so the next caller waits behind it.
+    pub fn reserve(&mut self, now: Duration) -> Duration {
+        if !(self.requests_per_sec.is_finite() && self.requests_per_sec > 0.0) {
+            return Duration::ZERO;
+        }
+
+        if now > self.last {
+            let refill = (now - self.last).as_secs_f64() * self.requests_per_sec;
+            self.tokens = (self.tokens + refill).min(self.burst);
+            self.last = now;
+        }
+
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            // `last` may be ahead of `now` while earlier reservations are queued
+            return self.last.saturating_sub(now);
+        }
+
+        // Borrow the token from the future and move the refill point past it
+        let wait = Duration::from_secs_f64(-self.tokens / self.requests_per_sec);
+        self.tokens = 0.0;
+        self.last += wait;
+        self.last.saturating_sub(now)
+    }
+}
+
 /// Metadata sidecar for cached raw data.
 ///
 /// Stored alongside raw data files to track provenance and enable cache validation.
@@ -145,6 +329,32 @@ impl CacheMetadata {
         )
     }
 
+    /// Sub-ranges of `start..=end` not covered by this cache entry.
+    ///
+    /// Returns the gap before the cached range and the gap after it, in date
+    /// order; an empty result means the cache already covers the request. If
+    /// the request neither overlaps nor touches the cached range, the whole
+    /// request is returned, since merging would leave a hole.
+    pub fn missing_ranges(&self, start: NaiveDate, end: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
+        if start > end {
+            return Vec::new();
+        }
+        let touches = start <= self.end.succ_opt().unwrap_or(self.end)
+            && end >= self.start.pred_opt().unwrap_or(self.start);
+        if !touches {
+            return vec![(start, end)];
+        }
+
+        let mut missing = Vec::new();
+        if start < self.start {
+            missing.push((start, self.start.pred_opt().unwrap_or(self.start)));
+        }
+        if end > self.end {
+            missing.push((self.end.succ_opt().unwrap_or(self.end), end));
+        }
+        missing
+    }
+
     /// Generate the metadata sidecar path (relative to data/raw/).
     ///
     /// Format: `{provider}/{symbol}/{start}_{end}.meta.json`
@@ -163,6 +373,8 @@ pub enum DataSource {
     Cache,
     /// Data was freshly fetched from the provider.
     Fresh,
+    /// Data was freshly fetched from Tiingo (adjusted prices).
+    Tiingo,
 }
 
 /// Result of a fetch operation.
@@ -176,6 +388,9 @@ pub struct FetchResult {
 
     /// Cache metadata (if data was cached).
     pub metadata: Option<CacheMetadata>,
+
+    /// Number of retries needed before the fetch succeeded (0 for cache hits).
+    pub retries: u32,
 }
 
 #[cfg(test)]
@@ -195,6 +410,100 @@ mod tests {
         assert!(!req.force);
     }
 
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_millis(1_000));
+        assert_eq!(policy.backoff(2), Duration::from_millis(2_000));
+        assert_eq!(policy.backoff(3), Duration::from_millis(4_000));
+        assert_eq!(policy.backoff(10), Duration::from_millis(16_000));
+        assert_eq!(policy.with_max_attempts(0).max_attempts, 1);
+    }
+
+    #[test]
+    fn test_retry_policy_jitter_bounds() {
+        let policy = RetryPolicy::default();
+        assert_eq!(
+            policy.backoff_with_jitter(2, 0.0),
+            Duration::from_millis(1_000)
+        );
+        assert_eq!(
+            policy.backoff_with_jitter(2, 1.0),
+            Duration::from_millis(2_000)
+        );
+        let jittered = policy.jittered_backoff(2);
+        assert!(
+            jittered >= Duration::from_millis(1_000) && jittered <= Duration::from_millis(2_000)
+        );
+    }
+
+    #[test]
+    fn test_rate_limiter_paces_requests() {
+        let rate = 4.0;
+        let n = 9;
+        let mut limiter = RateLimiter::new(rate);
+
+        // Mock clock: every request sleeps for its reserved delay
+        let mut now = Duration::ZERO;
+        for _ in 0..n {
+            now += limiter.reserve(now);
+        }
+        // The first request is free; the other n - 1 are spaced 1 / rate apart
+        let min_elapsed = (n - 1) as f64 / rate;
+        assert!(now.as_secs_f64() >= min_elapsed - 1e-9);
+        assert!(now.as_secs_f64() < min_elapsed + 0.01);
+
+        // Concurrent callers reserving at the same instant queue up behind each other
+        let mut limiter = RateLimiter::new(rate).with_burst(2);
+        let waits: Vec<Duration> = (0..4).map(|_| limiter.reserve(Duration::ZERO)).collect();
+        assert_eq!(waits[0], Duration::ZERO);
+        assert_eq!(waits[1], Duration::ZERO);
+        assert_eq!(waits[2], Duration::from_millis(250));
+        assert_eq!(waits[3], Duration::from_millis(500));
+
+        // A disabled limiter never waits
+        let mut unlimited = RateLimiter::new(0.0);
+        assert_eq!(unlimited.reserve(Duration::ZERO), Duration::ZERO);
+        assert_eq!(unlimited.reserve(Duration::ZERO), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_cache_metadata_missing_ranges() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let meta = CacheMetadata::new(
+            "yahoo",
+            "SPY",
+            date(2020, 1, 1),
+            date(2023, 12, 31),
+            "1d",
+            1006,
+            "abc123",
+        );
+
+        // Only the 2024 tail needs fetching
+        assert_eq!(
+            meta.missing_ranges(date(2020, 1, 1), date(2024, 12, 31)),
+            vec![(date(2024, 1, 1), date(2024, 12, 31))]
+        );
+        // Gaps on both sides
+        assert_eq!(
+            meta.missing_ranges(date(2019, 1, 1), date(2024, 6, 30)),
+            vec![
+                (date(2019, 1, 1), date(2019, 12, 31)),
+                (date(2024, 1, 1), date(2024, 6, 30))
+            ]
+        );
+        // Fully covered
+        assert!(meta
+            .missing_ranges(date(2021, 1, 1), date(2022, 12, 31))
+            .is_empty());
+        // Disjoint request is fetched whole
+        assert_eq!(
+            meta.missing_ranges(date(2025, 1, 1), date(2025, 6, 30)),
+            vec![(date(2025, 1, 1), date(2025, 6, 30))]
+        );
+    }
+
     #[test]
     fn test_cache_metadata_paths() {
         let meta = CacheMetadata::new(
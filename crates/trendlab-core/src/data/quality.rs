@@ -2,12 +2,16 @@
 //!
 //! Checks for:
 //! - Duplicate timestamps
-//! - Gaps in time series
+//! - Gaps in time series (missing trading days per a [`TradingCalendar`])
 //! - Out-of-order timestamps
 //! - Invalid OHLC relationships (e.g., high < low)
+//! - Price spikes (isolated bad ticks far outside the rolling return distribution)
+//!
+//! Also provides optional forward-filling of missing weekday bars in daily data
+//! and interpolation of spiked closes.
 
 use crate::bar::Bar;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -39,6 +43,13 @@ pub enum QualityIssue {
         symbol: String,
         reason: String,
     },
+    /// Close-to-close return far outside the rolling return distribution,
+    /// reverting on the next bar
+    PriceSpike {
+        ts: DateTime<Utc>,
+        symbol: String,
+        z_score: f64,
+    },
 }
 
 /// Summary report of data quality checks.
@@ -52,8 +63,14 @@ pub struct DataQualityReport {
     pub out_of_order_count: usize,
     /// Number of bars with invalid OHLC relationships
     pub invalid_ohlc_count: usize,
+    /// Number of price spikes (only checked when spike detection is enabled)
+    #[serde(default)]
+    pub spike_count: usize,
     /// Total bars analyzed
     pub total_bars: usize,
+    /// Number of bars synthesized by gap filling (0 if no fill was applied)
+    #[serde(default)]
+    pub synthesized_count: usize,
     /// Detailed list of issues
     pub issues: Vec<QualityIssue>,
 }
@@ -65,6 +82,7 @@ impl DataQualityReport {
             && self.gap_count == 0
             && self.out_of_order_count == 0
             && self.invalid_ohlc_count == 0
+            && self.spike_count == 0
     }
 
     /// Get all duplicate timestamps.
@@ -87,11 +105,161 @@ impl DataQualityReport {
     }
 }
 
+/// How [`DataQualityChecker::fill_gaps`] fills missing bars.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FillMethod {
+    /// Leave gaps as they are
+    #[default]
+    None,
+    /// Repeat the previous close as OHLC with zero volume
+    ForwardFill,
+}
+
+/// Built-in holiday rules for a [`TradingCalendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HolidayRules {
+    /// Every weekday is a trading day
+    None,
+    /// NYSE full-day closures
+    Nyse,
+}
+
+/// Calendar of expected trading days, used to tell real gaps from market closures.
+///
+/// Weekends are never trading days. On top of the built-in rules, extra
+/// closures can be injected with [`TradingCalendar::with_holidays`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TradingCalendar {
+    rules: HolidayRules,
+    holidays: HashSet<NaiveDate>,
+}
+
+impl Default for TradingCalendar {
+    fn default() -> Self {
+        Self::nyse()
+    }
+}
+
+impl TradingCalendar {
+    /// NYSE calendar: weekends plus the major US exchange holidays.
+    pub fn nyse() -> Self {
+        Self {
+            rules: HolidayRules::Nyse,
+            holidays: HashSet::new(),
+        }
+    }
+
+    /// Naive calendar where every weekday is a trading day.
+    pub fn weekdays() -> Self {
+        Self {
+            rules: HolidayRules::None,
+            holidays: HashSet::new(),
+        }
+    }
+
+    /// Add custom non-trading dates (e.g., one-off closures or a non-US exchange).
+    pub fn with_holidays(mut self, holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(holidays);
+        self
+    }
+
+    /// Returns true if the exchange is expected to be open on `date`.
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        if matches!(date.weekday(), Weekday::Sat | Weekday::Sun) || self.holidays.contains(&date) {
+            return false;
+        }
+        match self.rules {
+            HolidayRules::None => true,
+            HolidayRules::Nyse => !is_nyse_holiday(date),
+        }
+    }
+
+    /// Number of trading days strictly between `from` and `to`.
+    pub fn trading_days_between(&self, from: NaiveDate, to: NaiveDate) -> usize {
+        from.iter_days()
+            .skip(1)
+            .take_while(|d| *d < to)
+            .filter(|d| self.is_trading_day(*d))
+            .count()
+    }
+}
+
+/// The `n`-th (1-based) `weekday` of a month.
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+}
+
+/// The last `weekday` of a month.
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    nth_weekday(year, month, weekday, 5).or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+/// Shift a fixed-date holiday that falls on a weekend to the observed weekday.
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date - Duration::days(1),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    }
+}
+
+/// Easter Sunday (anonymous Gregorian algorithm).
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// Full-day NYSE holidays observed in `year`.
+fn nyse_holidays(year: i32) -> Vec<NaiveDate> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).map(observed);
+
+    let mut days = vec![
+        // New Year's Day is not moved back into the prior year when it falls on a Saturday
+        NaiveDate::from_ymd_opt(year, 1, 1)
+            .map(observed)
+            .filter(|d| d.year() == year),
+        nth_weekday(year, 1, Weekday::Mon, 3), // Martin Luther King Jr. Day
+        nth_weekday(year, 2, Weekday::Mon, 3), // Washington's Birthday
+        easter_sunday(year).map(|d| d - Duration::days(2)), // Good Friday
+        last_weekday(year, 5, Weekday::Mon),   // Memorial Day
+        fixed(7, 4),                           // Independence Day
+        nth_weekday(year, 9, Weekday::Mon, 1), // Labor Day
+        nth_weekday(year, 11, Weekday::Thu, 4), // Thanksgiving
+        fixed(12, 25),                         // Christmas
+    ];
+    if year >= 2022 {
+        days.push(fixed(6, 19)); // Juneteenth
+    }
+
+    days.into_iter().flatten().collect()
+}
+
+fn is_nyse_holiday(date: NaiveDate) -> bool {
+    nyse_holidays(date.year()).contains(&date)
+}
+
 /// Checker for data quality issues.
 #[derive(Debug, Default)]
 pub struct DataQualityChecker {
     /// Expected timeframe for gap detection (e.g., "1d")
     timeframe: Option<String>,
+    /// Calendar of expected trading days for gap detection
+    calendar: TradingCalendar,
+    /// Spike detection as (rolling window, z-score threshold)
+    spike_detection: Option<(usize, f64)>,
 }
 
 impl DataQualityChecker {
@@ -106,6 +274,22 @@ impl DataQualityChecker {
         self
     }
 
+    /// Set the trading calendar used to decide which missing days are gaps.
+    pub fn with_calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// Enable price-spike detection.
+    ///
+    /// A bar is a spike when its log return is more than `z_threshold`
+    /// standard deviations from the mean of the previous `window` returns and
+    /// the next return is just as extreme in the opposite direction.
+    pub fn with_spike_detection(mut self, window: usize, z_threshold: f64) -> Self {
+        self.spike_detection = Some((window, z_threshold));
+        self
+    }
+
     /// Check a slice of bars for quality issues.
     pub fn check(&self, bars: &[Bar]) -> DataQualityReport {
         let mut report = DataQualityReport {
@@ -131,9 +315,92 @@ impl DataQualityChecker {
         // Check OHLC validity
         self.check_ohlc_validity(bars, &mut report);
 
+        // Check for price spikes (only if enabled)
+        if self.spike_detection.is_some() {
+            self.check_spikes(bars, &mut report);
+        }
+
         report
     }
 
+    /// Replace the open, high, low and close of each detected price spike
+    /// with the midpoints of its neighbours' values, widening high/low to
+    /// contain the interpolated open and close.
+    ///
+    /// Returns the bars unchanged if spike detection is not enabled.
+    pub fn repair_spikes(&self, bars: &[Bar]) -> Vec<Bar> {
+        let mut repaired = bars.to_vec();
+        for indices in bars_by_symbol(bars).into_values() {
+            let closes: Vec<f64> = indices.iter().map(|&i| bars[i].close).collect();
+            for (pos, _) in self.find_spikes(&closes) {
+                let (prev, next) = (&bars[indices[pos - 1]], &bars[indices[pos + 1]]);
+                let bar = &mut repaired[indices[pos]];
+                bar.open = (prev.open + next.open) / 2.0;
+                bar.close = (prev.close + next.close) / 2.0;
+                bar.high = ((prev.high + next.high) / 2.0).max(bar.open.max(bar.close));
+                bar.low = ((prev.low + next.low) / 2.0).min(bar.open.min(bar.close));
+            }
+        }
+        repaired
+    }
+
+    /// Fill missing weekday bars in daily data.
+    ///
+    /// Only bars with timeframe "1d" are filled, and weekends are never
+    /// synthesized. The result is sorted by timestamp, then symbol.
+    pub fn fill_gaps(bars: &[Bar], method: FillMethod) -> Vec<Bar> {
+        let mut filled: Vec<Bar> = bars.to_vec();
+
+        if method == FillMethod::ForwardFill {
+            let mut by_symbol: std::collections::HashMap<&str, Vec<&Bar>> =
+                std::collections::HashMap::new();
+            for bar in bars.iter().filter(|b| b.timeframe == "1d") {
+                by_symbol.entry(&bar.symbol).or_default().push(bar);
+            }
+
+            for (_, mut symbol_bars) in by_symbol {
+                symbol_bars.sort_by_key(|b| b.ts);
+
+                for window in symbol_bars.windows(2) {
+                    let (prev, curr) = (window[0], window[1]);
+                    let mut ts = prev.ts + Duration::days(1);
+                    while ts < curr.ts {
+                        if !matches!(ts.weekday(), Weekday::Sat | Weekday::Sun) {
+                            filled.push(Bar::new(
+                                ts,
+                                prev.close,
+                                prev.close,
+                                prev.close,
+                                prev.close,
+                                0.0,
+                                &prev.symbol,
+                                &prev.timeframe,
+                            ));
+                        }
+                        ts += Duration::days(1);
+                    }
+                }
+            }
+        }
+
+        filled.sort_by(|a, b| a.ts.cmp(&b.ts).then_with(|| a.symbol.cmp(&b.symbol)));
+        filled
+    }
+
+    /// Fill gaps with `method`, then check the filled bars.
+    ///
+    /// The report's `synthesized_count` records how many bars were added.
+    pub fn fill_and_check(
+        &self,
+        bars: &[Bar],
+        method: FillMethod,
+    ) -> (Vec<Bar>, DataQualityReport) {
+        let filled = Self::fill_gaps(bars, method);
+        let mut report = self.check(&filled);
+        report.synthesized_count = filled.len() - bars.len();
+        (filled, report)
+    }
+
     fn check_duplicates(&self, bars: &[Bar], report: &mut DataQualityReport) {
         let mut seen: HashSet<(String, DateTime<Utc>)> = HashSet::new();
         let mut dup_keys: HashSet<(String, DateTime<Utc>)> = HashSet::new();
@@ -206,24 +473,91 @@ impl DataQualityChecker {
 
             for window in symbol_bars.windows(2) {
                 let (prev, curr) = (window[0], window[1]);
-                let days_diff = (curr.ts - prev.ts).num_days();
 
-                // For daily data, any gap > 1 day is flagged
-                // Note: This is a simple heuristic. A proper implementation would
-                // use a trading calendar to distinguish gaps from weekends/holidays.
-                if days_diff > 1 {
+                // Only missing trading days count; weekends and holidays are expected
+                let missing = self
+                    .calendar
+                    .trading_days_between(prev.ts.date_naive(), curr.ts.date_naive());
+                if missing > 0 {
                     report.gap_count += 1;
                     report.issues.push(QualityIssue::Gap {
                         from: prev.ts,
                         to: curr.ts,
                         symbol: symbol.to_string(),
-                        expected_bars: (days_diff - 1) as usize,
+                        expected_bars: missing,
                     });
                 }
             }
         }
     }
 
+    fn check_spikes(&self, bars: &[Bar], report: &mut DataQualityReport) {
+        for (symbol, indices) in bars_by_symbol(bars) {
+            let closes: Vec<f64> = indices.iter().map(|&i| bars[i].close).collect();
+            for (pos, z_score) in self.find_spikes(&closes) {
+                report.spike_count += 1;
+                report.issues.push(QualityIssue::PriceSpike {
+                    ts: bars[indices[pos]].ts,
+                    symbol: symbol.to_string(),
+                    z_score,
+                });
+            }
+        }
+    }
+
+    /// Positions (and z-scores) of spiked closes in one symbol's series.
+    ///
+    /// Returns touching an already-flagged bar are left out of later windows
+    /// so one bad tick does not mask or trigger its neighbours.
+    fn find_spikes(&self, closes: &[f64]) -> Vec<(usize, f64)> {
+        let Some((window, z_threshold)) = self.spike_detection else {
+            return Vec::new();
+        };
+
+        // returns[i] is the log return into bar i (None for bar 0 or bad prices)
+        let returns: Vec<Option<f64>> = (0..closes.len())
+            .map(|i| {
+                (i > 0 && closes[i - 1] > 0.0 && closes[i] > 0.0)
+                    .then(|| (closes[i] / closes[i - 1]).ln())
+            })
+            .collect();
+
+        let mut spikes: Vec<(usize, f64)> = Vec::new();
+        for i in 1..closes.len().saturating_sub(1) {
+            let flagged = |j: usize| spikes.iter().any(|&(s, _)| s == j || s + 1 == j);
+            if flagged(i) {
+                continue;
+            }
+            let (Some(r_in), Some(r_out)) = (returns[i], returns[i + 1]) else {
+                continue;
+            };
+
+            let history: Vec<f64> = (i.saturating_sub(window)..i)
+                .filter(|&j| !flagged(j))
+                .filter_map(|j| returns[j])
+                .collect();
+            if history.len() < 2 {
+                continue;
+            }
+            let n = history.len() as f64;
+            let mean = history.iter().sum::<f64>() / n;
+            let std = (history.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+            if std <= 0.0 {
+                continue;
+            }
+
+            let z_in = (r_in - mean) / std;
+            let z_out = (r_out - mean) / std;
+            if z_in.abs() > z_threshold
+                && z_out.abs() > z_threshold
+                && z_in.signum() != z_out.signum()
+            {
+                spikes.push((i, z_in));
+            }
+        }
+        spikes
+    }
+
     fn check_ohlc_validity(&self, bars: &[Bar], report: &mut DataQualityReport) {
         for bar in bars {
             // Check: high >= open, high >= close, high >= low
@@ -258,6 +592,19 @@ impl DataQualityChecker {
     }
 }
 
+/// Indices of each symbol's bars, sorted by timestamp.
+fn bars_by_symbol(bars: &[Bar]) -> std::collections::HashMap<&str, Vec<usize>> {
+    let mut by_symbol: std::collections::HashMap<&str, Vec<usize>> =
+        std::collections::HashMap::new();
+    for (i, bar) in bars.iter().enumerate() {
+        by_symbol.entry(&bar.symbol).or_default().push(i);
+    }
+    for indices in by_symbol.values_mut() {
+        indices.sort_by_key(|&i| bars[i].ts);
+    }
+    by_symbol
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,4 +677,142 @@ mod tests {
 
         assert!(report.is_clean());
     }
+
+    #[test]
+    fn forward_fill_closes_weekday_hole() {
+        // Tue 2024-01-02 .. Fri 2024-01-05, then a hole over Mon-Wed
+        // (8th-10th) before Thu 2024-01-11.
+        let bars = vec![
+            mk_bar(2, 100.0, 101.0, 99.0, 100.5),
+            mk_bar(3, 100.5, 102.0, 100.0, 101.5),
+            mk_bar(4, 101.5, 103.0, 101.0, 102.5),
+            mk_bar(5, 102.5, 104.0, 102.0, 103.5),
+            mk_bar(11, 104.0, 105.0, 103.0, 104.5),
+        ];
+
+        let checker = DataQualityChecker::new().with_timeframe("1d");
+        let (filled, report) = checker.fill_and_check(&bars, FillMethod::ForwardFill);
+
+        assert_eq!(report.synthesized_count, 3);
+        assert_eq!(filled.len(), 8);
+        assert!(filled.windows(2).all(|w| w[0].ts < w[1].ts));
+
+        let synthetic: Vec<&Bar> = filled.iter().filter(|b| b.volume == 0.0).collect();
+        let days: Vec<u32> = synthetic.iter().map(|b| b.ts.day()).collect();
+        assert_eq!(days, vec![8, 9, 10]);
+        for bar in synthetic {
+            assert_eq!(bar.open, 103.5);
+            assert_eq!(bar.high, 103.5);
+            assert_eq!(bar.low, 103.5);
+            assert_eq!(bar.close, 103.5);
+        }
+
+        // The weekend between Fri 5th and Mon 8th is not a gap.
+        assert_eq!(report.gap_count, 0);
+    }
+
+    #[test]
+    fn thanksgiving_is_not_a_gap_on_nyse_calendar() {
+        let mk = |day: u32| {
+            Bar::new(
+                Utc.with_ymd_and_hms(2024, 11, day, 0, 0, 0).unwrap(),
+                100.0,
+                101.0,
+                99.0,
+                100.5,
+                1000.0,
+                "TEST",
+                "1d",
+            )
+        };
+        // Wed 27th, then Fri 29th after the Thanksgiving closure
+        let bars = vec![mk(26), mk(27), mk(29)];
+
+        let nyse = DataQualityChecker::new().with_timeframe("1d");
+        assert_eq!(nyse.check(&bars).gap_count, 0);
+
+        let naive = DataQualityChecker::new()
+            .with_timeframe("1d")
+            .with_calendar(TradingCalendar::weekdays());
+        let report = naive.check(&bars);
+        assert_eq!(report.gap_count, 1);
+        assert!(matches!(
+            report.issues[0],
+            QualityIssue::Gap {
+                expected_bars: 1,
+                ..
+            }
+        ));
+
+        let custom = DataQualityChecker::new()
+            .with_timeframe("1d")
+            .with_calendar(
+                TradingCalendar::weekdays()
+                    .with_holidays([NaiveDate::from_ymd_opt(2024, 11, 28).unwrap()]),
+            );
+        assert_eq!(custom.check(&bars).gap_count, 0);
+    }
+
+    #[test]
+    fn nyse_calendar_observes_weekend_holidays() {
+        let cal = TradingCalendar::nyse();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Independence Day 2026 is a Saturday, observed Friday July 3rd
+        assert!(!cal.is_trading_day(date(2026, 7, 3)));
+        // Good Friday 2024
+        assert!(!cal.is_trading_day(date(2024, 3, 29)));
+        // New Year's 2022 fell on a Saturday; Dec 31 2021 was still a trading day
+        assert!(cal.is_trading_day(date(2021, 12, 31)));
+        assert!(cal.is_trading_day(date(2024, 11, 27)));
+    }
+
+    #[test]
+    fn flags_and_repairs_ten_x_price_spike() {
+        // Small repeating moves, then a single bad tick 10x the prior close
+        let mut bars: Vec<Bar> = (0..30)
+            .map(|i| {
+                let close = 100.0 + (i % 3) as f64 * 0.4;
+                mk_bar(i + 1, close, close + 0.5, close - 0.5, close)
+            })
+            .collect();
+        let spike_close = bars[19].close * 10.0;
+        bars[20].close = spike_close;
+        bars[20].high = spike_close;
+
+        let checker = DataQualityChecker::new().with_spike_detection(20, 4.0);
+        let report = checker.check(&bars);
+
+        assert_eq!(report.spike_count, 1);
+        assert!(!report.is_clean());
+        match &report.issues[0] {
+            QualityIssue::PriceSpike { ts, z_score, .. } => {
+                assert_eq!(*ts, bars[20].ts);
+                assert!(*z_score > 100.0, "z = {}", z_score);
+            }
+            other => panic!("expected a price spike, got {:?}", other),
+        }
+
+        // Interpolated close and high sit between the neighbours
+        let repaired = checker.repair_spikes(&bars);
+        let expected = (bars[19].close + bars[21].close) / 2.0;
+        assert!((repaired[20].close - expected).abs() < 1e-12);
+        let expected_high = (bars[19].high + bars[21].high) / 2.0;
+        assert!((repaired[20].high - expected_high).abs() < 1e-12);
+        assert_eq!(checker.check(&repaired).spike_count, 0);
+
+        // Disabled by default
+        assert_eq!(DataQualityChecker::new().check(&bars).spike_count, 0);
+    }
+
+    #[test]
+    fn fill_method_none_leaves_bars_unchanged() {
+        let bars = vec![
+            mk_bar(2, 100.0, 101.0, 99.0, 100.5),
+            mk_bar(5, 102.5, 104.0, 102.0, 103.5),
+        ];
+
+        let filled = DataQualityChecker::fill_gaps(&bars, FillMethod::None);
+        assert_eq!(filled, bars);
+    }
 }
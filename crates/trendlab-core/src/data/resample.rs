@@ -0,0 +1,145 @@
+//! Resampling of daily bars into higher timeframes.
+//!
+//! Bars are grouped by calendar period (ISO week or month) and aggregated
+//! OHLCV-style: first open, highest high, lowest low, last close, summed
+//! volume. Input is expected to be a single symbol sorted by timestamp.
+
+use crate::bar::Bar;
+use chrono::{DateTime, Datelike, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Target period for [`resample`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ResamplePeriod {
+    /// ISO week (Monday through Sunday)
+    Weekly,
+    /// Calendar month
+    Monthly,
+}
+
+impl ResamplePeriod {
+    /// Timeframe label stamped on resampled bars.
+    pub fn timeframe(&self) -> &'static str {
+        match self {
+            ResamplePeriod::Weekly => "1w",
+            ResamplePeriod::Monthly => "1mo",
+        }
+    }
+
+    /// Approximate number of trading days in one period.
+    pub fn trading_days(&self) -> usize {
+        match self {
+            ResamplePeriod::Weekly => 5,
+            ResamplePeriod::Monthly => 21,
+        }
+    }
+
+    /// Key identifying the period a timestamp falls in.
+    ///
+    /// Two timestamps land in the same resampled bar iff their keys match.
+    pub fn period_key(&self, ts: DateTime<Utc>) -> (i32, u32) {
+        match self {
+            ResamplePeriod::Weekly => {
+                let week = ts.iso_week();
+                (week.year(), week.week())
+            }
+            ResamplePeriod::Monthly => (ts.year(), ts.month()),
+        }
+    }
+}
+
+/// Aggregate bars into one bar per period.
+///
+/// Consecutive bars sharing a [`ResamplePeriod::period_key`] are merged; the
+/// resampled bar carries the timestamp of the first bar in its period. The
+/// last period may be partial if the input ends mid-week or mid-month.
+pub fn resample(bars: &[Bar], period: ResamplePeriod) -> Vec<Bar> {
+    let mut out: Vec<Bar> = Vec::new();
+    let mut current_key = None;
+
+    for bar in bars {
+        let key = period.period_key(bar.ts);
+        match out.last_mut() {
+            Some(agg) if current_key == Some(key) => {
+                agg.high = agg.high.max(bar.high);
+                agg.low = agg.low.min(bar.low);
+                agg.close = bar.close;
+                agg.volume += bar.volume;
+            }
+            _ => {
+                out.push(Bar::new(
+                    bar.ts,
+                    bar.open,
+                    bar.high,
+                    bar.low,
+                    bar.close,
+                    bar.volume,
+                    bar.symbol.as_str(),
+                    period.timeframe(),
+                ));
+                current_key = Some(key);
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn daily_bars(n: i64) -> Vec<Bar> {
+        // 2024-01-01 is a Monday
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        (0..n)
+            .map(|i| {
+                let close = 100.0 + i as f64;
+                Bar::new(
+                    start + Duration::days(i),
+                    close - 0.5,
+                    close + 1.0,
+                    close - 1.0,
+                    close,
+                    10.0,
+                    "TEST",
+                    "1d",
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_resample_weekly_aggregates_ohlcv() {
+        let bars = daily_bars(10);
+        let weekly = resample(&bars, ResamplePeriod::Weekly);
+
+        assert_eq!(weekly.len(), 2);
+        let first = &weekly[0];
+        assert_eq!(first.ts, bars[0].ts);
+        assert_eq!(first.open, bars[0].open);
+        assert_eq!(first.high, bars[6].high);
+        assert_eq!(first.low, bars[0].low);
+        assert_eq!(first.close, bars[6].close);
+        assert_eq!(first.volume, 70.0);
+        assert_eq!(first.timeframe, "1w");
+
+        // Partial second week: Jan 8-10
+        assert_eq!(weekly[1].ts, bars[7].ts);
+        assert_eq!(weekly[1].close, bars[9].close);
+        assert_eq!(weekly[1].volume, 30.0);
+    }
+
+    #[test]
+    fn test_resample_monthly() {
+        let bars = daily_bars(40);
+        let monthly = resample(&bars, ResamplePeriod::Monthly);
+
+        assert_eq!(monthly.len(), 2);
+        assert_eq!(monthly[0].close, bars[30].close);
+        assert_eq!(monthly[1].open, bars[31].open);
+        assert_eq!(monthly[1].timeframe, "1mo");
+        assert!(resample(&[], ResamplePeriod::Weekly).is_empty());
+    }
+}
@@ -0,0 +1,207 @@
+//! Tiingo data parsing.
+//!
+//! Parses responses from the Tiingo end-of-day prices API:
+//! - JSON array of daily bars with raw and split/dividend-adjusted fields
+//! - API key read from the `TIINGO_API_KEY` environment variable
+//!
+//! This module contains pure parsing logic with no network I/O.
+
+use crate::bar::Bar;
+use crate::data::ProviderError;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Environment variable holding the Tiingo API token.
+pub const TIINGO_API_KEY_VAR: &str = "TIINGO_API_KEY";
+
+/// Tiingo daily price row (intermediate representation).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TiingoRow {
+    date: DateTime<Utc>,
+    open: Option<f64>,
+    high: Option<f64>,
+    low: Option<f64>,
+    close: Option<f64>,
+    volume: Option<f64>,
+    adj_open: Option<f64>,
+    adj_high: Option<f64>,
+    adj_low: Option<f64>,
+    adj_close: Option<f64>,
+    adj_volume: Option<f64>,
+}
+
+/// Read the Tiingo API key from `TIINGO_API_KEY`.
+///
+/// Returns [`ProviderError::MissingApiKey`] if the variable is unset or blank.
+pub fn tiingo_api_key() -> Result<String, ProviderError> {
+    match std::env::var(TIINGO_API_KEY_VAR) {
+        Ok(key) if !key.trim().is_empty() => Ok(key.trim().to_string()),
+        _ => Err(ProviderError::MissingApiKey {
+            provider: "Tiingo".to_string(),
+            var: TIINGO_API_KEY_VAR.to_string(),
+        }),
+    }
+}
+
+/// Build a Tiingo end-of-day prices URL.
+///
+/// # Arguments
+/// * `symbol` - Ticker symbol
+/// * `start` - Start date (inclusive)
+/// * `end` - End date (inclusive)
+/// * `api_key` - Tiingo API token (see [`tiingo_api_key`])
+///
+/// # Returns
+/// URL string for the Tiingo daily prices API (JSON).
+pub fn build_tiingo_url(symbol: &str, start: NaiveDate, end: NaiveDate, api_key: &str) -> String {
+    format!(
+        "https://api.tiingo.com/tiingo/daily/{}/prices?startDate={}&endDate={}&format=json&resampleFreq=daily&token={}",
+        symbol.to_lowercase(),
+        start.format("%Y-%m-%d"),
+        end.format("%Y-%m-%d"),
+        api_key
+    )
+}
+
+/// Parse a Tiingo daily prices JSON response into bars.
+///
+/// # Arguments
+/// * `body` - Raw JSON text from the Tiingo prices API
+/// * `symbol` - Symbol to assign to the bars
+/// * `timeframe` - Timeframe to assign (e.g., "1d")
+///
+/// # Returns
+/// Vector of bars, sorted by timestamp ascending.
+///
+/// # JSON Structure
+/// ```json
+/// [
+///   {"date": "2024-01-02T00:00:00.000Z", "open": 472.16, "high": 473.67, "low": 470.49,
+///    "close": 472.65, "volume": 123623700, "adjOpen": 466.6, "adjHigh": 468.09,
+///    "adjLow": 464.95, "adjClose": 467.09, "adjVolume": 123623700,
+///    "divCash": 0.0, "splitFactor": 1.0}
+/// ]
+/// ```
+///
+/// # Notes
+/// - Uses the `adj*` fields (split/dividend adjusted), falling back to the raw
+///   fields when the adjusted ones are missing
+/// - Rows with null OHLC values are skipped
+/// - An error object (`{"detail": "..."}`) is mapped to a [`ProviderError`]
+pub fn parse_tiingo_json(
+    body: &str,
+    symbol: &str,
+    timeframe: &str,
+) -> Result<Vec<Bar>, ProviderError> {
+    let json: Value = serde_json::from_str(body).map_err(|e| ProviderError::ParseError {
+        message: format!("Invalid JSON: {}", e),
+    })?;
+
+    // Tiingo reports errors as an object with a "detail" message
+    if let Some(detail) = json.get("detail").and_then(|d| d.as_str()) {
+        return Err(tiingo_error(detail, symbol));
+    }
+
+    let rows: Vec<TiingoRow> =
+        serde_json::from_value(json).map_err(|e| ProviderError::ParseError {
+            message: format!("Unexpected Tiingo response: {}", e),
+        })?;
+
+    let mut bars: Vec<Bar> = rows
+        .into_iter()
+        .filter_map(|row| {
+            let open = row.adj_open.or(row.open)?;
+            let high = row.adj_high.or(row.high)?;
+            let low = row.adj_low.or(row.low)?;
+            let close = row.adj_close.or(row.close)?;
+            let volume = row.adj_volume.or(row.volume).unwrap_or(0.0);
+
+            Some(Bar::new(
+                row.date, open, high, low, close, volume, symbol, timeframe,
+            ))
+        })
+        .collect();
+
+    bars.sort_by_key(|b| b.ts);
+    Ok(bars)
+}
+
+/// Map a Tiingo error detail message to a provider error.
+fn tiingo_error(detail: &str, symbol: &str) -> ProviderError {
+    let lower = detail.to_lowercase();
+    if lower.contains("not found") {
+        ProviderError::SymbolNotFound {
+            symbol: symbol.to_string(),
+        }
+    } else if lower.contains("limit") {
+        ProviderError::RateLimited {
+            message: detail.to_string(),
+        }
+    } else {
+        ProviderError::NetworkError {
+            message: format!("Tiingo API error: {}", detail),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Captured response for `SPY`, 2024-01-02..2024-01-04, plus a null row.
+    const TIINGO_FIXTURE: &str = r#"[
+        {"date":"2024-01-03T00:00:00.000Z","close":468.79,"high":471.19,"low":468.17,"open":470.43,"volume":103585900,"adjClose":463.2787,"adjHigh":465.6505,"adjLow":462.6661,"adjOpen":464.9000,"adjVolume":103585900,"divCash":0.0,"splitFactor":1.0},
+        {"date":"2024-01-02T00:00:00.000Z","close":472.65,"high":473.67,"low":470.49,"open":472.16,"volume":123623700,"adjClose":467.0933,"adjHigh":468.1013,"adjLow":464.9587,"adjOpen":466.6091,"adjVolume":123623700,"divCash":0.0,"splitFactor":1.0},
+        {"date":"2024-01-04T00:00:00.000Z","close":467.28,"high":470.96,"low":467.05,"open":468.30,"volume":84232200,"adjClose":461.7865,"adjHigh":465.4232,"adjLow":461.5592,"adjOpen":462.7946,"adjVolume":84232200,"divCash":0.0,"splitFactor":1.0},
+        {"date":"2024-01-05T00:00:00.000Z","close":null,"high":null,"low":null,"open":null,"volume":null,"adjClose":null,"adjHigh":null,"adjLow":null,"adjOpen":null,"adjVolume":null,"divCash":0.0,"splitFactor":1.0}
+    ]"#;
+
+    #[test]
+    fn test_parse_tiingo_json_uses_adjusted_fields() {
+        let bars = parse_tiingo_json(TIINGO_FIXTURE, "SPY", "1d").unwrap();
+        assert_eq!(bars.len(), 3);
+
+        // Sorted ascending, null row skipped
+        assert_eq!(bars[0].ts.date_naive().to_string(), "2024-01-02");
+        assert_eq!(bars[2].ts.date_naive().to_string(), "2024-01-04");
+
+        assert_eq!(bars[0].open, 466.6091);
+        assert_eq!(bars[0].high, 468.1013);
+        assert_eq!(bars[0].low, 464.9587);
+        assert_eq!(bars[0].close, 467.0933);
+        assert_eq!(bars[0].volume, 123623700.0);
+        assert_eq!(bars[0].symbol, "SPY");
+        assert_eq!(bars[0].timeframe, "1d");
+    }
+
+    #[test]
+    fn test_parse_tiingo_json_error_detail() {
+        let err = parse_tiingo_json(
+            r#"{"detail":"Error: Ticker 'ZZZZ' not found"}"#,
+            "ZZZZ",
+            "1d",
+        )
+        .unwrap_err();
+        assert!(matches!(err, ProviderError::SymbolNotFound { .. }));
+
+        let err = parse_tiingo_json(r#"{"detail":"Invalid token."}"#, "SPY", "1d").unwrap_err();
+        assert!(err.to_string().contains("Invalid token"));
+    }
+
+    #[test]
+    fn test_build_tiingo_url() {
+        let url = build_tiingo_url(
+            "SPY",
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            "secret",
+        );
+
+        assert!(url.starts_with("https://api.tiingo.com/tiingo/daily/spy/prices?"));
+        assert!(url.contains("startDate=2024-01-01"));
+        assert!(url.contains("endDate=2024-12-31"));
+        assert!(url.contains("token=secret"));
+    }
+}
@@ -24,6 +24,43 @@ struct YahooRow {
     volume: f64,
 }
 
+/// Reject response bodies that are not market data before parsing.
+///
+/// When Yahoo throttles a client it serves an HTML error page (or a bare
+/// "Too Many Requests" line) with a success status. Those bodies are reported
+/// as [`ProviderError::RateLimited`] so callers can back off and retry instead
+/// of surfacing a confusing parse error. With `expect_json`, any body that
+/// does not start like a JSON document is treated the same way.
+pub fn check_yahoo_response(body: &str, expect_json: bool) -> Result<(), ProviderError> {
+    let trimmed = body.trim_start();
+    let head: String = trimmed.chars().take(256).collect::<String>().to_lowercase();
+
+    if head.starts_with('<') || head.contains("<html") {
+        return Err(ProviderError::RateLimited {
+            message: "received an HTML page instead of data".to_string(),
+        });
+    }
+    if head.starts_with("too many requests") {
+        return Err(ProviderError::RateLimited {
+            message: "Too Many Requests".to_string(),
+        });
+    }
+    if expect_json && !trimmed.is_empty() && !trimmed.starts_with(['{', '[']) {
+        let preview: String = trimmed
+            .lines()
+            .next()
+            .unwrap_or("")
+            .chars()
+            .take(80)
+            .collect();
+        return Err(ProviderError::RateLimited {
+            message: format!("received a non-JSON response: {}", preview),
+        });
+    }
+
+    Ok(())
+}
+
 /// Parse a Yahoo Finance CSV response into bars.
 ///
 /// # Arguments
@@ -57,6 +94,8 @@ pub fn parse_yahoo_csv(
         return Ok(Vec::new());
     }
 
+    check_yahoo_response(csv_text, false)?;
+
     // Validate header
     let header = lines[0].to_lowercase();
     if !header.contains("date") || !header.contains("close") {
@@ -77,9 +116,10 @@ pub fn parse_yahoo_csv(
             Ok(None) => {
                 // Row had null values, skip it
             }
-            Err(e) => {
-                return Err(ProviderError::ParseError {
-                    message: format!("Line {}: {}", line_num + 1, e),
+            Err(message) => {
+                return Err(ProviderError::RowParseError {
+                    row: line_num + 1,
+                    message,
                 });
             }
         }
@@ -252,11 +292,25 @@ pub fn build_yahoo_chart_url(symbol: &str, start: NaiveDate, end: NaiveDate) ->
 ///   }
 /// }
 /// ```
+///
+/// # Errors
+/// - [`ProviderError::RateLimited`] for throttling pages (see [`check_yahoo_response`])
+/// - [`ProviderError::EmptyResponse`] for a blank body or a null/empty `result`
+/// - [`ProviderError::SymbolNotFound`] for a "Not Found" API error
+/// - [`ProviderError::ParseError`] for malformed JSON or other API errors
 pub fn parse_yahoo_chart_json(
     json_text: &str,
     symbol: &str,
     timeframe: &str,
 ) -> Result<Vec<Bar>, ProviderError> {
+    check_yahoo_response(json_text, true)?;
+
+    if json_text.trim().is_empty() {
+        return Err(ProviderError::EmptyResponse {
+            symbol: symbol.to_string(),
+        });
+    }
+
     let json: Value = serde_json::from_str(json_text).map_err(|e| ProviderError::ParseError {
         message: format!("Invalid JSON: {}", e),
     })?;
@@ -272,6 +326,11 @@ pub fn parse_yahoo_chart_json(
                 .get("description")
                 .and_then(|d| d.as_str())
                 .unwrap_or("unknown error");
+            if code.eq_ignore_ascii_case("not found") {
+                return Err(ProviderError::SymbolNotFound {
+                    symbol: symbol.to_string(),
+                });
+            }
             return Err(ProviderError::ParseError {
                 message: format!("Yahoo API error: {} - {}", code, desc),
             });
@@ -279,13 +338,15 @@ pub fn parse_yahoo_chart_json(
     }
 
     // Navigate to the result
-    let result = json
+    let results = json
         .get("chart")
         .and_then(|c| c.get("result"))
-        .and_then(|r| r.get(0))
         .ok_or_else(|| ProviderError::ParseError {
-            message: "Missing chart.result[0] in response".to_string(),
+            message: "Missing chart.result in response".to_string(),
         })?;
+    let result = results.get(0).ok_or_else(|| ProviderError::EmptyResponse {
+        symbol: symbol.to_string(),
+    })?;
 
     // Get timestamps
     let timestamps = result
@@ -598,7 +659,82 @@ mod tests {
             }
         }"#;
 
-        let result = parse_yahoo_chart_json(json, "XYZ", "1d");
-        assert!(result.is_err());
+        let err = parse_yahoo_chart_json(json, "XYZ", "1d").unwrap_err();
+        assert!(matches!(err, ProviderError::SymbolNotFound { .. }));
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_failure_fixtures_map_to_variants() {
+        // Blank body and a null result without an error object
+        let err = parse_yahoo_chart_json("  \n", "SPY", "1d").unwrap_err();
+        assert!(matches!(err, ProviderError::EmptyResponse { .. }));
+        let err = parse_yahoo_chart_json(r#"{"chart":{"result":null,"error":null}}"#, "SPY", "1d")
+            .unwrap_err();
+        assert!(matches!(err, ProviderError::EmptyResponse { .. }));
+        assert!(!err.is_retryable());
+
+        // Malformed CSV row reports its line number
+        let csv = "Date,Open,High,Low,Close,Adj Close,Volume\n\
+                   2024-01-02,100.0,101.0,99.0,100.5,100.5,1000\n\
+                   2024-01-03,abc,101.0,99.0,100.5,100.5,1000";
+        let err = parse_yahoo_csv(csv, "SPY", "1d").unwrap_err();
+        assert!(
+            matches!(err, ProviderError::RowParseError { row: 3, .. }),
+            "{:?}",
+            err
+        );
+
+        // Other API errors and truncated JSON are plain parse errors
+        let err = parse_yahoo_chart_json(
+            r#"{"chart":{"result":null,"error":{"code":"Bad Request","description":"Invalid input"}}}"#,
+            "SPY",
+            "1d",
+        )
+        .unwrap_err();
+        assert!(matches!(err, ProviderError::ParseError { .. }));
+        let err = parse_yahoo_chart_json(r#"{"chart":{"result":["#, "SPY", "1d").unwrap_err();
+        assert!(matches!(err, ProviderError::ParseError { .. }));
+
+        // Throttling and HTTP status codes
+        let err = parse_yahoo_chart_json("Too Many Requests", "SPY", "1d").unwrap_err();
+        assert!(err.is_retryable());
+        assert!(matches!(
+            ProviderError::from_http_status(404, "SPY"),
+            ProviderError::SymbolNotFound { .. }
+        ));
+        assert!(ProviderError::from_http_status(503, "SPY").is_retryable());
+        assert!(ProviderError::from_http_status(408, "SPY").is_retryable());
+        for status in [400, 401, 403] {
+            let err = ProviderError::from_http_status(status, "SPY");
+            assert!(matches!(err, ProviderError::RequestRejected { .. }));
+            assert!(!err.is_retryable());
+        }
+    }
+
+    #[test]
+    fn test_html_body_is_rate_limited() {
+        let html = "<!DOCTYPE html>\n<html><head><title>Yahoo</title></head>\n<body>Will be right back...</body></html>";
+
+        let err = parse_yahoo_chart_json(html, "SPY", "1d").unwrap_err();
+        assert!(
+            matches!(err, ProviderError::RateLimited { .. }),
+            "{:?}",
+            err
+        );
+        assert!(err.is_retryable());
+
+        let err = parse_yahoo_csv(html, "SPY", "1d").unwrap_err();
+        assert!(
+            matches!(err, ProviderError::RateLimited { .. }),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_plain_text_body_is_rate_limited() {
+        let err = parse_yahoo_chart_json("Too Many Requests\r\n", "SPY", "1d").unwrap_err();
+        assert!(matches!(err, ProviderError::RateLimited { .. }));
     }
 }
@@ -502,7 +502,7 @@ pub fn get_param_bounds(strategy_type: StrategyTypeId) -> Vec<ParamBounds> {
             ParamBounds::new("af_max", 0.1, 0.5, 0.01),
         ],
         // Strategies without clear parameter bounds or fixed params
-        StrategyTypeId::TurtleS1 | StrategyTypeId::TurtleS2 => {
+        StrategyTypeId::TurtleS1 | StrategyTypeId::TurtleS2 | StrategyTypeId::BuyHold => {
             vec![] // Fixed strategies
         }
         _ => vec![], // Other strategies not yet supported
@@ -602,7 +602,7 @@ fn extract_config_params(config: &StrategyConfigId) -> Option<Vec<f64>> {
         StrategyConfigId::HeikinAshi { confirmation_bars } => Some(vec![*confirmation_bars as f64]),
 
         // Fixed strategies
-        StrategyConfigId::TurtleS1 | StrategyConfigId::TurtleS2 => None,
+        StrategyConfigId::TurtleS1 | StrategyConfigId::TurtleS2 | StrategyConfigId::BuyHold => None,
 
         // Variants - extract same params as base
         StrategyConfigId::SupertrendVolume {
@@ -1156,6 +1156,8 @@ pub fn extract_indicator_requirements(
                         window: *slow_period,
                     },
                 ],
+                // Hull MA and KAMA are computed by the strategy itself (not cached)
+                MAType::Hull | MAType::Kama => vec![],
             }
         }
         StrategySpec::Tsmom { lookback } => {
@@ -5,6 +5,8 @@
 use crate::bar::Bar;
 use chrono::Datelike;
 
+pub mod streaming;
+
 /// Donchian channel values (upper and lower bands).
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DonchianChannel {
@@ -145,6 +147,11 @@ pub fn ema_close(bars: &[Bar], window: usize) -> Vec<Option<f64>> {
 pub enum MAType {
     SMA,
     EMA,
+    /// Hull moving average (low-lag weighted MA)
+    Hull,
+    /// Kaufman adaptive moving average (window = efficiency-ratio period,
+    /// default 2/30 fast/slow smoothing)
+    Kama,
 }
 
 impl MAType {
@@ -153,8 +160,176 @@ impl MAType {
         match self {
             Self::SMA => "SMA",
             Self::EMA => "EMA",
+            Self::Hull => "Hull",
+            Self::Kama => "KAMA",
+        }
+    }
+}
+
+/// Linearly weighted moving average over a series with a `None` warmup prefix.
+///
+/// The newest value gets weight `window`, the oldest weight 1. Output is `None`
+/// until `window` consecutive values are available.
+fn wma(values: &[Option<f64>], window: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; values.len()];
+    if window == 0 {
+        return out;
+    }
+
+    let denom = (window * (window + 1)) as f64 / 2.0;
+    for i in (window - 1)..values.len() {
+        let mut sum = 0.0;
+        let mut complete = true;
+        for (k, v) in values[(i + 1 - window)..=i].iter().enumerate() {
+            match v {
+                Some(v) => sum += v * (k + 1) as f64,
+                None => {
+                    complete = false;
+                    break;
+                }
+            }
+        }
+        if complete {
+            out[i] = Some(sum / denom);
+        }
+    }
+
+    out
+}
+
+/// Simple moving average over a series with a `None` warmup prefix.
+///
+/// Output is `None` until `window` consecutive values are available.
+fn sma_series(values: &[Option<f64>], window: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; values.len()];
+    if window == 0 {
+        return out;
+    }
+
+    for i in (window - 1)..values.len() {
+        let window_values = &values[(i + 1 - window)..=i];
+        if window_values.iter().all(|v| v.is_some()) {
+            out[i] = Some(window_values.iter().flatten().sum::<f64>() / window as f64);
         }
     }
+
+    out
+}
+
+/// Exponential moving average over a series with a `None` warmup prefix.
+///
+/// Seeded with the SMA of the first `window` values after the prefix, like
+/// `ema_close`. Output is `None` until then.
+pub(crate) fn ema_series(values: &[Option<f64>], window: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; values.len()];
+    let Some(start) = values.iter().position(|v| v.is_some()) else {
+        return out;
+    };
+    if window == 0 || values.len() - start < window {
+        return out;
+    }
+
+    let k = 2.0 / (window as f64 + 1.0);
+    let seed = values[start..start + window].iter().flatten().sum::<f64>() / window as f64;
+    out[start + window - 1] = Some(seed);
+
+    let mut prev = seed;
+    for i in (start + window)..values.len() {
+        if let Some(v) = values[i] {
+            prev = v * k + prev * (1.0 - k);
+            out[i] = Some(prev);
+        }
+    }
+
+    out
+}
+
+/// Hull moving average of `close`.
+///
+/// HMA = WMA(2 × WMA(close, period / 2) − WMA(close, period), round(√period))
+///
+/// The difference of weighted MAs cancels most of the lag, and the final
+/// √period WMA smooths the result. Values are `None` for the first
+/// `period + round(√period) - 2` bars.
+pub fn hma_close(bars: &[Bar], period: usize) -> Vec<Option<f64>> {
+    if period == 0 {
+        return vec![None; bars.len()];
+    }
+
+    let half = (period / 2).max(1);
+    let sqrt_period = ((period as f64).sqrt().round() as usize).max(1);
+
+    let closes: Vec<Option<f64>> = bars.iter().map(|b| Some(b.close)).collect();
+    let wma_half = wma(&closes, half);
+    let wma_full = wma(&closes, period);
+
+    let raw: Vec<Option<f64>> = wma_half
+        .iter()
+        .zip(&wma_full)
+        .map(|(h, f)| match (h, f) {
+            (Some(h), Some(f)) => Some(2.0 * h - f),
+            _ => None,
+        })
+        .collect();
+
+    wma(&raw, sqrt_period)
+}
+
+/// Default fast EMA period for KAMA's smoothing constant.
+pub const KAMA_DEFAULT_FAST: usize = 2;
+
+/// Default slow EMA period for KAMA's smoothing constant.
+pub const KAMA_DEFAULT_SLOW: usize = 30;
+
+/// Kaufman adaptive moving average of `close`.
+///
+/// The efficiency ratio ER = |close[t] - close[t - er_period]| / Σ|Δclose|
+/// over the last `er_period` bars scales the smoothing constant between the
+/// `fast` and `slow` EMA constants:
+///
+/// SC = (ER × (2/(fast+1) - 2/(slow+1)) + 2/(slow+1))²
+/// KAMA[t] = KAMA[t-1] + SC × (close[t] - KAMA[t-1])
+///
+/// Clean trends (ER → 1) let KAMA hug price; choppy ranges (ER → 0) flatten it.
+/// KAMA is seeded with `close[er_period - 1]`; the first `er_period` values are `None`.
+pub fn kama(bars: &[Bar], er_period: usize, fast: usize, slow: usize) -> Vec<Option<f64>> {
+    let closes: Vec<f64> = bars.iter().map(|b| b.close).collect();
+    kama_from_closes(&closes, er_period, fast, slow)
+}
+
+/// KAMA over a raw close series (shared by `kama` and the Polars expression).
+pub(crate) fn kama_from_closes(
+    closes: &[f64],
+    er_period: usize,
+    fast: usize,
+    slow: usize,
+) -> Vec<Option<f64>> {
+    let mut out = vec![None; closes.len()];
+    if er_period == 0 || closes.len() <= er_period {
+        return out;
+    }
+
+    let fast_sc = 2.0 / (fast as f64 + 1.0);
+    let slow_sc = 2.0 / (slow as f64 + 1.0);
+    let mut prev = closes[er_period - 1];
+
+    for i in er_period..closes.len() {
+        let change = (closes[i] - closes[i - er_period]).abs();
+        let volatility: f64 = ((i + 1 - er_period)..=i)
+            .map(|j| (closes[j] - closes[j - 1]).abs())
+            .sum();
+        let er = if volatility > 0.0 {
+            change / volatility
+        } else {
+            0.0
+        };
+        let sc = (er * (fast_sc - slow_sc) + slow_sc).powi(2);
+
+        prev += sc * (closes[i] - prev);
+        out[i] = Some(prev);
+    }
+
+    out
 }
 
 /// Compute True Range for each bar.
@@ -188,6 +363,23 @@ pub fn true_range(bars: &[Bar]) -> Vec<f64> {
     out
 }
 
+/// True Range from separate high, low and close series.
+///
+/// Same definition as [`true_range`]; the slices must have equal length.
+pub fn true_range_hlc(high: &[f64], low: &[f64], close: &[f64]) -> Vec<f64> {
+    (0..high.len())
+        .map(|i| {
+            let (h, l) = (high[i], low[i]);
+            if i == 0 {
+                h - l
+            } else {
+                let prev_c = close[i - 1];
+                (h - l).max((h - prev_c).abs()).max((l - prev_c).abs())
+            }
+        })
+        .collect()
+}
+
 /// Average True Range (ATR) over a fixed window.
 ///
 /// ATR is the simple moving average of True Range values.
@@ -230,14 +422,17 @@ pub fn atr(bars: &[Bar], window: usize) -> Vec<Option<f64>> {
 ///
 /// Wilder smoothing is equivalent to EMA with alpha = 1/window.
 pub fn atr_wilder(bars: &[Bar], window: usize) -> Vec<Option<f64>> {
-    if window == 0 || bars.is_empty() {
-        return vec![None; bars.len()];
-    }
+    atr_wilder_from_true_range(&true_range(bars), window)
+}
 
-    let tr = true_range(bars);
-    let mut out = vec![None; bars.len()];
+/// Wilder ATR over a precomputed true range series (see [`atr_wilder`]).
+///
+/// Lets callers holding columnar high/low/close data (see [`true_range_hlc`])
+/// get exactly the same values as the bar-based version.
+pub fn atr_wilder_from_true_range(tr: &[f64], window: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; tr.len()];
 
-    if bars.len() < window {
+    if window == 0 || tr.len() < window {
         return out;
     }
 
@@ -248,7 +443,7 @@ pub fn atr_wilder(bars: &[Bar], window: usize) -> Vec<Option<f64>> {
 
     // Wilder smoothing for subsequent values
     let alpha = 1.0 / window as f64;
-    for i in window..bars.len() {
+    for i in window..tr.len() {
         let atr_val = prev_atr * (1.0 - alpha) + tr[i] * alpha;
         out[i] = Some(atr_val);
         prev_atr = atr_val;
@@ -882,6 +1077,91 @@ pub fn darvas_boxes(bars: &[Bar], confirmation_bars: usize) -> Vec<Option<Darvas
     out
 }
 
+/// Pivot point calculation method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum PivotMethod {
+    /// Floor-trader pivots: levels derived from P = (H + L + C) / 3.
+    Classic,
+    /// Fibonacci pivots: P ± 0.382 / 0.618 / 1.000 × prior range.
+    Fibonacci,
+    /// Camarilla pivots: prior close ± range × 1.1 / 12, / 6, / 4.
+    Camarilla,
+}
+
+impl PivotMethod {
+    /// Get display name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Classic => "Classic",
+            Self::Fibonacci => "Fibonacci",
+            Self::Camarilla => "Camarilla",
+        }
+    }
+}
+
+/// Pivot point support/resistance levels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PivotLevels {
+    pub pivot: f64,
+    pub r1: f64,
+    pub r2: f64,
+    pub r3: f64,
+    pub s1: f64,
+    pub s2: f64,
+    pub s3: f64,
+}
+
+/// Compute pivot levels from the prior bar's high, low and close.
+///
+/// Levels at index `t` are derived from bar `t - 1`, i.e. they are the levels
+/// a trader would have drawn before bar `t` opened (prior-day HLC → today's
+/// levels on daily bars). The first bar has no prior and yields `None`.
+///
+/// All methods share the pivot P = (H + L + C) / 3; they differ in how the
+/// support (S1–S3) and resistance (R1–R3) levels are spaced.
+pub fn pivot_points(bars: &[Bar], method: PivotMethod) -> Vec<Option<PivotLevels>> {
+    let mut out = vec![None; bars.len()];
+
+    for i in 1..bars.len() {
+        let prev = &bars[i - 1];
+        let (h, l, c) = (prev.high, prev.low, prev.close);
+        let pivot = (h + l + c) / 3.0;
+        let range = h - l;
+
+        out[i] = Some(match method {
+            PivotMethod::Classic => PivotLevels {
+                pivot,
+                r1: 2.0 * pivot - l,
+                r2: pivot + range,
+                r3: h + 2.0 * (pivot - l),
+                s1: 2.0 * pivot - h,
+                s2: pivot - range,
+                s3: l - 2.0 * (h - pivot),
+            },
+            PivotMethod::Fibonacci => PivotLevels {
+                pivot,
+                r1: pivot + 0.382 * range,
+                r2: pivot + 0.618 * range,
+                r3: pivot + range,
+                s1: pivot - 0.382 * range,
+                s2: pivot - 0.618 * range,
+                s3: pivot - range,
+            },
+            PivotMethod::Camarilla => PivotLevels {
+                pivot,
+                r1: c + range * 1.1 / 12.0,
+                r2: c + range * 1.1 / 6.0,
+                r3: c + range * 1.1 / 4.0,
+                s1: c - range * 1.1 / 12.0,
+                s2: c - range * 1.1 / 6.0,
+                s3: c - range * 1.1 / 4.0,
+            },
+        });
+    }
+
+    out
+}
+
 // =============================================================================
 // Phase 4: Stateful Trend Indicators
 // =============================================================================
@@ -1060,6 +1340,11 @@ pub enum OpeningPeriod {
     Monthly,
     /// Rolling: Range is computed from the prior N bars (no calendar alignment).
     Rolling,
+    /// Daily: Range is computed from the first N bars of each trading session.
+    ///
+    /// Intended for intraday bars; the range resets whenever the UTC date of
+    /// `Bar::ts` changes.
+    Daily,
 }
 
 impl OpeningPeriod {
@@ -1069,10 +1354,44 @@ impl OpeningPeriod {
             Self::Weekly => "Weekly",
             Self::Monthly => "Monthly",
             Self::Rolling => "Rolling",
+            Self::Daily => "Daily",
         }
     }
 }
 
+/// Returns true if the bar at `idx` opens a new trading session.
+///
+/// Sessions are delimited by the UTC calendar date of `Bar::ts`. The first
+/// bar of the series always starts a session.
+pub fn is_session_start(bars: &[Bar], idx: usize) -> bool {
+    idx == 0 || bars[idx].ts.date_naive() != bars[idx - 1].ts.date_naive()
+}
+
+/// Flags bars whose next bar is expected to be the last bar of the session.
+///
+/// The closing time is taken from the last bar of the previous session and
+/// the bar spacing from the gap to the previous bar, so each flag only uses
+/// data up to its own bar. Always false during the first session (no close
+/// has been seen yet) and on the first bar of a session.
+pub fn pre_session_close(bars: &[Bar]) -> Vec<bool> {
+    let mut flags = vec![false; bars.len()];
+    let mut close_time = None;
+
+    for idx in 1..bars.len() {
+        if is_session_start(bars, idx) {
+            close_time = Some(bars[idx - 1].ts.time());
+            continue;
+        }
+        if let Some(close_time) = close_time {
+            let next_ts = bars[idx].ts + (bars[idx].ts - bars[idx - 1].ts);
+            flags[idx] =
+                next_ts.date_naive() == bars[idx].ts.date_naive() && next_ts.time() >= close_time;
+        }
+    }
+
+    flags
+}
+
 /// Opening Range state for each bar.
 ///
 /// Used for Opening Range Breakout strategies adapted for daily bars.
@@ -1109,10 +1428,10 @@ impl OpeningRange {
 ///
 /// **Parameters:**
 /// - `range_bars`: Number of bars that define the opening range
-/// - `period`: How to determine when a new period starts (Weekly, Monthly, Rolling)
+/// - `period`: How to determine when a new period starts (Weekly, Monthly, Rolling, Daily)
 ///
-/// **For Weekly/Monthly periods:**
-/// - Detects the first bar of each week/month based on timestamp
+/// **For Weekly/Monthly/Daily periods:**
+/// - Detects the first bar of each week/month/session based on timestamp
 /// - Accumulates high/low of first N bars after period start
 /// - After N bars, range is "complete" and breakout signals are valid
 ///
@@ -1154,7 +1473,7 @@ pub fn opening_range(
                 });
             }
         }
-        OpeningPeriod::Weekly | OpeningPeriod::Monthly => {
+        OpeningPeriod::Weekly | OpeningPeriod::Monthly | OpeningPeriod::Daily => {
             // Track the start of each period and accumulate range
             let mut period_start_idx: Option<usize> = None;
             let mut range_high = f64::NEG_INFINITY;
@@ -1162,6 +1481,7 @@ pub fn opening_range(
             let mut bars_in_range = 0usize;
             let mut prev_week = None;
             let mut prev_month = None;
+            let mut prev_day = None;
 
             for i in 0..bars.len() {
                 let bar = &bars[i];
@@ -1185,6 +1505,12 @@ pub fn opening_range(
                         prev_month = Some(key);
                         is_new
                     }
+                    OpeningPeriod::Daily => {
+                        let key = ts.date_naive();
+                        let is_new = prev_day != Some(key);
+                        prev_day = Some(key);
+                        is_new
+                    }
                     OpeningPeriod::Rolling => unreachable!(),
                 };
 
@@ -1719,6 +2045,43 @@ pub fn aroon(bars: &[Bar], period: usize) -> Vec<Option<AroonIndicator>> {
     out
 }
 
+/// Compute the Vortex Indicator (VI+, VI-) for each bar.
+///
+/// VM+ = |high - prev_low|, VM- = |low - prev_high|. Over the last `period`
+/// bars (each with a previous bar):
+///
+/// VI+ = Σ VM+ / Σ TR, VI- = Σ VM- / Σ TR
+///
+/// VI+ above VI- indicates upward trend pressure. The first `period` entries
+/// are `None` (bar 0 has no previous bar), as is any window with zero true range.
+pub fn vortex(bars: &[Bar], period: usize) -> Vec<Option<(f64, f64)>> {
+    let mut out = vec![None; bars.len()];
+    if period == 0 || bars.len() <= period {
+        return out;
+    }
+
+    let tr = true_range(bars);
+    let mut vm_plus = vec![0.0; bars.len()];
+    let mut vm_minus = vec![0.0; bars.len()];
+    for i in 1..bars.len() {
+        vm_plus[i] = (bars[i].high - bars[i - 1].low).abs();
+        vm_minus[i] = (bars[i].low - bars[i - 1].high).abs();
+    }
+
+    for (i, out_slot) in out.iter_mut().enumerate().skip(period) {
+        let window = (i + 1 - period)..=i;
+        let tr_sum: f64 = tr[window.clone()].iter().sum();
+        if tr_sum == 0.0 {
+            continue;
+        }
+        let plus: f64 = vm_plus[window.clone()].iter().sum();
+        let minus: f64 = vm_minus[window].iter().sum();
+        *out_slot = Some((plus / tr_sum, minus / tr_sum));
+    }
+
+    out
+}
+
 // =============================================================================
 // Phase 5: Oscillator Indicators (TA-Focused)
 // =============================================================================
@@ -1872,22 +2235,28 @@ pub struct RSIValue {
 ///
 /// Returns `None` until there are `period` bars.
 pub fn rsi(bars: &[Bar], period: usize) -> Vec<Option<RSIValue>> {
-    if bars.is_empty() || period == 0 {
-        return vec![None; bars.len()];
+    let closes: Vec<f64> = bars.iter().map(|b| b.close).collect();
+    rsi_from_closes(&closes, period)
+}
+
+/// RSI over a raw close series (shared by `rsi` and `stoch_rsi`).
+pub(crate) fn rsi_from_closes(closes: &[f64], period: usize) -> Vec<Option<RSIValue>> {
+    if closes.is_empty() || period == 0 {
+        return vec![None; closes.len()];
     }
 
-    let mut out = vec![None; bars.len()];
+    let mut out = vec![None; closes.len()];
 
-    if bars.len() < period + 1 {
+    if closes.len() < period + 1 {
         return out;
     }
 
     // Calculate gains and losses
-    let mut gains = vec![0.0; bars.len()];
-    let mut losses = vec![0.0; bars.len()];
+    let mut gains = vec![0.0; closes.len()];
+    let mut losses = vec![0.0; closes.len()];
 
-    for i in 1..bars.len() {
-        let change = bars[i].close - bars[i - 1].close;
+    for i in 1..closes.len() {
+        let change = closes[i] - closes[i - 1];
         if change > 0.0 {
             gains[i] = change;
         } else {
@@ -1917,7 +2286,7 @@ pub fn rsi(bars: &[Bar], period: usize) -> Vec<Option<RSIValue>> {
     let mut prev_avg_gain = initial_avg_gain;
     let mut prev_avg_loss = initial_avg_loss;
 
-    for i in (period + 1)..bars.len() {
+    for i in (period + 1)..closes.len() {
         let avg_gain = prev_avg_gain * (1.0 - alpha) + gains[i] * alpha;
         let avg_loss = prev_avg_loss * (1.0 - alpha) + losses[i] * alpha;
 
@@ -2063,6 +2432,84 @@ pub fn stochastic(
     out
 }
 
+/// Compute Stochastic RSI: the stochastic formula applied to the RSI series.
+///
+/// Standard parameters: rsi_period=14, stoch_period=14, k_smooth=3, d_period=3
+///
+/// - %K raw = (RSI - Lowest RSI) / (Highest RSI - Lowest RSI) * 100
+/// - %K smooth = SMA of %K raw, %D = SMA of %K smooth
+///
+/// Values are bounded to [0, 100]. When the RSI is flat across the window
+/// (zero range), %K raw is the midpoint, 50.
+///
+/// Returns `None` until there are enough bars for all calculations: the first
+/// value is at index `rsi_period + stoch_period + k_smooth + d_period - 3`.
+pub fn stoch_rsi(
+    bars: &[Bar],
+    rsi_period: usize,
+    stoch_period: usize,
+    k_smooth: usize,
+    d_period: usize,
+) -> Vec<Option<StochasticValue>> {
+    let closes: Vec<f64> = bars.iter().map(|b| b.close).collect();
+    let k_raw = stoch_rsi_raw_from_closes(&closes, rsi_period, stoch_period);
+    let k_smooth_vals = sma_series(&k_raw, k_smooth);
+    let d_vals = sma_series(&k_smooth_vals, d_period);
+
+    k_raw
+        .iter()
+        .zip(&k_smooth_vals)
+        .zip(&d_vals)
+        .map(|((kr, ks), d)| {
+            Some(StochasticValue {
+                k_raw: (*kr)?,
+                k_smooth: (*ks)?,
+                d: (*d)?,
+            })
+        })
+        .collect()
+}
+
+/// Raw Stochastic RSI %K over a close series (shared by `stoch_rsi` and the
+/// Polars expression).
+pub(crate) fn stoch_rsi_raw_from_closes(
+    closes: &[f64],
+    rsi_period: usize,
+    stoch_period: usize,
+) -> Vec<Option<f64>> {
+    let mut out = vec![None; closes.len()];
+    if stoch_period == 0 {
+        return out;
+    }
+
+    let rsi_vals: Vec<Option<f64>> = rsi_from_closes(closes, rsi_period)
+        .iter()
+        .map(|v| v.map(|v| v.rsi))
+        .collect();
+
+    for i in (stoch_period - 1)..closes.len() {
+        let Some(window) = rsi_vals[(i + 1 - stoch_period)..=i]
+            .iter()
+            .copied()
+            .collect::<Option<Vec<f64>>>()
+        else {
+            continue;
+        };
+        let highest = window.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let lowest = window.iter().copied().fold(f64::INFINITY, f64::min);
+        let current = window[stoch_period - 1];
+
+        let range = highest - lowest;
+        out[i] = if range > 0.0 {
+            Some(((current - lowest) / range * 100.0).clamp(0.0, 100.0))
+        } else {
+            Some(50.0) // Midpoint when the RSI is flat
+        };
+    }
+
+    out
+}
+
 /// Williams %R value.
 ///
 /// Williams %R is similar to Stochastic but inverted, ranging from -100 to 0:
@@ -2212,6 +2659,188 @@ pub fn roc(bars: &[Bar], period: usize) -> Vec<Option<ROCValue>> {
     out
 }
 
+/// Compute the Coppock Curve.
+///
+/// Coppock = WMA(ROC(`roc1`) + ROC(`roc2`), `wma_period`)
+///
+/// A long-horizon momentum oscillator, classically run on monthly closes with
+/// 14/11/10. The first `max(roc1, roc2) + wma_period - 1` entries are `None`,
+/// so on daily bars with month-scaled periods the warmup spans years.
+pub fn coppock(bars: &[Bar], roc1: usize, roc2: usize, wma_period: usize) -> Vec<Option<f64>> {
+    if roc1 == 0 || roc2 == 0 || wma_period == 0 {
+        return vec![None; bars.len()];
+    }
+
+    let summed: Vec<Option<f64>> = roc(bars, roc1)
+        .iter()
+        .zip(roc(bars, roc2))
+        .map(|(a, b)| Some(a.as_ref()?.roc + b?.roc))
+        .collect();
+
+    wma(&summed, wma_period)
+}
+
+/// Compute Elder-Ray Bull and Bear Power.
+///
+/// Returns `(bull_power, bear_power)` per bar:
+/// - Bull Power = High - EMA(close, `ema_period`)
+/// - Bear Power = Low - EMA(close, `ema_period`)
+///
+/// The EMA is SMA-seeded like [`ema_close`], so the first `ema_period - 1`
+/// entries are `None`.
+pub fn elder_ray(bars: &[Bar], ema_period: usize) -> Vec<Option<(f64, f64)>> {
+    ema_close(bars, ema_period)
+        .into_iter()
+        .zip(bars)
+        .map(|(ema, bar)| ema.map(|ema| (bar.high - ema, bar.low - ema)))
+        .collect()
+}
+
+/// Compute CMO (Chande Momentum Oscillator).
+///
+/// Over the last `period` close-to-close changes:
+///
+/// CMO = (sum_up - sum_down) / (sum_up + sum_down) × 100
+///
+/// Values are bounded [-100, 100]; a window with no price change is 0.
+/// Standard period: 14. Returns `None` for the first `period` bars.
+pub fn cmo(bars: &[Bar], period: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; bars.len()];
+    if period == 0 || bars.len() <= period {
+        return out;
+    }
+
+    for (i, out_slot) in out.iter_mut().enumerate().skip(period) {
+        let mut sum_up = 0.0;
+        let mut sum_down = 0.0;
+        for j in (i + 1 - period)..=i {
+            let change = bars[j].close - bars[j - 1].close;
+            if change > 0.0 {
+                sum_up += change;
+            } else {
+                sum_down -= change;
+            }
+        }
+
+        let total = sum_up + sum_down;
+        *out_slot = Some(if total > 0.0 {
+            (sum_up - sum_down) / total * 100.0
+        } else {
+            0.0
+        });
+    }
+
+    out
+}
+
+/// Largest magnitude a normalized price may reach before the Fisher transform.
+///
+/// `atanh(±1)` is infinite, so values at the edge of the window are clamped.
+pub const FISHER_CLAMP: f64 = 0.999;
+
+/// Compute the Fisher Transform (fisher, trigger) for each bar.
+///
+/// The median price (H+L)/2 is normalized to [-1, 1] over the last `period` bars:
+///
+/// x = 2 × (price - lowest) / (highest - lowest) - 1
+///
+/// A flat window (highest == lowest) normalizes to 0. `x` is clamped to
+/// ±[`FISHER_CLAMP`], then fisher = atanh(x) = 0.5 × ln((1 + x) / (1 - x)).
+/// The trigger is the previous bar's fisher value.
+///
+/// Standard period: 10. Returns `None` for the first `period` bars.
+pub fn fisher_transform(bars: &[Bar], period: usize) -> Vec<Option<(f64, f64)>> {
+    let prices: Vec<f64> = bars.iter().map(|b| (b.high + b.low) / 2.0).collect();
+    let fisher = fisher_from_prices(&prices, period);
+
+    (0..fisher.len())
+        .map(
+            |i| match (fisher[i], i.checked_sub(1).and_then(|j| fisher[j])) {
+                (Some(value), Some(trigger)) => Some((value, trigger)),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+/// Fisher line over a raw price series (shared by `fisher_transform` and the
+/// Polars expression). The first value is at index `period - 1`.
+pub(crate) fn fisher_from_prices(prices: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; prices.len()];
+    if period == 0 {
+        return out;
+    }
+
+    for i in (period - 1)..prices.len() {
+        let window = &prices[(i + 1 - period)..=i];
+        let highest = window.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let lowest = window.iter().copied().fold(f64::INFINITY, f64::min);
+        let range = highest - lowest;
+
+        let normalized = if range > 0.0 {
+            2.0 * (prices[i] - lowest) / range - 1.0
+        } else {
+            0.0
+        };
+        out[i] = Some(normalized.clamp(-FISHER_CLAMP, FISHER_CLAMP).atanh());
+    }
+
+    out
+}
+
+/// Compute TRIX (rate of change of a triple-smoothed EMA).
+///
+/// EMA3 = EMA(EMA(EMA(close, period), period), period)
+///
+/// TRIX = (EMA3 - prev EMA3) / prev EMA3 × 100
+///
+/// The triple smoothing filters out short cycles, so TRIX crossing zero marks
+/// a turn in the underlying trend. Standard period: 15. Each EMA adds
+/// `period - 1` bars of warmup, so the first `3 × period - 2` values are `None`.
+pub fn trix(bars: &[Bar], period: usize) -> Vec<Option<f64>> {
+    let closes: Vec<f64> = bars.iter().map(|b| b.close).collect();
+    trix_from_closes(&closes, period)
+}
+
+/// TRIX with its signal line (an EMA of TRIX over `signal_period`).
+///
+/// Returns (trix, signal); `None` for the first `3 × period + signal_period - 3` bars.
+pub fn trix_with_signal(
+    bars: &[Bar],
+    period: usize,
+    signal_period: usize,
+) -> Vec<Option<(f64, f64)>> {
+    let values = trix(bars, period);
+    let signal = ema_series(&values, signal_period);
+
+    values
+        .iter()
+        .zip(&signal)
+        .map(|(t, s)| Some(((*t)?, (*s)?)))
+        .collect()
+}
+
+/// TRIX over a raw close series (shared by `trix` and the Polars expression).
+pub(crate) fn trix_from_closes(closes: &[f64], period: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; closes.len()];
+    if period == 0 {
+        return out;
+    }
+
+    let closes: Vec<Option<f64>> = closes.iter().map(|&c| Some(c)).collect();
+    let ema3 = ema_series(&ema_series(&ema_series(&closes, period), period), period);
+
+    for i in 1..ema3.len() {
+        if let (Some(current), Some(prev)) = (ema3[i], ema3[i - 1]) {
+            if prev != 0.0 {
+                out[i] = Some((current - prev) / prev * 100.0);
+            }
+        }
+    }
+
+    out
+}
+
 /// Ichimoku Cloud component values.
 ///
 /// The Ichimoku Cloud (Ichimoku Kinko Hyo) is a comprehensive indicator that defines:
@@ -2320,6 +2949,229 @@ pub fn ichimoku(
     out
 }
 
+// =============================================================================
+// Phase 6: Volume Indicators
+// =============================================================================
+
+/// Typical price of a bar: (high + low + close) / 3.
+fn typical_price(bar: &Bar) -> f64 {
+    (bar.high + bar.low + bar.close) / 3.0
+}
+
+/// Rolling volume-weighted average price over a fixed window.
+///
+/// VWAP = Σ(typical_price × volume) / Σ(volume) over the last `window` bars
+/// (including the current bar).
+///
+/// Zero-volume bars contribute nothing to either sum. Returns `None` during
+/// the warmup period and for any window whose total volume is zero.
+pub fn vwap(bars: &[Bar], window: usize) -> Vec<Option<f64>> {
+    if window == 0 {
+        return vec![None; bars.len()];
+    }
+
+    let mut out = vec![None; bars.len()];
+    let mut pv_sum = 0.0;
+    let mut vol_sum = 0.0;
+
+    for i in 0..bars.len() {
+        pv_sum += typical_price(&bars[i]) * bars[i].volume;
+        vol_sum += bars[i].volume;
+
+        if i >= window {
+            let dropped = &bars[i - window];
+            pv_sum -= typical_price(dropped) * dropped.volume;
+            vol_sum -= dropped.volume;
+        }
+
+        if i + 1 >= window && vol_sum > 0.0 {
+            out[i] = Some(pv_sum / vol_sum);
+        }
+    }
+
+    out
+}
+
+/// Anchored VWAP accumulated from `anchor_index` forward.
+///
+/// Bars before the anchor are `None`. From the anchor onward, the value is the
+/// cumulative Σ(typical_price × volume) / Σ(volume) since the anchor bar.
+/// Leading zero-volume bars after the anchor stay `None` until some volume
+/// has traded.
+pub fn anchored_vwap(bars: &[Bar], anchor_index: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; bars.len()];
+    let mut pv_sum = 0.0;
+    let mut vol_sum = 0.0;
+
+    for i in anchor_index..bars.len() {
+        pv_sum += typical_price(&bars[i]) * bars[i].volume;
+        vol_sum += bars[i].volume;
+
+        if vol_sum > 0.0 {
+            out[i] = Some(pv_sum / vol_sum);
+        }
+    }
+
+    out
+}
+
+/// Money Flow Index: a volume-weighted RSI computed from typical price.
+///
+/// Raw money flow = typical_price × volume. A bar's flow is positive when its
+/// typical price is above the previous bar's, negative when below, and ignored
+/// when unchanged. Over the last `period` bars:
+///
+/// MFI = 100 - 100 / (1 + positive_flow / negative_flow)
+///
+/// Values are bounded [0, 100]. When there is no negative flow in the window,
+/// MFI is 100 (same convention as `rsi`). The first `period` entries are `None`.
+pub fn mfi(bars: &[Bar], period: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; bars.len()];
+    if period == 0 || bars.len() <= period {
+        return out;
+    }
+
+    let mut pos_flow = vec![0.0; bars.len()];
+    let mut neg_flow = vec![0.0; bars.len()];
+    for i in 1..bars.len() {
+        let tp = typical_price(&bars[i]);
+        let prev_tp = typical_price(&bars[i - 1]);
+        if tp > prev_tp {
+            pos_flow[i] = tp * bars[i].volume;
+        } else if tp < prev_tp {
+            neg_flow[i] = tp * bars[i].volume;
+        }
+    }
+
+    for (i, out_slot) in out.iter_mut().enumerate().skip(period) {
+        let window = (i + 1 - period)..=i;
+        let pos: f64 = pos_flow[window.clone()].iter().sum();
+        let neg: f64 = neg_flow[window].iter().sum();
+
+        *out_slot = Some(if neg == 0.0 {
+            100.0
+        } else {
+            100.0 - 100.0 / (1.0 + pos / neg)
+        });
+    }
+
+    out
+}
+
+/// Chaikin Money Flow: volume-weighted accumulation/distribution over a window.
+///
+/// Each bar's money flow multiplier is ((close - low) - (high - close)) / (high - low),
+/// which is +1 when it closes at the high and -1 at the low. Over the last
+/// `period` bars:
+///
+/// CMF = sum(multiplier × volume) / sum(volume)
+///
+/// Values are bounded [-1, 1]. Zero-range bars (high == low) contribute a zero
+/// multiplier; a window with no volume is `None`. The first `period - 1`
+/// entries are `None`.
+pub fn cmf(bars: &[Bar], period: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; bars.len()];
+    if period == 0 || bars.len() < period {
+        return out;
+    }
+
+    let flow_volume: Vec<f64> = bars
+        .iter()
+        .map(|bar| money_flow_multiplier(bar) * bar.volume)
+        .collect();
+
+    for (i, out_slot) in out.iter_mut().enumerate().skip(period - 1) {
+        let window = (i + 1 - period)..=i;
+        let flow: f64 = flow_volume[window.clone()].iter().sum();
+        let volume: f64 = bars[window].iter().map(|bar| bar.volume).sum();
+
+        if volume > 0.0 {
+            *out_slot = Some(flow / volume);
+        }
+    }
+
+    out
+}
+
+/// Chaikin money flow multiplier: where the close sits in the bar's range, in [-1, 1].
+fn money_flow_multiplier(bar: &Bar) -> f64 {
+    let range = bar.high - bar.low;
+    if range <= 0.0 {
+        return 0.0;
+    }
+    ((bar.close - bar.low) - (bar.high - bar.close)) / range
+}
+
+/// On-Balance Volume: cumulative signed volume.
+///
+/// OBV starts at 0 on the first bar. Each subsequent bar adds its volume when
+/// the close is higher than the previous close, subtracts it when lower, and
+/// leaves OBV unchanged when the close is flat. No warmup is needed.
+pub fn obv(bars: &[Bar]) -> Vec<f64> {
+    let mut out = Vec::with_capacity(bars.len());
+    let mut total = 0.0;
+
+    for i in 0..bars.len() {
+        if i > 0 {
+            let prev_close = bars[i - 1].close;
+            if bars[i].close > prev_close {
+                total += bars[i].volume;
+            } else if bars[i].close < prev_close {
+                total -= bars[i].volume;
+            }
+        }
+        out.push(total);
+    }
+
+    out
+}
+
+/// Accumulation/Distribution line: cumulative money flow volume.
+///
+/// Each bar adds its Chaikin money flow multiplier × volume (see [`cmf`]), so
+/// the line climbs while closes print near bar highs and falls while they
+/// print near lows. Zero-range bars (high == low) add nothing. No warmup is
+/// needed.
+pub fn ad_line(bars: &[Bar]) -> Vec<f64> {
+    let mut out = Vec::with_capacity(bars.len());
+    let mut total = 0.0;
+
+    for bar in bars {
+        total += money_flow_multiplier(bar) * bar.volume;
+        out.push(total);
+    }
+
+    out
+}
+
+/// Williams Accumulation/Distribution: cumulative price-only accumulation.
+///
+/// Uses the true range bounds against the previous close:
+/// - Up close: add close - min(low, prev_close)
+/// - Down close: add close - max(high, prev_close) (a negative amount)
+/// - Flat close (and the first bar): add 0
+///
+/// Volume is not used. No warmup is needed.
+pub fn williams_ad(bars: &[Bar]) -> Vec<f64> {
+    let mut out = Vec::with_capacity(bars.len());
+    let mut total = 0.0;
+
+    for i in 0..bars.len() {
+        if i > 0 {
+            let prev_close = bars[i - 1].close;
+            let bar = &bars[i];
+            if bar.close > prev_close {
+                total += bar.close - bar.low.min(prev_close);
+            } else if bar.close < prev_close {
+                total += bar.close - bar.high.max(prev_close);
+            }
+        }
+        out.push(total);
+    }
+
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2330,9 +3182,8 @@ mod tests {
             .iter()
             .enumerate()
             .map(|(i, &c)| {
-                let ts = chrono::Utc
-                    .with_ymd_and_hms(2024, 1, 1 + i as u32, 0, 0, 0)
-                    .unwrap();
+                let ts = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+                    + chrono::Duration::days(i as i64);
                 Bar::new(ts, c, c, c, c, 0.0, "TEST", "1d")
             })
             .collect()
@@ -2342,9 +3193,8 @@ mod tests {
         ohlc.iter()
             .enumerate()
             .map(|(i, &(o, h, l, c))| {
-                let ts = chrono::Utc
-                    .with_ymd_and_hms(2024, 1, 1 + i as u32, 0, 0, 0)
-                    .unwrap();
+                let ts = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+                    + chrono::Duration::days(i as i64);
                 Bar::new(ts, o, h, l, c, 0.0, "TEST", "1d")
             })
             .collect()
@@ -2455,6 +3305,27 @@ mod tests {
         assert_eq!(ema, vec![None, None, None]);
     }
 
+    #[test]
+    fn hma_tracks_ramp_with_less_lag_than_sma() {
+        let closes: Vec<f64> = (0..40).map(|i| 100.0 + i as f64).collect();
+        let bars = bars_from_closes(&closes);
+        let hma = hma_close(&bars, 16);
+        let sma = sma_close(&bars, 16);
+
+        // Warmup: period + round(sqrt(period)) - 2 = 16 + 4 - 2
+        assert!(hma[..18].iter().all(|v| v.is_none()));
+        assert!(hma[18].is_some());
+
+        for i in 18..closes.len() {
+            let hma_lag = closes[i] - hma[i].unwrap();
+            let sma_lag = closes[i] - sma[i].unwrap();
+            // On a unit ramp SMA(16) lags 7.5; HMA(16) lags 2/3
+            assert!((sma_lag - 7.5).abs() < 1e-9);
+            assert!((hma_lag - 2.0 / 3.0).abs() < 1e-9);
+            assert!(hma_lag < sma_lag);
+        }
+    }
+
     #[test]
     fn true_range_basic() {
         // Simple case: no gaps, TR = high - low
@@ -3061,4 +3932,465 @@ mod tests {
 
         assert!((range.range_size() - 15.0).abs() < 1e-10);
     }
+
+    // =============================================================================
+    // Phase 6: Volume Indicators
+    // =============================================================================
+
+    fn bars_with_volume(data: &[(f64, f64, f64, f64)]) -> Vec<Bar> {
+        // (high, low, close, volume)
+        data.iter()
+            .enumerate()
+            .map(|(i, &(h, l, c, v))| {
+                let ts = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+                    + chrono::Duration::days(i as i64);
+                Bar::new(ts, c, h, l, c, v, "TEST", "1d")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn kama_flattens_in_ranging_market() {
+        // 15 trending bars (+1/bar), then 16 bars chopping between 112 and 114
+        let mut closes: Vec<f64> = (0..15).map(|i| 100.0 + i as f64).collect();
+        closes.extend((15..31).map(|i| if i % 2 == 0 { 114.0 } else { 112.0 }));
+        let bars = bars_from_closes(&closes);
+
+        let values = kama(&bars, 4, KAMA_DEFAULT_FAST, KAMA_DEFAULT_SLOW);
+        assert!(values[..4].iter().all(|v| v.is_none()));
+        assert!(values[4].is_some());
+
+        let mean_abs_change = |range: std::ops::Range<usize>| {
+            let n = range.len() as f64;
+            range
+                .map(|i| (values[i].unwrap() - values[i - 1].unwrap()).abs())
+                .sum::<f64>()
+                / n
+        };
+        let trending = mean_abs_change(8..15);
+        // From index 19 on, every ER window lies inside the range (ER = 0)
+        let ranging = mean_abs_change(20..31);
+
+        assert!(
+            trending > 0.9,
+            "KAMA should hug a clean trend: {}",
+            trending
+        );
+        assert!(
+            ranging < 0.1 * trending,
+            "KAMA should flatten in a range: {} vs {}",
+            ranging,
+            trending
+        );
+    }
+
+    #[test]
+    fn pivot_points_use_prior_bar_hlc() {
+        let bars = bars_from_ohlc(&[
+            (100.0, 110.0, 90.0, 105.0),
+            (105.0, 108.0, 101.0, 107.0),
+            (107.0, 112.0, 104.0, 111.0),
+        ]);
+
+        let classic = pivot_points(&bars, PivotMethod::Classic);
+        assert_eq!(classic[0], None);
+
+        // Bar 1 levels come from bar 0: H=110, L=90, C=105
+        let p = classic[1].unwrap();
+        assert!((p.pivot - 305.0 / 3.0).abs() < 1e-12);
+        assert!((p.r1 - (2.0 * p.pivot - 90.0)).abs() < 1e-12);
+        assert!((p.s1 - (2.0 * p.pivot - 110.0)).abs() < 1e-12);
+        assert!((p.r2 - (p.pivot + 20.0)).abs() < 1e-12);
+
+        // Bar 2 levels come from bar 1: H=108, L=101, C=107
+        assert!((classic[2].unwrap().pivot - 316.0 / 3.0).abs() < 1e-12);
+
+        let fib = pivot_points(&bars, PivotMethod::Fibonacci)[1].unwrap();
+        assert!((fib.r3 - (fib.pivot + 20.0)).abs() < 1e-12);
+        let cam = pivot_points(&bars, PivotMethod::Camarilla)[1].unwrap();
+        assert!((cam.s3 - (105.0 - 20.0 * 1.1 / 4.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn trix_positive_in_steady_uptrend() {
+        let closes: Vec<f64> = (0..40).map(|i| 100.0 + i as f64).collect();
+        let values = trix(&bars_from_closes(&closes), 5);
+
+        // Three EMAs of period 5: first value at 3 × 5 - 2
+        assert!(values[..13].iter().all(|v| v.is_none()));
+        assert!(values[13..].iter().all(|v| v.is_some_and(|t| t > 0.0)));
+
+        let with_signal = trix_with_signal(&bars_from_closes(&closes), 5, 3);
+        assert!(with_signal[..15].iter().all(|v| v.is_none()));
+        assert_eq!(with_signal[15].map(|(t, _)| t), values[15]);
+    }
+
+    #[test]
+    fn fisher_transform_clamp_keeps_values_finite() {
+        // Flat window normalizes to 0; the jump puts price at the window high
+        let bars = bars_from_closes(&[100.0, 100.0, 100.0, 100.0, 110.0, 120.0]);
+        let values = fisher_transform(&bars, 3);
+
+        assert_eq!(&values[..3], &[None, None, None]);
+        assert_eq!(values[3], Some((0.0, 0.0)));
+
+        let edge = FISHER_CLAMP.atanh();
+        let (fisher, trigger) = values[4].unwrap();
+        assert!(fisher.is_finite());
+        assert!((fisher - edge).abs() < 1e-12);
+        assert_eq!(trigger, 0.0);
+        assert_eq!(values[5], Some((edge, edge)));
+        assert!(values
+            .iter()
+            .flatten()
+            .all(|(f, t)| f.is_finite() && t.is_finite()));
+
+        // Midpoint of the window is 0
+        let mid = fisher_transform(&bars_from_closes(&[110.0, 100.0, 110.0, 105.0]), 3);
+        assert!(mid[3].unwrap().0.abs() < 1e-12);
+    }
+
+    #[test]
+    fn coppock_positive_in_multi_year_uptrend() {
+        // Four years of daily bars: 0.05%/day drift with a ±2% seasonal wobble
+        let closes: Vec<f64> = (0..1008)
+            .map(|i| 100.0 * 1.0005_f64.powi(i) * (1.0 + 0.02 * (i as f64 / 20.0).sin()))
+            .collect();
+        let bars = bars_from_closes(&closes);
+
+        // Classic 14/11/10 months scaled to trading days
+        let values = coppock(&bars, 294, 231, 210);
+        assert!(values[..503].iter().all(|v| v.is_none()));
+        assert!(values[503..].iter().all(|v| v.is_some_and(|c| c > 0.0)));
+
+        // Fixture shorter than the warmup is all None
+        assert!(coppock(&bars[..400], 294, 231, 210)
+            .iter()
+            .all(|v| v.is_none()));
+    }
+
+    #[test]
+    fn stoch_rsi_matches_hand_computed_values() {
+        let bars = bars_from_closes(&[10.0, 12.0, 11.0, 13.0, 13.0, 14.0, 12.0, 12.0]);
+
+        // RSI(2), Wilder-smoothed:
+        //   [_, _, 66.67, 85.71, 85.71, 93.33, 29.79, 29.79]
+        // %K raw over 2 RSI values (flat window -> 50):
+        //   [_, _, _, 100, 50, 100, 0, 50]
+        // %K smooth (SMA 2): [_, _, _, _, 75, 75, 50, 25]
+        // %D (SMA 2):        [_, _, _, _, _, 75, 62.5, 37.5]
+        let values = stoch_rsi(&bars, 2, 2, 2, 2);
+        assert!(values[..5].iter().all(|v| v.is_none()));
+
+        let expected = [(100.0, 75.0, 75.0), (0.0, 50.0, 62.5), (50.0, 25.0, 37.5)];
+        for (value, (k_raw, k_smooth, d)) in values[5..].iter().zip(expected) {
+            let value = value.unwrap();
+            assert!((value.k_raw - k_raw).abs() < 1e-9);
+            assert!((value.k_smooth - k_smooth).abs() < 1e-9);
+            assert!((value.d - d).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn stoch_rsi_bounded_and_flat_is_midpoint() {
+        // Constant closes: RSI is flat, so every %K is the midpoint
+        let flat = stoch_rsi(&bars_from_closes(&[100.0; 40]), 14, 14, 3, 3);
+        assert_eq!(
+            flat.iter().position(|v| v.is_some()),
+            Some(14 + 14 + 3 + 3 - 3)
+        );
+        assert!(flat
+            .iter()
+            .flatten()
+            .all(|v| v.k_raw == 50.0 && v.d == 50.0));
+
+        let closes: Vec<f64> = (0..60)
+            .map(|i| 100.0 + (i as f64 * 0.4).sin() * 10.0)
+            .collect();
+        let values = stoch_rsi(&bars_from_closes(&closes), 14, 14, 3, 3);
+        for v in values.iter().flatten() {
+            for x in [v.k_raw, v.k_smooth, v.d] {
+                assert!((0.0..=100.0).contains(&x));
+            }
+        }
+    }
+
+    #[test]
+    fn elder_ray_bull_power_positive_in_uptrend() {
+        // Steady 1-point-per-bar rise: the EMA lags below the highs
+        let closes: Vec<f64> = (0..30).map(|i| 100.0 + i as f64).collect();
+        let bars = bars_from_closes(&closes);
+
+        let values = elder_ray(&bars, 13);
+        assert!(values[..12].iter().all(|v| v.is_none()));
+        for (i, value) in values.iter().enumerate().skip(12) {
+            let (bull, bear) = value.unwrap();
+            assert!(bull > 0.0, "bull power at {} should be positive", i);
+            assert!(bull >= bear);
+        }
+
+        // Seed bar: EMA = mean of the first 13 closes (106)
+        let (bull, bear) = values[12].unwrap();
+        assert!((bull - (bars[12].high - 106.0)).abs() < 1e-12);
+        assert!((bear - (bars[12].low - 106.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cmo_all_up_closes_is_100() {
+        let bars = bars_from_closes(&[100.0, 101.0, 103.0, 104.0, 108.0, 109.0]);
+        let values = cmo(&bars, 3);
+
+        assert_eq!(&values[..3], &[None, None, None]);
+        assert!(values[3..].iter().all(|v| *v == Some(100.0)));
+
+        // Mixed window: +2 +1 -3 → (3 - 3) / 6 = 0; then +1 -3 +4 → (5 - 3) / 8
+        let mixed = cmo(
+            &bars_from_closes(&[100.0, 102.0, 103.0, 100.0, 101.0, 98.0, 102.0]),
+            3,
+        );
+        assert_eq!(mixed[3], Some(0.0));
+        assert!((mixed[6].unwrap() - 25.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vwap_constant_price_equals_price() {
+        let bars = bars_with_volume(&[
+            (50.0, 50.0, 50.0, 100.0),
+            (50.0, 50.0, 50.0, 0.0),
+            (50.0, 50.0, 50.0, 300.0),
+            (50.0, 50.0, 50.0, 250.0),
+            (50.0, 50.0, 50.0, 10.0),
+        ]);
+
+        let rolling = vwap(&bars, 3);
+        assert_eq!(rolling[0], None);
+        assert_eq!(rolling[1], None);
+        for v in &rolling[2..] {
+            assert!((v.unwrap() - 50.0).abs() < 1e-10);
+        }
+
+        let anchored = anchored_vwap(&bars, 1);
+        assert_eq!(anchored[0], None);
+        // Anchor bar has zero volume, so nothing has traded yet
+        assert_eq!(anchored[1], None);
+        for v in &anchored[2..] {
+            assert!((v.unwrap() - 50.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn vwap_weights_by_volume_and_skips_zero_volume() {
+        // Typical prices: 10, 20, 30
+        let bars = bars_with_volume(&[
+            (11.0, 9.0, 10.0, 100.0),
+            (21.0, 19.0, 20.0, 0.0),
+            (31.0, 29.0, 30.0, 300.0),
+        ]);
+
+        let rolling = vwap(&bars, 3);
+        // (10*100 + 30*300) / 400 = 25
+        assert!((rolling[2].unwrap() - 25.0).abs() < 1e-10);
+
+        // Window containing only the zero-volume bar has no VWAP
+        let single = vwap(&bars, 1);
+        assert!((single[0].unwrap() - 10.0).abs() < 1e-10);
+        assert_eq!(single[1], None);
+        assert!((single[2].unwrap() - 30.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn anchored_vwap_accumulates_from_anchor() {
+        let bars = bars_with_volume(&[
+            (101.0, 99.0, 100.0, 1000.0),
+            (11.0, 9.0, 10.0, 100.0),
+            (31.0, 29.0, 30.0, 100.0),
+        ]);
+
+        let anchored = anchored_vwap(&bars, 1);
+        assert_eq!(anchored[0], None);
+        assert!((anchored[1].unwrap() - 10.0).abs() < 1e-10);
+        assert!((anchored[2].unwrap() - 20.0).abs() < 1e-10);
+
+        // Anchor past the end yields all None
+        assert!(anchored_vwap(&bars, 10).iter().all(|v| v.is_none()));
+    }
+
+    #[test]
+    fn mfi_matches_hand_computed_example() {
+        // Typical prices: 10, 12, 11
+        let bars = bars_with_volume(&[
+            (11.0, 9.0, 10.0, 100.0),
+            (13.0, 11.0, 12.0, 200.0), // up: +12 * 200 = 2400
+            (12.0, 10.0, 11.0, 300.0), // down: -11 * 300 = 3300
+        ]);
+
+        let values = mfi(&bars, 2);
+        assert_eq!(values[0], None);
+        assert_eq!(values[1], None);
+        // 100 - 100 / (1 + 2400/3300) = 100 * 2400 / 5700
+        let expected = 100.0 * 2400.0 / 5700.0;
+        assert!((values[2].unwrap() - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn mfi_no_negative_flow_is_100() {
+        let bars = bars_with_volume(&[
+            (11.0, 9.0, 10.0, 100.0),
+            (12.0, 10.0, 11.0, 100.0),
+            (13.0, 11.0, 12.0, 100.0),
+            (14.0, 12.0, 13.0, 100.0),
+        ]);
+
+        let values = mfi(&bars, 3);
+        assert_eq!(&values[..3], &[None, None, None]);
+        assert_eq!(values[3], Some(100.0));
+        assert!(mfi(&bars, 4).iter().all(|v| v.is_none()));
+    }
+
+    #[test]
+    fn cmf_positive_on_accumulation() {
+        let bars = bars_with_volume(&[
+            (11.0, 9.0, 10.5, 100.0),  // multiplier 0.5 -> 50
+            (12.0, 10.0, 11.8, 200.0), // multiplier 0.8 -> 160
+            (12.0, 12.0, 12.0, 300.0), // zero range -> 0
+            (13.0, 11.0, 12.5, 100.0), // multiplier 0.5 -> 50
+            (14.0, 12.0, 13.6, 150.0), // multiplier 0.6 -> 90
+            (15.0, 13.0, 14.9, 250.0), // multiplier 0.9 -> 225
+        ]);
+
+        let values = cmf(&bars, 3);
+        assert_eq!(&values[..2], &[None, None]);
+        // (50 + 160 + 0) / 600
+        assert!((values[2].unwrap() - 0.35).abs() < 1e-10);
+        assert!(values[2..]
+            .iter()
+            .all(|v| v.is_some_and(|c| c > 0.0 && c <= 1.0)));
+
+        // Closing at the low every bar is full distribution
+        let bars = bars_with_volume(&[(11.0, 9.0, 9.0, 100.0), (10.0, 8.0, 8.0, 100.0)]);
+        assert_eq!(cmf(&bars, 2)[1], Some(-1.0));
+        assert!(cmf(&bars, 3).iter().all(|v| v.is_none()));
+    }
+
+    #[test]
+    fn obv_accumulates_signed_volume() {
+        let bars = bars_with_volume(&[
+            (10.0, 10.0, 10.0, 500.0),
+            (11.0, 11.0, 11.0, 100.0), // up: +100
+            (11.0, 11.0, 11.0, 300.0), // flat: unchanged
+            (9.0, 9.0, 9.0, 250.0),    // down: -250
+            (12.0, 12.0, 12.0, 50.0),  // up: +50
+        ]);
+
+        assert_eq!(obv(&bars), vec![0.0, 100.0, 100.0, -150.0, -100.0]);
+        assert!(obv(&[]).is_empty());
+    }
+
+    #[test]
+    fn ad_line_rises_when_closing_near_highs() {
+        // Closes in the top fifth of each range on rising volume
+        let bars = bars_with_volume(&[
+            (11.0, 9.0, 10.8, 100.0),  // multiplier 0.8 -> +80
+            (12.0, 10.0, 11.8, 200.0), // +160
+            (12.0, 12.0, 12.0, 500.0), // zero range -> +0
+            (13.0, 11.0, 12.9, 300.0), // multiplier 0.9 -> +270
+            (14.0, 12.0, 13.8, 400.0), // +320
+        ]);
+
+        let ad = ad_line(&bars);
+        let expected = [80.0, 240.0, 240.0, 510.0, 830.0];
+        for (value, expected) in ad.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-9, "{} vs {}", value, expected);
+        }
+        assert!(ad.windows(2).all(|w| w[1] >= w[0]));
+        assert!(ad_line(&[]).is_empty());
+
+        // Closing at the lows distributes
+        let bars = bars_with_volume(&[(11.0, 9.0, 9.0, 100.0), (10.0, 8.0, 8.0, 200.0)]);
+        assert_eq!(ad_line(&bars), vec![-100.0, -300.0]);
+    }
+
+    #[test]
+    fn williams_ad_accumulates_from_true_range_bounds() {
+        let bars = bars_with_volume(&[
+            (11.0, 9.0, 10.0, 100.0),
+            (12.0, 10.5, 11.5, 100.0), // up: 11.5 - min(10.5, 10) = 1.5
+            (12.0, 11.0, 11.5, 100.0), // flat: 0
+            (12.5, 10.0, 10.5, 100.0), // down: 10.5 - max(12.5, 11.5) = -2
+            (13.0, 11.0, 12.5, 100.0), // up: 12.5 - min(11, 10.5) = 2
+        ]);
+
+        assert_eq!(williams_ad(&bars), vec![0.0, 1.5, 1.5, -0.5, 1.5]);
+        assert!(williams_ad(&[]).is_empty());
+    }
+
+    #[test]
+    fn vortex_matches_hand_computed_example() {
+        let bars = bars_from_ohlc(&[
+            (9.0, 10.0, 8.0, 9.0),
+            (9.0, 12.0, 9.0, 11.0),   // VM+ 4, VM- 1, TR 3
+            (11.0, 13.0, 10.0, 12.0), // VM+ 4, VM- 2, TR 3
+            (12.0, 12.0, 9.0, 10.0),  // VM+ 2, VM- 4, TR 3
+        ]);
+        let values = vortex(&bars, 2);
+
+        assert_eq!(values[0], None);
+        assert_eq!(values[1], None);
+        let (plus, minus) = values[2].unwrap();
+        assert!((plus - 8.0 / 6.0).abs() < 1e-12);
+        assert!((minus - 0.5).abs() < 1e-12);
+        let (plus, minus) = values[3].unwrap();
+        assert!((plus - 1.0).abs() < 1e-12);
+        assert!((minus - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn opening_range_daily_resets_each_session() {
+        // Two sessions of four 5-minute bars each
+        let mut bars = Vec::new();
+        for day in [2u32, 3] {
+            for k in 0..4u32 {
+                let ts = chrono::Utc
+                    .with_ymd_and_hms(2024, 1, day, 14, 30 + k * 5, 0)
+                    .unwrap();
+                let base = if day == 2 { 100.0 } else { 200.0 } + k as f64;
+                bars.push(Bar::new(
+                    ts,
+                    base,
+                    base + 1.0,
+                    base - 1.0,
+                    base,
+                    1000.0,
+                    "TEST",
+                    "5m",
+                ));
+            }
+        }
+
+        let orb = opening_range(&bars, 2, OpeningPeriod::Daily);
+
+        assert!(!orb[0].unwrap().is_range_complete);
+        assert!(orb[1].unwrap().is_range_complete);
+        assert_eq!(orb[3].unwrap().range_high, 102.0);
+        assert_eq!(orb[3].unwrap().range_low, 99.0);
+
+        // New session: range restarts from the first bar of the day
+        let day2_start = orb[4].unwrap();
+        assert_eq!(day2_start.bars_in_range, 1);
+        assert!(!day2_start.is_range_complete);
+        assert_eq!(orb[5].unwrap().range_high, 202.0);
+        assert_eq!(orb[5].unwrap().range_low, 199.0);
+
+        assert!(is_session_start(&bars, 0));
+        assert!(!is_session_start(&bars, 3));
+        assert!(is_session_start(&bars, 4));
+
+        // First session has no known close; day 2 learns 14:45 from day 1
+        let pre_close = pre_session_close(&bars);
+        assert!(!pre_close[2]);
+        assert!(!pre_close[4]);
+        assert!(!pre_close[5]);
+        assert!(pre_close[6]);
+        assert!(pre_close[7]);
+    }
 }
@@ -0,0 +1,263 @@
+//! Incremental indicator state for bar-by-bar updates.
+//!
+//! Each struct holds just enough state to produce the next value in O(1),
+//! so live feeds and the TUI can update indicators without recomputing the
+//! whole history. Values match the batch functions in the parent module
+//! bar-for-bar, including their warmup periods.
+
+use std::collections::VecDeque;
+
+use crate::bar::Bar;
+
+/// Streaming exponential moving average, matching [`super::ema_close`].
+///
+/// The first `window` values seed the EMA with their SMA; after that each
+/// update applies `EMA = x * k + EMA_prev * (1 - k)` with `k = 2 / (window + 1)`.
+#[derive(Debug, Clone)]
+pub struct EmaState {
+    window: usize,
+    k: f64,
+    count: usize,
+    seed_sum: f64,
+    value: f64,
+}
+
+impl EmaState {
+    pub fn new(window: usize) -> Self {
+        Self {
+            window,
+            k: 2.0 / (window as f64 + 1.0),
+            count: 0,
+            seed_sum: 0.0,
+            value: 0.0,
+        }
+    }
+
+    /// Feed the next close and return the current EMA.
+    ///
+    /// During warmup this is the running mean of the closes seen so far; use
+    /// [`EmaState::is_ready`] to tell when it matches the batch output.
+    pub fn update(&mut self, close: f64) -> f64 {
+        self.count += 1;
+        if self.count <= self.window {
+            self.seed_sum += close;
+            self.value = self.seed_sum / self.count as f64;
+        } else {
+            self.value = close * self.k + self.value * (1.0 - self.k);
+        }
+        self.value
+    }
+
+    /// True once `window` closes have been seen.
+    pub fn is_ready(&self) -> bool {
+        self.window > 0 && self.count >= self.window
+    }
+
+    /// Current EMA, or `None` during warmup.
+    pub fn value(&self) -> Option<f64> {
+        self.is_ready().then_some(self.value)
+    }
+}
+
+/// Streaming true range, matching [`super::true_range`].
+#[derive(Debug, Clone, Default)]
+pub struct TrueRangeState {
+    prev_close: Option<f64>,
+}
+
+impl TrueRangeState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next bar and return its true range.
+    pub fn update(&mut self, bar: &Bar) -> f64 {
+        let (h, l) = (bar.high, bar.low);
+        let tr = match self.prev_close {
+            Some(prev_c) => (h - l).max((h - prev_c).abs()).max((l - prev_c).abs()),
+            None => h - l,
+        };
+        self.prev_close = Some(bar.close);
+        tr
+    }
+}
+
+/// Smoothing applied to true range by [`AtrState`].
+#[derive(Debug, Clone)]
+enum AtrSmoothing {
+    /// Rolling simple average over the last `window` TRs
+    Sma { recent: VecDeque<f64>, sum: f64 },
+    /// Wilder smoothing seeded with the SMA of the first `window` TRs
+    Wilder {
+        count: usize,
+        seed_sum: f64,
+        value: f64,
+    },
+}
+
+/// Streaming Average True Range.
+///
+/// [`AtrState::new`] matches [`super::atr`] (SMA of TR) and
+/// [`AtrState::wilder`] matches [`super::atr_wilder`].
+#[derive(Debug, Clone)]
+pub struct AtrState {
+    window: usize,
+    tr: TrueRangeState,
+    smoothing: AtrSmoothing,
+}
+
+impl AtrState {
+    /// ATR as a simple moving average of true range.
+    pub fn new(window: usize) -> Self {
+        Self {
+            window,
+            tr: TrueRangeState::new(),
+            smoothing: AtrSmoothing::Sma {
+                recent: VecDeque::with_capacity(window + 1),
+                sum: 0.0,
+            },
+        }
+    }
+
+    /// ATR with Wilder smoothing (alpha = 1 / window).
+    pub fn wilder(window: usize) -> Self {
+        Self {
+            window,
+            tr: TrueRangeState::new(),
+            smoothing: AtrSmoothing::Wilder {
+                count: 0,
+                seed_sum: 0.0,
+                value: 0.0,
+            },
+        }
+    }
+
+    /// Feed the next bar and return the ATR, or `None` during warmup.
+    pub fn update(&mut self, bar: &Bar) -> Option<f64> {
+        let tr = self.tr.update(bar);
+        if self.window == 0 {
+            return None;
+        }
+
+        match &mut self.smoothing {
+            AtrSmoothing::Sma { recent, sum } => {
+                *sum += tr;
+                recent.push_back(tr);
+                if recent.len() > self.window {
+                    *sum -= recent.pop_front().unwrap_or(0.0);
+                }
+                (recent.len() == self.window).then(|| *sum / self.window as f64)
+            }
+            AtrSmoothing::Wilder {
+                count,
+                seed_sum,
+                value,
+            } => {
+                *count += 1;
+                if *count < self.window {
+                    *seed_sum += tr;
+                    None
+                } else if *count == self.window {
+                    *seed_sum += tr;
+                    *value = *seed_sum / self.window as f64;
+                    Some(*value)
+                } else {
+                    let alpha = 1.0 / self.window as f64;
+                    *value = *value * (1.0 - alpha) + tr * alpha;
+                    Some(*value)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{atr, atr_wilder, ema_close, true_range};
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn fixture_bars(n: usize) -> Vec<Bar> {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        (0..n)
+            .map(|i| {
+                let x = i as f64;
+                let close = 100.0 + x * 0.3 + 4.0 * (x / 7.0).sin();
+                let open = close - (x / 3.0).cos();
+                let high = open.max(close) + 1.0 + (x / 5.0).sin().abs();
+                let low = open.min(close) - 1.0 - (x / 4.0).cos().abs();
+                Bar::new(
+                    start + Duration::days(i as i64),
+                    open,
+                    high,
+                    low,
+                    close,
+                    1000.0,
+                    "TEST",
+                    "1d",
+                )
+            })
+            .collect()
+    }
+
+    fn assert_close(a: Option<f64>, b: Option<f64>, i: usize) {
+        match (a, b) {
+            (Some(a), Some(b)) => assert!((a - b).abs() < 1e-9, "bar {}: {} vs {}", i, a, b),
+            (None, None) => {}
+            _ => panic!("bar {}: {:?} vs {:?}", i, a, b),
+        }
+    }
+
+    #[test]
+    fn streaming_ema_matches_batch() {
+        let bars = fixture_bars(120);
+        for window in [1, 5, 20] {
+            let batch = ema_close(&bars, window);
+            let mut state = EmaState::new(window);
+            for (i, bar) in bars.iter().enumerate() {
+                state.update(bar.close);
+                assert_close(state.value(), batch[i], i);
+            }
+        }
+    }
+
+    #[test]
+    fn streaming_true_range_matches_batch() {
+        let bars = fixture_bars(50);
+        let batch = true_range(&bars);
+        let mut state = TrueRangeState::new();
+        for (i, bar) in bars.iter().enumerate() {
+            assert_close(Some(state.update(bar)), Some(batch[i]), i);
+        }
+    }
+
+    #[test]
+    fn streaming_atr_matches_batch() {
+        let bars = fixture_bars(120);
+        for window in [1, 14, 20] {
+            let batch = atr(&bars, window);
+            let mut state = AtrState::new(window);
+            for (i, bar) in bars.iter().enumerate() {
+                assert_close(state.update(bar), batch[i], i);
+            }
+
+            let batch = atr_wilder(&bars, window);
+            let mut state = AtrState::wilder(window);
+            for (i, bar) in bars.iter().enumerate() {
+                assert_close(state.update(bar), batch[i], i);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_window_never_ready() {
+        let bars = fixture_bars(5);
+        let mut ema = EmaState::new(0);
+        let mut atr = AtrState::new(0);
+        for bar in &bars {
+            ema.update(bar.close);
+            assert!(ema.value().is_none());
+            assert!(atr.update(bar).is_none());
+        }
+    }
+}
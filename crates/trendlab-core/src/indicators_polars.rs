@@ -5,7 +5,14 @@
 //!
 //! Key invariant: indicator values at index `t` must depend only on bars `0..=t`.
 
+use crate::bar::Bar;
+use crate::data::bars_to_dataframe;
+use crate::indicators::{
+    ema_series, fisher_from_prices, kama_from_closes, stoch_rsi_raw_from_closes, trix_from_closes,
+    MAType, KAMA_DEFAULT_FAST, KAMA_DEFAULT_SLOW,
+};
 use polars::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Donchian channel as Polars expressions.
 ///
@@ -85,6 +92,73 @@ pub fn ema_close_expr_aliased(window: usize, alias: &str) -> Expr {
     ema_close_expr(window).alias(alias)
 }
 
+/// Linearly weighted moving average of an expression (newest weight = `window`).
+///
+/// Polars rejects weighted windows over nulls, so the input must be null-free.
+fn wma_expr(expr: Expr, window: usize) -> Expr {
+    let denom = (window * (window + 1)) as f64 / 2.0;
+    expr.rolling_sum(RollingOptionsFixedWindow {
+        window_size: window,
+        min_periods: window,
+        weights: Some((1..=window).map(|w| w as f64).collect()),
+        center: false,
+        fn_params: None,
+    }) / lit(denom)
+}
+
+/// Hull moving average of close price as a Polars expression.
+///
+/// HMA = WMA(2 × WMA(close, period / 2) − WMA(close, period), round(√period)).
+/// Matches `indicators::hma_close`: null for the first
+/// `period + round(√period) - 2` rows.
+pub fn hma_close_expr(window: usize) -> Expr {
+    let half = (window / 2).max(1);
+    let sqrt_window = ((window as f64).sqrt().round() as usize).max(1);
+    let warmup = window + sqrt_window - 2;
+
+    // The raw series is null during the full-period warmup; zero-fill it so the
+    // final weighted window can run, then mask the warmup rows back to null.
+    let raw = (lit(2.0) * wma_expr(col("close"), half) - wma_expr(col("close"), window))
+        .fill_null(lit(0.0));
+
+    when(col("close").shift(lit(warmup as i64)).is_null())
+        .then(lit(NULL).cast(DataType::Float64))
+        .otherwise(wma_expr(raw, sqrt_window))
+}
+
+/// Kaufman adaptive moving average of close price as a Polars expression.
+///
+/// KAMA's smoothing constant changes every bar, so the recursion cannot be
+/// expressed with `ewm_mean`; the close column is mapped through
+/// `indicators::kama` logic instead. Null for the first `er_period` rows.
+pub fn kama_close_expr(er_period: usize, fast: usize, slow: usize) -> Expr {
+    col("close").map(
+        move |c: Column| {
+            let closes: Vec<f64> = c
+                .f64()?
+                .into_iter()
+                .map(|v| v.unwrap_or(f64::NAN))
+                .collect();
+            let values = kama_from_closes(&closes, er_period, fast, slow);
+            Ok(Some(Series::new(c.name().clone(), values).into_column()))
+        },
+        GetOutput::from_type(DataType::Float64),
+    )
+}
+
+/// Moving average of close for the given `MAType`.
+///
+/// Dispatches to `sma_close_expr`, `ema_close_expr`, `hma_close_expr`, or
+/// `kama_close_expr` (with the default 2/30 fast/slow constants).
+pub fn ma_close_expr(ma_type: MAType, window: usize) -> Expr {
+    match ma_type {
+        MAType::SMA => sma_close_expr(window),
+        MAType::EMA => ema_close_expr(window),
+        MAType::Hull => hma_close_expr(window),
+        MAType::Kama => kama_close_expr(window, KAMA_DEFAULT_FAST, KAMA_DEFAULT_SLOW),
+    }
+}
+
 /// True Range as a Polars expression.
 ///
 /// True Range is defined as the maximum of:
@@ -494,6 +568,41 @@ pub fn apply_aroon_exprs(lf: LazyFrame, period: usize) -> LazyFrame {
     lf.with_column(aroon_oscillator_expr())
 }
 
+/// Vortex Indicator expressions.
+///
+/// Returns (vi_plus, vi_minus) expressions.
+/// VM+ = |high - prev_low|, VM- = |low - prev_high|
+/// VI± = rolling_sum(VM±, period) / rolling_sum(TR, period)
+///
+/// The first bar has no previous bar, so the first `period` rows are null.
+pub fn vortex_exprs(period: usize) -> (Expr, Expr) {
+    let opts = RollingOptionsFixedWindow {
+        window_size: period,
+        min_periods: period,
+        ..Default::default()
+    };
+    let vm_plus = (col("high") - col("low").shift(lit(1))).abs();
+    let vm_minus = (col("low") - col("high").shift(lit(1))).abs();
+    let tr_sum = true_range_expr().rolling_sum(opts.clone());
+
+    let vi = |vm: Expr, name: &str| {
+        when(tr_sum.clone().gt(lit(0.0)))
+            .then(vm.rolling_sum(opts.clone()) / tr_sum.clone())
+            .otherwise(lit(NULL).cast(DataType::Float64))
+            .alias(name)
+    };
+
+    (vi(vm_plus, "vi_plus"), vi(vm_minus, "vi_minus"))
+}
+
+/// Apply Vortex Indicator to a LazyFrame.
+///
+/// Adds columns: vi_plus, vi_minus
+pub fn apply_vortex_exprs(lf: LazyFrame, period: usize) -> LazyFrame {
+    let (vi_plus, vi_minus) = vortex_exprs(period);
+    lf.with_columns([vi_plus, vi_minus])
+}
+
 /// Apply Bollinger Bands to a LazyFrame.
 ///
 /// Adds columns: bb_middle, bb_upper, bb_lower, bb_bandwidth
@@ -725,8 +834,8 @@ use crate::indicators::OpeningPeriod;
 /// - range_low = min(low) over the prior N bars (excluding current bar)
 /// - is_range_complete = always true after warmup
 ///
-/// For **Weekly/Monthly** periods:
-/// - Detects period boundaries based on ISO week/month
+/// For **Weekly/Monthly/Daily** periods:
+/// - Detects period boundaries based on ISO week/month/calendar day
 /// - Computes the first N bars of each period
 /// - range_high/low are the max/min of those first N bars
 /// - is_range_complete becomes true after N bars in each period
@@ -741,6 +850,7 @@ pub fn apply_opening_range_exprs(
         OpeningPeriod::Rolling => apply_opening_range_rolling(lf, range_bars),
         OpeningPeriod::Weekly => apply_opening_range_calendar(lf, range_bars, "week"),
         OpeningPeriod::Monthly => apply_opening_range_calendar(lf, range_bars, "month"),
+        OpeningPeriod::Daily => apply_opening_range_calendar(lf, range_bars, "day"),
     }
 }
 
@@ -782,10 +892,10 @@ fn apply_opening_range_rolling(lf: LazyFrame, range_bars: usize) -> LazyFrame {
         .with_columns([is_complete, bars_in_range])
 }
 
-/// Calendar-based opening range (Weekly or Monthly).
+/// Calendar-based opening range (Weekly, Monthly, or Daily).
 ///
 /// This uses a simplified approach:
-/// 1. Extract period key (week+year or month+year)
+/// 1. Extract period key (week+year, month+year, or day-of-year+year)
 /// 2. Detect period changes
 /// 3. Compute cumulative bar count within each period
 /// 4. Compute running max/min within each period's first N bars
@@ -800,6 +910,10 @@ fn apply_opening_range_calendar(lf: LazyFrame, range_bars: usize, period_type: &
             // Create a unique key for each month: year * 100 + month
             (col("ts").dt().year() * lit(100) + col("ts").dt().month()).alias("_period_key")
         }
+        "day" => {
+            // Create a unique key for each session: year * 1000 + day of year
+            (col("ts").dt().year() * lit(1000) + col("ts").dt().ordinal_day()).alias("_period_key")
+        }
         _ => panic!("Invalid period type"),
     };
 
@@ -823,8 +937,9 @@ fn apply_opening_range_calendar(lf: LazyFrame, range_bars: usize, period_type: &
 
     // Compute bar index within each period using over() expression
     // Note: bars_in_period is 1-based (first bar of period = 1)
-    let bar_in_period = lit(1)
-        .cum_sum(false)
+    let bar_in_period = col("_period_idx")
+        .cum_count(false)
+        .cast(DataType::Int32)
         .over([col("_period_idx")])
         .alias("orb_bars_in_range");
 
@@ -878,6 +993,36 @@ fn apply_opening_range_calendar(lf: LazyFrame, range_bars: usize, period_type: &
     ])])
 }
 
+/// Flag bars whose next bar is expected to be the last of the session.
+///
+/// Polars counterpart of [`crate::indicators::pre_session_close`]: the
+/// closing time-of-day comes from the previous session's last bar and the
+/// bar spacing from the gap to the previous bar.
+pub fn pre_session_close_expr() -> Expr {
+    const DAY_MS: i64 = 86_400_000;
+
+    let ts = col("ts").cast(DataType::Int64);
+    let time_of_day = ts.clone() % lit(DAY_MS);
+    let day = ts.clone() - time_of_day.clone();
+    let session_start = day.clone().neq(day.clone().shift(lit(1)));
+
+    // Previous session's closing time, carried forward through the session
+    let close_time = when(session_start.clone())
+        .then(time_of_day.shift(lit(1)))
+        .otherwise(lit(NULL).cast(DataType::Int64))
+        .fill_null_with_strategy(FillNullStrategy::Forward(None));
+
+    let next_ts = ts.clone() + (ts.clone() - ts.shift(lit(1)));
+    let next_time_of_day = next_ts.clone() % lit(DAY_MS);
+    let next_day = next_ts - next_time_of_day.clone();
+
+    session_start
+        .not()
+        .and(next_day.eq(day))
+        .and(next_time_of_day.gt_eq(close_time))
+        .fill_null(lit(false))
+}
+
 // =============================================================================
 // Parabolic SAR Expressions
 // =============================================================================
@@ -1080,6 +1225,65 @@ pub fn apply_stochastic_exprs(
         .with_column(stochastic_d_expr(d_period))
 }
 
+/// Stochastic RSI %K raw expression.
+///
+/// %K raw = (RSI - Lowest RSI) / (Highest RSI - Lowest RSI) * 100, with 50 when
+/// the RSI is flat. Uses the same Wilder-seeded RSI as the sequential
+/// `stoch_rsi`, so the values match it exactly.
+pub fn stoch_rsi_k_raw_expr(rsi_period: usize, stoch_period: usize) -> Expr {
+    col("close")
+        .map(
+            move |c: Column| {
+                let closes: Vec<f64> = c.f64()?.into_no_null_iter().collect();
+                let values = stoch_rsi_raw_from_closes(&closes, rsi_period, stoch_period);
+                Ok(Some(Series::new(c.name().clone(), values).into_column()))
+            },
+            GetOutput::from_type(DataType::Float64),
+        )
+        .alias("stoch_rsi_k_raw")
+}
+
+/// Stochastic RSI %K smoothed expression (SMA of raw %K).
+///
+/// Note: Requires the stoch_rsi_k_raw column to be computed first.
+pub fn stoch_rsi_k_smooth_expr(k_smooth: usize) -> Expr {
+    col("stoch_rsi_k_raw")
+        .rolling_mean(RollingOptionsFixedWindow {
+            window_size: k_smooth,
+            min_periods: k_smooth,
+            ..Default::default()
+        })
+        .alias("stoch_rsi_k")
+}
+
+/// Stochastic RSI %D expression (SMA of smoothed %K).
+///
+/// Note: Requires the stoch_rsi_k column to be computed first.
+pub fn stoch_rsi_d_expr(d_period: usize) -> Expr {
+    col("stoch_rsi_k")
+        .rolling_mean(RollingOptionsFixedWindow {
+            window_size: d_period,
+            min_periods: d_period,
+            ..Default::default()
+        })
+        .alias("stoch_rsi_d")
+}
+
+/// Apply Stochastic RSI expressions to a LazyFrame.
+///
+/// Adds columns: stoch_rsi_k_raw, stoch_rsi_k, stoch_rsi_d
+pub fn apply_stoch_rsi_exprs(
+    lf: LazyFrame,
+    rsi_period: usize,
+    stoch_period: usize,
+    k_smooth: usize,
+    d_period: usize,
+) -> LazyFrame {
+    lf.with_column(stoch_rsi_k_raw_expr(rsi_period, stoch_period))
+        .with_column(stoch_rsi_k_smooth_expr(k_smooth))
+        .with_column(stoch_rsi_d_expr(d_period))
+}
+
 /// Williams %R expression.
 ///
 /// %R = (Highest High - Close) / (Highest High - Lowest Low) * -100
@@ -1160,6 +1364,188 @@ pub fn apply_roc_exprs(lf: LazyFrame, period: usize) -> LazyFrame {
     lf.with_column(roc_expr(period))
 }
 
+/// Coppock Curve expression.
+///
+/// WMA of ROC(`roc1`) + ROC(`roc2`). Matches `indicators::coppock`: null for
+/// the first `max(roc1, roc2) + wma_period - 1` rows.
+pub fn coppock_expr(roc1: usize, roc2: usize, wma_period: usize) -> Expr {
+    let warmup = roc1.max(roc2) + wma_period - 1;
+    let roc = |period: usize| {
+        let prev_close = col("close").shift(lit(period as i64));
+        (col("close") - prev_close.clone()) / prev_close * lit(100.0)
+    };
+
+    // Zero-fill the ROC warmup so the weighted window can run, then mask the
+    // warmup rows back to null (same approach as `hma_close_expr`)
+    let summed = (roc(roc1) + roc(roc2)).fill_null(lit(0.0));
+
+    when(col("close").shift(lit(warmup as i64)).is_null())
+        .then(lit(NULL).cast(DataType::Float64))
+        .otherwise(wma_expr(summed, wma_period))
+        .alias("coppock")
+}
+
+/// Apply Coppock Curve expression to a LazyFrame.
+pub fn apply_coppock_exprs(
+    lf: LazyFrame,
+    roc1: usize,
+    roc2: usize,
+    wma_period: usize,
+) -> LazyFrame {
+    lf.with_column(coppock_expr(roc1, roc2, wma_period))
+}
+
+/// Elder-Ray EMA expression (SMA-seeded EMA of close, aliased `elder_ema`).
+///
+/// Seeded like the sequential `elder_ray`, so the values match it exactly; the
+/// first `ema_period - 1` rows are null.
+pub fn elder_ray_ema_expr(ema_period: usize) -> Expr {
+    col("close")
+        .map(
+            move |c: Column| {
+                let closes: Vec<Option<f64>> = c.f64()?.into_iter().collect();
+                let values = ema_series(&closes, ema_period);
+                Ok(Some(Series::new(c.name().clone(), values).into_column()))
+            },
+            GetOutput::from_type(DataType::Float64),
+        )
+        .alias("elder_ema")
+}
+
+/// Elder-Ray Bull and Bear Power expressions.
+///
+/// Returns `(bull_power, bear_power)`: high and low minus the EMA.
+///
+/// Note: Requires the elder_ema column to be computed first.
+pub fn elder_ray_exprs() -> (Expr, Expr) {
+    let bull = (col("high") - col("elder_ema")).alias("bull_power");
+    let bear = (col("low") - col("elder_ema")).alias("bear_power");
+    (bull, bear)
+}
+
+/// Apply Elder-Ray expressions to a LazyFrame.
+///
+/// Adds columns: elder_ema, bull_power, bear_power
+pub fn apply_elder_ray_exprs(lf: LazyFrame, ema_period: usize) -> LazyFrame {
+    let (bull, bear) = elder_ray_exprs();
+    lf.with_column(elder_ray_ema_expr(ema_period))
+        .with_columns([bull, bear])
+}
+
+/// CMO (Chande Momentum Oscillator) expression.
+///
+/// CMO = (sum_up - sum_down) / (sum_up + sum_down) * 100 over the last
+/// `period` close-to-close changes. Windows with no price change are 0.
+pub fn cmo_expr(period: usize) -> Expr {
+    let opts = RollingOptionsFixedWindow {
+        window_size: period,
+        min_periods: period,
+        ..Default::default()
+    };
+    let change = col("close") - col("close").shift(lit(1));
+    // The first row's change is null, so mask both legs to keep it out of the window
+    let leg = |cond: Expr, value: Expr| {
+        when(change.clone().is_null())
+            .then(lit(NULL).cast(DataType::Float64))
+            .otherwise(when(cond).then(value).otherwise(lit(0.0)))
+    };
+    let up = leg(change.clone().gt(lit(0.0)), change.clone());
+    let down = leg(change.clone().lt(lit(0.0)), lit(0.0) - change.clone());
+
+    let sum_up = up.rolling_sum(opts.clone());
+    let sum_down = down.rolling_sum(opts);
+    let total = sum_up.clone() + sum_down.clone();
+
+    when(total.clone().eq(lit(0.0)))
+        .then(lit(0.0))
+        .otherwise((sum_up - sum_down) / total * lit(100.0))
+        .alias("cmo")
+}
+
+/// Apply CMO expressions to a LazyFrame.
+pub fn apply_cmo_exprs(lf: LazyFrame, period: usize) -> LazyFrame {
+    lf.with_column(cmo_expr(period))
+}
+
+/// Fisher Transform expressions.
+///
+/// Returns (fisher, fisher_trigger) expressions. The median price (H+L)/2 is
+/// normalized to [-1, 1] over `period` bars, clamped, and passed through
+/// atanh; the trigger is the fisher line shifted by one bar.
+pub fn fisher_transform_exprs(period: usize) -> (Expr, Expr) {
+    let fisher = ((col("high") + col("low")) / lit(2.0)).map(
+        move |c: Column| {
+            let prices: Vec<f64> = c
+                .f64()?
+                .into_iter()
+                .map(|v| v.unwrap_or(f64::NAN))
+                .collect();
+            let values = fisher_from_prices(&prices, period);
+            Ok(Some(Series::new(c.name().clone(), values).into_column()))
+        },
+        GetOutput::from_type(DataType::Float64),
+    );
+    let trigger = fisher.clone().shift(lit(1)).alias("fisher_trigger");
+
+    (fisher.alias("fisher"), trigger)
+}
+
+/// Apply Fisher Transform expressions to a LazyFrame.
+///
+/// Adds columns: fisher, fisher_trigger
+pub fn apply_fisher_exprs(lf: LazyFrame, period: usize) -> LazyFrame {
+    let (fisher, trigger) = fisher_transform_exprs(period);
+    lf.with_columns([fisher, trigger])
+}
+
+/// TRIX expression (rate of change of a triple-smoothed EMA of close).
+///
+/// Each EMA is SMA-seeded like the sequential `trix`, so the values match it
+/// exactly; the first `3 * period - 2` rows are null.
+pub fn trix_expr(period: usize) -> Expr {
+    col("close")
+        .map(
+            move |c: Column| {
+                let closes: Vec<f64> = c
+                    .f64()?
+                    .into_iter()
+                    .map(|v| v.unwrap_or(f64::NAN))
+                    .collect();
+                let values = trix_from_closes(&closes, period);
+                Ok(Some(Series::new(c.name().clone(), values).into_column()))
+            },
+            GetOutput::from_type(DataType::Float64),
+        )
+        .alias("trix")
+}
+
+/// TRIX signal line: SMA-seeded EMA of the `trix` column.
+///
+/// Note: Requires the trix column to be computed first.
+pub fn trix_signal_expr(signal_period: usize) -> Expr {
+    col("trix")
+        .map(
+            move |c: Column| {
+                let values: Vec<Option<f64>> = c.f64()?.into_iter().collect();
+                let signal = ema_series(&values, signal_period);
+                Ok(Some(Series::new(c.name().clone(), signal).into_column()))
+            },
+            GetOutput::from_type(DataType::Float64),
+        )
+        .alias("trix_signal")
+}
+
+/// Apply TRIX expressions to a LazyFrame.
+///
+/// Adds columns: trix, and trix_signal when `signal_period` is set
+pub fn apply_trix_exprs(lf: LazyFrame, period: usize, signal_period: Option<usize>) -> LazyFrame {
+    let lf = lf.with_column(trix_expr(period));
+    match signal_period {
+        Some(signal_period) => lf.with_column(trix_signal_expr(signal_period)),
+        None => lf,
+    }
+}
+
 /// Ichimoku Tenkan-sen (Conversion Line) expression.
 ///
 /// Tenkan-sen = (Highest High + Lowest Low) / 2 over tenkan_period
@@ -1253,8 +1639,172 @@ pub fn apply_ichimoku_exprs(
         )
 }
 
+// =============================================================================
+// Phase 6: Volume Indicators
+// =============================================================================
+
+/// Rolling VWAP (volume-weighted average price) expression.
+///
+/// VWAP = Σ(typical_price × volume) / Σ(volume) over the last `window` bars.
+/// Zero-volume bars contribute nothing; windows with zero total volume are null.
+pub fn vwap_expr(window: usize) -> Expr {
+    let opts = RollingOptionsFixedWindow {
+        window_size: window,
+        min_periods: window,
+        ..Default::default()
+    };
+    let tp = (col("high") + col("low") + col("close")) / lit(3.0);
+    let pv_sum = (tp * col("volume")).rolling_sum(opts.clone());
+    let vol_sum = col("volume").rolling_sum(opts);
+
+    when(vol_sum.clone().gt(lit(0.0)))
+        .then(pv_sum / vol_sum)
+        .otherwise(lit(NULL).cast(DataType::Float64))
+        .alias("vwap")
+}
+
+/// Apply VWAP expression to a LazyFrame.
+pub fn apply_vwap_exprs(lf: LazyFrame, window: usize) -> LazyFrame {
+    lf.with_column(vwap_expr(window))
+}
+
+/// Money Flow Index expression.
+///
+/// Typical-price money flow summed over `period` bars, split by whether the
+/// typical price rose or fell versus the previous bar. MFI is 100 when the
+/// window has no negative flow; the first `period` rows are null.
+pub fn mfi_expr(period: usize) -> Expr {
+    let opts = RollingOptionsFixedWindow {
+        window_size: period,
+        min_periods: period,
+        ..Default::default()
+    };
+    let tp = (col("high") + col("low") + col("close")) / lit(3.0);
+    let prev_tp = tp.clone().shift(lit(1));
+    let flow = tp.clone() * col("volume");
+
+    // First row has no previous typical price, so its flows are null
+    let pos_flow = when(prev_tp.clone().is_null())
+        .then(lit(NULL).cast(DataType::Float64))
+        .when(tp.clone().gt(prev_tp.clone()))
+        .then(flow.clone())
+        .otherwise(lit(0.0));
+    let neg_flow = when(prev_tp.clone().is_null())
+        .then(lit(NULL).cast(DataType::Float64))
+        .when(tp.lt(prev_tp))
+        .then(flow)
+        .otherwise(lit(0.0));
+
+    let pos_sum = pos_flow.rolling_sum(opts.clone());
+    let neg_sum = neg_flow.rolling_sum(opts);
+
+    when(neg_sum.clone().eq(lit(0.0)))
+        .then(lit(100.0))
+        .otherwise(lit(100.0) - lit(100.0) / (lit(1.0) + pos_sum / neg_sum))
+        .alias("mfi")
+}
+
+/// Apply MFI expression to a LazyFrame.
+pub fn apply_mfi_exprs(lf: LazyFrame, period: usize) -> LazyFrame {
+    lf.with_column(mfi_expr(period))
+}
+
+/// Chaikin Money Flow expression.
+///
+/// Money flow multiplier × volume summed over `period` bars, divided by the
+/// volume sum. Zero-range bars contribute a zero multiplier; windows with no
+/// volume are null, as are the first `period - 1` rows.
+pub fn cmf_expr(period: usize) -> Expr {
+    let opts = RollingOptionsFixedWindow {
+        window_size: period,
+        min_periods: period,
+        ..Default::default()
+    };
+    let range = col("high") - col("low");
+    let multiplier = when(range.clone().gt(lit(0.0)))
+        .then(((col("close") - col("low")) - (col("high") - col("close"))) / range)
+        .otherwise(lit(0.0));
+
+    let flow_sum = (multiplier * col("volume")).rolling_sum(opts.clone());
+    let vol_sum = col("volume").rolling_sum(opts);
+
+    when(vol_sum.clone().gt(lit(0.0)))
+        .then(flow_sum / vol_sum)
+        .otherwise(lit(NULL).cast(DataType::Float64))
+        .alias("cmf")
+}
+
+/// Apply CMF expression to a LazyFrame.
+pub fn apply_cmf_exprs(lf: LazyFrame, period: usize) -> LazyFrame {
+    lf.with_column(cmf_expr(period))
+}
+
+/// On-Balance Volume expression.
+///
+/// Cumulative sum of volume signed by the close-to-close direction: up closes
+/// add volume, down closes subtract it, flat closes (and the first bar) add 0.
+pub fn obv_expr() -> Expr {
+    let prev_close = col("close").shift(lit(1));
+    when(col("close").gt(prev_close.clone()))
+        .then(col("volume"))
+        .when(col("close").lt(prev_close))
+        .then(lit(0.0) - col("volume"))
+        .otherwise(lit(0.0))
+        .cum_sum(false)
+        .alias("obv")
+}
+
+/// Apply OBV expression to a LazyFrame.
+pub fn apply_obv_exprs(lf: LazyFrame) -> LazyFrame {
+    lf.with_column(obv_expr())
+}
+
+/// Accumulation/Distribution line expression.
+///
+/// Cumulative sum of the Chaikin money flow multiplier × volume. Zero-range
+/// bars contribute 0.
+pub fn ad_line_expr() -> Expr {
+    let range = col("high") - col("low");
+    let multiplier = when(range.clone().gt(lit(0.0)))
+        .then(((col("close") - col("low")) - (col("high") - col("close"))) / range)
+        .otherwise(lit(0.0));
+
+    (multiplier * col("volume")).cum_sum(false).alias("ad_line")
+}
+
+/// Williams Accumulation/Distribution expression.
+///
+/// Cumulative sum of close minus the true low (up closes) or true high (down
+/// closes), measured against the previous close. Flat closes and the first
+/// bar add 0.
+pub fn williams_ad_expr() -> Expr {
+    let prev_close = col("close").shift(lit(1));
+    let true_low = when(col("low").lt(prev_close.clone()))
+        .then(col("low"))
+        .otherwise(prev_close.clone());
+    let true_high = when(col("high").gt(prev_close.clone()))
+        .then(col("high"))
+        .otherwise(prev_close.clone());
+
+    when(col("close").gt(prev_close.clone()))
+        .then(col("close") - true_low)
+        .when(col("close").lt(prev_close))
+        .then(col("close") - true_high)
+        .otherwise(lit(0.0))
+        .cum_sum(false)
+        .alias("williams_ad")
+}
+
+/// Apply A/D line and Williams A/D expressions to a LazyFrame.
+pub fn apply_ad_exprs(lf: LazyFrame) -> LazyFrame {
+    lf.with_columns([ad_line_expr(), williams_ad_expr()])
+}
+
 /// Indicator specification for building indicator sets.
-#[derive(Debug, Clone)]
+///
+/// Serialized with a `type` tag, e.g. `{"type": "Donchian", "lookback": 20}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
 pub enum IndicatorSpec {
     /// Donchian channel with specified lookback
     Donchian { lookback: usize },
@@ -1268,10 +1818,20 @@ pub enum IndicatorSpec {
     ATRWilder { window: usize },
     /// Bollinger Bands with period and multiplier
     Bollinger { period: usize, multiplier: f64 },
+    /// Keltner Channel (EMA center, Wilder ATR bands)
+    Keltner {
+        ema_period: usize,
+        atr_period: usize,
+        multiplier: f64,
+    },
+    /// Supertrend raw bands (before ratcheting)
+    Supertrend { atr_period: usize, multiplier: f64 },
     /// DMI/ADX indicators (full set)
     DMI { period: usize },
     /// Aroon indicators (full set)
     Aroon { period: usize },
+    /// Vortex Indicator (VI+, VI-)
+    Vortex { period: usize },
     /// RSI (Relative Strength Index)
     RSI { period: usize },
     /// MACD (Moving Average Convergence Divergence)
@@ -1286,18 +1846,52 @@ pub enum IndicatorSpec {
         k_smooth: usize,
         d_period: usize,
     },
+    /// Stochastic RSI
+    StochRsi {
+        rsi_period: usize,
+        stoch_period: usize,
+        k_smooth: usize,
+        d_period: usize,
+    },
     /// Williams %R
     WilliamsR { period: usize },
     /// CCI (Commodity Channel Index)
     CCI { period: usize },
     /// ROC (Rate of Change)
     ROC { period: usize },
+    /// CMO (Chande Momentum Oscillator)
+    CMO { period: usize },
+    /// Fisher Transform
+    Fisher { period: usize },
+    /// TRIX (triple-smoothed EMA rate of change), with optional signal line
+    Trix {
+        period: usize,
+        signal_period: Option<usize>,
+    },
     /// Ichimoku Cloud
     Ichimoku {
         tenkan_period: usize,
         kijun_period: usize,
         senkou_b_period: usize,
     },
+    /// Rolling VWAP
+    VWAP { window: usize },
+    /// On-Balance Volume
+    OBV,
+    /// Money Flow Index
+    MFI { period: usize },
+    /// Chaikin Money Flow
+    CMF { period: usize },
+    /// Accumulation/Distribution line and Williams A/D
+    AccumulationDistribution,
+    /// Elder-Ray Bull/Bear Power
+    ElderRay { ema_period: usize },
+    /// Coppock Curve
+    Coppock {
+        roc1: usize,
+        roc2: usize,
+        wma_period: usize,
+    },
 }
 
 /// Collection of indicators to compute together.
@@ -1355,6 +1949,25 @@ impl IndicatorSet {
         self
     }
 
+    /// Add Keltner Channel.
+    pub fn with_keltner(mut self, ema_period: usize, atr_period: usize, multiplier: f64) -> Self {
+        self.indicators.push(IndicatorSpec::Keltner {
+            ema_period,
+            atr_period,
+            multiplier,
+        });
+        self
+    }
+
+    /// Add Supertrend raw bands.
+    pub fn with_supertrend(mut self, atr_period: usize, multiplier: f64) -> Self {
+        self.indicators.push(IndicatorSpec::Supertrend {
+            atr_period,
+            multiplier,
+        });
+        self
+    }
+
     /// Add DMI/ADX indicator set.
     pub fn with_dmi(mut self, period: usize) -> Self {
         self.indicators.push(IndicatorSpec::DMI { period });
@@ -1367,6 +1980,12 @@ impl IndicatorSet {
         self
     }
 
+    /// Add Vortex Indicator.
+    pub fn with_vortex(mut self, period: usize) -> Self {
+        self.indicators.push(IndicatorSpec::Vortex { period });
+        self
+    }
+
     /// Add RSI indicator.
     pub fn with_rsi(mut self, period: usize) -> Self {
         self.indicators.push(IndicatorSpec::RSI { period });
@@ -1398,6 +2017,23 @@ impl IndicatorSet {
         self
     }
 
+    /// Add Stochastic RSI indicator set.
+    pub fn with_stoch_rsi(
+        mut self,
+        rsi_period: usize,
+        stoch_period: usize,
+        k_smooth: usize,
+        d_period: usize,
+    ) -> Self {
+        self.indicators.push(IndicatorSpec::StochRsi {
+            rsi_period,
+            stoch_period,
+            k_smooth,
+            d_period,
+        });
+        self
+    }
+
     /// Add Williams %R indicator.
     pub fn with_williams_r(mut self, period: usize) -> Self {
         self.indicators.push(IndicatorSpec::WilliamsR { period });
@@ -1416,6 +2052,27 @@ impl IndicatorSet {
         self
     }
 
+    /// Add CMO indicator.
+    pub fn with_cmo(mut self, period: usize) -> Self {
+        self.indicators.push(IndicatorSpec::CMO { period });
+        self
+    }
+
+    /// Add Fisher Transform indicator.
+    pub fn with_fisher(mut self, period: usize) -> Self {
+        self.indicators.push(IndicatorSpec::Fisher { period });
+        self
+    }
+
+    /// Add TRIX indicator, with a signal line if `signal_period` is set.
+    pub fn with_trix(mut self, period: usize, signal_period: Option<usize>) -> Self {
+        self.indicators.push(IndicatorSpec::Trix {
+            period,
+            signal_period,
+        });
+        self
+    }
+
     /// Add Ichimoku Cloud indicator set.
     pub fn with_ichimoku(
         mut self,
@@ -1430,6 +2087,53 @@ impl IndicatorSet {
         });
         self
     }
+
+    /// Add rolling VWAP indicator.
+    pub fn with_vwap(mut self, window: usize) -> Self {
+        self.indicators.push(IndicatorSpec::VWAP { window });
+        self
+    }
+
+    /// Add On-Balance Volume indicator.
+    pub fn with_obv(mut self) -> Self {
+        self.indicators.push(IndicatorSpec::OBV);
+        self
+    }
+
+    /// Add Money Flow Index indicator.
+    pub fn with_mfi(mut self, period: usize) -> Self {
+        self.indicators.push(IndicatorSpec::MFI { period });
+        self
+    }
+
+    /// Add Chaikin Money Flow indicator.
+    pub fn with_cmf(mut self, period: usize) -> Self {
+        self.indicators.push(IndicatorSpec::CMF { period });
+        self
+    }
+
+    /// Add Accumulation/Distribution line and Williams A/D indicators.
+    pub fn with_accumulation_distribution(mut self) -> Self {
+        self.indicators
+            .push(IndicatorSpec::AccumulationDistribution);
+        self
+    }
+
+    /// Add Elder-Ray Bull/Bear Power indicators.
+    pub fn with_elder_ray(mut self, ema_period: usize) -> Self {
+        self.indicators.push(IndicatorSpec::ElderRay { ema_period });
+        self
+    }
+
+    /// Add Coppock Curve indicator.
+    pub fn with_coppock(mut self, roc1: usize, roc2: usize, wma_period: usize) -> Self {
+        self.indicators.push(IndicatorSpec::Coppock {
+            roc1,
+            roc2,
+            wma_period,
+        });
+        self
+    }
 }
 
 /// Apply an indicator set to a LazyFrame.
@@ -1480,8 +2184,18 @@ pub fn apply_indicators(lf: LazyFrame, indicator_set: &IndicatorSet) -> LazyFram
             IndicatorSpec::Bollinger { period, multiplier } => {
                 apply_bollinger_exprs(lf, *period, *multiplier)
             }
+            IndicatorSpec::Keltner {
+                ema_period,
+                atr_period,
+                multiplier,
+            } => apply_keltner_exprs(lf, *ema_period, *atr_period, *multiplier),
+            IndicatorSpec::Supertrend {
+                atr_period,
+                multiplier,
+            } => apply_supertrend_exprs(lf, *atr_period, *multiplier),
             IndicatorSpec::DMI { period } => apply_dmi_exprs(lf, *period),
             IndicatorSpec::Aroon { period } => apply_aroon_exprs(lf, *period),
+            IndicatorSpec::Vortex { period } => apply_vortex_exprs(lf, *period),
             IndicatorSpec::RSI { period } => apply_rsi_exprs(lf, *period),
             IndicatorSpec::MACD {
                 fast_period,
@@ -1493,34 +2207,88 @@ pub fn apply_indicators(lf: LazyFrame, indicator_set: &IndicatorSet) -> LazyFram
                 k_smooth,
                 d_period,
             } => apply_stochastic_exprs(lf, *k_period, *k_smooth, *d_period),
+            IndicatorSpec::StochRsi {
+                rsi_period,
+                stoch_period,
+                k_smooth,
+                d_period,
+            } => apply_stoch_rsi_exprs(lf, *rsi_period, *stoch_period, *k_smooth, *d_period),
             IndicatorSpec::WilliamsR { period } => apply_williams_r_exprs(lf, *period),
             IndicatorSpec::CCI { period } => apply_cci_exprs(lf, *period),
             IndicatorSpec::ROC { period } => apply_roc_exprs(lf, *period),
+            IndicatorSpec::CMO { period } => apply_cmo_exprs(lf, *period),
+            IndicatorSpec::Fisher { period } => apply_fisher_exprs(lf, *period),
+            IndicatorSpec::Trix {
+                period,
+                signal_period,
+            } => apply_trix_exprs(lf, *period, *signal_period),
             IndicatorSpec::Ichimoku {
                 tenkan_period,
                 kijun_period,
                 senkou_b_period,
             } => apply_ichimoku_exprs(lf, *tenkan_period, *kijun_period, *senkou_b_period),
+            IndicatorSpec::VWAP { window } => apply_vwap_exprs(lf, *window),
+            IndicatorSpec::OBV => apply_obv_exprs(lf),
+            IndicatorSpec::MFI { period } => apply_mfi_exprs(lf, *period),
+            IndicatorSpec::CMF { period } => apply_cmf_exprs(lf, *period),
+            IndicatorSpec::AccumulationDistribution => apply_ad_exprs(lf),
+            IndicatorSpec::ElderRay { ema_period } => apply_elder_ray_exprs(lf, *ema_period),
+            IndicatorSpec::Coppock {
+                roc1,
+                roc2,
+                wma_period,
+            } => apply_coppock_exprs(lf, *roc1, *roc2, *wma_period),
         };
     }
 
     lf
 }
 
+/// Compute a single indicator over bars and return its columns.
+///
+/// Each entry is `(column, values)` with one value per bar, `None` during
+/// warmup. The `true_range` helper column and boolean flags are left out.
+pub fn indicator_series(
+    bars: &[Bar],
+    spec: &IndicatorSpec,
+) -> PolarsResult<Vec<(String, Vec<Option<f64>>)>> {
+    let df =
+        bars_to_dataframe(bars).map_err(|e| PolarsError::ComputeError(e.to_string().into()))?;
+    let input_columns: Vec<String> = df
+        .get_column_names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+
+    let set = IndicatorSet {
+        indicators: vec![spec.clone()],
+    };
+    let result = apply_indicators(df.lazy(), &set).collect()?;
+
+    result
+        .get_columns()
+        .iter()
+        .filter(|c| {
+            let name = c.name().as_str();
+            c.dtype() == &DataType::Float64
+                && name != "true_range"
+                && !input_columns.iter().any(|input| input == name)
+        })
+        .map(|c| Ok((c.name().to_string(), c.f64()?.into_iter().collect())))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::bar::Bar;
-    use crate::data::bars_to_dataframe;
     use chrono::{TimeZone, Utc};
 
     fn bars_from_ohlc(ohlc: &[(f64, f64, f64, f64)]) -> Vec<Bar> {
         ohlc.iter()
             .enumerate()
             .map(|(i, &(o, h, l, c))| {
-                let ts = Utc
-                    .with_ymd_and_hms(2024, 1, 1 + i as u32, 0, 0, 0)
-                    .unwrap();
+                let ts = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+                    + chrono::Duration::days(i as i64);
                 Bar::new(ts, o, h, l, c, 1000.0, "TEST", "1d")
             })
             .collect()
@@ -1531,9 +2299,8 @@ mod tests {
             .iter()
             .enumerate()
             .map(|(i, &c)| {
-                let ts = Utc
-                    .with_ymd_and_hms(2024, 1, 1 + i as u32, 0, 0, 0)
-                    .unwrap();
+                let ts = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+                    + chrono::Duration::days(i as i64);
                 Bar::new(ts, c, c, c, c, 1000.0, "TEST", "1d")
             })
             .collect()
@@ -1706,4 +2473,497 @@ mod tests {
         assert!(result.column("true_range").is_ok());
         assert!(result.column("atr_wilder").is_ok());
     }
+
+    #[test]
+    fn test_cmo_polars_matches_sequential() {
+        let closes = [100.0, 102.0, 101.0, 101.0, 104.0, 103.0, 107.0, 105.0];
+        let bars = bars_from_closes(&closes);
+
+        let seq = crate::indicators::cmo(&bars, 4);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = df.lazy().with_column(cmo_expr(4)).collect().unwrap();
+        let pol = result.column("cmo").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => assert!(pol.get(i).is_none(), "Expected null at index {}", i),
+                Some(v) => {
+                    let pv = pol.get(i).unwrap();
+                    assert!(
+                        (pv - v).abs() < 1e-8,
+                        "CMO mismatch at {}: {} vs {}",
+                        i,
+                        pv,
+                        v
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_stoch_rsi_polars_matches_sequential() {
+        let closes: Vec<f64> = (0..50)
+            .map(|i| 100.0 + (i as f64 * 0.6).sin() * 6.0 + i as f64 * 0.2)
+            .collect();
+        let bars = bars_from_closes(&closes);
+
+        let seq = crate::indicators::stoch_rsi(&bars, 6, 5, 3, 3);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = apply_stoch_rsi_exprs(df.lazy(), 6, 5, 3, 3)
+            .collect()
+            .unwrap();
+        let k = result.column("stoch_rsi_k").unwrap().f64().unwrap();
+        let d = result.column("stoch_rsi_d").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => assert!(d.get(i).is_none(), "Expected null at index {}", i),
+                Some(v) => {
+                    assert!((k.get(i).unwrap() - v.k_smooth).abs() < 1e-8, "%K at {}", i);
+                    assert!((d.get(i).unwrap() - v.d).abs() < 1e-8, "%D at {}", i);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_trix_polars_matches_sequential() {
+        let closes: Vec<f64> = (0..30)
+            .map(|i| 100.0 + (i as f64 * 0.7).sin() * 5.0 + i as f64 * 0.3)
+            .collect();
+        let bars = bars_from_closes(&closes);
+
+        let seq = crate::indicators::trix_with_signal(&bars, 4, 3);
+        let seq_trix = crate::indicators::trix(&bars, 4);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = apply_trix_exprs(df.lazy(), 4, Some(3)).collect().unwrap();
+        let trix = result.column("trix").unwrap().f64().unwrap();
+        let signal = result.column("trix_signal").unwrap().f64().unwrap();
+
+        for i in 0..bars.len() {
+            assert_eq!(
+                trix.get(i).is_some(),
+                seq_trix[i].is_some(),
+                "trix at {}",
+                i
+            );
+            if let Some(v) = seq_trix[i] {
+                assert!((trix.get(i).unwrap() - v).abs() < 1e-10, "trix at {}", i);
+            }
+            assert_eq!(signal.get(i).is_some(), seq[i].is_some(), "signal at {}", i);
+            if let Some((_, s)) = seq[i] {
+                assert!(
+                    (signal.get(i).unwrap() - s).abs() < 1e-10,
+                    "signal at {}",
+                    i
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fisher_polars_matches_sequential() {
+        let closes = [
+            100.0, 102.0, 101.0, 101.0, 104.0, 103.0, 103.0, 103.0, 107.0, 105.0,
+        ];
+        let bars = bars_from_closes(&closes);
+
+        let seq = crate::indicators::fisher_transform(&bars, 4);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = apply_fisher_exprs(df.lazy(), 4).collect().unwrap();
+        let fisher = result.column("fisher").unwrap().f64().unwrap();
+        let trigger = result.column("fisher_trigger").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => assert!(trigger.get(i).is_none(), "Expected null at index {}", i),
+                Some((f, t)) => {
+                    assert!((fisher.get(i).unwrap() - f).abs() < 1e-8, "fisher at {}", i);
+                    assert!(
+                        (trigger.get(i).unwrap() - t).abs() < 1e-8,
+                        "trigger at {}",
+                        i
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_vwap_polars_matches_sequential() {
+        let ohlc = vec![
+            (100.0, 105.0, 95.0, 102.0),
+            (102.0, 108.0, 100.0, 106.0),
+            (106.0, 112.0, 104.0, 110.0),
+            (110.0, 115.0, 108.0, 113.0),
+            (113.0, 118.0, 111.0, 116.0),
+        ];
+        let mut bars = bars_from_ohlc(&ohlc);
+        for (i, bar) in bars.iter_mut().enumerate() {
+            bar.volume = [500.0, 0.0, 1500.0, 800.0, 1200.0][i];
+        }
+
+        let seq = crate::indicators::vwap(&bars, 3);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = df.lazy().with_column(vwap_expr(3)).collect().unwrap();
+        let pol = result.column("vwap").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => assert!(pol.get(i).is_none(), "Expected null at index {}", i),
+                Some(v) => {
+                    let pv = pol.get(i).unwrap();
+                    assert!(
+                        (pv - v).abs() < 1e-8,
+                        "VWAP mismatch at {}: {} vs {}",
+                        i,
+                        pv,
+                        v
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_obv_polars_matches_sequential() {
+        let ohlc = vec![
+            (100.0, 105.0, 95.0, 102.0),
+            (102.0, 108.0, 100.0, 106.0),
+            (106.0, 112.0, 104.0, 106.0),
+            (106.0, 110.0, 101.0, 103.0),
+            (103.0, 118.0, 102.0, 116.0),
+        ];
+        let mut bars = bars_from_ohlc(&ohlc);
+        for (i, bar) in bars.iter_mut().enumerate() {
+            bar.volume = [500.0, 700.0, 1500.0, 800.0, 1200.0][i];
+        }
+
+        let seq = crate::indicators::obv(&bars);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = df.lazy().with_column(obv_expr()).collect().unwrap();
+        let pol = result.column("obv").unwrap().f64().unwrap();
+
+        for (i, v) in seq.iter().enumerate() {
+            let pv = pol.get(i).unwrap();
+            assert!(
+                (pv - v).abs() < 1e-8,
+                "OBV mismatch at {}: {} vs {}",
+                i,
+                pv,
+                v
+            );
+        }
+    }
+
+    #[test]
+    fn test_ad_polars_matches_sequential() {
+        let ohlc = vec![
+            (100.0, 105.0, 95.0, 102.0),
+            (102.0, 108.0, 100.0, 106.0),
+            (106.0, 106.0, 106.0, 106.0), // zero range, flat close
+            (106.0, 110.0, 101.0, 103.0),
+            (103.0, 118.0, 102.0, 116.0),
+        ];
+        let mut bars = bars_from_ohlc(&ohlc);
+        for (i, bar) in bars.iter_mut().enumerate() {
+            bar.volume = [500.0, 700.0, 1500.0, 800.0, 1200.0][i];
+        }
+
+        let seq_ad = crate::indicators::ad_line(&bars);
+        let seq_wad = crate::indicators::williams_ad(&bars);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = apply_ad_exprs(df.lazy()).collect().unwrap();
+        let pol_ad = result.column("ad_line").unwrap().f64().unwrap();
+        let pol_wad = result.column("williams_ad").unwrap().f64().unwrap();
+
+        for i in 0..bars.len() {
+            let (pv, v) = (pol_ad.get(i).unwrap(), seq_ad[i]);
+            assert!(
+                (pv - v).abs() < 1e-8,
+                "A/D mismatch at {}: {} vs {}",
+                i,
+                pv,
+                v
+            );
+            let (pv, v) = (pol_wad.get(i).unwrap(), seq_wad[i]);
+            assert!(
+                (pv - v).abs() < 1e-8,
+                "Williams A/D mismatch at {}: {} vs {}",
+                i,
+                pv,
+                v
+            );
+        }
+    }
+
+    #[test]
+    fn test_mfi_polars_matches_sequential() {
+        let ohlc = vec![
+            (100.0, 105.0, 95.0, 102.0),
+            (102.0, 108.0, 100.0, 106.0),
+            (106.0, 107.0, 101.0, 103.0),
+            (103.0, 110.0, 102.0, 108.0),
+            (108.0, 109.0, 99.0, 100.0),
+            (100.0, 112.0, 100.0, 111.0),
+        ];
+        let mut bars = bars_from_ohlc(&ohlc);
+        for (i, bar) in bars.iter_mut().enumerate() {
+            bar.volume = [500.0, 700.0, 1500.0, 800.0, 1200.0, 900.0][i];
+        }
+
+        let seq = crate::indicators::mfi(&bars, 3);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = df.lazy().with_column(mfi_expr(3)).collect().unwrap();
+        let pol = result.column("mfi").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => assert!(pol.get(i).is_none(), "Expected null at index {}", i),
+                Some(v) => {
+                    let pv = pol.get(i).unwrap();
+                    assert!(
+                        (pv - v).abs() < 1e-8,
+                        "MFI mismatch at {}: {} vs {}",
+                        i,
+                        pv,
+                        v
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cmf_polars_matches_sequential() {
+        let ohlc = vec![
+            (100.0, 105.0, 95.0, 102.0),
+            (102.0, 108.0, 100.0, 106.0),
+            (106.0, 106.0, 106.0, 106.0), // zero range
+            (103.0, 110.0, 102.0, 108.0),
+            (108.0, 109.0, 99.0, 100.0),
+            (100.0, 112.0, 100.0, 111.0),
+        ];
+        let mut bars = bars_from_ohlc(&ohlc);
+        for (i, bar) in bars.iter_mut().enumerate() {
+            bar.volume = [500.0, 700.0, 1500.0, 800.0, 1200.0, 900.0][i];
+        }
+
+        let seq = crate::indicators::cmf(&bars, 3);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = df.lazy().with_column(cmf_expr(3)).collect().unwrap();
+        let pol = result.column("cmf").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => assert!(pol.get(i).is_none(), "Expected null at index {}", i),
+                Some(v) => {
+                    let pv = pol.get(i).unwrap();
+                    assert!(
+                        (pv - v).abs() < 1e-8,
+                        "CMF mismatch at {}: {} vs {}",
+                        i,
+                        pv,
+                        v
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_coppock_polars_matches_sequential() {
+        let closes: Vec<f64> = (0..40)
+            .map(|i| 100.0 + (i as f64 * 0.4).sin() * 8.0 + i as f64 * 0.2)
+            .collect();
+        let bars = bars_from_closes(&closes);
+
+        let seq = crate::indicators::coppock(&bars, 7, 5, 4);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = df
+            .lazy()
+            .with_column(coppock_expr(7, 5, 4))
+            .collect()
+            .unwrap();
+        let pol = result.column("coppock").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => assert!(pol.get(i).is_none(), "Expected null at index {}", i),
+                Some(v) => {
+                    let pv = pol.get(i).unwrap();
+                    assert!(
+                        (pv - v).abs() < 1e-8,
+                        "Coppock mismatch at {}: {} vs {}",
+                        i,
+                        pv,
+                        v
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_elder_ray_polars_matches_sequential() {
+        let closes: Vec<f64> = (0..30)
+            .map(|i| 100.0 + (i as f64 * 0.5).sin() * 6.0 + i as f64 * 0.3)
+            .collect();
+        let bars = bars_from_closes(&closes);
+
+        let seq = crate::indicators::elder_ray(&bars, 13);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = apply_elder_ray_exprs(df.lazy(), 13).collect().unwrap();
+        let bull = result.column("bull_power").unwrap().f64().unwrap();
+        let bear = result.column("bear_power").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => {
+                    assert!(bull.get(i).is_none(), "Expected null bull at index {}", i);
+                    assert!(bear.get(i).is_none(), "Expected null bear at index {}", i);
+                }
+                Some((b, s)) => {
+                    assert!(
+                        (bull.get(i).unwrap() - b).abs() < 1e-8,
+                        "Bull mismatch at {}",
+                        i
+                    );
+                    assert!(
+                        (bear.get(i).unwrap() - s).abs() < 1e-8,
+                        "Bear mismatch at {}",
+                        i
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hma_polars_matches_sequential() {
+        let closes: Vec<f64> = (0..30)
+            .map(|i| 100.0 + (i as f64 * 0.7).sin() * 5.0 + i as f64 * 0.3)
+            .collect();
+        let bars = bars_from_closes(&closes);
+
+        let seq = crate::indicators::hma_close(&bars, 9);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = df
+            .lazy()
+            .with_column(ma_close_expr(MAType::Hull, 9).alias("hma"))
+            .collect()
+            .unwrap();
+        let pol = result.column("hma").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => assert!(pol.get(i).is_none(), "Expected null at index {}", i),
+                Some(v) => {
+                    let pv = pol.get(i).unwrap();
+                    assert!(
+                        (pv - v).abs() < 1e-8,
+                        "HMA mismatch at {}: {} vs {}",
+                        i,
+                        pv,
+                        v
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kama_polars_matches_sequential() {
+        let closes: Vec<f64> = (0..30)
+            .map(|i| 100.0 + (i as f64 * 0.7).sin() * 5.0 + i as f64 * 0.3)
+            .collect();
+        let bars = bars_from_closes(&closes);
+
+        let seq = crate::indicators::kama(&bars, 10, KAMA_DEFAULT_FAST, KAMA_DEFAULT_SLOW);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = df
+            .lazy()
+            .with_column(ma_close_expr(MAType::Kama, 10).alias("kama"))
+            .collect()
+            .unwrap();
+        let pol = result.column("kama").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => assert!(pol.get(i).is_none(), "Expected null at index {}", i),
+                Some(v) => {
+                    let pv = pol.get(i).unwrap();
+                    assert!(
+                        (pv - v).abs() < 1e-8,
+                        "KAMA mismatch at {}: {} vs {}",
+                        i,
+                        pv,
+                        v
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_vortex_polars_matches_sequential() {
+        let ohlc = vec![
+            (100.0, 105.0, 95.0, 102.0),
+            (102.0, 108.0, 100.0, 106.0),
+            (106.0, 107.0, 101.0, 103.0),
+            (103.0, 110.0, 102.0, 108.0),
+            (108.0, 109.0, 99.0, 100.0),
+            (100.0, 112.0, 100.0, 111.0),
+            (111.0, 115.0, 109.0, 114.0),
+        ];
+        let bars = bars_from_ohlc(&ohlc);
+
+        let seq = crate::indicators::vortex(&bars, 3);
+
+        let df = bars_to_dataframe(&bars).unwrap();
+        let result = apply_vortex_exprs(df.lazy(), 3).collect().unwrap();
+        let plus = result.column("vi_plus").unwrap().f64().unwrap();
+        let minus = result.column("vi_minus").unwrap().f64().unwrap();
+
+        for (i, seq_val) in seq.iter().enumerate() {
+            match seq_val {
+                None => {
+                    assert!(plus.get(i).is_none(), "Expected null VI+ at index {}", i);
+                    assert!(minus.get(i).is_none(), "Expected null VI- at index {}", i);
+                }
+                Some((p, m)) => {
+                    let pp = plus.get(i).unwrap();
+                    let pm = minus.get(i).unwrap();
+                    assert!(
+                        (pp - p).abs() < 1e-8,
+                        "VI+ mismatch at {}: {} vs {}",
+                        i,
+                        pp,
+                        p
+                    );
+                    assert!(
+                        (pm - m).abs() < 1e-8,
+                        "VI- mismatch at {}: {} vs {}",
+                        i,
+                        pm,
+                        m
+                    );
+                }
+            }
+        }
+    }
 }
@@ -5,11 +5,16 @@
 //! - Leaderboard: Maintains top N strategies by Sharpe ratio
 //! - CrossSymbolLeaderboard: Aggregated performance across symbols
 //! - Session vs All-Time tracking for persistent discovery
-//! - Persistence to/from JSON
+//! - Persistence to/from JSON (atomic writes)
+//! - Per-iteration YOLO checkpoints for resuming interrupted sessions
+//! - CSV export for spreadsheets
+//! - Equity-curve correlation between entries (diversification check)
 
+use crate::analysis::pearson;
 use crate::metrics::Metrics;
+use crate::sector_analysis::CorrelationMatrix;
 use crate::statistics::{benjamini_hochberg, ConfidenceGrade};
-use crate::sweep::{StrategyConfigId, StrategyTypeId};
+use crate::sweep::{RankMetric, StrategyConfigId, StrategyTypeId};
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
@@ -26,6 +31,22 @@ where
     Ok(opt.unwrap_or_default())
 }
 
+/// Write `contents` to `path` via a temp file and rename.
+///
+/// A crash mid-write leaves the previous file intact instead of a truncated one.
+fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    std::fs::write(&tmp_path, contents)?;
+    std::fs::rename(&tmp_path, path)
+}
+
 // =============================================================================
 // Leaderboard Scope (Session vs All-Time)
 // =============================================================================
@@ -38,14 +59,17 @@ pub enum LeaderboardScope {
     Session,
     /// All-time persistent leaderboard
     AllTime,
+    /// Only configs whose out-of-sample Sharpe survived walk-forward validation
+    WalkForward,
 }
 
 impl LeaderboardScope {
-    /// Toggle between Session and AllTime.
+    /// Cycle Session -> AllTime -> WalkForward -> Session.
     pub fn toggle(&self) -> Self {
         match self {
             Self::Session => Self::AllTime,
-            Self::AllTime => Self::Session,
+            Self::AllTime => Self::WalkForward,
+            Self::WalkForward => Self::Session,
         }
     }
 
@@ -54,6 +78,7 @@ impl LeaderboardScope {
         match self {
             Self::Session => "Session",
             Self::AllTime => "All-Time",
+            Self::WalkForward => "Walk-Forward",
         }
     }
 }
@@ -173,6 +198,20 @@ impl LeaderboardEntry {
     pub fn compute_confidence_grade(&self) -> Option<ConfidenceGrade> {
         compute_confidence_from_equity(&self.equity_curve)
     }
+
+    /// Whether walk-forward validation ran and the mean OOS Sharpe reached `threshold`.
+    pub fn passes_walk_forward(&self, threshold: f64) -> bool {
+        self.mean_oos_sharpe.is_some_and(|oos| oos >= threshold)
+    }
+
+    /// Equity curve paired with its timestamps (for [`equity_correlation_matrix`]).
+    pub fn timed_equity(&self) -> Vec<(DateTime<Utc>, f64)> {
+        self.dates
+            .iter()
+            .copied()
+            .zip(self.equity_curve.iter().copied())
+            .collect()
+    }
 }
 
 /// Compute confidence grade from an equity curve.
@@ -194,11 +233,7 @@ pub fn compute_confidence_from_equity(equity_curve: &[f64]) -> Option<Confidence
         return Some(ConfidenceGrade::Insufficient);
     }
 
-    // Compute daily returns
-    let returns: Vec<f64> = equity_curve
-        .windows(2)
-        .map(|w| (w[1] - w[0]) / w[0].max(1e-10))
-        .collect();
+    let returns = equity_returns(equity_curve);
 
     if returns.len() < 30 {
         return Some(ConfidenceGrade::Insufficient);
@@ -233,6 +268,43 @@ pub fn compute_confidence_from_equity(equity_curve: &[f64]) -> Option<Confidence
     }
 }
 
+/// Bar-over-bar simple returns of an equity curve.
+fn equity_returns(equity_curve: &[f64]) -> Vec<f64> {
+    equity_curve
+        .windows(2)
+        .map(|w| (w[1] - w[0]) / w[0].max(1e-10))
+        .collect()
+}
+
+/// Pearson correlation of two equity curves' returns.
+///
+/// Curves of different lengths are compared over their common trailing
+/// (most recent) span. Returns None with fewer than two overlapping returns
+/// or when either series is flat.
+fn equity_return_correlation(a: &[f64], b: &[f64]) -> Option<f64> {
+    let (ra, rb) = (equity_returns(a), equity_returns(b));
+    let n = ra.len().min(rb.len());
+    if n < 2 {
+        return None;
+    }
+    let (ra, rb) = (&ra[ra.len() - n..], &rb[rb.len() - n..]);
+
+    let mean_a = ra.iter().sum::<f64>() / n as f64;
+    let mean_b = rb.iter().sum::<f64>() / n as f64;
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for (x, y) in ra.iter().zip(rb) {
+        let (dx, dy) = (x - mean_a, y - mean_b);
+        cov += dx * dy;
+        var_a += dx * dx;
+        var_b += dy * dy;
+    }
+
+    if var_a <= 0.0 || var_b <= 0.0 {
+        return None;
+    }
+    Some(cov / (var_a.sqrt() * var_b.sqrt()))
+}
+
 /// Compute a confidence grade for *cross-symbol* results using cross-sectional evidence.
 ///
 /// Rationale:
@@ -377,16 +449,51 @@ pub fn compute_cross_sector_confidence_from_metrics(
     }
 }
 
+/// Quote a CSV field if it contains a delimiter, quote, or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Write a header and rows to a CSV file, creating parent directories as needed.
+fn write_csv(
+    path: &Path,
+    header: &[&str],
+    rows: impl Iterator<Item = Vec<String>>,
+) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut out = header.join(",");
+    out.push('\n');
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+
+    std::fs::write(path, out)
+}
+
 // =============================================================================
 // Leaderboard
 // =============================================================================
 
-/// YOLO Mode leaderboard - maintains top N strategies by Sharpe ratio.
+/// YOLO Mode leaderboard - maintains top N strategies by Sharpe ratio
+/// (or another [`RankMetric`], see [`Leaderboard::with_rank_metric`]).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Leaderboard {
-    /// Sorted entries (best Sharpe first)
+    /// Sorted entries (best first by `rank_by`)
     pub entries: Vec<LeaderboardEntry>,
 
+    /// Metric entries are ranked by (Sharpe by default)
+    #[serde(default)]
+    pub rank_by: RankMetric,
+
     /// Maximum number of entries to keep
     pub max_entries: usize,
 
@@ -415,6 +522,7 @@ impl Leaderboard {
         let now = Utc::now();
         Self {
             entries: Vec::with_capacity(max_entries),
+            rank_by: RankMetric::Sharpe,
             max_entries,
             total_iterations: 0,
             started_at: now,
@@ -423,29 +531,44 @@ impl Leaderboard {
         }
     }
 
+    /// Rank entries by `metric` instead of Sharpe, re-ranking existing entries.
+    pub fn with_rank_metric(mut self, metric: RankMetric) -> Self {
+        self.rank_by = metric;
+        self.sort_and_rerank();
+        self
+    }
+
+    /// Ranking score of an entry under `rank_by`.
+    fn score(&self, entry: &LeaderboardEntry) -> f64 {
+        self.rank_by.extract(&entry.metrics)
+    }
+
     /// Try to insert an entry. Returns true if the entry was added (either new or replaced worse).
     ///
+    /// Scores come from `rank_by` (Sharpe by default).
+    ///
     /// Deduplication: If an entry with the same config_hash exists:
-    /// - If new Sharpe > existing Sharpe: replace it
+    /// - If new score > existing score: replace it
     /// - Otherwise: skip
     ///
     /// Otherwise:
     /// - If not full: add it
-    /// - If full and new Sharpe > worst: replace worst
+    /// - If full and new score > worst: replace worst
     pub fn try_insert(&mut self, entry: LeaderboardEntry) -> bool {
         let hash = entry.config_hash();
-        let new_sharpe = entry.metrics.sharpe;
+        let new_score = self.score(&entry);
         let symbol = entry.symbol.as_deref().unwrap_or("N/A");
 
         // Check for existing entry with same config
         if let Some(pos) = self.entries.iter().position(|e| e.config_hash() == hash) {
-            // Same config exists - only replace if better Sharpe
-            if new_sharpe > self.entries[pos].metrics.sharpe {
+            let old_score = self.score(&self.entries[pos]);
+            // Same config exists - only replace if better score
+            if new_score > old_score {
                 tracing::debug!(
                     symbol = %symbol,
-                    old_sharpe = %self.entries[pos].metrics.sharpe,
-                    new_sharpe = %new_sharpe,
-                    "Leaderboard: replaced existing entry with better Sharpe"
+                    old_score = %old_score,
+                    new_score = %new_score,
+                    "Leaderboard: replaced existing entry with better score"
                 );
                 self.entries[pos] = entry;
                 self.sort_and_rerank();
@@ -454,8 +577,8 @@ impl Leaderboard {
             }
             tracing::trace!(
                 symbol = %symbol,
-                existing_sharpe = %self.entries[pos].metrics.sharpe,
-                new_sharpe = %new_sharpe,
+                existing_score = %old_score,
+                new_score = %new_score,
                 "Leaderboard: rejected duplicate (not better)"
             );
             return false;
@@ -466,7 +589,7 @@ impl Leaderboard {
             // Not full, just add
             tracing::debug!(
                 symbol = %symbol,
-                sharpe = %new_sharpe,
+                score = %new_score,
                 rank = self.entries.len() + 1,
                 "Leaderboard: added new entry (not full)"
             );
@@ -478,12 +601,13 @@ impl Leaderboard {
 
         // Full - check if better than worst
         if let Some(worst) = self.entries.last() {
-            if new_sharpe > worst.metrics.sharpe {
+            let worst_score = self.score(worst);
+            if new_score > worst_score {
                 // Replace worst
                 tracing::debug!(
                     symbol = %symbol,
-                    sharpe = %new_sharpe,
-                    replaced_sharpe = %worst.metrics.sharpe,
+                    score = %new_score,
+                    replaced_score = %worst_score,
                     "Leaderboard: replaced worst entry"
                 );
                 self.entries.pop();
@@ -496,19 +620,30 @@ impl Leaderboard {
 
         tracing::trace!(
             symbol = %symbol,
-            sharpe = %new_sharpe,
+            score = %new_score,
             "Leaderboard: rejected (worse than worst)"
         );
         false
     }
 
-    /// Sort entries by Sharpe (descending), truncate to max_entries, and update ranks.
+    /// Insert only if the entry survived walk-forward validation.
+    ///
+    /// Entries whose mean OOS Sharpe is missing or below `threshold` are
+    /// rejected, keeping in-sample winners out of the walk-forward scope.
+    pub fn try_insert_walk_forward(&mut self, entry: LeaderboardEntry, threshold: f64) -> bool {
+        if !entry.passes_walk_forward(threshold) {
+            return false;
+        }
+        self.try_insert(entry)
+    }
+
+    /// Sort entries by `rank_by` (descending), truncate to max_entries, and update ranks.
     pub fn sort_and_rerank(&mut self) {
-        // Sort by Sharpe descending
+        let rank_by = self.rank_by;
         self.entries.sort_by(|a, b| {
-            b.metrics
-                .sharpe
-                .partial_cmp(&a.metrics.sharpe)
+            rank_by
+                .extract(&b.metrics)
+                .partial_cmp(&rank_by.extract(&a.metrics))
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
@@ -523,6 +658,68 @@ impl Leaderboard {
         }
     }
 
+    /// Merge entries from another leaderboard (e.g., a session run on another machine).
+    ///
+    /// Entries are unioned and deduplicated on `config_hash`, keeping the one with
+    /// the higher score under this leaderboard's `rank_by`. The result is re-ranked
+    /// and trimmed to `max_entries`, with ties broken by config display string and
+    /// then symbol.
+    pub fn merge(&mut self, other: &Leaderboard) {
+        for entry in &other.entries {
+            let hash = entry.config_hash();
+            match self.entries.iter().position(|e| e.config_hash() == hash) {
+                Some(pos) => {
+                    if self.score(entry) > self.score(&self.entries[pos]) {
+                        self.entries[pos] = entry.clone();
+                    }
+                }
+                None => self.entries.push(entry.clone()),
+            }
+        }
+
+        // Order ties deterministically; the stable sort in sort_and_rerank preserves it
+        self.entries.sort_by(|a, b| {
+            a.config
+                .display()
+                .cmp(&b.config.display())
+                .then_with(|| a.symbol.cmp(&b.symbol))
+        });
+        self.sort_and_rerank();
+
+        self.total_iterations += other.total_iterations;
+        self.total_configs_tested += other.total_configs_tested;
+        self.started_at = self.started_at.min(other.started_at);
+        self.last_updated = Utc::now();
+    }
+
+    /// Drop entries whose equity curve is a near-copy of a higher-ranked one.
+    ///
+    /// Walking from best to worst, an entry is kept only if its return
+    /// correlation with every already-kept entry is at or below `threshold`
+    /// (e.g. Donchian 21/10 is dropped behind 20/10). Entries without enough
+    /// equity history to correlate are always kept. Returns how many were removed.
+    pub fn dedup_by_equity_correlation(&mut self, threshold: f64) -> usize {
+        let before = self.entries.len();
+        let mut kept: Vec<LeaderboardEntry> = Vec::with_capacity(before);
+        for entry in std::mem::take(&mut self.entries) {
+            let duplicate = kept.iter().any(|k| {
+                equity_return_correlation(&k.equity_curve, &entry.equity_curve)
+                    .is_some_and(|corr| corr > threshold)
+            });
+            if !duplicate {
+                kept.push(entry);
+            }
+        }
+        self.entries = kept;
+
+        let removed = before - self.entries.len();
+        if removed > 0 {
+            self.sort_and_rerank();
+            self.last_updated = Utc::now();
+        }
+        removed
+    }
+
     /// Get the minimum Sharpe in the leaderboard (for quick filtering).
     pub fn min_sharpe(&self) -> Option<f64> {
         self.entries.last().map(|e| e.metrics.sharpe)
@@ -552,17 +749,12 @@ impl Leaderboard {
     // Persistence
     // =========================================================================
 
-    /// Save leaderboard to a JSON file.
+    /// Save leaderboard to a JSON file (atomically, creating parent directories).
     pub fn save(&self, path: &Path) -> io::Result<()> {
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-
         let json = serde_json::to_string_pretty(self)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
-        std::fs::write(path, json)
+        write_atomic(path, &json)
     }
 
     /// Load leaderboard from a JSON file.
@@ -575,6 +767,49 @@ impl Leaderboard {
     pub fn load_or_new(path: &Path, max_entries: usize) -> Self {
         Self::load(path).unwrap_or_else(|_| Self::new(max_entries))
     }
+
+    /// Export leaderboard to a CSV file, one row per entry.
+    pub fn to_csv(&self, path: &Path) -> io::Result<()> {
+        let header = [
+            "rank",
+            "strategy",
+            "config",
+            "symbol",
+            "sector",
+            "sharpe",
+            "sortino",
+            "cagr",
+            "total_return",
+            "max_drawdown",
+            "calmar",
+            "win_rate",
+            "profit_factor",
+            "num_trades",
+            "ulcer_index",
+            "upi",
+        ];
+        let rows = self.entries.iter().map(|e| {
+            vec![
+                e.rank.to_string(),
+                e.strategy_type.name().to_string(),
+                e.config.display(),
+                e.symbol.clone().unwrap_or_default(),
+                e.sector.clone().unwrap_or_default(),
+                e.metrics.sharpe.to_string(),
+                e.metrics.sortino.to_string(),
+                e.metrics.cagr.to_string(),
+                e.metrics.total_return.to_string(),
+                e.metrics.max_drawdown.to_string(),
+                e.metrics.calmar.to_string(),
+                e.metrics.win_rate.to_string(),
+                e.metrics.profit_factor.to_string(),
+                e.metrics.num_trades.to_string(),
+                e.metrics.ulcer_index.to_string(),
+                e.metrics.upi.to_string(),
+            ]
+        });
+        write_csv(path, &header, rows)
+    }
 }
 
 // =============================================================================
@@ -1863,6 +2098,13 @@ impl AggregatedConfigResult {
         compute_confidence_from_equity(&self.combined_equity_curve)
     }
 
+    /// Whether walk-forward validation ran and the mean OOS Sharpe reached `threshold`.
+    ///
+    /// Configs below the YOLO trigger threshold are never validated, so they fail.
+    pub fn passes_walk_forward(&self, threshold: f64) -> bool {
+        self.mean_oos_sharpe.is_some_and(|oos| oos >= threshold)
+    }
+
     /// Get the ranking value for a given metric.
     ///
     /// This handles both traditional metrics (from AggregatedMetrics) and
@@ -2060,6 +2302,21 @@ impl CrossSymbolLeaderboard {
         false
     }
 
+    /// Insert only if the entry survived walk-forward validation.
+    ///
+    /// Entries whose mean OOS Sharpe is missing or below `threshold` are
+    /// rejected, however strong their in-sample metrics.
+    pub fn try_insert_walk_forward(
+        &mut self,
+        entry: AggregatedConfigResult,
+        threshold: f64,
+    ) -> bool {
+        if !entry.passes_walk_forward(threshold) {
+            return false;
+        }
+        self.try_insert(entry)
+    }
+
     /// Try to insert an entry, keeping top N configs per strategy type.
     ///
     /// Unlike `try_insert`, this method allows multiple configs per strategy type,
@@ -2161,6 +2418,36 @@ impl CrossSymbolLeaderboard {
         }
     }
 
+    /// Merge entries from another cross-symbol leaderboard.
+    ///
+    /// Entries are unioned and deduplicated on `config_hash`, keeping the one that
+    /// scores higher on this leaderboard's `rank_by` metric. The result is re-ranked
+    /// and trimmed to `max_entries`, with ties broken by config display string.
+    pub fn merge(&mut self, other: &CrossSymbolLeaderboard) {
+        let rank_by = self.rank_by;
+        for entry in &other.entries {
+            let hash = entry.config_hash();
+            match self.entries.iter().position(|e| e.config_hash() == hash) {
+                Some(pos) => {
+                    if entry.rank_value(rank_by) > self.entries[pos].rank_value(rank_by) {
+                        self.entries[pos] = entry.clone();
+                    }
+                }
+                None => self.entries.push(entry.clone()),
+            }
+        }
+
+        // Order ties deterministically; the stable sort in sort_and_rerank preserves it
+        self.entries
+            .sort_by_key(|e| (e.config_id.display(), format!("{:?}", e.strategy_type)));
+        self.sort_and_rerank();
+
+        self.total_iterations += other.total_iterations;
+        self.total_configs_tested += other.total_configs_tested;
+        self.started_at = self.started_at.min(other.started_at);
+        self.last_updated = Utc::now();
+    }
+
     /// Get the best (highest) rank value in the leaderboard.
     pub fn best_value(&self) -> Option<f64> {
         self.entries
@@ -2199,16 +2486,12 @@ impl CrossSymbolLeaderboard {
     // Persistence
     // =========================================================================
 
-    /// Save cross-symbol leaderboard to a JSON file.
+    /// Save cross-symbol leaderboard to a JSON file (atomically, creating parent directories).
     pub fn save(&self, path: &Path) -> io::Result<()> {
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-
         let json = serde_json::to_string_pretty(self)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
-        std::fs::write(path, json)
+        write_atomic(path, &json)
     }
 
     /// Load cross-symbol leaderboard from a JSON file.
@@ -2222,6 +2505,67 @@ impl CrossSymbolLeaderboard {
         Self::load(path).unwrap_or_else(|_| Self::new(max_entries, rank_by))
     }
 
+    /// Export cross-symbol leaderboard to a CSV file, one row per config.
+    ///
+    /// Optional tail-risk metrics are left empty when not computed.
+    pub fn to_csv(&self, path: &Path) -> io::Result<()> {
+        let header = [
+            "rank",
+            "strategy",
+            "config",
+            "symbols",
+            "avg_sharpe",
+            "min_sharpe",
+            "max_sharpe",
+            "geo_mean_cagr",
+            "avg_cagr",
+            "worst_max_drawdown",
+            "avg_max_drawdown",
+            "profitable_count",
+            "total_symbols",
+            "hit_rate",
+            "avg_trades",
+            "avg_cvar_95",
+            "worst_cvar_95",
+            "avg_skewness",
+            "worst_skewness",
+            "avg_kurtosis",
+            "max_kurtosis",
+            "downside_ratio",
+            "regime_concentration_penalty",
+        ];
+        let opt = |v: Option<f64>| v.map(|x| x.to_string()).unwrap_or_default();
+        let rows = self.entries.iter().map(|e| {
+            let m = &e.aggregate_metrics;
+            vec![
+                e.rank.to_string(),
+                e.strategy_type.name().to_string(),
+                e.config_id.display(),
+                e.symbols.join(", "),
+                m.avg_sharpe.to_string(),
+                m.min_sharpe.to_string(),
+                m.max_sharpe.to_string(),
+                m.geo_mean_cagr.to_string(),
+                m.avg_cagr.to_string(),
+                m.worst_max_drawdown.to_string(),
+                m.avg_max_drawdown.to_string(),
+                m.profitable_count.to_string(),
+                m.total_symbols.to_string(),
+                m.hit_rate.to_string(),
+                m.avg_trades.to_string(),
+                opt(m.avg_cvar_95),
+                opt(m.worst_cvar_95),
+                opt(m.avg_skewness),
+                opt(m.worst_skewness),
+                opt(m.avg_kurtosis),
+                opt(m.max_kurtosis),
+                opt(m.downside_ratio),
+                opt(m.regime_concentration_penalty),
+            ]
+        });
+        write_csv(path, &header, rows)
+    }
+
     // =========================================================================
     // FDR Correction
     // =========================================================================
@@ -2482,6 +2826,191 @@ impl HistoryLogger {
     }
 }
 
+// =============================================================================
+// YOLO Session Checkpoints
+// =============================================================================
+
+/// File name of the per-symbol leaderboard in the artifacts directory.
+pub const LEADERBOARD_FILE: &str = "leaderboard.json";
+/// File name of the cross-symbol leaderboard in the artifacts directory.
+pub const CROSS_SYMBOL_LEADERBOARD_FILE: &str = "cross_symbol_leaderboard.json";
+/// File name of the YOLO checkpoint in the artifacts directory.
+pub const YOLO_CHECKPOINT_FILE: &str = "yolo_checkpoint.json";
+
+/// Marker for the last completed YOLO iteration.
+///
+/// Written after both leaderboards have been snapshotted, so a checkpoint
+/// that is not `finished` means the session was interrupted (crash or kill)
+/// and the leaderboards on disk reflect `session_iteration`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct YoloCheckpoint {
+    pub session_id: String,
+    /// Completed iterations in this session
+    pub session_iteration: u32,
+    pub saved_at: DateTime<Utc>,
+    pub per_symbol_entries: usize,
+    pub cross_symbol_entries: usize,
+    pub total_configs_tested: u64,
+    /// True once the session was stopped cleanly
+    pub finished: bool,
+}
+
+impl YoloCheckpoint {
+    /// Snapshot both leaderboards into `artifacts_dir` after a completed iteration.
+    ///
+    /// Leaderboards are written first (atomically), then the checkpoint, then
+    /// a line is appended to `yolo_snapshots/{session_id}.jsonl`.
+    pub fn snapshot(
+        artifacts_dir: &Path,
+        session_id: &str,
+        session_iteration: u32,
+        per_symbol: &Leaderboard,
+        cross_symbol: &CrossSymbolLeaderboard,
+    ) -> io::Result<Self> {
+        per_symbol.save(&artifacts_dir.join(LEADERBOARD_FILE))?;
+        cross_symbol.save(&artifacts_dir.join(CROSS_SYMBOL_LEADERBOARD_FILE))?;
+
+        let checkpoint = Self {
+            session_id: session_id.to_string(),
+            session_iteration,
+            saved_at: Utc::now(),
+            per_symbol_entries: per_symbol.entries.len(),
+            cross_symbol_entries: cross_symbol.entries.len(),
+            total_configs_tested: cross_symbol.total_configs_tested,
+            finished: false,
+        };
+        checkpoint.save(artifacts_dir)?;
+        checkpoint.append_history(artifacts_dir)?;
+        Ok(checkpoint)
+    }
+
+    /// Save the checkpoint to `artifacts_dir` (atomically).
+    pub fn save(&self, artifacts_dir: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        write_atomic(&artifacts_dir.join(YOLO_CHECKPOINT_FILE), &json)
+    }
+
+    /// Load the checkpoint from `artifacts_dir`.
+    pub fn load(artifacts_dir: &Path) -> io::Result<Self> {
+        let content = std::fs::read_to_string(artifacts_dir.join(YOLO_CHECKPOINT_FILE))?;
+        serde_json::from_str(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// The checkpoint of an interrupted session, if there is one to resume.
+    pub fn resumable(artifacts_dir: &Path) -> Option<Self> {
+        Self::load(artifacts_dir)
+            .ok()
+            .filter(|c| !c.finished && c.session_iteration > 0)
+    }
+
+    /// Mark the current checkpoint as cleanly finished (no-op if there is none).
+    pub fn mark_finished(artifacts_dir: &Path) -> io::Result<()> {
+        match Self::load(artifacts_dir) {
+            Ok(mut checkpoint) => {
+                checkpoint.finished = true;
+                checkpoint.save(artifacts_dir)
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Path of the per-session snapshot history file.
+    pub fn history_path(artifacts_dir: &Path, session_id: &str) -> std::path::PathBuf {
+        artifacts_dir
+            .join("yolo_snapshots")
+            .join(format!("{}.jsonl", session_id))
+    }
+
+    fn append_history(&self, artifacts_dir: &Path) -> io::Result<()> {
+        use std::io::Write;
+
+        let path = Self::history_path(artifacts_dir, &self.session_id);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let line = serde_json::to_string(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{}", line)
+    }
+}
+
+// =============================================================================
+// Equity Correlation
+// =============================================================================
+
+/// Pairwise correlation between the equity curves of leaderboard entries.
+///
+/// Use it to check that the top of a leaderboard isn't several variations
+/// of one idea. `equity_by_entry` supplies each entry's timestamped equity
+/// (usually [`LeaderboardEntry::timed_equity`]). Each pair is aligned on
+/// the timestamps both curves share, and correlated on the returns between
+/// those points, so entries backtested over different ranges are compared
+/// on their overlap only.
+///
+/// # Returns
+/// `CorrelationMatrix` labeled `"#<rank> <config>"` in entry order.
+/// `n_observations` is the smallest pairwise overlap (in returns). Pairs
+/// with fewer than two overlapping returns, or a flat curve, have no
+/// correlation (diagonal is always 1.0).
+pub fn equity_correlation_matrix<F>(
+    entries: &[LeaderboardEntry],
+    equity_by_entry: F,
+) -> CorrelationMatrix
+where
+    F: Fn(&LeaderboardEntry) -> Vec<(DateTime<Utc>, f64)>,
+{
+    let labels: Vec<String> = entries
+        .iter()
+        .map(|e| format!("#{} {}", e.rank, e.config.display()))
+        .collect();
+    let curves: Vec<HashMap<DateTime<Utc>, f64>> = entries
+        .iter()
+        .map(|e| equity_by_entry(e).into_iter().collect())
+        .collect();
+
+    let k = entries.len();
+    let mut values = vec![vec![None; k]; k];
+    let mut n_observations = usize::MAX;
+
+    for a in 0..k {
+        values[a][a] = Some(1.0);
+        for b in (a + 1)..k {
+            let mut shared: Vec<(DateTime<Utc>, f64, f64)> = curves[a]
+                .iter()
+                .filter_map(|(ts, &ea)| curves[b].get(ts).map(|&eb| (*ts, ea, eb)))
+                .collect();
+            shared.sort_by_key(|(ts, _, _)| *ts);
+
+            let returns: Vec<(f64, f64)> = shared
+                .windows(2)
+                .filter(|w| w[0].1.abs() > 1e-10 && w[0].2.abs() > 1e-10)
+                .map(|w| (w[1].1 / w[0].1 - 1.0, w[1].2 / w[0].2 - 1.0))
+                .collect();
+            n_observations = n_observations.min(returns.len());
+
+            let corr = if returns.len() >= 2 {
+                pearson(&returns).filter(|c| c.is_finite())
+            } else {
+                None
+            };
+            values[a][b] = corr;
+            values[b][a] = corr;
+        }
+    }
+
+    CorrelationMatrix {
+        labels,
+        values,
+        n_observations: if k < 2 { 0 } else { n_observations },
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -2596,6 +3125,39 @@ mod tests {
         assert_eq!(lb.entries[0].iteration, 1);
     }
 
+    #[test]
+    fn test_dedup_by_equity_correlation() {
+        let curve = |f: &dyn Fn(f64) -> f64| -> Vec<f64> {
+            let mut equity = vec![100.0];
+            for i in 1..60 {
+                let last = *equity.last().unwrap();
+                equity.push(last * (1.0 + f(i as f64)));
+            }
+            equity
+        };
+
+        let mut lb = Leaderboard::new(4);
+        let mut best = make_entry(2.0, 1, Some("A"));
+        best.equity_curve = curve(&|x| 0.01 * (x * 0.7).sin());
+        // Near-copy of the best, e.g. a neighbouring lookback
+        let mut twin = make_entry(1.9, 2, Some("B"));
+        twin.equity_curve = curve(&|x| 0.01 * (x * 0.7).sin() + 0.0005 * (x * 3.1).cos());
+        let mut distinct = make_entry(1.5, 3, Some("C"));
+        distinct.equity_curve = curve(&|x| 0.01 * (x * 2.3).cos());
+        for entry in [twin, distinct, best] {
+            lb.try_insert(entry);
+        }
+
+        assert_eq!(lb.dedup_by_equity_correlation(0.95), 1);
+        assert_eq!(lb.entries.len(), 2);
+        assert_eq!(lb.entries[0].symbol.as_deref(), Some("A"));
+        assert_eq!(lb.entries[1].symbol.as_deref(), Some("C"));
+        assert_eq!(lb.entries[1].rank, 2);
+
+        // Nothing left to drop
+        assert_eq!(lb.dedup_by_equity_correlation(0.95), 0);
+    }
+
     #[test]
     fn test_best_and_min_sharpe() {
         let mut lb = Leaderboard::new(4);
@@ -2606,6 +3168,61 @@ mod tests {
         assert_eq!(lb.min_sharpe(), Some(1.0));
     }
 
+    #[test]
+    fn test_yolo_checkpoint_resume_after_crash() {
+        let dir =
+            std::env::temp_dir().join(format!("trendlab_checkpoint_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut per_symbol = Leaderboard::new(10);
+        let mut cross_symbol = CrossSymbolLeaderboard::new(10, CrossSymbolRankMetric::AvgSharpe);
+        let mut last_snapshot = String::new();
+
+        for iteration in 1..=4 {
+            let mut entry = make_entry(iteration as f64, iteration, Some("SPY"));
+            entry.config = StrategyConfigId::Donchian {
+                entry_lookback: 10 * iteration as usize,
+                exit_lookback: 10,
+            };
+            per_symbol.try_insert(entry);
+            per_symbol.total_iterations = iteration;
+            cross_symbol.add_configs_tested(10);
+
+            if iteration == 4 {
+                // Crash mid-write of iteration 4: only a partial temp file is left
+                std::fs::write(dir.join("leaderboard.json.tmp"), "{\"entries\": [").unwrap();
+                break;
+            }
+            YoloCheckpoint::snapshot(&dir, "session-a", iteration, &per_symbol, &cross_symbol)
+                .unwrap();
+            last_snapshot = serde_json::to_string(&per_symbol).unwrap();
+        }
+
+        // Restart: the reloaded state matches the iteration-3 snapshot
+        let checkpoint = YoloCheckpoint::resumable(&dir).expect("interrupted session");
+        assert_eq!(checkpoint.session_id, "session-a");
+        assert_eq!(checkpoint.session_iteration, 3);
+        assert_eq!(checkpoint.total_configs_tested, 30);
+
+        let reloaded = Leaderboard::load(&dir.join(LEADERBOARD_FILE)).unwrap();
+        assert_eq!(serde_json::to_string(&reloaded).unwrap(), last_snapshot);
+        assert_eq!(reloaded.entries.len(), 3);
+        assert_eq!(reloaded.best_sharpe(), Some(3.0));
+        let reloaded_cross =
+            CrossSymbolLeaderboard::load(&dir.join(CROSS_SYMBOL_LEADERBOARD_FILE)).unwrap();
+        assert_eq!(reloaded_cross.total_configs_tested, 30);
+
+        let history =
+            std::fs::read_to_string(YoloCheckpoint::history_path(&dir, "session-a")).unwrap();
+        assert_eq!(history.lines().count(), 3);
+
+        // A clean stop is not offered for resume
+        YoloCheckpoint::mark_finished(&dir).unwrap();
+        assert!(YoloCheckpoint::resumable(&dir).is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_save_and_load() {
         let mut lb = Leaderboard::new(4);
@@ -2728,6 +3345,104 @@ mod tests {
         assert_eq!(lb.entries[3].confidence_grade, Some(ConfidenceGrade::High));
     }
 
+    #[test]
+    fn test_walk_forward_scope_excludes_oos_failures() {
+        let threshold = 0.25;
+        let mut lb = CrossSymbolLeaderboard::new(10, CrossSymbolRankMetric::AvgSharpe);
+
+        // Great in-sample, but collapses out of sample
+        let mut snooped = make_cross_symbol_entry(2.5, Some(0.4));
+        snooped.mean_oos_sharpe = Some(0.05);
+        assert!(!lb.try_insert_walk_forward(snooped, threshold));
+
+        // Never validated (below the WF trigger)
+        let mut unvalidated = make_cross_symbol_entry(1.0, None);
+        unvalidated.mean_oos_sharpe = None;
+        assert!(!lb.try_insert_walk_forward(unvalidated, threshold));
+
+        // Modest in-sample, holds up out of sample
+        let mut validated = make_cross_symbol_entry(1.2, Some(0.01));
+        validated.config_id = StrategyConfigId::Donchian {
+            entry_lookback: 55,
+            exit_lookback: 20,
+        };
+        validated.mean_oos_sharpe = Some(0.9);
+        assert!(lb.try_insert_walk_forward(validated, threshold));
+
+        assert_eq!(lb.entries.len(), 1);
+        assert_eq!(lb.entries[0].mean_oos_sharpe, Some(0.9));
+
+        // Per-symbol entries follow the same rule
+        let mut per_symbol = Leaderboard::new(10);
+        assert!(!per_symbol.try_insert_walk_forward(make_entry(3.0, 1, Some("AAPL")), threshold));
+        assert!(per_symbol.entries.is_empty());
+
+        assert_eq!(
+            LeaderboardScope::AllTime.toggle(),
+            LeaderboardScope::WalkForward
+        );
+        assert_eq!(
+            LeaderboardScope::WalkForward.toggle(),
+            LeaderboardScope::Session
+        );
+    }
+
+    #[test]
+    fn test_equity_correlation_matrix_flags_duplicates() {
+        use chrono::{Duration, TimeZone};
+
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let curve = |start: i64, len: i64, scale: f64, ret: fn(i64) -> f64| {
+            let mut equity = scale;
+            let mut points = Vec::new();
+            for day in start..start + len {
+                equity *= 1.0 + ret(day);
+                points.push((base + Duration::days(day), equity));
+            }
+            points
+        };
+        let wavy: fn(i64) -> f64 = |d| 0.01 * (d as f64 * 0.7).sin();
+        let other: fn(i64) -> f64 = |d| 0.01 * (d as f64 * 1.9).cos();
+
+        let mut entries = Vec::new();
+        // Same returns over a shifted, overlapping range at a different size
+        for (rank, points) in [
+            curve(0, 60, 100.0, wavy),
+            curve(20, 60, 250.0, wavy),
+            curve(0, 60, 100.0, other),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut entry = make_entry(1.0, 1, Some("SPY"));
+            entry.rank = rank + 1;
+            entry.dates = points.iter().map(|(ts, _)| *ts).collect();
+            entry.equity_curve = points.iter().map(|(_, eq)| *eq).collect();
+            entries.push(entry);
+        }
+
+        let matrix = equity_correlation_matrix(&entries, LeaderboardEntry::timed_equity);
+        assert_eq!(matrix.labels.len(), 3);
+        assert!(matrix.labels[0].starts_with("#1 "));
+        for i in 0..3 {
+            assert_eq!(matrix.values[i][i], Some(1.0));
+        }
+        assert!((matrix.values[0][1].unwrap() - 1.0).abs() < 1e-9);
+        assert_eq!(matrix.values[0][1], matrix.values[1][0]);
+        assert!(matrix.values[0][2].unwrap() < 0.5);
+        assert!(matrix.values[1][2].unwrap() < 0.5);
+        // Entries 1 and 2 share 40 dates, i.e. 39 returns
+        assert_eq!(matrix.n_observations, 39);
+
+        // An entry without dates has no overlap; the matrix still round-trips
+        entries[2].dates.clear();
+        let matrix = equity_correlation_matrix(&entries, LeaderboardEntry::timed_equity);
+        assert_eq!(matrix.values[0][2], None);
+        let json = serde_json::to_string(&matrix).unwrap();
+        let restored: CorrelationMatrix = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, matrix);
+    }
+
     // =========================================================================
     // Phase 3A: Combined Equity Realism Tests
     // =========================================================================
@@ -3349,4 +4064,165 @@ mod tests {
             "Different profiles should generally produce different scores"
         );
     }
+
+    /// Split a CSV line, honoring double-quoted fields.
+    fn split_csv_line(line: &str) -> Vec<String> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if in_quotes && chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = !in_quotes,
+                ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+                _ => field.push(c),
+            }
+        }
+        fields.push(field);
+        fields
+    }
+
+    #[test]
+    fn test_leaderboard_custom_rank_metric() {
+        let entry = |lookback, sharpe, calmar, num_trades| {
+            let mut e = make_entry(sharpe, 1, Some("SPY"));
+            e.config = StrategyConfigId::Donchian {
+                entry_lookback: lookback,
+                exit_lookback: 10,
+            };
+            e.metrics.calmar = calmar;
+            e.metrics.num_trades = num_trades;
+            e
+        };
+        let weights = crate::sweep::RankWeights {
+            sharpe: 0.5,
+            calmar: 0.5,
+            trade_penalty: 0.01,
+            ..Default::default()
+        };
+
+        let kept = |metric| -> Vec<String> {
+            let mut lb = Leaderboard::new(2).with_rank_metric(metric);
+            lb.try_insert(entry(20, 2.0, 0.5, 40)); // score 0.85
+            lb.try_insert(entry(30, 1.5, 2.0, 10)); // score 1.65
+            lb.try_insert(entry(40, 1.0, 1.0, 5)); // score 0.95
+            lb.entries.iter().map(|e| e.config.display()).collect()
+        };
+
+        assert_eq!(
+            kept(RankMetric::Custom(weights)),
+            ["Donchian 30/10", "Donchian 40/10"]
+        );
+        assert_eq!(
+            kept(RankMetric::Sharpe),
+            ["Donchian 20/10", "Donchian 30/10"]
+        );
+    }
+
+    #[test]
+    fn test_leaderboard_to_csv() {
+        let mut lb = Leaderboard::new(4);
+        lb.try_insert(make_entry(1.5, 1, Some("AAPL")));
+        lb.try_insert(make_entry(2.0, 2, Some("MSFT")));
+
+        let path = std::env::temp_dir().join("test_leaderboard_export.csv");
+        lb.to_csv(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 3); // header + 2 entries
+
+        let header = split_csv_line(lines[0]);
+        let first = split_csv_line(lines[1]);
+        assert_eq!(first.len(), header.len());
+        assert_eq!(first[0], "1");
+        assert_eq!(first[2], "Donchian 20/10");
+        assert_eq!(first[3], "MSFT");
+        assert_eq!(first[5], "2");
+    }
+
+    #[test]
+    fn test_cross_symbol_leaderboard_to_csv_quotes_symbols() {
+        let mut lb = CrossSymbolLeaderboard::new(4, CrossSymbolRankMetric::AvgSharpe);
+        lb.try_insert(make_cross_symbol_entry(1.2, None));
+
+        let path = std::env::temp_dir().join("test_cross_symbol_export.csv");
+        lb.to_csv(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains("\"AAPL, MSFT\""));
+
+        let header = split_csv_line(lines[0]);
+        let row = split_csv_line(lines[1]);
+        assert_eq!(row.len(), header.len());
+        assert_eq!(row[3], "AAPL, MSFT");
+        assert_eq!(row[4], "1.2");
+        // Tail-risk metrics not computed -> empty cells
+        assert_eq!(row[header.len() - 1], "");
+    }
+
+    #[test]
+    fn test_merge_leaderboards_dedupes_overlap() {
+        let mut a = Leaderboard::new(3);
+        a.try_insert(make_entry(1.0, 1, Some("AAPL")));
+        a.try_insert(make_entry(1.2, 2, Some("GOOG")));
+        a.total_iterations = 5;
+
+        let mut b = Leaderboard::new(3);
+        b.try_insert(make_entry(1.8, 7, Some("AAPL"))); // overlaps, better
+        b.try_insert(make_entry(0.5, 8, Some("MSFT")));
+        b.total_iterations = 3;
+
+        a.merge(&b);
+
+        // Union of 3 distinct configs fits exactly in capacity
+        assert_eq!(a.entries.len(), 3);
+        let aapl: Vec<_> = a
+            .entries
+            .iter()
+            .filter(|e| e.symbol.as_deref() == Some("AAPL"))
+            .collect();
+        assert_eq!(aapl.len(), 1);
+        assert_eq!(aapl[0].metrics.sharpe, 1.8);
+        assert_eq!(aapl[0].iteration, 7);
+        assert_eq!(a.entries[0].rank, 1);
+        assert_eq!(a.entries[0].symbol.as_deref(), Some("AAPL"));
+        assert_eq!(a.total_iterations, 8);
+    }
+
+    #[test]
+    fn test_merge_cross_symbol_trims_and_breaks_ties() {
+        let with_lookbacks = |entry_lookback, exit_lookback| {
+            let mut e = make_cross_symbol_entry(1.0, None);
+            e.config_id = StrategyConfigId::Donchian {
+                entry_lookback,
+                exit_lookback,
+            };
+            e
+        };
+
+        let mut a = CrossSymbolLeaderboard::new(2, CrossSymbolRankMetric::AvgSharpe);
+        a.try_insert(with_lookbacks(55, 20));
+
+        let mut b = CrossSymbolLeaderboard::new(2, CrossSymbolRankMetric::AvgSharpe);
+        b.try_insert(with_lookbacks(20, 10));
+        let mut weak = with_lookbacks(40, 15);
+        weak.aggregate_metrics.avg_sharpe = 0.5;
+        b.try_insert(weak);
+
+        a.merge(&b);
+
+        assert_eq!(a.entries.len(), 2);
+        assert_eq!(a.entries[0].config_id.display(), "Donchian 20/10");
+        assert_eq!(a.entries[1].config_id.display(), "Donchian 55/20");
+        assert_eq!(a.entries[1].rank, 2);
+    }
 }
@@ -8,9 +8,11 @@
 //! - Parameter sweep infrastructure
 //! - Strategy artifact export for Pine Script parity
 //! - Post-backtest statistical analysis
+//! - Application defaults loaded from `trendlab.toml`
 
 pub mod analysis;
 pub mod analysis_polars;
+pub mod app_config;
 pub mod artifact;
 pub mod backtest;
 pub mod backtest_polars;
@@ -25,6 +27,7 @@ pub mod indicators_polars;
 pub mod latin_hypercube;
 pub mod leaderboard;
 pub mod metrics;
+pub mod result_filter;
 pub mod sector_analysis;
 pub mod sizing;
 pub mod statistics;
@@ -35,30 +38,38 @@ pub mod sweep_polars;
 pub mod universe;
 pub mod validation;
 
+pub use app_config::{AppConfig, AppConfigError, BacktestDefaults, APP_CONFIG_FILE};
 pub use artifact::{
-    create_52wk_high_artifact, create_artifact_from_config, create_donchian_artifact,
-    export_artifact_to_file, ArtifactBuilder, ArtifactCostModel, ArtifactError, ArtifactMetadata,
-    DataRange, IndicatorDef, IndicatorValue, OhlcvData, ParamValue, ParityVector, ParityVectors,
+    compare_parity, create_52wk_high_artifact, create_artifact_from_config,
+    create_donchian_artifact, export_artifact_to_file, export_bundle, to_pine_v6, ArtifactBuilder,
+    ArtifactCostModel, ArtifactError, ArtifactMetadata, BarParityDiff, BundleExport, DataRange,
+    IndicatorDef, IndicatorValue, OhlcvData, ParamValue, ParityReport, ParityVector, ParityVectors,
     Rule, Rules, StrategyArtifact, SCHEMA_VERSION,
 };
 pub use backtest::{
-    run_backtest, run_backtest_with_pyramid, run_backtest_with_sizer, BacktestConfig,
-    BacktestResult, BacktestSizingConfig, CostModel, EquityPoint, Fill, FillModel, PyramidConfig,
-    PyramidTrade, Side, Trade, TradeDirection,
+    assert_engine_parity, buy_and_hold, run_backtest, run_backtest_with_pyramid,
+    run_backtest_with_pyramid_sizer, run_backtest_with_sizer, run_portfolio_backtest, Allocation,
+    BacktestConfig, BacktestResult, BacktestSizingConfig, CostModel, EquityPoint, ExitReason, Fill,
+    FillModel, IntrabarModel, ParityDiff, ParityMismatch, PortfolioEquityPoint, PortfolioResult,
+    PyramidConfig, PyramidTrade, Side, SlippageModel, Trade, TradeDirection,
+    ENGINE_PARITY_TOLERANCE,
 };
 pub use backtest_polars::{
     load_streaming_sweep_results, run_backtest_polars, run_donchian_backtest_polars,
-    run_donchian_sweep_polars, run_strategy_sweep_polars, run_strategy_sweep_polars_cached,
-    run_strategy_sweep_polars_lazy, run_strategy_sweep_polars_optimized,
-    run_strategy_sweep_polars_parallel, run_strategy_sweep_polars_streaming,
+    run_donchian_sweep_polars, run_multi_strategy_sweep_polars_parallel, run_strategy_sweep_polars,
+    run_strategy_sweep_polars_cached, run_strategy_sweep_polars_lazy,
+    run_strategy_sweep_polars_optimized, run_strategy_sweep_polars_parallel,
+    run_strategy_sweep_polars_parallel_with_cancel, run_strategy_sweep_polars_streaming,
     DonchianBacktestConfig, PolarsBacktestConfig, PolarsBacktestResult, StreamingSweepConfig,
     StreamingSweepProgress, StreamingSweepResult, StreamingSweepSummary,
 };
 // Re-export IntoLazy trait for DataFrame.lazy() calls
 pub use analysis::{
-    AnalysisConfig, EdgeRatioStats, ExcursionStats, HoldingBucket, HoldingPeriodStats,
-    RegimeAnalysis, RegimeMetrics, ReturnDistribution, StatisticalAnalysis, TradeAnalysis,
-    TradeExcursion, VolAtEntryStats, VolRegime,
+    monte_carlo_trades, monthly_returns, rolling_correlation, seasonality, streak_stats,
+    underwater_curve, AnalysisConfig, EdgeRatioStats, ExcursionStats, HoldingBucket,
+    HoldingPeriodStats, McPercentiles, McResult, MonthlyReturn, RMultipleStats, RegimeAnalysis,
+    RegimeMetrics, ReturnDistribution, SeasonalBucket, Seasonality, StatisticalAnalysis,
+    StreakStats, TradeAnalysis, TradeExcursion, VolAtEntryStats, VolRegime,
 };
 pub use analysis_polars::{
     compute_analysis, compute_regime_analysis, compute_return_distribution, compute_trade_analysis,
@@ -72,12 +83,14 @@ pub use clustering::{
     ROBUSTNESS_CLUSTER_FEATURES,
 };
 pub use data::{
-    bars_to_dataframe, build_yahoo_chart_url, build_yahoo_url, dataframe_to_bars,
-    get_parquet_date_range, parquet_path, parse_yahoo_chart_json, parse_yahoo_csv,
-    partition_by_year, read_parquet, scan_multiple_parquet_lazy, scan_parquet_lazy,
-    scan_symbol_parquet_lazy, write_parquet, write_partitioned_parquet, CacheMetadata,
-    DataQualityChecker, DataQualityReport, DataSource, FetchRequest, FetchResult, ProviderError,
-    QualityIssue,
+    bars_to_dataframe, build_tiingo_url, build_yahoo_chart_url, build_yahoo_url,
+    check_yahoo_response, dataframe_to_bars, get_parquet_date_range, parquet_path,
+    parse_tiingo_json, parse_yahoo_chart_json, parse_yahoo_csv, partition_by_year, read_parquet,
+    resample, scan_multiple_parquet_lazy, scan_parquet_lazy, scan_symbol_parquet_lazy,
+    tiingo_api_key, write_parquet, write_partitioned_parquet, CacheMetadata, DataQualityChecker,
+    DataQualityReport, DataSource, FetchRequest, FetchResult, FillMethod, ProviderError,
+    QualityIssue, RateLimiter, ResamplePeriod, RetryPolicy, TradingCalendar,
+    DEFAULT_REQUESTS_PER_SEC, TIINGO_API_KEY_VAR,
 };
 pub use error::TrendLabError;
 pub use exploration::{
@@ -92,26 +105,37 @@ pub use indicator_cache::{
     collect_indicator_requirements, extract_indicator_requirements, CacheStats, IndicatorCache,
     IndicatorKey, LazyIndicatorCache,
 };
+pub use indicators::streaming::{AtrState, EmaState, TrueRangeState};
 pub use indicators::{
-    aroon, aroon_down, aroon_up, atr, atr_wilder, bollinger_bands, cci, darvas_boxes, dmi,
-    donchian_channel, ema_close, heikin_ashi, high_proximity, ichimoku, keltner_channel, macd,
-    minus_di, minus_dm, opening_range, parabolic_sar, plus_di, plus_dm, prior_day_range,
-    range_breakout_levels, roc, rolling_max_close, rolling_max_high, rolling_min_close,
-    rolling_min_low, rolling_std, rsi, sma_close, starc_bands, stochastic, supertrend, true_range,
-    williams_r, AroonIndicator, BollingerBands, DarvasBox, DonchianChannel, HABar, HighProximity,
-    IchimokuValue, KeltnerChannel, MACDEntryMode, MACDValue, MAType, OpeningPeriod, OpeningRange,
-    ParabolicSAR, STARCBands, StochasticValue, SupertrendValue, DMI,
+    ad_line, anchored_vwap, aroon, aroon_down, aroon_up, atr, atr_wilder,
+    atr_wilder_from_true_range, bollinger_bands, cci, cmf, cmo, coppock, darvas_boxes, dmi,
+    donchian_channel, elder_ray, ema_close, fisher_transform, heikin_ashi, high_proximity,
+    hma_close, ichimoku, is_session_start, kama, keltner_channel, macd, mfi, minus_di, minus_dm,
+    obv, opening_range, parabolic_sar, pivot_points, plus_di, plus_dm, pre_session_close,
+    prior_day_range, range_breakout_levels, roc, rolling_max_close, rolling_max_high,
+    rolling_min_close, rolling_min_low, rolling_std, rsi, sma_close, starc_bands, stoch_rsi,
+    stochastic, supertrend, trix, trix_with_signal, true_range, true_range_hlc, vortex, vwap,
+    williams_ad, williams_r, AroonIndicator, BollingerBands, DarvasBox, DonchianChannel, HABar,
+    HighProximity, IchimokuValue, KeltnerChannel, MACDEntryMode, MACDValue, MAType, OpeningPeriod,
+    OpeningRange, ParabolicSAR, PivotLevels, PivotMethod, STARCBands, StochasticValue,
+    SupertrendValue, DMI, FISHER_CLAMP, KAMA_DEFAULT_FAST, KAMA_DEFAULT_SLOW,
 };
 pub use indicators_polars::{
-    adx_expr, apply_aroon_exprs, apply_bollinger_exprs, apply_dmi_exprs, apply_heikin_ashi_exprs,
-    apply_ichimoku_exprs, apply_indicators, apply_keltner_exprs, apply_macd_exprs,
-    apply_opening_range_exprs, apply_parabolic_sar_exprs, apply_starc_exprs,
-    apply_stochastic_exprs, apply_supertrend_exprs, aroon_down_expr, aroon_oscillator_expr,
-    aroon_up_expr, atr_sma_expr, atr_wilder_expr, bollinger_bands_exprs, cci_expr,
-    donchian_channel_exprs, dx_expr, ema_close_expr, minus_di_expr, minus_dm_expr,
-    minus_dm_smoothed_expr, plus_di_expr, plus_dm_expr, plus_dm_smoothed_expr, roc_expr,
-    rolling_std_expr, rsi_expr, sma_close_expr, starc_bands_exprs, supertrend_basic_exprs,
-    true_range_expr, williams_r_expr, IndicatorSet, IndicatorSpec,
+    ad_line_expr, adx_expr, apply_ad_exprs, apply_aroon_exprs, apply_bollinger_exprs,
+    apply_cmf_exprs, apply_cmo_exprs, apply_coppock_exprs, apply_dmi_exprs, apply_elder_ray_exprs,
+    apply_fisher_exprs, apply_heikin_ashi_exprs, apply_ichimoku_exprs, apply_indicators,
+    apply_keltner_exprs, apply_macd_exprs, apply_obv_exprs, apply_opening_range_exprs,
+    apply_parabolic_sar_exprs, apply_starc_exprs, apply_stoch_rsi_exprs, apply_stochastic_exprs,
+    apply_supertrend_exprs, apply_trix_exprs, apply_vortex_exprs, aroon_down_expr,
+    aroon_oscillator_expr, aroon_up_expr, atr_sma_expr, atr_wilder_expr, bollinger_bands_exprs,
+    cci_expr, cmf_expr, cmo_expr, coppock_expr, donchian_channel_exprs, dx_expr,
+    elder_ray_ema_expr, elder_ray_exprs, ema_close_expr, fisher_transform_exprs, hma_close_expr,
+    indicator_series, kama_close_expr, ma_close_expr, mfi_expr, minus_di_expr, minus_dm_expr,
+    minus_dm_smoothed_expr, obv_expr, plus_di_expr, plus_dm_expr, plus_dm_smoothed_expr,
+    pre_session_close_expr, roc_expr, rolling_std_expr, rsi_expr, sma_close_expr,
+    starc_bands_exprs, stoch_rsi_d_expr, stoch_rsi_k_raw_expr, stoch_rsi_k_smooth_expr,
+    supertrend_basic_exprs, trix_expr, trix_signal_expr, true_range_expr, vortex_exprs, vwap_expr,
+    williams_ad_expr, williams_r_expr, IndicatorSet, IndicatorSpec,
 };
 pub use latin_hypercube::{
     generate_lhs_2d, generate_lhs_3d, generate_lhs_samples, LatinHypercubeSampler, LhsConfig,
@@ -119,53 +143,66 @@ pub use latin_hypercube::{
 pub use leaderboard::{
     combine_equity_curves_realistic, combine_equity_curves_simple, compute_confidence_from_equity,
     compute_cross_sector_confidence_from_metrics, compute_cross_symbol_confidence_from_metrics,
-    generate_session_id, AggregatedConfigResult, AggregatedMetrics, CombinedEquityAggregation,
-    CombinedEquityConfig, CombinedEquityResult, CombinedEquityWeighting, CrossSymbolLeaderboard,
-    CrossSymbolRankMetric, HistoryEntry, HistoryLogger, Leaderboard, LeaderboardEntry,
-    LeaderboardScope, RankingWeights, RiskProfile, RobustScoreConfig,
+    equity_correlation_matrix, generate_session_id, AggregatedConfigResult, AggregatedMetrics,
+    CombinedEquityAggregation, CombinedEquityConfig, CombinedEquityResult, CombinedEquityWeighting,
+    CrossSymbolLeaderboard, CrossSymbolRankMetric, HistoryEntry, HistoryLogger, Leaderboard,
+    LeaderboardEntry, LeaderboardScope, RankingWeights, RiskProfile, RobustScoreConfig,
+    YoloCheckpoint, CROSS_SYMBOL_LEADERBOARD_FILE, LEADERBOARD_FILE, YOLO_CHECKPOINT_FILE,
+};
+pub use metrics::{
+    compute_metrics, compute_metrics_with_config, compute_relative_metrics, Metrics, MetricsConfig,
+    RelativeMetrics, TRADING_DAYS_PER_YEAR,
 };
-pub use metrics::{compute_metrics, Metrics};
 pub use polars::prelude::IntoLazy;
+pub use result_filter::{parse_filter, CompareOp, FilterError, FilterExpr, MetricField};
 pub use sector_analysis::{
-    best_strategy_per_sector, filter_sectors, sector_concentration, sector_dispersion,
-    sector_performance, sector_summary_ranked, sector_vs_universe, top_per_sector,
+    best_strategy_per_sector, correlation_matrix, filter_sectors, sector_concentration,
+    sector_dispersion, sector_performance, sector_summary_ranked, sector_vs_universe,
+    top_per_sector, CorrelationMatrix,
 };
 pub use sizing::{
-    turtle_sizer, FixedSizer, PositionSizer, SizeResult, SizingConfig, VolatilitySizer,
+    atr_volatility, turtle_sizer, DrawdownSizer, FixedSizer, PositionSizer, SizeResult,
+    SizingConfig, VolatilitySizer,
 };
 pub use statistics::{
-    benjamini_hochberg, block_bootstrap_ci, block_bootstrap_sharpe, bonferroni, bootstrap_ci,
-    bootstrap_sharpe, holm_bonferroni, one_sided_mean_pvalue, permutation_test, sample_statistics,
+    adjust_p_values, benjamini_hochberg, benjamini_yekutieli, block_bootstrap_ci,
+    block_bootstrap_sharpe, bonferroni, bootstrap_ci, bootstrap_sharpe, holm_bonferroni,
+    one_sided_mean_pvalue, permutation_test, permutation_test_signals, probabilistic_sharpe,
+    psr_from_result, reality_check, sample_statistics, sign_flip_test, sweep_return_series,
     BlockBootstrapConfig, BootstrapConfig, BootstrapMethod, BootstrapResult, ConfidenceGrade,
-    MultipleComparisonMethod, MultipleComparisonResult, PermutationResult, SampleStatistics,
-    StatisticsError, StrategyStatistics,
+    ConfidenceGradeConfig, MultipleComparisonMethod, MultipleComparisonResult, PermutationResult,
+    RealityCheckResult, SampleStatistics, StatisticsError, StrategyStatistics,
 };
 pub use strategy::{
-    AroonCrossStrategy, BollingerSqueezeStrategy, CCIStrategy, DarvasBoxStrategy, DmiAdxStrategy,
-    DonchianBreakoutStrategy, EnsembleStrategy, FiftyTwoWeekHighMomentumStrategy,
-    FiftyTwoWeekHighStrategy, FiftyTwoWeekHighTrailingStrategy, HeikinAshiRegimeStrategy,
-    IchimokuStrategy, KeltnerBreakoutStrategy, LarryWilliamsStrategy, MACDAdxStrategy,
-    MACDStrategy, MACrossoverStrategy, NullStrategy, OpeningRangeBreakoutStrategy,
-    OscillatorConfluenceStrategy, ParabolicSARStrategy, ParabolicSarDelayedStrategy,
-    ParabolicSarFilteredStrategy, Position, ROCStrategy, RSIBollingerStrategy, RSIStrategy,
-    STARCBreakoutStrategy, Signal, StochasticStrategy, Strategy, SupertrendAsymmetricStrategy,
+    AroonCrossStrategy, BollingerSqueezeStrategy, BuyHoldStrategy, CCIStrategy, CmoStrategy,
+    CoppockStrategy, DarvasBoxStrategy, DmiAdxStrategy, DonchianBreakoutStrategy, ElderRayStrategy,
+    EnsembleStrategy, FiftyTwoWeekHighMomentumStrategy, FiftyTwoWeekHighStrategy,
+    FiftyTwoWeekHighTrailingStrategy, FisherStrategy, HeikinAshiRegimeStrategy, IchimokuStrategy,
+    KeltnerBreakoutStrategy, LarryWilliamsStrategy, MACDAdxStrategy, MACDStrategy,
+    MACrossoverStrategy, MultiTimeframe, NullStrategy, ObvTrendStrategy,
+    OpeningRangeBreakoutStrategy, OscillatorConfluenceStrategy, ParabolicSARStrategy,
+    ParabolicSarDelayedStrategy, ParabolicSarFilteredStrategy, Position, ROCStrategy,
+    RSIBollingerStrategy, RSIStrategy, RegimeFilter, STARCBreakoutStrategy, Signal,
+    StochRsiStrategy, StochasticStrategy, Strategy, SupertrendAsymmetricStrategy,
     SupertrendConfirmedStrategy, SupertrendCooldownStrategy, SupertrendStrategy,
-    SupertrendVolumeStrategy, TradingMode, TsmomStrategy, VotingMethod, WilliamsRStrategy,
+    SupertrendVolumeStrategy, TimeExit, TradingMode, TrixStrategy, TsmomStrategy,
+    VortexCrossStrategy, VotingMethod, WilliamsRStrategy,
 };
 pub use strategy_v2::{
-    create_strategy_v2, create_strategy_v2_from_config, AroonV2, BollingerSqueezeV2, DarvasBoxV2,
-    DmiAdxV2, DonchianBreakoutV2, EnsembleV2, FiftyTwoWeekHighV2, HeikinAshiV2, KeltnerV2,
-    LarryWilliamsV2, MACrossoverV2, OpeningRangeBreakoutV2, ParabolicSARV2, StarcV2, StrategySpec,
-    StrategyV2, SupertrendV2, TsmomV2,
+    create_strategy_v2, create_strategy_v2_from_config, AroonV2, BollingerSqueezeV2, CmoV2,
+    DarvasBoxV2, DmiAdxV2, DonchianBreakoutV2, EnsembleV2, FiftyTwoWeekHighV2, FisherV2,
+    HeikinAshiV2, KeltnerV2, LarryWilliamsV2, MACrossoverV2, ObvTrendV2, OpeningRangeBreakoutV2,
+    ParabolicSARV2, StarcV2, StrategySpec, StrategyV2, SupertrendV2, TrixV2, TsmomV2,
+    VortexCrossV2,
 };
 pub use sweep::{
-    compute_cost_sensitivity, compute_neighbor_sensitivity, create_strategy_from_config,
-    generate_summary_markdown, run_single_config_backtest, run_strategy_sweep, run_sweep,
-    AggregatedPortfolioResult, ConfigId, CostSensitivity, MultiStrategyGrid,
-    MultiStrategySweepResult, MultiSweepResult, NeighborSensitivity, RankMetric, ResultPaths,
-    RunManifest, StrategyBestResult, StrategyComparisonEntry, StrategyConfigId, StrategyGridConfig,
-    StrategyParams, StrategyTypeId, SweepConfig, SweepConfigResult, SweepDepth, SweepGrid,
-    SweepResult,
+    apply_fdr, compute_cost_sensitivity, compute_neighbor_sensitivity, create_strategy_from_config,
+    derive_seed, generate_summary_markdown, run_single_config_backtest, run_strategy_sweep,
+    run_sweep, top_n_diverse, AggregatedPortfolioResult, ConfigId, CostSensitivity,
+    MultiStrategyGrid, MultiStrategySweepResult, MultiSweepResult, NeighborSensitivity, RankMetric,
+    RankWeights, ResultPaths, RunManifest, StrategyBestResult, StrategyComparisonEntry,
+    StrategyConfigId, StrategyGridConfig, StrategyParams, StrategyTypeId, SweepConfig,
+    SweepConfigResult, SweepDepth, SweepGrid, SweepResult,
 };
 pub use sweep_polars::{
     analyze_sweep, compare_strategies, enrich_with_sector, multi_sweep_to_dataframe,
@@ -175,9 +212,9 @@ pub use sweep_polars::{
 };
 pub use universe::{Sector, Universe, UniverseError};
 pub use validation::{
-    generate_ts_cv_splits, generate_walk_forward_folds, slice_by_index, train_test_split_by_date,
-    CVSplit, FoldResult, TimeSeriesCVConfig, ValidationError, WalkForwardConfig, WalkForwardFold,
-    WalkForwardResult,
+    cpcv_path_count, generate_cpcv, generate_ts_cv_splits, generate_walk_forward_folds,
+    slice_by_index, train_test_split_by_date, CVSplit, FoldResult, TimeSeriesCVConfig,
+    ValidationError, WalkForwardConfig, WalkForwardFold, WalkForwardResult,
 };
 
 use std::path::{Path, PathBuf};
@@ -221,8 +258,8 @@ pub fn artifacts_dir() -> PathBuf {
 /// Re-export commonly used types
 pub mod prelude {
     pub use crate::backtest::{
-        run_backtest_with_pyramid, BacktestConfig, BacktestResult, CostModel, FillModel,
-        PyramidConfig, PyramidTrade,
+        run_backtest_with_pyramid, run_backtest_with_pyramid_sizer, BacktestConfig, BacktestResult,
+        CostModel, FillModel, PyramidConfig, PyramidTrade,
     };
     pub use crate::bar::Bar;
     pub use crate::data::{DataQualityChecker, DataQualityReport};
@@ -234,7 +271,7 @@ pub mod prelude {
         BollingerBands, DarvasBox, DonchianChannel, HABar, HighProximity, KeltnerChannel, MAType,
         STARCBands, SupertrendValue, DMI,
     };
-    pub use crate::sizing::{FixedSizer, PositionSizer, VolatilitySizer};
+    pub use crate::sizing::{DrawdownSizer, FixedSizer, PositionSizer, VolatilitySizer};
     pub use crate::strategy::{
         AroonCrossStrategy, BollingerSqueezeStrategy, CCIStrategy, DarvasBoxStrategy,
         DmiAdxStrategy, DonchianBreakoutStrategy, FiftyTwoWeekHighStrategy,
@@ -248,8 +285,9 @@ pub mod prelude {
     };
     pub use crate::sweep::{
         create_strategy_from_config, run_strategy_sweep, run_sweep, ConfigId, MultiStrategyGrid,
-        MultiStrategySweepResult, RankMetric, StrategyBestResult, StrategyComparisonEntry,
-        StrategyConfigId, StrategyGridConfig, StrategyTypeId, SweepConfig, SweepGrid, SweepResult,
+        MultiStrategySweepResult, RankMetric, RankWeights, StrategyBestResult,
+        StrategyComparisonEntry, StrategyConfigId, StrategyGridConfig, StrategyTypeId, SweepConfig,
+        SweepGrid, SweepResult,
     };
     pub use crate::universe::{Sector, Universe};
 }
@@ -1,7 +1,7 @@
 //! Performance metrics calculations.
 
-use crate::backtest::{BacktestResult, Trade};
-use serde::{Deserialize, Deserializer, Serialize};
+use crate::backtest::{BacktestResult, EquityPoint, Trade};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Deserialize a field that may be null as the default value.
 fn deserialize_null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
@@ -13,6 +13,71 @@ where
     Ok(opt.unwrap_or_default())
 }
 
+/// Serialize a ratio that may be non-finite.
+///
+/// JSON has no infinity or NaN, so those are written as strings ("inf",
+/// "-inf", "NaN") rather than collapsing to `null`.
+fn serialize_ratio<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    if value.is_finite() {
+        serializer.serialize_f64(*value)
+    } else {
+        serializer.serialize_str(&value.to_string())
+    }
+}
+
+/// Deserialize a ratio written by [`serialize_ratio`]; null reads as 0.0.
+fn deserialize_ratio<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Ratio {
+        Number(f64),
+        Text(String),
+    }
+
+    match Option::<Ratio>::deserialize(deserializer)? {
+        None => Ok(0.0),
+        Some(Ratio::Number(v)) => Ok(v),
+        Some(Ratio::Text(s)) => s.parse().map_err(D::Error::custom),
+    }
+}
+
+/// Trading days per year used to annualize daily returns.
+pub const TRADING_DAYS_PER_YEAR: f64 = 252.0;
+
+/// Settings for [`compute_metrics_with_config`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    /// Bars per year used to annualize per-bar returns (252 daily, 52 weekly, 12 monthly).
+    pub periods_per_year: f64,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self::daily()
+    }
+}
+
+impl MetricsConfig {
+    pub fn new(periods_per_year: f64) -> Self {
+        Self { periods_per_year }
+    }
+
+    /// Daily bars (252 trading days).
+    pub fn daily() -> Self {
+        Self::new(TRADING_DAYS_PER_YEAR)
+    }
+
+    /// Weekly bars, e.g. from `resample(.., ResamplePeriod::Weekly)`.
+    pub fn weekly() -> Self {
+        Self::new(52.0)
+    }
+
+    /// Monthly bars, e.g. from `resample(.., ResamplePeriod::Monthly)`.
+    pub fn monthly() -> Self {
+        Self::new(12.0)
+    }
+}
+
 /// Performance metrics for a backtest run.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
@@ -25,7 +90,7 @@ pub struct Metrics {
     #[serde(default, deserialize_with = "deserialize_null_as_default")]
     pub cagr: f64,
 
-    /// Annualized Sharpe ratio (assuming 252 trading days)
+    /// Annualized Sharpe ratio (`MetricsConfig::periods_per_year`, 252 by default)
     #[serde(default, deserialize_with = "deserialize_null_as_default")]
     pub sharpe: f64,
 
@@ -68,10 +133,35 @@ pub struct Metrics {
     /// Average length of losing streaks
     #[serde(default, deserialize_with = "deserialize_null_as_default")]
     pub avg_losing_streak: f64,
+
+    /// Ulcer Index: RMS of drawdowns from the running peak (as a decimal)
+    #[serde(default, deserialize_with = "deserialize_null_as_default")]
+    pub ulcer_index: f64,
+
+    /// Ulcer Performance Index (CAGR / Ulcer Index, risk-free rate of 0).
+    /// Infinite when there is no drawdown.
+    #[serde(
+        default,
+        serialize_with = "serialize_ratio",
+        deserialize_with = "deserialize_ratio"
+    )]
+    pub upi: f64,
 }
 
-/// Compute all metrics from a BacktestResult.
+/// Compute all metrics from a BacktestResult, annualizing as daily bars.
 pub fn compute_metrics(result: &BacktestResult, initial_cash: f64) -> Metrics {
+    compute_metrics_with_config(result, initial_cash, &MetricsConfig::default())
+}
+
+/// Compute all metrics, annualizing per-bar returns with `config.periods_per_year`.
+///
+/// CAGR and turnover are measured against calendar time between the first and
+/// last equity timestamps, so they need no bar-frequency adjustment.
+pub fn compute_metrics_with_config(
+    result: &BacktestResult,
+    initial_cash: f64,
+    config: &MetricsConfig,
+) -> Metrics {
     if result.equity.is_empty() {
         return Metrics::default();
     }
@@ -109,7 +199,7 @@ pub fn compute_metrics(result: &BacktestResult, initial_cash: f64) -> Metrics {
         0.0
     };
 
-    // Daily returns for Sharpe calculation
+    // Per-bar returns for Sharpe calculation
     // Guard against division by zero if equity ever hits zero
     let daily_returns: Vec<f64> = equity_curve
         .windows(2)
@@ -122,8 +212,8 @@ pub fn compute_metrics(result: &BacktestResult, initial_cash: f64) -> Metrics {
         })
         .collect();
 
-    let sharpe = calculate_sharpe(&daily_returns);
-    let sortino = calculate_sortino(&daily_returns);
+    let sharpe = calculate_sharpe_annualized(&daily_returns, config.periods_per_year);
+    let sortino = calculate_sortino_annualized(&daily_returns, config.periods_per_year);
 
     // Trade-based metrics
     let num_trades = result.trades.len() as u32;
@@ -169,6 +259,10 @@ pub fn compute_metrics(result: &BacktestResult, initial_cash: f64) -> Metrics {
     let (max_consecutive_losses, max_consecutive_wins, avg_losing_streak) =
         calculate_streaks(&result.trades);
 
+    // Ulcer-based downside risk
+    let ulcer_index = calculate_ulcer_index(&equity_curve);
+    let upi = calculate_upi(cagr, 0.0, ulcer_index);
+
     Metrics {
         total_return,
         cagr,
@@ -183,6 +277,97 @@ pub fn compute_metrics(result: &BacktestResult, initial_cash: f64) -> Metrics {
         max_consecutive_losses,
         max_consecutive_wins,
         avg_losing_streak,
+        ulcer_index,
+        upi,
+    }
+}
+
+/// Performance of a strategy measured against a benchmark equity curve.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct RelativeMetrics {
+    /// Sensitivity of strategy returns to benchmark returns (cov / var)
+    pub beta: f64,
+    /// Annualized Jensen's alpha (excess return not explained by beta)
+    pub alpha: f64,
+    /// Annualized active return divided by tracking error (0 when undefined)
+    pub information_ratio: f64,
+    /// Annualized standard deviation of active returns
+    pub tracking_error: f64,
+    /// Number of aligned return observations used
+    pub n_observations: usize,
+}
+
+/// Compute benchmark-relative metrics (beta, alpha, IR, tracking error).
+///
+/// The strategy and benchmark equity curves are aligned by timestamp; bars
+/// present in only one series are dropped. Returns are computed between
+/// consecutive aligned bars and annualized assuming 252 trading days,
+/// consistent with `calculate_sharpe`.
+pub fn compute_relative_metrics(
+    result: &BacktestResult,
+    benchmark: &[EquityPoint],
+) -> RelativeMetrics {
+    let benchmark_by_ts: std::collections::HashMap<_, f64> =
+        benchmark.iter().map(|p| (p.ts, p.equity)).collect();
+
+    let aligned: Vec<(f64, f64)> = result
+        .equity
+        .iter()
+        .filter_map(|p| benchmark_by_ts.get(&p.ts).map(|&b| (p.equity, b)))
+        .collect();
+
+    let (strat_returns, bench_returns): (Vec<f64>, Vec<f64>) = aligned
+        .windows(2)
+        .filter(|w| w[0].0.abs() > 1e-10 && w[0].1.abs() > 1e-10)
+        .map(|w| ((w[1].0 - w[0].0) / w[0].0, (w[1].1 - w[0].1) / w[0].1))
+        .unzip();
+
+    if strat_returns.is_empty() {
+        return RelativeMetrics::default();
+    }
+
+    let n = strat_returns.len() as f64;
+    let mean_s = strat_returns.iter().sum::<f64>() / n;
+    let mean_b = bench_returns.iter().sum::<f64>() / n;
+
+    let mut cov = 0.0;
+    let mut var_b = 0.0;
+    for (s, b) in strat_returns.iter().zip(&bench_returns) {
+        cov += (s - mean_s) * (b - mean_b);
+        var_b += (b - mean_b).powi(2);
+    }
+    cov /= n;
+    var_b /= n;
+
+    let beta = if var_b > 0.0 { cov / var_b } else { 0.0 };
+    let alpha = (mean_s - beta * mean_b) * 252.0;
+
+    let active: Vec<f64> = strat_returns
+        .iter()
+        .zip(&bench_returns)
+        .map(|(s, b)| s - b)
+        .collect();
+    let mean_active = active.iter().sum::<f64>() / n;
+    let active_var = active
+        .iter()
+        .map(|a| (a - mean_active).powi(2))
+        .sum::<f64>()
+        / n;
+    let tracking_error = active_var.sqrt() * 252.0_f64.sqrt();
+
+    // Identical return streams have zero tracking error; IR is undefined there
+    let information_ratio = if tracking_error > 1e-12 {
+        (mean_active * 252.0) / tracking_error
+    } else {
+        0.0
+    };
+
+    RelativeMetrics {
+        beta,
+        alpha,
+        information_ratio,
+        tracking_error,
+        n_observations: strat_returns.len(),
     }
 }
 
@@ -198,40 +383,48 @@ pub fn calculate_cagr(initial: f64, final_value: f64, years: f64) -> f64 {
 ///
 /// Assumes 252 trading days per year and risk-free rate of 0.
 pub fn calculate_sharpe(daily_returns: &[f64]) -> f64 {
-    if daily_returns.is_empty() {
+    calculate_sharpe_annualized(daily_returns, TRADING_DAYS_PER_YEAR)
+}
+
+/// Calculate Sharpe ratio from per-bar returns, annualized with `periods_per_year`.
+///
+/// Risk-free rate of 0.
+pub fn calculate_sharpe_annualized(returns: &[f64], periods_per_year: f64) -> f64 {
+    if returns.is_empty() {
         return 0.0;
     }
 
-    let n = daily_returns.len() as f64;
-    let mean = daily_returns.iter().sum::<f64>() / n;
-    let variance = daily_returns
-        .iter()
-        .map(|r| (r - mean).powi(2))
-        .sum::<f64>()
-        / n;
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / n;
     let std_dev = variance.sqrt();
 
     if std_dev == 0.0 {
         return 0.0;
     }
 
-    // Annualize: multiply mean by 252, std by sqrt(252)
-    (mean * 252.0) / (std_dev * 252.0_f64.sqrt())
+    // Annualize: multiply mean by N, std by sqrt(N)
+    (mean * periods_per_year) / (std_dev * periods_per_year.sqrt())
 }
 
 /// Calculate annualized Sortino ratio from daily returns.
 ///
 /// Like Sharpe but only penalizes downside volatility.
 pub fn calculate_sortino(daily_returns: &[f64]) -> f64 {
-    if daily_returns.is_empty() {
+    calculate_sortino_annualized(daily_returns, TRADING_DAYS_PER_YEAR)
+}
+
+/// Calculate Sortino ratio from per-bar returns, annualized with `periods_per_year`.
+pub fn calculate_sortino_annualized(returns: &[f64], periods_per_year: f64) -> f64 {
+    if returns.is_empty() {
         return 0.0;
     }
 
-    let n = daily_returns.len() as f64;
-    let mean = daily_returns.iter().sum::<f64>() / n;
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
 
     // Downside deviation: only consider returns below zero
-    let downside_variance = daily_returns
+    let downside_variance = returns
         .iter()
         .map(|r| if *r < 0.0 { r.powi(2) } else { 0.0 })
         .sum::<f64>()
@@ -243,7 +436,7 @@ pub fn calculate_sortino(daily_returns: &[f64]) -> f64 {
     }
 
     // Annualize
-    (mean * 252.0) / (downside_dev * 252.0_f64.sqrt())
+    (mean * periods_per_year) / (downside_dev * periods_per_year.sqrt())
 }
 
 /// Calculate maximum drawdown from an equity curve.
@@ -273,6 +466,51 @@ pub fn calculate_max_drawdown(equity_curve: &[f64]) -> f64 {
     max_dd
 }
 
+/// Calculate the Ulcer Index from an equity curve.
+///
+/// The Ulcer Index is the root-mean-square of the percentage drawdown from the
+/// running peak at every point of the curve (returned as a decimal, like
+/// `calculate_max_drawdown`). Unlike max drawdown it penalises both the depth
+/// and the duration of drawdowns. A curve that never falls below its peak has
+/// an Ulcer Index of 0.
+pub fn calculate_ulcer_index(equity_curve: &[f64]) -> f64 {
+    if equity_curve.is_empty() {
+        return 0.0;
+    }
+
+    let mut peak = equity_curve[0];
+    let mut sum_sq = 0.0;
+
+    for &equity in equity_curve {
+        if equity > peak {
+            peak = equity;
+        }
+        let dd = if peak.abs() > 1e-10 {
+            (peak - equity) / peak
+        } else {
+            0.0
+        };
+        sum_sq += dd * dd;
+    }
+
+    (sum_sq / equity_curve.len() as f64).sqrt()
+}
+
+/// Calculate the Ulcer Performance Index (Martin ratio).
+///
+/// UPI = (CAGR - risk_free) / Ulcer Index. With no drawdown (Ulcer Index 0)
+/// the ratio is `f64::INFINITY`, or NaN if CAGR does not beat the risk-free
+/// rate.
+pub fn calculate_upi(cagr: f64, risk_free: f64, ulcer_index: f64) -> f64 {
+    if ulcer_index > 0.0 {
+        (cagr - risk_free) / ulcer_index
+    } else if cagr > risk_free {
+        f64::INFINITY
+    } else {
+        f64::NAN
+    }
+}
+
 /// Calculate consecutive win/loss streaks from trades.
 ///
 /// Returns: (max_consecutive_losses, max_consecutive_wins, avg_losing_streak)
@@ -330,8 +568,8 @@ pub fn calculate_streaks(trades: &[Trade]) -> (u32, u32, f64) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::backtest::{Fill, Side, TradeDirection};
-    use chrono::Utc;
+    use crate::backtest::{ExitReason, Fill, Side, TradeDirection};
+    use chrono::{TimeZone, Utc};
 
     /// Helper to create a trade with just net_pnl (for streak testing)
     fn make_trade(net_pnl: f64) -> Trade {
@@ -350,6 +588,7 @@ mod tests {
             gross_pnl: net_pnl,
             net_pnl,
             direction: TradeDirection::Long,
+            exit_reason: ExitReason::Signal,
         }
     }
 
@@ -368,6 +607,37 @@ mod tests {
         assert!((dd - 0.25).abs() < 0.001);
     }
 
+    #[test]
+    fn test_ulcer_index_rising_curve_is_zero() {
+        let equity = vec![100.0, 101.0, 103.0, 103.0, 110.0];
+        assert_eq!(calculate_ulcer_index(&equity), 0.0);
+        assert!(calculate_upi(0.12, 0.0, 0.0).is_infinite());
+        assert!(calculate_upi(0.0, 0.02, 0.0).is_nan());
+
+        let result = BacktestResult {
+            equity: make_equity(&equity, 0),
+            ..Default::default()
+        };
+        let metrics = compute_metrics(&result, 100.0);
+        assert_eq!(metrics.ulcer_index, 0.0);
+        assert!(metrics.upi.is_infinite());
+
+        // Infinity survives a JSON round trip instead of becoming null
+        let json = serde_json::to_string(&metrics).unwrap();
+        assert!(json.contains(r#""upi":"inf""#));
+        let restored: Metrics = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.upi, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_ulcer_index_single_drawdown() {
+        // Peak 100, one 10% drawdown bar, then recovery: sqrt(0.1² / 4) = 0.05
+        let equity = vec![100.0, 90.0, 100.0, 105.0];
+        let ui = calculate_ulcer_index(&equity);
+        assert!((ui - 0.05).abs() < 1e-12);
+        assert!((calculate_upi(0.10, 0.02, ui) - 1.6).abs() < 1e-12);
+    }
+
     #[test]
     fn test_streaks_empty() {
         let trades: Vec<Trade> = vec![];
@@ -444,4 +714,85 @@ mod tests {
         assert_eq!(max_losses, 2); // Breakeven + loss = 2 streak
         assert_eq!(max_wins, 1);
     }
+
+    fn make_equity(values: &[f64], start_day: i64) -> Vec<EquityPoint> {
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &equity)| EquityPoint {
+                ts: base + chrono::Duration::days(start_day + i as i64),
+                cash: equity,
+                position_qty: 0.0,
+                close: equity,
+                equity,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_relative_metrics_identical_to_benchmark() {
+        let curve = [100.0, 101.0, 99.5, 102.0, 103.5, 101.0, 104.0];
+        let result = BacktestResult {
+            equity: make_equity(&curve, 0),
+            ..Default::default()
+        };
+        let benchmark = make_equity(&curve, 0);
+
+        let rel = compute_relative_metrics(&result, &benchmark);
+        assert_eq!(rel.n_observations, 6);
+        assert!((rel.beta - 1.0).abs() < 1e-9);
+        assert!(rel.alpha.abs() < 1e-9);
+        assert!(rel.tracking_error.abs() < 1e-9);
+        assert_eq!(rel.information_ratio, 0.0);
+    }
+
+    #[test]
+    fn test_relative_metrics_drops_unaligned_bars() {
+        // Benchmark starts two days later, so only the overlap is used
+        let result = BacktestResult {
+            equity: make_equity(&[100.0, 105.0, 110.0, 121.0, 133.1], 0),
+            ..Default::default()
+        };
+        let benchmark = make_equity(&[50.0, 55.0, 60.5, 70.0], 2);
+
+        let rel = compute_relative_metrics(&result, &benchmark);
+        assert_eq!(rel.n_observations, 2);
+        // Strategy returns (10%, 10%) vs benchmark (10%, 10%): no active return
+        assert!(rel.tracking_error.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_weekly_sharpe_uses_periods_per_year() {
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let curve = [100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 108.0, 107.0];
+        let result = BacktestResult {
+            equity: curve
+                .iter()
+                .enumerate()
+                .map(|(i, &equity)| EquityPoint {
+                    ts: base + chrono::Duration::weeks(i as i64),
+                    cash: equity,
+                    position_qty: 0.0,
+                    close: equity,
+                    equity,
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        // Manual annualization of weekly returns: mean * 52 / (std * sqrt(52))
+        let returns: Vec<f64> = curve.windows(2).map(|w| w[1] / w[0] - 1.0).collect();
+        let n = returns.len() as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let std = (returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / n).sqrt();
+        let expected = mean * 52.0 / (std * 52.0_f64.sqrt());
+
+        let weekly = compute_metrics_with_config(&result, 100.0, &MetricsConfig::weekly());
+        assert!((weekly.sharpe - expected).abs() < 1e-9);
+
+        // The daily default overstates it by sqrt(252 / 52)
+        let daily = compute_metrics(&result, 100.0);
+        assert!((daily.sharpe / weekly.sharpe - (252.0_f64 / 52.0).sqrt()).abs() < 1e-9);
+    }
 }
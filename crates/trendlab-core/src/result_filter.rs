@@ -0,0 +1,487 @@
+//! Small expression language for filtering sweep results by metric values.
+//!
+//! Lets callers express ad-hoc filters such as
+//! `sharpe > 1 and max_drawdown < 0.2 and num_trades >= 30` without
+//! hardcoding every combination.
+//!
+//! Grammar (keywords are case-insensitive):
+//! ```text
+//! expr       := and_expr ("or" and_expr)*
+//! and_expr   := unary ("and" unary)*
+//! unary      := "not" unary | "(" expr ")" | comparison
+//! comparison := field op number
+//! op         := ">" | ">=" | "<" | "<=" | "==" | "!="
+//! ```
+
+use crate::metrics::Metrics;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Errors produced while parsing a filter expression.
+#[derive(Debug, Error, PartialEq)]
+pub enum FilterError {
+    #[error("Unknown metric '{0}'")]
+    UnknownField(String),
+
+    #[error("Invalid number '{0}'")]
+    InvalidNumber(String),
+
+    #[error("Unexpected character '{0}' at position {1}")]
+    UnexpectedChar(char, usize),
+
+    #[error("Expected {expected}, found {found}")]
+    UnexpectedToken { expected: String, found: String },
+
+    #[error("Empty filter expression")]
+    Empty,
+}
+
+/// Metric fields that can be referenced in a filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MetricField {
+    TotalReturn,
+    Cagr,
+    Sharpe,
+    Sortino,
+    MaxDrawdown,
+    Calmar,
+    WinRate,
+    ProfitFactor,
+    NumTrades,
+    Turnover,
+    MaxConsecutiveLosses,
+    MaxConsecutiveWins,
+    AvgLosingStreak,
+    UlcerIndex,
+    Upi,
+}
+
+impl MetricField {
+    /// All filterable fields.
+    pub fn all() -> &'static [MetricField] {
+        &[
+            Self::TotalReturn,
+            Self::Cagr,
+            Self::Sharpe,
+            Self::Sortino,
+            Self::MaxDrawdown,
+            Self::Calmar,
+            Self::WinRate,
+            Self::ProfitFactor,
+            Self::NumTrades,
+            Self::Turnover,
+            Self::MaxConsecutiveLosses,
+            Self::MaxConsecutiveWins,
+            Self::AvgLosingStreak,
+            Self::UlcerIndex,
+            Self::Upi,
+        ]
+    }
+
+    /// Field name as written in filter expressions (matches `Metrics` field names).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TotalReturn => "total_return",
+            Self::Cagr => "cagr",
+            Self::Sharpe => "sharpe",
+            Self::Sortino => "sortino",
+            Self::MaxDrawdown => "max_drawdown",
+            Self::Calmar => "calmar",
+            Self::WinRate => "win_rate",
+            Self::ProfitFactor => "profit_factor",
+            Self::NumTrades => "num_trades",
+            Self::Turnover => "turnover",
+            Self::MaxConsecutiveLosses => "max_consecutive_losses",
+            Self::MaxConsecutiveWins => "max_consecutive_wins",
+            Self::AvgLosingStreak => "avg_losing_streak",
+            Self::UlcerIndex => "ulcer_index",
+            Self::Upi => "upi",
+        }
+    }
+
+    /// Look up a field by name (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|f| f.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Extract this field's value from a `Metrics` instance.
+    pub fn extract(&self, m: &Metrics) -> f64 {
+        match self {
+            Self::TotalReturn => m.total_return,
+            Self::Cagr => m.cagr,
+            Self::Sharpe => m.sharpe,
+            Self::Sortino => m.sortino,
+            Self::MaxDrawdown => m.max_drawdown,
+            Self::Calmar => m.calmar,
+            Self::WinRate => m.win_rate,
+            Self::ProfitFactor => m.profit_factor,
+            Self::NumTrades => m.num_trades as f64,
+            Self::Turnover => m.turnover,
+            Self::MaxConsecutiveLosses => m.max_consecutive_losses as f64,
+            Self::MaxConsecutiveWins => m.max_consecutive_wins as f64,
+            Self::AvgLosingStreak => m.avg_losing_streak,
+            Self::UlcerIndex => m.ulcer_index,
+            Self::Upi => m.upi,
+        }
+    }
+}
+
+/// Comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompareOp {
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Eq,
+    Ne,
+}
+
+impl CompareOp {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Eq => "==",
+            Self::Ne => "!=",
+        }
+    }
+
+    fn apply(&self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Self::Gt => lhs > rhs,
+            Self::Ge => lhs >= rhs,
+            Self::Lt => lhs < rhs,
+            Self::Le => lhs <= rhs,
+            Self::Eq => (lhs - rhs).abs() < 1e-12,
+            Self::Ne => (lhs - rhs).abs() >= 1e-12,
+        }
+    }
+}
+
+/// Parsed filter expression tree.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FilterExpr {
+    Compare {
+        field: MetricField,
+        op: CompareOp,
+        value: f64,
+    },
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    Or(Box<FilterExpr>, Box<FilterExpr>),
+    Not(Box<FilterExpr>),
+}
+
+impl FilterExpr {
+    /// Evaluate the expression against a set of metrics.
+    ///
+    /// NaN metric values never satisfy a comparison.
+    pub fn matches(&self, m: &Metrics) -> bool {
+        match self {
+            Self::Compare { field, op, value } => {
+                let lhs = field.extract(m);
+                !lhs.is_nan() && op.apply(lhs, *value)
+            }
+            Self::And(a, b) => a.matches(m) && b.matches(m),
+            Self::Or(a, b) => a.matches(m) || b.matches(m),
+            Self::Not(inner) => !inner.matches(m),
+        }
+    }
+}
+
+impl fmt::Display for FilterExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Compare { field, op, value } => {
+                write!(f, "{} {} {}", field.name(), op.symbol(), value)
+            }
+            Self::And(a, b) => write!(f, "({} and {})", a, b),
+            Self::Or(a, b) => write!(f, "({} or {})", a, b),
+            Self::Not(inner) => write!(f, "not {}", inner),
+        }
+    }
+}
+
+impl FromStr for FilterExpr {
+    type Err = FilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_filter(s)
+    }
+}
+
+/// Parse a filter expression string.
+pub fn parse_filter(input: &str) -> Result<FilterExpr, FilterError> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        return Err(FilterError::Empty);
+    }
+
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_or()?;
+    match parser.peek() {
+        None => Ok(expr),
+        Some(tok) => Err(FilterError::UnexpectedToken {
+            expected: "end of expression".to_string(),
+            found: tok.to_string(),
+        }),
+    }
+}
+
+// =============================================================================
+// Tokenizer
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f64),
+    Op(CompareOp),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Ident(s) => write!(f, "'{}'", s),
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Op(op) => write!(f, "'{}'", op.symbol()),
+            Token::And => write!(f, "'and'"),
+            Token::Or => write!(f, "'or'"),
+            Token::Not => write!(f, "'not'"),
+            Token::LParen => write!(f, "'('"),
+            Token::RParen => write!(f, "')'"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, FilterError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '>' | '<' | '=' | '!' => {
+                let next_is_eq = chars.get(i + 1) == Some(&'=');
+                let op = match (c, next_is_eq) {
+                    ('>', true) => CompareOp::Ge,
+                    ('>', false) => CompareOp::Gt,
+                    ('<', true) => CompareOp::Le,
+                    ('<', false) => CompareOp::Lt,
+                    ('=', true) => CompareOp::Eq,
+                    ('!', true) => CompareOp::Ne,
+                    _ => return Err(FilterError::UnexpectedChar(c, i)),
+                };
+                tokens.push(Token::Op(op));
+                i += if next_is_eq { 2 } else { 1 };
+            }
+            c if c.is_ascii_digit() || c == '.' || c == '-' || c == '+' => {
+                let start = i;
+                i += 1;
+                while i < chars.len()
+                    && (chars[i].is_ascii_digit()
+                        || chars[i] == '.'
+                        || chars[i] == 'e'
+                        || chars[i] == 'E'
+                        || ((chars[i] == '-' || chars[i] == '+')
+                            && matches!(chars[i - 1], 'e' | 'E')))
+                {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let value = text
+                    .parse::<f64>()
+                    .map_err(|_| FilterError::InvalidNumber(text.clone()))?;
+                tokens.push(Token::Number(value));
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let token = match word.to_ascii_lowercase().as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => Token::Ident(word),
+                };
+                tokens.push(token);
+            }
+            _ => return Err(FilterError::UnexpectedChar(c, i)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+// =============================================================================
+// Recursive-descent parser
+// =============================================================================
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        tok
+    }
+
+    fn unexpected(expected: &str, found: Option<Token>) -> FilterError {
+        FilterError::UnexpectedToken {
+            expected: expected.to_string(),
+            found: found
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "end of expression".to_string()),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<FilterExpr, FilterError> {
+        let mut lhs = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            let rhs = self.parse_and()?;
+            lhs = FilterExpr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<FilterExpr, FilterError> {
+        let mut lhs = self.parse_unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            let rhs = self.parse_unary()?;
+            lhs = FilterExpr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<FilterExpr, FilterError> {
+        match self.advance() {
+            Some(Token::Not) => Ok(FilterExpr::Not(Box::new(self.parse_unary()?))),
+            Some(Token::LParen) => {
+                let inner = self.parse_or()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(inner),
+                    other => Err(Self::unexpected("')'", other)),
+                }
+            }
+            Some(Token::Ident(name)) => {
+                let field = MetricField::from_name(&name).ok_or(FilterError::UnknownField(name))?;
+                let op = match self.advance() {
+                    Some(Token::Op(op)) => op,
+                    other => return Err(Self::unexpected("comparison operator", other)),
+                };
+                let value = match self.advance() {
+                    Some(Token::Number(v)) => v,
+                    other => return Err(Self::unexpected("number", other)),
+                };
+                Ok(FilterExpr::Compare { field, op, value })
+            }
+            other => Err(Self::unexpected("metric name, 'not' or '('", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(sharpe: f64, max_drawdown: f64, num_trades: u32) -> Metrics {
+        Metrics {
+            sharpe,
+            max_drawdown,
+            num_trades,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_compound_expression_includes_and_excludes() {
+        let filter: FilterExpr = "sharpe > 1 and max_drawdown < 0.2 and num_trades >= 30"
+            .parse()
+            .unwrap();
+
+        // Passes all three clauses
+        assert!(filter.matches(&metrics(1.4, 0.15, 30)));
+        // Sharpe too low
+        assert!(!filter.matches(&metrics(0.9, 0.15, 50)));
+        // Drawdown too deep
+        assert!(!filter.matches(&metrics(1.4, 0.25, 50)));
+        // Too few trades (boundary is inclusive)
+        assert!(!filter.matches(&metrics(1.4, 0.15, 29)));
+    }
+
+    #[test]
+    fn test_precedence_and_parentheses() {
+        // "and" binds tighter than "or"
+        let filter = parse_filter("sharpe > 2 or sharpe > 1 and num_trades > 10").unwrap();
+        assert!(filter.matches(&metrics(2.5, 0.0, 0)));
+        assert!(filter.matches(&metrics(1.5, 0.0, 20)));
+        assert!(!filter.matches(&metrics(1.5, 0.0, 5)));
+
+        let grouped = parse_filter("(sharpe > 2 or sharpe > 1) and num_trades > 10").unwrap();
+        assert!(!grouped.matches(&metrics(2.5, 0.0, 0)));
+
+        let negated = parse_filter("NOT (max_drawdown >= 0.3)").unwrap();
+        assert!(negated.matches(&metrics(0.0, 0.1, 0)));
+        assert!(!negated.matches(&metrics(0.0, 0.3, 0)));
+    }
+
+    #[test]
+    fn test_negative_numbers_and_equality() {
+        let filter = parse_filter("sharpe >= -0.5 and num_trades != 0").unwrap();
+        assert!(filter.matches(&metrics(-0.5, 0.0, 3)));
+        assert!(!filter.matches(&metrics(-0.6, 0.0, 3)));
+        assert!(!filter.matches(&metrics(1.0, 0.0, 0)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_filter("alpha > 1"),
+            Err(FilterError::UnknownField("alpha".to_string()))
+        );
+        assert_eq!(parse_filter("   "), Err(FilterError::Empty));
+        assert!(parse_filter("sharpe >").is_err());
+        assert!(parse_filter("sharpe > 1 and").is_err());
+        assert!(parse_filter("(sharpe > 1").is_err());
+        assert!(parse_filter("sharpe = 1").is_err());
+        assert!(parse_filter("sharpe > 1 cagr > 0").is_err());
+    }
+}
@@ -6,8 +6,10 @@
 //! - Intra-sector dispersion analysis
 //! - Top performers per sector
 //! - Cross-sector comparisons
+//! - Pairwise sector return correlations
 
 use polars::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Aggregate performance metrics by sector.
 ///
@@ -441,6 +443,104 @@ pub fn sector_summary_ranked(df: DataFrame) -> PolarsResult<DataFrame> {
         .collect()
 }
 
+/// Pairwise Pearson correlation matrix between return series.
+///
+/// Built from sector returns ([`correlation_matrix`]) or leaderboard equity
+/// curves ([`crate::leaderboard::equity_correlation_matrix`]).
+/// `labels[i]` names both row `i` and column `i` of `values`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CorrelationMatrix {
+    /// Series names (sectors, or leaderboard entries), in input order
+    pub labels: Vec<String>,
+    /// Square matrix of correlations (`values[i][j]` = corr(labels[i], labels[j])),
+    /// `None` where the correlation is undefined
+    pub values: Vec<Vec<Option<f64>>>,
+    /// Number of observations used after dropping missing data
+    pub n_observations: usize,
+}
+
+impl CorrelationMatrix {
+    /// Look up the correlation between two series by label.
+    ///
+    /// `None` if either label is unknown or the correlation is undefined.
+    pub fn get(&self, a: &str, b: &str) -> Option<f64> {
+        let i = self.labels.iter().position(|l| l == a)?;
+        let j = self.labels.iter().position(|l| l == b)?;
+        self.values[i][j]
+    }
+}
+
+/// Compute pairwise correlations between sector return series.
+///
+/// Expects a wide DataFrame with an optional `date` column and one numeric
+/// return column per sector. Dates where any sector is missing (null or NaN)
+/// are dropped so every pair is measured over the same overlapping range.
+///
+/// # Arguments
+/// * `sector_returns` - DataFrame with columns: [date], <sector_1>, <sector_2>, ...
+///
+/// # Returns
+/// `CorrelationMatrix` labeled by sector column name. Pairs where either
+/// series has zero variance have no correlation (diagonal is always 1.0).
+pub fn correlation_matrix(sector_returns: &DataFrame) -> PolarsResult<CorrelationMatrix> {
+    let mut labels = Vec::new();
+    let mut series: Vec<Vec<Option<f64>>> = Vec::new();
+
+    for column in sector_returns.get_columns() {
+        if column.name().as_str() == "date" {
+            continue;
+        }
+        let values = column.cast(&DataType::Float64)?;
+        labels.push(column.name().to_string());
+        series.push(values.f64()?.into_iter().collect());
+    }
+
+    // Keep only dates where every sector has a finite return
+    let rows: Vec<Vec<f64>> = (0..sector_returns.height())
+        .filter_map(|i| {
+            series
+                .iter()
+                .map(|s| s[i].filter(|v| v.is_finite()))
+                .collect::<Option<Vec<f64>>>()
+        })
+        .collect();
+
+    let k = labels.len();
+    let n = rows.len();
+    let mut values = vec![vec![None; k]; k];
+
+    let means: Vec<f64> = (0..k)
+        .map(|j| rows.iter().map(|r| r[j]).sum::<f64>() / n as f64)
+        .collect();
+
+    for a in 0..k {
+        values[a][a] = Some(1.0);
+        for b in (a + 1)..k {
+            let mut cov = 0.0;
+            let mut var_a = 0.0;
+            let mut var_b = 0.0;
+            for r in &rows {
+                let da = r[a] - means[a];
+                let db = r[b] - means[b];
+                cov += da * db;
+                var_a += da * da;
+                var_b += db * db;
+            }
+
+            let denom = (var_a * var_b).sqrt();
+            let corr = (n >= 2 && denom > 1e-15).then(|| (cov / denom).clamp(-1.0, 1.0));
+            values[a][b] = corr;
+            values[b][a] = corr;
+        }
+    }
+
+    Ok(CorrelationMatrix {
+        labels,
+        values,
+        n_observations: n,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -598,4 +698,61 @@ mod tests {
         assert!(result.column("sharpe_rank").is_ok());
         assert!(result.column("composite_rank").is_ok());
     }
+
+    #[test]
+    fn test_correlation_matrix_perfect_and_anti() {
+        // Tech and Comms move together; Utilities moves opposite.
+        // Row 2 has a missing Utilities value and should be dropped.
+        let df = DataFrame::new(vec![
+            Series::new("date".into(), vec![1i64, 2, 3, 4, 5, 6]).into(),
+            Series::new(
+                "Technology".into(),
+                vec![0.01, -0.02, 0.03, 0.015, -0.01, 0.02],
+            )
+            .into(),
+            Series::new(
+                "Communication".into(),
+                vec![0.02, -0.04, 0.06, 0.03, -0.02, 0.04],
+            )
+            .into(),
+            Series::new(
+                "Utilities".into(),
+                vec![
+                    Some(-0.01),
+                    Some(0.02),
+                    None,
+                    Some(-0.015),
+                    Some(0.01),
+                    Some(-0.02),
+                ],
+            )
+            .into(),
+        ])
+        .unwrap();
+
+        let matrix = correlation_matrix(&df).unwrap();
+
+        assert_eq!(
+            matrix.labels,
+            vec!["Technology", "Communication", "Utilities"]
+        );
+        assert_eq!(matrix.n_observations, 5);
+
+        let tech_comm = matrix.get("Technology", "Communication").unwrap();
+        let tech_util = matrix.get("Technology", "Utilities").unwrap();
+        let comm_util = matrix.get("Communication", "Utilities").unwrap();
+
+        assert!((tech_comm - 1.0).abs() < 1e-10);
+        assert!((tech_util + 1.0).abs() < 1e-10);
+        assert!((comm_util + 1.0).abs() < 1e-10);
+        assert!(!tech_util.is_nan());
+
+        // Symmetric with unit diagonal
+        for i in 0..3 {
+            assert!((matrix.values[i][i].unwrap() - 1.0).abs() < 1e-12);
+            for j in 0..3 {
+                assert_eq!(matrix.values[i][j], matrix.values[j][i]);
+            }
+        }
+    }
 }
@@ -3,6 +3,8 @@
 //! Position sizing determines how many units to trade based on various factors.
 //! The Turtle trading system pioneered volatility-based sizing where position
 //! size is inversely proportional to recent volatility (ATR).
+//! [`DrawdownSizer`] additionally tapers any sizer as the account's drawdown
+//! deepens.
 
 use crate::bar::Bar;
 use crate::indicators::{atr, atr_wilder};
@@ -25,6 +27,10 @@ pub struct SizingConfig {
     pub min_units: f64,
     /// Maximum position size in units.
     pub max_units: f64,
+    /// Current account equity (for drawdown-aware sizing).
+    pub current_equity: Option<f64>,
+    /// Peak account equity so far (for drawdown-aware sizing).
+    pub peak_equity: Option<f64>,
 }
 
 impl Default for SizingConfig {
@@ -32,6 +38,8 @@ impl Default for SizingConfig {
         Self {
             min_units: 1.0,
             max_units: f64::MAX,
+            current_equity: None,
+            peak_equity: None,
         }
     }
 }
@@ -41,6 +49,16 @@ impl SizingConfig {
     pub fn clamp(&self, units: f64) -> f64 {
         units.clamp(self.min_units, self.max_units)
     }
+
+    /// Current drawdown from peak as a fraction (0.1 = 10% below peak).
+    ///
+    /// `None` unless both equities are set and the peak is positive.
+    pub fn drawdown(&self) -> Option<f64> {
+        match (self.current_equity, self.peak_equity) {
+            (Some(equity), Some(peak)) if peak > 0.0 => Some((1.0 - equity / peak).max(0.0)),
+            _ => None,
+        }
+    }
 }
 
 /// Trait for position sizing strategies.
@@ -60,6 +78,11 @@ pub trait PositionSizer: Send + Sync {
 
     /// Returns a description of the sizing method.
     fn description(&self) -> String;
+
+    /// Record the account's mark-to-market equity at the close of each bar.
+    ///
+    /// Only equity-aware sizers use this; the default ignores it.
+    fn update_equity(&mut self, _equity: f64) {}
 }
 
 /// Fixed position sizing - always returns the same number of units.
@@ -227,6 +250,113 @@ impl PositionSizer for VolatilitySizer {
     }
 }
 
+/// Drawdown-constrained position sizing.
+///
+/// Scales the units of an inner sizer by a linear taper on the current
+/// drawdown: full size at 0% drawdown, no entries at `max_drawdown` and beyond.
+/// Units = Inner Units × (1 - Drawdown / Max Drawdown)
+///
+/// Equity is passed through the [`SizingConfig`], either up front with
+/// [`DrawdownSizer::with_equity`] or bar by bar via
+/// [`PositionSizer::update_equity`]; until it is set the inner size is
+/// returned unchanged.
+#[derive(Debug, Clone)]
+pub struct DrawdownSizer<S: PositionSizer> {
+    /// Sizer whose units are tapered.
+    inner: S,
+    /// Drawdown (fraction) at which size reaches zero.
+    max_drawdown: f64,
+    /// Current and peak equity.
+    config: SizingConfig,
+}
+
+impl<S: PositionSizer> DrawdownSizer<S> {
+    pub fn new(inner: S, max_drawdown: f64) -> Self {
+        assert!(
+            max_drawdown > 0.0 && max_drawdown <= 1.0,
+            "Max drawdown must be in (0, 1]"
+        );
+        Self {
+            inner,
+            max_drawdown,
+            config: SizingConfig::default(),
+        }
+    }
+
+    /// Set the current and peak equity.
+    pub fn with_equity(mut self, current: f64, peak: f64) -> Self {
+        self.config.current_equity = Some(current);
+        self.config.peak_equity = Some(peak);
+        self
+    }
+
+    /// Get the drawdown at which size reaches zero.
+    pub fn max_drawdown(&self) -> f64 {
+        self.max_drawdown
+    }
+
+    /// Fraction of the inner size currently allowed, in [0, 1].
+    pub fn scale(&self) -> f64 {
+        match self.config.drawdown() {
+            Some(dd) => (1.0 - dd / self.max_drawdown).clamp(0.0, 1.0),
+            None => 1.0,
+        }
+    }
+}
+
+impl<S: PositionSizer> PositionSizer for DrawdownSizer<S> {
+    fn size(&self, bars: &[Bar], price: f64) -> Option<SizeResult> {
+        let result = self.inner.size(bars, price)?;
+        let units = result.units * self.scale();
+        // Fully tapered: skip the entry rather than open a zero-size position
+        if units <= 0.0 {
+            return None;
+        }
+        Some(SizeResult { units, ..result })
+    }
+
+    fn warmup_period(&self) -> usize {
+        self.inner.warmup_period()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} (drawdown taper to {:.0}%)",
+            self.inner.description(),
+            self.max_drawdown * 100.0
+        )
+    }
+
+    /// Record the latest equity, raising the peak if it is a new high.
+    fn update_equity(&mut self, equity: f64) {
+        let peak = self.config.peak_equity.map_or(equity, |p| p.max(equity));
+        self.config.current_equity = Some(equity);
+        self.config.peak_equity = Some(peak);
+    }
+}
+
+/// Trailing volatility as ATR over `atr_period` bars divided by the last close.
+///
+/// The same ATR estimate [`VolatilitySizer`] uses, expressed as a fraction of
+/// price so instruments at different price levels compare directly. `None`
+/// during warmup or when the last close is not positive.
+pub fn atr_volatility(bars: &[Bar], atr_period: usize) -> Option<f64> {
+    if atr_period == 0 || bars.len() < atr_period {
+        return None;
+    }
+    // Only the trailing window (plus one bar for the previous close) matters
+    let tail = &bars[bars.len().saturating_sub(atr_period + 1)..];
+    let close = tail.last()?.close;
+    if close <= 0.0 {
+        return None;
+    }
+    atr(tail, atr_period)
+        .last()
+        .copied()
+        .flatten()
+        .map(|a| a / close)
+}
+
 /// Turtle-style position sizing using the "N" concept.
 ///
 /// The Turtles defined:
@@ -266,6 +396,27 @@ mod tests {
         assert!(result.atr.is_none());
     }
 
+    #[test]
+    fn drawdown_sizer_tapers_linearly() {
+        let bars = bars_from_ohlc(&[(100.0, 105.0, 95.0, 102.0)]);
+
+        // 10% drawdown with a 20% cap = half size
+        let sizer =
+            DrawdownSizer::new(FixedSizer::new(100.0), 0.20).with_equity(90_000.0, 100_000.0);
+        assert!((sizer.size(&bars, 100.0).unwrap().units - 50.0).abs() < 1e-9);
+
+        // At or beyond the cap there is no entry; no equity means full size
+        let sizer =
+            DrawdownSizer::new(FixedSizer::new(100.0), 0.20).with_equity(75_000.0, 100_000.0);
+        assert!(sizer.size(&bars, 100.0).is_none());
+
+        let mut sizer = DrawdownSizer::new(FixedSizer::new(100.0), 0.20);
+        assert_eq!(sizer.size(&bars, 100.0).unwrap().units, 100.0);
+        sizer.update_equity(100_000.0);
+        sizer.update_equity(95_000.0);
+        assert!((sizer.size(&bars, 100.0).unwrap().units - 75.0).abs() < 1e-9);
+    }
+
     #[test]
     fn volatility_sizer_inversely_proportional() {
         let sizer = VolatilitySizer::new(1000.0, 3);
@@ -356,4 +507,17 @@ mod tests {
         let sizer = VolatilitySizer::from_risk(100_000.0, 2.0, 14);
         assert_eq!(sizer.target_volatility(), 2000.0);
     }
+
+    #[test]
+    fn atr_volatility_is_relative_to_price() {
+        let bars = bars_from_ohlc(&[
+            (50.0, 51.25, 48.75, 50.0),
+            (50.0, 51.25, 48.75, 50.0),
+            (50.0, 51.25, 48.75, 50.0),
+        ]);
+        // ATR 2.5 on a 50 close = 5%
+        assert!((atr_volatility(&bars, 2).unwrap() - 0.05).abs() < 1e-12);
+        assert!(atr_volatility(&bars, 5).is_none());
+        assert!(atr_volatility(&bars, 0).is_none());
+    }
 }
@@ -2,15 +2,24 @@
 //!
 //! Provides rigorous statistical testing to guard against overfitting:
 //! - Bootstrap confidence intervals for performance metrics
-//! - Permutation tests for significance
+//! - Permutation tests for significance (group means and signal timing)
 //! - False Discovery Rate (FDR) correction for multiple comparisons
 //! - Standard errors and hypothesis testing
+//! - Probabilistic Sharpe Ratio for non-normal returns
+//! - White's Reality Check for data snooping across sweep configs
 
 use rand::prelude::*;
 use rand::rngs::SmallRng;
 use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
 use thiserror::Error;
 
+use crate::backtest::{run_backtest, BacktestConfig, BacktestResult};
+use crate::bar::Bar;
+use crate::metrics::calculate_sharpe;
+use crate::strategy::{Position, Signal, Strategy};
+use crate::sweep::SweepResult;
+
 /// Errors that can occur during statistical operations.
 #[derive(Debug, Error)]
 pub enum StatisticsError {
@@ -622,6 +631,200 @@ pub fn permutation_test(
     })
 }
 
+/// Sign-flip permutation test for a positive mean.
+///
+/// Under H0 the returns are symmetric around zero, so each sign is equally
+/// likely. Every permutation flips each sample's sign at random; the p-value is
+/// the share of permutations whose mean is at least the observed mean
+/// (one-sided: H1 mean > 0).
+///
+/// # Arguments
+/// * `samples` - Per-period returns
+/// * `n_permutations` - Number of random sign flips
+/// * `seed` - Random seed
+///
+/// # Returns
+/// Permutation test result with the observed mean and p-value
+pub fn sign_flip_test(
+    samples: &[f64],
+    n_permutations: usize,
+    seed: u64,
+) -> Result<PermutationResult, StatisticsError> {
+    if samples.is_empty() {
+        return Err(StatisticsError::InsufficientSamples {
+            needed: 1,
+            available: 0,
+        });
+    }
+
+    let n = samples.len() as f64;
+    let observed_statistic = samples.iter().sum::<f64>() / n;
+
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut n_extreme = 0;
+
+    for _ in 0..n_permutations {
+        let perm_mean = samples
+            .iter()
+            .map(|&x| if rng.gen::<bool>() { x } else { -x })
+            .sum::<f64>()
+            / n;
+
+        if perm_mean >= observed_statistic {
+            n_extreme += 1;
+        }
+    }
+
+    let p_value = (n_extreme + 1) as f64 / (n_permutations + 1) as f64;
+
+    Ok(PermutationResult {
+        observed_statistic,
+        p_value,
+        n_permutations,
+        n_extreme,
+    })
+}
+
+/// Permutation test on a strategy's signal timing.
+///
+/// Runs the strategy once to record the signal it emits on each bar, then
+/// shuffles those signals across bars (the price path stays fixed) and replays
+/// each shuffled sequence through the backtest. The statistic is the annualized
+/// Sharpe of the equity curve; the p-value is the share of shuffles whose Sharpe
+/// is at least the observed one (one-sided: does the timing have edge?).
+///
+/// Backtests use [`BacktestConfig::default`]. Warmup bars and the final bar,
+/// whose signal can never fill, are left out of the shuffle.
+///
+/// # Arguments
+/// * `bars` - Price history, held fixed across permutations
+/// * `strategy` - Strategy under test
+/// * `n_permutations` - Number of signal shuffles
+/// * `seed` - Random seed
+///
+/// # Returns
+/// Permutation test result with the observed Sharpe and p-value
+pub fn permutation_test_signals<S: Strategy + ?Sized>(
+    bars: &[Bar],
+    strategy: &mut S,
+    n_permutations: usize,
+    seed: u64,
+) -> Result<PermutationResult, StatisticsError> {
+    if bars.len() < 3 {
+        return Err(StatisticsError::InsufficientSamples {
+            needed: 3,
+            available: bars.len(),
+        });
+    }
+
+    let config = BacktestConfig::default();
+    let start = strategy.warmup_period().saturating_sub(1);
+
+    let mut recorder = SignalRecorder {
+        inner: strategy,
+        signals: Mutex::new(vec![Signal::Hold; bars.len()]),
+    };
+    let observed = run_backtest(bars, &mut recorder, config)
+        .map_err(|e| StatisticsError::ComputationError(e.to_string()))?;
+    let observed_statistic = calculate_sharpe(&equity_returns(&observed));
+
+    let mut replay = SignalReplay {
+        signals: recorder
+            .signals
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner()),
+    };
+    let shuffle_end = bars.len() - 1;
+
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut n_extreme = 0;
+
+    for _ in 0..n_permutations {
+        if start < shuffle_end {
+            replay.signals[start..shuffle_end].shuffle(&mut rng);
+        }
+
+        let result = run_backtest(bars, &mut replay, config)
+            .map_err(|e| StatisticsError::ComputationError(e.to_string()))?;
+        if calculate_sharpe(&equity_returns(&result)) >= observed_statistic {
+            n_extreme += 1;
+        }
+    }
+
+    let p_value = (n_extreme + 1) as f64 / (n_permutations + 1) as f64;
+
+    Ok(PermutationResult {
+        observed_statistic,
+        p_value,
+        n_permutations,
+        n_extreme,
+    })
+}
+
+/// Forwards to a strategy and records the signal it emits on each bar.
+struct SignalRecorder<'a, S: ?Sized> {
+    inner: &'a mut S,
+    signals: Mutex<Vec<Signal>>,
+}
+
+impl<S: Strategy + ?Sized> Strategy for SignalRecorder<'_, S> {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn warmup_period(&self) -> usize {
+        self.inner.warmup_period()
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        let signal = self.inner.signal(bars, current_position);
+        if let Some(slot) = self
+            .signals
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_mut(bars.len().saturating_sub(1))
+        {
+            *slot = signal;
+        }
+        signal
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    fn prepare(&mut self, bars: &[Bar]) {
+        self.inner.prepare(bars);
+    }
+}
+
+/// Replays a fixed per-bar signal sequence.
+///
+/// Signals that don't fit the current position (e.g. an exit while flat) are
+/// ignored by the backtest.
+struct SignalReplay {
+    signals: Vec<Signal>,
+}
+
+impl Strategy for SignalReplay {
+    fn id(&self) -> &str {
+        "signal_replay"
+    }
+
+    fn warmup_period(&self) -> usize {
+        0
+    }
+
+    fn signal(&self, bars: &[Bar], _current_position: Position) -> Signal {
+        self.signals
+            .get(bars.len().saturating_sub(1))
+            .copied()
+            .unwrap_or(Signal::Hold)
+    }
+
+    fn reset(&mut self) {}
+}
+
 // =============================================================================
 // P-Value Computation for OOS Sharpe Testing
 // =============================================================================
@@ -821,6 +1024,273 @@ fn ln_gamma(x: f64) -> f64 {
     (2.0 * std::f64::consts::PI).sqrt().ln() + (x + 0.5) * tmp.ln() - tmp + ag.ln()
 }
 
+// =============================================================================
+// Probabilistic Sharpe Ratio
+// =============================================================================
+
+/// Probabilistic Sharpe Ratio (Bailey & López de Prado, 2012).
+///
+/// Estimates the probability that the true Sharpe ratio exceeds
+/// `benchmark_sharpe`, given an observed Sharpe computed from `sample_len`
+/// returns. Skewed or fat-tailed returns widen the standard error of the
+/// estimate and pull the probability towards 0.5.
+///
+/// # Arguments
+/// * `observed_sharpe` - Per-period (non-annualized) observed Sharpe ratio
+/// * `benchmark_sharpe` - Per-period (non-annualized) benchmark Sharpe ratio
+/// * `sample_len` - Number of returns the observed Sharpe was computed from
+/// * `skew` - Skewness of the returns
+/// * `kurtosis` - Excess kurtosis of the returns (0 for normal), as reported
+///   by [`sample_statistics`]
+///
+/// # Returns
+/// Probability in [0, 1]
+pub fn probabilistic_sharpe(
+    observed_sharpe: f64,
+    benchmark_sharpe: f64,
+    sample_len: usize,
+    skew: f64,
+    kurtosis: f64,
+) -> f64 {
+    if sample_len < 2 || !observed_sharpe.is_finite() || !benchmark_sharpe.is_finite() {
+        return 0.5;
+    }
+
+    let sr = observed_sharpe;
+    // Variance of the Sharpe estimator uses raw kurtosis: (γ4 - 1) / 4 = (excess + 2) / 4
+    let variance = 1.0 - skew * sr + (kurtosis + 2.0) / 4.0 * sr * sr;
+    let std_error = variance.max(1e-10).sqrt();
+
+    let z = (sr - benchmark_sharpe) * ((sample_len - 1) as f64).sqrt() / std_error;
+    standard_normal_cdf(z).clamp(0.0, 1.0)
+}
+
+/// Compute the Probabilistic Sharpe Ratio for a backtest.
+///
+/// Per-bar returns are derived from the equity curve, and their Sharpe,
+/// skewness and kurtosis feed [`probabilistic_sharpe`].
+///
+/// # Arguments
+/// * `result` - Backtest result with an equity curve
+/// * `benchmark_sharpe` - Annualized benchmark Sharpe (252 trading days),
+///   comparable to `Metrics::sharpe`
+pub fn psr_from_result(
+    result: &BacktestResult,
+    benchmark_sharpe: f64,
+) -> Result<f64, StatisticsError> {
+    let returns = equity_returns(result);
+
+    if returns.len() < 3 {
+        return Err(StatisticsError::InsufficientSamples {
+            needed: 3,
+            available: returns.len(),
+        });
+    }
+
+    let stats = sample_statistics(&returns)?;
+    if stats.std < 1e-10 {
+        return Err(StatisticsError::ComputationError(
+            "Zero variance in equity returns".to_string(),
+        ));
+    }
+
+    let observed_sharpe = stats.mean / stats.std;
+    let benchmark_per_period = benchmark_sharpe / 252.0_f64.sqrt();
+
+    Ok(probabilistic_sharpe(
+        observed_sharpe,
+        benchmark_per_period,
+        stats.n,
+        stats.skewness,
+        stats.kurtosis,
+    ))
+}
+
+/// Per-bar simple returns from a backtest's equity curve.
+fn equity_returns(result: &BacktestResult) -> Vec<f64> {
+    result
+        .equity
+        .windows(2)
+        .map(|w| {
+            if w[0].equity.abs() > 1e-10 {
+                (w[1].equity - w[0].equity) / w[0].equity
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+// =============================================================================
+// White's Reality Check for Data Snooping
+// =============================================================================
+
+/// Result of White's Reality Check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RealityCheckResult {
+    /// Index of the config with the highest mean return
+    pub best_config_index: usize,
+    /// Mean per-period return of the best config
+    pub best_mean: f64,
+    /// Observed test statistic: max over configs of sqrt(n) * mean return
+    pub test_statistic: f64,
+    /// P-value for H0: the best config has no edge over a zero benchmark
+    pub p_value: f64,
+    /// Number of configs tested
+    pub n_configs: usize,
+    /// Number of return observations per config
+    pub n_observations: usize,
+    /// Number of bootstrap resamples used
+    pub n_bootstrap: usize,
+    /// Expected block length of the stationary bootstrap
+    pub expected_block_length: f64,
+}
+
+impl RealityCheckResult {
+    /// Check if significant at given alpha level.
+    pub fn is_significant(&self, alpha: f64) -> bool {
+        self.p_value < alpha
+    }
+
+    /// Check if significant at 5% level.
+    pub fn is_significant_05(&self) -> bool {
+        self.is_significant(0.05)
+    }
+}
+
+/// White's Reality Check (White, 2000) for the best of many configurations.
+///
+/// Picking the best of N configs on the same data inflates its apparent edge.
+/// The reality check bootstraps the distribution of the *maximum* mean return
+/// across all configs under the null of no edge, so the p-value accounts for
+/// the whole search rather than the winner alone.
+///
+/// The same stationary-bootstrap indices are applied to every config in each
+/// resample, preserving both autocorrelation and cross-config correlation.
+///
+/// # Arguments
+/// * `per_config_returns` - Time-aligned return series, one per config (equal lengths)
+/// * `n_bootstrap` - Number of bootstrap resamples
+/// * `seed` - Random seed
+///
+/// # Returns
+/// Reality check result with p-value for H0: max mean return <= 0
+pub fn reality_check(
+    per_config_returns: &[Vec<f64>],
+    n_bootstrap: usize,
+    seed: u64,
+) -> Result<RealityCheckResult, StatisticsError> {
+    if per_config_returns.is_empty() {
+        return Err(StatisticsError::InsufficientSamples {
+            needed: 1,
+            available: 0,
+        });
+    }
+    if n_bootstrap == 0 {
+        return Err(StatisticsError::InvalidParameter(
+            "n_bootstrap must be > 0".to_string(),
+        ));
+    }
+
+    let n = per_config_returns[0].len();
+    if n < 3 {
+        return Err(StatisticsError::InsufficientSamples {
+            needed: 3,
+            available: n,
+        });
+    }
+    if per_config_returns.iter().any(|r| r.len() != n) {
+        return Err(StatisticsError::InvalidParameter(
+            "All return series must have the same length".to_string(),
+        ));
+    }
+
+    let means: Vec<f64> = per_config_returns
+        .iter()
+        .map(|r| r.iter().sum::<f64>() / n as f64)
+        .collect();
+
+    let (best_config_index, best_mean) = means
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .unwrap_or((0, 0.0));
+
+    let sqrt_n = (n as f64).sqrt();
+    let test_statistic = sqrt_n * best_mean;
+
+    let expected_block_length = (n as f64).powf(1.0 / 3.0).max(2.0);
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut indices = Vec::with_capacity(n);
+    let mut n_extreme = 0;
+
+    for _ in 0..n_bootstrap {
+        indices.clear();
+        generate_stationary_indices(n, expected_block_length, &mut indices, &mut rng);
+
+        // Recenter each resampled mean on the observed mean to impose the null
+        let boot_max = per_config_returns
+            .iter()
+            .zip(&means)
+            .map(|(returns, &mean)| {
+                let boot_mean = indices.iter().map(|&i| returns[i]).sum::<f64>() / n as f64;
+                sqrt_n * (boot_mean - mean)
+            })
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        if boot_max >= test_statistic {
+            n_extreme += 1;
+        }
+    }
+
+    Ok(RealityCheckResult {
+        best_config_index,
+        best_mean,
+        test_statistic,
+        p_value: n_extreme as f64 / n_bootstrap as f64,
+        n_configs: per_config_returns.len(),
+        n_observations: n,
+        n_bootstrap,
+        expected_block_length,
+    })
+}
+
+/// Extract per-config equity return series from a sweep, in config order.
+///
+/// The result can be passed directly to [`reality_check`].
+pub fn sweep_return_series(sweep: &SweepResult) -> Vec<Vec<f64>> {
+    sweep
+        .config_results
+        .iter()
+        .map(|r| equity_returns(&r.backtest_result))
+        .collect()
+}
+
+/// Generate stationary bootstrap indices into a series of length `n`.
+///
+/// Same block structure as [`generate_stationary_sample`], but yields positions
+/// so one resample can be applied to several aligned series.
+fn generate_stationary_indices(
+    n: usize,
+    expected_block_length: f64,
+    indices: &mut Vec<usize>,
+    rng: &mut SmallRng,
+) {
+    let p_end = 1.0 / expected_block_length.max(1.0);
+    let mut pos = rng.gen_range(0..n);
+
+    while indices.len() < n {
+        indices.push(pos);
+
+        if rng.gen::<f64>() < p_end {
+            pos = rng.gen_range(0..n);
+        } else {
+            pos = (pos + 1) % n;
+        }
+    }
+}
+
 /// Result of multiple comparison adjustment.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultipleComparisonResult {
@@ -999,6 +1469,70 @@ pub fn holm_bonferroni(
     })
 }
 
+/// Apply Benjamini-Yekutieli FDR correction.
+///
+/// Like Benjamini-Hochberg, but valid under arbitrary dependence between tests
+/// (e.g., strategies run on the same symbols) at the cost of extra conservatism.
+pub fn benjamini_yekutieli(
+    p_values: &[f64],
+    alpha: f64,
+) -> Result<MultipleComparisonResult, StatisticsError> {
+    let bh = benjamini_hochberg(p_values, alpha)?;
+
+    // Harmonic correction factor c(m) = sum_{i=1}^{m} 1/i
+    let c_m: f64 = (1..=p_values.len()).map(|i| 1.0 / i as f64).sum();
+    let adjusted: Vec<f64> = bh
+        .adjusted_p_values
+        .iter()
+        .map(|&p| (p * c_m).min(1.0))
+        .collect();
+    let rejections: Vec<bool> = adjusted.iter().map(|&p| p < alpha).collect();
+    let n_rejections = rejections.iter().filter(|&&r| r).count();
+
+    Ok(MultipleComparisonResult {
+        original_p_values: p_values.to_vec(),
+        adjusted_p_values: adjusted,
+        rejections,
+        method: MultipleComparisonMethod::BenjaminiYekutieli,
+        alpha,
+        n_rejections,
+    })
+}
+
+/// Apply the given multiple comparison correction.
+///
+/// `MultipleComparisonMethod::None` leaves p-values unadjusted.
+pub fn adjust_p_values(
+    p_values: &[f64],
+    alpha: f64,
+    method: MultipleComparisonMethod,
+) -> Result<MultipleComparisonResult, StatisticsError> {
+    match method {
+        MultipleComparisonMethod::None => {
+            if p_values.is_empty() {
+                return Err(StatisticsError::InsufficientSamples {
+                    needed: 1,
+                    available: 0,
+                });
+            }
+            let rejections: Vec<bool> = p_values.iter().map(|&p| p < alpha).collect();
+            let n_rejections = rejections.iter().filter(|&&r| r).count();
+            Ok(MultipleComparisonResult {
+                original_p_values: p_values.to_vec(),
+                adjusted_p_values: p_values.to_vec(),
+                rejections,
+                method,
+                alpha,
+                n_rejections,
+            })
+        }
+        MultipleComparisonMethod::Bonferroni => bonferroni(p_values, alpha),
+        MultipleComparisonMethod::Holm => holm_bonferroni(p_values, alpha),
+        MultipleComparisonMethod::BenjaminiHochberg => benjamini_hochberg(p_values, alpha),
+        MultipleComparisonMethod::BenjaminiYekutieli => benjamini_yekutieli(p_values, alpha),
+    }
+}
+
 /// Summary statistics for a sample.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SampleStatistics {
@@ -1133,6 +1667,36 @@ impl ConfidenceGrade {
     }
 }
 
+/// Thresholds for assigning a [`ConfidenceGrade`].
+///
+/// The defaults reproduce the grading used by [`StrategyStatistics::from_returns`];
+/// stricter research standards can raise the bounds or lower `max_p_value`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceGradeConfig {
+    /// Minimum return observations for any grade other than Insufficient
+    pub min_samples: usize,
+    /// Observations needed to grade a non-positive Sharpe CI as Low (vs Insufficient)
+    pub min_samples_for_low: usize,
+    /// Sharpe CI lower bound above which the grade is High
+    pub high_ci_lower: f64,
+    /// Sharpe CI lower bound above which the grade is Medium
+    pub medium_ci_lower: f64,
+    /// FDR-adjusted p-values at or above this downgrade High/Medium by one step
+    pub max_p_value: f64,
+}
+
+impl Default for ConfidenceGradeConfig {
+    fn default() -> Self {
+        Self {
+            min_samples: 30,
+            min_samples_for_low: 252,
+            high_ci_lower: 0.5,
+            medium_ci_lower: 0.0,
+            max_p_value: 0.05,
+        }
+    }
+}
+
 /// Comprehensive statistical evaluation of a strategy.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StrategyStatistics {
@@ -1168,26 +1732,46 @@ impl StrategyStatistics {
         let return_stats = sample_statistics(returns)?;
         let sharpe_ci = bootstrap_sharpe(returns, 252.0, config)?;
 
+        let mut stats = Self {
+            sharpe_ci,
+            cagr_ci: None,
+            drawdown_ci: None,
+            fdr_adjusted: false,
+            fdr_p_value: None,
+            confidence_grade: ConfidenceGrade::Insufficient,
+            return_stats,
+        };
         // Determine confidence grade based on Sharpe CI
-        let confidence_grade = if sharpe_ci.ci_lower > 0.5 {
+        stats.confidence_grade = stats.grade_with(&ConfidenceGradeConfig::default());
+
+        Ok(stats)
+    }
+
+    /// Grade this strategy under the given thresholds.
+    ///
+    /// Uses the Sharpe CI lower bound and sample size, then downgrades one step
+    /// if an FDR-adjusted p-value is present and not below `max_p_value`.
+    pub fn grade_with(&self, config: &ConfidenceGradeConfig) -> ConfidenceGrade {
+        let n = self.return_stats.n;
+        let ci_lower = self.sharpe_ci.ci_lower;
+
+        let grade = if n < config.min_samples {
+            ConfidenceGrade::Insufficient
+        } else if ci_lower > config.high_ci_lower {
             ConfidenceGrade::High
-        } else if sharpe_ci.ci_lower > 0.0 {
+        } else if ci_lower > config.medium_ci_lower {
             ConfidenceGrade::Medium
-        } else if returns.len() >= 252 {
+        } else if n >= config.min_samples_for_low {
             ConfidenceGrade::Low
         } else {
             ConfidenceGrade::Insufficient
         };
 
-        Ok(Self {
-            sharpe_ci,
-            cagr_ci: None,
-            drawdown_ci: None,
-            fdr_adjusted: false,
-            fdr_p_value: None,
-            confidence_grade,
-            return_stats,
-        })
+        match (grade, self.fdr_p_value) {
+            (ConfidenceGrade::High, Some(p)) if p >= config.max_p_value => ConfidenceGrade::Medium,
+            (ConfidenceGrade::Medium, Some(p)) if p >= config.max_p_value => ConfidenceGrade::Low,
+            (grade, _) => grade,
+        }
     }
 
     /// Update with FDR-adjusted results.
@@ -1285,6 +1869,24 @@ mod tests {
         assert!(result.p_value > 0.01);
     }
 
+    #[test]
+    fn test_sign_flip_test() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let drifting: Vec<f64> = (0..200).map(|_| rng.gen_range(-0.01..0.012)).collect();
+        let result = sign_flip_test(&drifting, 1000, 42).unwrap();
+        assert!(result.observed_statistic > 0.0);
+        assert!(result.is_significant_05());
+
+        // Exact mirror images: no edge either way
+        let symmetric: Vec<f64> = drifting.iter().flat_map(|&x| [x, -x]).collect();
+        assert!(sign_flip_test(&symmetric, 1000, 42).unwrap().p_value > 0.2);
+
+        let losing: Vec<f64> = drifting.iter().map(|x| -x).collect();
+        assert!(sign_flip_test(&losing, 1000, 42).unwrap().p_value > 0.9);
+
+        assert!(sign_flip_test(&[], 10, 42).is_err());
+    }
+
     #[test]
     fn test_benjamini_hochberg() {
         // Mix of significant and non-significant p-values
@@ -1380,6 +1982,138 @@ mod tests {
         ));
     }
 
+    /// Price path of alternating 20-bar up/down regimes with deterministic wiggle.
+    fn regime_bars(n: usize) -> Vec<Bar> {
+        use chrono::TimeZone;
+
+        let start = chrono::Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let mut close = 100.0;
+        (0..n)
+            .map(|i| {
+                let open = close;
+                let drift = if regime_is_up(i) { 0.01 } else { -0.01 };
+                close *= 1.0 + drift + 0.004 * (i as f64 * 1.7).sin();
+                let ts = start + chrono::Duration::days(i as i64);
+                Bar::new(
+                    ts,
+                    open,
+                    open.max(close),
+                    open.min(close),
+                    close,
+                    0.0,
+                    "TEST",
+                    "1d",
+                )
+            })
+            .collect()
+    }
+
+    fn regime_is_up(i: usize) -> bool {
+        (i / 20).is_multiple_of(2)
+    }
+
+    /// Knows the regime of the next bar, so its timing is genuinely predictive.
+    struct RegimeOracle;
+
+    impl Strategy for RegimeOracle {
+        fn id(&self) -> &str {
+            "regime_oracle"
+        }
+
+        fn warmup_period(&self) -> usize {
+            0
+        }
+
+        fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+            let next_up = regime_is_up(bars.len());
+            match current_position {
+                Position::Flat if next_up => Signal::EnterLong,
+                Position::Long if !next_up => Signal::ExitLong,
+                _ => Signal::Hold,
+            }
+        }
+
+        fn reset(&mut self) {}
+    }
+
+    /// Flips in and out on a hash of the bar index, unrelated to price.
+    struct CoinFlip;
+
+    impl Strategy for CoinFlip {
+        fn id(&self) -> &str {
+            "coin_flip"
+        }
+
+        fn warmup_period(&self) -> usize {
+            0
+        }
+
+        fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+            let i = bars.len() - 1;
+            if (i * 104_729 + 13) % 31 >= 5 {
+                return Signal::Hold;
+            }
+            match current_position {
+                Position::Flat => Signal::EnterLong,
+                Position::Long => Signal::ExitLong,
+                Position::Short => Signal::Hold,
+            }
+        }
+
+        fn reset(&mut self) {}
+    }
+
+    #[test]
+    fn test_permutation_test_signals() {
+        let bars = regime_bars(300);
+
+        let predictive = permutation_test_signals(&bars, &mut RegimeOracle, 200, 42).unwrap();
+        assert!(predictive.observed_statistic > 5.0);
+        assert!(predictive.is_significant_05());
+
+        let random = permutation_test_signals(&bars, &mut CoinFlip, 200, 42).unwrap();
+        assert!(random.p_value > 0.2);
+        assert_eq!(random.n_permutations, 200);
+
+        assert!(permutation_test_signals(&bars[..2], &mut CoinFlip, 10, 42).is_err());
+    }
+
+    #[test]
+    fn test_grade_with_stricter_config() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let returns: Vec<f64> = (0..300).map(|_| rng.gen_range(-0.005..0.015)).collect();
+        let mut stats =
+            StrategyStatistics::from_returns(&returns, &BootstrapConfig::quick()).unwrap();
+
+        // Borderline: clears the default High bar, but not a stricter one
+        stats.sharpe_ci.ci_lower = 0.6;
+        let strict = ConfidenceGradeConfig {
+            high_ci_lower: 1.0,
+            max_p_value: 0.01,
+            ..Default::default()
+        };
+        assert_eq!(
+            stats.grade_with(&ConfidenceGradeConfig::default()),
+            ConfidenceGrade::High
+        );
+        assert_eq!(stats.grade_with(&strict), ConfidenceGrade::Medium);
+
+        // A p-value that passes at 5% but not at 1% costs another step
+        stats.fdr_p_value = Some(0.03);
+        assert_eq!(
+            stats.grade_with(&ConfidenceGradeConfig::default()),
+            ConfidenceGrade::High
+        );
+        assert_eq!(stats.grade_with(&strict), ConfidenceGrade::Low);
+
+        // Too few samples for the stricter standard
+        let needs_more = ConfidenceGradeConfig {
+            min_samples: 500,
+            ..Default::default()
+        };
+        assert_eq!(stats.grade_with(&needs_more), ConfidenceGrade::Insufficient);
+    }
+
     #[test]
     fn test_standard_normal_cdf() {
         // Test known values
@@ -1648,4 +2382,110 @@ mod tests {
             consecutive_count
         );
     }
+
+    #[test]
+    fn test_probabilistic_sharpe_bounds() {
+        // Observed equal to benchmark: coin flip regardless of sample length
+        let psr = probabilistic_sharpe(0.1, 0.1, 250, 0.0, 0.0);
+        assert!((psr - 0.5).abs() < 1e-6);
+
+        // High observed Sharpe over a long sample: near certainty
+        let psr = probabilistic_sharpe(0.2, 0.0, 2000, 0.0, 0.0);
+        assert!(psr > 0.999, "PSR should approach 1.0, got {}", psr);
+
+        // Negative skew and fat tails reduce confidence for the same Sharpe
+        let normal = probabilistic_sharpe(0.1, 0.0, 100, 0.0, 0.0);
+        let fat_tailed = probabilistic_sharpe(0.1, 0.0, 100, -2.0, 10.0);
+        assert!(fat_tailed < normal);
+        assert!((0.0..=1.0).contains(&fat_tailed));
+    }
+
+    #[test]
+    fn test_psr_from_result() {
+        use crate::backtest::EquityPoint;
+        use chrono::{TimeZone, Utc};
+
+        // Alternating +0.2% / -0.1% returns: per-period Sharpe ~0.33
+        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut equity = 100_000.0;
+        let points: Vec<EquityPoint> = (0..501)
+            .map(|i| {
+                if i > 0 {
+                    equity *= if i % 2 == 1 { 1.002 } else { 0.999 };
+                }
+                EquityPoint {
+                    ts: base + chrono::Duration::days(i as i64),
+                    cash: equity,
+                    position_qty: 0.0,
+                    close: equity,
+                    equity,
+                }
+            })
+            .collect();
+        let result = BacktestResult {
+            equity: points,
+            ..Default::default()
+        };
+
+        let psr = psr_from_result(&result, 0.0).unwrap();
+        assert!(psr > 0.99, "Expected PSR near 1.0, got {}", psr);
+
+        // An annualized benchmark of 10 is well above the ~5.3 observed
+        let psr = psr_from_result(&result, 10.0).unwrap();
+        assert!(psr < 0.01, "Expected PSR near 0.0, got {}", psr);
+
+        assert!(psr_from_result(&BacktestResult::default(), 0.0).is_err());
+    }
+
+    fn noise_series(n: usize, rng: &mut SmallRng) -> Vec<f64> {
+        let mut series: Vec<f64> = (0..n).map(|_| rng.gen_range(-0.01..0.01)).collect();
+        // Center exactly so the series has no edge
+        let mean = series.iter().sum::<f64>() / n as f64;
+        series.iter_mut().for_each(|x| *x -= mean);
+        series
+    }
+
+    #[test]
+    fn test_reality_check_noise_not_significant() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let configs: Vec<Vec<f64>> = (0..20).map(|_| noise_series(250, &mut rng)).collect();
+
+        let result = reality_check(&configs, 500, 42).unwrap();
+
+        assert_eq!(result.n_configs, 20);
+        assert_eq!(result.n_observations, 250);
+        assert!(
+            result.p_value > 0.5,
+            "Pure noise should fail to reject, got p = {}",
+            result.p_value
+        );
+        assert!(!result.is_significant_05());
+    }
+
+    #[test]
+    fn test_reality_check_detects_real_edge() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut configs: Vec<Vec<f64>> = (0..19).map(|_| noise_series(250, &mut rng)).collect();
+        let edge: Vec<f64> = noise_series(250, &mut rng)
+            .into_iter()
+            .map(|x| x + 0.002)
+            .collect();
+        configs.push(edge);
+
+        let result = reality_check(&configs, 500, 42).unwrap();
+
+        assert_eq!(result.best_config_index, 19);
+        assert!(
+            result.is_significant_05(),
+            "Strong edge should be significant, got p = {}",
+            result.p_value
+        );
+    }
+
+    #[test]
+    fn test_reality_check_rejects_misaligned_series() {
+        let configs = vec![vec![0.01; 10], vec![0.01; 9]];
+        assert!(reality_check(&configs, 100, 42).is_err());
+        assert!(reality_check(&[], 100, 42).is_err());
+    }
 }
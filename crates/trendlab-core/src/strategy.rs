@@ -1,12 +1,17 @@
 //! Strategy trait and common implementations.
 
 use crate::bar::Bar;
+use crate::data::{resample, ResamplePeriod};
 use crate::indicators::{
-    aroon, atr, bollinger_bands, cci, darvas_boxes, dmi, donchian_channel, ema_close, heikin_ashi,
-    ichimoku, keltner_channel, macd, opening_range, parabolic_sar, range_breakout_levels, roc,
-    rolling_max_close, rsi, sma_close, sma_volume, starc_bands, stochastic, supertrend, williams_r,
-    BollingerBands, DarvasBox, HABar, MACDEntryMode, MAType, OpeningPeriod,
+    aroon, atr, bollinger_bands, cci, cmo, coppock, darvas_boxes, dmi, donchian_channel, elder_ray,
+    ema_close, fisher_transform, heikin_ashi, hma_close, ichimoku, is_session_start, kama,
+    keltner_channel, macd, obv, opening_range, parabolic_sar, pre_session_close,
+    range_breakout_levels, roc, rolling_max_close, rsi, sma_close, sma_volume, starc_bands,
+    stoch_rsi, stochastic, supertrend, trix, trix_with_signal, vortex, williams_r, BollingerBands,
+    DarvasBox, DonchianChannel, HABar, MACDEntryMode, MAType, OpeningPeriod, KAMA_DEFAULT_FAST,
+    KAMA_DEFAULT_SLOW,
 };
+use chrono::{DateTime, Utc};
 
 /// Position state in a backtest.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -88,10 +93,23 @@ impl Signal {
 /// This follows the Turtle trading system convention:
 /// - System 1: 20-day entry, 10-day exit
 /// - System 2: 55-day entry, 20-day exit
+///
+/// Both channels are computed once in [`Strategy::prepare`]; without it,
+/// each `signal` call rescans the slice it is given.
 #[derive(Debug, Clone)]
 pub struct DonchianBreakoutStrategy {
     entry_lookback: usize,
     exit_lookback: usize,
+    /// Channels precomputed by `prepare`, if any
+    prepared: Option<PreparedChannels>,
+}
+
+/// Entry and exit channels for a full bar series, keyed by bar timestamp.
+#[derive(Debug, Clone)]
+struct PreparedChannels {
+    ts: Vec<DateTime<Utc>>,
+    entry: Vec<Option<DonchianChannel>>,
+    exit: Vec<Option<DonchianChannel>>,
 }
 
 impl DonchianBreakoutStrategy {
@@ -99,6 +117,7 @@ impl DonchianBreakoutStrategy {
         Self {
             entry_lookback,
             exit_lookback,
+            prepared: None,
         }
     }
 
@@ -121,6 +140,28 @@ impl DonchianBreakoutStrategy {
     pub fn exit_lookback(&self) -> usize {
         self.exit_lookback
     }
+
+    /// Channel value at the last bar, from the prepared series when it covers
+    /// these bars and recomputed from the slice otherwise.
+    fn channel_at(&self, bars: &[Bar], entry: bool) -> Option<DonchianChannel> {
+        let current_idx = bars.len() - 1;
+        if let Some(p) = &self.prepared {
+            if p.ts.get(current_idx) == Some(&bars[current_idx].ts) {
+                return if entry {
+                    p.entry[current_idx]
+                } else {
+                    p.exit[current_idx]
+                };
+            }
+        }
+
+        let lookback = if entry {
+            self.entry_lookback
+        } else {
+            self.exit_lookback
+        };
+        donchian_channel(bars, lookback)[current_idx]
+    }
 }
 
 impl Strategy for DonchianBreakoutStrategy {
@@ -149,8 +190,7 @@ impl Strategy for DonchianBreakoutStrategy {
         match current_position {
             Position::Flat => {
                 // Check for entry: close > upper channel
-                let entry_channel = donchian_channel(bars, self.entry_lookback);
-                if let Some(ch) = entry_channel[current_idx] {
+                if let Some(ch) = self.channel_at(bars, true) {
                     if current_close > ch.upper {
                         return Signal::EnterLong;
                     }
@@ -159,8 +199,7 @@ impl Strategy for DonchianBreakoutStrategy {
             }
             Position::Long => {
                 // Check for exit: close < lower channel
-                let exit_channel = donchian_channel(bars, self.exit_lookback);
-                if let Some(ch) = exit_channel[current_idx] {
+                if let Some(ch) = self.channel_at(bars, false) {
                     if current_close < ch.lower {
                         return Signal::ExitLong;
                     }
@@ -176,7 +215,17 @@ impl Strategy for DonchianBreakoutStrategy {
         }
     }
 
-    fn reset(&mut self) {}
+    fn reset(&mut self) {
+        self.prepared = None;
+    }
+
+    fn prepare(&mut self, bars: &[Bar]) {
+        self.prepared = Some(PreparedChannels {
+            ts: bars.iter().map(|b| b.ts).collect(),
+            entry: donchian_channel(bars, self.entry_lookback),
+            exit: donchian_channel(bars, self.exit_lookback),
+        });
+    }
 }
 
 /// Moving Average Crossover strategy.
@@ -252,6 +301,14 @@ impl MACrossoverStrategy {
                 ema_close(bars, self.fast_period),
                 ema_close(bars, self.slow_period),
             ),
+            MAType::Hull => (
+                hma_close(bars, self.fast_period),
+                hma_close(bars, self.slow_period),
+            ),
+            MAType::Kama => (
+                kama(bars, self.fast_period, KAMA_DEFAULT_FAST, KAMA_DEFAULT_SLOW),
+                kama(bars, self.slow_period, KAMA_DEFAULT_FAST, KAMA_DEFAULT_SLOW),
+            ),
         }
     }
 }
@@ -458,6 +515,15 @@ pub trait Strategy: Send + Sync {
     /// Returns the minimum number of bars required before generating signals.
     fn warmup_period(&self) -> usize;
 
+    /// Returns the fewest bars a backtest needs for this strategy to trade:
+    /// its longest indicator lookback.
+    ///
+    /// Defaults to `warmup_period`, and never less than 1. Backtests suppress
+    /// signals until this many bars exist and warn when handed fewer.
+    fn min_bars(&self) -> usize {
+        self.warmup_period().max(1)
+    }
+
     /// Compute signal for the current bar.
     ///
     /// # Arguments
@@ -470,6 +536,14 @@ pub trait Strategy: Send + Sync {
 
     /// Reset internal state (for running multiple backtests).
     fn reset(&mut self);
+
+    /// Precompute state from the full bar series before a backtest.
+    ///
+    /// Called by the backtest runners once after `reset`. Strategies that
+    /// need an expensive whole-series view (e.g. a resampled higher
+    /// timeframe) can build it here, but `signal` must still only use data
+    /// up to the current bar. Defaults to a no-op.
+    fn prepare(&mut self, _bars: &[Bar]) {}
 }
 
 /// Placeholder strategy that never trades.
@@ -492,6 +566,357 @@ impl Strategy for NullStrategy {
     fn reset(&mut self) {}
 }
 
+/// Buy-and-hold baseline.
+///
+/// Enters long on the first bar and never exits, so a sweep that includes it
+/// always has a passive benchmark row to compare the active strategies to.
+#[derive(Debug, Default, Clone)]
+pub struct BuyHoldStrategy;
+
+impl Strategy for BuyHoldStrategy {
+    fn id(&self) -> &str {
+        "buy_hold"
+    }
+
+    fn warmup_period(&self) -> usize {
+        0
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        match current_position {
+            Position::Flat if !bars.is_empty() => Signal::EnterLong,
+            _ => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// Decorator that forces an exit after a maximum holding period.
+///
+/// Delegates to the inner strategy, but once a long has been held for
+/// `max_hold` bars since its entry signal, returns `ExitLong` regardless of
+/// what the inner strategy says. Useful for trend strategies whose exit
+/// condition may never trigger on a losing position.
+///
+/// Entry bars come from replaying the wrapper's own signals over the series,
+/// so a position opened or closed by anything else (a skipped entry, a
+/// stop-loss) is timed from the replayed entry.
+#[derive(Debug, Clone)]
+pub struct TimeExit<S: Strategy> {
+    inner: S,
+    max_hold: usize,
+    /// Entry bars replayed by `prepare`, if any
+    prepared: Option<PreparedEntries>,
+}
+
+/// Entry bar of the long held at each bar of a full series, keyed by bar timestamp.
+#[derive(Debug, Clone)]
+struct PreparedEntries {
+    ts: Vec<DateTime<Utc>>,
+    entry_idx: Vec<Option<usize>>,
+}
+
+impl<S: Strategy> TimeExit<S> {
+    pub fn new(inner: S, max_hold: usize) -> Self {
+        assert!(max_hold > 0, "Max hold must be at least 1 bar");
+
+        Self {
+            inner,
+            max_hold,
+            prepared: None,
+        }
+    }
+
+    /// The wrapped strategy.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn max_hold(&self) -> usize {
+        self.max_hold
+    }
+
+    /// Signal at the last bar, given the bar the current long was entered on.
+    fn signal_since(
+        &self,
+        bars: &[Bar],
+        current_position: Position,
+        entry_idx: Option<usize>,
+    ) -> Signal {
+        let current_idx = bars.len() - 1;
+        if let (Position::Long, Some(entered)) = (current_position, entry_idx) {
+            if current_idx - entered >= self.max_hold {
+                return Signal::ExitLong;
+            }
+        }
+        self.inner.signal(bars, current_position)
+    }
+
+    /// Replay the wrapper over `bars`, recording for each bar the entry bar
+    /// of the long held going into it (`None` while flat).
+    fn replay_entries(&self, bars: &[Bar]) -> Vec<Option<usize>> {
+        let mut entries = Vec::with_capacity(bars.len());
+        let mut entry_idx = None;
+        for i in 0..bars.len() {
+            entries.push(entry_idx);
+            let position = if entry_idx.is_some() {
+                Position::Long
+            } else {
+                Position::Flat
+            };
+            match self.signal_since(&bars[..=i], position, entry_idx) {
+                Signal::EnterLong if entry_idx.is_none() => entry_idx = Some(i),
+                Signal::ExitLong => entry_idx = None,
+                _ => {}
+            }
+        }
+        entries
+    }
+
+    /// Entry bar of the long held at the last bar, from the prepared series
+    /// when it covers these bars and replayed from the slice otherwise.
+    fn entry_at(&self, bars: &[Bar]) -> Option<usize> {
+        let current_idx = bars.len() - 1;
+        if let Some(p) = &self.prepared {
+            if p.ts.get(current_idx) == Some(&bars[current_idx].ts) {
+                return p.entry_idx[current_idx];
+            }
+        }
+        self.replay_entries(bars)[current_idx]
+    }
+}
+
+impl<S: Strategy> Strategy for TimeExit<S> {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn warmup_period(&self) -> usize {
+        self.inner.warmup_period()
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let entry_idx = match current_position {
+            Position::Long => self.entry_at(bars),
+            Position::Flat | Position::Short => None,
+        };
+        self.signal_since(bars, current_position, entry_idx)
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.prepared = None;
+    }
+
+    fn prepare(&mut self, bars: &[Bar]) {
+        self.inner.prepare(bars);
+        self.prepared = Some(PreparedEntries {
+            ts: bars.iter().map(|b| b.ts).collect(),
+            entry_idx: self.replay_entries(bars),
+        });
+    }
+}
+
+/// Decorator that only lets entries through in a long-term uptrend.
+///
+/// `EnterLong` from the inner strategy passes only when the close is above
+/// the SMA of closes over `sma_period` bars (200 by default); otherwise it
+/// becomes `Hold`. Exits and all other signals pass through unchanged, so
+/// open positions are never trapped by the filter.
+#[derive(Debug, Clone)]
+pub struct RegimeFilter<S: Strategy> {
+    inner: S,
+    sma_period: usize,
+}
+
+impl<S: Strategy> RegimeFilter<S> {
+    /// Default trend filter length (the classic 200-day SMA).
+    pub const DEFAULT_SMA_PERIOD: usize = 200;
+
+    /// Wrap `inner` with the default 200-bar SMA filter.
+    pub fn new(inner: S) -> Self {
+        Self::with_sma_period(inner, Self::DEFAULT_SMA_PERIOD)
+    }
+
+    pub fn with_sma_period(inner: S, sma_period: usize) -> Self {
+        assert!(sma_period > 0, "SMA period must be at least 1");
+        Self { inner, sma_period }
+    }
+
+    /// The wrapped strategy.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn sma_period(&self) -> usize {
+        self.sma_period
+    }
+}
+
+impl<S: Strategy> Strategy for RegimeFilter<S> {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn warmup_period(&self) -> usize {
+        self.inner.warmup_period().max(self.sma_period - 1)
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let signal = self.inner.signal(bars, current_position);
+        if signal != Signal::EnterLong {
+            return signal;
+        }
+
+        // Only entries pay for the SMA; unknown regime (warmup) blocks entry
+        let current_idx = bars.len() - 1;
+        let sma = sma_close(bars, self.sma_period);
+        match sma[current_idx] {
+            Some(trend) if bars[current_idx].close > trend => Signal::EnterLong,
+            _ => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    fn prepare(&mut self, bars: &[Bar]) {
+        self.inner.prepare(bars);
+    }
+}
+
+/// Decorator that confirms entries against a higher-timeframe trend.
+///
+/// Daily bars are resampled (weekly by default) and `EnterLong` from the
+/// inner strategy passes only when the last *completed* period closed above
+/// the SMA of period closes over `sma_period` periods. The period in
+/// progress is never used, so there is no lookahead. Exits pass through.
+///
+/// The trend series is built once in [`Strategy::prepare`]; if `signal` is
+/// called on bars that weren't prepared, it is recomputed from the slice.
+#[derive(Debug, Clone)]
+pub struct MultiTimeframe<S: Strategy> {
+    inner: S,
+    period: ResamplePeriod,
+    sma_period: usize,
+    /// Per-bar higher-timeframe trend from `prepare`, keyed by bar timestamp
+    trend: Vec<(DateTime<Utc>, bool)>,
+}
+
+impl<S: Strategy> MultiTimeframe<S> {
+    /// Default higher-timeframe SMA length (10 weeks, roughly the 50-day).
+    pub const DEFAULT_SMA_PERIOD: usize = 10;
+
+    /// Wrap `inner` with a weekly trend filter over the default SMA period.
+    pub fn new(inner: S) -> Self {
+        Self::with_period(inner, ResamplePeriod::Weekly, Self::DEFAULT_SMA_PERIOD)
+    }
+
+    pub fn with_period(inner: S, period: ResamplePeriod, sma_period: usize) -> Self {
+        assert!(sma_period > 0, "SMA period must be at least 1");
+        Self {
+            inner,
+            period,
+            sma_period,
+            trend: Vec::new(),
+        }
+    }
+
+    /// The wrapped strategy.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn period(&self) -> ResamplePeriod {
+        self.period
+    }
+
+    pub fn sma_period(&self) -> usize {
+        self.sma_period
+    }
+
+    /// Higher-timeframe trend for each bar, from completed periods only.
+    fn trend_series(&self, bars: &[Bar]) -> Vec<bool> {
+        let periods = resample(bars, self.period);
+        let sma = sma_close(&periods, self.sma_period);
+        let period_up: Vec<bool> = periods
+            .iter()
+            .zip(&sma)
+            .map(|(p, s)| s.is_some_and(|s| p.close > s))
+            .collect();
+
+        // `resample` groups consecutive bars, so counting key changes maps
+        // each bar to its period index
+        let mut out = Vec::with_capacity(bars.len());
+        let mut period_idx = 0;
+        let mut current_key = None;
+        for bar in bars {
+            let key = self.period.period_key(bar.ts);
+            if current_key.is_some_and(|k| k != key) {
+                period_idx += 1;
+            }
+            current_key = Some(key);
+            out.push(period_idx > 0 && period_up[period_idx - 1]);
+        }
+        out
+    }
+
+    fn trend_up(&self, bars: &[Bar]) -> bool {
+        let current_idx = bars.len() - 1;
+        match self.trend.get(current_idx) {
+            Some(&(ts, up)) if ts == bars[current_idx].ts => up,
+            _ => self.trend_series(bars).last().copied().unwrap_or(false),
+        }
+    }
+}
+
+impl<S: Strategy> Strategy for MultiTimeframe<S> {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn warmup_period(&self) -> usize {
+        let bars_needed = (self.sma_period + 1) * self.period.trading_days();
+        self.inner.warmup_period().max(bars_needed)
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let signal = self.inner.signal(bars, current_position);
+        if signal == Signal::EnterLong && !self.trend_up(bars) {
+            return Signal::Hold;
+        }
+        signal
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.trend.clear();
+    }
+
+    fn prepare(&mut self, bars: &[Bar]) {
+        self.inner.prepare(bars);
+        self.trend = bars
+            .iter()
+            .map(|b| b.ts)
+            .zip(self.trend_series(bars))
+            .collect();
+    }
+}
+
 // =============================================================================
 // Phase 1: ATR-Based Channel Strategies
 // =============================================================================
@@ -1481,83 +1906,178 @@ impl Strategy for AroonCrossStrategy {
     fn reset(&mut self) {}
 }
 
-/// Bollinger Squeeze Breakout strategy.
+/// Vortex Cross strategy.
 ///
-/// Entry: When in squeeze AND close breaks above upper band
-/// Exit: When close falls below middle band (SMA)
+/// Entry: VI+ crosses above VI-
+/// Exit: VI+ crosses below VI-
 ///
-/// A "squeeze" occurs when volatility contracts (bandwidth narrows).
-/// The breakout from a squeeze often leads to significant moves.
+/// The Vortex Indicator compares upward movement (|high - prev_low|) against
+/// downward movement (|low - prev_high|), each normalised by true range.
+/// - VI+ > VI- suggests an uptrend is in control
+/// - VI- > VI+ suggests a downtrend is in control
 ///
 /// Common configurations:
-/// - Period 20, Mult 2.0, Threshold 0.04: Standard
-/// - Period 20, Mult 2.5, Threshold 0.03: Tighter squeeze detection
+/// - Period 14: Standard
+/// - Period 21: Slower, fewer whipsaws
 #[derive(Debug, Clone)]
-pub struct BollingerSqueezeStrategy {
-    /// Period for SMA and standard deviation
+pub struct VortexCrossStrategy {
+    /// Period for Vortex calculation
     period: usize,
-    /// Standard deviation multiplier for bands
-    std_mult: f64,
-    /// Bandwidth threshold for squeeze detection
-    squeeze_threshold: f64,
-    /// Track whether we were in squeeze on previous bar
-    was_in_squeeze: bool,
 }
 
-impl BollingerSqueezeStrategy {
-    pub fn new(period: usize, std_mult: f64, squeeze_threshold: f64) -> Self {
+impl VortexCrossStrategy {
+    pub fn new(period: usize) -> Self {
         assert!(period > 0, "Period must be at least 1");
-        assert!(
-            std_mult > 0.0,
-            "Standard deviation multiplier must be positive"
-        );
-        assert!(
-            squeeze_threshold > 0.0,
-            "Squeeze threshold must be positive"
-        );
-
-        Self {
-            period,
-            std_mult,
-            squeeze_threshold,
-            was_in_squeeze: false,
-        }
+        Self { period }
     }
 
-    /// Standard configuration: period 20, mult 2.0, threshold 0.04
+    /// Standard configuration: period 14
     pub fn standard() -> Self {
-        Self::new(20, 2.0, 0.04)
+        Self::new(14)
     }
 
-    /// Tight squeeze configuration: period 20, mult 2.5, threshold 0.03
-    pub fn tight_squeeze() -> Self {
-        Self::new(20, 2.5, 0.03)
+    /// Slow configuration: period 21
+    pub fn slow() -> Self {
+        Self::new(21)
     }
 
     /// Get the period.
     pub fn period(&self) -> usize {
         self.period
     }
-
-    /// Get the standard deviation multiplier.
-    pub fn std_mult(&self) -> f64 {
-        self.std_mult
-    }
-
-    /// Get the squeeze threshold.
-    pub fn squeeze_threshold(&self) -> f64 {
-        self.squeeze_threshold
-    }
-
-    /// Check if in squeeze (bandwidth < threshold).
-    pub fn is_in_squeeze(&self, bb: &BollingerBands) -> bool {
-        bb.bandwidth < self.squeeze_threshold
-    }
 }
 
-impl Strategy for BollingerSqueezeStrategy {
+impl Strategy for VortexCrossStrategy {
     fn id(&self) -> &str {
-        "bollinger_squeeze"
+        "vortex_cross"
+    }
+
+    fn warmup_period(&self) -> usize {
+        // First VI value is at index `period`; crossover needs the one before
+        self.period + 1
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let current_idx = bars.len() - 1;
+
+        // During warmup, no signals
+        if current_idx < self.warmup_period() {
+            return Signal::Hold;
+        }
+
+        let vi_values = vortex(bars, self.period);
+        let (Some((plus, minus)), Some((prev_plus, prev_minus))) =
+            (vi_values[current_idx], vi_values[current_idx - 1])
+        else {
+            return Signal::Hold;
+        };
+
+        let plus_above = plus > minus;
+        let prev_plus_above = prev_plus > prev_minus;
+
+        match current_position {
+            Position::Flat => {
+                // Entry: VI+ crosses above VI-
+                if plus_above && !prev_plus_above {
+                    return Signal::EnterLong;
+                }
+                Signal::Hold
+            }
+            Position::Long => {
+                // Exit: VI+ crosses below VI-
+                if !plus_above && prev_plus_above {
+                    return Signal::ExitLong;
+                }
+                Signal::Hold
+            }
+            Position::Short => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// Bollinger Squeeze Breakout strategy.
+///
+/// Entry: When in squeeze AND close breaks above upper band
+/// Exit: When close falls below middle band (SMA)
+///
+/// A "squeeze" occurs when volatility contracts (bandwidth narrows).
+/// The breakout from a squeeze often leads to significant moves.
+///
+/// Common configurations:
+/// - Period 20, Mult 2.0, Threshold 0.04: Standard
+/// - Period 20, Mult 2.5, Threshold 0.03: Tighter squeeze detection
+#[derive(Debug, Clone)]
+pub struct BollingerSqueezeStrategy {
+    /// Period for SMA and standard deviation
+    period: usize,
+    /// Standard deviation multiplier for bands
+    std_mult: f64,
+    /// Bandwidth threshold for squeeze detection
+    squeeze_threshold: f64,
+    /// Track whether we were in squeeze on previous bar
+    was_in_squeeze: bool,
+}
+
+impl BollingerSqueezeStrategy {
+    pub fn new(period: usize, std_mult: f64, squeeze_threshold: f64) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        assert!(
+            std_mult > 0.0,
+            "Standard deviation multiplier must be positive"
+        );
+        assert!(
+            squeeze_threshold > 0.0,
+            "Squeeze threshold must be positive"
+        );
+
+        Self {
+            period,
+            std_mult,
+            squeeze_threshold,
+            was_in_squeeze: false,
+        }
+    }
+
+    /// Standard configuration: period 20, mult 2.0, threshold 0.04
+    pub fn standard() -> Self {
+        Self::new(20, 2.0, 0.04)
+    }
+
+    /// Tight squeeze configuration: period 20, mult 2.5, threshold 0.03
+    pub fn tight_squeeze() -> Self {
+        Self::new(20, 2.5, 0.03)
+    }
+
+    /// Get the period.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Get the standard deviation multiplier.
+    pub fn std_mult(&self) -> f64 {
+        self.std_mult
+    }
+
+    /// Get the squeeze threshold.
+    pub fn squeeze_threshold(&self) -> f64 {
+        self.squeeze_threshold
+    }
+
+    /// Check if in squeeze (bandwidth < threshold).
+    pub fn is_in_squeeze(&self, bb: &BollingerBands) -> bool {
+        bb.bandwidth < self.squeeze_threshold
+    }
+}
+
+impl Strategy for BollingerSqueezeStrategy {
+    fn id(&self) -> &str {
+        "bollinger_squeeze"
     }
 
     fn warmup_period(&self) -> usize {
@@ -2644,12 +3164,15 @@ impl Strategy for ParabolicSarDelayedStrategy {
 /// Exit: Close breaks below the opening range low
 ///
 /// The "opening range" is defined as the high/low of the first N bars
-/// of a trading period (week, month, or rolling).
+/// of a trading period (week, month, session, or rolling).
 ///
 /// Common configurations:
 /// - 5 bars, Weekly: First week's range, trade breakouts for rest of month
 /// - 3 bars, Rolling: Simple rolling range breakout
 /// - 10 bars, Monthly: First 2 weeks define range for month
+/// - 6 bars, Daily: Intraday ORB; positions are flattened on the session's
+///   last bar (closing time learned from the previous session), or on the
+///   next session's first bar when no close is known yet
 #[derive(Debug, Clone)]
 pub struct OpeningRangeBreakoutStrategy {
     /// Number of bars to define the opening range
@@ -2707,6 +3230,18 @@ impl Strategy for OpeningRangeBreakoutStrategy {
 
         let current_idx = bars.len() - 1;
 
+        // Daily sessions flatten ahead of the close so the exit fills on the
+        // session's last bar; the session-start exit covers the first session
+        // (no close seen yet) and early closes.
+        let is_daily = self.period == OpeningPeriod::Daily;
+        let pre_close = is_daily && pre_session_close(bars)[current_idx];
+        if is_daily
+            && current_position == Position::Long
+            && (pre_close || is_session_start(bars, current_idx))
+        {
+            return Signal::ExitLong;
+        }
+
         // During warmup, no signals
         if current_idx < self.warmup_period() {
             return Signal::Hold;
@@ -2728,8 +3263,8 @@ impl Strategy for OpeningRangeBreakoutStrategy {
 
         match current_position {
             Position::Flat => {
-                // Entry: close breaks above range high
-                if current_or.is_breakout_high(current_close) {
+                // Entry: close breaks above range high (not into the close)
+                if !pre_close && current_or.is_breakout_high(current_close) {
                     return Signal::EnterLong;
                 }
                 Signal::Hold
@@ -2749,7 +3284,10 @@ impl Strategy for OpeningRangeBreakoutStrategy {
 }
 
 /// Voting method for ensemble strategies.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+///
+/// Note: Implements Eq and Hash manually using f64.to_bits() for the
+/// `Weighted` weights, so configs can still be used as HashMap keys.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum VotingMethod {
     /// Simple majority vote (>50% must agree)
     Majority,
@@ -2757,9 +3295,59 @@ pub enum VotingMethod {
     WeightedByHorizon,
     /// All strategies must agree for entry, any triggers exit
     UnanimousEntry,
+    /// Fixed weight per strategy, normalized to sum to 1.
+    ///
+    /// Each entry votes +weight and each exit -weight. Enters long when the
+    /// net vote is above zero and exits when it is below; an exact tie
+    /// (within [`VotingMethod::WEIGHTED_TIE_EPSILON`]) holds.
+    Weighted(Vec<f64>),
+}
+
+impl Eq for VotingMethod {}
+
+impl std::hash::Hash for VotingMethod {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        if let VotingMethod::Weighted(weights) = self {
+            weights.len().hash(state);
+            for w in weights {
+                w.to_bits().hash(state);
+            }
+        }
+    }
 }
 
 impl VotingMethod {
+    /// Net weighted votes within this distance of zero count as a tie.
+    pub const WEIGHTED_TIE_EPSILON: f64 = 1e-9;
+
+    /// Normalize `Weighted` weights to sum to 1.
+    ///
+    /// Negative and non-finite weights count as zero. Returns `None` when no
+    /// weight is positive.
+    pub fn normalized_weights(weights: &[f64]) -> Option<Vec<f64>> {
+        let clean: Vec<f64> = weights
+            .iter()
+            .map(|&w| if w.is_finite() && w > 0.0 { w } else { 0.0 })
+            .collect();
+        let total: f64 = clean.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+        Some(clean.into_iter().map(|w| w / total).collect())
+    }
+
+    /// Filesystem-safe identifier (the variant name, plus weights if any).
+    pub fn file_id(&self) -> String {
+        match self {
+            VotingMethod::Weighted(weights) => {
+                let parts: Vec<String> = weights.iter().map(|w| w.to_string()).collect();
+                format!("Weighted_{}", parts.join("_"))
+            }
+            other => format!("{:?}", other),
+        }
+    }
+
     /// Vote on signals using the specified method.
     ///
     /// Returns the aggregated signal based on voting rules.
@@ -2772,6 +3360,7 @@ impl VotingMethod {
             VotingMethod::Majority => Self::majority_vote(signals),
             VotingMethod::WeightedByHorizon => Self::weighted_vote(signals, horizons),
             VotingMethod::UnanimousEntry => Self::unanimous_entry_vote(signals),
+            VotingMethod::Weighted(weights) => Self::net_weighted_vote(signals, weights),
         }
     }
 
@@ -2857,6 +3446,33 @@ impl VotingMethod {
         Signal::Hold
     }
 
+    fn net_weighted_vote(signals: &[Signal], weights: &[f64]) -> Signal {
+        if signals.len() != weights.len() {
+            return Signal::Hold;
+        }
+        let Some(weights) = Self::normalized_weights(weights) else {
+            return Signal::Hold;
+        };
+
+        let net: f64 = signals
+            .iter()
+            .zip(&weights)
+            .map(|(signal, w)| match signal {
+                Signal::EnterLong => *w,
+                Signal::ExitLong => -*w,
+                _ => 0.0,
+            })
+            .sum();
+
+        if net > Self::WEIGHTED_TIE_EPSILON {
+            Signal::EnterLong
+        } else if net < -Self::WEIGHTED_TIE_EPSILON {
+            Signal::ExitLong
+        } else {
+            Signal::Hold
+        }
+    }
+
     fn unanimous_entry_vote(signals: &[Signal]) -> Signal {
         // For entry: ALL must agree
         // For exit: ANY triggers exit
@@ -3009,8 +3625,8 @@ impl EnsembleStrategy {
     }
 
     /// Get the voting method.
-    pub fn voting(&self) -> VotingMethod {
-        self.voting
+    pub fn voting(&self) -> &VotingMethod {
+        &self.voting
     }
 
     /// Get the horizons.
@@ -3022,32 +3638,26 @@ impl EnsembleStrategy {
     pub fn num_strategies(&self) -> usize {
         self.strategies.len()
     }
-}
-
-impl Strategy for EnsembleStrategy {
-    fn id(&self) -> &str {
-        "ensemble"
-    }
-
-    fn warmup_period(&self) -> usize {
-        // Warmup is the max of all child strategy warmups
-        self.strategies
-            .iter()
-            .map(|s| s.warmup_period())
-            .max()
-            .unwrap_or(0)
-    }
 
-    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+    /// Voted signal plus the fraction of child strategies that voted for it.
+    ///
+    /// Confidence is in [0, 1]; a `Hold` outcome reports the share of
+    /// children that held. Before warmup completes no votes are cast and the
+    /// result is `(Hold, 0.0)`.
+    pub fn signal_with_confidence(
+        &self,
+        bars: &[Bar],
+        current_position: Position,
+    ) -> (Signal, f64) {
         if bars.is_empty() {
-            return Signal::Hold;
+            return (Signal::Hold, 0.0);
         }
 
         let current_idx = bars.len() - 1;
 
         // During warmup, no signals
         if current_idx < self.warmup_period() {
-            return Signal::Hold;
+            return (Signal::Hold, 0.0);
         }
 
         // Collect signals from all child strategies
@@ -3058,7 +3668,28 @@ impl Strategy for EnsembleStrategy {
             .collect();
 
         // Aggregate using voting method
-        self.voting.vote(&signals, &self.horizons)
+        let voted = self.voting.vote(&signals, &self.horizons);
+        let agreeing = signals.iter().filter(|&&s| s == voted).count();
+        (voted, agreeing as f64 / signals.len() as f64)
+    }
+}
+
+impl Strategy for EnsembleStrategy {
+    fn id(&self) -> &str {
+        "ensemble"
+    }
+
+    fn warmup_period(&self) -> usize {
+        // Warmup is the max of all child strategy warmups
+        self.strategies
+            .iter()
+            .map(|s| s.warmup_period())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        self.signal_with_confidence(bars, current_position).0
     }
 
     fn reset(&mut self) {
@@ -3066,6 +3697,12 @@ impl Strategy for EnsembleStrategy {
             strategy.reset();
         }
     }
+
+    fn prepare(&mut self, bars: &[Bar]) {
+        for strategy in &mut self.strategies {
+            strategy.prepare(bars);
+        }
+    }
 }
 
 // =============================================================================
@@ -3582,38 +4219,53 @@ impl Strategy for StochasticStrategy {
     fn reset(&mut self) {}
 }
 
-/// Williams %R Strategy.
+/// Stochastic RSI momentum strategy.
 ///
-/// Entry: %R crosses above oversold threshold (-80) from below
-/// Exit: %R crosses below overbought threshold (-20) from above
+/// Entry: Stochastic RSI %K crosses up through the oversold level
+/// Exit: %K crosses down through the overbought level
 ///
-/// Williams %R is an inverse of the Fast Stochastic Oscillator.
-/// Values range from -100 (oversold) to 0 (overbought).
+/// Applying the stochastic formula to the RSI makes a faster, more sensitive
+/// oscillator than either alone; a turn up out of oversold marks momentum
+/// returning to the upside.
 ///
 /// Common configurations:
-/// - Period 14, Oversold -80, Overbought -20: Standard
-/// - Period 10, Oversold -90, Overbought -10: More extreme
-/// - Period 21, Oversold -80, Overbought -20: Smoother
+/// - RSI 14, Stoch 14, 3/3, Oversold 20, Overbought 80: Standard
 #[derive(Debug, Clone)]
-pub struct WilliamsRStrategy {
-    /// Period for high/low range
-    period: usize,
-    /// Oversold threshold (typically -80)
+pub struct StochRsiStrategy {
+    /// RSI period
+    rsi_period: usize,
+    /// Lookback over the RSI series for the stochastic range
+    stoch_period: usize,
+    /// %K smoothing period
+    k_smooth: usize,
+    /// %D period
+    d_period: usize,
+    /// Oversold threshold (entry)
     oversold: f64,
-    /// Overbought threshold (typically -20)
+    /// Overbought threshold (exit)
     overbought: f64,
 }
 
-impl WilliamsRStrategy {
-    pub fn new(period: usize, oversold: f64, overbought: f64) -> Self {
-        assert!(period > 0, "Period must be at least 1");
+impl StochRsiStrategy {
+    pub fn new(
+        rsi_period: usize,
+        stoch_period: usize,
+        k_smooth: usize,
+        d_period: usize,
+        oversold: f64,
+        overbought: f64,
+    ) -> Self {
+        assert!(rsi_period > 0, "RSI period must be at least 1");
+        assert!(stoch_period > 0, "Stochastic period must be at least 1");
+        assert!(k_smooth > 0, "K smooth must be at least 1");
+        assert!(d_period > 0, "D period must be at least 1");
         assert!(
-            (-100.0..0.0).contains(&oversold),
-            "Oversold must be between -100 and 0"
+            oversold > 0.0 && oversold < 100.0,
+            "Oversold must be between 0 and 100"
         );
         assert!(
-            overbought > -100.0 && overbought <= 0.0,
-            "Overbought must be between -100 and 0"
+            overbought > 0.0 && overbought < 100.0,
+            "Overbought must be between 0 and 100"
         );
         assert!(
             oversold < overbought,
@@ -3621,34 +4273,173 @@ impl WilliamsRStrategy {
         );
 
         Self {
-            period,
+            rsi_period,
+            stoch_period,
+            k_smooth,
+            d_period,
             oversold,
             overbought,
         }
     }
 
-    /// Standard configuration: period 14, -80/-20
+    /// Standard configuration: RSI 14, Stoch 14, 3/3, 20/80
     pub fn standard() -> Self {
-        Self::new(14, -80.0, -20.0)
+        Self::new(14, 14, 3, 3, 20.0, 80.0)
     }
 
-    /// Extreme configuration: period 10, -90/-10
-    pub fn extreme() -> Self {
-        Self::new(10, -90.0, -10.0)
+    /// Get the RSI period.
+    pub fn rsi_period(&self) -> usize {
+        self.rsi_period
     }
 
-    /// Smooth configuration: period 21, -80/-20
-    pub fn smooth() -> Self {
-        Self::new(21, -80.0, -20.0)
+    /// Get the stochastic lookback period.
+    pub fn stoch_period(&self) -> usize {
+        self.stoch_period
     }
 
-    /// Get the period.
-    pub fn period(&self) -> usize {
-        self.period
+    /// Get the K smoothing period.
+    pub fn k_smooth(&self) -> usize {
+        self.k_smooth
     }
 
-    /// Get the oversold threshold.
-    pub fn oversold(&self) -> f64 {
+    /// Get the D period.
+    pub fn d_period(&self) -> usize {
+        self.d_period
+    }
+
+    /// Get the oversold threshold.
+    pub fn oversold(&self) -> f64 {
+        self.oversold
+    }
+
+    /// Get the overbought threshold.
+    pub fn overbought(&self) -> f64 {
+        self.overbought
+    }
+}
+
+impl Strategy for StochRsiStrategy {
+    fn id(&self) -> &str {
+        "stoch_rsi"
+    }
+
+    fn warmup_period(&self) -> usize {
+        // First value is at index rsi + stoch + k + d - 3; a cross needs the one before
+        self.rsi_period + self.stoch_period + self.k_smooth + self.d_period - 2
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let current_idx = bars.len() - 1;
+
+        // During warmup, no signals
+        if current_idx < self.warmup_period() {
+            return Signal::Hold;
+        }
+
+        let values = stoch_rsi(
+            bars,
+            self.rsi_period,
+            self.stoch_period,
+            self.k_smooth,
+            self.d_period,
+        );
+        let (Some(current), Some(prev)) = (values[current_idx], values[current_idx - 1]) else {
+            return Signal::Hold;
+        };
+
+        match current_position {
+            Position::Flat => {
+                // Entry: %K crosses up out of oversold
+                if prev.k_smooth <= self.oversold && current.k_smooth > self.oversold {
+                    return Signal::EnterLong;
+                }
+                Signal::Hold
+            }
+            Position::Long => {
+                // Exit: %K crosses down out of overbought
+                if prev.k_smooth >= self.overbought && current.k_smooth < self.overbought {
+                    return Signal::ExitLong;
+                }
+                Signal::Hold
+            }
+            Position::Short => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// Williams %R Strategy.
+///
+/// Entry: %R crosses above oversold threshold (-80) from below
+/// Exit: %R crosses below overbought threshold (-20) from above
+///
+/// Williams %R is an inverse of the Fast Stochastic Oscillator.
+/// Values range from -100 (oversold) to 0 (overbought).
+///
+/// Common configurations:
+/// - Period 14, Oversold -80, Overbought -20: Standard
+/// - Period 10, Oversold -90, Overbought -10: More extreme
+/// - Period 21, Oversold -80, Overbought -20: Smoother
+#[derive(Debug, Clone)]
+pub struct WilliamsRStrategy {
+    /// Period for high/low range
+    period: usize,
+    /// Oversold threshold (typically -80)
+    oversold: f64,
+    /// Overbought threshold (typically -20)
+    overbought: f64,
+}
+
+impl WilliamsRStrategy {
+    pub fn new(period: usize, oversold: f64, overbought: f64) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        assert!(
+            (-100.0..0.0).contains(&oversold),
+            "Oversold must be between -100 and 0"
+        );
+        assert!(
+            overbought > -100.0 && overbought <= 0.0,
+            "Overbought must be between -100 and 0"
+        );
+        assert!(
+            oversold < overbought,
+            "Oversold must be less than overbought"
+        );
+
+        Self {
+            period,
+            oversold,
+            overbought,
+        }
+    }
+
+    /// Standard configuration: period 14, -80/-20
+    pub fn standard() -> Self {
+        Self::new(14, -80.0, -20.0)
+    }
+
+    /// Extreme configuration: period 10, -90/-10
+    pub fn extreme() -> Self {
+        Self::new(10, -90.0, -10.0)
+    }
+
+    /// Smooth configuration: period 21, -80/-20
+    pub fn smooth() -> Self {
+        Self::new(21, -80.0, -20.0)
+    }
+
+    /// Get the period.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Get the oversold threshold.
+    pub fn oversold(&self) -> f64 {
         self.oversold
     }
 
@@ -3956,108 +4747,85 @@ impl Strategy for ROCStrategy {
     fn reset(&mut self) {}
 }
 
-// =============================================================================
-// Phase 5b: Hybrid/Confluence Strategies
-// =============================================================================
-
-/// RSI + Bollinger Bands Hybrid Strategy.
+/// CMO (Chande Momentum Oscillator) Strategy.
 ///
-/// Entry: RSI < oversold threshold AND close touches/breaks lower Bollinger Band
-/// Exit: Close crosses above middle band (SMA) OR RSI > exit_rsi threshold
+/// Entry: CMO crosses above oversold threshold from below (bullish crossover)
+/// Exit: CMO crosses below overbought threshold from above (bearish crossover)
 ///
-/// This strategy combines mean reversion (RSI oversold) with volatility bands
-/// to identify high-probability reversal points.
+/// CMO compares the sum of up-closes to the sum of down-closes over the period.
+/// Values range from -100 to 100, with readings below -50 typically
+/// considered oversold and above 50 considered overbought.
 ///
 /// Common configurations:
-/// - RSI 14/30, BB 20/2.0, Exit RSI 50: Standard
-/// - RSI 7/20, BB 20/2.5, Exit RSI 50: More aggressive entries
+/// - Period 14, Oversold -50, Overbought 50: Standard
+/// - Period 9, Oversold -60, Overbought 60: Faster, more extreme levels
 #[derive(Debug, Clone)]
-pub struct RSIBollingerStrategy {
-    /// RSI period
-    rsi_period: usize,
-    /// RSI oversold threshold for entry
-    rsi_oversold: f64,
-    /// RSI level for exit (typically neutral)
-    rsi_exit: f64,
-    /// Bollinger Bands period
-    bb_period: usize,
-    /// Bollinger Bands standard deviation multiplier
-    bb_std_mult: f64,
+pub struct CmoStrategy {
+    /// Period for CMO calculation
+    period: usize,
+    /// Oversold threshold (entry when crossing above)
+    oversold: f64,
+    /// Overbought threshold (exit when crossing below)
+    overbought: f64,
 }
 
-impl RSIBollingerStrategy {
-    pub fn new(
-        rsi_period: usize,
-        rsi_oversold: f64,
-        rsi_exit: f64,
-        bb_period: usize,
-        bb_std_mult: f64,
-    ) -> Self {
-        assert!(rsi_period > 0, "RSI period must be at least 1");
+impl CmoStrategy {
+    pub fn new(period: usize, oversold: f64, overbought: f64) -> Self {
+        assert!(period > 0, "Period must be at least 1");
         assert!(
-            rsi_oversold > 0.0 && rsi_oversold < 100.0,
-            "RSI oversold must be between 0 and 100"
+            oversold > -100.0 && oversold < 100.0,
+            "Oversold must be between -100 and 100"
         );
         assert!(
-            rsi_exit > rsi_oversold,
-            "RSI exit must be greater than oversold"
+            overbought > -100.0 && overbought < 100.0,
+            "Overbought must be between -100 and 100"
+        );
+        assert!(
+            oversold < overbought,
+            "Oversold must be less than overbought"
         );
-        assert!(bb_period > 0, "BB period must be at least 1");
-        assert!(bb_std_mult > 0.0, "BB std mult must be positive");
 
         Self {
-            rsi_period,
-            rsi_oversold,
-            rsi_exit,
-            bb_period,
-            bb_std_mult,
+            period,
+            oversold,
+            overbought,
         }
     }
 
-    /// Standard configuration: RSI 14/30/50, BB 20/2.0
+    /// Standard configuration: period 14, oversold -50, overbought 50
     pub fn standard() -> Self {
-        Self::new(14, 30.0, 50.0, 20, 2.0)
-    }
-
-    /// Aggressive configuration: RSI 7/20/50, BB 20/2.5
-    pub fn aggressive() -> Self {
-        Self::new(7, 20.0, 50.0, 20, 2.5)
-    }
-
-    /// Get the RSI period.
-    pub fn rsi_period(&self) -> usize {
-        self.rsi_period
+        Self::new(14, -50.0, 50.0)
     }
 
-    /// Get the RSI oversold threshold.
-    pub fn rsi_oversold(&self) -> f64 {
-        self.rsi_oversold
+    /// Fast configuration: period 9, oversold -60, overbought 60
+    pub fn fast() -> Self {
+        Self::new(9, -60.0, 60.0)
     }
 
-    /// Get the RSI exit threshold.
-    pub fn rsi_exit(&self) -> f64 {
-        self.rsi_exit
+    /// Get the period.
+    pub fn period(&self) -> usize {
+        self.period
     }
 
-    /// Get the Bollinger Bands period.
-    pub fn bb_period(&self) -> usize {
-        self.bb_period
+    /// Get the oversold threshold.
+    pub fn oversold(&self) -> f64 {
+        self.oversold
     }
 
-    /// Get the Bollinger Bands std multiplier.
-    pub fn bb_std_mult(&self) -> f64 {
-        self.bb_std_mult
+    /// Get the overbought threshold.
+    pub fn overbought(&self) -> f64 {
+        self.overbought
     }
 }
 
-impl Strategy for RSIBollingerStrategy {
+impl Strategy for CmoStrategy {
     fn id(&self) -> &str {
-        "rsi_bollinger"
+        "cmo"
     }
 
     fn warmup_period(&self) -> usize {
-        // Max of RSI and BB warmup periods
-        (self.rsi_period + 1).max(self.bb_period)
+        // First CMO value is at index `period`; crossover needs the one before
+        self.period + 1
     }
 
     fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
@@ -4072,33 +4840,24 @@ impl Strategy for RSIBollingerStrategy {
             return Signal::Hold;
         }
 
-        let rsi_values = rsi(bars, self.rsi_period);
-        let bb_values = bollinger_bands(bars, self.bb_period, self.bb_std_mult);
-
-        // Get current values
-        let current_rsi = match rsi_values[current_idx] {
-            Some(ref r) => r.rsi,
-            None => return Signal::Hold,
-        };
-
-        let current_bb = match bb_values[current_idx] {
-            Some(ref b) => b,
-            None => return Signal::Hold,
+        let cmo_values = cmo(bars, self.period);
+        let (Some(current_cmo), Some(prev_cmo)) =
+            (cmo_values[current_idx], cmo_values[current_idx - 1])
+        else {
+            return Signal::Hold;
         };
 
-        let current_close = bars[current_idx].close;
-
         match current_position {
             Position::Flat => {
-                // Entry: RSI < oversold AND close <= lower BB
-                if current_rsi < self.rsi_oversold && current_close <= current_bb.lower {
+                // Entry: CMO crosses above oversold threshold
+                if current_cmo > self.oversold && prev_cmo <= self.oversold {
                     return Signal::EnterLong;
                 }
                 Signal::Hold
             }
             Position::Long => {
-                // Exit: Close > middle band OR RSI > exit threshold
-                if current_close > current_bb.middle || current_rsi > self.rsi_exit {
+                // Exit: CMO crosses below overbought threshold
+                if current_cmo < self.overbought && prev_cmo >= self.overbought {
                     return Signal::ExitLong;
                 }
                 Signal::Hold
@@ -4110,29 +4869,585 @@ impl Strategy for RSIBollingerStrategy {
     fn reset(&mut self) {}
 }
 
-/// MACD + ADX Filter Strategy.
+/// Coppock Curve Strategy.
 ///
-/// Entry: MACD crosses above signal line AND ADX > threshold
-/// Exit: MACD crosses below signal line
+/// Entry: Coppock turns up while below zero (a trough in negative territory)
+/// Exit: Coppock turns down while above zero
 ///
-/// This strategy combines MACD momentum signals with ADX trend strength
-/// filter to avoid false signals in ranging markets.
+/// A long-horizon momentum signal: the classic use is buying the first upturn
+/// after a bear market. With month-scaled periods on daily bars the warmup runs
+/// to several years, so short histories produce no trades.
 ///
 /// Common configurations:
-/// - MACD 12/26/9, ADX 14/25: Standard
-/// - MACD 8/17/9, ADX 14/20: Faster with lower ADX threshold
+/// - ROC 14/11, WMA 10: Classic (monthly bars)
+/// - ROC 294/231, WMA 210: Classic scaled to daily bars
 #[derive(Debug, Clone)]
-pub struct MACDAdxStrategy {
-    /// MACD fast period
-    fast_period: usize,
-    /// MACD slow period
-    slow_period: usize,
-    /// MACD signal period
-    signal_period: usize,
-    /// ADX period
-    adx_period: usize,
-    /// ADX threshold for trend strength
-    adx_threshold: f64,
+pub struct CoppockStrategy {
+    /// Longer rate-of-change period
+    roc1: usize,
+    /// Shorter rate-of-change period
+    roc2: usize,
+    /// Weighted moving average period applied to the summed ROCs
+    wma_period: usize,
+}
+
+impl CoppockStrategy {
+    pub fn new(roc1: usize, roc2: usize, wma_period: usize) -> Self {
+        assert!(roc1 > 0, "ROC1 period must be at least 1");
+        assert!(roc2 > 0, "ROC2 period must be at least 1");
+        assert!(wma_period > 0, "WMA period must be at least 1");
+
+        Self {
+            roc1,
+            roc2,
+            wma_period,
+        }
+    }
+
+    /// Classic configuration: ROC 14/11, WMA 10
+    pub fn standard() -> Self {
+        Self::new(14, 11, 10)
+    }
+
+    /// Get the longer ROC period.
+    pub fn roc1(&self) -> usize {
+        self.roc1
+    }
+
+    /// Get the shorter ROC period.
+    pub fn roc2(&self) -> usize {
+        self.roc2
+    }
+
+    /// Get the WMA period.
+    pub fn wma_period(&self) -> usize {
+        self.wma_period
+    }
+}
+
+impl Strategy for CoppockStrategy {
+    fn id(&self) -> &str {
+        "coppock"
+    }
+
+    fn warmup_period(&self) -> usize {
+        // First Coppock value is at index max(roc1, roc2) + wma_period - 1; a turn
+        // needs the two before
+        self.roc1.max(self.roc2) + self.wma_period + 1
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let current_idx = bars.len() - 1;
+
+        // During warmup (including fixtures shorter than it), no signals
+        if current_idx < self.warmup_period() {
+            return Signal::Hold;
+        }
+
+        let values = coppock(bars, self.roc1, self.roc2, self.wma_period);
+        let (Some(current), Some(prev), Some(prev2)) = (
+            values[current_idx],
+            values[current_idx - 1],
+            values[current_idx - 2],
+        ) else {
+            return Signal::Hold;
+        };
+
+        match current_position {
+            Position::Flat => {
+                // Entry: Coppock turns up from below zero
+                if prev < 0.0 && current > prev && prev <= prev2 {
+                    return Signal::EnterLong;
+                }
+                Signal::Hold
+            }
+            Position::Long => {
+                // Exit: Coppock turns down from above zero
+                if prev > 0.0 && current < prev && prev >= prev2 {
+                    return Signal::ExitLong;
+                }
+                Signal::Hold
+            }
+            Position::Short => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// Elder-Ray Bull Power strategy.
+///
+/// Entry: Bull Power (high - EMA) turns positive while the EMA is rising
+/// Exit: Bull Power falls back to zero or below
+///
+/// Bull Power measures how far buyers can push the high above the consensus
+/// value (the EMA). Requiring a rising EMA keeps entries on the side of the
+/// trend, so a bounce inside a downtrend is ignored.
+///
+/// Common configurations:
+/// - EMA 13: Elder's original setting
+#[derive(Debug, Clone)]
+pub struct ElderRayStrategy {
+    /// EMA period for the consensus value
+    ema_period: usize,
+}
+
+impl ElderRayStrategy {
+    pub fn new(ema_period: usize) -> Self {
+        assert!(ema_period > 0, "EMA period must be at least 1");
+        Self { ema_period }
+    }
+
+    /// Elder's configuration: EMA 13
+    pub fn standard() -> Self {
+        Self::new(13)
+    }
+
+    /// Get the EMA period.
+    pub fn ema_period(&self) -> usize {
+        self.ema_period
+    }
+}
+
+impl Strategy for ElderRayStrategy {
+    fn id(&self) -> &str {
+        "elder_ray"
+    }
+
+    fn warmup_period(&self) -> usize {
+        // First EMA value is at index ema_period - 1; the slope needs the one before
+        self.ema_period
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let current_idx = bars.len() - 1;
+
+        // During warmup, no signals
+        if current_idx < self.warmup_period() {
+            return Signal::Hold;
+        }
+
+        let ema = ema_close(bars, self.ema_period);
+        let powers = elder_ray(bars, self.ema_period);
+        let (Some(ema_now), Some(ema_prev), Some((bull, _)), Some((prev_bull, _))) = (
+            ema[current_idx],
+            ema[current_idx - 1],
+            powers[current_idx],
+            powers[current_idx - 1],
+        ) else {
+            return Signal::Hold;
+        };
+
+        match current_position {
+            Position::Flat => {
+                // Entry: Bull Power crosses above zero with a rising EMA
+                if prev_bull <= 0.0 && bull > 0.0 && ema_now > ema_prev {
+                    return Signal::EnterLong;
+                }
+                Signal::Hold
+            }
+            Position::Long => {
+                // Exit: highs no longer clear the EMA
+                if bull <= 0.0 {
+                    return Signal::ExitLong;
+                }
+                Signal::Hold
+            }
+            Position::Short => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// Fisher Transform reversal strategy.
+///
+/// Entry: Fisher line crosses above its trigger (the prior bar's Fisher value)
+/// Exit: Fisher line crosses below its trigger
+///
+/// The Fisher Transform maps price's position in its recent range onto a
+/// near-Gaussian scale, so turning points show up as sharp, clear reversals.
+///
+/// Common configurations:
+/// - Period 10: Standard (Ehlers)
+/// - Period 5: Faster, more signals
+#[derive(Debug, Clone)]
+pub struct FisherStrategy {
+    /// Lookback window for price normalization
+    period: usize,
+}
+
+impl FisherStrategy {
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        Self { period }
+    }
+
+    /// Standard configuration: period 10
+    pub fn standard() -> Self {
+        Self::new(10)
+    }
+
+    /// Get the period.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Strategy for FisherStrategy {
+    fn id(&self) -> &str {
+        "fisher"
+    }
+
+    fn warmup_period(&self) -> usize {
+        // First (fisher, trigger) pair is at index `period`; crossover needs the one before
+        self.period + 1
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let current_idx = bars.len() - 1;
+
+        // During warmup, no signals
+        if current_idx < self.warmup_period() {
+            return Signal::Hold;
+        }
+
+        let values = fisher_transform(bars, self.period);
+        let (Some((fisher, trigger)), Some((prev_fisher, prev_trigger))) =
+            (values[current_idx], values[current_idx - 1])
+        else {
+            return Signal::Hold;
+        };
+
+        match current_position {
+            Position::Flat => {
+                // Entry: Fisher crosses above its trigger
+                if fisher > trigger && prev_fisher <= prev_trigger {
+                    return Signal::EnterLong;
+                }
+                Signal::Hold
+            }
+            Position::Long => {
+                // Exit: Fisher crosses below its trigger
+                if fisher < trigger && prev_fisher >= prev_trigger {
+                    return Signal::ExitLong;
+                }
+                Signal::Hold
+            }
+            Position::Short => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// TRIX Strategy.
+///
+/// Entry: TRIX crosses above zero (or above its signal line, if configured)
+/// Exit: TRIX crosses below zero (or below its signal line)
+///
+/// TRIX is the rate of change of a triple-smoothed EMA, so it ignores short
+/// cycles and turns only when the underlying trend does.
+///
+/// Common configurations:
+/// - Period 15, zero line: Standard
+/// - Period 15, signal 9: Earlier entries from the signal-line crossover
+#[derive(Debug, Clone)]
+pub struct TrixStrategy {
+    /// Period for each of the three EMAs
+    period: usize,
+    /// Signal line EMA period; `None` trades zero-line crossings
+    signal_period: Option<usize>,
+}
+
+impl TrixStrategy {
+    /// TRIX strategy trading zero-line crossings.
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        Self {
+            period,
+            signal_period: None,
+        }
+    }
+
+    /// TRIX strategy trading crossings of its signal line.
+    pub fn with_signal(period: usize, signal_period: usize) -> Self {
+        assert!(period > 0, "Period must be at least 1");
+        assert!(signal_period > 0, "Signal period must be at least 1");
+        Self {
+            period,
+            signal_period: Some(signal_period),
+        }
+    }
+
+    /// Standard configuration: period 15, zero line
+    pub fn standard() -> Self {
+        Self::new(15)
+    }
+
+    /// Get the period.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Get the signal line period, if any.
+    pub fn signal_period(&self) -> Option<usize> {
+        self.signal_period
+    }
+
+    /// (TRIX, reference line) per bar; the reference is zero or the signal line.
+    fn lines(&self, bars: &[Bar]) -> Vec<Option<(f64, f64)>> {
+        match self.signal_period {
+            Some(signal_period) => trix_with_signal(bars, self.period, signal_period),
+            None => trix(bars, self.period)
+                .into_iter()
+                .map(|t| t.map(|t| (t, 0.0)))
+                .collect(),
+        }
+    }
+}
+
+impl Strategy for TrixStrategy {
+    fn id(&self) -> &str {
+        "trix"
+    }
+
+    fn warmup_period(&self) -> usize {
+        // First TRIX value is at index 3 * period - 2 (plus signal_period - 1 for
+        // the signal line); crossover needs the one before
+        3 * self.period - 1 + self.signal_period.map_or(0, |s| s - 1)
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let current_idx = bars.len() - 1;
+
+        // During warmup, no signals
+        if current_idx < self.warmup_period() {
+            return Signal::Hold;
+        }
+
+        let lines = self.lines(bars);
+        let (Some((trix, reference)), Some((prev_trix, prev_reference))) =
+            (lines[current_idx], lines[current_idx - 1])
+        else {
+            return Signal::Hold;
+        };
+
+        match current_position {
+            Position::Flat => {
+                // Entry: TRIX crosses above zero / signal line
+                if trix > reference && prev_trix <= prev_reference {
+                    return Signal::EnterLong;
+                }
+                Signal::Hold
+            }
+            Position::Long => {
+                // Exit: TRIX crosses below zero / signal line
+                if trix < reference && prev_trix >= prev_reference {
+                    return Signal::ExitLong;
+                }
+                Signal::Hold
+            }
+            Position::Short => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+// =============================================================================
+// Phase 5b: Hybrid/Confluence Strategies
+// =============================================================================
+
+/// RSI + Bollinger Bands Hybrid Strategy.
+///
+/// Entry: RSI < oversold threshold AND close touches/breaks lower Bollinger Band
+/// Exit: Close crosses above middle band (SMA) OR RSI > exit_rsi threshold
+///
+/// This strategy combines mean reversion (RSI oversold) with volatility bands
+/// to identify high-probability reversal points.
+///
+/// Common configurations:
+/// - RSI 14/30, BB 20/2.0, Exit RSI 50: Standard
+/// - RSI 7/20, BB 20/2.5, Exit RSI 50: More aggressive entries
+#[derive(Debug, Clone)]
+pub struct RSIBollingerStrategy {
+    /// RSI period
+    rsi_period: usize,
+    /// RSI oversold threshold for entry
+    rsi_oversold: f64,
+    /// RSI level for exit (typically neutral)
+    rsi_exit: f64,
+    /// Bollinger Bands period
+    bb_period: usize,
+    /// Bollinger Bands standard deviation multiplier
+    bb_std_mult: f64,
+}
+
+impl RSIBollingerStrategy {
+    pub fn new(
+        rsi_period: usize,
+        rsi_oversold: f64,
+        rsi_exit: f64,
+        bb_period: usize,
+        bb_std_mult: f64,
+    ) -> Self {
+        assert!(rsi_period > 0, "RSI period must be at least 1");
+        assert!(
+            rsi_oversold > 0.0 && rsi_oversold < 100.0,
+            "RSI oversold must be between 0 and 100"
+        );
+        assert!(
+            rsi_exit > rsi_oversold,
+            "RSI exit must be greater than oversold"
+        );
+        assert!(bb_period > 0, "BB period must be at least 1");
+        assert!(bb_std_mult > 0.0, "BB std mult must be positive");
+
+        Self {
+            rsi_period,
+            rsi_oversold,
+            rsi_exit,
+            bb_period,
+            bb_std_mult,
+        }
+    }
+
+    /// Standard configuration: RSI 14/30/50, BB 20/2.0
+    pub fn standard() -> Self {
+        Self::new(14, 30.0, 50.0, 20, 2.0)
+    }
+
+    /// Aggressive configuration: RSI 7/20/50, BB 20/2.5
+    pub fn aggressive() -> Self {
+        Self::new(7, 20.0, 50.0, 20, 2.5)
+    }
+
+    /// Get the RSI period.
+    pub fn rsi_period(&self) -> usize {
+        self.rsi_period
+    }
+
+    /// Get the RSI oversold threshold.
+    pub fn rsi_oversold(&self) -> f64 {
+        self.rsi_oversold
+    }
+
+    /// Get the RSI exit threshold.
+    pub fn rsi_exit(&self) -> f64 {
+        self.rsi_exit
+    }
+
+    /// Get the Bollinger Bands period.
+    pub fn bb_period(&self) -> usize {
+        self.bb_period
+    }
+
+    /// Get the Bollinger Bands std multiplier.
+    pub fn bb_std_mult(&self) -> f64 {
+        self.bb_std_mult
+    }
+}
+
+impl Strategy for RSIBollingerStrategy {
+    fn id(&self) -> &str {
+        "rsi_bollinger"
+    }
+
+    fn warmup_period(&self) -> usize {
+        // Max of RSI and BB warmup periods
+        (self.rsi_period + 1).max(self.bb_period)
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let current_idx = bars.len() - 1;
+
+        // During warmup, no signals
+        if current_idx < self.warmup_period() {
+            return Signal::Hold;
+        }
+
+        let rsi_values = rsi(bars, self.rsi_period);
+        let bb_values = bollinger_bands(bars, self.bb_period, self.bb_std_mult);
+
+        // Get current values
+        let current_rsi = match rsi_values[current_idx] {
+            Some(ref r) => r.rsi,
+            None => return Signal::Hold,
+        };
+
+        let current_bb = match bb_values[current_idx] {
+            Some(ref b) => b,
+            None => return Signal::Hold,
+        };
+
+        let current_close = bars[current_idx].close;
+
+        match current_position {
+            Position::Flat => {
+                // Entry: RSI < oversold AND close <= lower BB
+                if current_rsi < self.rsi_oversold && current_close <= current_bb.lower {
+                    return Signal::EnterLong;
+                }
+                Signal::Hold
+            }
+            Position::Long => {
+                // Exit: Close > middle band OR RSI > exit threshold
+                if current_close > current_bb.middle || current_rsi > self.rsi_exit {
+                    return Signal::ExitLong;
+                }
+                Signal::Hold
+            }
+            Position::Short => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// MACD + ADX Filter Strategy.
+///
+/// Entry: MACD crosses above signal line AND ADX > threshold
+/// Exit: MACD crosses below signal line
+///
+/// This strategy combines MACD momentum signals with ADX trend strength
+/// filter to avoid false signals in ranging markets.
+///
+/// Common configurations:
+/// - MACD 12/26/9, ADX 14/25: Standard
+/// - MACD 8/17/9, ADX 14/20: Faster with lower ADX threshold
+#[derive(Debug, Clone)]
+pub struct MACDAdxStrategy {
+    /// MACD fast period
+    fast_period: usize,
+    /// MACD slow period
+    slow_period: usize,
+    /// MACD signal period
+    signal_period: usize,
+    /// ADX period
+    adx_period: usize,
+    /// ADX threshold for trend strength
+    adx_threshold: f64,
 }
 
 impl MACDAdxStrategy {
@@ -4555,42 +5870,147 @@ impl Strategy for IchimokuStrategy {
             None => return Signal::Hold,
         };
 
-        // Need previous bar for crossover detection
-        if current_idx == 0 {
+        // Need previous bar for crossover detection
+        if current_idx == 0 {
+            return Signal::Hold;
+        }
+
+        let prev_ich = match ichimoku_values[current_idx - 1] {
+            Some(ref i) => i,
+            None => return Signal::Hold,
+        };
+
+        let current_close = bars[current_idx].close;
+
+        // Determine cloud bounds (cloud is between Senkou Span A and B)
+        let cloud_top = current_ich.senkou_span_a.max(current_ich.senkou_span_b);
+        let cloud_bottom = current_ich.senkou_span_a.min(current_ich.senkou_span_b);
+
+        // Price position relative to cloud
+        let price_above_cloud = current_close > cloud_top;
+        let price_below_cloud = current_close < cloud_bottom;
+
+        // Tenkan/Kijun crossover
+        let tenkan_above_kijun = current_ich.tenkan_sen > current_ich.kijun_sen;
+        let prev_tenkan_above_kijun = prev_ich.tenkan_sen > prev_ich.kijun_sen;
+
+        match current_position {
+            Position::Flat => {
+                // Entry: Price above cloud AND Tenkan crosses above Kijun
+                if price_above_cloud && tenkan_above_kijun && !prev_tenkan_above_kijun {
+                    return Signal::EnterLong;
+                }
+                Signal::Hold
+            }
+            Position::Long => {
+                // Exit: Price below cloud OR Tenkan crosses below Kijun
+                let tenkan_bearish_cross = !tenkan_above_kijun && prev_tenkan_above_kijun;
+                if price_below_cloud || tenkan_bearish_cross {
+                    return Signal::ExitLong;
+                }
+                Signal::Hold
+            }
+            Position::Short => Signal::Hold,
+        }
+    }
+
+    fn reset(&mut self) {}
+}
+
+// =============================================================================
+// Phase 6: Volume Strategies
+// =============================================================================
+
+/// OBV trend strategy.
+///
+/// Uses the N-bar slope of On-Balance Volume to detect accumulation,
+/// confirmed by price trading above its simple moving average.
+///
+/// Entry: OBV slope turns positive AND close > SMA
+/// Exit: OBV slope turns negative OR close < SMA
+///
+/// The OBV slope is `obv[t] - obv[t - slope_period]`.
+#[derive(Debug, Clone)]
+pub struct ObvTrendStrategy {
+    /// Lookback for the OBV slope
+    slope_period: usize,
+    /// Period for the price SMA filter
+    sma_period: usize,
+}
+
+impl ObvTrendStrategy {
+    pub fn new(slope_period: usize, sma_period: usize) -> Self {
+        assert!(slope_period > 0, "Slope period must be at least 1");
+        assert!(sma_period > 0, "SMA period must be at least 1");
+
+        Self {
+            slope_period,
+            sma_period,
+        }
+    }
+
+    /// Standard configuration: 20-bar OBV slope, 50-bar SMA
+    pub fn standard() -> Self {
+        Self::new(20, 50)
+    }
+
+    /// Get the OBV slope period.
+    pub fn slope_period(&self) -> usize {
+        self.slope_period
+    }
+
+    /// Get the SMA period.
+    pub fn sma_period(&self) -> usize {
+        self.sma_period
+    }
+}
+
+impl Strategy for ObvTrendStrategy {
+    fn id(&self) -> &str {
+        "obv_trend"
+    }
+
+    fn warmup_period(&self) -> usize {
+        // Slope crossover needs the previous slope, which needs slope_period + 1 bars
+        (self.slope_period + 1).max(self.sma_period)
+    }
+
+    fn signal(&self, bars: &[Bar], current_position: Position) -> Signal {
+        if bars.is_empty() {
+            return Signal::Hold;
+        }
+
+        let current_idx = bars.len() - 1;
+
+        // During warmup, no signals
+        if current_idx < self.warmup_period() {
             return Signal::Hold;
         }
 
-        let prev_ich = match ichimoku_values[current_idx - 1] {
-            Some(ref i) => i,
+        let obv_values = obv(bars);
+        let current_slope = obv_values[current_idx] - obv_values[current_idx - self.slope_period];
+        let prev_slope =
+            obv_values[current_idx - 1] - obv_values[current_idx - 1 - self.slope_period];
+
+        let sma_values = sma_close(bars, self.sma_period);
+        let current_sma = match sma_values[current_idx] {
+            Some(v) => v,
             None => return Signal::Hold,
         };
-
         let current_close = bars[current_idx].close;
 
-        // Determine cloud bounds (cloud is between Senkou Span A and B)
-        let cloud_top = current_ich.senkou_span_a.max(current_ich.senkou_span_b);
-        let cloud_bottom = current_ich.senkou_span_a.min(current_ich.senkou_span_b);
-
-        // Price position relative to cloud
-        let price_above_cloud = current_close > cloud_top;
-        let price_below_cloud = current_close < cloud_bottom;
-
-        // Tenkan/Kijun crossover
-        let tenkan_above_kijun = current_ich.tenkan_sen > current_ich.kijun_sen;
-        let prev_tenkan_above_kijun = prev_ich.tenkan_sen > prev_ich.kijun_sen;
-
         match current_position {
             Position::Flat => {
-                // Entry: Price above cloud AND Tenkan crosses above Kijun
-                if price_above_cloud && tenkan_above_kijun && !prev_tenkan_above_kijun {
+                // Entry: OBV slope turns positive with price above SMA
+                if current_slope > 0.0 && prev_slope <= 0.0 && current_close > current_sma {
                     return Signal::EnterLong;
                 }
                 Signal::Hold
             }
             Position::Long => {
-                // Exit: Price below cloud OR Tenkan crosses below Kijun
-                let tenkan_bearish_cross = !tenkan_above_kijun && prev_tenkan_above_kijun;
-                if price_below_cloud || tenkan_bearish_cross {
+                // Exit: OBV slope turns negative or price falls below SMA
+                let slope_turns_negative = current_slope < 0.0 && prev_slope >= 0.0;
+                if slope_turns_negative || current_close < current_sma {
                     return Signal::ExitLong;
                 }
                 Signal::Hold
@@ -4620,6 +6040,534 @@ mod tests {
         assert_eq!(strategy.signal(&[], Position::Flat), Signal::Hold);
     }
 
+    #[test]
+    fn test_buy_hold_enters_once_and_never_exits() {
+        use crate::backtest::{run_backtest, BacktestConfig, Side};
+
+        // Rally, sell-off and recovery: nothing should shake the position
+        let closes = [
+            100.0, 102.0, 105.0, 108.0, 104.0, 99.0, 94.0, 90.0, 93.0, 97.0, 101.0, 106.0,
+        ];
+        let bars: Vec<Bar> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| make_bar(i as u32 + 1, c, c + 1.0, c - 1.0, c))
+            .collect();
+
+        let mut strategy = BuyHoldStrategy;
+        assert_eq!(strategy.id(), "buy_hold");
+        assert_eq!(strategy.signal(&[], Position::Flat), Signal::Hold);
+
+        let result = run_backtest(&bars, &mut strategy, BacktestConfig::default()).unwrap();
+        let entries = result.fills.iter().filter(|f| f.side == Side::Buy).count();
+        let exits = result.fills.iter().filter(|f| f.side == Side::Sell).count();
+        assert_eq!(entries, 1);
+        assert_eq!(exits, 0);
+        assert!(result.trades.is_empty());
+        // Signal on the first bar, filled at the next open
+        assert_eq!(result.fills[0].ts, bars[1].ts);
+    }
+
+    /// Emits the same signal on every bar.
+    struct FixedSignal(Signal);
+
+    impl Strategy for FixedSignal {
+        fn id(&self) -> &str {
+            "fixed_signal"
+        }
+
+        fn warmup_period(&self) -> usize {
+            0
+        }
+
+        fn signal(&self, _bars: &[Bar], _current_position: Position) -> Signal {
+            self.0
+        }
+
+        fn reset(&mut self) {}
+    }
+
+    #[test]
+    fn test_weighted_vote_high_weight_overrides_dissenters() {
+        let bars = vec![make_bar(1, 100.0, 101.0, 99.0, 100.0)];
+        let ensemble = |signals: [Signal; 3], weights: Vec<f64>| {
+            let strategies: Vec<Box<dyn Strategy>> = signals
+                .iter()
+                .map(|&s| Box::new(FixedSignal(s)) as Box<dyn Strategy>)
+                .collect();
+            EnsembleStrategy::new(strategies, vec![1, 1, 1], VotingMethod::Weighted(weights))
+        };
+
+        // One heavy entry outvotes two light exits (0.6 vs 0.2 + 0.2)
+        let heavy_entry = ensemble(
+            [Signal::EnterLong, Signal::ExitLong, Signal::ExitLong],
+            vec![3.0, 1.0, 1.0],
+        );
+        assert_eq!(heavy_entry.signal(&bars, Position::Flat), Signal::EnterLong);
+
+        // Majority voting would side with the two dissenters
+        let signals = [Signal::EnterLong, Signal::ExitLong, Signal::ExitLong];
+        assert_eq!(
+            VotingMethod::Majority.vote(&signals, &[1, 1, 1]),
+            Signal::ExitLong
+        );
+
+        // Same heavy weight on the exit side
+        let heavy_exit = ensemble(
+            [Signal::ExitLong, Signal::EnterLong, Signal::EnterLong],
+            vec![3.0, 1.0, 1.0],
+        );
+        assert_eq!(heavy_exit.signal(&bars, Position::Long), Signal::ExitLong);
+
+        // Net vote exactly at the threshold: no trade
+        let tie = ensemble(
+            [Signal::EnterLong, Signal::ExitLong, Signal::Hold],
+            vec![0.1 + 0.2, 0.3, 5.0],
+        );
+        assert_eq!(tie.signal(&bars, Position::Flat), Signal::Hold);
+
+        // Weights are normalized, so their scale doesn't matter
+        let signals = [Signal::EnterLong, Signal::Hold, Signal::ExitLong];
+        assert_eq!(
+            VotingMethod::Weighted(vec![2.0, 1.0, 1.0]).vote(&signals, &[]),
+            VotingMethod::Weighted(vec![200.0, 100.0, 100.0]).vote(&signals, &[])
+        );
+        assert_eq!(
+            VotingMethod::normalized_weights(&[2.0, -1.0, 2.0]),
+            Some(vec![0.5, 0.0, 0.5])
+        );
+        assert_eq!(VotingMethod::normalized_weights(&[0.0, f64::NAN]), None);
+    }
+
+    #[test]
+    fn test_ensemble_signal_with_confidence() {
+        let bars = vec![make_bar(1, 100.0, 101.0, 99.0, 100.0)];
+        let strategies: Vec<Box<dyn Strategy>> = vec![
+            Box::new(FixedSignal(Signal::EnterLong)),
+            Box::new(FixedSignal(Signal::EnterLong)),
+            Box::new(FixedSignal(Signal::Hold)),
+            Box::new(FixedSignal(Signal::EnterLong)),
+        ];
+        let ensemble = EnsembleStrategy::new(strategies, vec![1, 1, 1, 1], VotingMethod::Majority);
+
+        let (signal, confidence) = ensemble.signal_with_confidence(&bars, Position::Flat);
+        assert_eq!(signal, Signal::EnterLong);
+        assert_eq!(confidence, 0.75);
+        // The trait signal is the same vote
+        assert_eq!(ensemble.signal(&bars, Position::Flat), Signal::EnterLong);
+
+        assert_eq!(
+            ensemble.signal_with_confidence(&[], Position::Flat),
+            (Signal::Hold, 0.0)
+        );
+    }
+
+    /// Enters whenever flat and never exits.
+    struct AlwaysLong;
+
+    impl Strategy for AlwaysLong {
+        fn id(&self) -> &str {
+            "always_long"
+        }
+
+        fn warmup_period(&self) -> usize {
+            0
+        }
+
+        fn signal(&self, _bars: &[Bar], current_position: Position) -> Signal {
+            match current_position {
+                Position::Flat => Signal::EnterLong,
+                _ => Signal::Hold,
+            }
+        }
+
+        fn reset(&mut self) {}
+    }
+
+    #[test]
+    fn test_time_exit_forces_exit_after_max_hold() {
+        let bars: Vec<Bar> = (1..=20)
+            .map(|d| make_bar(d, 100.0, 101.0, 99.0, 100.0))
+            .collect();
+        let strategy = TimeExit::new(AlwaysLong, 3);
+        assert_eq!(strategy.id(), "always_long");
+
+        let mut position = Position::Flat;
+        let mut entries = Vec::new();
+        let mut exits = Vec::new();
+        for i in 0..bars.len() {
+            match strategy.signal(&bars[..=i], position) {
+                Signal::EnterLong => {
+                    entries.push(i);
+                    position = Position::Long;
+                }
+                Signal::ExitLong => {
+                    exits.push(i);
+                    position = Position::Flat;
+                }
+                _ => {}
+            }
+        }
+
+        assert_eq!(entries, vec![0, 4, 8, 12, 16]);
+        assert_eq!(exits, vec![3, 7, 11, 15, 19]);
+        for (entry, exit) in entries.iter().zip(&exits) {
+            assert_eq!(exit - entry, 3);
+        }
+    }
+
+    #[test]
+    fn test_time_exit_prepare_matches_replay() {
+        let bars: Vec<Bar> = (1..=20)
+            .map(|d| make_bar(d, 100.0, 101.0, 99.0, 100.0))
+            .collect();
+        let mut strategy = TimeExit::new(AlwaysLong, 3);
+        strategy.prepare(&bars);
+        assert!(strategy.prepared.is_some());
+
+        // Entered on bar 4: held 2 bars at bar 6, forced out at bar 7
+        assert_eq!(strategy.signal(&bars[..7], Position::Long), Signal::Hold);
+        assert_eq!(
+            strategy.signal(&bars[..8], Position::Long),
+            Signal::ExitLong
+        );
+
+        // Reset drops the prepared entries; the slice is replayed instead
+        strategy.reset();
+        assert!(strategy.prepared.is_none());
+        assert_eq!(strategy.signal(&bars[..7], Position::Long), Signal::Hold);
+        assert_eq!(
+            strategy.signal(&bars[..8], Position::Long),
+            Signal::ExitLong
+        );
+    }
+
+    #[test]
+    fn test_regime_filter_gates_entries_on_sma() {
+        // Falling to 90, then rallying back above the 5-bar SMA
+        let closes = [100.0, 98.0, 96.0, 94.0, 92.0, 90.0, 91.0, 95.0, 99.0];
+        let bars: Vec<Bar> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| make_bar(i as u32 + 1, c, c + 1.0, c - 1.0, c))
+            .collect();
+        let strategy = RegimeFilter::with_sma_period(AlwaysLong, 5);
+        assert_eq!(strategy.warmup_period(), 4);
+
+        // Inner wants in during the decline: SMA(5) at bar 5 is 94, close 90
+        assert_eq!(strategy.signal(&bars[..6], Position::Flat), Signal::Hold);
+        // Warmup: no SMA yet, so no entry
+        assert_eq!(strategy.signal(&bars[..2], Position::Flat), Signal::Hold);
+        assert_eq!(strategy.signal(&[], Position::Flat), Signal::Hold);
+        // Bar 8: SMA(5) = (92+90+91+95+99)/5 = 93.4, close 99
+        assert_eq!(
+            strategy.signal(&bars[..9], Position::Flat),
+            Signal::EnterLong
+        );
+        assert_eq!(RegimeFilter::new(AlwaysLong).sma_period(), 200);
+    }
+
+    /// Weekday-only daily bars starting Monday 2024-01-01.
+    fn weekday_bars(closes: &[f64]) -> Vec<Bar> {
+        use chrono::{Datelike, Duration, Weekday};
+
+        let mut day = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut bars = Vec::with_capacity(closes.len());
+        for &c in closes {
+            while matches!(day.weekday(), Weekday::Sat | Weekday::Sun) {
+                day += Duration::days(1);
+            }
+            bars.push(Bar::new(day, c, c + 1.0, c - 1.0, c, 1000.0, "TEST", "1d"));
+            day += Duration::days(1);
+        }
+        bars
+    }
+
+    #[test]
+    fn test_multi_timeframe_blocks_entries_in_weekly_downtrend() {
+        // Eight weeks falling one point a day, then four weeks rallying hard
+        let mut closes: Vec<f64> = (0..40).map(|i| 200.0 - i as f64).collect();
+        closes.extend((1..=20).map(|i| 161.0 + 3.0 * i as f64));
+        let bars = weekday_bars(&closes);
+        let mut strategy = MultiTimeframe::with_period(AlwaysLong, ResamplePeriod::Weekly, 3);
+
+        // Bar 35 is in week 7: week 6 closed at 166, below its 3-week SMA of 171
+        assert_eq!(strategy.signal(&bars[..36], Position::Flat), Signal::Hold);
+        // Before three weeks have completed the trend is unknown
+        assert_eq!(strategy.signal(&bars[..10], Position::Flat), Signal::Hold);
+        assert_eq!(strategy.signal(&[], Position::Flat), Signal::Hold);
+        // Bar 55 is in week 11: week 10 closed at 206, above its SMA of 191
+        assert_eq!(
+            strategy.signal(&bars[..56], Position::Flat),
+            Signal::EnterLong
+        );
+        // Exits and holds are never filtered
+        assert_eq!(strategy.signal(&bars[..36], Position::Long), Signal::Hold);
+
+        // Prepared lookups agree with computing from the slice
+        let unprepared: Vec<Signal> = (0..bars.len())
+            .map(|i| strategy.signal(&bars[..=i], Position::Flat))
+            .collect();
+        strategy.prepare(&bars);
+        for (i, expected) in unprepared.iter().enumerate() {
+            assert_eq!(strategy.signal(&bars[..=i], Position::Flat), *expected);
+        }
+        strategy.reset();
+        assert_eq!(strategy.warmup_period(), 20);
+    }
+
+    #[test]
+    fn test_obv_trend_entry_and_exit() {
+        // Decline into 100, rally to 112, then roll over
+        let closes = [
+            110.0, 108.0, 106.0, 104.0, 102.0, 100.0, 103.0, 106.0, 109.0, 112.0, 108.0, 104.0,
+            100.0,
+        ];
+        let bars: Vec<Bar> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| make_bar(i as u32 + 1, c, c + 1.0, c - 1.0, c))
+            .collect();
+
+        let strategy = ObvTrendStrategy::new(3, 5);
+        assert_eq!(strategy.id(), "obv_trend");
+        assert_eq!(strategy.warmup_period(), 5);
+
+        // OBV 3-bar slope turns positive at index 7 while close (106) > SMA5 (103)
+        let entry_idx = (0..bars.len())
+            .find(|&i| strategy.signal(&bars[..=i], Position::Flat) == Signal::EnterLong);
+        assert_eq!(entry_idx, Some(7));
+
+        // Slope turns negative at index 11
+        let exit_idx = (8..bars.len())
+            .find(|&i| strategy.signal(&bars[..=i], Position::Long) == Signal::ExitLong);
+        assert_eq!(exit_idx, Some(11));
+    }
+
+    #[test]
+    fn test_vortex_cross_enters_on_uptrend() {
+        // Decline 110 -> 100, then a clean uptrend
+        let closes: Vec<f64> = (0..6)
+            .map(|i| 110.0 - 2.0 * i as f64)
+            .chain((1..=14).map(|i| 100.0 + 2.0 * i as f64))
+            .collect();
+        let bars: Vec<Bar> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| make_bar(i as u32 + 1, c, c + 1.0, c - 1.0, c))
+            .collect();
+
+        let strategy = VortexCrossStrategy::new(5);
+        assert_eq!(strategy.id(), "vortex_cross");
+        assert_eq!(strategy.warmup_period(), 6);
+
+        // VI+ dominates once the 5-bar window holds 3 up bars (index 8)
+        let entry_idx = (0..bars.len())
+            .find(|&i| strategy.signal(&bars[..=i], Position::Flat) == Signal::EnterLong);
+        assert_eq!(entry_idx, Some(8));
+
+        let (plus, minus) = vortex(&bars, 5)[bars.len() - 1].unwrap();
+        assert!(
+            plus > minus,
+            "VI+ ({}) should dominate VI- ({})",
+            plus,
+            minus
+        );
+
+        // No exit while the uptrend continues
+        assert!(
+            (9..bars.len()).all(|i| strategy.signal(&bars[..=i], Position::Long) == Signal::Hold)
+        );
+    }
+
+    #[test]
+    fn test_cmo_entry_and_exit() {
+        // Sell-off into 100, recovery, a rally, then a pullback
+        let closes = [
+            110.0, 108.0, 106.0, 104.0, 102.0, 100.0, 103.0, 106.0, 109.0, 112.0, 115.0, 113.0,
+            111.0,
+        ];
+        let bars: Vec<Bar> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| make_bar(i as u32 + 1, c, c + 1.0, c - 1.0, c))
+            .collect();
+
+        let strategy = CmoStrategy::new(4, -50.0, 50.0);
+        assert_eq!(strategy.id(), "cmo");
+        assert_eq!(strategy.warmup_period(), 5);
+
+        // CMO(4): -100 at index 5, then (3 - 6) / 9 = -33 at index 6
+        let entry_idx = (0..bars.len())
+            .find(|&i| strategy.signal(&bars[..=i], Position::Flat) == Signal::EnterLong);
+        assert_eq!(entry_idx, Some(6));
+
+        // CMO: 100 at index 10, then (9 - 2) / 11 = 64 at 11, (6 - 4) / 10 = 20 at 12
+        let exit_idx = (7..bars.len())
+            .find(|&i| strategy.signal(&bars[..=i], Position::Long) == Signal::ExitLong);
+        assert_eq!(exit_idx, Some(12));
+    }
+
+    #[test]
+    fn test_trix_entry_and_exit() {
+        // Decline, rally, then a sell-off
+        let closes = [
+            120.0, 118.0, 116.0, 114.0, 112.0, 110.0, 108.0, 106.0, 104.0, 102.0, 102.0, 105.0,
+            108.0, 111.0, 114.0, 117.0, 120.0, 123.0, 124.0, 120.0, 116.0, 112.0, 108.0, 104.0,
+        ];
+        let bars: Vec<Bar> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| make_bar(i as u32 + 1, c, c + 1.0, c - 1.0, c))
+            .collect();
+
+        let find = |strategy: &TrixStrategy, position: Position, signal: Signal| {
+            (0..bars.len()).find(|&i| strategy.signal(&bars[..=i], position) == signal)
+        };
+
+        // Zero line: TRIX(3) turns positive at index 13, negative at 21
+        let zero = TrixStrategy::new(3);
+        assert_eq!(zero.id(), "trix");
+        assert_eq!(zero.warmup_period(), 8);
+        assert_eq!(find(&zero, Position::Flat, Signal::EnterLong), Some(13));
+        assert_eq!(find(&zero, Position::Long, Signal::ExitLong), Some(21));
+
+        // Signal line (EMA 3 of TRIX) crosses earlier on both sides
+        let signal = TrixStrategy::with_signal(3, 3);
+        assert_eq!(signal.warmup_period(), 10);
+        assert_eq!(find(&signal, Position::Flat, Signal::EnterLong), Some(10));
+        assert_eq!(find(&signal, Position::Long, Signal::ExitLong), Some(19));
+    }
+
+    #[test]
+    fn test_coppock_entry_and_exit() {
+        // Rally, bear leg, recovery, then a second decline
+        let closes = [
+            100.0, 101.0, 102.0, 103.0, 104.0, 105.0, 102.0, 99.0, 96.0, 93.0, 90.0, 87.0, 84.0,
+            86.0, 88.0, 90.0, 92.0, 94.0, 96.0, 98.0, 100.0, 102.0, 104.0, 106.0, 103.0, 100.0,
+            97.0, 94.0, 91.0,
+        ];
+        let bars: Vec<Bar> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| make_bar(i as u32 + 1, c, c + 1.0, c - 1.0, c))
+            .collect();
+
+        let strategy = CoppockStrategy::new(3, 2, 2);
+        assert_eq!(strategy.id(), "coppock");
+        assert_eq!(strategy.warmup_period(), 6);
+
+        let find = |position: Position, signal: Signal| {
+            (0..bars.len()).find(|&i| strategy.signal(&bars[..=i], position) == signal)
+        };
+
+        // Coppock bottoms at index 12 (deep below zero) and turns up at 13
+        assert_eq!(find(Position::Flat, Signal::EnterLong), Some(13));
+        // Peaks at 16 above zero and turns down at 17
+        assert_eq!(find(Position::Long, Signal::ExitLong), Some(17));
+
+        // Fewer bars than the warmup: no signals, no panic
+        let classic = CoppockStrategy::standard();
+        for i in 0..bars.len() {
+            assert_eq!(classic.signal(&bars[..=i], Position::Flat), Signal::Hold);
+        }
+    }
+
+    #[test]
+    fn test_stoch_rsi_entry_and_exit() {
+        // Decline into 96, rally to 108, then a sell-off
+        let closes = [
+            100.0, 102.0, 104.0, 103.0, 101.0, 99.0, 97.0, 96.0, 97.0, 99.0, 102.0, 105.0, 107.0,
+            108.0, 107.0, 105.0, 102.0, 100.0,
+        ];
+        let bars: Vec<Bar> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| make_bar(i as u32 + 1, c, c + 1.0, c - 1.0, c))
+            .collect();
+
+        let strategy = StochRsiStrategy::new(3, 3, 2, 2, 20.0, 80.0);
+        assert_eq!(strategy.id(), "stoch_rsi");
+        assert_eq!(strategy.warmup_period(), 8);
+
+        let find = |position: Position, signal: Signal| {
+            (0..bars.len()).find(|&i| strategy.signal(&bars[..=i], position) == signal)
+        };
+
+        // %K goes 0 -> 50 as the decline turns at 8
+        assert_eq!(find(Position::Flat, Signal::EnterLong), Some(8));
+        // %K drops 100 -> 50 on the first down close after the peak
+        assert_eq!(find(Position::Long, Signal::ExitLong), Some(14));
+    }
+
+    #[test]
+    fn test_elder_ray_entry_and_exit() {
+        // Decline, rally, then a pullback
+        let closes = [
+            110.0, 107.0, 104.0, 101.0, 98.0, 95.0, 99.0, 102.0, 105.0, 108.0, 111.0, 114.0, 111.0,
+            108.0, 105.0, 102.0,
+        ];
+        let make_bars = |closes: &[f64]| -> Vec<Bar> {
+            closes
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| make_bar(i as u32 + 1, c, c + 1.0, c - 1.0, c))
+                .collect()
+        };
+        let bars = make_bars(&closes);
+
+        let strategy = ElderRayStrategy::new(3);
+        assert_eq!(strategy.id(), "elder_ray");
+        assert_eq!(strategy.warmup_period(), 3);
+
+        let find = |bars: &[Bar], from: usize, position: Position, signal: Signal| {
+            (from..bars.len()).find(|&i| strategy.signal(&bars[..=i], position) == signal)
+        };
+
+        // Bull Power turns positive at 6 as the close lifts the EMA
+        assert_eq!(find(&bars, 0, Position::Flat, Signal::EnterLong), Some(6));
+        // Once in, the high drops below the EMA at 13
+        assert_eq!(find(&bars, 6, Position::Long, Signal::ExitLong), Some(13));
+        // No signals while the EMA is still warming up
+        assert_eq!(strategy.signal(&bars[..3], Position::Long), Signal::Hold);
+
+        // Bull Power turns positive with a flat EMA (close 98 = EMA 98): no entry
+        let mut flat = closes;
+        for close in flat.iter_mut().skip(6) {
+            *close -= 1.0;
+        }
+        let flat_bars = make_bars(&flat);
+        assert_eq!(find(&flat_bars, 0, Position::Flat, Signal::EnterLong), None);
+    }
+
+    #[test]
+    fn test_fisher_entry_and_exit() {
+        // Sell-off into 100, recovery, a rally, then a pullback
+        let closes = [
+            110.0, 108.0, 106.0, 104.0, 102.0, 100.0, 103.0, 106.0, 109.0, 112.0, 115.0, 113.0,
+            111.0,
+        ];
+        let bars: Vec<Bar> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| make_bar(i as u32 + 1, c, c + 1.0, c - 1.0, c))
+            .collect();
+
+        let strategy = FisherStrategy::new(4);
+        assert_eq!(strategy.id(), "fisher");
+        assert_eq!(strategy.warmup_period(), 5);
+
+        // Fisher pinned at the low through index 5, then turns up at 6
+        let entry_idx = (0..bars.len())
+            .find(|&i| strategy.signal(&bars[..=i], Position::Flat) == Signal::EnterLong);
+        assert_eq!(entry_idx, Some(6));
+
+        // Pinned at the high through index 10, then turns down at 11
+        let exit_idx = (7..bars.len())
+            .find(|&i| strategy.signal(&bars[..=i], Position::Long) == Signal::ExitLong);
+        assert_eq!(exit_idx, Some(11));
+    }
+
     #[test]
     fn test_donchian_uptrend_generates_entry() {
         // Create a clear uptrend: price goes from 100 to 120 over 15 bars
@@ -4670,6 +6618,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_min_bars_is_longest_lookback() {
+        assert_eq!(DonchianBreakoutStrategy::new(55, 20).min_bars(), 55);
+        assert_eq!(DonchianBreakoutStrategy::new(10, 20).min_bars(), 20);
+        assert_eq!(
+            MACrossoverStrategy::new(50, 200, MAType::SMA).min_bars(),
+            200
+        );
+        assert_eq!(KeltnerBreakoutStrategy::new(20, 10, 2.0).min_bars(), 20);
+        // No lookback at all still needs one bar
+        assert_eq!(NullStrategy.min_bars(), 1);
+    }
+
     #[test]
     fn test_donchian_exit_on_downtrend() {
         // First, create uptrend then reversal
@@ -4700,4 +6661,124 @@ mod tests {
             "Strategy should exit on downtrend"
         );
     }
+
+    #[test]
+    fn test_donchian_prepared_matches_rescan() {
+        // Two years of oscillating bars with several breakouts each way;
+        // close-only bars let each move clear the prior channel
+        let start = chrono::Utc.with_ymd_and_hms(2022, 1, 3, 0, 0, 0).unwrap();
+        let bars: Vec<Bar> = (0..500)
+            .map(|i| {
+                let x = i as f64;
+                let c = 100.0 + 0.05 * x + 8.0 * (x / 23.0).sin();
+                let ts = start + chrono::Duration::days(i);
+                Bar::new(ts, c, c, c, c, 1000.0, "TEST", "1d")
+            })
+            .collect();
+
+        let mut strategy = DonchianBreakoutStrategy::new(20, 10);
+        let rescan: Vec<(Signal, Signal)> = (0..bars.len())
+            .map(|i| {
+                (
+                    strategy.signal(&bars[..=i], Position::Flat),
+                    strategy.signal(&bars[..=i], Position::Long),
+                )
+            })
+            .collect();
+
+        strategy.prepare(&bars);
+        for (i, expected) in rescan.iter().enumerate() {
+            let prepared = (
+                strategy.signal(&bars[..=i], Position::Flat),
+                strategy.signal(&bars[..=i], Position::Long),
+            );
+            assert_eq!(prepared, *expected, "bar {}", i);
+        }
+        assert!(rescan.iter().any(|(e, _)| *e == Signal::EnterLong));
+        assert!(rescan.iter().any(|(_, x)| *x == Signal::ExitLong));
+
+        // A different series of the same length falls back to rescanning
+        let shifted: Vec<Bar> = bars
+            .iter()
+            .map(|b| Bar {
+                ts: b.ts + chrono::Duration::days(1000),
+                ..b.clone()
+            })
+            .collect();
+        assert_eq!(
+            strategy.signal(&shifted[..300], Position::Flat),
+            rescan[299].0
+        );
+
+        strategy.reset();
+        assert_eq!(strategy.signal(&bars[..300], Position::Flat), rescan[299].0);
+    }
+
+    #[test]
+    fn test_orb_daily_session_reset() {
+        // Two sessions of six 5-minute bars. Range = first 2 bars of each day.
+        let mut bars = Vec::new();
+        for (day, base) in [(2u32, 100.0), (3u32, 90.0)] {
+            let closes = [
+                base,
+                base + 0.5,
+                base + 0.2,
+                base + 3.0,
+                base + 3.5,
+                base + 4.0,
+            ];
+            for (k, &close) in closes.iter().enumerate() {
+                let ts = chrono::Utc
+                    .with_ymd_and_hms(2024, 1, day, 14, 30 + k as u32 * 5, 0)
+                    .unwrap();
+                bars.push(Bar::new(
+                    ts,
+                    close,
+                    close + 0.5,
+                    close - 0.5,
+                    close,
+                    1000.0,
+                    "TEST",
+                    "5m",
+                ));
+            }
+        }
+
+        let strategy = OpeningRangeBreakoutStrategy::new(2, OpeningPeriod::Daily);
+
+        // No entries while the first session's opening range is forming
+        for i in 1..=2 {
+            assert_eq!(strategy.signal(&bars[..i], Position::Flat), Signal::Hold);
+        }
+        // Bar 2 is inside the range (high 101.0); bar 3 breaks out
+        assert_eq!(strategy.signal(&bars[..3], Position::Flat), Signal::Hold);
+        assert_eq!(
+            strategy.signal(&bars[..4], Position::Flat),
+            Signal::EnterLong
+        );
+
+        // First bar of day 2 flattens a position carried overnight
+        assert_eq!(
+            strategy.signal(&bars[..7], Position::Long),
+            Signal::ExitLong
+        );
+
+        // Day 2 closes (90.x) are far below day 1's range high, yet the range
+        // has reset: no entry until day 2's own range completes, then breakout
+        assert_eq!(strategy.signal(&bars[..7], Position::Flat), Signal::Hold);
+        assert_eq!(strategy.signal(&bars[..8], Position::Flat), Signal::Hold);
+        assert_eq!(strategy.signal(&bars[..9], Position::Flat), Signal::Hold);
+        assert_eq!(
+            strategy.signal(&bars[..10], Position::Flat),
+            Signal::EnterLong
+        );
+
+        // Day 2 closes at 14:55 like day 1: flatten at 14:50 so the exit fills
+        // on the session's last bar, and take no fresh breakout into the close
+        assert_eq!(
+            strategy.signal(&bars[..11], Position::Long),
+            Signal::ExitLong
+        );
+        assert_eq!(strategy.signal(&bars[..11], Position::Flat), Signal::Hold);
+    }
 }
@@ -12,16 +12,20 @@ use crate::indicators::MACDEntryMode;
 use crate::indicators::MAType;
 use crate::indicators::OpeningPeriod;
 use crate::metrics::{compute_metrics, Metrics};
+use crate::statistics::{adjust_p_values, permutation_test, MultipleComparisonMethod};
 use crate::strategy::{
-    AroonCrossStrategy, BollingerSqueezeStrategy, CCIStrategy, DarvasBoxStrategy, DmiAdxStrategy,
-    DonchianBreakoutStrategy, EnsembleStrategy, FiftyTwoWeekHighMomentumStrategy,
-    FiftyTwoWeekHighStrategy, FiftyTwoWeekHighTrailingStrategy, HeikinAshiRegimeStrategy,
-    IchimokuStrategy, KeltnerBreakoutStrategy, LarryWilliamsStrategy, MACDAdxStrategy,
-    MACDStrategy, MACrossoverStrategy, OpeningRangeBreakoutStrategy, OscillatorConfluenceStrategy,
-    ParabolicSARStrategy, ParabolicSarDelayedStrategy, ParabolicSarFilteredStrategy, ROCStrategy,
-    RSIBollingerStrategy, RSIStrategy, STARCBreakoutStrategy, StochasticStrategy, Strategy,
-    SupertrendAsymmetricStrategy, SupertrendConfirmedStrategy, SupertrendCooldownStrategy,
-    SupertrendStrategy, SupertrendVolumeStrategy, TsmomStrategy, VotingMethod, WilliamsRStrategy,
+    AroonCrossStrategy, BollingerSqueezeStrategy, BuyHoldStrategy, CCIStrategy, CmoStrategy,
+    CoppockStrategy, DarvasBoxStrategy, DmiAdxStrategy, DonchianBreakoutStrategy, ElderRayStrategy,
+    EnsembleStrategy, FiftyTwoWeekHighMomentumStrategy, FiftyTwoWeekHighStrategy,
+    FiftyTwoWeekHighTrailingStrategy, FisherStrategy, HeikinAshiRegimeStrategy, IchimokuStrategy,
+    KeltnerBreakoutStrategy, LarryWilliamsStrategy, MACDAdxStrategy, MACDStrategy,
+    MACrossoverStrategy, ObvTrendStrategy, OpeningRangeBreakoutStrategy,
+    OscillatorConfluenceStrategy, ParabolicSARStrategy, ParabolicSarDelayedStrategy,
+    ParabolicSarFilteredStrategy, ROCStrategy, RSIBollingerStrategy, RSIStrategy,
+    STARCBreakoutStrategy, StochasticStrategy, Strategy, SupertrendAsymmetricStrategy,
+    SupertrendConfirmedStrategy, SupertrendCooldownStrategy, SupertrendStrategy,
+    SupertrendVolumeStrategy, TrixStrategy, TsmomStrategy, VortexCrossStrategy, VotingMethod,
+    WilliamsRStrategy,
 };
 use crate::TrendLabError;
 use chrono::{DateTime, Utc};
@@ -169,6 +173,9 @@ pub struct SweepResult {
     pub config_results: Vec<SweepConfigResult>,
     pub started_at: DateTime<Utc>,
     pub completed_at: DateTime<Utc>,
+    /// True if the sweep was cancelled and `config_results` is partial.
+    #[serde(default)]
+    pub cancelled: bool,
 }
 
 impl SweepResult {
@@ -189,20 +196,24 @@ impl SweepResult {
     }
 
     /// Returns configs ranked by a metric (descending by default).
+    ///
+    /// Ties on the metric are broken deterministically, independent of the
+    /// order configs were run in: more trades first, then by `ConfigId::id()`
+    /// ascending.
     pub fn rank_by(&self, metric: RankMetric, ascending: bool) -> Vec<&SweepConfigResult> {
         let mut results: Vec<&SweepConfigResult> = self.config_results.iter().collect();
         results.sort_by(|a, b| {
             let val_a = metric.extract(&a.metrics);
             let val_b = metric.extract(&b.metrics);
-            if ascending {
-                val_a
-                    .partial_cmp(&val_b)
-                    .unwrap_or(std::cmp::Ordering::Equal)
+            let by_metric = if ascending {
+                val_a.partial_cmp(&val_b)
             } else {
-                val_b
-                    .partial_cmp(&val_a)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            }
+                val_b.partial_cmp(&val_a)
+            };
+            by_metric
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| b.metrics.num_trades.cmp(&a.metrics.num_trades))
+                .then_with(|| a.config_id.id().cmp(&b.config_id.id()))
         });
         results
     }
@@ -217,8 +228,9 @@ impl SweepResult {
 }
 
 /// Metrics available for ranking.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
 pub enum RankMetric {
+    #[default]
     Sharpe,
     Cagr,
     Sortino,
@@ -227,10 +239,13 @@ pub enum RankMetric {
     WinRate,
     ProfitFactor,
     TotalReturn,
+    /// Weighted combination of metrics (see [`RankWeights`])
+    Custom(RankWeights),
 }
 
 impl RankMetric {
-    fn extract(&self, m: &Metrics) -> f64 {
+    /// Value of this metric for `m` (higher ranks first when descending).
+    pub fn extract(&self, m: &Metrics) -> f64 {
         match self {
             RankMetric::Sharpe => m.sharpe,
             RankMetric::Cagr => m.cagr,
@@ -240,10 +255,46 @@ impl RankMetric {
             RankMetric::WinRate => m.win_rate,
             RankMetric::ProfitFactor => m.profit_factor,
             RankMetric::TotalReturn => m.total_return,
+            RankMetric::Custom(weights) => weights.score(m),
         }
     }
 }
 
+/// Weights for a custom ranking score.
+///
+/// The score is the weighted sum of the metrics minus `trade_penalty` per
+/// trade, e.g. `0.5*Sharpe + 0.5*Calmar - 0.01*trades`. Unset weights are zero.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RankWeights {
+    pub sharpe: f64,
+    pub sortino: f64,
+    pub calmar: f64,
+    pub cagr: f64,
+    pub total_return: f64,
+    /// Applied to max drawdown as reported (use a negative weight to penalize it)
+    pub max_drawdown: f64,
+    pub win_rate: f64,
+    pub profit_factor: f64,
+    /// Subtracted once per trade
+    pub trade_penalty: f64,
+}
+
+impl RankWeights {
+    /// Weighted score for `m`.
+    pub fn score(&self, m: &Metrics) -> f64 {
+        self.sharpe * m.sharpe
+            + self.sortino * m.sortino
+            + self.calmar * m.calmar
+            + self.cagr * m.cagr
+            + self.total_return * m.total_return
+            + self.max_drawdown * m.max_drawdown
+            + self.win_rate * m.win_rate
+            + self.profit_factor * m.profit_factor
+            - self.trade_penalty * m.num_trades as f64
+    }
+}
+
 /// Run manifest for reproducibility.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RunManifest {
@@ -263,6 +314,9 @@ pub struct SweepConfig {
     pub symbol: String,
     pub start_date: String,
     pub end_date: String,
+    /// Root seed for any randomized step; per-config seeds come from `derive_seed`.
+    #[serde(default)]
+    pub seed: u64,
 }
 
 /// Paths to sweep output files.
@@ -368,6 +422,7 @@ pub fn run_sweep(bars: &[Bar], grid: &SweepGrid, backtest_config: BacktestConfig
         config_results,
         started_at,
         completed_at,
+        cancelled: false,
     }
 }
 
@@ -458,6 +513,7 @@ pub fn compute_cost_sensitivity(
         config.cost_model = CostModel {
             fees_bps_per_side: cost_bps,
             slippage_bps: 0.0,
+            ..Default::default()
         };
 
         let mut strategy =
@@ -481,6 +537,49 @@ pub fn compute_cost_sensitivity(
     })
 }
 
+/// Select the top N configurations while keeping them spread across parameter space.
+///
+/// Walks the configs in descending `metric` order and greedily accepts a config
+/// only if it is at least `min_param_distance` away from every config already
+/// accepted. Distance is the Chebyshev distance in lookback units
+/// (`max(|Δentry|, |Δexit|)`), matching the neighbor definition used by
+/// [`compute_neighbor_sensitivity`].
+///
+/// Returns fewer than `n` configs if the grid cannot supply enough
+/// sufficiently distant configurations.
+pub fn top_n_diverse(
+    result: &SweepResult,
+    n: usize,
+    metric: RankMetric,
+    min_param_distance: usize,
+) -> Vec<ConfigId> {
+    let mut selected: Vec<ConfigId> = Vec::with_capacity(n);
+
+    for candidate in result.rank_by(metric, false) {
+        if selected.len() >= n {
+            break;
+        }
+
+        let far_enough = selected.iter().all(|chosen| {
+            let entry_diff = candidate
+                .config_id
+                .entry_lookback
+                .abs_diff(chosen.entry_lookback);
+            let exit_diff = candidate
+                .config_id
+                .exit_lookback
+                .abs_diff(chosen.exit_lookback);
+            entry_diff.max(exit_diff) >= min_param_distance
+        });
+
+        if far_enough {
+            selected.push(candidate.config_id.clone());
+        }
+    }
+
+    selected
+}
+
 /// Result for a multi-symbol sweep containing per-symbol results and aggregated portfolio.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultiSweepResult {
@@ -623,6 +722,8 @@ impl AggregatedPortfolioResult {
             max_consecutive_losses: 0,
             max_consecutive_wins: 0,
             avg_losing_streak: 0.0,
+            ulcer_index: 0.0,
+            upi: 0.0,
         };
 
         Some(Self {
@@ -732,10 +833,21 @@ pub enum StrategyTypeId {
     WilliamsR,
     Cci,
     Roc,
+    Cmo,
+    Fisher,
+    Trix,
+    Coppock,
+    ElderRay,
     RsiBollinger,
     MacdAdx,
     OscillatorConfluence,
     Ichimoku,
+    // Phase 6: Volume Strategies
+    ObvTrend,
+    // Trend direction
+    VortexCross,
+    // Benchmark
+    BuyHold,
     // Combo strategies (combinations of different strategy types)
     Combo2, // 2-way combo (two strategies confirming each other)
     Combo3, // 3-way combo (three strategies confirming each other)
@@ -782,10 +894,20 @@ impl StrategyTypeId {
             Self::WilliamsR,
             Self::Cci,
             Self::Roc,
+            Self::Cmo,
+            Self::Fisher,
+            Self::Trix,
+            Self::Coppock,
+            Self::ElderRay,
             Self::RsiBollinger,
             Self::MacdAdx,
             Self::OscillatorConfluence,
             Self::Ichimoku,
+            // Phase 6
+            Self::ObvTrend,
+            Self::VortexCross,
+            // Benchmark: included so every multi-strategy sweep has a buy-and-hold row
+            Self::BuyHold,
             // Note: Combo2 and Combo3 are not included in all() since they're
             // dynamically generated during YOLO combo iterations, not user-selectable
         ]
@@ -832,10 +954,18 @@ impl StrategyTypeId {
             Self::WilliamsR => "Williams %R",
             Self::Cci => "CCI Breakout",
             Self::Roc => "Rate of Change",
+            Self::Cmo => "Chande Momentum",
+            Self::Fisher => "Fisher Transform",
+            Self::Trix => "TRIX",
+            Self::Coppock => "Coppock Curve",
+            Self::ElderRay => "Elder-Ray",
             Self::RsiBollinger => "RSI + Bollinger Bands",
             Self::MacdAdx => "MACD + ADX Filter",
             Self::OscillatorConfluence => "Oscillator Confluence",
             Self::Ichimoku => "Ichimoku Cloud",
+            Self::ObvTrend => "OBV Trend",
+            Self::VortexCross => "Vortex Cross",
+            Self::BuyHold => "Buy & Hold",
             Self::Combo2 => "2-Way Combo",
             Self::Combo3 => "3-Way Combo",
         }
@@ -877,10 +1007,18 @@ impl StrategyTypeId {
             Self::WilliamsR => "williams_r",
             Self::Cci => "cci",
             Self::Roc => "roc",
+            Self::Cmo => "cmo",
+            Self::Fisher => "fisher",
+            Self::Trix => "trix",
+            Self::Coppock => "coppock",
+            Self::ElderRay => "elder_ray",
             Self::RsiBollinger => "rsi_bollinger",
             Self::MacdAdx => "macd_adx",
             Self::OscillatorConfluence => "oscillator_confluence",
             Self::Ichimoku => "ichimoku",
+            Self::ObvTrend => "obv_trend",
+            Self::VortexCross => "vortex_cross",
+            Self::BuyHold => "buy_hold",
             Self::Combo2 => "combo2",
             Self::Combo3 => "combo3",
         }
@@ -1044,6 +1182,26 @@ pub enum StrategyConfigId {
     Roc {
         period: usize,
     },
+    Cmo {
+        period: usize,
+        oversold: f64,
+        overbought: f64,
+    },
+    Fisher {
+        period: usize,
+    },
+    Trix {
+        period: usize,
+        signal_period: Option<usize>,
+    },
+    Coppock {
+        roc1: usize,
+        roc2: usize,
+        wma_period: usize,
+    },
+    ElderRay {
+        ema_period: usize,
+    },
     RsiBollinger {
         rsi_period: usize,
         rsi_oversold: f64,
@@ -1073,6 +1231,14 @@ pub enum StrategyConfigId {
         kijun_period: usize,
         senkou_b_period: usize,
     },
+    ObvTrend {
+        slope_period: usize,
+        sma_period: usize,
+    },
+    VortexCross {
+        period: usize,
+    },
+    BuyHold, // No params
     /// Combo strategy combining multiple different strategy types.
     /// Components are sorted by StrategyTypeId for order-independent hashing.
     Combo {
@@ -1449,6 +1615,42 @@ impl PartialEq for StrategyConfigId {
                 },
             ) => p1 == p2 && e1.to_bits() == e2.to_bits() && x1.to_bits() == x2.to_bits(),
             (Self::Roc { period: p1 }, Self::Roc { period: p2 }) => p1 == p2,
+            (
+                Self::Cmo {
+                    period: p1,
+                    oversold: o1,
+                    overbought: b1,
+                },
+                Self::Cmo {
+                    period: p2,
+                    oversold: o2,
+                    overbought: b2,
+                },
+            ) => p1 == p2 && o1.to_bits() == o2.to_bits() && b1.to_bits() == b2.to_bits(),
+            (Self::Fisher { period: p1 }, Self::Fisher { period: p2 }) => p1 == p2,
+            (
+                Self::Trix {
+                    period: p1,
+                    signal_period: s1,
+                },
+                Self::Trix {
+                    period: p2,
+                    signal_period: s2,
+                },
+            ) => p1 == p2 && s1 == s2,
+            (
+                Self::Coppock {
+                    roc1: a1,
+                    roc2: b1,
+                    wma_period: w1,
+                },
+                Self::Coppock {
+                    roc1: a2,
+                    roc2: b2,
+                    wma_period: w2,
+                },
+            ) => a1 == a2 && b1 == b2 && w1 == w2,
+            (Self::ElderRay { ema_period: p1 }, Self::ElderRay { ema_period: p2 }) => p1 == p2,
             (
                 Self::RsiBollinger {
                     rsi_period: rp1,
@@ -1530,6 +1732,18 @@ impl PartialEq for StrategyConfigId {
                     senkou_b_period: s2,
                 },
             ) => t1 == t2 && k1 == k2 && s1 == s2,
+            (
+                Self::ObvTrend {
+                    slope_period: s1,
+                    sma_period: m1,
+                },
+                Self::ObvTrend {
+                    slope_period: s2,
+                    sma_period: m2,
+                },
+            ) => s1 == s2 && m1 == m2,
+            (Self::VortexCross { period: p1 }, Self::VortexCross { period: p2 }) => p1 == p2,
+            (Self::BuyHold, Self::BuyHold) => true,
             (
                 Self::Combo {
                     components: c1,
@@ -1799,6 +2013,33 @@ impl std::hash::Hash for StrategyConfigId {
                 hash_f64(*exit_threshold, state);
             }
             Self::Roc { period } => period.hash(state),
+            Self::Cmo {
+                period,
+                oversold,
+                overbought,
+            } => {
+                period.hash(state);
+                hash_f64(*oversold, state);
+                hash_f64(*overbought, state);
+            }
+            Self::Fisher { period } => period.hash(state),
+            Self::Trix {
+                period,
+                signal_period,
+            } => {
+                period.hash(state);
+                signal_period.hash(state);
+            }
+            Self::Coppock {
+                roc1,
+                roc2,
+                wma_period,
+            } => {
+                roc1.hash(state);
+                roc2.hash(state);
+                wma_period.hash(state);
+            }
+            Self::ElderRay { ema_period } => ema_period.hash(state),
             Self::RsiBollinger {
                 rsi_period,
                 rsi_oversold,
@@ -1853,6 +2094,15 @@ impl std::hash::Hash for StrategyConfigId {
                 kijun_period.hash(state);
                 senkou_b_period.hash(state);
             }
+            Self::ObvTrend {
+                slope_period,
+                sma_period,
+            } => {
+                slope_period.hash(state);
+                sma_period.hash(state);
+            }
+            Self::VortexCross { period } => period.hash(state),
+            Self::BuyHold => {}
             Self::Combo { components, voting } => {
                 // Components are already sorted by StrategyTypeId for order-independence
                 components.len().hash(state);
@@ -1906,10 +2156,18 @@ impl StrategyConfigId {
             Self::WilliamsR { .. } => StrategyTypeId::WilliamsR,
             Self::Cci { .. } => StrategyTypeId::Cci,
             Self::Roc { .. } => StrategyTypeId::Roc,
+            Self::Cmo { .. } => StrategyTypeId::Cmo,
+            Self::Fisher { .. } => StrategyTypeId::Fisher,
+            Self::Trix { .. } => StrategyTypeId::Trix,
+            Self::Coppock { .. } => StrategyTypeId::Coppock,
+            Self::ElderRay { .. } => StrategyTypeId::ElderRay,
             Self::RsiBollinger { .. } => StrategyTypeId::RsiBollinger,
             Self::MacdAdx { .. } => StrategyTypeId::MacdAdx,
             Self::OscillatorConfluence { .. } => StrategyTypeId::OscillatorConfluence,
             Self::Ichimoku { .. } => StrategyTypeId::Ichimoku,
+            Self::ObvTrend { .. } => StrategyTypeId::ObvTrend,
+            Self::VortexCross { .. } => StrategyTypeId::VortexCross,
+            Self::BuyHold => StrategyTypeId::BuyHold,
             Self::Combo { components, .. } => {
                 if components.len() == 2 {
                     StrategyTypeId::Combo2
@@ -2130,6 +2388,25 @@ impl StrategyConfigId {
                 period, entry_threshold, exit_threshold
             ),
             Self::Roc { period } => format!("ROC {}", period),
+            Self::Cmo {
+                period,
+                oversold,
+                overbought,
+            } => format!("CMO {}/{:.0}/{:.0}", period, oversold, overbought),
+            Self::Fisher { period } => format!("Fisher {}", period),
+            Self::Trix {
+                period,
+                signal_period,
+            } => match signal_period {
+                Some(signal) => format!("TRIX {}/{}", period, signal),
+                None => format!("TRIX {}", period),
+            },
+            Self::Coppock {
+                roc1,
+                roc2,
+                wma_period,
+            } => format!("Coppock {}/{}/{}", roc1, roc2, wma_period),
+            Self::ElderRay { ema_period } => format!("Elder-Ray {}", ema_period),
             Self::RsiBollinger {
                 rsi_period,
                 rsi_oversold,
@@ -2178,6 +2455,12 @@ impl StrategyConfigId {
                 "Ichimoku {}/{}/{}",
                 tenkan_period, kijun_period, senkou_b_period
             ),
+            Self::ObvTrend {
+                slope_period,
+                sma_period,
+            } => format!("OBV Trend {}/{}", slope_period, sma_period),
+            Self::VortexCross { period } => format!("Vortex {}", period),
+            Self::BuyHold => "Buy & Hold".to_string(),
             Self::Combo { components, voting } => {
                 let names: Vec<String> =
                     components.iter().map(|(t, _)| t.id().to_string()).collect();
@@ -2331,14 +2614,14 @@ impl StrategyConfigId {
                 horizons,
                 voting,
             } => format!(
-                "{}_{}_{:?}",
+                "{}_{}_{}",
                 base_strategy.id(),
                 horizons
                     .iter()
                     .map(|h| h.to_string())
                     .collect::<Vec<_>>()
                     .join("_"),
-                voting
+                voting.file_id()
             ),
             // Phase 5: Oscillator Strategies
             Self::Rsi {
@@ -2376,6 +2659,25 @@ impl StrategyConfigId {
                 exit_threshold,
             } => format!("{}_{:.0}_{:.0}", period, entry_threshold, exit_threshold),
             Self::Roc { period } => format!("{}", period),
+            Self::Cmo {
+                period,
+                oversold,
+                overbought,
+            } => format!("{}_{:.0}_{:.0}", period, oversold, overbought),
+            Self::Fisher { period } => format!("{}", period),
+            Self::Trix {
+                period,
+                signal_period,
+            } => match signal_period {
+                Some(signal) => format!("{}_{}", period, signal),
+                None => format!("{}", period),
+            },
+            Self::Coppock {
+                roc1,
+                roc2,
+                wma_period,
+            } => format!("{}_{}_{}", roc1, roc2, wma_period),
+            Self::ElderRay { ema_period } => format!("{}", ema_period),
             Self::RsiBollinger {
                 rsi_period,
                 rsi_oversold,
@@ -2421,12 +2723,18 @@ impl StrategyConfigId {
                 kijun_period,
                 senkou_b_period,
             } => format!("{}_{}_{}", tenkan_period, kijun_period, senkou_b_period),
+            Self::ObvTrend {
+                slope_period,
+                sma_period,
+            } => format!("{}_{}", slope_period, sma_period),
+            Self::VortexCross { period } => format!("{}", period),
+            Self::BuyHold => "buy_hold".to_string(),
             Self::Combo { components, voting } => {
                 let ids: Vec<String> = components
                     .iter()
                     .map(|(t, c)| format!("{}_{}", t.id(), c.file_id()))
                     .collect();
-                format!("{:?}_{}", voting, ids.join("_"))
+                format!("{}_{}", voting.file_id(), ids.join("_"))
             }
         }
     }
@@ -2504,6 +2812,14 @@ impl StrategyConfigId {
             Self::WilliamsR { period, .. } => ConfigId::new(*period, 0),
             Self::Cci { period, .. } => ConfigId::new(*period, 0),
             Self::Roc { period } => ConfigId::new(*period, 0),
+            Self::Cmo { period, .. } => ConfigId::new(*period, 0),
+            Self::Fisher { period } => ConfigId::new(*period, 0),
+            Self::Trix {
+                period,
+                signal_period,
+            } => ConfigId::new(*period, signal_period.unwrap_or(0)),
+            Self::Coppock { roc1, roc2, .. } => ConfigId::new(*roc1, *roc2),
+            Self::ElderRay { ema_period } => ConfigId::new(*ema_period, 0),
             Self::RsiBollinger {
                 rsi_period,
                 bb_period,
@@ -2524,6 +2840,12 @@ impl StrategyConfigId {
                 kijun_period,
                 ..
             } => ConfigId::new(*tenkan_period, *kijun_period),
+            Self::ObvTrend {
+                slope_period,
+                sma_period,
+            } => ConfigId::new(*slope_period, *sma_period),
+            Self::VortexCross { period } => ConfigId::new(*period, 0),
+            Self::BuyHold => ConfigId::new(0, 0),
             Self::Combo { components, .. } => {
                 // For legacy compat, use first component's params (best effort)
                 if let Some((_, first_config)) = components.first() {
@@ -2919,6 +3241,24 @@ bgcolor(strategy.position_size > 0 ? color.new(color.green, 90) : na)
                 let ma_func = match ma_type {
                     MAType::SMA => "ta.sma",
                     MAType::EMA => "ta.ema",
+                    MAType::Hull => "ta.hma",
+                    MAType::Kama => "kama",
+                };
+                // Pine has no built-in KAMA, so emit a helper with the default 2/30 constants
+                let ma_helper = match ma_type {
+                    MAType::Kama => {
+                        r#"kama(float src, int len) =>
+    float change = math.abs(src - src[len])
+    float volatility = math.sum(math.abs(src - src[1]), len)
+    float er = volatility != 0 ? change / volatility : 0.0
+    float sc = math.pow(er * (2.0 / 3.0 - 2.0 / 31.0) + 2.0 / 31.0, 2)
+    float k = na
+    k := na(k[1]) ? src : k[1] + sc * (src - k[1])
+    k
+
+"#
+                    }
+                    _ => "",
                 };
                 format!(
                     r#"//@version=6
@@ -2943,7 +3283,7 @@ endDate = input.time(timestamp("2099-12-31"), "End Date", tooltip="Backtest end
 bool inDateRange = time >= startDate and time <= endDate
 
 // === INDICATORS ===
-float fastMA = {6}(close, fastPeriodInput)
+{7}float fastMA = {6}(close, fastPeriodInput)
 float slowMA = {6}(close, slowPeriodInput)
 
 // === SIGNALS ===
@@ -2968,7 +3308,14 @@ bgcolor(strategy.position_size > 0 ? color.new(color.green, 90) : na)
 plotshape(goldenCross, "Golden Cross", shape.triangleup, location.belowbar, color.green, size=size.small)
 plotshape(deathCross, "Death Cross", shape.triangledown, location.abovebar, color.red, size=size.small)
 "#,
-                    strategy_name, config_display, fast, slow, ma_type, perf_comment, ma_func
+                    strategy_name,
+                    config_display,
+                    fast,
+                    slow,
+                    ma_type,
+                    perf_comment,
+                    ma_func,
+                    ma_helper
                 )
             }
 
@@ -3341,6 +3688,27 @@ pub enum StrategyParams {
     Roc {
         periods: Vec<usize>,
     },
+    Cmo {
+        periods: Vec<usize>,
+        oversolds: Vec<f64>,
+        overboughts: Vec<f64>,
+    },
+    Fisher {
+        periods: Vec<usize>,
+    },
+    Trix {
+        periods: Vec<usize>,
+        /// `None` trades zero-line crossings
+        signal_periods: Vec<Option<usize>>,
+    },
+    Coppock {
+        roc1s: Vec<usize>,
+        roc2s: Vec<usize>,
+        wma_periods: Vec<usize>,
+    },
+    ElderRay {
+        ema_periods: Vec<usize>,
+    },
     RsiBollinger {
         rsi_periods: Vec<usize>,
         rsi_oversolds: Vec<f64>,
@@ -3370,6 +3738,17 @@ pub enum StrategyParams {
         kijun_periods: Vec<usize>,
         senkou_b_periods: Vec<usize>,
     },
+    // Phase 6: Volume Strategies
+    ObvTrend {
+        slope_periods: Vec<usize>,
+        sma_periods: Vec<usize>,
+    },
+    // Trend direction
+    VortexCross {
+        periods: Vec<usize>,
+    },
+    // Benchmark
+    BuyHold, // No params - fixed
 }
 
 impl StrategyParams {
@@ -3794,11 +4173,11 @@ impl StrategyParams {
                 let mut configs = Vec::new();
                 for &base_strategy in base_strategies {
                     for horizons in horizon_sets {
-                        for &voting in voting_methods {
+                        for voting in voting_methods {
                             configs.push(StrategyConfigId::Ensemble {
                                 base_strategy,
                                 horizons: horizons.clone(),
-                                voting,
+                                voting: voting.clone(),
                             });
                         }
                     }
@@ -3919,6 +4298,71 @@ impl StrategyParams {
                 .iter()
                 .map(|&period| StrategyConfigId::Roc { period })
                 .collect(),
+            Self::Cmo {
+                periods,
+                oversolds,
+                overboughts,
+            } => {
+                let mut configs = Vec::new();
+                for &period in periods {
+                    for &oversold in oversolds {
+                        for &overbought in overboughts {
+                            configs.push(StrategyConfigId::Cmo {
+                                period,
+                                oversold,
+                                overbought,
+                            });
+                        }
+                    }
+                }
+                configs
+            }
+            Self::Fisher { periods } => periods
+                .iter()
+                .map(|&period| StrategyConfigId::Fisher { period })
+                .collect(),
+            Self::Trix {
+                periods,
+                signal_periods,
+            } => {
+                let mut configs = Vec::new();
+                for &period in periods {
+                    for &signal_period in signal_periods {
+                        configs.push(StrategyConfigId::Trix {
+                            period,
+                            signal_period,
+                        });
+                    }
+                }
+                configs
+            }
+            Self::Coppock {
+                roc1s,
+                roc2s,
+                wma_periods,
+            } => {
+                let mut configs = Vec::new();
+                for &roc1 in roc1s {
+                    for &roc2 in roc2s {
+                        // ROC1 is the longer period; swapped pairs are duplicates
+                        if roc2 >= roc1 {
+                            continue;
+                        }
+                        for &wma_period in wma_periods {
+                            configs.push(StrategyConfigId::Coppock {
+                                roc1,
+                                roc2,
+                                wma_period,
+                            });
+                        }
+                    }
+                }
+                configs
+            }
+            Self::ElderRay { ema_periods } => ema_periods
+                .iter()
+                .map(|&ema_period| StrategyConfigId::ElderRay { ema_period })
+                .collect(),
             Self::RsiBollinger {
                 rsi_periods,
                 rsi_oversolds,
@@ -4035,6 +4479,26 @@ impl StrategyParams {
                 }
                 configs
             }
+            Self::ObvTrend {
+                slope_periods,
+                sma_periods,
+            } => {
+                let mut configs = Vec::new();
+                for &slope_period in slope_periods {
+                    for &sma_period in sma_periods {
+                        configs.push(StrategyConfigId::ObvTrend {
+                            slope_period,
+                            sma_period,
+                        });
+                    }
+                }
+                configs
+            }
+            Self::VortexCross { periods } => periods
+                .iter()
+                .map(|&period| StrategyConfigId::VortexCross { period })
+                .collect(),
+            Self::BuyHold => vec![StrategyConfigId::BuyHold],
         }
     }
 
@@ -4071,6 +4535,15 @@ impl StrategyGridConfig {
         }
     }
 
+    /// Buy-and-hold benchmark (no parameters).
+    pub fn buy_hold() -> Self {
+        Self {
+            strategy_type: StrategyTypeId::BuyHold,
+            enabled: true,
+            params: StrategyParams::BuyHold,
+        }
+    }
+
     /// Turtle System 1 (fixed preset).
     pub fn turtle_s1() -> Self {
         Self {
@@ -4147,7 +4620,7 @@ impl StrategyGridConfig {
     /// Parameter ranges based on Golden Cross/Death Cross research:
     /// - Quick: Classic 50/200 SMA only
     /// - Standard: Common fast/slow combinations with SMA + EMA
-    /// - Comprehensive: Extended periods including short-term scalping
+    /// - Comprehensive: Extended periods including short-term scalping, plus Hull MA and KAMA
     pub fn ma_crossover_with_depth(depth: SweepDepth) -> Self {
         let (fast_periods, slow_periods, ma_types) = match depth {
             SweepDepth::Quick => (vec![20, 50], vec![50, 200], vec![MAType::SMA]),
@@ -4159,7 +4632,7 @@ impl StrategyGridConfig {
             SweepDepth::Comprehensive => (
                 vec![5, 9, 10, 20, 50],
                 vec![20, 21, 50, 100, 200],
-                vec![MAType::SMA, MAType::EMA],
+                vec![MAType::SMA, MAType::EMA, MAType::Hull, MAType::Kama],
             ),
         };
         Self {
@@ -4871,6 +5344,222 @@ impl StrategyGridConfig {
         }
     }
 
+    // -------------------------------------------------------------------------
+    // Phase 6: Volume Strategies
+    // -------------------------------------------------------------------------
+
+    /// Default OBV trend grid.
+    pub fn obv_trend_default() -> Self {
+        Self {
+            strategy_type: StrategyTypeId::ObvTrend,
+            enabled: true,
+            params: StrategyParams::ObvTrend {
+                slope_periods: vec![10, 20, 30],
+                sma_periods: vec![50, 100],
+            },
+        }
+    }
+
+    /// OBV trend grid with specified sweep depth.
+    pub fn obv_trend_with_depth(depth: SweepDepth) -> Self {
+        let (slope_periods, sma_periods) = match depth {
+            SweepDepth::Quick => (vec![20], vec![50]),
+            SweepDepth::Standard => (vec![10, 20, 30], vec![50, 100]),
+            SweepDepth::Comprehensive => (vec![5, 10, 20, 30, 50], vec![20, 50, 100, 200]),
+        };
+        Self {
+            strategy_type: StrategyTypeId::ObvTrend,
+            enabled: true,
+            params: StrategyParams::ObvTrend {
+                slope_periods,
+                sma_periods,
+            },
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Trend Direction Strategies
+    // -------------------------------------------------------------------------
+
+    /// Default Vortex cross grid.
+    pub fn vortex_cross_default() -> Self {
+        Self {
+            strategy_type: StrategyTypeId::VortexCross,
+            enabled: true,
+            params: StrategyParams::VortexCross {
+                periods: vec![14, 21, 28],
+            },
+        }
+    }
+
+    /// Vortex cross grid with specified sweep depth.
+    pub fn vortex_cross_with_depth(depth: SweepDepth) -> Self {
+        let periods = match depth {
+            SweepDepth::Quick => vec![14],
+            SweepDepth::Standard => vec![14, 21, 28],
+            SweepDepth::Comprehensive => vec![7, 10, 14, 21, 28, 35],
+        };
+        Self {
+            strategy_type: StrategyTypeId::VortexCross,
+            enabled: true,
+            params: StrategyParams::VortexCross { periods },
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Phase 5: Oscillator Strategies
+    // -------------------------------------------------------------------------
+
+    /// Default CMO grid.
+    pub fn cmo_default() -> Self {
+        Self {
+            strategy_type: StrategyTypeId::Cmo,
+            enabled: true,
+            params: StrategyParams::Cmo {
+                periods: vec![9, 14, 20],
+                oversolds: vec![-50.0],
+                overboughts: vec![50.0],
+            },
+        }
+    }
+
+    /// CMO grid with specified sweep depth.
+    pub fn cmo_with_depth(depth: SweepDepth) -> Self {
+        let (periods, oversolds, overboughts) = match depth {
+            SweepDepth::Quick => (vec![14], vec![-50.0], vec![50.0]),
+            SweepDepth::Standard => (vec![9, 14, 20], vec![-50.0], vec![50.0]),
+            SweepDepth::Comprehensive => (
+                vec![9, 14, 20, 30],
+                vec![-60.0, -50.0, -40.0],
+                vec![40.0, 50.0, 60.0],
+            ),
+        };
+        Self {
+            strategy_type: StrategyTypeId::Cmo,
+            enabled: true,
+            params: StrategyParams::Cmo {
+                periods,
+                oversolds,
+                overboughts,
+            },
+        }
+    }
+
+    /// Default Fisher Transform grid.
+    pub fn fisher_default() -> Self {
+        Self {
+            strategy_type: StrategyTypeId::Fisher,
+            enabled: true,
+            params: StrategyParams::Fisher {
+                periods: vec![5, 10, 20],
+            },
+        }
+    }
+
+    /// Fisher Transform grid with specified sweep depth.
+    pub fn fisher_with_depth(depth: SweepDepth) -> Self {
+        let periods = match depth {
+            SweepDepth::Quick => vec![10],
+            SweepDepth::Standard => vec![5, 10, 20],
+            SweepDepth::Comprehensive => vec![5, 8, 10, 14, 20, 30],
+        };
+        Self {
+            strategy_type: StrategyTypeId::Fisher,
+            enabled: true,
+            params: StrategyParams::Fisher { periods },
+        }
+    }
+
+    /// Default TRIX grid.
+    pub fn trix_default() -> Self {
+        Self {
+            strategy_type: StrategyTypeId::Trix,
+            enabled: true,
+            params: StrategyParams::Trix {
+                periods: vec![9, 15, 20],
+                signal_periods: vec![None, Some(9)],
+            },
+        }
+    }
+
+    /// TRIX grid with specified sweep depth.
+    pub fn trix_with_depth(depth: SweepDepth) -> Self {
+        let (periods, signal_periods) = match depth {
+            SweepDepth::Quick => (vec![15], vec![None]),
+            SweepDepth::Standard => (vec![9, 15, 20], vec![None, Some(9)]),
+            SweepDepth::Comprehensive => (vec![9, 12, 15, 20, 30], vec![None, Some(5), Some(9)]),
+        };
+        Self {
+            strategy_type: StrategyTypeId::Trix,
+            enabled: true,
+            params: StrategyParams::Trix {
+                periods,
+                signal_periods,
+            },
+        }
+    }
+
+    /// Default Coppock Curve grid: the classic 14/11/10 and its daily-bar
+    /// scaling (months × 21).
+    pub fn coppock_default() -> Self {
+        Self {
+            strategy_type: StrategyTypeId::Coppock,
+            enabled: true,
+            params: StrategyParams::Coppock {
+                roc1s: vec![14, 294],
+                roc2s: vec![11, 231],
+                wma_periods: vec![10, 210],
+            },
+        }
+    }
+
+    /// Coppock Curve grid with specified sweep depth.
+    pub fn coppock_with_depth(depth: SweepDepth) -> Self {
+        let (roc1s, roc2s, wma_periods) = match depth {
+            SweepDepth::Quick => (vec![14], vec![11], vec![10]),
+            SweepDepth::Standard => (vec![14, 294], vec![11, 231], vec![10, 210]),
+            SweepDepth::Comprehensive => (
+                vec![14, 21, 63, 294],
+                vec![11, 15, 42, 231],
+                vec![10, 42, 210],
+            ),
+        };
+        Self {
+            strategy_type: StrategyTypeId::Coppock,
+            enabled: true,
+            params: StrategyParams::Coppock {
+                roc1s,
+                roc2s,
+                wma_periods,
+            },
+        }
+    }
+
+    /// Default Elder-Ray grid.
+    pub fn elder_ray_default() -> Self {
+        Self {
+            strategy_type: StrategyTypeId::ElderRay,
+            enabled: true,
+            params: StrategyParams::ElderRay {
+                ema_periods: vec![13, 21, 34],
+            },
+        }
+    }
+
+    /// Elder-Ray grid with specified sweep depth.
+    pub fn elder_ray_with_depth(depth: SweepDepth) -> Self {
+        let ema_periods = match depth {
+            SweepDepth::Quick => vec![13],
+            SweepDepth::Standard => vec![13, 21, 34],
+            SweepDepth::Comprehensive => vec![8, 13, 21, 34, 50],
+        };
+        Self {
+            strategy_type: StrategyTypeId::ElderRay,
+            enabled: true,
+            params: StrategyParams::ElderRay { ema_periods },
+        }
+    }
+
     /// Generate all configs for this strategy.
     pub fn generate_configs(&self) -> Vec<StrategyConfigId> {
         if !self.enabled {
@@ -4893,6 +5582,11 @@ impl StrategyGridConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultiStrategyGrid {
     pub strategies: Vec<StrategyGridConfig>,
+    /// Root seed for randomized exploration (e.g. YOLO jitter).
+    ///
+    /// 0 means unseeded: callers pick a fresh seed each run.
+    #[serde(default)]
+    pub seed: u64,
 }
 
 impl MultiStrategyGrid {
@@ -4916,7 +5610,18 @@ impl MultiStrategyGrid {
                 StrategyGridConfig::parabolic_sar_default(),
                 StrategyGridConfig::orb_default(),
                 StrategyGridConfig::ensemble_default(),
+                // Phase 6
+                StrategyGridConfig::obv_trend_default(),
+                StrategyGridConfig::vortex_cross_default(),
+                StrategyGridConfig::cmo_default(),
+                StrategyGridConfig::fisher_default(),
+                StrategyGridConfig::trix_default(),
+                StrategyGridConfig::coppock_default(),
+                StrategyGridConfig::elder_ray_default(),
+                // Benchmark
+                StrategyGridConfig::buy_hold(),
             ],
+            seed: 0,
         }
     }
 
@@ -4944,11 +5649,21 @@ impl MultiStrategyGrid {
             StrategyTypeId::ParabolicSar => StrategyGridConfig::parabolic_sar_default(),
             StrategyTypeId::OpeningRangeBreakout => StrategyGridConfig::orb_default(),
             StrategyTypeId::Ensemble => StrategyGridConfig::ensemble_default(),
+            // Phase 6
+            StrategyTypeId::ObvTrend => StrategyGridConfig::obv_trend_default(),
+            StrategyTypeId::VortexCross => StrategyGridConfig::vortex_cross_default(),
+            StrategyTypeId::Cmo => StrategyGridConfig::cmo_default(),
+            StrategyTypeId::Fisher => StrategyGridConfig::fisher_default(),
+            StrategyTypeId::Trix => StrategyGridConfig::trix_default(),
+            StrategyTypeId::Coppock => StrategyGridConfig::coppock_default(),
+            StrategyTypeId::ElderRay => StrategyGridConfig::elder_ray_default(),
+            StrategyTypeId::BuyHold => StrategyGridConfig::buy_hold(),
             // Phase 5 oscillator strategies - not yet implemented in grid
             _ => panic!("Grid config not yet implemented for this strategy type"),
         };
         Self {
             strategies: vec![config],
+            seed: 0,
         }
     }
 
@@ -4995,7 +5710,20 @@ impl MultiStrategyGrid {
                 StrategyGridConfig::parabolic_sar_delayed_with_depth(depth),
                 StrategyGridConfig::orb_with_depth(depth),
                 StrategyGridConfig::ensemble_with_depth(depth),
+                // Phase 6: Volume
+                StrategyGridConfig::obv_trend_with_depth(depth),
+                // Trend direction
+                StrategyGridConfig::vortex_cross_with_depth(depth),
+                // Oscillators
+                StrategyGridConfig::cmo_with_depth(depth),
+                StrategyGridConfig::fisher_with_depth(depth),
+                StrategyGridConfig::trix_with_depth(depth),
+                StrategyGridConfig::coppock_with_depth(depth),
+                StrategyGridConfig::elder_ray_with_depth(depth),
+                // Benchmark
+                StrategyGridConfig::buy_hold(),
             ],
+            seed: 0,
         }
     }
 
@@ -5051,14 +5779,30 @@ impl MultiStrategyGrid {
             }
             StrategyTypeId::OpeningRangeBreakout => StrategyGridConfig::orb_with_depth(depth),
             StrategyTypeId::Ensemble => StrategyGridConfig::ensemble_with_depth(depth),
+            // Phase 6: Volume
+            StrategyTypeId::ObvTrend => StrategyGridConfig::obv_trend_with_depth(depth),
+            StrategyTypeId::VortexCross => StrategyGridConfig::vortex_cross_with_depth(depth),
+            StrategyTypeId::Cmo => StrategyGridConfig::cmo_with_depth(depth),
+            StrategyTypeId::Fisher => StrategyGridConfig::fisher_with_depth(depth),
+            StrategyTypeId::Trix => StrategyGridConfig::trix_with_depth(depth),
+            StrategyTypeId::Coppock => StrategyGridConfig::coppock_with_depth(depth),
+            StrategyTypeId::ElderRay => StrategyGridConfig::elder_ray_with_depth(depth),
+            StrategyTypeId::BuyHold => StrategyGridConfig::buy_hold(),
             // Phase 5 oscillator strategies - not yet implemented in grid
             _ => panic!("Grid config not yet implemented for this strategy type"),
         };
         Self {
             strategies: vec![config],
+            seed: 0,
         }
     }
 
+    /// Set the root seed used for reproducible exploration.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
     /// Total number of configurations across all strategies.
     pub fn total_configs(&self) -> usize {
         self.strategies.iter().map(|s| s.config_count()).sum()
@@ -5142,10 +5886,20 @@ impl MultiStrategySweepResult {
         self.completed_at = Utc::now();
     }
 
+    /// Results in (symbol, strategy id) order, so tie-breaks don't depend on
+    /// HashMap iteration order.
+    fn sorted_results(
+        results: &HashMap<(String, StrategyTypeId), SweepResult>,
+    ) -> Vec<(&(String, StrategyTypeId), &SweepResult)> {
+        let mut sorted: Vec<_> = results.iter().collect();
+        sorted.sort_by(|(a, _), (b, _)| (&a.0, a.1.id()).cmp(&(&b.0, b.1.id())));
+        sorted
+    }
+
     fn compute_best_per_symbol(&mut self) {
         // Group results by symbol
         let mut by_symbol: HashMap<String, Vec<(&StrategyTypeId, &SweepResult)>> = HashMap::new();
-        for ((symbol, strategy_type), result) in &self.results {
+        for ((symbol, strategy_type), result) in Self::sorted_results(&self.results) {
             by_symbol
                 .entry(symbol.clone())
                 .or_default()
@@ -5215,7 +5969,7 @@ impl MultiStrategySweepResult {
     fn compute_best_per_strategy(&mut self) {
         // Group results by strategy type
         let mut by_strategy: HashMap<StrategyTypeId, Vec<(&String, &SweepResult)>> = HashMap::new();
-        for ((symbol, strategy_type), result) in &self.results {
+        for ((symbol, strategy_type), result) in Self::sorted_results(&self.results) {
             by_strategy
                 .entry(*strategy_type)
                 .or_default()
@@ -5286,7 +6040,7 @@ impl MultiStrategySweepResult {
     fn compute_strategy_comparison(&mut self) {
         // Group results by strategy type
         let mut by_strategy: HashMap<StrategyTypeId, Vec<&Metrics>> = HashMap::new();
-        for ((_, strategy_type), result) in &self.results {
+        for ((_, strategy_type), result) in Self::sorted_results(&self.results) {
             for config_result in &result.config_results {
                 by_strategy
                     .entry(*strategy_type)
@@ -5322,15 +6076,103 @@ impl MultiStrategySweepResult {
             });
         }
 
-        // Sort by best sharpe descending
+        // Sort by best sharpe descending, ties by strategy id
         self.strategy_comparison.sort_by(|a, b| {
             b.best_sharpe
                 .partial_cmp(&a.best_sharpe)
                 .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.strategy_type.id().cmp(b.strategy_type.id()))
         });
     }
 }
 
+/// Derive a sub-seed for one config (or strategy, symbol, iteration...) from a root seed.
+///
+/// `key` is the explicit byte encoding of the unit of work (e.g. a strategy id or
+/// a little-endian iteration number). Seed and key go through FNV-1a, so the result
+/// is the same across runs, platforms, and compiler versions.
+///
+/// Giving each unit of work its own RNG seeded this way makes results
+/// independent of the order threads happen to pick work up in.
+pub fn derive_seed(seed: u64, key: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &b in seed.to_le_bytes().iter().chain(key) {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+
+    // SplitMix64 finalizer so nearby keys give unrelated seeds
+    let mut z = hash.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Number of permutations used per strategy when computing FDR p-values.
+const FDR_PERMUTATIONS: usize = 1000;
+
+/// Prune multi-strategy results to strategies that survive multiple comparison control.
+///
+/// For each strategy, the best config's equity-curve returns are permutation-tested
+/// against a flat (zero-return) benchmark. Strategies whose best config lost money
+/// get a p-value of 1.0. The p-values are then adjusted with `method` and each
+/// strategy is flagged as surviving when its adjusted p-value is below `alpha`.
+///
+/// Each strategy's permutations are seeded with `derive_seed(seed, strategy id)`,
+/// so its p-value does not change when other strategies join or leave the run.
+///
+/// Strategies are returned in `StrategyTypeId::all()` order.
+pub fn apply_fdr(
+    result: &MultiStrategySweepResult,
+    alpha: f64,
+    method: MultipleComparisonMethod,
+    seed: u64,
+) -> Vec<(StrategyTypeId, bool)> {
+    let (strategies, p_values): (Vec<StrategyTypeId>, Vec<f64>) =
+        fdr_p_values(result, seed).into_iter().unzip();
+
+    match adjust_p_values(&p_values, alpha, method) {
+        Ok(adjusted) => strategies.into_iter().zip(adjusted.rejections).collect(),
+        Err(_) => strategies.into_iter().map(|st| (st, false)).collect(),
+    }
+}
+
+/// Unadjusted per-strategy p-values used by [`apply_fdr`], in `StrategyTypeId::all()` order.
+pub(crate) fn fdr_p_values(
+    result: &MultiStrategySweepResult,
+    seed: u64,
+) -> Vec<(StrategyTypeId, f64)> {
+    StrategyTypeId::all()
+        .into_iter()
+        .filter(|st| result.best_per_strategy.contains_key(st))
+        .map(|st| {
+            let equity = &result.best_per_strategy[&st].equity_curve;
+            let returns: Vec<f64> = equity
+                .windows(2)
+                .map(|w| {
+                    if w[0].abs() > 1e-10 {
+                        (w[1] - w[0]) / w[0]
+                    } else {
+                        0.0
+                    }
+                })
+                .collect();
+            if returns.len() < 3 {
+                return (st, 1.0);
+            }
+
+            let benchmark = vec![0.0; returns.len()];
+            let strategy_seed = derive_seed(seed, st.id().as_bytes());
+            let p_value =
+                match permutation_test(&returns, &benchmark, FDR_PERMUTATIONS, strategy_seed) {
+                    Ok(perm) if perm.observed_statistic > 0.0 => perm.p_value,
+                    _ => 1.0,
+                };
+            (st, p_value)
+        })
+        .collect()
+}
+
 /// Convert legacy ConfigId to StrategyConfigId (for backwards compatibility).
 fn config_id_to_strategy_config_id(
     config_id: &ConfigId,
@@ -5343,6 +6185,7 @@ fn config_id_to_strategy_config_id(
         },
         StrategyTypeId::TurtleS1 => StrategyConfigId::TurtleS1,
         StrategyTypeId::TurtleS2 => StrategyConfigId::TurtleS2,
+        StrategyTypeId::BuyHold => StrategyConfigId::BuyHold,
         StrategyTypeId::MACrossover => StrategyConfigId::MACrossover {
             fast: config_id.entry_lookback,
             slow: config_id.exit_lookback,
@@ -5537,7 +6380,7 @@ pub fn create_strategy_from_config(config: &StrategyConfigId) -> Box<dyn Strateg
         } => Box::new(EnsembleStrategy::from_base_strategy(
             *base_strategy,
             horizons.clone(),
-            *voting,
+            voting.clone(),
         )),
         // Phase 1: ATR-Based Channels
         StrategyConfigId::Keltner {
@@ -5642,6 +6485,25 @@ pub fn create_strategy_from_config(config: &StrategyConfigId) -> Box<dyn Strateg
             exit_threshold,
         } => Box::new(CCIStrategy::new(*period, *entry_threshold, *exit_threshold)),
         StrategyConfigId::Roc { period } => Box::new(ROCStrategy::new(*period)),
+        StrategyConfigId::Cmo {
+            period,
+            oversold,
+            overbought,
+        } => Box::new(CmoStrategy::new(*period, *oversold, *overbought)),
+        StrategyConfigId::Fisher { period } => Box::new(FisherStrategy::new(*period)),
+        StrategyConfigId::Trix {
+            period,
+            signal_period,
+        } => match signal_period {
+            Some(signal) => Box::new(TrixStrategy::with_signal(*period, *signal)),
+            None => Box::new(TrixStrategy::new(*period)),
+        },
+        StrategyConfigId::Coppock {
+            roc1,
+            roc2,
+            wma_period,
+        } => Box::new(CoppockStrategy::new(*roc1, *roc2, *wma_period)),
+        StrategyConfigId::ElderRay { ema_period } => Box::new(ElderRayStrategy::new(*ema_period)),
         StrategyConfigId::RsiBollinger {
             rsi_period,
             rsi_oversold,
@@ -5696,6 +6558,12 @@ pub fn create_strategy_from_config(config: &StrategyConfigId) -> Box<dyn Strateg
             *kijun_period,
             *senkou_b_period,
         )),
+        StrategyConfigId::ObvTrend {
+            slope_period,
+            sma_period,
+        } => Box::new(ObvTrendStrategy::new(*slope_period, *sma_period)),
+        StrategyConfigId::VortexCross { period } => Box::new(VortexCrossStrategy::new(*period)),
+        StrategyConfigId::BuyHold => Box::new(BuyHoldStrategy),
         // Combo strategies (2-way and 3-way combinations of different strategy types)
         StrategyConfigId::Combo { components, voting } => {
             // Build each component strategy recursively
@@ -5708,7 +6576,7 @@ pub fn create_strategy_from_config(config: &StrategyConfigId) -> Box<dyn Strateg
                 .iter()
                 .map(|s| s.warmup_period().max(1))
                 .collect();
-            Box::new(EnsembleStrategy::new(strategies, horizons, *voting))
+            Box::new(EnsembleStrategy::new(strategies, horizons, voting.clone()))
         }
     }
 }
@@ -5757,6 +6625,7 @@ pub fn run_strategy_sweep(
         config_results,
         started_at,
         completed_at,
+        cancelled: false,
     }
 }
 
@@ -5806,6 +6675,23 @@ mod tests {
         assert_eq!(result.len(), 4);
     }
 
+    #[test]
+    fn test_coppock_sweep_on_short_fixture() {
+        // 100 bars is far short of the daily-scaled Coppock warmup
+        let bars = make_test_bars(100);
+        let grid = StrategyGridConfig::coppock_default();
+        let configs = grid.generate_configs();
+        assert_eq!(configs.len(), 6);
+
+        let result = run_strategy_sweep(&bars, &grid, BacktestConfig::default());
+        assert_eq!(result.len(), configs.len());
+        for config_result in &result.config_results {
+            if config_result.config_id.entry_lookback > 100 {
+                assert_eq!(config_result.metrics.num_trades, 0);
+            }
+        }
+    }
+
     #[test]
     fn test_ranking() {
         let bars = make_test_bars(100);
@@ -5822,10 +6708,218 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ranking_breaks_ties_deterministically() {
+        let make = |entry, exit, num_trades| SweepConfigResult {
+            config_id: ConfigId::new(entry, exit),
+            backtest_result: BacktestResult::default(),
+            metrics: Metrics {
+                sharpe: 1.5,
+                num_trades,
+                ..Default::default()
+            },
+        };
+        let ranked_ids = |config_results: Vec<SweepConfigResult>| -> Vec<String> {
+            let result = SweepResult {
+                sweep_id: "ties".to_string(),
+                config_results,
+                started_at: Utc::now(),
+                completed_at: Utc::now(),
+                cancelled: false,
+            };
+            result
+                .top_n(3, RankMetric::Sharpe, false)
+                .into_iter()
+                .map(|r| r.config_id.id())
+                .collect()
+        };
+
+        // Equal Sharpe: more trades first, then config id ascending
+        let expected = vec!["donchian_30_10", "donchian_20_10", "donchian_25_10"];
+        assert_eq!(
+            ranked_ids(vec![make(25, 10, 8), make(20, 10, 8), make(30, 10, 12)]),
+            expected
+        );
+        // Insertion order does not matter
+        assert_eq!(
+            ranked_ids(vec![make(30, 10, 12), make(25, 10, 8), make(20, 10, 8)]),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_custom_rank_metric_reorders_top_n() {
+        let make = |entry, sharpe, calmar, num_trades| SweepConfigResult {
+            config_id: ConfigId::new(entry, 10),
+            backtest_result: BacktestResult::default(),
+            metrics: Metrics {
+                sharpe,
+                calmar,
+                num_trades,
+                ..Default::default()
+            },
+        };
+        let result = SweepResult {
+            sweep_id: "custom".to_string(),
+            config_results: vec![
+                make(20, 2.0, 0.5, 40),
+                make(30, 1.5, 2.0, 10),
+                make(40, 1.0, 1.0, 5),
+            ],
+            started_at: Utc::now(),
+            completed_at: Utc::now(),
+            cancelled: false,
+        };
+        let ids = |metric| -> Vec<String> {
+            result
+                .top_n(3, metric, false)
+                .into_iter()
+                .map(|r| r.config_id.id())
+                .collect()
+        };
+
+        let custom = RankMetric::Custom(RankWeights {
+            sharpe: 0.5,
+            calmar: 0.5,
+            trade_penalty: 0.01,
+            ..Default::default()
+        });
+        // Scores: 0.85, 1.65, 0.95
+        assert_eq!(
+            ids(custom),
+            ["donchian_30_10", "donchian_40_10", "donchian_20_10"]
+        );
+        assert_eq!(
+            ids(RankMetric::Sharpe),
+            ["donchian_20_10", "donchian_30_10", "donchian_40_10"]
+        );
+    }
+
     #[test]
     fn test_result_paths() {
         let paths = ResultPaths::for_sweep("test_sweep_001");
         assert!(paths.manifest.to_string_lossy().contains("test_sweep_001"));
         assert!(paths.manifest.to_string_lossy().ends_with("manifest.json"));
     }
+
+    #[test]
+    fn test_top_n_diverse_spreads_selection() {
+        // Sharpe peaks at (20, 10) and decays with distance, so the plain top 5
+        // are all adjacent neighbors of the peak.
+        let mut config_results = Vec::new();
+        for entry in 15usize..=40 {
+            for exit in 5usize..=15 {
+                let dist = entry.abs_diff(20).max(exit.abs_diff(10)) as f64;
+                config_results.push(SweepConfigResult {
+                    config_id: ConfigId::new(entry, exit),
+                    backtest_result: BacktestResult::default(),
+                    metrics: Metrics {
+                        sharpe: 2.0 - dist * 0.1 - (entry as f64) * 1e-4,
+                        ..Default::default()
+                    },
+                });
+            }
+        }
+        let result = SweepResult {
+            sweep_id: "diverse".to_string(),
+            config_results,
+            started_at: Utc::now(),
+            completed_at: Utc::now(),
+            cancelled: false,
+        };
+
+        let plain: Vec<ConfigId> = result
+            .top_n(5, RankMetric::Sharpe, false)
+            .into_iter()
+            .map(|r| r.config_id.clone())
+            .collect();
+        assert_eq!(plain[0], ConfigId::new(20, 10));
+        assert!(plain.iter().all(|c| {
+            c.entry_lookback
+                .abs_diff(20)
+                .max(c.exit_lookback.abs_diff(10))
+                <= 1
+        }));
+
+        let diverse = top_n_diverse(&result, 5, RankMetric::Sharpe, 5);
+        assert_eq!(diverse.len(), 5);
+        assert_eq!(diverse[0], ConfigId::new(20, 10));
+        for (i, a) in diverse.iter().enumerate() {
+            for b in diverse.iter().skip(i + 1) {
+                let dist = a
+                    .entry_lookback
+                    .abs_diff(b.entry_lookback)
+                    .max(a.exit_lookback.abs_diff(b.exit_lookback));
+                assert!(dist >= 5, "{:?} and {:?} are too close", a, b);
+            }
+        }
+
+        // Distance of zero degenerates to the plain top N
+        assert_eq!(top_n_diverse(&result, 5, RankMetric::Sharpe, 0), plain);
+    }
+
+    #[test]
+    fn test_derive_seed_is_stable_and_key_sensitive() {
+        let a = derive_seed(42, b"donchian");
+        assert_eq!(a, derive_seed(42, b"donchian"));
+        assert_ne!(a, derive_seed(43, b"donchian"));
+        assert_ne!(a, derive_seed(42, b"tsmom"));
+        // Pinned so an accidental change to the hash shows up as a failure
+        assert_eq!(a, 0x1c46_1a7a_8ce5_8a2d);
+    }
+
+    #[test]
+    fn test_apply_fdr_keeps_only_edged_strategy() {
+        use rand::rngs::SmallRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(11);
+        let mut equity_from = |drift: f64| {
+            let mut noise: Vec<f64> = (0..250).map(|_| rng.gen_range(-0.01..0.01)).collect();
+            // Center the noise so only the drift carries an edge
+            let mean = noise.iter().sum::<f64>() / noise.len() as f64;
+            noise.iter_mut().for_each(|x| *x -= mean);
+
+            let mut equity = vec![100_000.0];
+            for r in noise {
+                let last = *equity.last().unwrap();
+                equity.push(last * (1.0 + drift + r));
+            }
+            equity
+        };
+
+        let mut result = MultiStrategySweepResult::new("fdr_test".to_string());
+        let strategies = [
+            StrategyTypeId::Donchian,
+            StrategyTypeId::TurtleS1,
+            StrategyTypeId::TurtleS2,
+            StrategyTypeId::MACrossover,
+            StrategyTypeId::Tsmom,
+        ];
+        for (i, &strategy_type) in strategies.iter().enumerate() {
+            let drift = if i == 0 { 0.003 } else { 0.0 };
+            result.best_per_strategy.insert(
+                strategy_type,
+                StrategyBestResult {
+                    strategy_type,
+                    config_id: StrategyConfigId::TurtleS1,
+                    symbol: None,
+                    metrics: Metrics::default(),
+                    equity_curve: equity_from(drift),
+                    dates: Vec::new(),
+                },
+            );
+        }
+
+        let survivors = apply_fdr(
+            &result,
+            0.05,
+            MultipleComparisonMethod::BenjaminiHochberg,
+            7,
+        );
+
+        assert_eq!(survivors.len(), 5);
+        assert_eq!(survivors[0], (StrategyTypeId::Donchian, true));
+        assert!(survivors[1..].iter().all(|(_, survives)| !survives));
+    }
 }
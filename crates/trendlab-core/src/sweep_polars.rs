@@ -365,9 +365,8 @@ impl SweepQuery {
         }
 
         // Sort by specified metric
-        let sort_col = metric_to_col_name(self.sort_by);
         let sort_opts = SortMultipleOptions::new().with_order_descending(!self.ascending);
-        lf = lf.sort([sort_col], sort_opts);
+        lf = lf.sort_by_exprs([metric_expr(self.sort_by)], sort_opts);
 
         // Apply limit
         if let Some(n) = self.limit {
@@ -421,13 +420,11 @@ pub fn top_configs_by_sharpe(
 /// suitable for plotting as a heatmap.
 pub fn parameter_heatmap(result: &SweepResult, metric: RankMetric) -> PolarsResult<DataFrame> {
     let df = sweep_to_dataframe(result)?;
-    let metric_col = metric_to_col_name(metric);
-
     df.lazy()
         .select([
             col("entry_lookback"),
             col("exit_lookback"),
-            col(metric_col).alias("value"),
+            metric_expr(metric).alias("value"),
         ])
         .collect()
 }
@@ -435,7 +432,7 @@ pub fn parameter_heatmap(result: &SweepResult, metric: RankMetric) -> PolarsResu
 /// Calculate parameter sensitivity: variance of metric across parameter changes.
 pub fn parameter_sensitivity(result: &SweepResult, metric: RankMetric) -> PolarsResult<DataFrame> {
     let df = sweep_to_dataframe(result)?;
-    let metric_col = metric_to_col_name(metric);
+    let metric_col = metric_expr(metric);
 
     // Group by entry_lookback and compute variance of metric across exit_lookbacks
     let entry_sensitivity = df
@@ -443,8 +440,8 @@ pub fn parameter_sensitivity(result: &SweepResult, metric: RankMetric) -> Polars
         .lazy()
         .group_by([col("entry_lookback")])
         .agg([
-            col(metric_col).mean().alias("mean_value"),
-            col(metric_col).var(0).alias("variance"),
+            metric_col.clone().mean().alias("mean_value"),
+            metric_col.clone().var(0).alias("variance"),
             len().alias("n_configs"),
         ])
         .with_column(lit("entry_lookback").alias("parameter"))
@@ -464,8 +461,8 @@ pub fn parameter_sensitivity(result: &SweepResult, metric: RankMetric) -> Polars
         .lazy()
         .group_by([col("exit_lookback")])
         .agg([
-            col(metric_col).mean().alias("mean_value"),
-            col(metric_col).var(0).alias("variance"),
+            metric_col.clone().mean().alias("mean_value"),
+            metric_col.clone().var(0).alias("variance"),
             len().alias("n_configs"),
         ])
         .with_column(lit("exit_lookback").alias("parameter"))
@@ -509,17 +506,30 @@ pub fn compare_strategies(result: &MultiStrategySweepResult) -> PolarsResult<Dat
         .collect()
 }
 
-/// Convert RankMetric to DataFrame column name.
-fn metric_to_col_name(metric: RankMetric) -> &'static str {
+/// Convert RankMetric to a DataFrame column expression.
+///
+/// `Custom` weights become the same weighted score as [`crate::sweep::RankWeights::score`].
+fn metric_expr(metric: RankMetric) -> Expr {
     match metric {
-        RankMetric::Sharpe => "sharpe",
-        RankMetric::Cagr => "cagr",
-        RankMetric::Sortino => "sortino",
-        RankMetric::MaxDrawdown => "max_drawdown",
-        RankMetric::Calmar => "calmar",
-        RankMetric::WinRate => "win_rate",
-        RankMetric::ProfitFactor => "profit_factor",
-        RankMetric::TotalReturn => "total_return",
+        RankMetric::Sharpe => col("sharpe"),
+        RankMetric::Cagr => col("cagr"),
+        RankMetric::Sortino => col("sortino"),
+        RankMetric::MaxDrawdown => col("max_drawdown"),
+        RankMetric::Calmar => col("calmar"),
+        RankMetric::WinRate => col("win_rate"),
+        RankMetric::ProfitFactor => col("profit_factor"),
+        RankMetric::TotalReturn => col("total_return"),
+        RankMetric::Custom(w) => {
+            lit(w.sharpe) * col("sharpe")
+                + lit(w.sortino) * col("sortino")
+                + lit(w.calmar) * col("calmar")
+                + lit(w.cagr) * col("cagr")
+                + lit(w.total_return) * col("total_return")
+                + lit(w.max_drawdown) * col("max_drawdown")
+                + lit(w.win_rate) * col("win_rate")
+                + lit(w.profit_factor) * col("profit_factor")
+                - lit(w.trade_penalty) * col("num_trades").cast(DataType::Float64)
+        }
     }
 }
 
@@ -644,6 +654,7 @@ mod tests {
                     trades: vec![],
                     fills: vec![],
                     pyramid_trades: vec![],
+                    vs_benchmark: None,
                 },
                 metrics: Metrics {
                     total_return: 0.05,
@@ -659,6 +670,8 @@ mod tests {
                     max_consecutive_losses: 0,
                     max_consecutive_wins: 0,
                     avg_losing_streak: 0.0,
+                    ulcer_index: 0.0,
+                    upi: 0.0,
                 },
             },
             SweepConfigResult {
@@ -674,6 +687,7 @@ mod tests {
                     trades: vec![],
                     fills: vec![],
                     pyramid_trades: vec![],
+                    vs_benchmark: None,
                 },
                 metrics: Metrics {
                     total_return: 0.10,
@@ -689,6 +703,8 @@ mod tests {
                     max_consecutive_losses: 0,
                     max_consecutive_wins: 0,
                     avg_losing_streak: 0.0,
+                    ulcer_index: 0.0,
+                    upi: 0.0,
                 },
             },
             SweepConfigResult {
@@ -704,6 +720,7 @@ mod tests {
                     trades: vec![],
                     fills: vec![],
                     pyramid_trades: vec![],
+                    vs_benchmark: None,
                 },
                 metrics: Metrics {
                     total_return: -0.05,
@@ -719,6 +736,8 @@ mod tests {
                     max_consecutive_losses: 0,
                     max_consecutive_wins: 0,
                     avg_losing_streak: 0.0,
+                    ulcer_index: 0.0,
+                    upi: 0.0,
                 },
             },
         ];
@@ -728,6 +747,7 @@ mod tests {
             config_results,
             started_at: Utc::now(),
             completed_at: Utc::now(),
+            cancelled: false,
         }
     }
 
@@ -787,6 +807,31 @@ mod tests {
         assert!(df.column("value").is_ok());
     }
 
+    #[test]
+    fn test_custom_rank_metric_matches_score() {
+        use crate::sweep::RankWeights;
+
+        let result = make_test_sweep_result();
+        let weights = RankWeights {
+            sharpe: 1.0,
+            calmar: 0.5,
+            trade_penalty: 0.01,
+            ..Default::default()
+        };
+        let df = parameter_heatmap(&result, RankMetric::Custom(weights)).unwrap();
+        let values = df.column("value").unwrap().f64().unwrap();
+        for (i, r) in result.config_results.iter().enumerate() {
+            assert!((values.get(i).unwrap() - weights.score(&r.metrics)).abs() < 1e-9);
+        }
+
+        let df = sweep_to_dataframe(&result).unwrap();
+        let ranked = SweepQuery::new()
+            .sort(RankMetric::Custom(weights), false)
+            .execute(df)
+            .unwrap();
+        assert_eq!(ranked.count, result.config_results.len());
+    }
+
     #[test]
     fn test_parameter_sensitivity() {
         let result = make_test_sweep_result();
@@ -3,8 +3,11 @@
 //! Provides:
 //! - Sector and Universe types for organizing tickers
 //! - TOML-based configuration loading
+//! - CSV/JSON (symbol, sector) manifest loading
 //! - Utility methods for ticker lookups
+//! - Liquidity filtering by average dollar volume
 
+use crate::bar::Bar;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
@@ -21,6 +24,33 @@ pub enum UniverseError {
 
     #[error("Sector not found: {0}")]
     SectorNotFound(String),
+
+    #[error("Failed to parse universe JSON: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    /// A manifest row that can't be read. `line` is the CSV line number or
+    /// the 1-based JSON entry number.
+    #[error("Row {line}: {message}")]
+    MalformedRow { line: usize, message: String },
+
+    #[error("Row {line}: symbol {symbol} has no sector")]
+    MissingSector { line: usize, symbol: String },
+
+    #[error("Row {line}: symbol {symbol} is in both {first} and {second}")]
+    ConflictingSector {
+        line: usize,
+        symbol: String,
+        first: String,
+        second: String,
+    },
+}
+
+/// One (symbol, sector) row of a CSV or JSON universe manifest.
+#[derive(Debug, Clone, Deserialize)]
+struct ManifestRow {
+    symbol: String,
+    #[serde(default)]
+    sector: Option<String>,
 }
 
 /// A sector containing related tickers.
@@ -107,6 +137,130 @@ impl Universe {
         })
     }
 
+    /// Load a universe from a CSV manifest of `symbol,sector` rows.
+    ///
+    /// An optional `symbol,sector` header is skipped, as are blank lines and
+    /// `#` comments. Sectors are created in order of first appearance, with an
+    /// ID derived from the name ("Consumer Defensive" -> "consumer_defensive").
+    /// Repeated symbols in the same sector are dropped; a symbol listed under
+    /// two sectors is an error. The universe is named after the file stem.
+    pub fn from_csv(path: impl AsRef<Path>) -> Result<Self, UniverseError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        Self::from_csv_str(&content, &manifest_name(path))
+    }
+
+    /// Parse a universe from CSV manifest content (see [`Universe::from_csv`]).
+    pub fn from_csv_str(content: &str, name: &str) -> Result<Self, UniverseError> {
+        let mut rows = Vec::new();
+        for (idx, raw) in content.lines().enumerate() {
+            let line = idx + 1;
+            let trimmed = raw.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = trimmed
+                .split(',')
+                .map(|f| f.trim().trim_matches('"').trim())
+                .collect();
+            if rows.is_empty() && fields[0].eq_ignore_ascii_case("symbol") {
+                continue;
+            }
+            if fields.len() > 2 {
+                return Err(UniverseError::MalformedRow {
+                    line,
+                    message: format!(
+                        "expected 2 columns (symbol, sector), found {}",
+                        fields.len()
+                    ),
+                });
+            }
+
+            rows.push((
+                line,
+                ManifestRow {
+                    symbol: fields[0].to_string(),
+                    sector: fields.get(1).map(|s| s.to_string()),
+                },
+            ));
+        }
+
+        Self::from_manifest_rows(name, rows)
+    }
+
+    /// Load a universe from a JSON manifest: an array of
+    /// `{"symbol": "AAPL", "sector": "Technology"}` objects.
+    ///
+    /// Same rules as [`Universe::from_csv`]; errors report the 1-based entry number.
+    pub fn from_json(path: impl AsRef<Path>) -> Result<Self, UniverseError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        Self::from_json_str(&content, &manifest_name(path))
+    }
+
+    /// Parse a universe from JSON manifest content (see [`Universe::from_json`]).
+    pub fn from_json_str(content: &str, name: &str) -> Result<Self, UniverseError> {
+        let entries: Vec<ManifestRow> = serde_json::from_str(content)?;
+        let rows = entries
+            .into_iter()
+            .enumerate()
+            .map(|(idx, row)| (idx + 1, row))
+            .collect();
+        Self::from_manifest_rows(name, rows)
+    }
+
+    /// Group validated manifest rows into sectors.
+    fn from_manifest_rows(
+        name: &str,
+        rows: Vec<(usize, ManifestRow)>,
+    ) -> Result<Self, UniverseError> {
+        let mut sectors: Vec<Sector> = Vec::new();
+        let mut sector_of: HashMap<String, String> = HashMap::new();
+
+        for (line, row) in rows {
+            let symbol = row.symbol.trim().to_string();
+            if symbol.is_empty() {
+                return Err(UniverseError::MalformedRow {
+                    line,
+                    message: "empty symbol".to_string(),
+                });
+            }
+            let sector_name = match row.sector.as_deref().map(str::trim) {
+                Some(s) if !s.is_empty() => s.to_string(),
+                _ => return Err(UniverseError::MissingSector { line, symbol }),
+            };
+
+            if let Some(first) = sector_of.get(&symbol) {
+                if *first == sector_name {
+                    continue;
+                }
+                return Err(UniverseError::ConflictingSector {
+                    line,
+                    symbol,
+                    first: first.clone(),
+                    second: sector_name,
+                });
+            }
+            sector_of.insert(symbol.clone(), sector_name.clone());
+
+            match sectors.iter_mut().find(|s| s.name == sector_name) {
+                Some(sector) => sector.tickers.push(symbol),
+                None => sectors.push(Sector::new(
+                    sector_id(&sector_name),
+                    sector_name,
+                    vec![symbol],
+                )),
+            }
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            description: String::new(),
+            sectors,
+        })
+    }
+
     /// Returns the number of sectors in this universe.
     pub fn sector_count(&self) -> usize {
         self.sectors.len()
@@ -190,6 +344,56 @@ impl Universe {
         lookup
     }
 
+    /// Keep only symbols liquid enough to trade.
+    ///
+    /// A symbol survives if its average `close × volume` over the trailing
+    /// `lookback` bars is at least `min_avg_dollar_volume`. Symbols missing
+    /// from `bars_by_symbol` or with fewer than `lookback` bars are dropped,
+    /// as are sectors left empty.
+    pub fn filter_by_liquidity(
+        &self,
+        bars_by_symbol: &HashMap<String, Vec<Bar>>,
+        min_avg_dollar_volume: f64,
+        lookback: usize,
+    ) -> Universe {
+        let lookback = lookback.max(1);
+        let is_liquid = |ticker: &String| {
+            let Some(bars) = bars_by_symbol.get(ticker) else {
+                return false;
+            };
+            if bars.len() < lookback {
+                return false;
+            }
+            let recent = &bars[bars.len() - lookback..];
+            let avg = recent.iter().map(|b| b.close * b.volume).sum::<f64>() / lookback as f64;
+            avg >= min_avg_dollar_volume
+        };
+
+        let sectors = self
+            .sectors
+            .iter()
+            .map(|sector| {
+                Sector::new(
+                    sector.id.clone(),
+                    sector.name.clone(),
+                    sector
+                        .tickers
+                        .iter()
+                        .filter(|t| is_liquid(t))
+                        .cloned()
+                        .collect(),
+                )
+            })
+            .filter(|sector| !sector.is_empty())
+            .collect();
+
+        Universe {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            sectors,
+        }
+    }
+
     /// Get tickers for a specific sector by ID.
     pub fn tickers_for_sector(&self, sector_id: &str) -> Result<&[String], UniverseError> {
         self.get_sector(sector_id)
@@ -450,6 +654,22 @@ impl Universe {
     }
 }
 
+/// Universe name for a manifest file: its stem, e.g. "watchlist" for `watchlist.csv`.
+fn manifest_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Machine-readable sector ID from a display name ("ETF - Energy" -> "etf_energy").
+fn sector_id(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -556,4 +776,106 @@ tickers = ["JPM", "GS"]
         assert_eq!(lookup.get("XOM"), Some(&"energy".to_string()));
         assert_eq!(lookup.get("LLY"), Some(&"healthcare".to_string()));
     }
+
+    #[test]
+    fn test_universe_from_csv() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("watchlist.csv");
+        std::fs::write(
+            &path,
+            "symbol,sector\n\
+             AAPL,Technology\n\
+             MSFT,Technology\n\
+             \n\
+             # banks\n\
+             JPM,Financial\n\
+             AAPL,Technology\n\
+             GS, Financial\n\
+             XOM,Energy\n",
+        )
+        .unwrap();
+
+        let universe = Universe::from_csv(&path).unwrap();
+        assert_eq!(universe.name, "watchlist");
+        assert_eq!(universe.sector_count(), 3);
+        assert_eq!(universe.ticker_count(), 5);
+        assert_eq!(universe.get_sector("technology").unwrap().len(), 2);
+        assert_eq!(universe.get_sector("financial").unwrap().len(), 2);
+        assert_eq!(universe.get_sector("energy").unwrap().len(), 1);
+
+        let lookup = universe.build_sector_id_lookup();
+        assert_eq!(lookup.len(), 5);
+        assert_eq!(lookup.get("AAPL"), Some(&"technology".to_string()));
+        assert_eq!(lookup.get("GS"), Some(&"financial".to_string()));
+        assert_eq!(lookup.get("XOM"), Some(&"energy".to_string()));
+    }
+
+    #[test]
+    fn test_universe_manifest_errors() {
+        let err = Universe::from_csv_str("AAPL,Technology\nSPY,\n", "t").unwrap_err();
+        assert!(matches!(err, UniverseError::MissingSector { line: 2, .. }));
+
+        let err = Universe::from_csv_str("AAPL,Tech\nAAPL,Consumer\n", "t").unwrap_err();
+        assert!(matches!(
+            err,
+            UniverseError::ConflictingSector { line: 2, .. }
+        ));
+
+        let err = Universe::from_csv_str("AAPL,Tech,extra\n", "t").unwrap_err();
+        assert!(matches!(err, UniverseError::MalformedRow { line: 1, .. }));
+
+        let json = r#"[{"symbol": "AAPL", "sector": "Technology"}, {"symbol": "SPY"}]"#;
+        let err = Universe::from_json_str(json, "t").unwrap_err();
+        assert!(matches!(err, UniverseError::MissingSector { line: 2, .. }));
+
+        let json = r#"[{"symbol": "SPY", "sector": "ETF - Broad Market"}]"#;
+        let universe = Universe::from_json_str(json, "t").unwrap();
+        assert_eq!(
+            universe.build_sector_id_lookup().get("SPY"),
+            Some(&"etf_broad_market".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filter_by_liquidity() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let bars = |symbol: &str, volume: f64, n: i64| -> Vec<Bar> {
+            let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+            (0..n)
+                .map(|i| {
+                    Bar::new(
+                        start + Duration::days(i),
+                        50.0,
+                        51.0,
+                        49.0,
+                        50.0,
+                        volume,
+                        symbol,
+                        "1d",
+                    )
+                })
+                .collect()
+        };
+
+        let universe = Universe {
+            name: "test".to_string(),
+            description: String::new(),
+            sectors: vec![
+                Sector::new("tech", "Technology", vec!["BIG".into(), "TINY".into()]),
+                Sector::new("misc", "Misc", vec!["NEW".into(), "NODATA".into()]),
+            ],
+        };
+        let mut bars_by_symbol = HashMap::new();
+        // $50 × 1M shares = $50M/day vs $50 × 1k = $50k/day
+        bars_by_symbol.insert("BIG".to_string(), bars("BIG", 1_000_000.0, 30));
+        bars_by_symbol.insert("TINY".to_string(), bars("TINY", 1_000.0, 30));
+        // Liquid but only 5 bars of history
+        bars_by_symbol.insert("NEW".to_string(), bars("NEW", 1_000_000.0, 5));
+
+        let liquid = universe.filter_by_liquidity(&bars_by_symbol, 1_000_000.0, 20);
+        assert_eq!(liquid.all_tickers_sorted(), vec!["BIG".to_string()]);
+        assert_eq!(liquid.sector_count(), 1);
+        assert_eq!(liquid.get_sector("tech").unwrap().tickers, vec!["BIG"]);
+    }
 }
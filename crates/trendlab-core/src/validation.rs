@@ -4,11 +4,13 @@
 //! realistic out-of-sample performance:
 //! - Walk-forward analysis (rolling optimization/test windows)
 //! - Time-series cross-validation
+//! - Combinatorial purged cross-validation (CPCV)
 //! - Train/test splitting with gap periods
 
 use chrono::{DateTime, Duration, Utc};
 use polars::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 use thiserror::Error;
 
 /// Errors that can occur during validation operations.
@@ -367,6 +369,25 @@ impl WalkForwardResult {
             && self.std_oos_sharpe < 0.5
     }
 
+    /// Walk-forward efficiency: out-of-sample return relative to in-sample.
+    ///
+    /// The ratio of summed OOS CAGR to summed IS CAGR across folds, so longer-
+    /// and shorter-window folds are compared on the same annualized footing.
+    /// Near 1.0 the in-sample edge persists out of sample; near 0 (or negative)
+    /// it was mostly overfit.
+    ///
+    /// Returns `f64::NAN` when the summed in-sample return is zero (including
+    /// when there are no folds): with no in-sample edge there is nothing for
+    /// the out-of-sample period to retain, so the ratio is undefined.
+    pub fn efficiency(&self) -> f64 {
+        let is_return: f64 = self.folds.iter().map(|f| f.is_cagr).sum();
+        if is_return.abs() < 1e-10 {
+            return f64::NAN;
+        }
+        let oos_return: f64 = self.folds.iter().map(|f| f.oos_cagr).sum();
+        oos_return / is_return
+    }
+
     /// Get a summary grade (A, B, C, D, F) based on walk-forward results.
     pub fn grade(&self) -> char {
         if self.passes_strict_test() && self.mean_oos_sharpe > 0.8 {
@@ -469,17 +490,36 @@ impl Default for TimeSeriesCVConfig {
     }
 }
 
-/// A single time-series cross-validation split.
+/// A single cross-validation split.
+///
+/// `split_idx` doubles as the `fold_idx` of the [`FoldResult`] evaluated on
+/// this split, so results aggregate with [`WalkForwardResult::from_folds`].
 #[derive(Debug, Clone)]
 pub struct CVSplit {
     /// Split index
     pub split_idx: usize,
-    /// Training indices
+    /// Training indices (bounding span of `train_ranges`)
     pub train_start: usize,
     pub train_end: usize,
-    /// Test indices
+    /// Test indices (bounding span of `test_ranges`)
     pub test_start: usize,
     pub test_end: usize,
+    /// Training ranges (a single range except for CPCV splits)
+    pub train_ranges: Vec<Range<usize>>,
+    /// Test ranges (one per test group)
+    pub test_ranges: Vec<Range<usize>>,
+}
+
+impl CVSplit {
+    /// All training bar indices, in order.
+    pub fn train_indices(&self) -> Vec<usize> {
+        self.train_ranges.iter().cloned().flatten().collect()
+    }
+
+    /// All test bar indices, in order.
+    pub fn test_indices(&self) -> Vec<usize> {
+        self.test_ranges.iter().cloned().flatten().collect()
+    }
 }
 
 /// Generate time-series cross-validation splits.
@@ -526,12 +566,135 @@ pub fn generate_ts_cv_splits(
             train_end,
             test_start,
             test_end,
+            train_ranges: std::iter::once(train_start..train_end).collect(),
+            test_ranges: std::iter::once(test_start..test_end).collect(),
+        });
+    }
+
+    Ok(splits)
+}
+
+/// Generate combinatorial purged cross-validation (CPCV) splits.
+///
+/// The data is cut into `groups` contiguous groups and every combination of
+/// `test_groups` of them is used as the test set once, giving
+/// C(groups, test_groups) splits. Stitching the test groups back together
+/// yields C(groups - 1, test_groups - 1) full backtest paths (see
+/// [`cpcv_path_count`]).
+///
+/// Training bars within `embargo_pct * n` bars (rounded up) of a test group
+/// are removed: those before it are purged, since positions opened there
+/// would still be open during the test period, and those after it are
+/// embargoed to limit serial-correlation leakage.
+///
+/// # Arguments
+/// * `n` - Total number of data points
+/// * `groups` - Number of contiguous groups
+/// * `test_groups` - Number of groups in each test set
+/// * `embargo_pct` - Purge/embargo width as a fraction of `n`
+pub fn generate_cpcv(
+    n: usize,
+    groups: usize,
+    test_groups: usize,
+    embargo_pct: f64,
+) -> Result<Vec<CVSplit>, ValidationError> {
+    if groups < 2 {
+        return Err(ValidationError::InvalidConfig(
+            "groups must be >= 2".to_string(),
+        ));
+    }
+    if test_groups == 0 || test_groups >= groups {
+        return Err(ValidationError::InvalidConfig(
+            "test_groups must be > 0 and < groups".to_string(),
+        ));
+    }
+    if !(0.0..1.0).contains(&embargo_pct) {
+        return Err(ValidationError::InvalidConfig(
+            "embargo_pct must be in [0, 1)".to_string(),
+        ));
+    }
+    if n < groups {
+        return Err(ValidationError::InsufficientData {
+            needed: groups,
+            available: n,
+        });
+    }
+
+    let bounds: Vec<Range<usize>> = (0..groups)
+        .map(|g| (g * n / groups)..((g + 1) * n / groups))
+        .collect();
+    let embargo = (n as f64 * embargo_pct).ceil() as usize;
+
+    let mut splits = Vec::new();
+    for (split_idx, combo) in combinations(groups, test_groups).into_iter().enumerate() {
+        let test_ranges: Vec<Range<usize>> = combo.iter().map(|&g| bounds[g].clone()).collect();
+
+        let excluded = |i: usize| {
+            test_ranges
+                .iter()
+                .any(|r| i + embargo >= r.start && i < r.end + embargo)
+        };
+
+        let mut train_ranges: Vec<Range<usize>> = Vec::new();
+        for i in (0..n).filter(|&i| !excluded(i)) {
+            match train_ranges.last_mut() {
+                Some(r) if r.end == i => r.end = i + 1,
+                _ => train_ranges.push(i..i + 1),
+            }
+        }
+
+        let (train_start, train_end) = match (train_ranges.first(), train_ranges.last()) {
+            (Some(first), Some(last)) => (first.start, last.end),
+            _ => (0, 0),
+        };
+
+        splits.push(CVSplit {
+            split_idx,
+            train_start,
+            train_end,
+            test_start: test_ranges[0].start,
+            test_end: test_ranges[test_ranges.len() - 1].end,
+            train_ranges,
+            test_ranges,
         });
     }
 
     Ok(splits)
 }
 
+/// Number of backtest paths produced by CPCV: C(groups - 1, test_groups - 1).
+pub fn cpcv_path_count(groups: usize, test_groups: usize) -> usize {
+    if test_groups == 0 || test_groups > groups {
+        return 0;
+    }
+    combinations(groups - 1, test_groups - 1).len()
+}
+
+/// All k-element combinations of `0..n`, in lexicographic order.
+fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    let mut combo: Vec<usize> = (0..k).collect();
+    if k > n {
+        return result;
+    }
+
+    loop {
+        result.push(combo.clone());
+
+        // Find the rightmost element that can still be incremented
+        let Some(i) = (0..k).rev().find(|&i| combo[i] < n - k + i) else {
+            break;
+        };
+        combo[i] += 1;
+        let start = combo[i];
+        for (offset, slot) in combo[i + 1..].iter_mut().enumerate() {
+            *slot = start + offset + 1;
+        }
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -633,6 +796,42 @@ mod tests {
         assert!((result.mean_oos_sharpe - 0.7).abs() < 0.01);
         assert!((result.pct_profitable_folds - 1.0).abs() < 0.01);
         assert_eq!(result.total_oos_trades, 22);
+        // (0.10 + 0.08) / (0.15 + 0.12)
+        assert!((result.efficiency() - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_walk_forward_efficiency() {
+        let fold = |fold_idx, is_cagr, oos_cagr| FoldResult {
+            fold_idx,
+            best_is_config: "c1".to_string(),
+            is_sharpe: 1.0,
+            oos_sharpe: 1.0,
+            is_cagr,
+            oos_cagr,
+            is_max_drawdown: 0.10,
+            oos_max_drawdown: 0.10,
+            oos_trades: 10,
+        };
+
+        // OOS matches IS in every fold
+        let folds = vec![
+            fold(0, 0.12, 0.12),
+            fold(1, -0.04, -0.04),
+            fold(2, 0.20, 0.20),
+        ];
+        let result = WalkForwardResult::from_folds(folds, WalkForwardConfig::default());
+        assert!((result.efficiency() - 1.0).abs() < 1e-9);
+
+        // No in-sample return: undefined
+        let folds = vec![fold(0, 0.05, 0.02), fold(1, -0.05, 0.01)];
+        let result = WalkForwardResult::from_folds(folds, WalkForwardConfig::default());
+        assert!(result.efficiency().is_nan());
+        assert!(
+            WalkForwardResult::from_folds(vec![], WalkForwardConfig::default())
+                .efficiency()
+                .is_nan()
+        );
     }
 
     #[test]
@@ -714,6 +913,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cpcv_splits_purge_and_embargo() {
+        // 60 bars in 6 groups of 10, 5% embargo = 3 bars
+        let splits = generate_cpcv(60, 6, 2, 0.05).unwrap();
+        assert_eq!(splits.len(), 15); // C(6, 2)
+        assert_eq!(cpcv_path_count(6, 2), 5);
+
+        // Test groups 1 and 3
+        let split = splits
+            .iter()
+            .find(|s| s.test_ranges == vec![10..20, 30..40])
+            .unwrap();
+        assert_eq!(split.train_ranges, vec![0..7, 23..27, 43..60]);
+
+        let train = split.train_indices();
+        for purged in [7, 8, 9, 27, 28, 29] {
+            assert!(!train.contains(&purged));
+        }
+        for embargoed in [20, 21, 22, 40, 41, 42] {
+            assert!(!train.contains(&embargoed));
+        }
+        assert_eq!(train.len() + split.test_indices().len() + 12, 60);
+
+        // Every bar is tested in exactly test_groups / groups of the splits
+        let tested = splits
+            .iter()
+            .filter(|s| s.test_indices().contains(&0))
+            .count();
+        assert_eq!(tested, 5);
+
+        assert!(generate_cpcv(60, 6, 6, 0.0).is_err());
+        assert!(generate_cpcv(4, 6, 2, 0.0).is_err());
+    }
+
     #[test]
     fn test_slice_by_index() {
         let df = DataFrame::new(vec![Series::new("x".into(), vec![1, 2, 3, 4, 5]).into()]).unwrap();
@@ -1,5 +1,7 @@
 //! Chart panel state and related types.
 
+use std::collections::BTreeMap;
+use std::path::Path;
 use std::sync::Mutex;
 
 use chrono::{DateTime, Utc};
@@ -187,6 +189,10 @@ pub struct ChartState {
     pub animation: AnimationState,
     /// Crosshair visibility
     pub show_crosshair: bool,
+    /// Accumulation/Distribution line overlay (GUI candlestick view)
+    pub show_ad_line: bool,
+    /// Williams A/D overlay (GUI candlestick view)
+    pub show_williams_ad: bool,
     /// Last chart rendering area (for hit-testing)
     /// Using Mutex for thread-safety in GUI context
     pub chart_area: Mutex<Option<ChartRect>>,
@@ -292,6 +298,119 @@ impl ChartState {
         self.candle_symbol = Some(symbol.to_string());
     }
 
+    /// Write the data behind the current view mode to a CSV file.
+    ///
+    /// Line views write a `date` column plus one value column per curve,
+    /// aligned on the union of dates (blank where a curve has no point). The
+    /// single view adds a `drawdown` column when drawdown is shown. The
+    /// candlestick view writes `date,open,high,low,close,volume`.
+    ///
+    /// Returns the number of data rows written.
+    pub fn export_current_view(&self, path: &Path) -> std::io::Result<usize> {
+        let (header, rows) = self.current_view_table();
+
+        let mut csv = header
+            .iter()
+            .map(|h| csv_field(h))
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push('\n');
+        for row in &rows {
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, csv)?;
+        Ok(rows.len())
+    }
+
+    /// Header and rows for the current view mode (see `export_current_view`).
+    fn current_view_table(&self) -> CsvTable {
+        match self.view_mode {
+            ChartViewMode::Candlestick => {
+                let header = ["date", "open", "high", "low", "close", "volume"]
+                    .iter()
+                    .map(|h| h.to_string())
+                    .collect();
+                let rows = self
+                    .candle_data
+                    .iter()
+                    .map(|c| {
+                        vec![
+                            c.date.clone(),
+                            c.open.to_string(),
+                            c.high.to_string(),
+                            c.low.to_string(),
+                            c.close.to_string(),
+                            c.volume.to_string(),
+                        ]
+                    })
+                    .collect();
+                (header, rows)
+            }
+            ChartViewMode::Single => {
+                let mut curves = vec![(
+                    "equity".to_string(),
+                    self.equity_dates.as_slice(),
+                    self.equity_curve.as_slice(),
+                )];
+                if self.show_drawdown {
+                    curves.push((
+                        "drawdown".to_string(),
+                        self.equity_dates.as_slice(),
+                        self.drawdown_curve.as_slice(),
+                    ));
+                }
+                aligned_table(&curves)
+            }
+            ChartViewMode::MultiTicker => aligned_table(
+                &self
+                    .ticker_curves
+                    .iter()
+                    .map(|c| (c.symbol.clone(), c.dates.as_slice(), c.equity.as_slice()))
+                    .collect::<Vec<_>>(),
+            ),
+            ChartViewMode::Portfolio => {
+                // The aggregate curve carries no dates; borrow them from a
+                // ticker curve of the same length.
+                let dates = self
+                    .ticker_curves
+                    .iter()
+                    .find(|c| c.dates.len() == self.portfolio_curve.len())
+                    .map(|c| c.dates.as_slice())
+                    .unwrap_or(&[]);
+                aligned_table(&[(
+                    "portfolio".to_string(),
+                    dates,
+                    self.portfolio_curve.as_slice(),
+                )])
+            }
+            ChartViewMode::StrategyComparison => aligned_table(
+                &self
+                    .strategy_curves
+                    .iter()
+                    .map(|c| {
+                        (
+                            format!("{} {}", c.strategy_type.name(), c.config_display),
+                            c.dates.as_slice(),
+                            c.equity.as_slice(),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            ChartViewMode::PerTickerBestStrategy => aligned_table(
+                &self
+                    .ticker_best_strategies
+                    .iter()
+                    .map(|c| (c.symbol.clone(), c.dates.as_slice(), c.equity.as_slice()))
+                    .collect::<Vec<_>>(),
+            ),
+        }
+    }
+
     /// Animated zoom in
     pub fn zoom_in_animated(&mut self) {
         self.animation.target_zoom = (self.animation.target_zoom * 1.2).min(4.0);
@@ -304,3 +423,83 @@ impl ChartState {
         self.animation.animating = true;
     }
 }
+
+/// A CSV header row plus its data rows.
+type CsvTable = (Vec<String>, Vec<Vec<String>>);
+
+/// A named equity curve: (column name, dates, values).
+type NamedCurve<'a> = (String, &'a [DateTime<Utc>], &'a [f64]);
+
+/// Align named (dates, values) curves into a date-indexed table.
+fn aligned_table(curves: &[NamedCurve]) -> CsvTable {
+    let mut header = vec!["date".to_string()];
+    let mut by_date: BTreeMap<DateTime<Utc>, Vec<String>> = BTreeMap::new();
+
+    for (col, (name, dates, values)) in curves.iter().enumerate() {
+        header.push(name.clone());
+        for (ts, value) in dates.iter().zip(values.iter()) {
+            by_date
+                .entry(*ts)
+                .or_insert_with(|| vec![String::new(); curves.len()])[col] = value.to_string();
+        }
+    }
+
+    let rows = by_date
+        .into_iter()
+        .map(|(ts, values)| {
+            let mut row = vec![ts.format("%Y-%m-%d").to_string()];
+            row.extend(values);
+            row
+        })
+        .collect();
+    (header, rows)
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_export_single_equity_curve() {
+        let dates: Vec<DateTime<Utc>> = (0..5)
+            .map(|i| Utc.with_ymd_and_hms(2024, 1, 1 + i, 0, 0, 0).unwrap())
+            .collect();
+        let chart = ChartState {
+            equity_curve: vec![100.0, 101.0, 99.5, 102.0, 103.0],
+            drawdown_curve: vec![0.0, 0.0, -1.5, 0.0, 0.0],
+            equity_dates: dates,
+            show_drawdown: true,
+            ..Default::default()
+        };
+
+        let path = std::env::temp_dir().join(format!(
+            "trendlab_chart_export_test_{}.csv",
+            std::process::id()
+        ));
+        let rows = chart.export_current_view(&path).unwrap();
+        let csv = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(rows, 5);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0], "date,equity,drawdown");
+        assert_eq!(lines[3], "2024-01-03,99.5,-1.5");
+    }
+
+    #[test]
+    fn test_csv_field_quotes_delimiters() {
+        assert_eq!(csv_field("SPY"), "SPY");
+        assert_eq!(csv_field("Donchian 20,10"), "\"Donchian 20,10\"");
+    }
+}
@@ -2,7 +2,9 @@
 
 use std::collections::{HashMap, HashSet};
 
-use trendlab_core::{Bar, Sector, Universe};
+use chrono::{DateTime, Utc};
+use polars::prelude::PolarsResult;
+use trendlab_core::{indicator_series, Bar, IndicatorSpec, Sector, Universe};
 
 /// Search suggestion from Yahoo.
 #[derive(Debug, Clone)]
@@ -23,6 +25,15 @@ pub enum DataViewMode {
     Tickers,
 }
 
+/// Indicator values aligned to a symbol's cached bars, for chart overlays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndicatorOverlay {
+    /// Bar timestamps, one per cached bar
+    pub timestamps: Vec<DateTime<Utc>>,
+    /// Indicator columns (e.g. `dc_upper`, `dc_lower`), one value per bar
+    pub series: Vec<(String, Vec<Option<f64>>)>,
+}
+
 /// Data panel state
 #[derive(Debug)]
 pub struct DataState {
@@ -83,6 +94,24 @@ impl DataState {
         self.selected_symbol().and_then(|s| self.bars_cache.get(s))
     }
 
+    /// Compute an indicator over the cached bars for `symbol`.
+    ///
+    /// Returns `Ok(None)` if no bars are cached for the symbol.
+    pub fn indicator_overlay(
+        &self,
+        symbol: &str,
+        spec: &IndicatorSpec,
+    ) -> PolarsResult<Option<IndicatorOverlay>> {
+        let Some(bars) = self.bars_cache.get(symbol) else {
+            return Ok(None);
+        };
+
+        Ok(Some(IndicatorOverlay {
+            timestamps: bars.iter().map(|b| b.ts).collect(),
+            series: indicator_series(bars, spec)?,
+        }))
+    }
+
     /// Get the currently selected sector.
     pub fn selected_sector(&self) -> Option<&Sector> {
         self.universe
@@ -234,3 +263,34 @@ impl DataState {
         self.ticker_scroll_offset = 0;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sine_wave_bars;
+
+    #[test]
+    fn test_donchian_overlay_aligns_with_bars() {
+        let bars = sine_wave_bars(40, 0.3, 10.0, 0.0, "SPY");
+        let mut data = DataState::default();
+        data.bars_cache.insert("SPY".to_string(), bars.clone());
+
+        let spec = IndicatorSpec::Donchian { lookback: 10 };
+        let overlay = data.indicator_overlay("SPY", &spec).unwrap().unwrap();
+
+        assert_eq!(overlay.timestamps.len(), bars.len());
+        let names: Vec<&str> = overlay.series.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["dc_upper", "dc_lower"]);
+        for (_, values) in &overlay.series {
+            assert_eq!(values.len(), bars.len());
+            // Null through warmup, populated after
+            assert!(values[..10].iter().all(Option::is_none));
+            assert!(values[10..].iter().all(Option::is_some));
+        }
+        let upper = overlay.series[0].1[10].unwrap();
+        let expected = bars[..10].iter().map(|b| b.high).fold(f64::MIN, f64::max);
+        assert!((upper - expected).abs() < 1e-9);
+
+        assert!(data.indicator_overlay("QQQ", &spec).unwrap().is_none());
+    }
+}
@@ -7,6 +7,7 @@
 //! - `data` - Data panel state and related types
 //! - `sweep` - Sweep panel state
 //! - `results` - Results panel state and view modes
+//! - `session` - Saving and restoring configurable state across launches
 //! - `yolo` - YOLO mode continuous auto-optimization state
 //! - `chart_state` - Chart panel state and visualization types
 //! - `utils` - Utility functions
@@ -21,6 +22,7 @@ pub mod data;
 pub mod navigation;
 pub mod randomization;
 pub mod results;
+pub mod session;
 pub mod strategies;
 pub mod sweep;
 pub mod utils;
@@ -31,7 +33,7 @@ pub use chart_state::{
     AnimationState, CandleData, ChartRect, ChartState, ChartViewMode, CursorState, StrategyCurve,
     TickerBestStrategy, TickerCurve, WinningConfig,
 };
-pub use data::{DataState, DataViewMode, SearchSuggestion};
+pub use data::{DataState, DataViewMode, IndicatorOverlay, SearchSuggestion};
 pub use navigation::{
     AutoRunState, AutoStage, HelpSection, HelpState, MessageType, OperationState, Panel,
     StartupMode, StartupState, StrategySelection,
@@ -42,6 +44,7 @@ pub use randomization::{
     RandomDefaults,
 };
 pub use results::{ResultsState, ResultsViewMode, TickerSummary};
+pub use session::SessionState;
 pub use strategies::{
     DonchianConfig, EnsembleConfig, KeltnerConfig, MACrossoverConfig, OpeningRangeConfig,
     ParabolicSarConfig, STARCConfig, StrategyCategory, StrategyFocus, StrategyState, StrategyType,
@@ -58,8 +61,8 @@ use chrono::{NaiveDate, Utc};
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 use trendlab_core::{
-    BacktestConfig, Bar, CostModel, CrossSymbolLeaderboard, FillModel, Leaderboard,
-    LeaderboardScope, MultiStrategyGrid, PyramidConfig, SweepDepth,
+    AppConfig, BacktestConfig, Bar, CrossSymbolLeaderboard, Leaderboard, LeaderboardScope,
+    MultiStrategyGrid, SweepDepth, YoloCheckpoint,
 };
 
 use crate::worker::{WorkerChannels, WorkerCommand};
@@ -84,6 +87,8 @@ pub struct App {
     pub fetch_range: (NaiveDate, NaiveDate),
     /// Canonical default (un-jittered) date range used for reference/reset.
     pub fetch_range_default: (NaiveDate, NaiveDate),
+    /// Backtest settings for sweeps, from `trendlab.toml` and the environment.
+    pub backtest_defaults: BacktestConfig,
 }
 
 impl App {
@@ -114,18 +119,6 @@ impl App {
     }
 
     pub fn new() -> Self {
-        // Scan for existing symbols on startup
-        let symbols = scan_parquet_directory();
-
-        // Load universe from config (or use default)
-        let mut data_state = DataState {
-            symbols,
-            ..Default::default()
-        };
-        data_state.load_universe_from_config();
-        // Auto-select all tickers by default for YOLO mode
-        data_state.select_all();
-
         // Randomize initial UI defaults.
         // Default: enabled (can be disabled).
         //
@@ -139,6 +132,24 @@ impl App {
         } else {
             0
         };
+        Self::with_random_defaults(random_enabled, seed)
+    }
+
+    /// Build the app with an explicit randomization seed, bypassing the
+    /// environment and the on-disk launch counter.
+    fn with_random_defaults(random_enabled: bool, seed: u64) -> Self {
+        // Scan for existing symbols on startup
+        let symbols = scan_parquet_directory();
+
+        // Load universe from config (or use default)
+        let mut data_state = DataState {
+            symbols,
+            ..Default::default()
+        };
+        data_state.load_universe_from_config();
+        // Auto-select all tickers by default for YOLO mode
+        data_state.select_all();
+
         let mut rng = StdRng::seed_from_u64(seed);
 
         let mut strategy_state = StrategyState::default();
@@ -483,6 +494,8 @@ impl App {
                     // Start viewing session by default
                     view_scope: LeaderboardScope::Session,
                     session_id: trendlab_core::generate_session_id(),
+                    // Offer to resume a session that crashed mid-run
+                    resume_from: YoloCheckpoint::resumable(&artifacts),
                     ..Default::default()
                 }
             },
@@ -492,6 +505,7 @@ impl App {
             },
             fetch_range,
             fetch_range_default: (fetch_default_start, fetch_default_end),
+            backtest_defaults: AppConfig::load().backtest_config(),
         }
     }
 
@@ -993,26 +1007,20 @@ impl App {
 
     pub fn handle_left(&mut self) {
         match self.active_panel {
-            Panel::Data => {
-                // Left arrow goes back from tickers to sectors
-                if self.data.view_mode == DataViewMode::Tickers {
-                    self.data.view_mode = DataViewMode::Sectors;
-                    self.status_message = "Sector view".to_string();
-                }
+            // Left arrow goes back from tickers to sectors
+            Panel::Data if self.data.view_mode == DataViewMode::Tickers => {
+                self.data.view_mode = DataViewMode::Sectors;
+                self.status_message = "Sector view".to_string();
             }
             Panel::Strategy => {
                 self.adjust_strategy_param(-1);
             }
-            Panel::Chart => {
-                if self.chart.scroll_offset > 0 {
-                    self.chart.scroll_offset -= 1;
-                }
+            Panel::Chart if self.chart.scroll_offset > 0 => {
+                self.chart.scroll_offset -= 1;
             }
-            Panel::Results => {
-                // Collapse leaderboard row in Leaderboard view
-                if self.results.view_mode == ResultsViewMode::Leaderboard {
-                    self.collapse_leaderboard_row();
-                }
+            // Collapse leaderboard row in Leaderboard view
+            Panel::Results if self.results.view_mode == ResultsViewMode::Leaderboard => {
+                self.collapse_leaderboard_row();
             }
             Panel::Help => {
                 // Previous section
@@ -1025,15 +1033,13 @@ impl App {
 
     pub fn handle_right(&mut self) {
         match self.active_panel {
-            Panel::Data => {
-                // Right arrow goes into ticker view for selected sector
-                if self.data.view_mode == DataViewMode::Sectors {
-                    self.data.view_mode = DataViewMode::Tickers;
-                    self.data.selected_ticker_index = 0;
-                    self.data.ticker_scroll_offset = 0; // Reset scroll when entering sector
-                    if let Some(sector) = self.data.selected_sector() {
-                        self.status_message = format!("{} tickers", sector.name);
-                    }
+            // Right arrow goes into ticker view for selected sector
+            Panel::Data if self.data.view_mode == DataViewMode::Sectors => {
+                self.data.view_mode = DataViewMode::Tickers;
+                self.data.selected_ticker_index = 0;
+                self.data.ticker_scroll_offset = 0; // Reset scroll when entering sector
+                if let Some(sector) = self.data.selected_sector() {
+                    self.status_message = format!("{} tickers", sector.name);
                 }
             }
             Panel::Strategy => {
@@ -1042,11 +1048,9 @@ impl App {
             Panel::Chart => {
                 self.chart.scroll_offset += 1;
             }
-            Panel::Results => {
-                // Expand leaderboard row in Leaderboard view
-                if self.results.view_mode == ResultsViewMode::Leaderboard {
-                    self.expand_leaderboard_row();
-                }
+            // Expand leaderboard row in Leaderboard view
+            Panel::Results if self.results.view_mode == ResultsViewMode::Leaderboard => {
+                self.expand_leaderboard_row();
             }
             Panel::Help => {
                 // Next section
@@ -1287,41 +1291,37 @@ impl App {
                     self.load_bars_for_symbol(&symbol);
                 }
             }
-            Panel::Sweep => {
-                if !self.sweep.is_running {
-                    // If user selected multiple tickers, run multi-sweep; otherwise run single.
-                    if self.data.selected_tickers.len() >= 2 {
-                        self.start_multi_sweep(channels);
-                    } else {
-                        self.start_single_sweep(channels);
-                    }
+            Panel::Sweep if !self.sweep.is_running => {
+                // If user selected multiple tickers, run multi-sweep; otherwise run single.
+                if self.data.selected_tickers.len() >= 2 {
+                    self.start_multi_sweep(channels);
+                } else {
+                    self.start_single_sweep(channels);
                 }
             }
-            Panel::Results => {
-                if !self.results.results.is_empty() {
-                    self.chart.selected_result_index = Some(self.results.selected_index);
-                    if let Some(result) = self.results.results.get(self.results.selected_index) {
-                        // Extract equity curve from backtest result
-                        self.chart.equity_curve = result
-                            .backtest_result
-                            .equity
-                            .iter()
-                            .map(|p| p.equity)
-                            .collect();
-                        // Extract dates from backtest result
-                        self.chart.equity_dates =
-                            result.backtest_result.equity.iter().map(|p| p.ts).collect();
-                        // Calculate drawdown curve
-                        self.chart.drawdown_curve = calculate_drawdown(&self.chart.equity_curve);
-                        // Set winning config for Pine export display
-                        self.chart.winning_config = Some(WinningConfig {
-                            strategy_name: self.strategy.selected_type.name().to_string(),
-                            config_display: self.strategy.config_display_string(),
-                            symbol: self.data.selected_symbol().cloned(),
-                        });
-                    }
-                    self.active_panel = Panel::Chart;
+            Panel::Results if !self.results.results.is_empty() => {
+                self.chart.selected_result_index = Some(self.results.selected_index);
+                if let Some(result) = self.results.results.get(self.results.selected_index) {
+                    // Extract equity curve from backtest result
+                    self.chart.equity_curve = result
+                        .backtest_result
+                        .equity
+                        .iter()
+                        .map(|p| p.equity)
+                        .collect();
+                    // Extract dates from backtest result
+                    self.chart.equity_dates =
+                        result.backtest_result.equity.iter().map(|p| p.ts).collect();
+                    // Calculate drawdown curve
+                    self.chart.drawdown_curve = calculate_drawdown(&self.chart.equity_curve);
+                    // Set winning config for Pine export display
+                    self.chart.winning_config = Some(WinningConfig {
+                        strategy_name: self.strategy.selected_type.name().to_string(),
+                        config_display: self.strategy.config_display_string(),
+                        symbol: self.data.selected_symbol().cloned(),
+                    });
                 }
+                self.active_panel = Panel::Chart;
             }
             _ => {}
         }
@@ -1329,11 +1329,9 @@ impl App {
 
     pub fn handle_escape(&mut self) {
         match self.active_panel {
-            Panel::Sweep => {
-                if self.sweep.is_running {
-                    self.sweep.is_running = false;
-                    self.status_message = "Sweep cancelled.".to_string();
-                }
+            Panel::Sweep if self.sweep.is_running => {
+                self.sweep.is_running = false;
+                self.status_message = "Sweep cancelled.".to_string();
             }
             Panel::Chart => {
                 self.chart.zoom_level = 1.0;
@@ -1440,7 +1438,7 @@ impl App {
                 // Trades (descending)
                 self.results
                     .results
-                    .sort_by(|a, b| b.metrics.num_trades.cmp(&a.metrics.num_trades));
+                    .sort_by_key(|r| std::cmp::Reverse(r.metrics.num_trades));
             }
             _ => {}
         }
@@ -1506,6 +1504,34 @@ impl App {
         };
     }
 
+    /// Handle 'x' key in Chart panel to export the current view to CSV.
+    ///
+    /// Writes `exports/chart_<mode>_<timestamp>.csv` under the artifacts directory.
+    pub fn handle_chart_export(&mut self) {
+        if self.active_panel != Panel::Chart {
+            return;
+        }
+
+        let mode = self
+            .chart
+            .view_mode_name()
+            .to_lowercase()
+            .replace([' ', '-'], "_");
+        let path = trendlab_core::artifacts_dir().join("exports").join(format!(
+            "chart_{}_{}.csv",
+            mode,
+            Utc::now().format("%Y%m%d_%H%M%S")
+        ));
+
+        match self.chart.export_current_view(&path) {
+            Ok(0) => self.set_status_warning("Nothing to export in this chart view"),
+            Ok(rows) => {
+                self.set_status_success(format!("Exported {} rows to {}", rows, path.display()))
+            }
+            Err(e) => self.set_status_error(format!("Chart export failed: {}", e)),
+        }
+    }
+
     /// Handle 'c' key in Chart panel to toggle crosshair
     pub fn handle_toggle_crosshair(&mut self) {
         if self.active_panel != Panel::Chart {
@@ -1916,16 +1942,7 @@ impl App {
                     return;
                 }
             };
-            let backtest_config = BacktestConfig {
-                initial_cash: 100_000.0,
-                fill_model: FillModel::NextOpen,
-                cost_model: CostModel {
-                    fees_bps_per_side: 10.0,
-                    slippage_bps: 5.0,
-                },
-                qty: 100.0,
-                pyramid_config: PyramidConfig::default(),
-            };
+            let backtest_config = self.backtest_defaults;
 
             // Send sweep command to worker
             // Use Polars by default for Donchian sweeps (vectorized, faster)
@@ -1986,16 +2003,7 @@ impl App {
                 return;
             }
         };
-        let backtest_config = BacktestConfig {
-            initial_cash: 100_000.0,
-            fill_model: FillModel::NextOpen,
-            cost_model: CostModel {
-                fees_bps_per_side: 10.0,
-                slippage_bps: 5.0,
-            },
-            qty: 100.0,
-            pyramid_config: PyramidConfig::default(),
-        };
+        let backtest_config = self.backtest_defaults;
 
         let cmd = WorkerCommand::StartMultiSweep {
             symbol_bars,
@@ -2045,16 +2053,7 @@ impl App {
 
         // Use selected sweep depth from startup modal
         let strategy_grid = MultiStrategyGrid::with_depth(self.startup.sweep_depth);
-        let backtest_config = BacktestConfig {
-            initial_cash: 100_000.0,
-            fill_model: FillModel::NextOpen,
-            cost_model: CostModel {
-                fees_bps_per_side: 10.0,
-                slippage_bps: 5.0,
-            },
-            qty: 100.0,
-            pyramid_config: PyramidConfig::default(),
-        };
+        let backtest_config = self.backtest_defaults;
 
         let cmd = WorkerCommand::StartMultiStrategySweep {
             symbol_bars,
@@ -2174,16 +2173,7 @@ impl App {
         // Build strategy grid (use Quick depth for faster iterations)
         let strategy_grid = MultiStrategyGrid::with_depth(SweepDepth::Quick);
 
-        let backtest_config = BacktestConfig {
-            initial_cash: 100_000.0,
-            fill_model: FillModel::NextOpen,
-            cost_model: CostModel {
-                fees_bps_per_side: 10.0,
-                slippage_bps: 5.0,
-            },
-            qty: 100.0,
-            pyramid_config: PyramidConfig::default(),
-        };
+        let backtest_config = self.backtest_defaults;
 
         // Use the already-loaded all-time leaderboards from app startup.
         // This ensures consistency: the worker gets the same data that was loaded
@@ -2216,6 +2206,16 @@ impl App {
             })
             .collect();
 
+        // Continue an interrupted session under its original ID
+        if let Some(checkpoint) = self.yolo.resume_from.take() {
+            tracing::info!(
+                session_id = %checkpoint.session_id,
+                iteration = checkpoint.session_iteration,
+                "YOLO start: resuming interrupted session"
+            );
+            self.yolo.session_id = checkpoint.session_id;
+        }
+
         let cmd = WorkerCommand::StartYoloMode {
             symbols: selected.clone(),
             symbol_sector_ids,
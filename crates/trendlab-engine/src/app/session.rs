@@ -0,0 +1,158 @@
+//! Session persistence: save and restore the user's configurable TUI state.
+
+use std::path::Path;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::strategies::{
+    DonchianConfig, EnsembleConfig, KeltnerConfig, MACrossoverConfig, OpeningRangeConfig,
+    ParabolicSarConfig, STARCConfig, StrategyType, SupertrendConfig, TsmomConfig,
+};
+use super::App;
+
+/// Snapshot of the configurable parts of [`App`].
+///
+/// Transient state (bar caches, progress, results, search input) is excluded,
+/// so a restored session starts idle with the same selections and parameters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionState {
+    /// Loaded symbols in the Data panel.
+    pub symbols: Vec<String>,
+    /// Tickers selected for multi-ticker sweeps (sorted).
+    pub selected_tickers: Vec<String>,
+    /// Strategies checked in the Strategy panel, in display order.
+    pub selected_strategies: Vec<StrategyType>,
+    pub selected_type: StrategyType,
+    pub ensemble: EnsembleConfig,
+    pub donchian_config: DonchianConfig,
+    pub ma_config: MACrossoverConfig,
+    pub tsmom_config: TsmomConfig,
+    pub keltner_config: KeltnerConfig,
+    pub starc_config: STARCConfig,
+    pub supertrend_config: SupertrendConfig,
+    pub parabolic_sar_config: ParabolicSarConfig,
+    pub opening_range_config: OpeningRangeConfig,
+    /// Sweep grid parameter ranges.
+    pub sweep_param_ranges: Vec<(String, Vec<String>)>,
+    pub fetch_range: (NaiveDate, NaiveDate),
+}
+
+impl App {
+    /// Capture the configurable state as a [`SessionState`].
+    pub fn session_state(&self) -> SessionState {
+        let mut selected_tickers: Vec<String> =
+            self.data.selected_tickers.iter().cloned().collect();
+        selected_tickers.sort();
+
+        let selected_strategies = StrategyType::all()
+            .iter()
+            .copied()
+            .filter(|s| self.strategy.selected_strategies.contains(s))
+            .collect();
+
+        SessionState {
+            symbols: self.data.symbols.clone(),
+            selected_tickers,
+            selected_strategies,
+            selected_type: self.strategy.selected_type,
+            ensemble: self.strategy.ensemble.clone(),
+            donchian_config: self.strategy.donchian_config.clone(),
+            ma_config: self.strategy.ma_config.clone(),
+            tsmom_config: self.strategy.tsmom_config.clone(),
+            keltner_config: self.strategy.keltner_config.clone(),
+            starc_config: self.strategy.starc_config.clone(),
+            supertrend_config: self.strategy.supertrend_config.clone(),
+            parabolic_sar_config: self.strategy.parabolic_sar_config.clone(),
+            opening_range_config: self.strategy.opening_range_config.clone(),
+            sweep_param_ranges: self.sweep.param_ranges.clone(),
+            fetch_range: self.fetch_range,
+        }
+    }
+
+    /// Restore configurable state from a [`SessionState`].
+    pub fn apply_session_state(&mut self, session: SessionState) {
+        self.data.symbols = session.symbols;
+        self.data.selected_index = 0;
+        self.data.selected_tickers = session.selected_tickers.into_iter().collect();
+
+        self.strategy.selected_strategies = session.selected_strategies.into_iter().collect();
+        self.strategy.selected_type = session.selected_type;
+        self.strategy.selected_type_index = StrategyType::all()
+            .iter()
+            .position(|s| *s == session.selected_type)
+            .unwrap_or(0);
+        self.strategy.ensemble = session.ensemble;
+        self.strategy.donchian_config = session.donchian_config;
+        self.strategy.ma_config = session.ma_config;
+        self.strategy.tsmom_config = session.tsmom_config;
+        self.strategy.keltner_config = session.keltner_config;
+        self.strategy.starc_config = session.starc_config;
+        self.strategy.supertrend_config = session.supertrend_config;
+        self.strategy.parabolic_sar_config = session.parabolic_sar_config;
+        self.strategy.opening_range_config = session.opening_range_config;
+
+        self.sweep.param_ranges = session.sweep_param_ranges;
+        self.fetch_range = session.fetch_range;
+    }
+
+    /// Save the configurable state to `path` as JSON.
+    pub fn save_session(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&self.session_state())?;
+        std::fs::write(path, json)
+    }
+
+    /// Load configurable state previously written by [`App::save_session`].
+    ///
+    /// On error the app is left unchanged.
+    pub fn load_session(&mut self, path: &Path) -> std::io::Result<()> {
+        let json = std::fs::read_to_string(path)?;
+        let session: SessionState = serde_json::from_str(&json)?;
+        self.apply_session_state(session);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_round_trip() {
+        let mut app = App::with_random_defaults(true, 7);
+        app.data.symbols = vec!["SPY".to_string(), "QQQ".to_string()];
+        app.data.selected_tickers = ["AAPL", "MSFT"].iter().map(|s| s.to_string()).collect();
+        app.strategy.selected_strategies = [StrategyType::Tsmom, StrategyType::Keltner]
+            .into_iter()
+            .collect();
+        app.strategy.donchian_config.entry_lookback = 55;
+        app.strategy.keltner_config.multiplier = 2.5;
+        app.sweep.param_ranges = vec![("entry_lookback".to_string(), vec!["15".to_string()])];
+        app.fetch_range = (
+            NaiveDate::from_ymd_opt(2015, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2020, 6, 30).unwrap(),
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.json");
+        app.save_session(&path).unwrap();
+
+        let mut restored = App::with_random_defaults(true, 7);
+        restored.load_session(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(restored.session_state(), app.session_state());
+        assert_eq!(restored.data.symbols, vec!["SPY", "QQQ"]);
+        assert!(restored.data.is_ticker_selected("MSFT"));
+        assert_eq!(restored.strategy.donchian_config.entry_lookback, 55);
+        assert!(restored.data.bars_cache.is_empty());
+
+        // A missing file leaves the app untouched
+        let before = restored.session_state();
+        assert!(restored.load_session(&path).is_err());
+        assert_eq!(restored.session_state(), before);
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::collections::HashSet;
 
+use serde::{Deserialize, Serialize};
+
 /// Strategy category for grouped checkboxes in the TUI.
 /// Each category groups related strategy types for easier navigation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -70,7 +72,7 @@ impl StrategyCategory {
 }
 
 /// Voting method for ensemble strategies.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum VotingMethod {
     /// Simple majority: > 50% agreement takes that signal
     #[default]
@@ -102,7 +104,7 @@ impl VotingMethod {
 }
 
 /// Ensemble configuration for multi-horizon voting.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnsembleConfig {
     /// Whether ensemble mode is enabled
     pub enabled: bool,
@@ -126,7 +128,7 @@ impl Default for EnsembleConfig {
 }
 
 /// Strategy type selection
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum StrategyType {
     #[default]
     Donchian,
@@ -222,7 +224,7 @@ impl StrategyType {
 }
 
 /// Donchian strategy configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DonchianConfig {
     pub entry_lookback: usize,
     pub exit_lookback: usize,
@@ -238,7 +240,7 @@ impl Default for DonchianConfig {
 }
 
 /// MA Crossover strategy configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MACrossoverConfig {
     pub fast_period: usize,
     pub slow_period: usize,
@@ -272,7 +274,7 @@ impl MACrossoverConfig {
 }
 
 /// TSMOM strategy configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TsmomConfig {
     pub lookback: usize,
 }
@@ -284,7 +286,7 @@ impl Default for TsmomConfig {
 }
 
 /// Keltner Channel strategy configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeltnerConfig {
     pub ema_period: usize,
     pub atr_period: usize,
@@ -302,7 +304,7 @@ impl Default for KeltnerConfig {
 }
 
 /// STARC Bands strategy configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct STARCConfig {
     pub sma_period: usize,
     pub atr_period: usize,
@@ -320,7 +322,7 @@ impl Default for STARCConfig {
 }
 
 /// Supertrend strategy configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SupertrendConfig {
     pub atr_period: usize,
     pub multiplier: f64,
@@ -337,7 +339,7 @@ impl Default for SupertrendConfig {
 }
 
 /// Parabolic SAR strategy configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParabolicSarConfig {
     pub af_start: f64,
     pub af_step: f64,
@@ -355,7 +357,7 @@ impl Default for ParabolicSarConfig {
 }
 
 /// Opening Range Breakout strategy configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OpeningRangeConfig {
     pub range_bars: usize,
     pub period: usize, // 0 = Weekly, 1 = Monthly, 2 = Rolling
@@ -1,12 +1,16 @@
 //! YOLO Mode state - continuous auto-optimization.
 
+use std::collections::HashMap;
+
 use chrono::{DateTime, NaiveDate, Utc};
 use tracing::debug;
 use trendlab_core::{
-    generate_session_id, AggregatedConfigResult, CrossSymbolLeaderboard, Leaderboard,
-    LeaderboardScope, RiskProfile, SweepDepth,
+    generate_session_id, AggregatedConfigResult, CrossSymbolLeaderboard, CrossSymbolRankMetric,
+    Leaderboard, LeaderboardScope, RiskProfile, SweepDepth, YoloCheckpoint,
 };
 
+use crate::worker::YoloRejectReason;
+
 /// Combo strategy mode for YOLO iterations
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ComboMode {
@@ -73,12 +77,21 @@ pub struct YoloState {
     /// All-time cross-symbol aggregated leaderboard
     pub all_time_cross_symbol_leaderboard: Option<CrossSymbolLeaderboard>,
 
+    // Walk-forward leaderboards (this session, OOS-validated configs only)
+    /// Per-symbol entries that passed walk-forward validation
+    pub walk_forward_leaderboard: Leaderboard,
+    /// Cross-symbol configs whose mean OOS Sharpe reached `wf_sharpe_threshold`
+    pub walk_forward_cross_symbol_leaderboard: Option<CrossSymbolLeaderboard>,
+
     /// Which scope is currently being displayed (toggle with 't')
     pub view_scope: LeaderboardScope,
 
     /// Unique session ID for tracking which session discovered entries
     pub session_id: String,
 
+    /// Checkpoint of an interrupted session; the next YOLO start resumes it
+    pub resume_from: Option<YoloCheckpoint>,
+
     /// Risk profile for weighted ranking (cycle with 'p')
     pub risk_profile: RiskProfile,
 
@@ -96,6 +109,8 @@ pub struct YoloState {
     pub session_configs_tested: u64,
     /// Total configs tested all-time (loaded from all_time_leaderboard)
     pub total_configs_tested: u64,
+    /// Configs rejected by the walk-forward gate this session, by reason
+    pub rejection_counts: HashMap<YoloRejectReason, u64>,
     /// When YOLO mode was started this session
     pub started_at: Option<DateTime<Utc>>,
     /// Whether the YOLO config modal is shown
@@ -209,10 +224,13 @@ impl Default for YoloState {
             // All-time leaderboards (will be loaded from disk in App::new)
             all_time_leaderboard: Leaderboard::new(500), // Larger capacity for historical data
             all_time_cross_symbol_leaderboard: None,
+            walk_forward_leaderboard: Leaderboard::new(500),
+            walk_forward_cross_symbol_leaderboard: None,
             // Default to showing session results
             view_scope: LeaderboardScope::Session,
             // Generate unique session ID
             session_id: generate_session_id(),
+            resume_from: None,
             // Default risk profile for weighted ranking
             risk_profile: RiskProfile::default(),
             // Default exploration strength for YOLO mode. Kept moderate so it explores meaningfully
@@ -225,6 +243,7 @@ impl Default for YoloState {
             warmup_iterations: 50,
             session_configs_tested: 0,
             total_configs_tested: 0,
+            rejection_counts: HashMap::new(),
             started_at: None,
             show_config: false,
             config: YoloConfigState::default(),
@@ -238,6 +257,7 @@ impl YoloState {
         match self.view_scope {
             LeaderboardScope::Session => &self.session_leaderboard,
             LeaderboardScope::AllTime => &self.all_time_leaderboard,
+            LeaderboardScope::WalkForward => &self.walk_forward_leaderboard,
         }
     }
 
@@ -246,18 +266,59 @@ impl YoloState {
         match self.view_scope {
             LeaderboardScope::Session => self.session_cross_symbol_leaderboard.as_ref(),
             LeaderboardScope::AllTime => self.all_time_cross_symbol_leaderboard.as_ref(),
+            LeaderboardScope::WalkForward => self.walk_forward_cross_symbol_leaderboard.as_ref(),
         }
     }
 
     /// Get configs tested count for the current view scope.
     pub fn configs_tested(&self) -> u64 {
         match self.view_scope {
-            LeaderboardScope::Session => self.session_configs_tested,
+            LeaderboardScope::Session | LeaderboardScope::WalkForward => {
+                self.session_configs_tested
+            }
             LeaderboardScope::AllTime => self.total_configs_tested,
         }
     }
 
-    /// Toggle the view scope between Session and AllTime.
+    /// Record configs that survived walk-forward validation.
+    ///
+    /// Uses `wf_sharpe_threshold` as the minimum mean OOS Sharpe, so configs
+    /// that only looked good in-sample never reach the walk-forward scope.
+    fn record_walk_forward<'a>(
+        &mut self,
+        per_symbol: &Leaderboard,
+        cross_symbol: impl IntoIterator<Item = &'a AggregatedConfigResult>,
+        rank_by: CrossSymbolRankMetric,
+    ) {
+        let threshold = self.wf_sharpe_threshold;
+        for entry in per_symbol.entries.iter() {
+            self.walk_forward_leaderboard
+                .try_insert_walk_forward(entry.clone(), threshold);
+        }
+        let wf_cross = self
+            .walk_forward_cross_symbol_leaderboard
+            .get_or_insert_with(|| {
+                CrossSymbolLeaderboard::with_max_per_strategy(
+                    1000, // Same limits as the session view
+                    rank_by, 100,
+                )
+            });
+        for entry in cross_symbol {
+            wf_cross.try_insert_walk_forward(entry.clone(), threshold);
+        }
+    }
+
+    /// Count a config rejected by the walk-forward gate.
+    pub fn record_rejection(&mut self, reason: YoloRejectReason) {
+        *self.rejection_counts.entry(reason).or_insert(0) += 1;
+    }
+
+    /// Total configs rejected by the walk-forward gate this session.
+    pub fn total_rejections(&self) -> u64 {
+        self.rejection_counts.values().sum()
+    }
+
+    /// Cycle the view scope: Session -> AllTime -> WalkForward.
     pub fn toggle_scope(&mut self) {
         self.view_scope = self.view_scope.toggle();
     }
@@ -367,6 +428,8 @@ impl YoloState {
 
         self.session_configs_tested += configs_tested_this_round as u64;
 
+        self.record_walk_forward(&per_symbol, &cross_symbol.entries, cross_symbol.rank_by);
+
         // Merge into all-time leaderboards (unchanged logic)
         for entry in per_symbol.entries.iter() {
             self.all_time_leaderboard.try_insert(entry.clone());
@@ -407,6 +470,13 @@ impl YoloState {
             "YOLO DEBUG: update_leaderboards_with_session called"
         );
 
+        // Keep walk-forward survivors before session_results is consumed
+        let validated: Vec<AggregatedConfigResult> = session_results
+            .iter()
+            .filter(|e| e.passes_walk_forward(self.wf_sharpe_threshold))
+            .cloned()
+            .collect();
+
         // Initialize session cross-symbol leaderboard if needed
         let session_cross = self
             .session_cross_symbol_leaderboard
@@ -451,6 +521,12 @@ impl YoloState {
 
         self.session_configs_tested += configs_tested_this_round as u64;
 
+        self.record_walk_forward(
+            &per_symbol,
+            validated.iter().chain(&cross_symbol.entries),
+            cross_symbol.rank_by,
+        );
+
         // Merge into all-time leaderboards (unchanged logic)
         for entry in per_symbol.entries.iter() {
             self.all_time_leaderboard.try_insert(entry.clone());
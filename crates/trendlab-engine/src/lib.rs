@@ -10,8 +10,12 @@
 //! - GUI: Uses Tauri commands to expose state to React frontend
 
 pub mod app;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
 pub mod worker;
 
 // Re-export main types for convenience
 pub use app::App;
-pub use worker::{WorkerChannels, WorkerCommand, WorkerUpdate};
+pub use worker::{
+    SweepEta, TimingSummary, WorkerChannels, WorkerCommand, WorkerUpdate, YoloRejectReason,
+};
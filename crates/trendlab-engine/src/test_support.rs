@@ -0,0 +1,29 @@
+//! Shared fixtures for engine and GUI tests.
+//!
+//! Compiled for this crate's own tests, and for other crates that enable the
+//! `test-support` feature (as a dev-dependency).
+
+use chrono::{Duration, TimeZone, Utc};
+use trendlab_core::Bar;
+
+/// Daily bars from 2024-01-01 whose close is
+/// `100 + drift * i + amplitude * sin(i * freq)`, with a one-point high/low band.
+pub fn sine_wave_bars(n: usize, freq: f64, amplitude: f64, drift: f64, symbol: &str) -> Vec<Bar> {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    (0..n)
+        .map(|i| {
+            let x = i as f64;
+            let close = 100.0 + drift * x + (x * freq).sin() * amplitude;
+            Bar::new(
+                start + Duration::days(i as i64),
+                close,
+                close + 1.0,
+                close - 1.0,
+                close,
+                1_000.0,
+                symbol,
+                "1d",
+            )
+        })
+        .collect()
+}
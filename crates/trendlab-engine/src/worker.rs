@@ -4,34 +4,38 @@
 //! - Yahoo Finance data fetching (async HTTP)
 //! - Parameter sweeps (parallel via Rayon)
 //! - Cancellation via atomic flag
+//! - Per-command timing (`WorkerUpdate::Timing`) and sweep throughput summaries
 #![allow(dead_code)]
 
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::mpsc::{Receiver, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
+use std::time::Instant;
 
 use chrono::{NaiveDate, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use tracing::{debug, info, trace};
 
 use crate::app::ComboMode;
 use trendlab_core::{
     bars_to_dataframe, build_exploration_state_from_history, build_tested_configs_index,
-    build_yahoo_chart_url, combine_equity_curves_simple, compute_analysis,
-    create_artifact_from_config, dataframe_to_bars, export_artifact_to_file,
+    build_yahoo_chart_url, check_yahoo_response, combine_equity_curves_simple, compute_analysis,
+    create_artifact_from_config, dataframe_to_bars, derive_seed, export_artifact_to_file,
     get_parquet_date_range, normalize_config, one_sided_mean_pvalue, parse_yahoo_chart_json,
     run_donchian_sweep_polars, run_strategy_sweep_polars_cached,
-    run_strategy_sweep_polars_parallel, scan_symbol_parquet_lazy,
-    select_exploration_mode_with_config, write_partitioned_parquet, AggregatedConfigResult,
-    AggregatedMetrics, AggregatedPortfolioResult, AnalysisConfig, BacktestConfig, BacktestResult,
-    Bar, CostModel, CrossSymbolLeaderboard, CrossSymbolRankMetric, DataQualityChecker,
-    DataQualityReport, DonchianBacktestConfig, ExplorationConfig, ExplorationMode,
-    ExplorationState, HistoryLogger, IntoLazy, Leaderboard, LeaderboardEntry, Metrics,
-    MultiStrategyGrid, MultiStrategySweepResult, MultiSweepResult, OpeningPeriod,
-    PolarsBacktestConfig, RankMetric, StatisticalAnalysis, StrategyBestResult, StrategyConfigId,
-    StrategyGridConfig, StrategyParams, StrategyTypeId, SweepConfigResult, SweepGrid, SweepResult,
-    TestedConfigsIndex, VotingMethod, WalkForwardConfig, WalkForwardResult,
+    run_strategy_sweep_polars_parallel_with_cancel, scan_symbol_parquet_lazy,
+    select_exploration_mode_with_config, sign_flip_test, write_partitioned_parquet,
+    AggregatedConfigResult, AggregatedMetrics, AggregatedPortfolioResult, AnalysisConfig,
+    BacktestConfig, BacktestResult, Bar, BootstrapConfig, CostModel, CrossSymbolLeaderboard,
+    CrossSymbolRankMetric, DataQualityChecker, DataQualityReport, DonchianBacktestConfig,
+    ExplorationConfig, ExplorationMode, ExplorationState, HistoryLogger, IntoLazy, Leaderboard,
+    LeaderboardEntry, Metrics, MultiStrategyGrid, MultiStrategySweepResult, MultiSweepResult,
+    OpeningPeriod, PermutationResult, PolarsBacktestConfig, ProviderError, RankMetric, RateLimiter,
+    RetryPolicy, StatisticalAnalysis, StrategyBestResult, StrategyConfigId, StrategyGridConfig,
+    StrategyParams, StrategyStatistics, StrategyTypeId, SweepConfigResult, SweepGrid, SweepResult,
+    TestedConfigsIndex, VotingMethod, WalkForwardConfig, WalkForwardResult, YoloCheckpoint,
+    CROSS_SYMBOL_LEADERBOARD_FILE, DEFAULT_REQUESTS_PER_SEC, LEADERBOARD_FILE,
 };
 
 /// Commands sent from TUI thread to worker thread.
@@ -120,6 +124,16 @@ pub enum WorkerCommand {
         config: AnalysisConfig,
     },
 
+    /// Compute bootstrap/permutation statistics for a backtest result.
+    ComputeStatistics {
+        /// Identifier for this run (e.g., config_id string)
+        config_id: String,
+        /// The backtest result whose equity returns are tested
+        backtest_result: BacktestResult,
+        /// Bootstrap configuration (iterations, confidence level, seed)
+        config: BootstrapConfig,
+    },
+
     /// Start YOLO mode - continuous auto-optimization loop.
     /// Runs multi-strategy sweeps with randomized parameters until cancelled.
     StartYoloMode {
@@ -160,6 +174,217 @@ pub enum WorkerCommand {
     },
 }
 
+impl WorkerCommand {
+    /// Short name used for timing and tracing spans.
+    pub fn name(&self) -> &'static str {
+        match self {
+            WorkerCommand::SearchSymbols { .. } => "search_symbols",
+            WorkerCommand::FetchData { .. } => "fetch_data",
+            WorkerCommand::LoadCachedData { .. } => "load_cached_data",
+            WorkerCommand::StartSweep { .. } => "sweep",
+            WorkerCommand::StartMultiSweep { .. } => "multi_sweep",
+            WorkerCommand::StartMultiStrategySweep { .. } => "multi_strategy_sweep",
+            WorkerCommand::StartSweepFromParquet { .. } => "sweep_from_parquet",
+            WorkerCommand::StartMultiStrategySweepFromParquet { .. } => {
+                "multi_strategy_sweep_from_parquet"
+            }
+            WorkerCommand::Cancel => "cancel",
+            WorkerCommand::Shutdown => "shutdown",
+            WorkerCommand::ComputeAnalysis { .. } => "compute_analysis",
+            WorkerCommand::ComputeStatistics { .. } => "compute_statistics",
+            WorkerCommand::StartYoloMode { .. } => "yolo_mode",
+        }
+    }
+}
+
+/// Cumulative command timings over the worker's lifetime.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimingSummary {
+    /// Number of commands timed so far.
+    pub commands: usize,
+    /// Total handler time across all commands, in milliseconds.
+    pub total_millis: f64,
+    /// Configurations completed by sweep commands (partial for cancelled sweeps).
+    pub sweep_configs: usize,
+    /// Handler time spent in sweep commands, in milliseconds.
+    pub sweep_millis: f64,
+}
+
+impl TimingSummary {
+    fn record(&mut self, millis: f64, sweep_configs: Option<usize>) {
+        self.commands += 1;
+        self.total_millis += millis;
+        if let Some(configs) = sweep_configs {
+            self.sweep_configs += configs;
+            self.sweep_millis += millis;
+        }
+    }
+
+    /// Sweep throughput in configurations per second.
+    pub fn configs_per_sec(&self) -> f64 {
+        if self.sweep_millis > 0.0 {
+            self.sweep_configs as f64 / (self.sweep_millis / 1000.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Minimum completed configs before a sweep ETA is reported.
+const ETA_MIN_COMPLETED: usize = 5;
+
+/// Number of recent progress samples the ETA rate is measured over.
+const ETA_WINDOW: usize = 20;
+
+/// Estimates time remaining for a sweep from its recent completion rate.
+///
+/// The rate is measured across the last [`ETA_WINDOW`] progress samples, so
+/// the estimate follows speed changes (warmup, slower parameter regions)
+/// instead of averaging over the whole run.
+#[derive(Debug)]
+pub struct SweepEta {
+    started: Instant,
+    total: usize,
+    /// Recent (elapsed seconds, completed configs) samples, oldest first.
+    samples: Mutex<VecDeque<(f64, usize)>>,
+}
+
+impl SweepEta {
+    /// Start timing a sweep of `total` configurations.
+    pub fn new(total: usize) -> Self {
+        Self {
+            started: Instant::now(),
+            total,
+            samples: Mutex::new(VecDeque::with_capacity(ETA_WINDOW + 1)),
+        }
+    }
+
+    /// Seconds remaining after `completed` configs, measured from now.
+    pub fn eta_seconds(&self, completed: usize) -> Option<f64> {
+        self.eta_at(completed, self.started.elapsed().as_secs_f64())
+    }
+
+    /// Seconds remaining after `completed` configs took `elapsed_secs`.
+    ///
+    /// Records the sample, then divides the remaining configs by the rate
+    /// since the oldest sample in the window (the sweep start until the window
+    /// fills). Returns `None` for the first few configs, where the rate is
+    /// dominated by warmup noise (thread pool spin-up, indicator caches).
+    pub fn eta_at(&self, completed: usize, elapsed_secs: f64) -> Option<f64> {
+        let mut samples = self.samples.lock().unwrap();
+        samples.push_back((elapsed_secs, completed));
+        if samples.len() > ETA_WINDOW {
+            samples.pop_front();
+        }
+
+        if completed < ETA_MIN_COMPLETED.min(self.total) {
+            return None;
+        }
+        let (since_secs, since_completed) = if samples.len() < ETA_WINDOW {
+            (0.0, 0)
+        } else {
+            samples[0]
+        };
+        let window_secs = elapsed_secs - since_secs;
+        let window_completed = completed.saturating_sub(since_completed);
+        if window_secs <= 0.0 || window_completed == 0 {
+            return None;
+        }
+
+        let remaining = self.total.saturating_sub(completed);
+        let rate = window_completed as f64 / window_secs;
+        Some(remaining as f64 / rate)
+    }
+}
+
+/// Largest number of configs in one `SweepPartialResults` batch.
+const SWEEP_BATCH_MAX: usize = 50;
+
+/// Configs per partial-results batch: about ten batches per sweep, capped at
+/// [`SWEEP_BATCH_MAX`] so large sweeps stream steadily.
+fn sweep_batch_size(total: usize) -> usize {
+    (total / 10).clamp(1, SWEEP_BATCH_MAX)
+}
+
+/// Run a Donchian grid through the Polars engine one batch at a time.
+///
+/// Each batch is sent as `SweepProgress` and `SweepPartialResults` as soon as
+/// it completes, and cancellation is checked between batches. If the sweep
+/// is cancelled or fails, sends `SweepCancelled` and returns the number of
+/// configs completed as the error.
+fn run_donchian_polars_batches(
+    df: &polars::prelude::DataFrame,
+    combinations: &[(usize, usize)],
+    config: BacktestConfig,
+    update_tx: &Sender<WorkerUpdate>,
+    cancel_flag: &Arc<AtomicBool>,
+) -> Result<Vec<SweepConfigResult>, usize> {
+    use trendlab_core::{compute_metrics, ConfigId};
+
+    let total = combinations.len();
+    let eta = SweepEta::new(total);
+    let mut results = Vec::with_capacity(total);
+
+    for chunk in combinations.chunks(sweep_batch_size(total)) {
+        if cancel_flag.load(Ordering::SeqCst) {
+            let _ = update_tx.send(WorkerUpdate::SweepCancelled {
+                completed: results.len(),
+            });
+            return Err(results.len());
+        }
+
+        let polars_configs: Vec<DonchianBacktestConfig> = chunk
+            .iter()
+            .map(|&(entry, exit)| {
+                DonchianBacktestConfig::new(entry, exit)
+                    .with_initial_cash(config.initial_cash)
+                    .with_qty(config.qty)
+                    .with_cost_model(config.cost_model)
+            })
+            .collect();
+
+        // Indicators are shared across the configs within a batch
+        let polars_results = match run_donchian_sweep_polars(df.clone().lazy(), &polars_configs) {
+            Ok(results) => results,
+            Err(e) => {
+                let _ = update_tx.send(WorkerUpdate::SweepCancelled {
+                    completed: results.len(),
+                });
+                eprintln!("Polars sweep failed: {}", e);
+                return Err(results.len());
+            }
+        };
+
+        // Convert Polars results to SweepConfigResult format
+        let batch: Vec<SweepConfigResult> = chunk
+            .iter()
+            .zip(polars_results.iter())
+            .filter_map(|(&(entry, exit), polars_result)| {
+                let backtest_result = polars_result.to_backtest_result().ok()?;
+                let metrics = compute_metrics(&backtest_result, config.initial_cash);
+
+                Some(SweepConfigResult {
+                    config_id: ConfigId::new(entry, exit),
+                    backtest_result,
+                    metrics,
+                })
+            })
+            .collect();
+
+        results.extend(batch.iter().cloned());
+        let _ = update_tx.send(WorkerUpdate::SweepProgress {
+            completed: results.len(),
+            total,
+            eta_seconds: eta.eta_seconds(results.len()),
+        });
+        if !batch.is_empty() {
+            let _ = update_tx.send(WorkerUpdate::SweepPartialResults { batch });
+        }
+    }
+
+    Ok(results)
+}
+
 /// A symbol search result from Yahoo.
 #[derive(Debug, Clone)]
 pub struct SymbolSearchResult {
@@ -228,6 +453,13 @@ pub enum WorkerUpdate {
     SweepProgress {
         completed: usize,
         total: usize,
+        /// Estimated seconds remaining; `None` until the rate has stabilized.
+        eta_seconds: Option<f64>,
+    },
+    /// Configs completed since the last batch, sent while the sweep runs.
+    /// Batches add up to the results in the final `SweepComplete`.
+    SweepPartialResults {
+        batch: Vec<SweepConfigResult>,
     },
     SweepComplete {
         result: SweepResult,
@@ -292,6 +524,17 @@ pub enum WorkerUpdate {
         analysis_id: String,
         error: String,
     },
+    StatisticsComplete {
+        config_id: String,
+        /// Bootstrap Sharpe CI and confidence grade
+        statistics: StrategyStatistics,
+        /// Sign-flip permutation test on the mean return
+        permutation: PermutationResult,
+    },
+    StatisticsError {
+        config_id: String,
+        error: String,
+    },
 
     // YOLO mode updates
     YoloModeStarted {
@@ -340,6 +583,16 @@ pub enum WorkerUpdate {
         /// These bypass the top-N displacement and go directly to session leaderboard
         session_results: Vec<AggregatedConfigResult>,
     },
+    /// A YOLO config did not pass walk-forward validation.
+    ///
+    /// The config still reaches the session leaderboards; it just never makes
+    /// the walk-forward scope. Tally `reason` to tune `wf_sharpe_threshold`.
+    YoloConfigRejected {
+        config_id: StrategyConfigId,
+        reason: YoloRejectReason,
+        /// Mean out-of-sample Sharpe (None when walk-forward never ran)
+        wf_sharpe: Option<f64>,
+    },
     YoloProgress {
         iteration: u32,
         phase: String,
@@ -357,6 +610,17 @@ pub enum WorkerUpdate {
         total_configs_tested: u64,
     },
 
+    // Timing updates
+    /// Wall-clock duration of a finished command handler.
+    Timing {
+        command: &'static str,
+        millis: f64,
+    },
+    /// Cumulative timings, sent after each sweep command completes.
+    TimingSummary {
+        summary: TimingSummary,
+    },
+
     // General
     Ready,
     Idle,
@@ -406,10 +670,22 @@ fn worker_loop(
     // Signal ready
     let _ = update_tx.send(WorkerUpdate::Ready);
 
+    let mut timings = TimingSummary::default();
+
+    // One pacer for the worker's lifetime so back-to-back fetch commands share the budget
+    let pacer = FetchPacer::new(DEFAULT_REQUESTS_PER_SEC);
+
     while let Ok(cmd) = command_rx.recv() {
         // Reset cancel flag for new operation
         cancel_flag.store(false, Ordering::SeqCst);
 
+        let command = cmd.name();
+        // Configs completed, set by sweep commands only
+        let mut sweep_configs: Option<usize> = None;
+        let span = tracing::debug_span!("worker_command", command);
+        let _entered = span.enter();
+        let started = Instant::now();
+
         match cmd {
             WorkerCommand::SearchSymbols { query } => {
                 rt.block_on(handle_search(&query, &update_tx));
@@ -426,6 +702,7 @@ fn worker_loop(
                     start,
                     end,
                     force,
+                    &pacer,
                     &update_tx,
                     &cancel_flag,
                 ));
@@ -441,11 +718,11 @@ fn worker_loop(
                 backtest_config,
                 use_polars,
             } => {
-                if use_polars {
-                    handle_sweep_polars(&bars, &grid, backtest_config, &update_tx, &cancel_flag);
+                sweep_configs = Some(if use_polars {
+                    handle_sweep_polars(&bars, &grid, backtest_config, &update_tx, &cancel_flag)
                 } else {
-                    handle_sweep(&bars, &grid, backtest_config, &update_tx, &cancel_flag);
-                }
+                    handle_sweep(&bars, &grid, backtest_config, &update_tx, &cancel_flag)
+                });
             }
 
             WorkerCommand::StartMultiSweep {
@@ -453,13 +730,13 @@ fn worker_loop(
                 grid,
                 backtest_config,
             } => {
-                handle_multi_sweep(
+                sweep_configs = Some(handle_multi_sweep(
                     symbol_bars,
                     &grid,
                     backtest_config,
                     &update_tx,
                     &cancel_flag,
-                );
+                ));
             }
 
             WorkerCommand::StartMultiStrategySweep {
@@ -467,13 +744,13 @@ fn worker_loop(
                 strategy_grid,
                 backtest_config,
             } => {
-                handle_multi_strategy_sweep(
+                sweep_configs = Some(handle_multi_strategy_sweep(
                     symbol_bars,
                     &strategy_grid,
                     backtest_config,
                     &update_tx,
                     &cancel_flag,
-                );
+                ));
             }
 
             WorkerCommand::StartSweepFromParquet {
@@ -484,7 +761,7 @@ fn worker_loop(
                 backtest_config,
                 use_polars: _,
             } => {
-                handle_sweep_from_parquet(
+                sweep_configs = Some(handle_sweep_from_parquet(
                     &symbol,
                     start,
                     end,
@@ -492,7 +769,7 @@ fn worker_loop(
                     backtest_config,
                     &update_tx,
                     &cancel_flag,
-                );
+                ));
             }
 
             WorkerCommand::StartMultiStrategySweepFromParquet {
@@ -502,7 +779,7 @@ fn worker_loop(
                 strategy_grid,
                 backtest_config,
             } => {
-                handle_multi_strategy_sweep_from_parquet(
+                sweep_configs = Some(handle_multi_strategy_sweep_from_parquet(
                     &symbols,
                     start,
                     end,
@@ -510,7 +787,7 @@ fn worker_loop(
                     backtest_config,
                     &update_tx,
                     &cancel_flag,
-                );
+                ));
             }
 
             WorkerCommand::ComputeAnalysis {
@@ -522,6 +799,14 @@ fn worker_loop(
                 handle_compute_analysis(&analysis_id, &backtest_result, &bars, &config, &update_tx);
             }
 
+            WorkerCommand::ComputeStatistics {
+                config_id,
+                backtest_result,
+                config,
+            } => {
+                handle_compute_statistics(&config_id, &backtest_result, &config, &update_tx);
+            }
+
             WorkerCommand::StartYoloMode {
                 symbols,
                 symbol_sector_ids,
@@ -557,6 +842,7 @@ fn worker_loop(
                     warmup_iterations,
                     combo_warmup_iterations,
                     combo_mode,
+                    &pacer,
                     &update_tx,
                     &cancel_flag,
                 ));
@@ -572,6 +858,16 @@ fn worker_loop(
             }
         }
 
+        let millis = started.elapsed().as_secs_f64() * 1000.0;
+        debug!(command, millis, "Worker command finished");
+        timings.record(millis, sweep_configs);
+        let _ = update_tx.send(WorkerUpdate::Timing { command, millis });
+        if sweep_configs.is_some() {
+            let _ = update_tx.send(WorkerUpdate::TimingSummary {
+                summary: timings.clone(),
+            });
+        }
+
         // Signal idle after each operation
         let _ = update_tx.send(WorkerUpdate::Idle);
     }
@@ -674,6 +970,7 @@ async fn handle_fetch(
     start: NaiveDate,
     end: NaiveDate,
     _force: bool,
+    pacer: &FetchPacer,
     update_tx: &Sender<WorkerUpdate>,
     cancel_flag: &Arc<AtomicBool>,
 ) {
@@ -685,6 +982,7 @@ async fn handle_fetch(
         .build()
         .unwrap_or_default();
 
+    let retry = RetryPolicy::default();
     let total = symbols.len();
     let mut fetched = 0;
 
@@ -705,58 +1003,37 @@ async fn handle_fetch(
 
         // Use the chart API (v8) which doesn't require authentication
         let url = build_yahoo_chart_url(symbol, start, end);
+        let (body, retries) =
+            fetch_yahoo_chart_with_retry(&client, &url, symbol, &retry, pacer).await;
+        if retries > 0 {
+            info!(symbol = %symbol, retries = retries, "Yahoo fetch needed retries");
+        }
+
+        match body.and_then(|json_text| parse_yahoo_chart_json(&json_text, symbol, "1d")) {
+            Ok(bars) => {
+                let checker = DataQualityChecker::new();
+                let quality = checker.check(&bars);
 
-        match client.get(&url).send().await {
-            Ok(response) => {
-                if !response.status().is_success() {
+                // Persist to Parquet cache
+                if let Err(e) = write_partitioned_parquet(&bars, parquet_dir) {
                     let _ = update_tx.send(WorkerUpdate::FetchError {
                         symbol: symbol.clone(),
-                        error: format!("HTTP {}", response.status()),
+                        error: format!("Parquet write error: {}", e),
                     });
                     continue;
                 }
 
-                match response.text().await {
-                    Ok(json_text) => match parse_yahoo_chart_json(&json_text, symbol, "1d") {
-                        Ok(bars) => {
-                            let checker = DataQualityChecker::new();
-                            let quality = checker.check(&bars);
-
-                            // Persist to Parquet cache
-                            if let Err(e) = write_partitioned_parquet(&bars, parquet_dir) {
-                                let _ = update_tx.send(WorkerUpdate::FetchError {
-                                    symbol: symbol.clone(),
-                                    error: format!("Parquet write error: {}", e),
-                                });
-                                continue;
-                            }
-
-                            let _ = update_tx.send(WorkerUpdate::FetchComplete {
-                                symbol: symbol.clone(),
-                                bars,
-                                quality,
-                            });
-                            fetched += 1;
-                        }
-                        Err(e) => {
-                            let _ = update_tx.send(WorkerUpdate::FetchError {
-                                symbol: symbol.clone(),
-                                error: format!("Parse error: {}", e),
-                            });
-                        }
-                    },
-                    Err(e) => {
-                        let _ = update_tx.send(WorkerUpdate::FetchError {
-                            symbol: symbol.clone(),
-                            error: format!("Read error: {}", e),
-                        });
-                    }
-                }
+                let _ = update_tx.send(WorkerUpdate::FetchComplete {
+                    symbol: symbol.clone(),
+                    bars,
+                    quality,
+                });
+                fetched += 1;
             }
             Err(e) => {
                 let _ = update_tx.send(WorkerUpdate::FetchError {
                     symbol: symbol.clone(),
-                    error: format!("Network error: {}", e),
+                    error: e.to_string(),
                 });
             }
         }
@@ -767,6 +1044,76 @@ async fn handle_fetch(
     });
 }
 
+/// Paces worker requests to Yahoo with a shared token bucket.
+struct FetchPacer {
+    limiter: Mutex<RateLimiter>,
+}
+
+impl FetchPacer {
+    fn new(requests_per_sec: f64) -> Self {
+        Self {
+            limiter: Mutex::new(RateLimiter::new(requests_per_sec)),
+        }
+    }
+
+    /// Wait until the next request is allowed.
+    async fn wait(&self) {
+        let delay = self
+            .limiter
+            .lock()
+            .map(|mut limiter| limiter.reserve_now())
+            .unwrap_or_default();
+        if !delay.is_zero() {
+            trace!(delay_ms = delay.as_millis() as u64, "Pacing Yahoo request");
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// Fetch a Yahoo chart response, retrying rate-limit and network failures.
+///
+/// Every attempt is paced by `pacer`; rate-limit retries back off with jitter.
+/// Returns the body (already checked to be JSON rather than an HTML error
+/// page) together with the number of retries used.
+async fn fetch_yahoo_chart_with_retry(
+    client: &reqwest::Client,
+    url: &str,
+    symbol: &str,
+    retry: &RetryPolicy,
+    pacer: &FetchPacer,
+) -> (Result<String, ProviderError>, u32) {
+    let mut retries = 0;
+    loop {
+        pacer.wait().await;
+        let result = match client.get(url).send().await {
+            Ok(response) if !response.status().is_success() => Err(
+                ProviderError::from_http_status(response.status().as_u16(), symbol),
+            ),
+            Ok(response) => match response.text().await {
+                Ok(body) => check_yahoo_response(&body, true).map(|_| body),
+                Err(e) => Err(ProviderError::NetworkError {
+                    message: e.to_string(),
+                }),
+            },
+            Err(e) => Err(ProviderError::NetworkError {
+                message: e.to_string(),
+            }),
+        };
+
+        match result {
+            Err(e) if e.is_retryable() && retries + 1 < retry.max_attempts => {
+                retries += 1;
+                let delay = match e {
+                    ProviderError::RateLimited { .. } => retry.jittered_backoff(retries),
+                    _ => retry.backoff(retries),
+                };
+                tokio::time::sleep(delay).await;
+            }
+            result => return (result, retries),
+        }
+    }
+}
+
 /// Handle sweep operation (parallel via Rayon).
 fn handle_sweep(
     bars: &[Bar],
@@ -774,7 +1121,7 @@ fn handle_sweep(
     config: BacktestConfig,
     update_tx: &Sender<WorkerUpdate>,
     cancel_flag: &Arc<AtomicBool>,
-) {
+) -> usize {
     use rayon::prelude::*;
     use trendlab_core::{compute_metrics, run_backtest, ConfigId, DonchianBreakoutStrategy};
 
@@ -788,6 +1135,11 @@ fn handle_sweep(
     // Atomic counter for progress
     let completed = Arc::new(AtomicUsize::new(0));
     let report_interval = (total / 100).max(1); // Report ~100 times
+    let eta = SweepEta::new(total);
+
+    // Results not yet sent as a partial batch
+    let batch_size = sweep_batch_size(total);
+    let pending_batch: Mutex<Vec<SweepConfigResult>> = Mutex::new(Vec::new());
 
     // Clone values needed for parallel closure
     let completed_clone = completed.clone();
@@ -826,9 +1178,20 @@ fn handle_sweep(
                 let _ = update_tx_clone.send(WorkerUpdate::SweepProgress {
                     completed: count,
                     total,
+                    eta_seconds: eta.eta_seconds(count),
                 });
             }
 
+            // Stream completed configs in batches
+            let ready = {
+                let mut pending = pending_batch.lock().unwrap();
+                pending.push(result.clone());
+                (pending.len() >= batch_size).then(|| std::mem::take(&mut *pending))
+            };
+            if let Some(batch) = ready {
+                let _ = update_tx_clone.send(WorkerUpdate::SweepPartialResults { batch });
+            }
+
             Some(result)
         })
         .collect();
@@ -839,7 +1202,12 @@ fn handle_sweep(
         let _ = update_tx.send(WorkerUpdate::SweepCancelled {
             completed: final_completed,
         });
-        return;
+        return final_completed;
+    }
+
+    let remaining = pending_batch.into_inner().unwrap();
+    if !remaining.is_empty() {
+        let _ = update_tx.send(WorkerUpdate::SweepPartialResults { batch: remaining });
     }
 
     let sweep_result = SweepResult {
@@ -847,11 +1215,13 @@ fn handle_sweep(
         config_results: results,
         started_at: chrono::Utc::now(), // Approximate - we could track actual start
         completed_at: chrono::Utc::now(),
+        cancelled: false,
     };
 
     let _ = update_tx.send(WorkerUpdate::SweepComplete {
         result: sweep_result,
     });
+    final_completed
 }
 
 /// Handle sweep operation using Polars-native backtest (vectorized).
@@ -861,9 +1231,7 @@ fn handle_sweep_polars(
     config: BacktestConfig,
     update_tx: &Sender<WorkerUpdate>,
     cancel_flag: &Arc<AtomicBool>,
-) {
-    use trendlab_core::{compute_metrics, ConfigId};
-
+) -> usize {
     let combinations = grid.combinations();
     let total = combinations.len();
 
@@ -877,74 +1245,29 @@ fn handle_sweep_polars(
         Err(e) => {
             let _ = update_tx.send(WorkerUpdate::SweepCancelled { completed: 0 });
             eprintln!("Failed to convert bars to DataFrame: {}", e);
-            return;
+            return 0;
         }
     };
 
-    // Build configs for Polars sweep
-    let polars_configs: Vec<DonchianBacktestConfig> = combinations
-        .iter()
-        .map(|&(entry, exit)| {
-            DonchianBacktestConfig::new(entry, exit)
-                .with_initial_cash(config.initial_cash)
-                .with_qty(config.qty)
-                .with_cost_model(config.cost_model)
-        })
-        .collect();
-
-    // Check for cancellation before running sweep
-    if cancel_flag.load(Ordering::SeqCst) {
-        let _ = update_tx.send(WorkerUpdate::SweepCancelled { completed: 0 });
-        return;
-    }
-
-    // Run Polars sweep (uses indicator reuse optimization)
-    let polars_results = match run_donchian_sweep_polars(df.lazy(), &polars_configs) {
-        Ok(results) => results,
-        Err(e) => {
-            let _ = update_tx.send(WorkerUpdate::SweepCancelled { completed: 0 });
-            eprintln!("Polars sweep failed: {}", e);
-            return;
-        }
-    };
-
-    // Convert Polars results to SweepConfigResult format
-    let results: Vec<SweepConfigResult> = combinations
-        .iter()
-        .zip(polars_results.iter())
-        .filter_map(|(&(entry, exit), polars_result)| {
-            // Convert PolarsBacktestResult to BacktestResult
-            let backtest_result = match polars_result.to_backtest_result() {
-                Ok(r) => r,
-                Err(_) => return None,
-            };
-
-            let metrics = compute_metrics(&backtest_result, config.initial_cash);
-
-            Some(SweepConfigResult {
-                config_id: ConfigId::new(entry, exit),
-                backtest_result,
-                metrics,
-            })
-        })
-        .collect();
-
-    // Send progress update (all at once for Polars)
-    let _ = update_tx.send(WorkerUpdate::SweepProgress {
-        completed: results.len(),
-        total,
-    });
+    let results =
+        match run_donchian_polars_batches(&df, &combinations, config, update_tx, cancel_flag) {
+            Ok(results) => results,
+            Err(completed) => return completed,
+        };
 
+    let completed = results.len();
     let sweep_result = SweepResult {
         sweep_id: format!("tui_polars_{}", chrono::Utc::now().format("%Y%m%d_%H%M%S")),
         config_results: results,
         started_at: chrono::Utc::now(),
         completed_at: chrono::Utc::now(),
+        cancelled: false,
     };
 
     let _ = update_tx.send(WorkerUpdate::SweepComplete {
         result: sweep_result,
     });
+    completed
 }
 
 /// Handle multi-ticker sweep operation.
@@ -954,7 +1277,7 @@ fn handle_multi_sweep(
     config: BacktestConfig,
     update_tx: &Sender<WorkerUpdate>,
     cancel_flag: &Arc<AtomicBool>,
-) {
+) -> usize {
     use trendlab_core::{compute_metrics, run_backtest, ConfigId, DonchianBreakoutStrategy};
 
     let total_symbols = symbol_bars.len();
@@ -970,6 +1293,7 @@ fn handle_multi_sweep(
     let started_at = chrono::Utc::now();
 
     let mut symbols_completed = 0;
+    let mut configs_completed = 0;
 
     // Sort symbols for deterministic ordering
     let mut symbols: Vec<String> = symbol_bars.keys().cloned().collect();
@@ -981,7 +1305,7 @@ fn handle_multi_sweep(
             let _ = update_tx.send(WorkerUpdate::MultiSweepCancelled {
                 completed_symbols: symbols_completed,
             });
-            return;
+            return configs_completed;
         }
 
         let _ = update_tx.send(WorkerUpdate::MultiSweepSymbolStarted {
@@ -1030,6 +1354,7 @@ fn handle_multi_sweep(
             config_results,
             started_at: symbol_started,
             completed_at: chrono::Utc::now(),
+            cancelled: false,
         };
 
         // Send symbol complete update
@@ -1038,6 +1363,7 @@ fn handle_multi_sweep(
             result: symbol_result.clone(),
         });
 
+        configs_completed += symbol_result.config_results.len();
         multi_result.add_symbol_result(symbol.clone(), symbol_result);
         symbols_completed += 1;
     }
@@ -1053,6 +1379,7 @@ fn handle_multi_sweep(
     let _ = update_tx.send(WorkerUpdate::MultiSweepComplete {
         result: multi_result,
     });
+    configs_completed
 }
 
 /// Handle sweep from Parquet directly (Phase 4 - no Vec<Bar> intermediate).
@@ -1067,9 +1394,8 @@ fn handle_sweep_from_parquet(
     config: BacktestConfig,
     update_tx: &Sender<WorkerUpdate>,
     cancel_flag: &Arc<AtomicBool>,
-) {
+) -> usize {
     use std::path::Path;
-    use trendlab_core::compute_metrics;
 
     let parquet_dir = Path::new("data/parquet");
 
@@ -1079,7 +1405,7 @@ fn handle_sweep_from_parquet(
         Err(e) => {
             let _ = update_tx.send(WorkerUpdate::SweepCancelled { completed: 0 });
             eprintln!("Failed to scan Parquet for {}: {}", symbol, e);
-            return;
+            return 0;
         }
     };
 
@@ -1089,7 +1415,7 @@ fn handle_sweep_from_parquet(
         Err(e) => {
             let _ = update_tx.send(WorkerUpdate::SweepCancelled { completed: 0 });
             eprintln!("Failed to collect Parquet data: {}", e);
-            return;
+            return 0;
         }
     };
 
@@ -1098,71 +1424,26 @@ fn handle_sweep_from_parquet(
         total_configs: total,
     });
 
-    // Check for cancellation
-    if cancel_flag.load(Ordering::SeqCst) {
-        let _ = update_tx.send(WorkerUpdate::SweepCancelled { completed: 0 });
-        return;
-    }
-
-    // Build Polars configs for sweep
     let combinations = grid.combinations();
-    let polars_configs: Vec<DonchianBacktestConfig> = combinations
-        .iter()
-        .map(|&(entry, exit)| {
-            DonchianBacktestConfig::new(entry, exit)
-                .with_initial_cash(config.initial_cash)
-                .with_qty(config.qty)
-                .with_cost_model(config.cost_model)
-        })
-        .collect();
-
-    // Run Polars sweep (uses indicator reuse optimization)
-    let polars_results = match run_donchian_sweep_polars(df.lazy(), &polars_configs) {
-        Ok(results) => results,
-        Err(e) => {
-            let _ = update_tx.send(WorkerUpdate::SweepCancelled { completed: 0 });
-            eprintln!("Polars sweep failed: {}", e);
-            return;
-        }
-    };
-
-    // Convert Polars results to SweepConfigResult format
-    use trendlab_core::ConfigId;
-    let results: Vec<SweepConfigResult> = combinations
-        .iter()
-        .zip(polars_results.iter())
-        .filter_map(|(&(entry, exit), polars_result)| {
-            let backtest_result = match polars_result.to_backtest_result() {
-                Ok(r) => r,
-                Err(_) => return None,
-            };
-
-            let metrics = compute_metrics(&backtest_result, config.initial_cash);
-
-            Some(SweepConfigResult {
-                config_id: ConfigId::new(entry, exit),
-                backtest_result,
-                metrics,
-            })
-        })
-        .collect();
-
-    // Send progress update
-    let _ = update_tx.send(WorkerUpdate::SweepProgress {
-        completed: results.len(),
-        total,
-    });
+    let results =
+        match run_donchian_polars_batches(&df, &combinations, config, update_tx, cancel_flag) {
+            Ok(results) => results,
+            Err(completed) => return completed,
+        };
 
+    let completed = results.len();
     let sweep_result = SweepResult {
         sweep_id: format!("tui_parquet_{}", chrono::Utc::now().format("%Y%m%d_%H%M%S")),
         config_results: results,
         started_at: chrono::Utc::now(),
         completed_at: chrono::Utc::now(),
+        cancelled: false,
     };
 
     let _ = update_tx.send(WorkerUpdate::SweepComplete {
         result: sweep_result,
     });
+    completed
 }
 
 /// Handle multi-strategy sweep from Parquet directly (Phase 4 - no Vec<Bar> intermediate).
@@ -1177,7 +1458,7 @@ fn handle_multi_strategy_sweep_from_parquet(
     config: BacktestConfig,
     update_tx: &Sender<WorkerUpdate>,
     cancel_flag: &Arc<AtomicBool>,
-) {
+) -> usize {
     use std::path::Path;
 
     let parquet_dir = Path::new("data/parquet");
@@ -1206,6 +1487,7 @@ fn handle_multi_strategy_sweep_from_parquet(
         PolarsBacktestConfig::new(config.initial_cash, config.qty).with_cost_model(CostModel {
             fees_bps_per_side: config.cost_model.fees_bps_per_side,
             slippage_bps: config.cost_model.slippage_bps,
+            ..Default::default()
         });
 
     // For each symbol
@@ -1214,7 +1496,7 @@ fn handle_multi_strategy_sweep_from_parquet(
             let _ = update_tx.send(WorkerUpdate::MultiStrategySweepCancelled {
                 completed_configs: completed_configs.load(Ordering::SeqCst),
             });
-            return;
+            return completed_configs.load(Ordering::SeqCst);
         }
 
         // Scan Parquet directly into LazyFrame
@@ -1250,23 +1532,35 @@ fn handle_multi_strategy_sweep_from_parquet(
             });
 
             // Run Polars-native sweep
-            let sweep_result =
-                match run_strategy_sweep_polars_parallel(&df, strategy_config, &polars_config) {
-                    Ok(r) => r,
-                    Err(e) => {
-                        eprintln!(
-                            "Polars sweep failed for {} / {:?}: {}",
-                            symbol, strategy_config.strategy_type, e
-                        );
-                        continue;
-                    }
-                };
+            let sweep_result = match run_strategy_sweep_polars_parallel_with_cancel(
+                &df,
+                strategy_config,
+                &polars_config,
+                cancel_flag,
+            ) {
+                Ok(r) => r,
+                Err(e) => {
+                    eprintln!(
+                        "Polars sweep failed for {} / {:?}: {}",
+                        symbol, strategy_config.strategy_type, e
+                    );
+                    continue;
+                }
+            };
 
             // Update progress
             let num_configs = sweep_result.config_results.len();
             let new_count =
                 completed_configs.fetch_add(num_configs, Ordering::SeqCst) + num_configs;
 
+            // Cancelled mid-sweep: report the partial count and stop
+            if sweep_result.cancelled {
+                let _ = update_tx.send(WorkerUpdate::MultiStrategySweepCancelled {
+                    completed_configs: new_count,
+                });
+                return new_count;
+            }
+
             let _ = update_tx.send(WorkerUpdate::MultiStrategySweepProgress {
                 completed_configs: new_count,
                 total_configs,
@@ -1283,6 +1577,7 @@ fn handle_multi_strategy_sweep_from_parquet(
     result.compute_aggregations();
 
     let _ = update_tx.send(WorkerUpdate::MultiStrategySweepComplete { result });
+    completed_configs.load(Ordering::SeqCst)
 }
 
 /// Handle multi-strategy sweep operation (all strategies across all symbols).
@@ -1294,7 +1589,7 @@ fn handle_multi_strategy_sweep(
     config: BacktestConfig,
     update_tx: &Sender<WorkerUpdate>,
     cancel_flag: &Arc<AtomicBool>,
-) {
+) -> usize {
     let total_symbols = symbol_bars.len();
     let enabled_strategies = grid.enabled_strategies();
     let total_strategies = enabled_strategies.len();
@@ -1319,6 +1614,7 @@ fn handle_multi_strategy_sweep(
         PolarsBacktestConfig::new(config.initial_cash, config.qty).with_cost_model(CostModel {
             fees_bps_per_side: config.cost_model.fees_bps_per_side,
             slippage_bps: config.cost_model.slippage_bps,
+            ..Default::default()
         });
 
     // Sort symbols for deterministic ordering
@@ -1331,7 +1627,7 @@ fn handle_multi_strategy_sweep(
             let _ = update_tx.send(WorkerUpdate::MultiStrategySweepCancelled {
                 completed_configs: completed_configs.load(Ordering::SeqCst),
             });
-            return;
+            return completed_configs.load(Ordering::SeqCst);
         }
 
         let bars = match symbol_bars.get(symbol) {
@@ -1363,23 +1659,35 @@ fn handle_multi_strategy_sweep(
             });
 
             // Run Polars-native sweep for this strategy/symbol
-            let sweep_result =
-                match run_strategy_sweep_polars_parallel(&df, strategy_config, &polars_config) {
-                    Ok(r) => r,
-                    Err(e) => {
-                        eprintln!(
-                            "Polars sweep failed for {} / {:?}: {}",
-                            symbol, strategy_config.strategy_type, e
-                        );
-                        continue;
-                    }
-                };
+            let sweep_result = match run_strategy_sweep_polars_parallel_with_cancel(
+                &df,
+                strategy_config,
+                &polars_config,
+                cancel_flag,
+            ) {
+                Ok(r) => r,
+                Err(e) => {
+                    eprintln!(
+                        "Polars sweep failed for {} / {:?}: {}",
+                        symbol, strategy_config.strategy_type, e
+                    );
+                    continue;
+                }
+            };
 
             // Update progress
             let num_configs = sweep_result.config_results.len();
             let new_count =
                 completed_configs.fetch_add(num_configs, Ordering::SeqCst) + num_configs;
 
+            // Cancelled mid-sweep: report the partial count and stop
+            if sweep_result.cancelled {
+                let _ = update_tx.send(WorkerUpdate::MultiStrategySweepCancelled {
+                    completed_configs: new_count,
+                });
+                return new_count;
+            }
+
             let _ = update_tx.send(WorkerUpdate::MultiStrategySweepProgress {
                 completed_configs: new_count,
                 total_configs,
@@ -1397,6 +1705,7 @@ fn handle_multi_strategy_sweep(
     result.compute_aggregations();
 
     let _ = update_tx.send(WorkerUpdate::MultiStrategySweepComplete { result });
+    completed_configs.load(Ordering::SeqCst)
 }
 
 /// Handle symbol search operation (async).
@@ -1597,6 +1906,45 @@ fn handle_compute_analysis(
     }
 }
 
+/// Handle bootstrap/permutation statistics for one backtest result.
+///
+/// The permutation p-value comes from a one-sided sign-flip test on the equity
+/// returns (H1: mean return > 0), using the bootstrap iteration count and seed.
+fn handle_compute_statistics(
+    config_id: &str,
+    backtest_result: &BacktestResult,
+    config: &BootstrapConfig,
+    update_tx: &Sender<WorkerUpdate>,
+) {
+    let returns = equity_curve_returns(backtest_result);
+    let outcome = StrategyStatistics::from_returns(&returns, config).and_then(|statistics| {
+        let permutation = sign_flip_test(&returns, config.n_iterations, config.seed)?;
+        Ok((statistics, permutation))
+    });
+
+    let _ = match outcome {
+        Ok((statistics, permutation)) => update_tx.send(WorkerUpdate::StatisticsComplete {
+            config_id: config_id.to_string(),
+            statistics,
+            permutation,
+        }),
+        Err(e) => update_tx.send(WorkerUpdate::StatisticsError {
+            config_id: config_id.to_string(),
+            error: format!("Statistics computation failed: {}", e),
+        }),
+    };
+}
+
+/// Bar-to-bar returns of a backtest's equity curve.
+fn equity_curve_returns(result: &BacktestResult) -> Vec<f64> {
+    result
+        .equity
+        .windows(2)
+        .filter(|w| w[0].equity > 0.0)
+        .map(|w| w[1].equity / w[0].equity - 1.0)
+        .collect()
+}
+
 // =============================================================================
 // Walk-Forward Helpers for YOLO Mode
 // =============================================================================
@@ -1706,6 +2054,114 @@ fn compute_wf_pvalue(wf_results: &[(String, WalkForwardResult)]) -> Option<f64>
     one_sided_mean_pvalue(&oos_sharpes).ok()
 }
 
+/// Why a YOLO config failed walk-forward validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YoloRejectReason {
+    /// Average Sharpe below `wf_sharpe_threshold`, so walk-forward was skipped
+    BelowThreshold,
+    /// Too few trades per symbol for the Sharpe to mean anything
+    TooFewTrades,
+    /// Equity curves too short for the walk-forward folds
+    InsufficientData,
+    /// Mean out-of-sample Sharpe below zero
+    NegativeOos,
+    /// Mean out-of-sample Sharpe positive but below `wf_sharpe_threshold`
+    WeakOos,
+}
+
+impl YoloRejectReason {
+    /// Stable identifier used in logs and telemetry.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::BelowThreshold => "below_threshold",
+            Self::TooFewTrades => "too_few_trades",
+            Self::InsufficientData => "insufficient_data",
+            Self::NegativeOos => "negative_oos",
+            Self::WeakOos => "weak_oos",
+        }
+    }
+}
+
+impl std::fmt::Display for YoloRejectReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Minimum average trades per symbol before YOLO runs walk-forward validation.
+const YOLO_WF_MIN_AVG_TRADES: f64 = 3.0;
+
+/// Walk-forward statistics: (grade, mean OOS Sharpe, std OOS Sharpe,
+/// % profitable folds, Sharpe degradation, OOS p-value).
+type WalkForwardStats = (
+    Option<char>,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+);
+
+/// Run the YOLO walk-forward gate for one config.
+///
+/// Walk-forward only runs for configs whose average Sharpe reaches
+/// `wf_sharpe_threshold` with enough trades behind it. Returns the
+/// walk-forward statistics (all `None` when skipped) and, if the config
+/// did not validate, the reason it was rejected.
+fn yolo_walk_forward_gate(
+    aggregate_metrics: &AggregatedMetrics,
+    per_symbol_equity: &HashMap<String, Vec<f64>>,
+    wf_sharpe_threshold: f64,
+) -> (WalkForwardStats, Option<YoloRejectReason>) {
+    const SKIPPED: WalkForwardStats = (None, None, None, None, None, None);
+
+    if aggregate_metrics.avg_sharpe < wf_sharpe_threshold {
+        return (SKIPPED, Some(YoloRejectReason::BelowThreshold));
+    }
+    if aggregate_metrics.avg_trades < YOLO_WF_MIN_AVG_TRADES {
+        return (SKIPPED, Some(YoloRejectReason::TooFewTrades));
+    }
+
+    let stats = compute_equity_based_wf(per_symbol_equity);
+    let reason = match stats.1 {
+        None => Some(YoloRejectReason::InsufficientData),
+        Some(oos) if oos < 0.0 => Some(YoloRejectReason::NegativeOos),
+        Some(oos) if oos < wf_sharpe_threshold => Some(YoloRejectReason::WeakOos),
+        Some(_) => None,
+    };
+    (stats, reason)
+}
+
+/// Run the walk-forward gate for one YOLO config and, if it was rejected,
+/// send a `YoloConfigRejected` update naming the config and the reason.
+fn report_yolo_walk_forward_gate(
+    strategy_type: StrategyTypeId,
+    config_id: &StrategyConfigId,
+    aggregate_metrics: &AggregatedMetrics,
+    per_symbol_equity: &HashMap<String, Vec<f64>>,
+    wf_sharpe_threshold: f64,
+    update_tx: &Sender<WorkerUpdate>,
+) -> WalkForwardStats {
+    let (stats, rejection) =
+        yolo_walk_forward_gate(aggregate_metrics, per_symbol_equity, wf_sharpe_threshold);
+    if let Some(reason) = rejection {
+        debug!(
+            strategy = ?strategy_type,
+            config = %config_id.display(),
+            reason = %reason,
+            avg_sharpe = aggregate_metrics.avg_sharpe,
+            wf_sharpe = ?stats.1,
+            "YOLO config rejected by walk-forward gate"
+        );
+        let _ = update_tx.send(WorkerUpdate::YoloConfigRejected {
+            config_id: config_id.clone(),
+            reason,
+            wf_sharpe: stats.1,
+        });
+    }
+    stats
+}
+
 /// Compute simplified walk-forward metrics from equity curves.
 ///
 /// This is a fast approximation that doesn't re-run backtests.
@@ -1716,17 +2172,7 @@ fn compute_wf_pvalue(wf_results: &[(String, WalkForwardResult)]) -> Option<f64>
 /// 4. Aggregates across symbols and folds
 ///
 /// Returns (grade, mean_oos_sharpe, std_oos_sharpe, pct_profitable, sharpe_degradation, oos_p_value)
-#[allow(clippy::type_complexity)]
-fn compute_equity_based_wf(
-    per_symbol_equity: &HashMap<String, Vec<f64>>,
-) -> (
-    Option<char>,
-    Option<f64>,
-    Option<f64>,
-    Option<f64>,
-    Option<f64>,
-    Option<f64>,
-) {
+fn compute_equity_based_wf(per_symbol_equity: &HashMap<String, Vec<f64>>) -> WalkForwardStats {
     const MIN_BARS_PER_FOLD: usize = 42; // ~2 months of trading days
     const NUM_FOLDS: usize = 3;
     const IS_RATIO: f64 = 0.85; // In-sample portion of each fold
@@ -1891,6 +2337,7 @@ async fn handle_yolo_mode(
     warmup_iterations: u32,
     combo_warmup_iterations: u32,
     combo_mode: ComboMode,
+    pacer: &FetchPacer,
     update_tx: &Sender<WorkerUpdate>,
     cancel_flag: &Arc<AtomicBool>,
 ) {
@@ -1917,8 +2364,8 @@ async fn handle_yolo_mode(
 
     let parquet_dir = Path::new("data/parquet");
     let artifacts = trendlab_core::artifacts_dir();
-    let per_symbol_path = artifacts.join("leaderboard.json");
-    let cross_symbol_path = artifacts.join("cross_symbol_leaderboard.json");
+    let per_symbol_path = artifacts.join(LEADERBOARD_FILE);
+    let cross_symbol_path = artifacts.join(CROSS_SYMBOL_LEADERBOARD_FILE);
 
     // Initialize or continue leaderboards
     let had_existing_per_symbol = existing_per_symbol_leaderboard.is_some();
@@ -2035,15 +2482,35 @@ async fn handle_yolo_mode(
     const YOLO_MAX_START_LAG_DAYS: i64 = 365; // 1 year after requested start
     const YOLO_MIN_COVERAGE_RATIO: f64 = 0.60; // Require at least 60% of span
 
-    // Session iteration starts at 0; all-time total_iterations loaded from leaderboard
-    let mut session_iteration: u32 = 0;
-    let all_time_iteration_offset = cross_symbol_leaderboard.total_iterations;
-
-    // Create seeded RNG for reproducibility (but different each run)
-    let seed = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_nanos() as u64)
-        .unwrap_or(42);
+    // Session iteration starts at 0 (or where an interrupted run of this session
+    // left off); all-time total_iterations loaded from leaderboard
+    let resumed_iteration = YoloCheckpoint::resumable(&artifacts)
+        .filter(|checkpoint| checkpoint.session_id == session_id_for_history)
+        .map(|checkpoint| checkpoint.session_iteration)
+        .unwrap_or(0);
+    if resumed_iteration > 0 {
+        info!(
+            session_id = %session_id_for_history,
+            iteration = resumed_iteration,
+            "Resuming interrupted YOLO session"
+        );
+    }
+    let mut session_iteration: u32 = resumed_iteration;
+    let all_time_iteration_offset = cross_symbol_leaderboard
+        .total_iterations
+        .saturating_sub(resumed_iteration);
+
+    // Create seeded RNG. A seeded grid reproduces the same session exactly;
+    // otherwise pick a fresh seed so each run explores differently.
+    let seeded = base_grid.seed != 0;
+    let seed = if seeded {
+        base_grid.seed
+    } else {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(42)
+    };
     let mut rng = ChaCha8Rng::seed_from_u64(seed);
 
     let total_symbols = symbols.len();
@@ -2170,40 +2637,23 @@ async fn handle_yolo_mode(
 
             // Fetch from Yahoo
             let url = build_yahoo_chart_url(symbol, start, end);
-
-            match client.get(&url).send().await {
-                Ok(response) => {
-                    if !response.status().is_success() {
-                        info!(symbol = %symbol, status = %response.status(), "Yahoo fetch failed");
+            let (body, retries) =
+                fetch_yahoo_chart_with_retry(&client, &url, symbol, &RetryPolicy::default(), pacer)
+                    .await;
+
+            match body.and_then(|json_text| parse_yahoo_chart_json(&json_text, symbol, "1d")) {
+                Ok(bars) => {
+                    // Write to parquet cache
+                    if let Err(e) = write_partitioned_parquet(&bars, parquet_dir) {
+                        info!(symbol = %symbol, error = %e, "Failed to write parquet");
                         failed += 1;
-                        continue;
-                    }
-
-                    match response.text().await {
-                        Ok(json_text) => match parse_yahoo_chart_json(&json_text, symbol, "1d") {
-                            Ok(bars) => {
-                                // Write to parquet cache
-                                if let Err(e) = write_partitioned_parquet(&bars, parquet_dir) {
-                                    info!(symbol = %symbol, error = %e, "Failed to write parquet");
-                                    failed += 1;
-                                } else {
-                                    info!(symbol = %symbol, bars = bars.len(), "Fetched and cached");
-                                    refreshed += 1;
-                                }
-                            }
-                            Err(e) => {
-                                info!(symbol = %symbol, error = %e, "Failed to parse Yahoo JSON");
-                                failed += 1;
-                            }
-                        },
-                        Err(e) => {
-                            info!(symbol = %symbol, error = %e, "Failed to read response");
-                            failed += 1;
-                        }
+                    } else {
+                        info!(symbol = %symbol, bars = bars.len(), retries = retries, "Fetched and cached");
+                        refreshed += 1;
                     }
                 }
                 Err(e) => {
-                    info!(symbol = %symbol, error = %e, "HTTP request failed");
+                    info!(symbol = %symbol, error = %e, retries = retries, "Yahoo fetch failed");
                     failed += 1;
                 }
             }
@@ -2246,6 +2696,7 @@ async fn handle_yolo_mode(
         PolarsBacktestConfig::new(config.initial_cash, config.qty).with_cost_model(CostModel {
             fees_bps_per_side: config.cost_model.fees_bps_per_side,
             slippage_bps: config.cost_model.slippage_bps,
+            ..Default::default()
         });
 
     // Pre-load all symbol DataFrames to avoid repeated I/O
@@ -2301,6 +2752,8 @@ async fn handle_yolo_mode(
         if cancel_flag.load(Ordering::SeqCst) {
             let _ = per_symbol_leaderboard.save(&per_symbol_path);
             let _ = cross_symbol_leaderboard.save(&cross_symbol_path);
+            // Stopped cleanly, so there is nothing to resume
+            let _ = YoloCheckpoint::mark_finished(&artifacts);
             // Save exploration state on cancellation
             let _ = exploration_state.save(&exploration_path);
             let total_configs = cross_symbol_leaderboard.total_configs_tested;
@@ -2332,11 +2785,15 @@ async fn handle_yolo_mode(
         // High randomization (>=80%) uses shorter interval (10) for maximum variance
         let reseed_interval: u32 = if randomization_pct >= 0.8 { 10 } else { 25 };
         if session_iteration > 1 && session_iteration.is_multiple_of(reseed_interval) {
-            let new_seed = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .map(|d| d.as_nanos() as u64)
-                .unwrap_or(42)
-                ^ (session_iteration as u64 * 0x517cc1b727220a95);
+            let new_seed = if seeded {
+                derive_seed(seed, &session_iteration.to_le_bytes())
+            } else {
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_nanos() as u64)
+                    .unwrap_or(42)
+                    ^ (session_iteration as u64 * 0x517cc1b727220a95)
+            };
             rng = ChaCha8Rng::seed_from_u64(new_seed);
             debug!(
                 session_iteration = session_iteration,
@@ -2702,6 +3159,11 @@ async fn handle_yolo_mode(
                                 cost_model: polars_config.cost_model,
                                 qty: polars_config.qty,
                                 pyramid_config: trendlab_core::PyramidConfig::default(),
+                                stop_loss_pct: None,
+                                take_profit_pct: None,
+                                cash_yield_annual: 0.0,
+                                intrabar: trendlab_core::IntrabarModel::default(),
+                                warmup_bars: None,
                             };
                             if let Some(cfg_result) = trendlab_core::run_single_config_backtest(
                                 &bars,
@@ -2882,11 +3344,14 @@ async fn handle_yolo_mode(
 
             // Walk-forward validation for promising configs
             let (wf_grade, mean_oos, std_oos, pct_profitable, degradation, oos_pval) =
-                if aggregate_metrics.avg_sharpe >= wf_sharpe_threshold {
-                    compute_equity_based_wf(&per_symbol_equity)
-                } else {
-                    (None, None, None, None, None, None)
-                };
+                report_yolo_walk_forward_gate(
+                    *strategy_type,
+                    config_id,
+                    &aggregate_metrics,
+                    &per_symbol_equity,
+                    wf_sharpe_threshold,
+                    update_tx,
+                );
 
             // Compute confidence grade
             let confidence_grade = Some(
@@ -3039,6 +3504,8 @@ async fn handle_yolo_mode(
         if cancel_flag.load(Ordering::SeqCst) {
             let _ = per_symbol_leaderboard.save(&per_symbol_path);
             let _ = cross_symbol_leaderboard.save(&cross_symbol_path);
+            // Stopped cleanly, so there is nothing to resume
+            let _ = YoloCheckpoint::mark_finished(&artifacts);
             // Save exploration state on cancellation
             let _ = exploration_state.save(&exploration_path);
             let total_configs = cross_symbol_leaderboard.total_configs_tested;
@@ -3065,6 +3532,15 @@ async fn handle_yolo_mode(
             let confidence_grade =
                 trendlab_core::compute_confidence_from_equity(&best.equity_curve);
 
+            // Same equity-based walk-forward as the cross-symbol configs, on this
+            // symbol alone, so the entry can qualify for the walk-forward scope
+            let symbol_equity = HashMap::from([(
+                best.symbol.clone().unwrap_or_default(),
+                best.equity_curve.clone(),
+            )]);
+            let (wf_grade, mean_oos, _, pct_profitable, degradation, oos_pval) =
+                compute_equity_based_wf(&symbol_equity);
+
             let entry = LeaderboardEntry {
                 rank: 0,
                 strategy_type: best.strategy_type,
@@ -3076,18 +3552,17 @@ async fn handle_yolo_mode(
                     .and_then(|s| symbol_sector_ids.get(s).cloned()),
                 metrics: best.metrics.clone(),
                 equity_curve: best.equity_curve.clone(),
-                dates: vec![], // Could extract from backtest result if needed
+                dates: best.dates.clone(),
                 discovered_at: Utc::now(),
                 iteration: all_time_iteration,
                 session_iteration: Some(session_iteration),
                 session_id: session_id.clone(),
                 confidence_grade,
-                // Walk-forward fields (per-symbol, not used in primary YOLO flow)
-                walk_forward_grade: None,
-                mean_oos_sharpe: None,
-                sharpe_degradation: None,
-                pct_profitable_folds: None,
-                oos_p_value: None,
+                walk_forward_grade: wf_grade,
+                mean_oos_sharpe: mean_oos,
+                sharpe_degradation: degradation,
+                pct_profitable_folds: pct_profitable,
+                oos_p_value: oos_pval,
                 fdr_adjusted_p_value: None,
             };
             per_symbol_leaderboard.try_insert(entry);
@@ -3108,9 +3583,16 @@ async fn handle_yolo_mode(
             "Applied FDR correction"
         );
 
-        // 6. Persist leaderboards (every iteration for crash safety)
-        let _ = per_symbol_leaderboard.save(&per_symbol_path);
-        let _ = cross_symbol_leaderboard.save(&cross_symbol_path);
+        // 6. Snapshot leaderboards atomically (every iteration for crash safety)
+        if let Err(e) = YoloCheckpoint::snapshot(
+            &artifacts,
+            &session_id_for_history,
+            session_iteration,
+            &per_symbol_leaderboard,
+            &cross_symbol_leaderboard,
+        ) {
+            info!(error = %e, "Failed to snapshot YOLO leaderboards");
+        }
 
         // 6b. Save exploration state periodically (every 5 iterations to reduce I/O)
         if session_iteration.is_multiple_of(5) {
@@ -3137,6 +3619,7 @@ async fn handle_yolo_mode(
             let cost_model = CostModel {
                 fees_bps_per_side: config.cost_model.fees_bps_per_side,
                 slippage_bps: config.cost_model.slippage_bps,
+                ..Default::default()
             };
             match auto_export_aggregate_artifact(best, &sid, parquet_dir, start, end, cost_model) {
                 Ok(path) => {
@@ -3257,6 +3740,7 @@ fn generate_config_ids_for_strategy(strategy_config: &StrategyGridConfig) -> Vec
         }
         StrategyParams::TurtleS1 => vec![StrategyConfigId::TurtleS1],
         StrategyParams::TurtleS2 => vec![StrategyConfigId::TurtleS2],
+        StrategyParams::BuyHold => vec![StrategyConfigId::BuyHold],
         StrategyParams::MACrossover {
             fast_periods,
             slow_periods,
@@ -3467,11 +3951,11 @@ fn generate_config_ids_for_strategy(strategy_config: &StrategyGridConfig) -> Vec
             let mut configs = Vec::new();
             for &base in base_strategies {
                 for horizons in horizon_sets {
-                    for &voting in voting_methods {
+                    for voting in voting_methods {
                         configs.push(StrategyConfigId::Ensemble {
                             base_strategy: base,
                             horizons: horizons.clone(),
-                            voting,
+                            voting: voting.clone(),
                         });
                     }
                 }
@@ -3498,6 +3982,7 @@ fn create_single_config_grid(
         },
         StrategyConfigId::TurtleS1 => StrategyParams::TurtleS1,
         StrategyConfigId::TurtleS2 => StrategyParams::TurtleS2,
+        StrategyConfigId::BuyHold => StrategyParams::BuyHold,
         StrategyConfigId::MACrossover {
             fast,
             slow,
@@ -3602,7 +4087,7 @@ fn create_single_config_grid(
         } => StrategyParams::Ensemble {
             base_strategies: vec![*base_strategy],
             horizon_sets: vec![horizons.clone()],
-            voting_methods: vec![*voting],
+            voting_methods: vec![voting.clone()],
         },
         // Phase 5 oscillator strategies not yet supported in YOLO mode
         _ => panic!("Strategy config ID not yet supported in YOLO mode"),
@@ -3629,6 +4114,7 @@ fn extract_strategy_config_id(
         },
         StrategyTypeId::TurtleS1 => StrategyConfigId::TurtleS1,
         StrategyTypeId::TurtleS2 => StrategyConfigId::TurtleS2,
+        StrategyTypeId::BuyHold => StrategyConfigId::BuyHold,
         // For other strategies, we use the entry_lookback as the primary param
         // This is a simplification - in practice each strategy has its own config
         StrategyTypeId::MACrossover => StrategyConfigId::MACrossover {
@@ -3799,7 +4285,9 @@ fn config_id_to_single_params(config: &StrategyConfigId) -> Option<StrategyParam
             squeeze_thresholds: vec![*squeeze_threshold],
         },
         // Fixed strategies can't be exploited (no params to jitter)
-        StrategyConfigId::TurtleS1 | StrategyConfigId::TurtleS2 => return None,
+        StrategyConfigId::TurtleS1 | StrategyConfigId::TurtleS2 | StrategyConfigId::BuyHold => {
+            return None
+        }
         // For other variants, return None (can be extended later)
         _ => return None,
     })
@@ -3836,7 +4324,10 @@ fn create_winner_centered_grid(
         })
         .collect();
 
-    Some(MultiStrategyGrid { strategies })
+    Some(MultiStrategyGrid {
+        strategies,
+        seed: base_grid.seed,
+    })
 }
 
 // =============================================================================
@@ -3973,9 +4464,10 @@ pub fn generate_random_combos(
         components.sort_by_key(|(t, _)| t.id());
 
         // Pick a random voting method
-        let voting = *voting_methods
+        let voting = voting_methods
             .choose(rng)
-            .unwrap_or(&VotingMethod::Majority);
+            .cloned()
+            .unwrap_or(VotingMethod::Majority);
 
         combos.push(StrategyConfigId::Combo { components, voting });
     }
@@ -4001,22 +4493,42 @@ fn jitter_multi_strategy_grid(
 /// Jitter all parameters in a MultiStrategyGrid by a percentage with optional non-local jumps.
 /// jump_prob: probability that any given parameter will jump to a random value within bounds
 /// instead of being locally jittered (15% = 0.15 is the recommended default for exploration)
+///
+/// Draws one seed from `rng`, then jitters each strategy with its own RNG derived from
+/// (seed, strategy type), so a strategy's jitter doesn't shift when others are added,
+/// removed, or reordered.
 fn jitter_multi_strategy_grid_with_jump(
     base: &MultiStrategyGrid,
     pct: f64,
     jump_prob: f64,
     rng: &mut impl Rng,
 ) -> MultiStrategyGrid {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    let iteration_seed: u64 = rng.gen();
     MultiStrategyGrid {
         strategies: base
             .strategies
             .iter()
-            .map(|config| StrategyGridConfig {
-                strategy_type: config.strategy_type,
-                enabled: config.enabled,
-                params: jitter_strategy_params_with_jump(&config.params, pct, jump_prob, rng),
+            .map(|config| {
+                let mut strategy_rng = ChaCha8Rng::seed_from_u64(derive_seed(
+                    iteration_seed,
+                    config.strategy_type.id().as_bytes(),
+                ));
+                StrategyGridConfig {
+                    strategy_type: config.strategy_type,
+                    enabled: config.enabled,
+                    params: jitter_strategy_params_with_jump(
+                        &config.params,
+                        pct,
+                        jump_prob,
+                        &mut strategy_rng,
+                    ),
+                }
             })
             .collect(),
+        seed: base.seed,
     }
 }
 
@@ -4246,6 +4758,7 @@ fn jitter_strategy_params_with_jump(
 
         StrategyParams::TurtleS1 => StrategyParams::TurtleS1,
         StrategyParams::TurtleS2 => StrategyParams::TurtleS2,
+        StrategyParams::BuyHold => StrategyParams::BuyHold,
 
         StrategyParams::MACrossover {
             fast_periods,
@@ -4770,6 +5283,7 @@ fn auto_export_aggregate_artifact(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::sine_wave_bars;
 
     #[test]
     fn test_parse_search_response_basic() {
@@ -4805,4 +5319,329 @@ mod tests {
         assert_eq!(extract_json_string(json, "name"), Some("Test".to_string()));
         assert_eq!(extract_json_string(json, "missing"), None);
     }
+
+    /// 301-point equity curve (three 100-day walk-forward folds). The last 15
+    /// days of each fold are the OOS window; `oos_profitable` flips their sign.
+    fn walk_forward_equity(oos_profitable: bool) -> Vec<f64> {
+        let mut equity = vec![100_000.0];
+        for i in 0..300 {
+            let flip = i % 100 >= 85 && !oos_profitable;
+            let r = if i % 2 == 0 { 0.01 } else { -0.002 };
+            let r = if flip { -r } else { r };
+            let last = *equity.last().unwrap();
+            equity.push(last * (1.0 + r));
+        }
+        equity
+    }
+
+    #[test]
+    fn test_yolo_walk_forward_gate_rejection_reasons() {
+        let threshold = 0.25;
+        let promising = AggregatedMetrics {
+            avg_sharpe: 1.0,
+            avg_trades: 12.0,
+            ..Default::default()
+        };
+        let equity_for = |curve: Vec<f64>| -> HashMap<String, Vec<f64>> {
+            [("SPY".to_string(), curve)].into_iter().collect()
+        };
+
+        // Weak in-sample Sharpe never reaches walk-forward
+        let weak = AggregatedMetrics {
+            avg_sharpe: 0.1,
+            ..promising.clone()
+        };
+        let (stats, reason) =
+            yolo_walk_forward_gate(&weak, &equity_for(walk_forward_equity(true)), threshold);
+        assert_eq!(reason, Some(YoloRejectReason::BelowThreshold));
+        assert_eq!(stats.1, None);
+
+        let sparse = AggregatedMetrics {
+            avg_trades: 1.0,
+            ..promising.clone()
+        };
+        let (_, reason) =
+            yolo_walk_forward_gate(&sparse, &equity_for(walk_forward_equity(true)), threshold);
+        assert_eq!(reason, Some(YoloRejectReason::TooFewTrades));
+
+        let (_, reason) =
+            yolo_walk_forward_gate(&promising, &equity_for(vec![100_000.0; 50]), threshold);
+        assert_eq!(reason, Some(YoloRejectReason::InsufficientData));
+
+        let (stats, reason) = yolo_walk_forward_gate(
+            &promising,
+            &equity_for(walk_forward_equity(false)),
+            threshold,
+        );
+        assert_eq!(reason, Some(YoloRejectReason::NegativeOos));
+        assert!(stats.1.unwrap() < 0.0);
+        assert_eq!(reason.unwrap().as_str(), "negative_oos");
+
+        // Validated configs carry walk-forward stats and no rejection
+        let (stats, reason) = yolo_walk_forward_gate(
+            &promising,
+            &equity_for(walk_forward_equity(true)),
+            threshold,
+        );
+        assert_eq!(reason, None);
+        assert!(stats.1.unwrap() >= threshold);
+    }
+
+    #[test]
+    fn test_yolo_iteration_reports_a_reason_for_every_rejected_config() {
+        let grid = StrategyGridConfig {
+            strategy_type: StrategyTypeId::Donchian,
+            enabled: true,
+            params: StrategyParams::Donchian {
+                entry_lookbacks: vec![10, 20],
+                exit_lookbacks: vec![5, 10],
+            },
+        };
+        let polars_config = PolarsBacktestConfig::new(100_000.0, 100.0);
+
+        // Sweep each symbol, then accumulate per-config results as an iteration does
+        #[allow(clippy::type_complexity)]
+        let mut per_config: HashMap<
+            StrategyConfigId,
+            (HashMap<String, Metrics>, HashMap<String, Vec<f64>>),
+        > = HashMap::new();
+        for (symbol, drift) in [("AAA", 0.05), ("BBB", -0.05)] {
+            let bars = sine_wave_bars(400, 0.05, 8.0, drift, symbol);
+            let df = bars_to_dataframe(&bars).unwrap();
+            let sweep = run_strategy_sweep_polars_cached(&df, &grid, &polars_config).unwrap();
+            for cfg in sweep.config_results {
+                let id = extract_strategy_config_id(grid.strategy_type, &cfg.config_id);
+                let equity = cfg
+                    .backtest_result
+                    .equity
+                    .iter()
+                    .map(|e| e.equity)
+                    .collect();
+                let entry = per_config.entry(id).or_default();
+                entry.0.insert(symbol.to_string(), cfg.metrics);
+                entry.1.insert(symbol.to_string(), equity);
+            }
+        }
+        assert!(!per_config.is_empty());
+
+        // An unreachable threshold rejects every config
+        let (tx, rx) = std::sync::mpsc::channel();
+        for (config_id, (metrics, equity)) in &per_config {
+            let aggregate = AggregatedMetrics::from_per_symbol_with_tail_risk(metrics, equity);
+            report_yolo_walk_forward_gate(
+                grid.strategy_type,
+                config_id,
+                &aggregate,
+                equity,
+                100.0,
+                &tx,
+            );
+        }
+        drop(tx);
+
+        let rejected: Vec<_> = rx
+            .try_iter()
+            .filter_map(|update| match update {
+                WorkerUpdate::YoloConfigRejected {
+                    config_id,
+                    reason,
+                    wf_sharpe,
+                } => Some((config_id, reason, wf_sharpe)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(rejected.len(), per_config.len());
+        for (config_id, reason, wf_sharpe) in &rejected {
+            assert!(per_config.contains_key(config_id));
+            assert!(matches!(
+                reason,
+                YoloRejectReason::BelowThreshold | YoloRejectReason::TooFewTrades
+            ));
+            assert!(!reason.as_str().is_empty());
+            // Skipped configs never reached walk-forward
+            assert_eq!(*wf_sharpe, None);
+        }
+    }
+
+    #[test]
+    fn test_worker_emits_sweep_timings() {
+        let bars = sine_wave_bars(60, 0.3, 10.0, 0.0, "TEST");
+        let bars = Arc::new(bars);
+        let grid = SweepGrid::new(vec![10, 20], vec![5]);
+
+        let (channels, handle) = spawn_worker();
+        for _ in 0..2 {
+            channels
+                .command_tx
+                .send(WorkerCommand::StartSweep {
+                    bars: bars.clone(),
+                    grid: grid.clone(),
+                    backtest_config: BacktestConfig::default(),
+                    use_polars: false,
+                })
+                .unwrap();
+        }
+        channels.command_tx.send(WorkerCommand::Shutdown).unwrap();
+        handle.join().unwrap();
+
+        let mut timings = Vec::new();
+        let mut summaries = Vec::new();
+        for update in channels.update_rx.try_iter() {
+            match update {
+                WorkerUpdate::Timing { command, millis } => timings.push((command, millis)),
+                WorkerUpdate::TimingSummary { summary } => summaries.push(summary),
+                _ => {}
+            }
+        }
+
+        assert_eq!(timings.len(), 2);
+        assert!(timings.iter().all(|&(cmd, ms)| cmd == "sweep" && ms > 0.0));
+
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].commands, 1);
+        assert_eq!(summaries[1].commands, 2);
+        assert_eq!(summaries[1].sweep_configs, 4);
+        assert!(summaries[1].sweep_millis > summaries[0].sweep_millis);
+        assert!(summaries[1].configs_per_sec() > 0.0);
+    }
+
+    #[test]
+    fn test_cancelled_sweep_counts_only_completed_configs() {
+        let bars = sine_wave_bars(60, 0.3, 10.0, 0.0, "TEST");
+        let grid = SweepGrid::new(vec![10, 20], vec![5]);
+        let (tx, rx) = std::sync::mpsc::channel();
+        let cancelled = Arc::new(AtomicBool::new(true));
+
+        for use_polars in [false, true] {
+            let completed = if use_polars {
+                handle_sweep_polars(&bars, &grid, BacktestConfig::default(), &tx, &cancelled)
+            } else {
+                handle_sweep(&bars, &grid, BacktestConfig::default(), &tx, &cancelled)
+            };
+            assert_eq!(completed, 0);
+        }
+        assert!(rx
+            .try_iter()
+            .any(|u| matches!(u, WorkerUpdate::SweepCancelled { completed: 0 })));
+
+        let mut timings = TimingSummary::default();
+        timings.record(500.0, Some(0));
+        timings.record(500.0, Some(4));
+        assert_eq!(timings.sweep_configs, 4);
+        assert_eq!(timings.configs_per_sec(), 4.0);
+    }
+
+    #[test]
+    fn test_sweep_partial_batches_sum_to_results() {
+        let bars = sine_wave_bars(80, 0.2, 10.0, 0.0, "TEST");
+        let bars = Arc::new(bars);
+        // 24 configs => batches of 2
+        let grid = SweepGrid::new(vec![10, 15, 20, 25, 30, 35], vec![5, 8, 10, 12]);
+
+        for use_polars in [false, true] {
+            let (channels, handle) = spawn_worker();
+            channels
+                .command_tx
+                .send(WorkerCommand::StartSweep {
+                    bars: bars.clone(),
+                    grid: grid.clone(),
+                    backtest_config: BacktestConfig::default(),
+                    use_polars,
+                })
+                .unwrap();
+            channels.command_tx.send(WorkerCommand::Shutdown).unwrap();
+            handle.join().unwrap();
+
+            let mut batch_sizes = Vec::new();
+            let mut final_count = None;
+            for update in channels.update_rx.try_iter() {
+                match update {
+                    WorkerUpdate::SweepPartialResults { batch } => {
+                        assert!(final_count.is_none(), "partial batch after completion");
+                        batch_sizes.push(batch.len());
+                    }
+                    WorkerUpdate::SweepComplete { result } => {
+                        final_count = Some(result.config_results.len())
+                    }
+                    _ => {}
+                }
+            }
+
+            let final_count = final_count.expect("sweep should complete");
+            assert_eq!(final_count, 24);
+            assert!(batch_sizes.len() > 1);
+            assert!(batch_sizes.iter().all(|&n| n <= sweep_batch_size(24)));
+            assert_eq!(batch_sizes.iter().sum::<usize>(), final_count);
+        }
+    }
+
+    #[test]
+    fn test_statistics_grade_matches_direct_call() {
+        let bars = sine_wave_bars(300, 0.15, 5.0, 0.2, "TEST");
+        let mut strategy = trendlab_core::DonchianBreakoutStrategy::new(20, 10);
+        let backtest_result =
+            trendlab_core::run_backtest(&bars, &mut strategy, BacktestConfig::default()).unwrap();
+        let config = BootstrapConfig::quick();
+
+        let (channels, handle) = spawn_worker();
+        channels
+            .command_tx
+            .send(WorkerCommand::ComputeStatistics {
+                config_id: "donchian_20_10".to_string(),
+                backtest_result: backtest_result.clone(),
+                config: config.clone(),
+            })
+            .unwrap();
+        channels.command_tx.send(WorkerCommand::Shutdown).unwrap();
+        handle.join().unwrap();
+
+        let (statistics, permutation) = channels
+            .update_rx
+            .try_iter()
+            .find_map(|update| match update {
+                WorkerUpdate::StatisticsComplete {
+                    config_id,
+                    statistics,
+                    permutation,
+                } => {
+                    assert_eq!(config_id, "donchian_20_10");
+                    Some((statistics, permutation))
+                }
+                _ => None,
+            })
+            .expect("statistics should complete");
+
+        let direct =
+            StrategyStatistics::from_returns(&equity_curve_returns(&backtest_result), &config)
+                .unwrap();
+        assert_eq!(statistics.confidence_grade, direct.confidence_grade);
+        assert_eq!(statistics.sharpe_ci.ci_lower, direct.sharpe_ci.ci_lower);
+        assert_eq!(statistics.return_stats.n, bars.len() - 1);
+        assert!(permutation.p_value > 0.0 && permutation.p_value <= 1.0);
+    }
+
+    #[test]
+    fn test_sweep_eta_converges() {
+        // 200 configs at 0.5s each, with the first 10 slowed by warmup
+        let total = 200;
+        let eta = SweepEta::new(total);
+        let mut elapsed = 0.0;
+        let mut errors = Vec::new();
+
+        for completed in 1..=total {
+            elapsed += if completed <= 10 { 2.0 } else { 0.5 };
+            let actual_remaining = (total - completed) as f64 * 0.5;
+            match eta.eta_at(completed, elapsed) {
+                None => assert!(completed < ETA_MIN_COMPLETED),
+                Some(secs) => errors.push((secs - actual_remaining).abs()),
+            }
+        }
+
+        assert_eq!(eta.eta_at(total, elapsed), Some(0.0));
+        // Error shrinks as warmup samples leave the window, then vanishes
+        assert!(errors.windows(2).skip(10).all(|w| w[1] <= w[0] + 1e-9));
+        assert!(errors[10] > 1.0);
+        let settled = 10 + ETA_WINDOW - ETA_MIN_COMPLETED + 1;
+        assert!(errors[settled..].iter().all(|&e| e < 1e-6));
+    }
 }
@@ -5,6 +5,7 @@
 //! - File-based logging with daily rotation
 //! - IPC-based log forwarding (for GUI → companion terminal)
 //! - Environment-based configuration
+//! - Human-readable or JSON-structured log files
 //!
 //! # Usage
 //!
@@ -22,14 +23,46 @@ mod ipc_layer;
 pub use ipc_layer::{IpcLayer, LogEvent, LogLevel};
 
 use std::path::PathBuf;
-use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use std::str::FromStr;
+use tracing_appender::{
+    non_blocking::NonBlocking,
+    rolling::{RollingFileAppender, Rotation},
+};
 use tracing_subscriber::{
     fmt::{self, format::FmtSpan},
     layer::SubscriberExt,
     util::SubscriberInitExt,
-    EnvFilter, Layer,
+    EnvFilter, Layer, Registry,
 };
 
+/// Output format for file logging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Human-readable lines with full span context (default).
+    #[default]
+    Pretty,
+    /// Abbreviated human-readable lines.
+    Compact,
+    /// One JSON object per line, for log aggregators.
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "pretty" => Ok(Self::Pretty),
+            "compact" => Ok(Self::Compact),
+            "json" => Ok(Self::Json),
+            other => Err(format!(
+                "unknown log format '{}' (expected pretty, compact or json)",
+                other
+            )),
+        }
+    }
+}
+
 /// Configuration for TrendLab logging.
 #[derive(Debug, Clone)]
 pub struct LogConfig {
@@ -41,6 +74,8 @@ pub struct LogConfig {
     pub log_dir: PathBuf,
     /// Enable daily log rotation.
     pub rotate_daily: bool,
+    /// Formatter used for the log file.
+    pub format: LogFormat,
 }
 
 impl Default for LogConfig {
@@ -56,6 +91,7 @@ impl Default for LogConfig {
             filter: "info,trendlab=debug,polars=warn".to_string(),
             log_dir,
             rotate_daily: true,
+            format: LogFormat::default(),
         }
     }
 }
@@ -76,6 +112,7 @@ impl LogConfig {
     /// - `TRENDLAB_LOG_ENABLED`: Set to "1" to enable logging
     /// - `TRENDLAB_LOG_FILTER`: Log filter string (default: "info,trendlab=debug")
     /// - `TRENDLAB_LOG_DIR`: Log directory (default: "data/logs")
+    /// - `TRENDLAB_LOG_FORMAT`: "pretty", "compact" or "json" (default: "pretty")
     pub fn from_env() -> Self {
         let enabled = std::env::var("TRENDLAB_LOG_ENABLED")
             .map(|v| v == "1" || v.to_lowercase() == "true")
@@ -103,11 +140,17 @@ impl LogConfig {
                 .join(&log_dir)
         };
 
+        let format = std::env::var("TRENDLAB_LOG_FORMAT")
+            .ok()
+            .and_then(|v| v.parse().map_err(|e| eprintln!("Warning: {}", e)).ok())
+            .unwrap_or_default();
+
         Self {
             enabled,
             filter,
             log_dir,
             rotate_daily: true,
+            format,
         }
     }
 
@@ -178,6 +221,25 @@ fn create_filter(config: &LogConfig) -> EnvFilter {
     })
 }
 
+/// Create the file output layer using the config's formatter.
+fn create_file_layer(
+    config: &LogConfig,
+    writer: NonBlocking,
+) -> Box<dyn Layer<Registry> + Send + Sync> {
+    let layer = fmt::layer()
+        .with_target(true)
+        .with_level(true)
+        .with_ansi(false)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(writer);
+
+    match config.format {
+        LogFormat::Pretty => layer.boxed(),
+        LogFormat::Compact => layer.compact().boxed(),
+        LogFormat::Json => layer.json().boxed(),
+    }
+}
+
 /// Initialize logging for the launcher (stderr output).
 ///
 /// This is used by the launcher before it spawns TUI or GUI.
@@ -221,13 +283,7 @@ pub fn init_tui_logging(config: &LogConfig) -> Option<LogGuard> {
     let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
 
     // TUI logs to file only with full details
-    let file_layer = fmt::layer()
-        .with_target(true)
-        .with_level(true)
-        .with_ansi(false)
-        .with_span_events(FmtSpan::CLOSE)
-        .with_writer(non_blocking)
-        .with_filter(filter);
+    let file_layer = create_file_layer(config, non_blocking).with_filter(filter);
 
     tracing_subscriber::registry().with(file_layer).init();
 
@@ -258,14 +314,8 @@ pub fn init_gui_logging(
     let file_appender = create_file_appender(config);
     let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
 
-    // File layer with full details
-    let file_layer = fmt::layer()
-        .with_target(true)
-        .with_level(true)
-        .with_ansi(false)
-        .with_span_events(FmtSpan::CLOSE)
-        .with_writer(non_blocking)
-        .with_filter(filter.clone());
+    // File layer with full details; IPC events keep their own format
+    let file_layer = create_file_layer(config, non_blocking).with_filter(filter.clone());
 
     if let Some(sender) = ipc_sender {
         // Dual output: file + IPC
@@ -337,4 +387,16 @@ mod tests {
         std::env::remove_var("TRENDLAB_LOG_ENABLED");
         std::env::remove_var("TRENDLAB_LOG_FILTER");
     }
+
+    #[test]
+    fn test_log_format_from_env() {
+        std::env::set_var("TRENDLAB_LOG_FORMAT", "json");
+        assert_eq!(LogConfig::from_env().format, LogFormat::Json);
+
+        std::env::set_var("TRENDLAB_LOG_FORMAT", "bogus");
+        assert_eq!(LogConfig::from_env().format, LogFormat::Pretty);
+
+        std::env::remove_var("TRENDLAB_LOG_FORMAT");
+        assert_eq!(LogConfig::from_env().format, LogFormat::Pretty);
+    }
 }
@@ -60,8 +60,20 @@ fn main() -> Result<()> {
     // Spawn background worker thread
     let (channels, worker_handle) = spawn_worker();
 
-    // Create app and run
+    // Create app, restoring the previous session if one was saved
     let mut app = App::new();
+    let session_path = session_path();
+    if session_path.exists() {
+        if let Err(e) = app.load_session(&session_path) {
+            tracing::warn!(error = %e, path = ?session_path, "Failed to load session, using defaults");
+        }
+    }
+    if let Some(ref checkpoint) = app.yolo.resume_from {
+        app.status_message = format!(
+            "Interrupted YOLO session {} found (iteration {}). Press y to resume it.",
+            checkpoint.session_id, checkpoint.session_iteration
+        );
+    }
     let res = run_app(&mut terminal, &mut app, &channels);
 
     // Cleanup: signal worker to shutdown
@@ -97,29 +109,27 @@ fn run_app<B: ratatui::backend::Backend>(
         // 2. Poll for input (non-blocking, 16ms timeout for ~60fps)
         if poll(Duration::from_millis(16))? {
             match event::read()? {
-                Event::Key(key) => {
-                    if key.kind == KeyEventKind::Press {
-                        // Handle Ctrl+d/u for Help panel page navigation
-                        if app.active_panel == Panel::Help
-                            && key.modifiers.contains(KeyModifiers::CONTROL)
-                        {
-                            match key.code {
-                                KeyCode::Char('d') => {
-                                    app.help_page_down();
-                                    continue;
-                                }
-                                KeyCode::Char('u') => {
-                                    app.help_page_up();
-                                    continue;
-                                }
-                                _ => {}
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    // Handle Ctrl+d/u for Help panel page navigation
+                    if app.active_panel == Panel::Help
+                        && key.modifiers.contains(KeyModifiers::CONTROL)
+                    {
+                        match key.code {
+                            KeyCode::Char('d') => {
+                                app.help_page_down();
+                                continue;
+                            }
+                            KeyCode::Char('u') => {
+                                app.help_page_up();
+                                continue;
                             }
+                            _ => {}
                         }
+                    }
 
-                        match handle_key(app, key.code, channels) {
-                            KeyResult::Quit => return Ok(()),
-                            KeyResult::Continue => {}
-                        }
+                    match handle_key(app, key.code, channels) {
+                        KeyResult::Quit => return Ok(()),
+                        KeyResult::Continue => {}
                     }
                 }
                 Event::Mouse(mouse) => {
@@ -127,17 +137,13 @@ fn run_app<B: ratatui::backend::Backend>(
                         MouseEventKind::Moved => {
                             app.update_cursor_position(mouse.column, mouse.row);
                         }
-                        MouseEventKind::ScrollUp => {
-                            // Zoom in on chart when in Chart panel
-                            if app.active_panel == Panel::Chart {
-                                app.chart.zoom_in_animated();
-                            }
+                        // Zoom in on chart when in Chart panel
+                        MouseEventKind::ScrollUp if app.active_panel == Panel::Chart => {
+                            app.chart.zoom_in_animated();
                         }
-                        MouseEventKind::ScrollDown => {
-                            // Zoom out on chart when in Chart panel
-                            if app.active_panel == Panel::Chart {
-                                app.chart.zoom_out_animated();
-                            }
+                        // Zoom out on chart when in Chart panel
+                        MouseEventKind::ScrollDown if app.active_panel == Panel::Chart => {
+                            app.chart.zoom_out_animated();
                         }
                         _ => {}
                     }
@@ -174,6 +180,11 @@ fn backup_file(path: &std::path::Path) {
     }
 }
 
+/// Location of the saved TUI session (selections, parameters, date range).
+fn session_path() -> std::path::PathBuf {
+    trendlab_core::artifacts_dir().join("session.json")
+}
+
 /// Save all-time leaderboards to disk before exiting.
 /// This ensures leaderboard data persists across TUI sessions.
 fn save_leaderboards_on_exit(yolo: &trendlab_engine::app::YoloState) {
@@ -386,7 +397,7 @@ fn handle_key(app: &mut App, code: KeyCode, channels: &WorkerChannels) -> KeyRes
         }
 
         KeyCode::Char('t') => {
-            // 't' for toggle leaderboard scope (Session vs All-Time) in Results panel
+            // 't' cycles leaderboard scope (Session / All-Time / Walk-Forward) in Results panel
             if app.active_panel == Panel::Results {
                 app.yolo.toggle_scope();
             }
@@ -419,6 +430,24 @@ fn handle_key(app: &mut App, code: KeyCode, channels: &WorkerChannels) -> KeyRes
             KeyResult::Continue
         }
 
+        KeyCode::Char('S') => {
+            // Save selections and parameters so the next launch restores them
+            let path = session_path();
+            match app.save_session(&path) {
+                Ok(()) => app.set_status_success(format!("Session saved to {}", path.display())),
+                Err(e) => app.set_status_error(format!("Failed to save session: {}", e)),
+            }
+            KeyResult::Continue
+        }
+
+        KeyCode::Char('x') => {
+            // 'x' to export the current chart view to CSV
+            if app.active_panel == Panel::Chart {
+                app.handle_chart_export();
+            }
+            KeyResult::Continue
+        }
+
         KeyCode::Char('c') => {
             // 'c' for toggle crosshair (in chart panel)
             if app.active_panel == Panel::Chart {
@@ -748,6 +777,14 @@ fn handle_yolo_config_key(app: &mut App, code: KeyCode, channels: &WorkerChannel
             KeyResult::Continue
         }
 
+        KeyCode::Char('n') | KeyCode::Char('N') => {
+            // Discard the interrupted session and start fresh
+            if app.yolo.resume_from.take().is_some() {
+                app.status_message = "YOLO will start a new session.".to_string();
+            }
+            KeyResult::Continue
+        }
+
         KeyCode::Tab | KeyCode::Down | KeyCode::Char('j') => {
             // Move to next field
             app.yolo.config.focused_field = app.yolo.config.focused_field.next();
@@ -928,6 +965,18 @@ fn apply_update(app: &mut App, update: WorkerUpdate, channels: &WorkerChannels)
             // Worker finished an operation
         }
 
+        // Timing updates
+        WorkerUpdate::Timing { .. } => {}
+
+        WorkerUpdate::TimingSummary { summary } => {
+            // Sweep completion already set the status; append lifetime throughput
+            app.status_message = format!(
+                "{} ({:.0} configs/sec)",
+                app.status_message,
+                summary.configs_per_sec()
+            );
+        }
+
         // Search updates
         WorkerUpdate::SearchResults { query, results } => {
             // Only apply if query matches current search input
@@ -1087,11 +1136,21 @@ fn apply_update(app: &mut App, update: WorkerUpdate, channels: &WorkerChannels)
             };
         }
 
-        WorkerUpdate::SweepProgress { completed, total } => {
+        WorkerUpdate::SweepProgress {
+            completed,
+            total,
+            eta_seconds,
+        } => {
             app.sweep.completed_configs = completed;
             app.sweep.total_configs = total;
             app.sweep.progress = completed as f64 / total as f64;
-            app.status_message = format!("Sweep: {}/{} configs", completed, total);
+            app.status_message = match eta_seconds {
+                Some(eta) => format!(
+                    "Sweep: {}/{} configs (~{:.0}s remaining)",
+                    completed, total, eta
+                ),
+                None => format!("Sweep: {}/{} configs", completed, total),
+            };
 
             if let OperationState::RunningSweep {
                 completed: ref mut c,
@@ -1103,6 +1162,9 @@ fn apply_update(app: &mut App, update: WorkerUpdate, channels: &WorkerChannels)
             }
         }
 
+        // Results are taken from SweepComplete
+        WorkerUpdate::SweepPartialResults { .. } => {}
+
         WorkerUpdate::SweepComplete { result } => {
             let count = result.config_results.len();
             app.set_status_success(format!("Sweep complete: {} configs", count));
@@ -1354,6 +1416,25 @@ fn apply_update(app: &mut App, update: WorkerUpdate, channels: &WorkerChannels)
             app.status_message = format!("Analysis failed for {}: {}", analysis_id, error);
         }
 
+        WorkerUpdate::StatisticsComplete {
+            config_id,
+            statistics,
+            permutation,
+        } => {
+            app.status_message = format!(
+                "Statistics for {}: Sharpe CI [{:.2}, {:.2}], p={:.3}, grade {}",
+                config_id,
+                statistics.sharpe_ci.ci_lower,
+                statistics.sharpe_ci.ci_upper,
+                permutation.p_value,
+                statistics.confidence_grade.as_str()
+            );
+        }
+
+        WorkerUpdate::StatisticsError { config_id, error } => {
+            app.status_message = format!("Statistics failed for {}: {}", config_id, error);
+        }
+
         // YOLO Mode updates
         WorkerUpdate::YoloModeStarted {
             total_symbols,
@@ -1481,6 +1562,8 @@ fn apply_update(app: &mut App, update: WorkerUpdate, channels: &WorkerChannels)
                         max_consecutive_losses: 0,
                         max_consecutive_wins: 0,
                         avg_losing_streak: 0.0,
+                        ulcer_index: 0.0,
+                        upi: 0.0,
                     },
                 });
             }
@@ -1508,6 +1591,20 @@ fn apply_update(app: &mut App, update: WorkerUpdate, channels: &WorkerChannels)
             }
         }
 
+        WorkerUpdate::YoloConfigRejected {
+            config_id,
+            reason,
+            wf_sharpe,
+        } => {
+            debug!(
+                config = %config_id.display(),
+                reason = %reason,
+                wf_sharpe = ?wf_sharpe,
+                "YOLO config rejected"
+            );
+            app.yolo.record_rejection(reason);
+        }
+
         WorkerUpdate::YoloProgress {
             iteration,
             phase,
@@ -1593,6 +1690,8 @@ fn apply_update(app: &mut App, update: WorkerUpdate, channels: &WorkerChannels)
                         max_consecutive_losses: 0,
                         max_consecutive_wins: 0,
                         avg_losing_streak: 0.0,
+                        ulcer_index: 0.0,
+                        upi: 0.0,
                     },
                 });
             }
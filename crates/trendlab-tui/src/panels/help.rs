@@ -59,6 +59,10 @@ const GLOBAL_HELP: HelpContent = HelpContent {
             key: "R",
             description: "Reset defaults (randomize if enabled)",
         },
+        HelpEntry {
+            key: "S",
+            description: "Save session (restored on next launch)",
+        },
     ],
     details: r#"
 TrendLab uses a panel-based interface with vim-style navigation throughout.
@@ -70,6 +74,9 @@ The startup modal (shown on launch) offers two modes:
 
 Press 'R' to reset parameters. If random defaults are enabled (seed shown in
 status bar), this randomizes parameters for exploration.
+
+Press 'S' to save the current tickers, strategy parameters, sweep grid and
+date range. The saved session is restored automatically on the next launch.
 "#,
 };
 
@@ -378,6 +385,10 @@ const CHART_HELP: HelpContent = HelpContent {
             key: "d",
             description: "Toggle drawdown overlay",
         },
+        HelpEntry {
+            key: "x",
+            description: "Export current view to CSV",
+        },
         HelpEntry {
             key: "0",
             description: "Reset view to default",
@@ -396,6 +407,7 @@ Display Options:
 - Volume (v): Show/hide volume bars below price
 - Crosshair (c): Enable/disable cursor tracking
 - Drawdown (d): Overlay drawdown percentage from peak
+- Export (x): Save the current view's curves (or OHLCV candles) to CSV
 
 Navigation:
 - ←/→: Pan through time (scroll)
@@ -741,11 +741,13 @@ fn draw_leaderboard_table(
             Cell::from(format!("{:.1}%", entry.aggregate_metrics.hit_rate * 100.0))
                 .style(hit_style),
             Cell::from(fdr_text).style(fdr_style),
-            // Show session_iteration in Session view, all-time iteration in AllTime view
+            // Show session_iteration in Session/WalkForward views, all-time iteration in AllTime view
             Cell::from(format!(
                 "{}",
                 match app.yolo.view_scope {
-                    LeaderboardScope::Session => entry.session_iteration.unwrap_or(entry.iteration),
+                    LeaderboardScope::Session | LeaderboardScope::WalkForward => {
+                        entry.session_iteration.unwrap_or(entry.iteration)
+                    }
                     LeaderboardScope::AllTime => entry.iteration,
                 }
             ))
@@ -1159,7 +1161,7 @@ fn draw_empty_leaderboard(f: &mut Frame, app: &mut App, area: Rect, is_active: b
             Span::styled("Press ", Style::default().fg(colors::FG_DARK)),
             Span::styled("'t'", Style::default().fg(colors::CYAN)),
             Span::styled(
-                " to toggle Session/All-Time, ",
+                " to cycle Session/All-Time/Walk-Forward, ",
                 Style::default().fg(colors::FG_DARK),
             ),
             Span::styled("'Y'", Style::default().fg(colors::MAGENTA)),
@@ -141,7 +141,7 @@ fn draw_status(f: &mut Frame, app: &App, area: Rect) {
             "↑↓: Select result  Enter: View chart  R: Reset defaults  Tab: Next panel"
         }
         Panel::Chart => {
-            "←→: Scroll  ↑↓: Zoom  m: Mode  v: Volume  c: Crosshair  d: Drawdown  R: Reset defaults"
+            "←→: Scroll  ↑↓: Zoom  m: Mode  v: Volume  c: Crosshair  d: Drawdown  x: Export  R: Reset defaults"
         }
         Panel::Help => {
             if app.help.search_mode {
@@ -707,6 +707,25 @@ fn draw_yolo_config_modal(f: &mut Frame, app: &App) {
         )]));
     }
 
+    // Offer to resume an interrupted session
+    if let Some(ref checkpoint) = app.yolo.resume_from {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled(
+            format!(
+                "Resumes interrupted session {} after iteration {}",
+                checkpoint.session_id, checkpoint.session_iteration
+            ),
+            Style::default().fg(colors::CYAN),
+        )]));
+        lines.push(Line::from(vec![
+            Span::styled("n", Style::default().fg(colors::YELLOW)),
+            Span::styled(
+                " start a new session instead",
+                Style::default().fg(colors::FG_DARK),
+            ),
+        ]));
+    }
+
     // Clear the area first
     f.render_widget(Clear, area);
 